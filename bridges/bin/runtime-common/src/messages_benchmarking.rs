@@ -34,7 +34,6 @@ use bp_messages::MessagePayload;
 use bp_polkadot_core::parachains::ParaHash;
 use bp_runtime::{Chain, Parachain, StorageProofSize, UnderlyingChainOf};
 use codec::Encode;
-use frame_support::weights::Weight;
 use pallet_bridge_messages::benchmarking::{MessageDeliveryProofParams, MessageProofParams};
 use sp_runtime::traits::{Header, Zero};
 use sp_std::prelude::*;
@@ -78,7 +77,7 @@ fn prepare_inbound_message(
 pub fn prepare_message_proof_from_grandpa_chain<R, FI, B>(
 	params: MessageProofParams,
 	message_generator: impl Fn(usize) -> MessagePayload,
-) -> (FromBridgedChainMessagesProof<HashOf<BridgedChain<B>>>, Weight)
+) -> FromBridgedChainMessagesProof<HashOf<BridgedChain<B>>>
 where
 	R: pallet_bridge_grandpa::Config<FI, BridgedChain = UnderlyingChainOf<BridgedChain<B>>>,
 	FI: 'static,
@@ -98,16 +97,13 @@ where
 	// update runtime storage
 	let (_, bridged_header_hash) = insert_header_to_grandpa_pallet::<R, FI>(state_root);
 
-	(
-		FromBridgedChainMessagesProof {
-			bridged_header_hash,
-			storage_proof,
-			lane: params.lane,
-			nonces_start: *params.message_nonces.start(),
-			nonces_end: *params.message_nonces.end(),
-		},
-		Weight::MAX / 1000,
-	)
+	FromBridgedChainMessagesProof {
+		bridged_header_hash,
+		storage_proof,
+		lane: params.lane,
+		nonces_start: *params.message_nonces.start(),
+		nonces_end: *params.message_nonces.end(),
+	}
 }
 
 /// Prepare proof of messages for the `receive_messages_proof` call.
@@ -121,7 +117,7 @@ where
 pub fn prepare_message_proof_from_parachain<R, PI, B>(
 	params: MessageProofParams,
 	message_generator: impl Fn(usize) -> MessagePayload,
-) -> (FromBridgedChainMessagesProof<HashOf<BridgedChain<B>>>, Weight)
+) -> FromBridgedChainMessagesProof<HashOf<BridgedChain<B>>>
 where
 	R: pallet_bridge_parachains::Config<PI>,
 	PI: 'static,
@@ -143,16 +139,13 @@ where
 	let (_, bridged_header_hash) =
 		insert_header_to_parachains_pallet::<R, PI, UnderlyingChainOf<BridgedChain<B>>>(state_root);
 
-	(
-		FromBridgedChainMessagesProof {
-			bridged_header_hash,
-			storage_proof,
-			lane: params.lane,
-			nonces_start: *params.message_nonces.start(),
-			nonces_end: *params.message_nonces.end(),
-		},
-		Weight::MAX / 1000,
-	)
+	FromBridgedChainMessagesProof {
+		bridged_header_hash,
+		storage_proof,
+		lane: params.lane,
+		nonces_start: *params.message_nonces.start(),
+		nonces_end: *params.message_nonces.end(),
+	}
 }
 
 /// Prepare proof of messages delivery for the `receive_messages_delivery_proof` call.
@@ -192,12 +192,12 @@ pub struct LocalXcmQueueManager<H>(PhantomData<H>);
 
 /// Maximal number of messages in the outbound bridge queue. Once we reach this limit, we
 /// send a "congestion" XCM message to the sending chain.
-const OUTBOUND_LANE_CONGESTED_THRESHOLD: MessageNonce = 8_192;
+pub const OUTBOUND_LANE_CONGESTED_THRESHOLD: MessageNonce = 8_192;
 
 /// After we have sent "congestion" XCM message to the sending chain, we wait until number
 /// of messages in the outbound bridge queue drops to this count, before sending `uncongestion`
 /// XCM message.
-const OUTBOUND_LANE_UNCONGESTED_THRESHOLD: MessageNonce = 1_024;
+pub const OUTBOUND_LANE_UNCONGESTED_THRESHOLD: MessageNonce = 1_024;
 
 impl<H: XcmBlobHauler> LocalXcmQueueManager<H> {
 	/// Must be called whenever we push a message to the bridge lane.
@@ -220,6 +220,8 @@ impl pallet_bridge_messages::Config for TestRuntime {
 		ConstU64<100_000>,
 	>;
 	type OnMessagesDelivered = ();
+	type LaneStallThreshold = ConstU32<10>;
+	type OnLaneStalled = ();
 
 	type SourceHeaderChain = SourceHeaderChainAdapter<OnThisChainBridge>;
 	type MessageDispatch = DummyMessageDispatch;
@@ -231,6 +233,7 @@ impl pallet_bridge_relayers::Config for TestRuntime {
 	type Reward = ThisChainBalance;
 	type PaymentProcedure = TestPaymentProcedure;
 	type StakeAndSlash = TestStakeAndSlash;
+	type BridgedRelayerOrigin = frame_system::EnsureNever<ThisChainAccountId>;
 	type WeightInfo = ();
 }
 
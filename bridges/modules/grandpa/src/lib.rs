@@ -607,6 +607,32 @@ pub mod pallet {
 	}
 }
 
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Get ids of the bridged headers that are currently stored by the pallet, ordered by their
+	/// import time (oldest first).
+	///
+	/// Headers are pruned once there are more than `HeadersToKeep` of them, so only the recently
+	/// imported headers are returned.
+	pub fn imported_headers() -> Vec<BridgedBlockId<T, I>> {
+		let pointer = ImportedHashesPointer::<T, I>::get();
+		(pointer..T::HeadersToKeep::get())
+			.chain(0..pointer)
+			.filter_map(ImportedHashes::<T, I>::get)
+			.filter_map(|hash| {
+				ImportedHeaders::<T, I>::get(hash).map(|header| HeaderId(header.number, hash))
+			})
+			.collect()
+	}
+
+	/// Returns true if the bridged header with given hash has been imported by the pallet and has
+	/// not been pruned yet.
+	///
+	/// The pallet only imports finalized headers, so every known header is finalized.
+	pub fn is_known_header(hash: BridgedBlockHash<T, I>) -> bool {
+		ImportedHeaders::<T, I>::contains_key(hash)
+	}
+}
+
 impl<T: Config<I>, I: 'static> Pallet<T, I>
 where
 	<T as frame_system::Config>::RuntimeEvent: TryInto<Event<T, I>>,
@@ -1385,6 +1411,32 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn imported_headers_are_ordered_by_import_time() {
+		run_test(|| {
+			initialize_substrate_bridge();
+			let genesis_hash = test_header(0).hash();
+			assert_eq!(Pallet::<TestRuntime>::imported_headers(), vec![HeaderId(0, genesis_hash)]);
+			assert!(Pallet::<TestRuntime>::is_known_header(genesis_hash));
+
+			for header in 1..=6 {
+				assert_ok!(submit_finality_proof(header));
+				next_block();
+			}
+
+			assert_eq!(
+				Pallet::<TestRuntime>::imported_headers(),
+				(2..=6)
+					.map(|number| HeaderId(number, test_header(number).hash()))
+					.collect::<Vec<_>>(),
+			);
+			assert!(!Pallet::<TestRuntime>::is_known_header(genesis_hash));
+			assert!(!Pallet::<TestRuntime>::is_known_header(test_header(1).hash()));
+			assert!(Pallet::<TestRuntime>::is_known_header(test_header(6).hash()));
+			assert!(!Pallet::<TestRuntime>::is_known_header(test_header(7).hash()));
+		})
+	}
+
 	#[test]
 	fn storage_keys_computed_properly() {
 		assert_eq!(
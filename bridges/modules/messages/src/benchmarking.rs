@@ -17,19 +17,21 @@
 //! Messages pallet benchmarking.
 
 use crate::{
-	inbound_lane::InboundLaneStorage, outbound_lane, weights_ext::EXPECTED_DEFAULT_MESSAGE_LENGTH,
-	Call, OutboundLanes, RuntimeInboundLaneStorage,
+	inbound_lane::InboundLaneStorage, outbound_lane, verify_and_decode_messages_proof,
+	weights_ext::EXPECTED_DEFAULT_MESSAGE_LENGTH, Call, OutboundLanes, OutboundLanesStalled,
+	RuntimeInboundLaneStorage,
 };
 
 use bp_messages::{
-	source_chain::TargetHeaderChain, target_chain::SourceHeaderChain, DeliveredMessages,
-	InboundLaneData, LaneId, MessageNonce, OutboundLaneData, UnrewardedRelayer,
+	source_chain::TargetHeaderChain,
+	target_chain::{MessageDispatch, SourceHeaderChain},
+	DeliveredMessages, InboundLaneData, LaneId, MessageNonce, OutboundLaneData, UnrewardedRelayer,
 	UnrewardedRelayersState,
 };
 use bp_runtime::StorageProofSize;
 use codec::Decode;
 use frame_benchmarking::{account, benchmarks_instance_pallet};
-use frame_support::weights::Weight;
+use frame_support::{traits::Get, weights::Weight};
 use frame_system::RawOrigin;
 use sp_runtime::traits::TrailingZeroInput;
 use sp_std::{ops::RangeInclusive, prelude::*};
@@ -92,9 +94,12 @@ pub trait Config<I: 'static>: crate::Config<I> {
 	fn endow_account(_account: &Self::AccountId) {}
 
 	/// Prepare messages proof to receive by the module.
+	///
+	/// Dispatch weight of proved messages is computed by the benchmarks using the runtime
+	/// `MessageDispatch` implementation.
 	fn prepare_message_proof(
 		params: MessageProofParams,
-	) -> (<Self::SourceHeaderChain as SourceHeaderChain>::MessagesProof, Weight);
+	) -> <Self::SourceHeaderChain as SourceHeaderChain>::MessagesProof;
 	/// Prepare messages delivery proof to receive by the module.
 	fn prepare_message_delivery_proof(
 		params: MessageDeliveryProofParams<Self::AccountId>,
@@ -129,7 +134,7 @@ benchmarks_instance_pallet! {
 		// mark messages 1..=20 as delivered
 		receive_messages::<T, I>(20);
 
-		let (proof, dispatch_weight) = T::prepare_message_proof(MessageProofParams {
+		let (proof, dispatch_weight) = prepare_message_proof::<T, I>(MessageProofParams {
 			lane: T::bench_lane_id(),
 			message_nonces: 21..=21,
 			outbound_lane_data: None,
@@ -162,7 +167,7 @@ benchmarks_instance_pallet! {
 		// mark messages 1..=20 as delivered
 		receive_messages::<T, I>(20);
 
-		let (proof, dispatch_weight) = T::prepare_message_proof(MessageProofParams {
+		let (proof, dispatch_weight) = prepare_message_proof::<T, I>(MessageProofParams {
 			lane: T::bench_lane_id(),
 			message_nonces: 21..=22,
 			outbound_lane_data: None,
@@ -195,7 +200,7 @@ benchmarks_instance_pallet! {
 		// mark messages 1..=20 as delivered
 		receive_messages::<T, I>(20);
 
-		let (proof, dispatch_weight) = T::prepare_message_proof(MessageProofParams {
+		let (proof, dispatch_weight) = prepare_message_proof::<T, I>(MessageProofParams {
 			lane: T::bench_lane_id(),
 			message_nonces: 21..=21,
 			outbound_lane_data: Some(OutboundLaneData {
@@ -230,7 +235,7 @@ benchmarks_instance_pallet! {
 		// mark messages 1..=20 as delivered
 		receive_messages::<T, I>(20);
 
-		let (proof, dispatch_weight) = T::prepare_message_proof(MessageProofParams {
+		let (proof, dispatch_weight) = prepare_message_proof::<T, I>(MessageProofParams {
 			lane: T::bench_lane_id(),
 			message_nonces: 21..=21,
 			outbound_lane_data: None,
@@ -264,7 +269,7 @@ benchmarks_instance_pallet! {
 		// mark messages 1..=20 as delivered
 		receive_messages::<T, I>(20);
 
-		let (proof, dispatch_weight) = T::prepare_message_proof(MessageProofParams {
+		let (proof, dispatch_weight) = prepare_message_proof::<T, I>(MessageProofParams {
 			lane: T::bench_lane_id(),
 			message_nonces: 21..=21,
 			outbound_lane_data: None,
@@ -422,7 +427,7 @@ benchmarks_instance_pallet! {
 		// mark messages 1..=20 as delivered
 		receive_messages::<T, I>(20);
 
-		let (proof, dispatch_weight) = T::prepare_message_proof(MessageProofParams {
+		let (proof, dispatch_weight) = prepare_message_proof::<T, I>(MessageProofParams {
 			lane: T::bench_lane_id(),
 			message_nonces: 21..=21,
 			outbound_lane_data: None,
@@ -438,9 +443,79 @@ benchmarks_instance_pallet! {
 		assert!(T::is_message_successfully_dispatched(21));
 	}
 
+	// Benchmark `receive_messages_proof` extrinsic with single message and following conditions:
+	//
+	// * proof does not include outbound lane state proof;
+	// * inbound lane already has state, so it needs to be read and decoded;
+	// * message is **NOT** dispatched, because it can't be decoded.
+	//
+	// The message has the same size as in the `receive_single_message_proof_with_dispatch`
+	// benchmark, so the difference between these two benchmarks is the weight of the
+	// message dispatch itself, without the weight of its delivery and proof verification.
+	receive_single_message_proof_without_dispatch {
+		let i in EXPECTED_DEFAULT_MESSAGE_LENGTH .. EXPECTED_DEFAULT_MESSAGE_LENGTH * 16;
+
+		let relayer_id_on_source = T::bridged_relayer_id();
+		let relayer_id_on_target = account("relayer", 0, SEED);
+		T::endow_account(&relayer_id_on_target);
+
+		// mark messages 1..=20 as delivered
+		receive_messages::<T, I>(20);
+
+		let (proof, dispatch_weight) = prepare_message_proof::<T, I>(MessageProofParams {
+			lane: T::bench_lane_id(),
+			message_nonces: 21..=21,
+			outbound_lane_data: None,
+			is_successful_dispatch_expected: false,
+			size: StorageProofSize::Minimal(i),
+		});
+	}: receive_messages_proof(RawOrigin::Signed(relayer_id_on_target), relayer_id_on_source, proof, 1, dispatch_weight)
+	verify {
+		assert_eq!(
+			crate::InboundLanes::<T, I>::get(&T::bench_lane_id()).last_delivered_nonce(),
+			21,
+		);
+	}
+
+	// Benchmark `mark_lane_stalled` extrinsic with following conditions:
+	// * the lane has a queued message;
+	// * no deliveries have been confirmed for `LaneStallThreshold` blocks.
+	mark_lane_stalled {
+		let caller: T::AccountId = account("caller", 0, SEED);
+
+		// send message that is never delivered
+		send_regular_message::<T, I>();
+		frame_system::Pallet::<T>::set_block_number(T::LaneStallThreshold::get());
+	}: mark_lane_stalled(RawOrigin::Signed(caller), T::bench_lane_id())
+	verify {
+		assert!(OutboundLanesStalled::<T, I>::get(T::bench_lane_id()));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::TestRuntime)
 }
 
+/// Prepare messages proof and compute dispatch weight of all messages in the proof, using the
+/// runtime `MessageDispatch` implementation.
+fn prepare_message_proof<T: Config<I>, I: 'static>(
+	params: MessageProofParams,
+) -> (<T::SourceHeaderChain as SourceHeaderChain>::MessagesProof, Weight) {
+	let messages_count = params.message_nonces.clone().count() as u32;
+	let proof = T::prepare_message_proof(params);
+	let dispatch_weight =
+		verify_and_decode_messages_proof::<T::SourceHeaderChain, T::InboundPayload>(
+			proof.clone(),
+			messages_count,
+		)
+		.expect("benchmarks are correct")
+		.into_values()
+		.flat_map(|lane_data| lane_data.messages)
+		.fold(Weight::zero(), |total_dispatch_weight, mut message| {
+			total_dispatch_weight.saturating_add(T::MessageDispatch::dispatch_weight(&mut message))
+		});
+
+	(proof, dispatch_weight)
+}
+
 fn send_regular_message<T: Config<I>, I: 'static>() {
 	let mut outbound_lane = outbound_lane::<T, I>(T::bench_lane_id());
 	outbound_lane.send_message(vec![]).expect("We craft valid messages");
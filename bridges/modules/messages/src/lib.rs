@@ -53,7 +53,7 @@ use crate::{
 
 use bp_messages::{
 	source_chain::{
-		DeliveryConfirmationPayments, LaneMessageVerifier, OnMessagesDelivered,
+		DeliveryConfirmationPayments, LaneMessageVerifier, OnLaneStalled, OnMessagesDelivered,
 		SendMessageArtifacts, TargetHeaderChain,
 	},
 	target_chain::{
@@ -161,6 +161,12 @@ pub mod pallet {
 		type DeliveryConfirmationPayments: DeliveryConfirmationPayments<Self::AccountId>;
 		/// Delivery confirmation callback.
 		type OnMessagesDelivered: OnMessagesDelivered;
+		/// Number of blocks without confirmed deliveries, after which the outbound lane with
+		/// queued messages may be marked as stalled.
+		#[pallet::constant]
+		type LaneStallThreshold: Get<BlockNumberFor<Self>>;
+		/// Lane stall callback.
+		type OnLaneStalled: OnLaneStalled;
 
 		// Types that are used by inbound_lane (on target chain).
 
@@ -462,6 +468,13 @@ pub mod pallet {
 				.map_err(Error::<T, I>::ReceivalConfirmation)?;
 
 			if let Some(confirmed_messages) = confirmed_messages {
+				// the lane is alive again
+				OutboundLanesLastActivity::<T, I>::insert(
+					lane_id,
+					frame_system::Pallet::<T>::block_number(),
+				);
+				OutboundLanesStalled::<T, I>::remove(lane_id);
+
 				// emit 'delivered' event
 				let received_range = confirmed_messages.begin..=confirmed_messages.end;
 				Self::deposit_event(Event::MessagesDelivered {
@@ -511,6 +524,54 @@ pub mod pallet {
 
 			Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::Yes })
 		}
+
+		/// Mark outbound lane as stalled.
+		///
+		/// May be called by anyone, if there are queued messages at the lane and no deliveries
+		/// have been confirmed for at least `LaneStallThreshold` blocks. The lane can't be marked
+		/// again until new deliveries are confirmed. The transaction is free if the lane has been
+		/// marked.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::mark_lane_stalled())]
+		pub fn mark_lane_stalled(
+			origin: OriginFor<T>,
+			lane_id: LaneId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_not_halted().map_err(Error::<T, I>::BridgeModule)?;
+			ensure_signed(origin)?;
+
+			ensure!(
+				T::ActiveOutboundLanes::get().contains(&lane_id),
+				Error::<T, I>::InactiveOutboundLane
+			);
+			ensure!(
+				!OutboundLanesStalled::<T, I>::get(lane_id),
+				Error::<T, I>::LaneIsAlreadyMarkedStalled
+			);
+
+			let enqueued_messages =
+				OutboundLanes::<T, I>::get(lane_id).queued_messages().saturating_len();
+			let inactive_blocks = frame_system::Pallet::<T>::block_number()
+				.saturating_sub(OutboundLanesLastActivity::<T, I>::get(lane_id));
+			ensure!(
+				enqueued_messages != 0 && inactive_blocks >= T::LaneStallThreshold::get(),
+				Error::<T, I>::LaneIsNotStalled
+			);
+
+			OutboundLanesStalled::<T, I>::insert(lane_id, true);
+			log::trace!(
+				target: LOG_TARGET,
+				"Outbound lane {:?} with {} queued messages is stalled for {:?} blocks",
+				lane_id,
+				enqueued_messages,
+				inactive_blocks,
+			);
+
+			T::OnLaneStalled::on_lane_stalled(lane_id, enqueued_messages);
+			Self::deposit_event(Event::LaneStalled { lane_id, enqueued_messages, inactive_blocks });
+
+			Ok(Pays::No.into())
+		}
 	}
 
 	#[pallet::event]
@@ -524,6 +585,13 @@ pub mod pallet {
 		),
 		/// Messages in the inclusive range have been delivered to the bridged chain.
 		MessagesDelivered { lane_id: LaneId, messages: DeliveredMessages },
+		/// Outbound lane has queued messages, but no deliveries have been confirmed for
+		/// `inactive_blocks` blocks.
+		LaneStalled {
+			lane_id: LaneId,
+			enqueued_messages: MessageNonce,
+			inactive_blocks: BlockNumberFor<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -560,6 +628,10 @@ pub mod pallet {
 		ReceivalConfirmation(ReceivalConfirmationError),
 		/// Error generated by the `OwnedBridgeModule` trait.
 		BridgeModule(bp_runtime::OwnedBridgeModuleError),
+		/// The outbound lane has no queued messages or deliveries have been confirmed recently.
+		LaneIsNotStalled,
+		/// The outbound lane is already marked as stalled.
+		LaneIsAlreadyMarkedStalled,
 	}
 
 	/// Optional pallet owner.
@@ -615,6 +687,32 @@ pub mod pallet {
 		MaxValues = MaybeOutboundLanesCount<T, I>,
 	>;
 
+	/// Map of lane id => block of the last outbound lane activity.
+	///
+	/// The activity is either a delivery confirmation, or a message that has been sent to the
+	/// empty lane.
+	#[pallet::storage]
+	pub type OutboundLanesLastActivity<T: Config<I>, I: 'static = ()> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = LaneId,
+		Value = BlockNumberFor<T>,
+		QueryKind = ValueQuery,
+		OnEmpty = GetDefault,
+		MaxValues = MaybeOutboundLanesCount<T, I>,
+	>;
+
+	/// Map of lane id => is the lane marked as stalled. The flag is cleared when new deliveries
+	/// are confirmed.
+	#[pallet::storage]
+	pub type OutboundLanesStalled<T: Config<I>, I: 'static = ()> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = LaneId,
+		Value = bool,
+		QueryKind = ValueQuery,
+		OnEmpty = GetDefault,
+		MaxValues = MaybeOutboundLanesCount<T, I>,
+	>;
+
 	/// All queued outbound messages.
 	#[pallet::storage]
 	pub type OutboundMessages<T: Config<I>, I: 'static = ()> =
@@ -746,6 +844,14 @@ fn send_message<T: Config<I>, I: 'static>(
 	// return number of messages in the queue to let sender know about its state
 	let enqueued_messages = lane.data().queued_messages().saturating_len();
 
+	// the lane has been empty before => it can't be stalled for longer than the message is queued
+	if enqueued_messages == 1 {
+		OutboundLanesLastActivity::<T, I>::insert(
+			lane_id,
+			frame_system::Pallet::<T>::block_number(),
+		);
+	}
+
 	log::trace!(
 		target: LOG_TARGET,
 		"Accepted message {} to lane {:?}. Message size: {:?}",
@@ -925,10 +1031,10 @@ mod tests {
 			inbound_unrewarded_relayers_state, message, message_payload, run_test,
 			unrewarded_relayer, AccountId, DbWeight, RuntimeEvent as TestEvent, RuntimeOrigin,
 			TestDeliveryConfirmationPayments, TestDeliveryPayments, TestMessageDispatch,
-			TestMessagesDeliveryProof, TestMessagesProof, TestOnMessagesDelivered, TestRelayer,
-			TestRuntime, TestWeightInfo, MAX_OUTBOUND_PAYLOAD_SIZE,
-			PAYLOAD_REJECTED_BY_TARGET_CHAIN, REGULAR_PAYLOAD, TEST_LANE_ID, TEST_LANE_ID_2,
-			TEST_LANE_ID_3, TEST_RELAYER_A, TEST_RELAYER_B,
+			TestMessagesDeliveryProof, TestMessagesProof, TestOnLaneStalled,
+			TestOnMessagesDelivered, TestRelayer, TestRuntime, TestWeightInfo,
+			MAX_OUTBOUND_PAYLOAD_SIZE, PAYLOAD_REJECTED_BY_TARGET_CHAIN, REGULAR_PAYLOAD,
+			TEST_LANE_ID, TEST_LANE_ID_2, TEST_LANE_ID_3, TEST_RELAYER_A, TEST_RELAYER_B,
 		},
 		outbound_lane::ReceivalConfirmationError,
 	};
@@ -1328,6 +1434,62 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn mark_lane_stalled_works() {
+		run_test(|| {
+			// message is sent at block #1
+			send_regular_message();
+
+			// no deliveries have been confirmed, but the threshold has not been reached yet
+			System::<TestRuntime>::set_block_number(10);
+			assert_noop!(
+				Pallet::<TestRuntime>::mark_lane_stalled(RuntimeOrigin::signed(1), TEST_LANE_ID),
+				Error::<TestRuntime, ()>::LaneIsNotStalled,
+			);
+
+			// the threshold has been reached => the lane is stalled
+			System::<TestRuntime>::set_block_number(11);
+			let result =
+				Pallet::<TestRuntime>::mark_lane_stalled(RuntimeOrigin::signed(1), TEST_LANE_ID);
+			assert_eq!(result.map(|post_info| post_info.pays_fee), Ok(Pays::No));
+			assert!(OutboundLanesStalled::<TestRuntime, ()>::get(TEST_LANE_ID));
+			assert_eq!(TestOnLaneStalled::call_arguments(), Some((TEST_LANE_ID, 1)));
+			assert_eq!(
+				System::<TestRuntime>::events().last().map(|record| record.event.clone()),
+				Some(TestEvent::Messages(Event::LaneStalled {
+					lane_id: TEST_LANE_ID,
+					enqueued_messages: 1,
+					inactive_blocks: 10,
+				})),
+			);
+
+			// the lane can't be marked again
+			assert_noop!(
+				Pallet::<TestRuntime>::mark_lane_stalled(RuntimeOrigin::signed(1), TEST_LANE_ID),
+				Error::<TestRuntime, ()>::LaneIsAlreadyMarkedStalled,
+			);
+
+			// delivery confirmation clears the flag and there are no more queued messages
+			receive_messages_delivery_proof();
+			assert!(!OutboundLanesStalled::<TestRuntime, ()>::get(TEST_LANE_ID));
+			System::<TestRuntime>::set_block_number(100);
+			assert_noop!(
+				Pallet::<TestRuntime>::mark_lane_stalled(RuntimeOrigin::signed(1), TEST_LANE_ID),
+				Error::<TestRuntime, ()>::LaneIsNotStalled,
+			);
+		});
+	}
+
+	#[test]
+	fn mark_lane_stalled_rejects_inactive_lane() {
+		run_test(|| {
+			assert_noop!(
+				Pallet::<TestRuntime>::mark_lane_stalled(RuntimeOrigin::signed(1), TEST_LANE_ID_3),
+				Error::<TestRuntime, ()>::InactiveOutboundLane,
+			);
+		});
+	}
+
 	#[test]
 	fn receive_messages_delivery_proof_rewards_relayers() {
 		run_test(|| {
@@ -22,7 +22,8 @@ use crate::Config;
 use bp_messages::{
 	calc_relayers_rewards,
 	source_chain::{
-		DeliveryConfirmationPayments, LaneMessageVerifier, OnMessagesDelivered, TargetHeaderChain,
+		DeliveryConfirmationPayments, LaneMessageVerifier, OnLaneStalled, OnMessagesDelivered,
+		TargetHeaderChain,
 	},
 	target_chain::{
 		DeliveryPayments, DispatchMessage, DispatchMessageData, MessageDispatch,
@@ -100,6 +101,7 @@ parameter_types! {
 	pub const MaxUnconfirmedMessagesAtInboundLane: u64 = 128;
 	pub const TestBridgedChainId: bp_runtime::ChainId = *b"test";
 	pub const ActiveOutboundLanes: &'static [LaneId] = &[TEST_LANE_ID, TEST_LANE_ID_2];
+	pub const LaneStallThreshold: u64 = 10;
 }
 
 /// weights of messages pallet calls we use in tests.
@@ -123,6 +125,8 @@ impl Config for TestRuntime {
 	type LaneMessageVerifier = TestLaneMessageVerifier;
 	type DeliveryConfirmationPayments = TestDeliveryConfirmationPayments;
 	type OnMessagesDelivered = TestOnMessagesDelivered;
+	type LaneStallThreshold = LaneStallThreshold;
+	type OnLaneStalled = TestOnLaneStalled;
 
 	type SourceHeaderChain = TestSourceHeaderChain;
 	type MessageDispatch = TestMessageDispatch;
@@ -135,23 +139,14 @@ impl crate::benchmarking::Config<()> for TestRuntime {
 		TEST_LANE_ID
 	}
 
-	fn prepare_message_proof(
-		params: crate::benchmarking::MessageProofParams,
-	) -> (TestMessagesProof, Weight) {
+	fn prepare_message_proof(params: crate::benchmarking::MessageProofParams) -> TestMessagesProof {
 		// in mock run we only care about benchmarks correctness, not the benchmark results
 		// => ignore size related arguments
-		let (messages, total_dispatch_weight) =
-			params.message_nonces.into_iter().map(|n| message(n, REGULAR_PAYLOAD)).fold(
-				(Vec::new(), Weight::zero()),
-				|(mut messages, total_dispatch_weight), message| {
-					let weight = REGULAR_PAYLOAD.declared_weight;
-					messages.push(message);
-					(messages, total_dispatch_weight.saturating_add(weight))
-				},
-			);
+		let messages =
+			params.message_nonces.into_iter().map(|n| message(n, REGULAR_PAYLOAD)).collect();
 		let mut proof: TestMessagesProof = Ok(messages).into();
 		proof.result.as_mut().unwrap().get_mut(0).unwrap().1.lane_state = params.outbound_lane_data;
-		(proof, total_dispatch_weight)
+		proof
 	}
 
 	fn prepare_message_delivery_proof(
@@ -419,6 +414,24 @@ impl OnMessagesDelivered for TestOnMessagesDelivered {
 	}
 }
 
+/// Test callback, called when the outbound lane is marked as stalled.
+pub struct TestOnLaneStalled;
+
+impl TestOnLaneStalled {
+	pub fn call_arguments() -> Option<(LaneId, MessageNonce)> {
+		frame_support::storage::unhashed::get(b"TestOnLaneStalled.OnLaneStalled")
+	}
+}
+
+impl OnLaneStalled for TestOnLaneStalled {
+	fn on_lane_stalled(lane: LaneId, enqueued_messages: MessageNonce) {
+		frame_support::storage::unhashed::put(
+			b"TestOnLaneStalled.OnLaneStalled",
+			&(lane, enqueued_messages),
+		);
+	}
+}
+
 /// Return test lane message with given nonce and payload.
 pub fn message(nonce: MessageNonce, payload: TestPayload) -> Message {
 	Message { key: MessageKey { lane_id: TEST_LANE_ID, nonce }, payload: payload.encode() }
@@ -59,6 +59,8 @@ pub trait WeightInfo {
 	fn receive_delivery_proof_for_two_messages_by_single_relayer() -> Weight;
 	fn receive_delivery_proof_for_two_messages_by_two_relayers() -> Weight;
 	fn receive_single_message_proof_with_dispatch(i: u32) -> Weight;
+	fn receive_single_message_proof_without_dispatch(i: u32) -> Weight;
+	fn mark_lane_stalled() -> Weight;
 }
 
 /// Weights for `pallet_bridge_messages` that are generated using one of the Bridge testnets.
@@ -292,6 +294,54 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: BridgeUnknownMessages PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeUnknownMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
+	/// added: 497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeUnknownGrandpa ImportedHeaders (r:1 w:0)
+	///
+	/// Proof: BridgeUnknownGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
+	/// added: 2048, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeUnknownMessages InboundLanes (r:1 w:1)
+	///
+	/// Proof: BridgeUnknownMessages InboundLanes (max_values: None, max_size: Some(49180), added:
+	/// 51655, mode: MaxEncodedLen)
+	///
+	/// The range of component `i` is `[128, 2048]`.
+	fn receive_single_message_proof_without_dispatch(i: u32) -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(52_101_203, 57170)
+			.saturating_add(Weight::from_parts(2_071, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: BridgeUnknownMessages PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeUnknownMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
+	/// added: 497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeUnknownMessages OutboundLanesStalled (r:1 w:1)
+	///
+	/// Proof: BridgeUnknownMessages OutboundLanesStalled (max_values: Some(1), max_size: Some(21),
+	/// added: 516, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeUnknownMessages OutboundLanes (r:1 w:0)
+	///
+	/// Proof: BridgeUnknownMessages OutboundLanes (max_values: Some(1), max_size: Some(44), added:
+	/// 539, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeUnknownMessages OutboundLanesLastActivity (r:1 w:0)
+	///
+	/// Proof: BridgeUnknownMessages OutboundLanesLastActivity (max_values: Some(1), max_size:
+	/// Some(24), added: 519, mode: MaxEncodedLen)
+	fn mark_lane_stalled() -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(21_719_000, 1529)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -522,4 +572,52 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: BridgeUnknownMessages PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeUnknownMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
+	/// added: 497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeUnknownGrandpa ImportedHeaders (r:1 w:0)
+	///
+	/// Proof: BridgeUnknownGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
+	/// added: 2048, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeUnknownMessages InboundLanes (r:1 w:1)
+	///
+	/// Proof: BridgeUnknownMessages InboundLanes (max_values: None, max_size: Some(49180), added:
+	/// 51655, mode: MaxEncodedLen)
+	///
+	/// The range of component `i` is `[128, 2048]`.
+	fn receive_single_message_proof_without_dispatch(i: u32) -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(52_101_203, 57170)
+			.saturating_add(Weight::from_parts(2_071, 0).saturating_mul(i.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: BridgeUnknownMessages PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeUnknownMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
+	/// added: 497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeUnknownMessages OutboundLanesStalled (r:1 w:1)
+	///
+	/// Proof: BridgeUnknownMessages OutboundLanesStalled (max_values: Some(1), max_size: Some(21),
+	/// added: 516, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeUnknownMessages OutboundLanes (r:1 w:0)
+	///
+	/// Proof: BridgeUnknownMessages OutboundLanes (max_values: Some(1), max_size: Some(44), added:
+	/// 539, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeUnknownMessages OutboundLanesLastActivity (r:1 w:0)
+	///
+	/// Proof: BridgeUnknownMessages OutboundLanesLastActivity (max_values: Some(1), max_size:
+	/// Some(24), added: 519, mode: MaxEncodedLen)
+	fn mark_lane_stalled() -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(21_719_000, 1529)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -438,12 +438,13 @@ pub trait WeightInfoExt: WeightInfo {
 	/// This function would return correct value only if your runtime is configured to run
 	/// `receive_single_message_proof_with_dispatch` benchmark. See its requirements for
 	/// details.
+	///
+	/// The weight of delivering and verifying the proof of the same message (without dispatching
+	/// it) is measured by the `receive_single_message_proof_without_dispatch` benchmark and is
+	/// not included into the returned value.
 	fn message_dispatch_weight(message_size: u32) -> Weight {
-		// There may be a tiny overweight/underweight here, because we don't account how message
-		// size affects all steps before dispatch. But the effect should be small enough and we
-		// may ignore it.
 		Self::receive_single_message_proof_with_dispatch(message_size)
-			.saturating_sub(Self::receive_single_message_proof())
+			.saturating_sub(Self::receive_single_message_proof_without_dispatch(message_size))
 	}
 }
 
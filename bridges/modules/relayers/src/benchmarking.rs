@@ -22,7 +22,7 @@ use crate::*;
 
 use bp_messages::LaneId;
 use bp_relayers::RewardsAccountOwner;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_system::RawOrigin;
 use sp_runtime::traits::One;
 
@@ -58,6 +58,22 @@ benchmarks! {
 		// also completed successfully
 	}
 
+	// Benchmark `claim_rewards_to` call.
+	claim_rewards_to {
+		let lane = LaneId([0, 0, 0, 0]);
+		let account_params =
+			RewardsAccountParams::new(lane, *b"test", RewardsAccountOwner::ThisChain);
+		let relayer: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let reward = T::Reward::from(REWARD_AMOUNT);
+
+		T::prepare_rewards_account(account_params, reward);
+		RelayerRewards::<T>::insert(&relayer, account_params, reward);
+	}: _(RawOrigin::Signed(relayer.clone()), account_params, beneficiary)
+	verify {
+		assert!(RelayerRewards::<T>::get(&relayer, account_params).is_none());
+	}
+
 	// Benchmark `register` call.
 	register {
 		let relayer: T::AccountId = whitelisted_caller();
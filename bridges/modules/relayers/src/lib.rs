@@ -67,6 +67,9 @@ pub mod pallet {
 		type PaymentProcedure: PaymentProcedure<Self::AccountId, Self::Reward>;
 		/// Stake and slash scheme.
 		type StakeAndSlash: StakeAndSlash<Self::AccountId, BlockNumberFor<Self>, Self::Reward>;
+		/// Origin of the message from the bridged chain that is allowed to claim rewards on
+		/// behalf of the relayer. On success, it returns the relayer account at this chain.
+		type BridgedRelayerOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 		/// Pallet call weights.
 		type WeightInfo: WeightInfoExt;
 	}
@@ -84,32 +87,7 @@ pub mod pallet {
 			rewards_account_params: RewardsAccountParams,
 		) -> DispatchResult {
 			let relayer = ensure_signed(origin)?;
-
-			RelayerRewards::<T>::try_mutate_exists(
-				&relayer,
-				rewards_account_params,
-				|maybe_reward| -> DispatchResult {
-					let reward = maybe_reward.take().ok_or(Error::<T>::NoRewardForRelayer)?;
-					T::PaymentProcedure::pay_reward(&relayer, rewards_account_params, reward)
-						.map_err(|e| {
-							log::trace!(
-								target: LOG_TARGET,
-								"Failed to pay {:?} rewards to {:?}: {:?}",
-								rewards_account_params,
-								relayer,
-								e,
-							);
-							Error::<T>::FailedToPayReward
-						})?;
-
-					Self::deposit_event(Event::<T>::RewardPaid {
-						relayer: relayer.clone(),
-						rewards_account_params,
-						reward,
-					});
-					Ok(())
-				},
-			)
+			Self::do_claim_rewards(relayer, rewards_account_params, None)
 		}
 
 		/// Register relayer or update its registration.
@@ -207,6 +185,40 @@ pub mod pallet {
 				Ok(())
 			})
 		}
+
+		/// Claim accumulated rewards and pay them to the `beneficiary` account instead of the
+		/// relayer account.
+		///
+		/// This allows relayer operators to keep rewards away from the (hot) key that is used to
+		/// sign relay transactions.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::claim_rewards_to())]
+		pub fn claim_rewards_to(
+			origin: OriginFor<T>,
+			rewards_account_params: RewardsAccountParams,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			Self::do_claim_rewards(relayer, rewards_account_params, Some(beneficiary))
+		}
+
+		/// Claim accumulated rewards on behalf of the relayer, identified by the
+		/// `BridgedRelayerOrigin`, and pay them to the `beneficiary` account.
+		///
+		/// This call is meant to be dispatched by the message from the bridged chain, so that the
+		/// relayer, which has earned rewards at this chain, may redirect them without holding any
+		/// funds here. The runtime is responsible for converting the bridged origin into the
+		/// relayer account.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::claim_rewards_to())]
+		pub fn claim_rewards_from_bridge(
+			origin: OriginFor<T>,
+			rewards_account_params: RewardsAccountParams,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			let relayer = T::BridgedRelayerOrigin::ensure_origin(origin)?;
+			Self::do_claim_rewards(relayer, rewards_account_params, Some(beneficiary))
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -335,6 +347,50 @@ pub mod pallet {
 			);
 		}
 
+		/// Pay accumulated relayer reward to the `beneficiary` or to the relayer itself.
+		fn do_claim_rewards(
+			relayer: T::AccountId,
+			rewards_account_params: RewardsAccountParams,
+			beneficiary: Option<T::AccountId>,
+		) -> DispatchResult {
+			RelayerRewards::<T>::try_mutate_exists(
+				&relayer,
+				rewards_account_params,
+				|maybe_reward| -> DispatchResult {
+					let reward = maybe_reward.take().ok_or(Error::<T>::NoRewardForRelayer)?;
+					let pay_to = beneficiary.as_ref().unwrap_or(&relayer);
+					T::PaymentProcedure::pay_reward(pay_to, rewards_account_params, reward)
+						.map_err(|e| {
+							log::trace!(
+								target: LOG_TARGET,
+								"Failed to pay {:?} rewards of {:?} to {:?}: {:?}",
+								rewards_account_params,
+								relayer,
+								pay_to,
+								e,
+							);
+							Error::<T>::FailedToPayReward
+						})?;
+
+					match beneficiary {
+						Some(beneficiary) =>
+							Self::deposit_event(Event::<T>::RewardPaidToBeneficiary {
+								relayer: relayer.clone(),
+								rewards_account_params,
+								beneficiary,
+								reward,
+							}),
+						None => Self::deposit_event(Event::<T>::RewardPaid {
+							relayer: relayer.clone(),
+							rewards_account_params,
+							reward,
+						}),
+					}
+					Ok(())
+				},
+			)
+		}
+
 		/// Return required registration lease.
 		pub(crate) fn required_registration_lease() -> BlockNumberFor<T> {
 			<T::StakeAndSlash as StakeAndSlash<
@@ -393,6 +449,17 @@ pub mod pallet {
 			/// Reward amount.
 			reward: T::Reward,
 		},
+		/// Relayer reward has been paid to the account, chosen by the relayer.
+		RewardPaidToBeneficiary {
+			/// Relayer account that has earned the reward.
+			relayer: T::AccountId,
+			/// Relayer has received reward from this account.
+			rewards_account_params: RewardsAccountParams,
+			/// Account that has received the reward.
+			beneficiary: T::AccountId,
+			/// Reward amount.
+			reward: T::Reward,
+		},
 		/// Relayer registration has been added or updated.
 		RegistrationUpdated {
 			/// Relayer account that has been registered.
@@ -470,7 +537,7 @@ mod tests {
 	use super::*;
 	use mock::{RuntimeEvent as TestEvent, *};
 
-	use crate::Event::{RewardPaid, RewardRegistered};
+	use crate::Event::{RewardPaid, RewardPaidToBeneficiary, RewardRegistered};
 	use bp_messages::LaneId;
 	use bp_relayers::RewardsAccountOwner;
 	use frame_support::{
@@ -591,6 +658,107 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn relayer_can_claim_reward_to_beneficiary() {
+		run_test(|| {
+			get_ready_for_events();
+
+			RelayerRewards::<TestRuntime>::insert(
+				REGULAR_RELAYER,
+				TEST_REWARDS_ACCOUNT_PARAMS,
+				100,
+			);
+			assert_ok!(Pallet::<TestRuntime>::claim_rewards_to(
+				RuntimeOrigin::signed(REGULAR_RELAYER),
+				TEST_REWARDS_ACCOUNT_PARAMS,
+				REGISTER_RELAYER,
+			));
+			assert_eq!(
+				RelayerRewards::<TestRuntime>::get(REGULAR_RELAYER, TEST_REWARDS_ACCOUNT_PARAMS),
+				None
+			);
+
+			// Check if the `RewardPaidToBeneficiary` event was emitted.
+			assert_eq!(
+				System::<TestRuntime>::events().last(),
+				Some(&EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Relayers(RewardPaidToBeneficiary {
+						relayer: REGULAR_RELAYER,
+						rewards_account_params: TEST_REWARDS_ACCOUNT_PARAMS,
+						beneficiary: REGISTER_RELAYER,
+						reward: 100
+					}),
+					topics: vec![],
+				}),
+			);
+		});
+	}
+
+	#[test]
+	fn relayer_cant_claim_reward_to_beneficiary_if_payment_procedure_fails() {
+		run_test(|| {
+			RelayerRewards::<TestRuntime>::insert(
+				REGULAR_RELAYER,
+				TEST_REWARDS_ACCOUNT_PARAMS,
+				100,
+			);
+			assert_noop!(
+				Pallet::<TestRuntime>::claim_rewards_to(
+					RuntimeOrigin::signed(REGULAR_RELAYER),
+					TEST_REWARDS_ACCOUNT_PARAMS,
+					FAILING_RELAYER,
+				),
+				Error::<TestRuntime>::FailedToPayReward,
+			);
+		});
+	}
+
+	#[test]
+	fn root_cant_claim_rewards_from_bridge() {
+		run_test(|| {
+			assert_noop!(
+				Pallet::<TestRuntime>::claim_rewards_from_bridge(
+					RuntimeOrigin::root(),
+					TEST_REWARDS_ACCOUNT_PARAMS,
+					REGISTER_RELAYER,
+				),
+				DispatchError::BadOrigin,
+			);
+		});
+	}
+
+	#[test]
+	fn bridged_relayer_can_claim_reward_to_beneficiary() {
+		run_test(|| {
+			get_ready_for_events();
+
+			RelayerRewards::<TestRuntime>::insert(
+				REGULAR_RELAYER,
+				TEST_REWARDS_ACCOUNT_PARAMS,
+				100,
+			);
+			assert_ok!(Pallet::<TestRuntime>::claim_rewards_from_bridge(
+				RuntimeOrigin::signed(REGULAR_RELAYER),
+				TEST_REWARDS_ACCOUNT_PARAMS,
+				REGISTER_RELAYER,
+			));
+			assert_eq!(
+				RelayerRewards::<TestRuntime>::get(REGULAR_RELAYER, TEST_REWARDS_ACCOUNT_PARAMS),
+				None
+			);
+			assert_eq!(
+				System::<TestRuntime>::events().last().map(|r| r.event.clone()),
+				Some(TestEvent::Relayers(RewardPaidToBeneficiary {
+					relayer: REGULAR_RELAYER,
+					rewards_account_params: TEST_REWARDS_ACCOUNT_PARAMS,
+					beneficiary: REGISTER_RELAYER,
+					reward: 100
+				})),
+			);
+		});
+	}
+
 	#[test]
 	fn pay_reward_from_account_actually_pays_reward() {
 		type Balances = pallet_balances::Pallet<TestRuntime>;
@@ -77,6 +77,7 @@ impl pallet_bridge_relayers::Config for TestRuntime {
 	type Reward = Balance;
 	type PaymentProcedure = TestPaymentProcedure;
 	type StakeAndSlash = TestStakeAndSlash;
+	type BridgedRelayerOrigin = frame_system::EnsureSigned<AccountId>;
 	type WeightInfo = ();
 }
 
@@ -19,11 +19,19 @@
 use crate::{Config, Pallet};
 
 use bp_messages::{
-	source_chain::{DeliveryConfirmationPayments, RelayersRewards},
+	source_chain::{DeliveryConfirmationPayments, OnLaneStalled, RelayersRewards},
 	LaneId, MessageNonce,
 };
-use bp_relayers::{RewardsAccountOwner, RewardsAccountParams};
-use frame_support::{sp_runtime::SaturatedConversion, traits::Get};
+use bp_relayers::{PayRewardFromAccount, RewardsAccountOwner, RewardsAccountParams};
+use codec::{Decode, Encode};
+use frame_support::{
+	sp_runtime::SaturatedConversion,
+	traits::{
+		fungible::{Inspect, Mutate},
+		tokens::Preservation,
+		Get,
+	},
+};
 use sp_arithmetic::traits::{Saturating, Zero};
 use sp_std::{collections::vec_deque::VecDeque, marker::PhantomData, ops::RangeInclusive};
 
@@ -67,6 +75,58 @@ where
 	}
 }
 
+/// Adapter that boosts rewards of relayers, serving the stalled lane of the messages pallet.
+///
+/// When the lane is marked as stalled, `Boost` is transferred from the `Pot` account to the
+/// account that pays rewards for delivering messages over this lane (see
+/// [`PayRewardFromAccount`]).
+pub struct StalledLaneRewardsBoostAdapter<T, MI, Currency, Pot, Boost>(
+	PhantomData<(T, MI, Currency, Pot, Boost)>,
+);
+
+impl<T, MI, Currency, Pot, Boost> OnLaneStalled
+	for StalledLaneRewardsBoostAdapter<T, MI, Currency, Pot, Boost>
+where
+	T: Config + pallet_bridge_messages::Config<MI>,
+	MI: 'static,
+	Currency: Mutate<T::AccountId>,
+	Pot: Get<T::AccountId>,
+	Boost: Get<<Currency as Inspect<T::AccountId>>::Balance>,
+{
+	fn on_lane_stalled(lane_id: LaneId, _enqueued_messages: MessageNonce) {
+		boost_lane_rewards::<Currency, T::AccountId>(
+			RewardsAccountParams::new(
+				lane_id,
+				T::BridgedChainId::get(),
+				RewardsAccountOwner::BridgedChain,
+			),
+			&Pot::get(),
+			Boost::get(),
+		);
+	}
+}
+
+// Transfer `boost` from the `pot` to the account that pays rewards for given lane.
+fn boost_lane_rewards<Currency, AccountId>(
+	rewards_account_params: RewardsAccountParams,
+	pot: &AccountId,
+	boost: Currency::Balance,
+) where
+	Currency: Mutate<AccountId>,
+	AccountId: Decode + Encode + Eq,
+{
+	let rewards_account =
+		PayRewardFromAccount::<Currency, AccountId>::rewards_account(rewards_account_params);
+	if let Err(e) = Currency::transfer(pot, &rewards_account, boost, Preservation::Preserve) {
+		log::warn!(
+			target: crate::LOG_TARGET,
+			"Failed to boost rewards of relayers serving {:?} from the pot: {:?}",
+			rewards_account_params,
+			e,
+		);
+	}
+}
+
 // Update rewards to given relayers, optionally rewarding confirmation relayer.
 fn register_relayers_rewards<T: Config>(
 	confirmation_relayer: &T::AccountId,
@@ -131,6 +191,25 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn stalled_lane_rewards_are_boosted_from_pot() {
+		run_test(|| {
+			let pot = 42;
+			let rewards_account =
+				TestPaymentProcedure::rewards_account(TEST_REWARDS_ACCOUNT_PARAMS);
+			Balances::mint_into(&pot, 150).unwrap();
+
+			boost_lane_rewards::<Balances, AccountId>(TEST_REWARDS_ACCOUNT_PARAMS, &pot, 100);
+			assert_eq!(Balances::free_balance(pot), 50);
+			assert_eq!(Balances::free_balance(rewards_account), 100);
+
+			// the pot is exhausted => nothing is transferred
+			boost_lane_rewards::<Balances, AccountId>(TEST_REWARDS_ACCOUNT_PARAMS, &pot, 100);
+			assert_eq!(Balances::free_balance(pot), 50);
+			assert_eq!(Balances::free_balance(rewards_account), 100);
+		});
+	}
+
 	#[test]
 	fn confirmation_relayer_is_not_rewarded_if_it_has_not_delivered_any_messages() {
 		run_test(|| {
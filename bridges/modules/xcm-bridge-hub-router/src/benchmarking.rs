@@ -18,12 +18,12 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::{Bridge, Call};
+use crate::{Bridge, Call, FeeFactorParameters, FeeFactorParams, MAX_FEE_FACTOR_THRESHOLDS};
 
 use bp_xcm_bridge_hub_router::{BridgeState, MINIMAL_DELIVERY_FEE_FACTOR};
 use frame_benchmarking::{benchmarks_instance_pallet, BenchmarkError};
 use frame_support::traits::{EnsureOrigin, Get, Hooks, UnfilteredDispatchable};
-use sp_runtime::traits::Zero;
+use sp_runtime::{traits::Zero, FixedU128};
 use xcm::prelude::*;
 
 /// Pallet we're benchmarking here.
@@ -80,6 +80,23 @@ benchmarks_instance_pallet! {
 		assert!(Bridge::<T, I>::get().is_congested);
 	}
 
+	set_fee_factor_params {
+		let origin: T::RuntimeOrigin = T::AdminOrigin::try_successful_origin().expect("expected valid AdminOrigin");
+		let params = FeeFactorParams {
+			thresholds: (0..MAX_FEE_FACTOR_THRESHOLDS)
+				.map(|i| FixedU128::from_u32(2 + i))
+				.collect::<sp_std::vec::Vec<_>>()
+				.try_into()
+				.expect("number of thresholds is within bounds"),
+			..Default::default()
+		};
+
+		let call = Call::<T, I>::set_fee_factor_params { params: params.clone() };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(FeeFactorParameters::<T, I>::get(), params);
+	}
+
 	send_message {
 		let dest = T::ensure_bridged_target_destination()?;
 		let xcm = sp_std::vec![].into();
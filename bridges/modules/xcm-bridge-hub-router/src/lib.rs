@@ -33,10 +33,11 @@
 use bp_xcm_bridge_hub_router::{
 	BridgeState, XcmChannelStatusProvider, MINIMAL_DELIVERY_FEE_FACTOR,
 };
-use codec::Encode;
-use frame_support::traits::Get;
-use sp_core::H256;
-use sp_runtime::{FixedPointNumber, FixedU128, Saturating};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::Get, BoundedVec};
+use scale_info::TypeInfo;
+use sp_core::{ConstU32, H256};
+use sp_runtime::{FixedPointNumber, FixedU128, RuntimeDebug, Saturating};
 use xcm::prelude::*;
 use xcm_builder::{ExporterFor, SovereignPaidRemoteExporter};
 
@@ -54,6 +55,49 @@ const EXPONENTIAL_FEE_BASE: FixedU128 = FixedU128::from_rational(105, 100); // 1
 /// The factor that is used to increase current message fee factor for every sent kilobyte.
 const MESSAGE_SIZE_FEE_BASE: FixedU128 = FixedU128::from_rational(1, 1000); // 0.001
 
+/// Minimal value of the `FeeFactorParams::exponential_fee_base`.
+pub const MIN_EXPONENTIAL_FEE_BASE: FixedU128 = FixedU128::from_rational(1001, 1000); // 1.001
+/// Maximal value of the `FeeFactorParams::exponential_fee_base`.
+pub const MAX_EXPONENTIAL_FEE_BASE: FixedU128 = FixedU128::from_rational(2, 1); // 2
+/// Maximal value of the `FeeFactorParams::message_size_fee_base`.
+pub const MAX_MESSAGE_SIZE_FEE_BASE: FixedU128 = FixedU128::from_rational(1, 10); // 0.1
+/// Maximal number of `FeeFactorParams::thresholds`.
+pub const MAX_FEE_FACTOR_THRESHOLDS: u32 = 8;
+
+/// Parameters of the delivery fee factor growth and decay.
+#[derive(Clone, Decode, Encode, Eq, PartialEq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct FeeFactorParams {
+	/// The fee factor is multiplied by this value for every message that is sent over the
+	/// congested bridge. It is divided by this value at every block when the bridge is not
+	/// congested.
+	pub exponential_fee_base: FixedU128,
+	/// This value is added to the `exponential_fee_base` for every kilobyte of the sent message.
+	pub message_size_fee_base: FixedU128,
+	/// The `DeliveryFeeFactorThresholdCrossed` event is emitted when the fee factor crosses any
+	/// of these values.
+	pub thresholds: BoundedVec<FixedU128, ConstU32<MAX_FEE_FACTOR_THRESHOLDS>>,
+}
+
+impl Default for FeeFactorParams {
+	fn default() -> Self {
+		FeeFactorParams {
+			exponential_fee_base: EXPONENTIAL_FEE_BASE,
+			message_size_fee_base: MESSAGE_SIZE_FEE_BASE,
+			thresholds: BoundedVec::new(),
+		}
+	}
+}
+
+impl FeeFactorParams {
+	/// Returns true if parameters are within sane bounds.
+	pub fn is_valid(&self) -> bool {
+		self.exponential_fee_base >= MIN_EXPONENTIAL_FEE_BASE &&
+			self.exponential_fee_base <= MAX_EXPONENTIAL_FEE_BASE &&
+			self.message_size_fee_base <= MAX_MESSAGE_SIZE_FEE_BASE &&
+			self.thresholds.iter().all(|threshold| *threshold > MINIMAL_DELIVERY_FEE_FACTOR)
+	}
+}
+
 /// Maximal size of the XCM message that may be sent over bridge.
 ///
 /// This should be less than the maximal size, allowed by the messages pallet, because
@@ -76,6 +120,9 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// Benchmarks results from runtime we're plugged into.
 		type WeightInfo: WeightInfo;
 
@@ -94,6 +141,8 @@ pub mod pallet {
 
 		/// Origin of the sibling bridge hub that is allowed to report bridge status.
 		type BridgeHubOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin that is allowed to change parameters of the delivery fee factor.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// Actual message sender (`HRMP` or `DMP`) to the sibling bridge hub location.
 		type ToBridgeHubSender: SendXcm;
 		/// Underlying channel with the sibling bridge hub. It must match the channel, used
@@ -134,9 +183,11 @@ pub mod pallet {
 				return T::WeightInfo::on_initialize_when_congested()
 			}
 
+			let params = Self::fee_factor_params();
 			let previous_factor = bridge.delivery_fee_factor;
-			bridge.delivery_fee_factor =
-				MINIMAL_DELIVERY_FEE_FACTOR.max(bridge.delivery_fee_factor / EXPONENTIAL_FEE_BASE);
+			bridge.delivery_fee_factor = MINIMAL_DELIVERY_FEE_FACTOR
+				.max(bridge.delivery_fee_factor / params.exponential_fee_base);
+			Self::on_fee_factor_changed(&params, previous_factor, bridge.delivery_fee_factor);
 			log::info!(
 				target: LOG_TARGET,
 				"Bridge queue is uncongested. Decreased fee factor from {} to {}",
@@ -175,6 +226,44 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Change parameters of the delivery fee factor growth and decay.
+		///
+		/// May only be called by the `AdminOrigin`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_fee_factor_params())]
+		pub fn set_fee_factor_params(
+			origin: OriginFor<T>,
+			params: FeeFactorParams,
+		) -> DispatchResult {
+			let _ = T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(params.is_valid(), Error::<T, I>::InvalidFeeFactorParams);
+
+			log::info!(target: LOG_TARGET, "Updated fee factor parameters: {:?}", params);
+
+			FeeFactorParameters::<T, I>::put(&params);
+			Self::deposit_event(Event::FeeFactorParamsUpdated { params });
+			Ok(())
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// Parameters of the delivery fee factor have been changed.
+		FeeFactorParamsUpdated { params: FeeFactorParams },
+		/// Delivery fee factor has crossed the configured threshold.
+		DeliveryFeeFactorThresholdCrossed {
+			threshold: FixedU128,
+			previous_factor: FixedU128,
+			new_factor: FixedU128,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// Fee factor parameters are out of bounds.
+		InvalidFeeFactorParams,
 	}
 
 	/// Bridge that we are using.
@@ -188,6 +277,12 @@ pub mod pallet {
 	#[pallet::getter(fn bridge)]
 	pub type Bridge<T: Config<I>, I: 'static = ()> = StorageValue<_, BridgeState, ValueQuery>;
 
+	/// Parameters of the delivery fee factor growth and decay.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_factor_params)]
+	pub type FeeFactorParameters<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, FeeFactorParams, ValueQuery>;
+
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Called when new message is sent (queued to local outbound XCM queue) over the bridge.
 		pub(crate) fn on_message_sent_to_bridge(message_size: u32) {
@@ -202,12 +297,14 @@ pub mod pallet {
 				}
 
 				// ok - we need to increase the fee factor, let's do that
+				let params = Self::fee_factor_params();
 				let message_size_factor = FixedU128::from_u32(message_size.saturating_div(1024))
-					.saturating_mul(MESSAGE_SIZE_FEE_BASE);
-				let total_factor = EXPONENTIAL_FEE_BASE.saturating_add(message_size_factor);
+					.saturating_mul(params.message_size_fee_base);
+				let total_factor = params.exponential_fee_base.saturating_add(message_size_factor);
 				let previous_factor = bridge.delivery_fee_factor;
 				bridge.delivery_fee_factor =
 					bridge.delivery_fee_factor.saturating_mul(total_factor);
+				Self::on_fee_factor_changed(&params, previous_factor, bridge.delivery_fee_factor);
 
 				log::info!(
 					target: LOG_TARGET,
@@ -219,6 +316,23 @@ pub mod pallet {
 				Ok(())
 			});
 		}
+
+		/// Emit events for all thresholds that the fee factor has crossed.
+		fn on_fee_factor_changed(
+			params: &FeeFactorParams,
+			previous_factor: FixedU128,
+			new_factor: FixedU128,
+		) {
+			for threshold in params.thresholds.iter().copied() {
+				if (previous_factor < threshold) != (new_factor < threshold) {
+					Self::deposit_event(Event::DeliveryFeeFactorThresholdCrossed {
+						threshold,
+						previous_factor,
+						new_factor,
+					});
+				}
+			}
+		}
 	}
 }
 
@@ -373,11 +487,12 @@ impl<T: Config<I>, I: 'static> SendXcm for Pallet<T, I> {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use frame_support::assert_ok;
+	use frame_support::{assert_noop, assert_ok};
 	use mock::*;
 
 	use frame_support::traits::Hooks;
-	use sp_runtime::traits::One;
+	use frame_system::{EventRecord, Phase};
+	use sp_runtime::{traits::One, DispatchError};
 
 	fn congested_bridge(delivery_fee_factor: FixedU128) -> BridgeState {
 		BridgeState { is_congested: true, delivery_fee_factor }
@@ -441,6 +556,119 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn set_fee_factor_params_works() {
+		run_test(|| {
+			System::set_block_number(1);
+			let params = FeeFactorParams {
+				exponential_fee_base: FixedU128::from_rational(110, 100),
+				message_size_fee_base: FixedU128::from_rational(1, 100),
+				thresholds: vec![FixedU128::from_u32(2)].try_into().unwrap(),
+			};
+
+			assert_noop!(
+				XcmBridgeHubRouter::set_fee_factor_params(RuntimeOrigin::signed(1), params.clone()),
+				DispatchError::BadOrigin,
+			);
+			assert_noop!(
+				XcmBridgeHubRouter::set_fee_factor_params(
+					RuntimeOrigin::root(),
+					FeeFactorParams {
+						exponential_fee_base: FixedU128::from_u32(1),
+						..params.clone()
+					},
+				),
+				Error::<TestRuntime, ()>::InvalidFeeFactorParams,
+			);
+			assert_noop!(
+				XcmBridgeHubRouter::set_fee_factor_params(
+					RuntimeOrigin::root(),
+					FeeFactorParams {
+						thresholds: vec![MINIMAL_DELIVERY_FEE_FACTOR].try_into().unwrap(),
+						..params.clone()
+					},
+				),
+				Error::<TestRuntime, ()>::InvalidFeeFactorParams,
+			);
+
+			assert_ok!(XcmBridgeHubRouter::set_fee_factor_params(
+				RuntimeOrigin::root(),
+				params.clone()
+			));
+			assert_eq!(XcmBridgeHubRouter::fee_factor_params(), params);
+			assert_eq!(
+				System::events().last(),
+				Some(&EventRecord {
+					phase: Phase::Initialization,
+					event: RuntimeEvent::XcmBridgeHubRouter(Event::FeeFactorParamsUpdated {
+						params
+					}),
+					topics: vec![],
+				}),
+			);
+		})
+	}
+
+	#[test]
+	fn fee_factor_is_decreased_using_configured_base() {
+		run_test(|| {
+			FeeFactorParameters::<TestRuntime, ()>::put(FeeFactorParams {
+				exponential_fee_base: FixedU128::from_u32(2),
+				..Default::default()
+			});
+			Bridge::<TestRuntime, ()>::put(uncongested_bridge(FixedU128::from_u32(8)));
+
+			XcmBridgeHubRouter::on_initialize(One::one());
+			assert_eq!(XcmBridgeHubRouter::bridge(), uncongested_bridge(FixedU128::from_u32(4)));
+		})
+	}
+
+	#[test]
+	fn event_is_emitted_when_fee_factor_crosses_threshold() {
+		run_test(|| {
+			System::set_block_number(1);
+			let threshold = FixedU128::from_rational(110, 100);
+			FeeFactorParameters::<TestRuntime, ()>::put(FeeFactorParams {
+				thresholds: vec![threshold].try_into().unwrap(),
+				..Default::default()
+			});
+			Bridge::<TestRuntime, ()>::put(congested_bridge(MINIMAL_DELIVERY_FEE_FACTOR));
+			let dest = MultiLocation::new(
+				2,
+				X2(GlobalConsensus(BridgedNetworkId::get()), Parachain(1000)),
+			);
+
+			// 1.0 -> 1.05: below threshold
+			assert_ok!(send_xcm::<XcmBridgeHubRouter>(dest, vec![ClearOrigin].into()).map(drop));
+			assert!(System::events().is_empty());
+
+			// 1.05 -> 1.1025: threshold is crossed
+			assert_ok!(send_xcm::<XcmBridgeHubRouter>(dest, vec![ClearOrigin].into()).map(drop));
+			let raised_factor = XcmBridgeHubRouter::bridge().delivery_fee_factor;
+			assert_eq!(
+				System::events().last().map(|record| record.event.clone()),
+				Some(RuntimeEvent::XcmBridgeHubRouter(Event::DeliveryFeeFactorThresholdCrossed {
+					threshold,
+					previous_factor: FixedU128::from_rational(105, 100),
+					new_factor: raised_factor,
+				})),
+			);
+
+			// 1.1025 -> 1.05: threshold is crossed back
+			Bridge::<TestRuntime, ()>::put(uncongested_bridge(raised_factor));
+			XcmBridgeHubRouter::on_initialize(One::one());
+			assert_eq!(
+				System::events().last().map(|record| record.event.clone()),
+				Some(RuntimeEvent::XcmBridgeHubRouter(Event::DeliveryFeeFactorThresholdCrossed {
+					threshold,
+					previous_factor: raised_factor,
+					new_factor: XcmBridgeHubRouter::bridge().delivery_fee_factor,
+				})),
+			);
+			assert_eq!(System::events().len(), 2);
+		})
+	}
+
 	#[test]
 	fn not_applicable_if_destination_is_within_other_network() {
 		run_test(|| {
@@ -54,6 +54,7 @@ pub trait WeightInfo {
 	fn on_initialize_when_congested() -> Weight;
 	fn report_bridge_status() -> Weight;
 	fn send_message() -> Weight;
+	fn set_fee_factor_params() -> Weight;
 }
 
 /// Weights for `pallet_xcm_bridge_hub_router` that are generated using one of the Bridge testnets.
@@ -131,6 +132,14 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `XcmBridgeHubRouter::FeeFactorParameters` (r:0 w:1)
+	///
+	/// Proof: `XcmBridgeHubRouter::FeeFactorParameters` (`max_values`: Some(1), `max_size`:
+	/// Some(162), added: 657, mode: `MaxEncodedLen`)
+	fn set_fee_factor_params() -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(8_530_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -205,4 +214,12 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `XcmBridgeHubRouter::FeeFactorParameters` (r:0 w:1)
+	///
+	/// Proof: `XcmBridgeHubRouter::FeeFactorParameters` (`max_values`: Some(1), `max_size`:
+	/// Some(162), added: 657, mode: `MaxEncodedLen`)
+	fn set_fee_factor_params() -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(8_530_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
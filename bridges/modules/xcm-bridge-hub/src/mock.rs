@@ -32,7 +32,11 @@ use bridge_runtime_common::{
 	messages_xcm_extension::{SenderAndLane, XcmBlobHauler},
 };
 use codec::Encode;
-use frame_support::{derive_impl, parameter_types, traits::ConstU32, weights::RuntimeDbWeight};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64},
+	weights::RuntimeDbWeight,
+};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header as SubstrateHeader,
@@ -113,6 +117,8 @@ impl pallet_bridge_messages::Config for TestRuntime {
 	type LaneMessageVerifier = TestLaneMessageVerifier;
 	type DeliveryConfirmationPayments = ();
 	type OnMessagesDelivered = ();
+	type LaneStallThreshold = ConstU64<10>;
+	type OnLaneStalled = ();
 	type SourceHeaderChain = SourceHeaderChainAdapter<OnThisChainBridge>;
 	type MessageDispatch = TestMessageDispatch;
 }
@@ -151,6 +157,14 @@ impl pallet_bridge_messages::WeightInfo for TestMessagesWeights {
 	fn receive_single_message_proof_with_dispatch(_: u32) -> Weight {
 		Weight::from_parts(1, 0)
 	}
+
+	fn receive_single_message_proof_without_dispatch(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn mark_lane_stalled() -> Weight {
+		Weight::zero()
+	}
 }
 
 impl pallet_bridge_messages::WeightInfoExt for TestMessagesWeights {
@@ -129,6 +129,20 @@ impl OnMessagesDelivered for () {
 	fn on_messages_delivered(_lane: LaneId, _enqueued_messages: MessageNonce) {}
 }
 
+/// Callback that is called at the source chain (bridge hub) when someone proves that the outbound
+/// lane is stalled, i.e. there are queued messages, but no deliveries have been confirmed for a
+/// while.
+pub trait OnLaneStalled {
+	/// The outbound lane has been marked as stalled.
+	///
+	/// The only argument of the function is the number of yet undelivered messages
+	fn on_lane_stalled(lane: LaneId, enqueued_messages: MessageNonce);
+}
+
+impl OnLaneStalled for () {
+	fn on_lane_stalled(_lane: LaneId, _enqueued_messages: MessageNonce) {}
+}
+
 /// Send message artifacts.
 #[derive(Eq, RuntimeDebug, PartialEq)]
 pub struct SendMessageArtifacts {
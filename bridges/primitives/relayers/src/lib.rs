@@ -82,7 +82,8 @@ pub trait PaymentProcedure<Relayer, Reward> {
 	/// Error that may be returned by the procedure.
 	type Error: Debug;
 
-	/// Pay reward to the relayer from the account with provided params.
+	/// Pay reward to the relayer (or to the beneficiary, chosen by the relayer) from the account
+	/// with provided params.
 	fn pay_reward(
 		relayer: &Relayer,
 		rewards_account_params: RewardsAccountParams,
@@ -277,9 +277,12 @@ pub type TransactionEraOf<C> = crate::TransactionEra<BlockNumberOf<C>, HashOf<C>
 /// This includes:
 /// - chain-specific bridge runtime APIs:
 ///     - `<ThisChain>FinalityApi`
+///     - `<ThisChain>HeaderAncestryApi` (for chains with GRANDPA finality)
 /// - constants that are stringified names of runtime API methods:
 ///     - `BEST_FINALIZED_<THIS_CHAIN>_HEADER_METHOD`
 ///     - `<THIS_CHAIN>_ACCEPTED_<CONSENSUS>_FINALITY_PROOFS_METHOD`
+///     - `<THIS_CHAIN>_IMPORTED_HEADERS_METHOD` (for chains with GRANDPA finality)
+///     - `IS_KNOWN_<THIS_CHAIN>_HEADER_METHOD` (for chains with GRANDPA finality)
 /// The name of the chain has to be specified in snake case (e.g. `bridge_hub_polkadot`).
 #[macro_export]
 macro_rules! decl_bridge_finality_runtime_apis {
@@ -322,6 +325,40 @@ macro_rules! decl_bridge_finality_runtime_apis {
 	};
 	($chain: ident, grandpa) => {
 		decl_bridge_finality_runtime_apis!($chain, grandpa => bp_header_chain::StoredHeaderGrandpaInfo<Header>);
+
+		bp_runtime::paste::item! {
+			mod [<$chain _header_ancestry_api>] {
+				use super::*;
+
+				/// Name of the `<ThisChain>HeaderAncestryApi::imported_headers` runtime method.
+				pub const [<$chain:upper _IMPORTED_HEADERS_METHOD>]: &str =
+					stringify!([<$chain:camel HeaderAncestryApi_imported_headers>]);
+
+				/// Name of the `<ThisChain>HeaderAncestryApi::is_known_header` runtime method.
+				pub const [<IS_KNOWN_ $chain:upper _HEADER_METHOD>]: &str =
+					stringify!([<$chain:camel HeaderAncestryApi_is_known_header>]);
+
+				sp_api::decl_runtime_apis! {
+					/// API for querying the chain headers, imported by the bridge GRANDPA module.
+					///
+					/// The best finalized header is available through the `<ThisChain>FinalityApi`.
+					///
+					/// This API is implemented by runtimes that are receiving messages from this chain, not by this
+					/// chain's runtime itself.
+					pub trait [<$chain:camel HeaderAncestryApi>] {
+						/// Returns number and hash of all headers that are currently stored by the bridge module,
+						/// ordered by their import time (oldest first).
+						fn imported_headers() -> sp_std::vec::Vec<bp_runtime::HeaderId<Hash, BlockNumber>>;
+
+						/// Returns true if header with given hash has been imported by the bridge module and has
+						/// not been pruned yet. All imported headers are finalized.
+						fn is_known_header(hash: Hash) -> bool;
+					}
+				}
+			}
+
+			pub use [<$chain _header_ancestry_api>]::*;
+		}
 	};
 }
 
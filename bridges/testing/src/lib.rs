@@ -0,0 +1,302 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Helpers for the [zombienet-sdk](https://github.com/paritytech/zombienet-sdk) based tests of the
+//! local Rococo <> Westend bridge.
+//!
+//! The networks are spawned with the native provider, so the `polkadot`, `polkadot-parachain`
+//! and `substrate-relay` binaries must be built locally. The chains are initialized with the
+//! `cumulus/scripts/bridges_rococo_westend.sh` script, which is also used to start the relayer.
+
+use bp_messages::LaneId;
+use bp_relayers::{RelayerRewardsKeyProvider, RewardsAccountParams};
+use bp_runtime::{HeaderId, StorageDoubleMapKeyProvider};
+use codec::{Decode, Encode};
+use polkadot_parachain_primitives::primitives::{HrmpChannelId, Id as ParaId};
+use sp_core::{
+	hashing::{blake2_128, twox_128, twox_64},
+	storage::StorageKey,
+	Bytes, H256,
+};
+use sp_runtime::AccountId32;
+use std::{
+	future::Future,
+	path::{Path, PathBuf},
+	time::Duration,
+};
+use substrate_rpc_client::{ws_client, StateApi, WsClient};
+use xcm::v3::{Junction, Junctions, MultiLocation, NetworkId};
+use zombienet_sdk::{LocalFileSystem, Network, NetworkConfig, NetworkConfigExt};
+
+pub use zombienet_sdk;
+
+/// Result of the test helpers.
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+const LOG_TARGET: &str = "bridges-testing";
+
+/// Network config of the Rococo relay chain with its bridge hub and asset hub.
+pub const ROCOCO_NETWORK: &str = "bridge_hub_rococo_local_network.toml";
+/// Network config of the Westend relay chain with its bridge hub and asset hub.
+pub const WESTEND_NETWORK: &str = "bridge_hub_westend_local_network.toml";
+
+/// Id of the lane used by the asset hubs, as passed to the bridge script.
+pub const LANE_ID: &str = "00000002";
+/// Id of the lane used by the asset hubs.
+pub const LANE: LaneId = LaneId([0, 0, 0, 2]);
+
+/// How often the chain state is checked while waiting for some condition.
+const POLL_INTERVAL: Duration = Duration::from_secs(12);
+
+/// The environment variables used by the network configs and the bridge script, with the default
+/// value of each, relative to the repository root.
+const BINARY_PATHS: &[(&str, &str)] = &[
+	("POLKADOT_BINARY_PATH", "target/release/polkadot"),
+	("POLKADOT_PARACHAIN_BINARY_PATH", "target/release/polkadot-parachain"),
+	("POLKADOT_PARACHAIN_BINARY_PATH_FOR_ASSET_HUB_ROCOCO", "target/release/polkadot-parachain"),
+	("POLKADOT_PARACHAIN_BINARY_PATH_FOR_ASSET_HUB_WESTEND", "target/release/polkadot-parachain"),
+];
+
+/// Returns the root folder of the `polkadot-sdk` repository.
+pub fn polkadot_sdk_folder() -> PathBuf {
+	Path::new(env!("CARGO_MANIFEST_DIR"))
+		.join("../..")
+		.canonicalize()
+		.expect("the crate lives in the `polkadot-sdk` repository; qed")
+}
+
+/// Sets the environment variables expected by the network configs and the bridge script, unless
+/// they are already set.
+pub fn init_environment() {
+	let _ = sp_tracing::try_init_simple();
+
+	let root = polkadot_sdk_folder();
+	std::env::set_var("POLKADOT_SDK_FOLDER", &root);
+	for (name, default) in BINARY_PATHS {
+		if std::env::var_os(name).is_none() {
+			std::env::set_var(name, root.join(default));
+		}
+	}
+	std::env::set_var("LANE_ID", LANE_ID);
+}
+
+/// Replaces the `{{VARIABLE}}` placeholders of the network config with the values of the
+/// environment variables.
+fn substitute_env_variables(config: &str) -> Result<String> {
+	let mut result = String::with_capacity(config.len());
+	let mut rest = config;
+	while let Some(start) = rest.find("{{") {
+		let end = rest[start..]
+			.find("}}")
+			.ok_or_else(|| format!("unterminated placeholder in: {}", &rest[start..]))?;
+		let name = &rest[start + 2..start + end];
+		let value = std::env::var(name)
+			.map_err(|_| format!("environment variable `{}` is not set", name))?;
+
+		result.push_str(&rest[..start]);
+		result.push_str(&value);
+		rest = &rest[start + end + 2..];
+	}
+	result.push_str(rest);
+	Ok(result)
+}
+
+/// Spawns the network from one of the configs in `cumulus/zombienet/bridge-hubs`.
+pub async fn spawn_network(config_name: &str) -> Result<Network<LocalFileSystem>> {
+	let path = polkadot_sdk_folder().join("cumulus/zombienet/bridge-hubs").join(config_name);
+	let config = substitute_env_variables(&std::fs::read_to_string(&path)?)?;
+
+	let file = tempfile::Builder::new().suffix(".toml").tempfile()?;
+	std::fs::write(file.path(), config)?;
+	let config = NetworkConfig::load_from_toml(&file.path().to_string_lossy())?;
+
+	log::info!(target: LOG_TARGET, "Spawning network from {}", path.display());
+	Ok(config.spawn_native().await?)
+}
+
+/// Returns the path of the script that initializes the bridge and starts the relayer.
+fn bridge_script() -> PathBuf {
+	polkadot_sdk_folder().join("cumulus/scripts/bridges_rococo_westend.sh")
+}
+
+/// Runs the given command of the bridge script and waits until it completes.
+pub async fn run_bridge_script(command: &str) -> Result<()> {
+	log::info!(target: LOG_TARGET, "Running bridge script command: {}", command);
+
+	let status = tokio::process::Command::new(bridge_script())
+		.arg(command)
+		.current_dir(polkadot_sdk_folder().join("cumulus/scripts"))
+		.status()
+		.await?;
+	if !status.success() {
+		return Err(format!("bridge script command `{}` has failed: {}", command, status).into())
+	}
+
+	Ok(())
+}
+
+/// Initializes the bridge and starts the relayer between both bridge hubs.
+///
+/// The relayer is killed when the returned child is dropped.
+pub fn start_relayer() -> Result<tokio::process::Child> {
+	log::info!(target: LOG_TARGET, "Starting relayer");
+
+	Ok(tokio::process::Command::new(bridge_script())
+		.arg("run-relay")
+		.current_dir(polkadot_sdk_folder().join("cumulus/scripts"))
+		.kill_on_drop(true)
+		.spawn()?)
+}
+
+/// Calls `check` every [`POLL_INTERVAL`] until it returns some value or `timeout` elapses.
+pub async fn wait_for<T, F, Fut>(what: &str, timeout: Duration, mut check: F) -> Result<T>
+where
+	F: FnMut() -> Fut,
+	Fut: Future<Output = Result<Option<T>>>,
+{
+	log::info!(target: LOG_TARGET, "Waiting until {}", what);
+
+	let wait = async {
+		loop {
+			if let Some(value) = check().await? {
+				return Ok(value)
+			}
+			tokio::time::sleep(POLL_INTERVAL).await;
+		}
+	};
+
+	tokio::time::timeout(timeout, wait)
+		.await
+		.map_err(|_| format!("timeout while waiting until {}", what))?
+}
+
+/// Returns the key of a storage value or of a map entry, given the already hashed keys.
+fn storage_key(pallet: &str, item: &str, hashed_keys: &[Vec<u8>]) -> StorageKey {
+	let mut key = [twox_128(pallet.as_bytes()), twox_128(item.as_bytes())].concat();
+	for hashed_key in hashed_keys {
+		key.extend_from_slice(hashed_key);
+	}
+	StorageKey(key)
+}
+
+/// `Blake2_128Concat` hash of the encoded value.
+fn blake2_128_concat(value: &impl Encode) -> Vec<u8> {
+	let encoded = value.encode();
+	[&blake2_128(&encoded)[..], &encoded[..]].concat()
+}
+
+/// `Twox64Concat` hash of the encoded value.
+fn twox_64_concat(value: &impl Encode) -> Vec<u8> {
+	let encoded = value.encode();
+	[&twox_64(&encoded)[..], &encoded[..]].concat()
+}
+
+/// Location of the native token of the given bridged network.
+fn bridged_native_token(network: NetworkId) -> MultiLocation {
+	MultiLocation::new(2, Junctions::X1(Junction::GlobalConsensus(network)))
+}
+
+/// RPC client of a node, with typed accessors for the storage of the bridged chains.
+pub struct ChainClient {
+	client: WsClient,
+}
+
+impl ChainClient {
+	/// Connects to the node with the given name.
+	pub async fn connect(network: &Network<LocalFileSystem>, node_name: &str) -> Result<Self> {
+		let node = network.get_node(node_name)?;
+		Ok(ChainClient { client: ws_client(node.ws_uri()).await? })
+	}
+
+	/// Reads and decodes the value under the given key at the best block.
+	async fn storage<T: Decode>(&self, key: StorageKey) -> Result<Option<T>> {
+		let data = StateApi::<H256>::storage(&self.client, key, None).await?;
+		Ok(data.map(|data| T::decode(&mut &data.0[..])).transpose()?)
+	}
+
+	/// Returns the free balance of the account in the native token.
+	pub async fn free_balance(&self, account: &AccountId32) -> Result<u128> {
+		let key = storage_key("System", "Account", &[blake2_128_concat(account)]);
+		Ok(self
+			.storage::<frame_system::AccountInfo<u32, pallet_balances::AccountData<u128>>>(key)
+			.await?
+			.map(|info| info.data.free)
+			.unwrap_or_default())
+	}
+
+	/// Returns the balance of the account in the native token of the bridged network.
+	pub async fn foreign_asset_balance(
+		&self,
+		account: &AccountId32,
+		network: NetworkId,
+	) -> Result<u128> {
+		let key = storage_key(
+			"ForeignAssets",
+			"Account",
+			&[blake2_128_concat(&bridged_native_token(network)), blake2_128_concat(account)],
+		);
+		// The balance is the first field of the `AssetAccount`, so the rest can be ignored.
+		Ok(self.storage::<u128>(key).await?.unwrap_or_default())
+	}
+
+	/// Returns the reward of the relayer that is not claimed yet.
+	pub async fn relayer_reward(
+		&self,
+		relayer: &AccountId32,
+		params: RewardsAccountParams,
+	) -> Result<u128> {
+		let key = RelayerRewardsKeyProvider::<AccountId32, u128>::final_key(
+			"BridgeRelayers",
+			relayer,
+			&params,
+		);
+		Ok(self.storage::<u128>(key).await?.unwrap_or_default())
+	}
+
+	/// Returns the number of the best finalized header of the bridged relay chain, as known by
+	/// the bridge hub.
+	pub async fn best_finalized_bridged_header(&self, bridged_chain: &str) -> Result<Option<u32>> {
+		let method = format!("{}FinalityApi_best_finalized", bridged_chain);
+		let result = StateApi::<H256>::call(&self.client, method, Bytes(Vec::new()), None).await?;
+		let header_id = Option::<HeaderId<H256, u32>>::decode(&mut &result.0[..])?;
+		Ok(header_id.map(|id| id.0))
+	}
+
+	/// Returns whether the HRMP channel between the parachains is open at the relay chain.
+	pub async fn hrmp_channel_exists(&self, sender: u32, recipient: u32) -> Result<bool> {
+		let channel_id =
+			HrmpChannelId { sender: ParaId::from(sender), recipient: ParaId::from(recipient) };
+		let key = storage_key("Hrmp", "HrmpChannels", &[twox_64_concat(&channel_id)]);
+		let data = StateApi::<H256>::storage(&self.client, key, None).await?;
+		Ok(data.is_some())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn substitute_env_variables_works() {
+		std::env::set_var("BRIDGES_TESTING_BINARY", "/bin/polkadot");
+		assert_eq!(
+			substitute_env_variables("command = \"{{BRIDGES_TESTING_BINARY}}\"\nid = 1").unwrap(),
+			"command = \"/bin/polkadot\"\nid = 1",
+		);
+		assert!(substitute_env_variables("command = \"{{BRIDGES_TESTING_UNKNOWN}}\"").is_err());
+		assert!(substitute_env_variables("command = \"{{BRIDGES_TESTING_BINARY\"").is_err());
+	}
+}
@@ -0,0 +1,202 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! End-to-end test of the asset transfer between Rococo Asset Hub and Westend Asset Hub.
+//!
+//! The test spawns both networks and the relayer, so it is ignored by default. Run it with
+//! `cargo test -p bridges-testing -- --ignored` once the binaries are built, see the README.
+
+use bp_relayers::{RewardsAccountOwner, RewardsAccountParams};
+use bp_runtime::{ChainId, BRIDGE_HUB_ROCOCO_CHAIN_ID, BRIDGE_HUB_WESTEND_CHAIN_ID};
+use bridges_testing::{
+	init_environment, run_bridge_script, spawn_network, start_relayer, wait_for, ChainClient,
+	Result, LANE, ROCOCO_NETWORK, WESTEND_NETWORK,
+};
+use sp_keyring::AccountKeyring;
+use std::time::Duration;
+use xcm::v3::NetworkId;
+
+const ASSET_HUB_PARA_ID: u32 = 1000;
+const BRIDGE_HUB_ROCOCO_PARA_ID: u32 = 1013;
+const BRIDGE_HUB_WESTEND_PARA_ID: u32 = 1002;
+
+/// The clients of the chains of one side of the bridge.
+struct BridgeSide {
+	/// Name of the relay chain, as used by the runtime APIs of the other bridge hub.
+	name: &'static str,
+	/// Id of the network in XCM.
+	network: NetworkId,
+	/// Id of the bridge hub, as used by the relayers pallet of the other bridge hub.
+	bridge_hub_chain_id: ChainId,
+	/// Id of the bridge hub parachain.
+	bridge_hub_para_id: u32,
+	relay: ChainClient,
+	bridge_hub: ChainClient,
+	asset_hub: ChainClient,
+}
+
+impl BridgeSide {
+	/// Initializes the asset hub and the bridge hub with the bridge script.
+	async fn init(&self) -> Result<()> {
+		let suffix = format!("{}-local", self.name.to_lowercase());
+		run_bridge_script(&format!("init-asset-hub-{}", suffix)).await?;
+		run_bridge_script(&format!("init-bridge-hub-{}", suffix)).await?;
+
+		wait_for("HRMP channel is opened", Duration::from_secs(400), || async {
+			Ok(self
+				.relay
+				.hrmp_channel_exists(ASSET_HUB_PARA_ID, self.bridge_hub_para_id)
+				.await?
+				.then_some(()))
+		})
+		.await
+	}
+
+	/// Sends the native token to `//Alice` at the asset hub of the other side.
+	async fn reserve_transfer(&self) -> Result<()> {
+		run_bridge_script(&format!(
+			"reserve-transfer-assets-from-asset-hub-{}-local",
+			self.name.to_lowercase()
+		))
+		.await
+	}
+
+	/// Sends the wrapped tokens of the other side back to `//Alice` at the other asset hub.
+	async fn withdraw_reserve(&self) -> Result<()> {
+		run_bridge_script(&format!(
+			"withdraw-reserve-assets-from-asset-hub-{}-local",
+			self.name.to_lowercase()
+		))
+		.await
+	}
+}
+
+/// Waits until the bridge hub of `side` knows a finalized header of `bridged`.
+async fn wait_for_bridged_header(side: &BridgeSide, bridged: &BridgeSide) -> Result<u32> {
+	wait_for(
+		&format!("{} headers are relayed to {}", bridged.name, side.name),
+		Duration::from_secs(400),
+		|| async { side.bridge_hub.best_finalized_bridged_header(bridged.name).await },
+	)
+	.await
+}
+
+/// Waits until `//Alice` at the asset hub of `side` holds the wrapped tokens of `bridged`.
+async fn wait_for_wrapped_tokens(side: &BridgeSide, bridged: &BridgeSide) -> Result<u128> {
+	let alice = AccountKeyring::Alice.to_account_id();
+	wait_for(
+		&format!("wrapped {} tokens are received at {}", bridged.name, side.name),
+		Duration::from_secs(600),
+		|| async {
+			let balance = side.asset_hub.foreign_asset_balance(&alice, bridged.network).await?;
+			Ok((balance > 0).then_some(balance))
+		},
+	)
+	.await
+}
+
+/// Waits until the relayer `//Charlie` is rewarded at the bridge hub of `side` from both lane
+/// accounts.
+async fn wait_for_relayer_rewards(side: &BridgeSide, bridged: &BridgeSide) -> Result<()> {
+	let charlie = AccountKeyring::Charlie.to_account_id();
+	for owner in [RewardsAccountOwner::ThisChain, RewardsAccountOwner::BridgedChain] {
+		let params = RewardsAccountParams::new(LANE, bridged.bridge_hub_chain_id, owner);
+		wait_for(
+			&format!("relayer is rewarded at {} by {:?}", side.name, owner),
+			Duration::from_secs(300),
+			|| async {
+				let reward = side.bridge_hub.relayer_reward(&charlie, params).await?;
+				Ok((reward > 0).then_some(()))
+			},
+		)
+		.await?;
+	}
+	Ok(())
+}
+
+/// Waits until the native balance of `//Alice` at the asset hub of `side` exceeds `initial`.
+async fn wait_for_native_tokens(side: &BridgeSide, initial: u128) -> Result<u128> {
+	let alice = AccountKeyring::Alice.to_account_id();
+	wait_for(
+		&format!("native tokens are returned to {}", side.name),
+		Duration::from_secs(600),
+		|| async {
+			let balance = side.asset_hub.free_balance(&alice).await?;
+			Ok((balance > initial).then_some(balance))
+		},
+	)
+	.await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[ignore]
+async fn asset_transfer_works() -> Result<()> {
+	init_environment();
+
+	let (rococo_network, westend_network) =
+		tokio::try_join!(spawn_network(ROCOCO_NETWORK), spawn_network(WESTEND_NETWORK))?;
+
+	let rococo = BridgeSide {
+		name: "Rococo",
+		network: NetworkId::Rococo,
+		bridge_hub_chain_id: BRIDGE_HUB_ROCOCO_CHAIN_ID,
+		bridge_hub_para_id: BRIDGE_HUB_ROCOCO_PARA_ID,
+		relay: ChainClient::connect(&rococo_network, "alice-rococo-validator").await?,
+		bridge_hub: ChainClient::connect(&rococo_network, "bridge-hub-rococo-collator1").await?,
+		asset_hub: ChainClient::connect(&rococo_network, "asset-hub-rococo-collator1").await?,
+	};
+	let westend = BridgeSide {
+		name: "Westend",
+		network: NetworkId::Westend,
+		bridge_hub_chain_id: BRIDGE_HUB_WESTEND_CHAIN_ID,
+		bridge_hub_para_id: BRIDGE_HUB_WESTEND_PARA_ID,
+		relay: ChainClient::connect(&westend_network, "alice-westend-validator").await?,
+		bridge_hub: ChainClient::connect(&westend_network, "bridge-hub-westend-collator1").await?,
+		asset_hub: ChainClient::connect(&westend_network, "asset-hub-westend-collator1").await?,
+	};
+
+	// initialize both sides and start relaying
+	tokio::try_join!(rococo.init(), westend.init())?;
+	let _relayer = start_relayer()?;
+	tokio::try_join!(
+		wait_for_bridged_header(&rococo, &westend),
+		wait_for_bridged_header(&westend, &rococo),
+	)?;
+
+	// send native tokens over the bridge in both directions
+	tokio::try_join!(rococo.reserve_transfer(), westend.reserve_transfer())?;
+	tokio::try_join!(
+		wait_for_wrapped_tokens(&rococo, &westend),
+		wait_for_wrapped_tokens(&westend, &rococo),
+	)?;
+	tokio::try_join!(
+		wait_for_relayer_rewards(&rococo, &westend),
+		wait_for_relayer_rewards(&westend, &rococo),
+	)?;
+
+	// send the wrapped tokens back
+	tokio::try_join!(rococo.withdraw_reserve(), westend.withdraw_reserve())?;
+	// the fees of the withdrawal are already paid, so the balance may only increase now
+	let alice = AccountKeyring::Alice.to_account_id();
+	let rococo_initial = rococo.asset_hub.free_balance(&alice).await?;
+	let westend_initial = westend.asset_hub.free_balance(&alice).await?;
+	tokio::try_join!(
+		wait_for_native_tokens(&rococo, rococo_initial),
+		wait_for_native_tokens(&westend, westend_initial),
+	)?;
+
+	Ok(())
+}
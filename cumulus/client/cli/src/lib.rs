@@ -46,11 +46,11 @@ pub struct PurgeChainCmd {
 	pub base: sc_cli::PurgeChainCmd,
 
 	/// Only delete the para chain database
-	#[arg(long, aliases = &["para"])]
+	#[arg(long, aliases = &["para", "parachain-only"])]
 	pub parachain: bool,
 
 	/// Only delete the relay chain database
-	#[arg(long, aliases = &["relay"])]
+	#[arg(long, aliases = &["relay", "relay-only"])]
 	pub relaychain: bool,
 }
 
@@ -85,7 +85,11 @@ impl PurgeChainCmd {
 			for db_path in &db_paths {
 				println!("{}", db_path.display());
 			}
-			print!("Are you sure to remove? [y/N]: ");
+			if self.base.keep_offchain_storage {
+				print!("Are you sure to remove the blocks and state? [y/N]: ");
+			} else {
+				print!("Are you sure to remove? [y/N]: ");
+			}
 			io::stdout().flush().expect("failed to flush stdout");
 
 			let mut input = String::new();
@@ -101,6 +105,18 @@ impl PurgeChainCmd {
 			}
 		}
 
+		if self.base.keep_offchain_storage {
+			for ((_, database), db_path) in databases.iter().zip(&db_paths) {
+				if !db_path.exists() {
+					eprintln!("{:?} did not exist.", &db_path);
+					continue
+				}
+				sc_client_db::purge_database(database, true)?;
+				println!("Blocks and state of {:?} removed, offchain storage kept.", &db_path);
+			}
+			return Ok(())
+		}
+
 		for db_path in &db_paths {
 			match fs::remove_dir_all(db_path) {
 				Ok(_) => {
@@ -150,22 +166,11 @@ impl ExportGenesisHeadCommand {
 		B: BlockT,
 		C: HeaderBackend<B> + 'static,
 	{
-		let genesis_hash = client.hash(Zero::zero())?.ok_or(sc_cli::Error::Client(
-			sp_blockchain::Error::Backend(
-				"Failed to lookup genesis block hash when exporting genesis head data.".into(),
-			),
-		))?;
-		let genesis_header = client.header(genesis_hash)?.ok_or(sc_cli::Error::Client(
-			sp_blockchain::Error::Backend(
-				"Failed to lookup genesis header by hash when exporting genesis head data.".into(),
-			),
-		))?;
-
-		let raw_header = genesis_header.encode();
+		let raw_header = extract_genesis_head::<B, _>(client)?;
 		let output_buf = if self.raw {
 			raw_header
 		} else {
-			format!("0x{:?}", HexDisplay::from(&genesis_header.encode())).into_bytes()
+			format!("0x{:?}", HexDisplay::from(&raw_header)).into_bytes()
 		};
 
 		if let Some(output) = &self.output {
@@ -227,6 +232,27 @@ impl ExportGenesisWasmCommand {
 	}
 }
 
+/// Extract the encoded genesis header, i.e. the genesis head data, from the given client.
+pub fn extract_genesis_head<B, C>(client: Arc<C>) -> sc_cli::Result<Vec<u8>>
+where
+	B: BlockT,
+	C: HeaderBackend<B> + 'static,
+{
+	let genesis_hash =
+		client
+			.hash(Zero::zero())?
+			.ok_or(sc_cli::Error::Client(sp_blockchain::Error::Backend(
+				"Failed to lookup genesis block hash when exporting genesis head data.".into(),
+			)))?;
+	let genesis_header = client.header(genesis_hash)?.ok_or(sc_cli::Error::Client(
+		sp_blockchain::Error::Backend(
+			"Failed to lookup genesis header by hash when exporting genesis head data.".into(),
+		),
+	))?;
+
+	Ok(genesis_header.encode())
+}
+
 /// Extract the genesis code from a given ChainSpec.
 pub fn extract_genesis_wasm(chain_spec: &dyn ChainSpec) -> sc_cli::Result<Vec<u8>> {
 	let mut storage = chain_spec.build_storage()?;
@@ -249,6 +275,101 @@ impl sc_cli::CliConfiguration for ExportGenesisWasmCommand {
 	}
 }
 
+/// Encoding of the artifacts written by [`ExportGenesisArtifactsCommand`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum GenesisArtifactsFormat {
+	/// One `0x` prefixed hex file per artifact.
+	Hex,
+	/// One binary file per artifact.
+	Binary,
+	/// A single JSON manifest containing both artifacts in hex and their hashes.
+	Json,
+}
+
+/// Command for exporting the genesis head data and the validation code of the parachain, which
+/// are needed to register the parachain on the relay chain.
+#[derive(Debug, clap::Parser)]
+pub struct ExportGenesisArtifactsCommand {
+	/// Directory to write the artifacts to.
+	///
+	/// The artifacts are named `genesis-head` and `genesis-wasm` (with a `.hex` or `.bin`
+	/// extension), or `genesis-artifacts.json` for the JSON manifest. If unspecified, the JSON
+	/// manifest is written to stdout; the other formats require a directory.
+	#[arg(long, short)]
+	pub output_dir: Option<PathBuf>,
+
+	/// Encoding of the artifacts.
+	#[arg(long, value_enum, default_value_t = GenesisArtifactsFormat::Hex)]
+	pub format: GenesisArtifactsFormat,
+
+	#[allow(missing_docs)]
+	#[command(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+}
+
+impl ExportGenesisArtifactsCommand {
+	/// Run the export-genesis-artifacts command
+	pub fn run<B, C>(&self, chain_spec: &dyn ChainSpec, client: Arc<C>) -> sc_cli::Result<()>
+	where
+		B: BlockT,
+		C: HeaderBackend<B> + 'static,
+	{
+		let head = extract_genesis_head::<B, _>(client)?;
+		let wasm = extract_genesis_wasm(chain_spec)?;
+		let hex = |data: &[u8]| format!("0x{:?}", HexDisplay::from(&data));
+
+		let files = match self.format {
+			GenesisArtifactsFormat::Hex => vec![
+				("genesis-head.hex", hex(&head).into_bytes()),
+				("genesis-wasm.hex", hex(&wasm).into_bytes()),
+			],
+			GenesisArtifactsFormat::Binary =>
+				vec![("genesis-head.bin", head), ("genesis-wasm.bin", wasm)],
+			GenesisArtifactsFormat::Json => {
+				let manifest = serde_json::json!({
+					"chain": chain_spec.id(),
+					"genesis_head": hex(&head),
+					"genesis_head_hash": hex(&sp_core::hashing::blake2_256(&head)),
+					"validation_code": hex(&wasm),
+					"validation_code_hash": hex(&sp_core::hashing::blake2_256(&wasm)),
+				});
+				let manifest = serde_json::to_vec_pretty(&manifest)
+					.map_err(|e| format!("Failed to encode the genesis artifacts: {}", e))?;
+				vec![("genesis-artifacts.json", manifest)]
+			},
+		};
+
+		match &self.output_dir {
+			Some(dir) => {
+				fs::create_dir_all(dir)?;
+				for (name, content) in files {
+					fs::write(dir.join(name), content)?;
+				}
+			},
+			None if self.format == GenesisArtifactsFormat::Json =>
+				for (_, content) in files {
+					io::stdout().write_all(&content)?;
+				},
+			None => return Err("`--output-dir` is required unless `--format json` is used".into()),
+		}
+
+		Ok(())
+	}
+}
+
+impl sc_cli::CliConfiguration for ExportGenesisArtifactsCommand {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+
+	fn base_path(&self) -> sc_cli::Result<Option<BasePath>> {
+		// As we are just exporting the genesis artifacts a tmp database is enough.
+		//
+		// As otherwise we may "pollute" the global base path.
+		Ok(Some(BasePath::new_temp_dir()?))
+	}
+}
+
 fn validate_relay_chain_url(arg: &str) -> Result<Url, String> {
 	let url = Url::parse(arg).map_err(|e| e.to_string())?;
 
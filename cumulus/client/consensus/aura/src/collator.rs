@@ -29,7 +29,7 @@ use cumulus_client_collator::service::ServiceInterface as CollatorServiceInterfa
 use cumulus_client_consensus_common::{
 	self as consensus_common, ParachainBlockImportMarker, ParachainCandidate,
 };
-use cumulus_client_consensus_proposer::ProposerInterface;
+use cumulus_client_consensus_proposer::{CollationStats, ProposerInterface};
 use cumulus_primitives_core::{
 	relay_chain::Hash as PHash, DigestItem, ParachainBlockData, PersistedValidationData,
 };
@@ -55,7 +55,11 @@ use sp_runtime::{
 };
 use sp_state_machine::StorageChanges;
 use sp_timestamp::Timestamp;
-use std::{convert::TryFrom, error::Error, time::Duration};
+use std::{
+	convert::TryFrom,
+	error::Error,
+	time::{Duration, Instant},
+};
 
 /// Parameters for instantiating a [`Collator`].
 pub struct Params<BI, CIDP, RClient, Proposer, CS> {
@@ -213,11 +217,17 @@ where
 			.map_err(|e| Box::new(e) as Box<dyn Error + Send>)
 			.await?;
 
+		let build_start = Instant::now();
 		if let Some((collation, block_data)) = self.collator_service.build_collation(
 			parent_header,
 			post_hash,
 			ParachainCandidate { block, proof: proposal.proof },
 		) {
+			self.proposer.note_collation(CollationStats {
+				pov_size: block_data.encoded_size(),
+				compression_time: build_start.elapsed(),
+			});
+
 			tracing::info!(
 				target: crate::LOG_TARGET,
 				"PoV size {{ header: {}kb, extrinsics: {}kb, storage_proof: {}kb }}",
@@ -0,0 +1,211 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Adaptive authoring duration.
+//!
+//! Building a collation doesn't end with proposing the block: the PoV still has to be encoded
+//! and compressed before it can be submitted, and the time this takes grows with the size of the
+//! PoV. [`AdaptiveDuration`] keeps a rolling window of recent PoV sizes and compression times and
+//! shortens the authoring duration by the time expected to be spent on compressing the next PoV.
+
+use prometheus_endpoint::{register, Gauge, PrometheusError, Registry, U64};
+use std::{collections::VecDeque, time::Duration};
+
+/// The default number of recent collations taken into account.
+pub const DEFAULT_WINDOW: usize = 16;
+
+/// The authoring duration is never reduced below this fraction of the maximum duration.
+const MIN_DURATION_DIVISOR: u32 = 4;
+
+/// Statistics about a collation built on top of a proposal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollationStats {
+	/// The size of the PoV, in bytes.
+	pub pov_size: usize,
+	/// The time it took to encode and compress the PoV.
+	pub compression_time: Duration,
+}
+
+/// Chooses the authoring duration based on the statistics of recent collations.
+pub struct AdaptiveDuration {
+	window: usize,
+	samples: VecDeque<CollationStats>,
+	metrics: Option<Metrics>,
+}
+
+impl AdaptiveDuration {
+	/// Create a new instance taking the last `window` collations into account.
+	///
+	/// Metrics are registered in the given registry, if any.
+	pub fn new(window: usize, registry: Option<&Registry>) -> Self {
+		let metrics = registry.and_then(|registry| {
+			Metrics::register(registry)
+				.map_err(|err| {
+					tracing::warn!(
+						target: crate::LOG_TARGET,
+						"Failed to register adaptive authoring duration metrics: {}",
+						err,
+					)
+				})
+				.ok()
+		});
+
+		AdaptiveDuration {
+			window: window.max(1),
+			samples: VecDeque::with_capacity(window),
+			metrics,
+		}
+	}
+
+	/// Note the statistics of a newly built collation, evicting the oldest one if the window is
+	/// full.
+	pub fn note_collation(&mut self, stats: CollationStats) {
+		if self.samples.len() >= self.window {
+			self.samples.pop_front();
+		}
+
+		self.samples.push_back(stats);
+	}
+
+	/// The time expected to be spent on compressing the next PoV.
+	///
+	/// This assumes the next PoV is as large as the largest recent one and is compressed at the
+	/// average rate observed over the window.
+	pub fn predicted_compression_time(&self) -> Duration {
+		let total_size: u128 = self.samples.iter().map(|s| s.pov_size as u128).sum();
+		if total_size == 0 {
+			return Duration::ZERO
+		}
+
+		let total_nanos: u128 = self.samples.iter().map(|s| s.compression_time.as_nanos()).sum();
+		let largest = self.samples.iter().map(|s| s.pov_size as u128).max().unwrap_or_default();
+
+		let nanos = largest.saturating_mul(total_nanos) / total_size;
+		Duration::from_nanos(nanos.try_into().unwrap_or(u64::MAX))
+	}
+
+	/// Choose the authoring duration for the next proposal.
+	///
+	/// Without any recent collations this is `max_duration`. Otherwise `max_duration` is
+	/// reduced by the predicted compression time, but never below a quarter of it.
+	pub fn authoring_duration(&self, max_duration: Duration) -> Duration {
+		let predicted = self.predicted_compression_time();
+		let duration =
+			max_duration.saturating_sub(predicted).max(max_duration / MIN_DURATION_DIVISOR);
+
+		if let Some(metrics) = &self.metrics {
+			let largest = self.samples.iter().map(|s| s.pov_size).max().unwrap_or_default();
+
+			metrics.authoring_duration.set(duration.as_millis() as u64);
+			metrics.predicted_compression_time.set(predicted.as_millis() as u64);
+			metrics.recent_max_pov_size.set(largest as u64);
+		}
+
+		tracing::trace!(
+			target: crate::LOG_TARGET,
+			?max_duration,
+			?predicted,
+			?duration,
+			"Chose authoring duration",
+		);
+
+		duration
+	}
+}
+
+/// Adaptive authoring duration metrics.
+#[derive(Clone)]
+struct Metrics {
+	authoring_duration: Gauge<U64>,
+	predicted_compression_time: Gauge<U64>,
+	recent_max_pov_size: Gauge<U64>,
+}
+
+impl Metrics {
+	fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			authoring_duration: register(
+				Gauge::new(
+					"cumulus_proposer_authoring_duration",
+					"Authoring duration chosen for the last proposal, in milliseconds",
+				)?,
+				registry,
+			)?,
+			predicted_compression_time: register(
+				Gauge::new(
+					"cumulus_proposer_predicted_compression_time",
+					"Time expected to be spent on compressing the next PoV, in milliseconds",
+				)?,
+				registry,
+			)?,
+			recent_max_pov_size: register(
+				Gauge::new(
+					"cumulus_proposer_recent_max_pov_size",
+					"Largest PoV size among recent collations, in bytes",
+				)?,
+				registry,
+			)?,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn stats(pov_size: usize, compression_millis: u64) -> CollationStats {
+		CollationStats { pov_size, compression_time: Duration::from_millis(compression_millis) }
+	}
+
+	#[test]
+	fn falls_back_to_max_duration_without_samples() {
+		let adaptive = AdaptiveDuration::new(DEFAULT_WINDOW, None);
+
+		assert_eq!(adaptive.authoring_duration(Duration::from_secs(2)), Duration::from_secs(2));
+	}
+
+	#[test]
+	fn reduces_duration_by_predicted_compression_time() {
+		let mut adaptive = AdaptiveDuration::new(DEFAULT_WINDOW, None);
+		adaptive.note_collation(stats(1_000, 100));
+		adaptive.note_collation(stats(3_000, 300));
+
+		// 100 bytes/ms on average, the largest recent PoV is 3000 bytes.
+		assert_eq!(adaptive.predicted_compression_time(), Duration::from_millis(300));
+		assert_eq!(
+			adaptive.authoring_duration(Duration::from_secs(2)),
+			Duration::from_millis(1_700),
+		);
+	}
+
+	#[test]
+	fn never_goes_below_a_quarter_of_max_duration() {
+		let mut adaptive = AdaptiveDuration::new(DEFAULT_WINDOW, None);
+		adaptive.note_collation(stats(5_000_000, 5_000));
+
+		assert_eq!(adaptive.authoring_duration(Duration::from_secs(2)), Duration::from_millis(500));
+	}
+
+	#[test]
+	fn old_samples_are_evicted() {
+		let mut adaptive = AdaptiveDuration::new(2, None);
+		adaptive.note_collation(stats(10_000, 1_000));
+		adaptive.note_collation(stats(1_000, 10));
+		adaptive.note_collation(stats(1_000, 10));
+
+		assert_eq!(adaptive.predicted_compression_time(), Duration::from_millis(10));
+	}
+}
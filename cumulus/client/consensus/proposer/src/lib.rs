@@ -29,6 +29,13 @@ use sp_state_machine::StorageProof;
 
 use std::{fmt::Debug, time::Duration};
 
+pub use adaptive::{AdaptiveDuration, CollationStats, DEFAULT_WINDOW};
+pub use prometheus_endpoint::Registry;
+
+mod adaptive;
+
+const LOG_TARGET: &str = "cumulus-proposer";
+
 /// Errors that can occur when proposing a parachain block.
 #[derive(thiserror::Error, Debug)]
 #[error(transparent)]
@@ -77,18 +84,34 @@ pub trait ProposerInterface<Block: BlockT> {
 		max_duration: Duration,
 		block_size_limit: Option<usize>,
 	) -> Result<Proposal<Block, StorageProof>, Error>;
+
+	/// Note the statistics of a collation built from the last proposal.
+	///
+	/// Proposers may use this to adapt the duration of later proposals. Does nothing by default.
+	fn note_collation(&mut self, _stats: CollationStats) {}
 }
 
 /// A simple wrapper around a Substrate proposer for creating collations.
 pub struct Proposer<B, T> {
 	inner: T,
+	adaptive_duration: Option<AdaptiveDuration>,
 	_marker: std::marker::PhantomData<B>,
 }
 
 impl<B, T> Proposer<B, T> {
 	/// Create a new Cumulus [`Proposer`].
 	pub fn new(inner: T) -> Self {
-		Proposer { inner, _marker: std::marker::PhantomData }
+		Proposer { inner, adaptive_duration: None, _marker: std::marker::PhantomData }
+	}
+
+	/// Adapt the authoring duration to recent collations.
+	///
+	/// The `max_duration` passed to [`ProposerInterface::propose`] is then treated as an upper
+	/// bound which is reduced by the time expected to be spent on compressing the resulting PoV.
+	/// See [`AdaptiveDuration`] for details.
+	pub fn with_adaptive_duration(mut self, registry: Option<&Registry>) -> Self {
+		self.adaptive_duration = Some(AdaptiveDuration::new(DEFAULT_WINDOW, registry));
+		self
 	}
 }
 
@@ -124,9 +147,20 @@ where
 			)
 			.map_err(|e| Error::proposing(anyhow::Error::new(e)))?;
 
+		let max_duration = self
+			.adaptive_duration
+			.as_ref()
+			.map_or(max_duration, |adaptive| adaptive.authoring_duration(max_duration));
+
 		proposer
 			.propose(inherent_data, inherent_digests, max_duration, block_size_limit)
 			.await
 			.map_err(|e| Error::proposing(anyhow::Error::new(e)).into())
 	}
+
+	fn note_collation(&mut self, stats: CollationStats) {
+		if let Some(adaptive) = self.adaptive_duration.as_mut() {
+			adaptive.note_collation(stats);
+		}
+	}
 }
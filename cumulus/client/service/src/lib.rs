@@ -48,7 +48,7 @@ use sc_telemetry::{log, TelemetryWorkerHandle};
 use sc_utils::mpsc::TracingUnboundedSender;
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::{HeaderBackend, HeaderMetadata};
-use sp_core::{traits::SpawnNamed, Decode};
+use sp_core::{storage::StorageKey, traits::SpawnNamed, twox_128, Decode};
 use sp_runtime::traits::{Block as BlockT, BlockIdTo, Header};
 use std::{sync::Arc, time::Duration};
 
@@ -335,13 +335,22 @@ pub mod old_consensus {
 /// Prepare the parachain's node configuration
 ///
 /// This function will disable the default announcement of Substrate for the parachain in favor
-/// of the one of Cumulus.
+/// of the one of Cumulus. It also enables the background precompilation of the validation code
+/// of upgrades that are scheduled by the parachain system pallet.
 pub fn prepare_node_config(mut parachain_config: Configuration) -> Configuration {
 	parachain_config.announce_block = false;
+	parachain_config.pending_runtime_code_keys.push(pending_validation_code_key());
 
 	parachain_config
 }
 
+/// The storage key of the validation code of a scheduled upgrade.
+///
+/// Assumes that the parachain system pallet is called `ParachainSystem` in the runtime.
+fn pending_validation_code_key() -> StorageKey {
+	StorageKey([twox_128(b"ParachainSystem"), twox_128(b"PendingValidationCode")].concat())
+}
+
 /// Build a relay chain interface.
 /// Will return a minimal relay chain node with RPC
 /// client or an inprocess node, based on the [`CollatorOptions`] passed in.
@@ -539,7 +548,9 @@ where
 	RCInterface: RelayChainInterface + Send + 'static,
 {
 	let mut imported_blocks = relay_chain_interface.import_notification_stream().await?.fuse();
-	while imported_blocks.next().await.is_some() {
+	// The relay chain may already be synced (e.g. when using an external relay chain node), in
+	// which case the target can be determined without waiting for the next relay chain block.
+	loop {
 		let is_syncing = relay_chain_interface.is_major_syncing().await.map_err(|e| {
 			Box::<dyn std::error::Error + Send + Sync>::from(format!(
 				"Unable to determine sync status. {e}"
@@ -547,14 +558,14 @@ where
 		})?;
 
 		if !is_syncing {
-			let relay_chain_best_hash = relay_chain_interface
+			let relay_chain_finalized_hash = relay_chain_interface
 				.finalized_block_hash()
 				.await
 				.map_err(|e| Box::new(e) as Box<_>)?;
 
 			let validation_data = relay_chain_interface
 				.persisted_validation_data(
-					relay_chain_best_hash,
+					relay_chain_finalized_hash,
 					para_id,
 					OccupiedCoreAssumption::TimedOut,
 				)
@@ -566,14 +577,22 @@ where
 				.map_err(|e| format!("Failed to decode parachain head: {e}"))?;
 
 			log::info!(
-				"🎉 Received target parachain header #{} ({}) from the relay chain.",
+				"🎉 Received target parachain header #{} ({}) from the finalized relay chain block {}.",
 				finalized_header.number(),
-				finalized_header.hash()
+				finalized_header.hash(),
+				relay_chain_finalized_hash,
 			);
 			let _ = sender.send(finalized_header);
 			return Ok(())
 		}
+
+		if imported_blocks.next().await.is_none() {
+			break
+		}
 	}
 
 	Err("Stopping following imported blocks. Could not determine parachain target block".into())
 }
+
+#[cfg(test)]
+mod tests;
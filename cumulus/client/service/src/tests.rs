@@ -0,0 +1,238 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use async_trait::async_trait;
+use cumulus_primitives_core::relay_chain::BlockId;
+use cumulus_relay_chain_interface::{
+	CommittedCandidateReceipt, InboundDownwardMessage, InboundHrmpMessage, OverseerHandle, PHash,
+	PHeader, PersistedValidationData, SessionIndex, StorageValue, ValidatorId,
+};
+use futures::{executor::block_on, poll, Stream};
+use polkadot_primitives::{Block, HeadData, Header};
+use sp_core::Encode;
+use std::{
+	collections::BTreeMap,
+	pin::Pin,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Mutex,
+	},
+};
+
+/// Relay chain interface that only supports what is needed to determine the warp sync target.
+struct WarpSyncRelayChainInterface {
+	is_major_syncing: Arc<AtomicBool>,
+	imported_blocks: Mutex<Option<mpsc::UnboundedReceiver<PHeader>>>,
+	para_head: Header,
+}
+
+impl WarpSyncRelayChainInterface {
+	fn new(is_major_syncing: bool) -> (Self, Arc<AtomicBool>, mpsc::UnboundedSender<PHeader>) {
+		let is_major_syncing = Arc::new(AtomicBool::new(is_major_syncing));
+		let (sender, receiver) = mpsc::unbounded();
+		let interface = Self {
+			is_major_syncing: is_major_syncing.clone(),
+			imported_blocks: Mutex::new(Some(receiver)),
+			para_head: para_head(),
+		};
+
+		(interface, is_major_syncing, sender)
+	}
+}
+
+#[async_trait]
+impl RelayChainInterface for WarpSyncRelayChainInterface {
+	async fn get_storage_by_key(
+		&self,
+		_: PHash,
+		_: &[u8],
+	) -> RelayChainResult<Option<StorageValue>> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn validators(&self, _: PHash) -> RelayChainResult<Vec<ValidatorId>> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn best_block_hash(&self) -> RelayChainResult<PHash> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn header(&self, _: BlockId) -> RelayChainResult<Option<PHeader>> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn finalized_block_hash(&self) -> RelayChainResult<PHash> {
+		Ok(PHash::repeat_byte(1))
+	}
+
+	async fn retrieve_dmq_contents(
+		&self,
+		_: ParaId,
+		_: PHash,
+	) -> RelayChainResult<Vec<InboundDownwardMessage>> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn retrieve_all_inbound_hrmp_channel_contents(
+		&self,
+		_: ParaId,
+		_: PHash,
+	) -> RelayChainResult<BTreeMap<ParaId, Vec<InboundHrmpMessage>>> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn persisted_validation_data(
+		&self,
+		_: PHash,
+		_: ParaId,
+		_: OccupiedCoreAssumption,
+	) -> RelayChainResult<Option<PersistedValidationData>> {
+		Ok(Some(PersistedValidationData {
+			parent_head: HeadData(self.para_head.encode()),
+			..Default::default()
+		}))
+	}
+
+	async fn candidate_pending_availability(
+		&self,
+		_: PHash,
+		_: ParaId,
+	) -> RelayChainResult<Option<CommittedCandidateReceipt>> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn session_index_for_child(&self, _: PHash) -> RelayChainResult<SessionIndex> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn import_notification_stream(
+		&self,
+	) -> RelayChainResult<Pin<Box<dyn Stream<Item = PHeader> + Send>>> {
+		let imported_blocks = self.imported_blocks.lock().unwrap().take();
+		Ok(Box::pin(imported_blocks.expect("Import notification stream is only requested once")))
+	}
+
+	async fn new_best_notification_stream(
+		&self,
+	) -> RelayChainResult<Pin<Box<dyn Stream<Item = PHeader> + Send>>> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn wait_for_block(&self, _: PHash) -> RelayChainResult<()> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn finality_notification_stream(
+		&self,
+	) -> RelayChainResult<Pin<Box<dyn Stream<Item = PHeader> + Send>>> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn is_major_syncing(&self) -> RelayChainResult<bool> {
+		Ok(self.is_major_syncing.load(Ordering::SeqCst))
+	}
+
+	fn overseer_handle(&self) -> RelayChainResult<OverseerHandle> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn prove_read(
+		&self,
+		_: PHash,
+		_: &Vec<Vec<u8>>,
+	) -> RelayChainResult<sc_client_api::StorageProof> {
+		unimplemented!("Not needed for test")
+	}
+}
+
+fn para_head() -> Header {
+	Header {
+		parent_hash: PHash::repeat_byte(2),
+		number: 100,
+		state_root: PHash::repeat_byte(3),
+		extrinsics_root: PHash::repeat_byte(4),
+		digest: Default::default(),
+	}
+}
+
+fn relay_header(number: u32) -> PHeader {
+	PHeader {
+		parent_hash: Default::default(),
+		number,
+		state_root: Default::default(),
+		extrinsics_root: Default::default(),
+		digest: Default::default(),
+	}
+}
+
+#[test]
+fn warp_sync_target_is_found_without_waiting_for_relay_chain_block() {
+	let (relay_chain_interface, _, _imported_blocks) = WarpSyncRelayChainInterface::new(false);
+	let (sender, mut receiver) = oneshot::channel();
+
+	// no relay chain block is imported, so the target must be found right away
+	let result =
+		wait_for_finalized_para_head::<Block, _>(sender, ParaId::from(100), relay_chain_interface)
+			.now_or_never()
+			.expect("target is determined without waiting for a relay chain block");
+
+	assert!(result.is_ok());
+	assert_eq!(receiver.try_recv().unwrap(), Some(para_head()));
+}
+
+#[test]
+fn warp_sync_target_waits_for_relay_chain_to_sync() {
+	let (relay_chain_interface, is_major_syncing, imported_blocks) =
+		WarpSyncRelayChainInterface::new(true);
+	let (sender, mut receiver) = oneshot::channel();
+
+	block_on(async move {
+		let mut target = Box::pin(wait_for_finalized_para_head::<Block, _>(
+			sender,
+			ParaId::from(100),
+			relay_chain_interface,
+		));
+
+		// the relay chain is syncing, so the target is not determined
+		assert!(poll!(&mut target).is_pending());
+		imported_blocks.unbounded_send(relay_header(1)).unwrap();
+		assert!(poll!(&mut target).is_pending());
+		assert_eq!(receiver.try_recv().unwrap(), None);
+
+		// the relay chain is synced, the target is determined on the next imported block
+		is_major_syncing.store(false, Ordering::SeqCst);
+		imported_blocks.unbounded_send(relay_header(2)).unwrap();
+		assert!(target.await.is_ok());
+		assert_eq!(receiver.try_recv().unwrap(), Some(para_head()));
+	});
+}
+
+#[test]
+fn warp_sync_target_is_not_found_if_relay_chain_stops_importing_blocks() {
+	let (relay_chain_interface, _, imported_blocks) = WarpSyncRelayChainInterface::new(true);
+	let (sender, _receiver) = oneshot::channel::<Header>();
+	drop(imported_blocks);
+
+	let result = block_on(wait_for_finalized_para_head::<Block, _>(
+		sender,
+		ParaId::from(100),
+		relay_chain_interface,
+	));
+
+	assert!(result.is_err());
+}
@@ -281,6 +281,39 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_candidacy_bond_grace_period() -> Result<(), BenchmarkError> {
+		let grace_period: BlockNumberFor<T> = 100u32.into();
+		let origin =
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, grace_period);
+
+		assert_last_event::<T>(Event::NewCandidacyBondGracePeriod { grace_period }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_auto_rebid(c: Linear<1, { T::MaxCandidates::get() }>) {
+		<CandidacyBond<T>>::put(T::Currency::minimum_balance());
+
+		register_validators::<T>(c);
+		register_candidates::<T>(c);
+
+		// The caller is the last one in the list, so the whole list is searched.
+		let caller = <CandidateList<T>>::get().iter().last().unwrap().who.clone();
+		v2::whitelist!(caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), true);
+
+		assert_last_event::<T>(
+			Event::AutoRebidSet { account_id: caller.clone(), enabled: true }.into(),
+		);
+		assert!(<AutoRebid<T>>::contains_key(&caller));
+	}
+
 	// worse case is when we have all the max-candidate slots filled except one, and we fill that
 	// one.
 	#[benchmark]
@@ -381,6 +414,35 @@ mod benchmarks {
 		assert_eq!(frame_system::Pallet::<T>::block_number(), new_block);
 	}
 
+	// worst case for evicting under-bonded candidates: all candidates are under-bonded and their
+	// grace period has passed.
+	#[benchmark]
+	fn evict_under_bonded_candidates(u: Linear<0, { T::MaxCandidates::get() }>) {
+		<CandidacyBond<T>>::put(T::Currency::minimum_balance());
+
+		register_validators::<T>(u);
+		register_candidates::<T>(u);
+
+		<CandidacyBond<T>>::put(T::Currency::minimum_balance() * 2u32.into());
+		let zero_block: BlockNumberFor<T> = 0u32.into();
+		for candidate in <CandidateList<T>>::get() {
+			<UnderBondedCandidates<T>>::insert(candidate.who, zero_block);
+		}
+		let min_candidates = min_candidates::<T>();
+
+		#[block]
+		{
+			<CollatorSelection<T>>::evict_under_bonded_candidates();
+		}
+
+		// Candidates are evicted down to the minimum number of eligible collators.
+		let remaining: u32 = <CandidateList<T>>::decode_len()
+			.unwrap_or_default()
+			.try_into()
+			.unwrap_or_default();
+		assert_eq!(remaining, u.min(min_candidates));
+	}
+
 	// worst case for new session.
 	#[benchmark]
 	fn new_session(
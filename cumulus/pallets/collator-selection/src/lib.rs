@@ -65,6 +65,13 @@
 //! the desired number of collators is reached. Candidates can increase or decrease their deposits
 //! between sessions in order to ensure they receive a slot in the collator list.
 //!
+//! When the `CandidacyBond` is raised, candidates whose deposit falls below the new bond are
+//! under-bonded. Candidates that opted in through `set_auto_rebid` have their deposit raised to the
+//! new bond automatically, if they can afford it. The remaining under-bonded candidates are evicted
+//! immediately, unless a `CandidacyBondGracePeriod` is set. In that case, they are evicted on the
+//! first session change after the grace period, unless they raise their deposit through
+//! `update_bond` in the meantime.
+//!
 //! ### Rewards
 //!
 //! The Collator Selection pallet maintains an on-chain account (the "Pot"). In each block, the
@@ -233,6 +240,25 @@ pub mod pallet {
 	#[pallet::getter(fn candidacy_bond)]
 	pub type CandidacyBond<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+	/// Number of blocks that candidates left under-bonded by a raise of the [`CandidacyBond`] have
+	/// to top up their deposit before being evicted.
+	///
+	/// If zero, under-bonded candidates are evicted as soon as the bond is raised.
+	#[pallet::storage]
+	#[pallet::getter(fn candidacy_bond_grace_period)]
+	pub type CandidacyBondGracePeriod<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// Candidates whose deposit is automatically raised to the new [`CandidacyBond`] when it is
+	/// increased.
+	#[pallet::storage]
+	pub type AutoRebid<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	/// Candidates whose deposit is below the [`CandidacyBond`], mapped to the block number after
+	/// which they are evicted on a session change.
+	#[pallet::storage]
+	pub type UnderBondedCandidates<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -293,6 +319,19 @@ pub mod pallet {
 		/// An account was unable to be added to the Invulnerables because they did not have keys
 		/// registered. Other Invulnerables may have been set.
 		InvalidInvulnerableSkipped { account_id: T::AccountId },
+		/// The candidacy bond grace period was set.
+		NewCandidacyBondGracePeriod { grace_period: BlockNumberFor<T> },
+		/// A candidate opted in or out of automatically raising its deposit to the candidacy bond.
+		AutoRebidSet { account_id: T::AccountId, enabled: bool },
+		/// The deposit of a candidate is below the raised candidacy bond. The candidate will be
+		/// evicted after `evict_at` unless it raises its deposit.
+		CandidateUnderBonded {
+			account_id: T::AccountId,
+			deposit: BalanceOf<T>,
+			evict_at: BlockNumberFor<T>,
+		},
+		/// A candidate was evicted because its deposit was below the candidacy bond.
+		CandidateEvicted { account_id: T::AccountId, deposit: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -456,8 +495,10 @@ pub mod pallet {
 		/// Set the candidacy bond amount.
 		///
 		/// If the candidacy bond is increased by this call, all current candidates which have a
-		/// deposit lower than the new bond will be kicked from the list and get their deposits
-		/// back.
+		/// deposit lower than the new bond and opted in to auto-rebid get their deposit raised to
+		/// the new bond, if they can afford it. The remaining ones will be kicked from the list and
+		/// get their deposits back, either immediately or, if a `CandidacyBondGracePeriod` is set,
+		/// once it has passed.
 		///
 		/// The origin for this call must be the `UpdateOrigin`.
 		#[pallet::call_index(2)]
@@ -477,22 +518,7 @@ pub mod pallet {
 			});
 			let initial_len = <CandidateList<T>>::decode_len().unwrap_or_default();
 			let kicked = (bond_increased && initial_len > 0)
-				.then(|| {
-					// Closure below returns the number of candidates which were kicked because
-					// their deposits were lower than the new candidacy bond.
-					<CandidateList<T>>::mutate(|candidates| -> usize {
-						let first_safe_candidate = candidates
-							.iter()
-							.position(|candidate| candidate.deposit >= bond)
-							.unwrap_or(initial_len);
-						let kicked_candidates = candidates.drain(..first_safe_candidate);
-						for candidate in kicked_candidates {
-							T::Currency::unreserve(&candidate.who, candidate.deposit);
-							<LastAuthoredBlock<T>>::remove(candidate.who);
-						}
-						first_safe_candidate
-					})
-				})
+				.then(|| Self::handle_under_bonded_candidates(bond))
 				.unwrap_or_default();
 			Self::deposit_event(Event::NewCandidacyBond { bond_amount: bond });
 			Ok(Some(T::WeightInfo::set_candidacy_bond(initial_len as u32, kicked as u32)).into())
@@ -700,6 +726,9 @@ pub mod pallet {
 					Ok(candidate_count)
 				})?;
 
+			// The new deposit is at least the candidacy bond.
+			<UnderBondedCandidates<T>>::remove(&who);
+
 			Self::deposit_event(Event::CandidateBondUpdated {
 				account_id: who,
 				deposit: new_deposit,
@@ -784,6 +813,8 @@ pub mod pallet {
 			T::Currency::reserve(&who, deposit)?;
 			T::Currency::unreserve(&target_info.who, target_info.deposit);
 			<LastAuthoredBlock<T>>::remove(target_info.who.clone());
+			<AutoRebid<T>>::remove(&target_info.who);
+			<UnderBondedCandidates<T>>::remove(&target_info.who);
 			<LastAuthoredBlock<T>>::insert(
 				who.clone(),
 				frame_system::Pallet::<T>::block_number() + T::KickThreshold::get(),
@@ -792,6 +823,48 @@ pub mod pallet {
 			Self::deposit_event(Event::CandidateReplaced { old: target, new: who, deposit });
 			Ok(Some(T::WeightInfo::take_candidate_slot(length as u32)).into())
 		}
+
+		/// Opt in or out of automatically raising the deposit of collator candidate `origin` to
+		/// the new candidacy bond whenever it is increased above the deposit.
+		///
+		/// This call will fail if `origin` is not a collator candidate.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_auto_rebid(T::MaxCandidates::get()))]
+		pub fn set_auto_rebid(origin: OriginFor<T>, enabled: bool) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let candidates = <CandidateList<T>>::get();
+			ensure!(
+				candidates.iter().any(|candidate_info| candidate_info.who == who),
+				Error::<T>::NotCandidate
+			);
+
+			if enabled {
+				<AutoRebid<T>>::insert(&who, ());
+			} else {
+				<AutoRebid<T>>::remove(&who);
+			}
+
+			Self::deposit_event(Event::AutoRebidSet { account_id: who, enabled });
+			Ok(Some(T::WeightInfo::set_auto_rebid(candidates.len() as u32)).into())
+		}
+
+		/// Set the number of blocks that candidates left under-bonded by an increase of the
+		/// candidacy bond have to raise their deposit before being evicted.
+		///
+		/// The new grace period only applies to future increases of the candidacy bond.
+		///
+		/// The origin for this call must be the `UpdateOrigin`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_candidacy_bond_grace_period())]
+		pub fn set_candidacy_bond_grace_period(
+			origin: OriginFor<T>,
+			grace_period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			<CandidacyBondGracePeriod<T>>::put(grace_period);
+			Self::deposit_event(Event::NewCandidacyBondGracePeriod { grace_period });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -826,12 +899,120 @@ pub mod pallet {
 				if remove_last_authored {
 					<LastAuthoredBlock<T>>::remove(who.clone())
 				};
+				<AutoRebid<T>>::remove(who);
+				<UnderBondedCandidates<T>>::remove(who);
 				Ok(())
 			})?;
 			Self::deposit_event(Event::CandidateRemoved { account_id: who.clone() });
 			Ok(())
 		}
 
+		/// Sends an under-bonded candidate, already removed from the candidate list, back their
+		/// deposit.
+		fn evict_candidate(candidate: CandidateInfo<T::AccountId, BalanceOf<T>>) {
+			T::Currency::unreserve(&candidate.who, candidate.deposit);
+			<LastAuthoredBlock<T>>::remove(&candidate.who);
+			<AutoRebid<T>>::remove(&candidate.who);
+			<UnderBondedCandidates<T>>::remove(&candidate.who);
+			Self::deposit_event(Event::CandidateEvicted {
+				account_id: candidate.who,
+				deposit: candidate.deposit,
+			});
+		}
+
+		/// Handles the candidates with a deposit lower than the newly increased candidacy `bond`.
+		///
+		/// Candidates that opted in to auto-rebid and can reserve the difference get their deposit
+		/// raised to `bond`. The others are marked for eviction after the
+		/// [`CandidacyBondGracePeriod`] or, if there is none, evicted immediately.
+		///
+		/// Return value is the number of evicted candidates.
+		fn handle_under_bonded_candidates(bond: BalanceOf<T>) -> usize {
+			let now = frame_system::Pallet::<T>::block_number();
+			let grace_period = <CandidacyBondGracePeriod<T>>::get();
+			<CandidateList<T>>::mutate(|candidates| -> usize {
+				let first_safe_candidate = candidates
+					.iter()
+					.position(|candidate| candidate.deposit >= bond)
+					.unwrap_or(candidates.len());
+				let under_bonded = candidates.drain(..first_safe_candidate).collect::<Vec<_>>();
+
+				let mut evicted = 0;
+				let mut kept = Vec::with_capacity(first_safe_candidate);
+				let mut rebid = Vec::new();
+				for mut candidate in under_bonded {
+					if <AutoRebid<T>>::contains_key(&candidate.who) &&
+						T::Currency::reserve(&candidate.who, bond - candidate.deposit).is_ok()
+					{
+						candidate.deposit = bond;
+						<UnderBondedCandidates<T>>::remove(&candidate.who);
+						Self::deposit_event(Event::CandidateBondUpdated {
+							account_id: candidate.who.clone(),
+							deposit: bond,
+						});
+						rebid.push(candidate);
+					} else if !grace_period.is_zero() {
+						// Candidates which are already under-bonded keep their original deadline.
+						let evict_at = <UnderBondedCandidates<T>>::mutate(&candidate.who, |at| {
+							*at.get_or_insert(now.saturating_add(grace_period))
+						});
+						Self::deposit_event(Event::CandidateUnderBonded {
+							account_id: candidate.who.clone(),
+							deposit: candidate.deposit,
+							evict_at,
+						});
+						kept.push(candidate);
+					} else {
+						Self::evict_candidate(candidate);
+						evicted += 1;
+					}
+				}
+
+				// The rebid candidates now have the lowest deposit among the candidates at or
+				// above the bond, and are the most recently updated ones. They go right after the
+				// candidates that are still under-bonded.
+				kept.extend(rebid);
+				kept.extend(candidates.drain(..));
+				*candidates =
+					kept.try_into().expect("the candidate list can only have shrunk; qed");
+				evicted
+			})
+		}
+
+		/// Evicts the under-bonded candidates whose grace period has passed and refunds their
+		/// deposits. Candidates whose deposit is no longer below the candidacy bond are forgiven.
+		///
+		/// As with stale candidates, nobody is evicted if the number of eligible collators would
+		/// fall below `MinEligibleCollators`.
+		///
+		/// Return value is the number of under-bonded candidates that were checked.
+		pub fn evict_under_bonded_candidates() -> u32 {
+			let now = frame_system::Pallet::<T>::block_number();
+			let bond = <CandidacyBond<T>>::get();
+			let min_collators = T::MinEligibleCollators::get();
+			let under_bonded = <UnderBondedCandidates<T>>::iter().collect::<Vec<_>>();
+			let checked = under_bonded.len() as u32;
+			for (who, evict_at) in under_bonded {
+				if now < evict_at {
+					continue
+				}
+				let mut candidates = <CandidateList<T>>::get();
+				let Some(idx) = candidates.iter().position(|candidate| candidate.who == who) else {
+					// Can't normally happen but no need to panic.
+					<UnderBondedCandidates<T>>::remove(&who);
+					continue
+				};
+				if candidates[idx].deposit >= bond {
+					<UnderBondedCandidates<T>>::remove(&who);
+				} else if Self::eligible_collators() > min_collators {
+					let candidate = candidates.remove(idx);
+					<CandidateList<T>>::put(candidates);
+					Self::evict_candidate(candidate);
+				}
+			}
+			checked
+		}
+
 		/// Assemble the current set of candidates and invulnerables into the next collator set.
 		///
 		/// This is done on the fly, as frequent as we are told to do so, as the session manager.
@@ -960,6 +1141,7 @@ pub mod pallet {
 				.unwrap_or_default()
 				.try_into()
 				.expect("length is at most `T::MaxCandidates`, so it must fit in `u32`; qed");
+			let under_bonded = Self::evict_under_bonded_candidates();
 			let active_candidates_count = Self::kick_stale_candidates(
 				<CandidateList<T>>::get()
 					.iter()
@@ -969,7 +1151,8 @@ pub mod pallet {
 			let result = Self::assemble_collators();
 
 			frame_system::Pallet::<T>::register_extra_weight_unchecked(
-				T::WeightInfo::new_session(candidates_len_before, removed),
+				T::WeightInfo::new_session(candidates_len_before, removed)
+					.saturating_add(T::WeightInfo::evict_under_bonded_candidates(under_bonded)),
 				DispatchClass::Mandatory,
 			);
 			Some(result)
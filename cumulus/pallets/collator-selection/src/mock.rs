@@ -178,6 +178,7 @@ impl pallet_session::Config for Test {
 	type SessionManager = CollatorSelection;
 	type SessionHandler = TestSessionHandler;
 	type Keys = MockSessionKeys;
+	type EnforceOwnershipProof = frame_support::traits::ConstBool<false>;
 	type WeightInfo = ();
 }
 
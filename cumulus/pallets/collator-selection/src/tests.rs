@@ -472,6 +472,151 @@ fn set_candidacy_bond_with_many_candidates_different_deposits() {
 	});
 }
 
+#[test]
+fn set_candidacy_bond_grace_period_works() {
+	new_test_ext().execute_with(|| {
+		// given
+		assert_eq!(CollatorSelection::candidacy_bond_grace_period(), 0);
+
+		// bad origin
+		assert_noop!(
+			CollatorSelection::set_candidacy_bond_grace_period(RuntimeOrigin::signed(1), 5),
+			BadOrigin
+		);
+
+		// works
+		assert_ok!(CollatorSelection::set_candidacy_bond_grace_period(
+			RuntimeOrigin::signed(RootAccount::get()),
+			5
+		));
+		assert_eq!(CollatorSelection::candidacy_bond_grace_period(), 5);
+		System::assert_last_event(RuntimeEvent::CollatorSelection(
+			crate::Event::NewCandidacyBondGracePeriod { grace_period: 5 },
+		));
+	});
+}
+
+#[test]
+fn set_auto_rebid_works() {
+	new_test_ext().execute_with(|| {
+		// only candidates can opt in
+		assert_noop!(
+			CollatorSelection::set_auto_rebid(RuntimeOrigin::signed(3), true),
+			Error::<Test>::NotCandidate
+		);
+
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::set_auto_rebid(RuntimeOrigin::signed(3), true));
+		assert!(<crate::AutoRebid<Test>>::contains_key(3));
+		System::assert_last_event(RuntimeEvent::CollatorSelection(crate::Event::AutoRebidSet {
+			account_id: 3,
+			enabled: true,
+		}));
+
+		assert_ok!(CollatorSelection::set_auto_rebid(RuntimeOrigin::signed(3), false));
+		assert!(!<crate::AutoRebid<Test>>::contains_key(3));
+
+		// leaving clears the opt-in
+		assert_ok!(CollatorSelection::set_auto_rebid(RuntimeOrigin::signed(3), true));
+		assert_ok!(CollatorSelection::leave_intent(RuntimeOrigin::signed(3)));
+		assert!(!<crate::AutoRebid<Test>>::contains_key(3));
+	});
+}
+
+#[test]
+fn set_candidacy_bond_auto_rebids_candidates() {
+	new_test_ext().execute_with(|| {
+		// given
+		assert_eq!(CollatorSelection::candidacy_bond(), 10);
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(4)));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(5)));
+		assert_ok!(CollatorSelection::update_bond(RuntimeOrigin::signed(5), 20));
+		assert_ok!(CollatorSelection::set_auto_rebid(RuntimeOrigin::signed(3), true));
+
+		// 3 is topped up to the new bond, 4 is kicked
+		assert_ok!(CollatorSelection::set_candidacy_bond(
+			RuntimeOrigin::signed(RootAccount::get()),
+			20
+		));
+		assert_eq!(
+			<crate::CandidateList<Test>>::get(),
+			vec![CandidateInfo { who: 3, deposit: 20 }, CandidateInfo { who: 5, deposit: 20 }]
+		);
+		assert_eq!(Balances::reserved_balance(3), 20);
+		assert_eq!(Balances::reserved_balance(4), 0);
+		System::assert_has_event(RuntimeEvent::CollatorSelection(
+			crate::Event::CandidateBondUpdated { account_id: 3, deposit: 20 },
+		));
+		System::assert_has_event(RuntimeEvent::CollatorSelection(crate::Event::CandidateEvicted {
+			account_id: 4,
+			deposit: 10,
+		}));
+
+		// 3 cannot afford the next raise and is kicked as well
+		assert_ok!(CollatorSelection::set_candidacy_bond(
+			RuntimeOrigin::signed(RootAccount::get()),
+			150
+		));
+		assert!(<crate::CandidateList<Test>>::get().is_empty());
+		assert_eq!(Balances::free_balance(3), 100);
+		assert!(!<crate::AutoRebid<Test>>::contains_key(3));
+		System::assert_has_event(RuntimeEvent::CollatorSelection(crate::Event::CandidateEvicted {
+			account_id: 3,
+			deposit: 20,
+		}));
+	});
+}
+
+#[test]
+fn set_candidacy_bond_with_grace_period_evicts_on_session_change() {
+	new_test_ext().execute_with(|| {
+		// given
+		initialize_to_block(1);
+		assert_ok!(CollatorSelection::set_candidacy_bond_grace_period(
+			RuntimeOrigin::signed(RootAccount::get()),
+			5
+		));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(4)));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(5)));
+		assert_ok!(CollatorSelection::update_bond(RuntimeOrigin::signed(5), 30));
+
+		// 3 and 4 are under-bonded but stay in the list for now
+		assert_ok!(CollatorSelection::set_candidacy_bond(
+			RuntimeOrigin::signed(RootAccount::get()),
+			20
+		));
+		assert_eq!(<crate::CandidateList<Test>>::get().len(), 3);
+		assert_eq!(<crate::UnderBondedCandidates<Test>>::get(3), Some(6));
+		assert_eq!(<crate::UnderBondedCandidates<Test>>::get(4), Some(6));
+		System::assert_has_event(RuntimeEvent::CollatorSelection(
+			crate::Event::CandidateUnderBonded { account_id: 3, deposit: 10, evict_at: 6 },
+		));
+
+		// 4 tops up its deposit in time
+		assert_ok!(CollatorSelection::update_bond(RuntimeOrigin::signed(4), 20));
+		assert_eq!(<crate::UnderBondedCandidates<Test>>::get(4), None);
+
+		// nothing happens before the session change
+		initialize_to_block(9);
+		assert_eq!(<crate::CandidateList<Test>>::get().len(), 3);
+
+		// 3 is evicted on the session change after the grace period
+		initialize_to_block(10);
+		assert_eq!(
+			<crate::CandidateList<Test>>::get(),
+			vec![CandidateInfo { who: 4, deposit: 20 }, CandidateInfo { who: 5, deposit: 30 }]
+		);
+		assert_eq!(Balances::free_balance(3), 100);
+		assert_eq!(<crate::UnderBondedCandidates<Test>>::get(3), None);
+		System::assert_has_event(RuntimeEvent::CollatorSelection(crate::Event::CandidateEvicted {
+			account_id: 3,
+			deposit: 10,
+		}));
+	});
+}
+
 #[test]
 fn cannot_register_candidate_if_too_many() {
 	new_test_ext().execute_with(|| {
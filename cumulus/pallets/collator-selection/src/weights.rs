@@ -37,6 +37,9 @@ pub trait WeightInfo {
 	fn take_candidate_slot(_c: u32) -> Weight;
 	fn note_author() -> Weight;
 	fn new_session(_c: u32, _r: u32) -> Weight;
+	fn set_auto_rebid(_c: u32) -> Weight;
+	fn set_candidacy_bond_grace_period() -> Weight;
+	fn evict_under_bonded_candidates(_u: u32) -> Weight;
 }
 
 /// Weights for pallet_collator_selection using the Substrate node and recommended hardware.
@@ -98,6 +101,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64.saturating_mul(r as u64)))
 			.saturating_add(T::DbWeight::get().writes(2_u64.saturating_mul(c as u64)))
 	}
+	fn set_auto_rebid(c: u32) -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(14_012_000_u64, 0)
+			.saturating_add(Weight::from_parts(52_000_u64, 0).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_candidacy_bond_grace_period() -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(7_301_000_u64, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn evict_under_bonded_candidates(u: u32) -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(10_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(60_000_000_u64, 0).saturating_mul(u as u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64.saturating_mul(u as u64)))
+			.saturating_add(T::DbWeight::get().writes(5_u64.saturating_mul(u as u64)))
+	}
 	/// Storage: Session NextKeys (r:1 w:0)
 	/// Proof Skipped: Session NextKeys (max_values: None, max_size: None, mode: Measured)
 	/// Storage: CollatorSelection Invulnerables (r:1 w:1)
@@ -199,6 +221,25 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64.saturating_mul(r as u64)))
 			.saturating_add(RocksDbWeight::get().writes(2_u64.saturating_mul(c as u64)))
 	}
+	fn set_auto_rebid(c: u32) -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(14_012_000_u64, 0)
+			.saturating_add(Weight::from_parts(52_000_u64, 0).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_candidacy_bond_grace_period() -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(7_301_000_u64, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn evict_under_bonded_candidates(u: u32) -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(10_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(60_000_000_u64, 0).saturating_mul(u as u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64.saturating_mul(u as u64)))
+			.saturating_add(RocksDbWeight::get().writes(5_u64.saturating_mul(u as u64)))
+	}
 	/// Storage: Session NextKeys (r:1 w:0)
 	/// Proof Skipped: Session NextKeys (max_values: None, max_size: None, mode: Measured)
 	/// Storage: CollatorSelection Invulnerables (r:1 w:1)
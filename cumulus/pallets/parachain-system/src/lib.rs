@@ -27,7 +27,7 @@
 //!
 //! Users must ensure that they register this pallet as an inherent provider.
 
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, Encode};
 use cumulus_primitives_core::{
 	relay_chain, AbridgedHostConfiguration, ChannelInfo, ChannelStatus, CollationInfo,
 	GetChannelInfo, InboundDownwardMessage, InboundHrmpMessage, MessageSendError,
@@ -43,7 +43,7 @@ use frame_support::{
 	traits::{Get, HandleMessage},
 	weights::Weight,
 };
-use frame_system::{ensure_none, ensure_root, pallet_prelude::HeaderFor};
+use frame_system::{ensure_none, ensure_root, pallet_prelude::HeaderFor, WeightInfo as _};
 use polkadot_parachain_primitives::primitives::RelayChainBlockNumber;
 use polkadot_runtime_parachains::FeeTracker;
 use scale_info::TypeInfo;
@@ -169,20 +169,6 @@ impl CheckAssociatedRelayNumber for RelayNumberMonotonicallyIncreases {
 	}
 }
 
-/// Information needed when a new runtime binary is submitted and needs to be authorized before
-/// replacing the current runtime.
-#[derive(Decode, Encode, Default, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
-#[scale_info(skip_type_params(T))]
-struct CodeUpgradeAuthorization<T>
-where
-	T: Config,
-{
-	/// Hash of the new runtime binary.
-	code_hash: T::Hash,
-	/// Whether or not to carry out version checks.
-	check_version: bool,
-}
-
 /// The max length of a DMP message.
 pub type MaxDmpMessageLenOf<T> = <<T as Config>::DmpQueue as HandleMessage>::MaxMessageLen;
 
@@ -204,7 +190,7 @@ pub mod ump_constants {
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::*;
+	use frame_system::{pallet_prelude::*, WeightInfo as _};
 
 	#[pallet::pallet]
 	#[pallet::storage_version(migration::STORAGE_VERSION)]
@@ -434,6 +420,10 @@ pub mod pallet {
 			HrmpOutboundMessages::<T>::put(outbound_messages);
 		}
 
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::retry_blocked_upgrade(remaining_weight)
+		}
+
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
 			let mut weight = Weight::zero();
 
@@ -622,11 +612,11 @@ pub mod pallet {
 				},
 				None => {},
 			}
-			<UpgradeRestrictionSignal<T>>::put(
-				relay_state_proof
-					.read_upgrade_restriction_signal()
-					.expect("Invalid upgrade restriction signal"),
-			);
+			let upgrade_restriction = relay_state_proof
+				.read_upgrade_restriction_signal()
+				.expect("Invalid upgrade restriction signal");
+			<UpgradeRestrictionSignal<T>>::put(upgrade_restriction);
+			<LastUpgradeRestriction<T>>::set(upgrade_restriction);
 			<UpgradeGoAhead<T>>::put(upgrade_go_ahead_signal);
 
 			let host_config = relay_state_proof
@@ -676,17 +666,18 @@ pub mod pallet {
 		/// it cannot actually perform the verification.
 		///
 		/// This call requires Root origin.
+		///
+		/// Deprecated: this forwards to `frame_system`, use `frame_system::authorize_upgrade` or
+		/// `frame_system::authorize_upgrade_without_checks` instead.
 		#[pallet::call_index(2)]
-		#[pallet::weight((1_000_000, DispatchClass::Operational))]
+		#[pallet::weight((T::SystemWeightInfo::authorize_upgrade(), DispatchClass::Operational))]
 		pub fn authorize_upgrade(
 			origin: OriginFor<T>,
 			code_hash: T::Hash,
 			check_version: bool,
 		) -> DispatchResult {
 			ensure_root(origin)?;
-			AuthorizedUpgrade::<T>::put(CodeUpgradeAuthorization { code_hash, check_version });
-
-			Self::deposit_event(Event::UpgradeAuthorized { code_hash });
+			frame_system::Pallet::<T>::do_authorize_upgrade(code_hash, check_version);
 			Ok(())
 		}
 
@@ -696,19 +687,31 @@ pub mod pallet {
 		/// remains unchanged and that the spec version has increased.
 		///
 		/// Note that this function will not apply the new `code`, but only attempt to schedule the
-		/// upgrade with the Relay Chain.
+		/// upgrade with the Relay Chain. If the Relay Chain currently restricts upgrades of this
+		/// parachain, the `code` is kept and the upgrade is scheduled as soon as the restriction
+		/// is lifted.
 		///
 		/// All origins are allowed.
+		///
+		/// Deprecated: this forwards to `frame_system`, use
+		/// `frame_system::apply_authorized_upgrade` instead. Note that it doesn't retry upgrades
+		/// blocked by the Relay Chain.
 		#[pallet::call_index(3)]
-		#[pallet::weight({1_000_000})]
+		#[pallet::weight(T::SystemWeightInfo::apply_authorized_upgrade())]
 		pub fn enact_authorized_upgrade(
 			_: OriginFor<T>,
 			code: Vec<u8>,
 		) -> DispatchResultWithPostInfo {
-			Self::validate_authorized_upgrade(&code[..])?;
-			Self::schedule_code_upgrade(code)?;
-			AuthorizedUpgrade::<T>::kill();
-			Ok(Pays::No.into())
+			if let Some(restriction) = <UpgradeRestrictionSignal<T>>::get() {
+				ensure!(!<BlockedUpgrade<T>>::exists(), Error::<T>::OverlappingUpgrades);
+				let code_hash = frame_system::Pallet::<T>::validate_authorized_upgrade(&code[..])?;
+				<BlockedUpgrade<T>>::put(code);
+				Self::deposit_event(Event::UpgradeBlockedByRelay { code_hash, restriction });
+				return Ok(Pays::No.into())
+			}
+
+			let post = frame_system::Pallet::<T>::do_apply_authorize_upgrade(code)?;
+			Ok(post)
 		}
 	}
 
@@ -721,14 +724,19 @@ pub mod pallet {
 		ValidationFunctionApplied { relay_chain_block_num: RelayChainBlockNumber },
 		/// The relay-chain aborted the upgrade process.
 		ValidationFunctionDiscarded,
-		/// An upgrade has been authorized.
-		UpgradeAuthorized { code_hash: T::Hash },
 		/// Some downward messages have been received and will be processed.
 		DownwardMessagesReceived { count: u32 },
 		/// Downward messages were processed using the given weight.
 		DownwardMessagesProcessed { weight_used: Weight, dmq_head: relay_chain::Hash },
 		/// An upward message was sent to the relay chain.
 		UpwardMessageSent { message_hash: Option<XcmHash> },
+		/// Enacting an authorized upgrade was blocked by the relay chain. It will be retried once
+		/// the restriction is lifted.
+		UpgradeBlockedByRelay { code_hash: T::Hash, restriction: relay_chain::UpgradeRestriction },
+		/// An upgrade that was blocked by the relay chain has been scheduled.
+		BlockedUpgradeEnacted { code_hash: T::Hash },
+		/// An upgrade that was blocked by the relay chain could not be scheduled and was dropped.
+		BlockedUpgradeDiscarded { code_hash: T::Hash, error: DispatchError },
 	}
 
 	#[pallet::error]
@@ -746,10 +754,6 @@ pub mod pallet {
 		HostConfigurationNotAvailable,
 		/// No validation function upgrade is currently scheduled.
 		NotScheduled,
-		/// No code upgrade has been authorized.
-		NothingAuthorized,
-		/// The given code upgrade has not been authorized.
-		Unauthorized,
 	}
 
 	/// Latest included block descendants the runtime accepted. In other words, these are
@@ -816,6 +820,19 @@ pub mod pallet {
 	pub(super) type UpgradeRestrictionSignal<T: Config> =
 		StorageValue<_, Option<relay_chain::UpgradeRestriction>, ValueQuery>;
 
+	/// The upgrade restriction the relay chain imposed on this parachain, as of the last block.
+	///
+	/// Unlike [`UpgradeRestrictionSignal`], this is kept across blocks so that it can be queried
+	/// outside of block execution.
+	#[pallet::storage]
+	pub(super) type LastUpgradeRestriction<T: Config> =
+		StorageValue<_, relay_chain::UpgradeRestriction, OptionQuery>;
+
+	/// The code of an authorized upgrade which couldn't be enacted because the relay chain
+	/// restricted upgrades. It is scheduled as soon as the restriction is lifted.
+	#[pallet::storage]
+	pub(super) type BlockedUpgrade<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
 	/// Optional upgrade go-ahead signal from the relay-chain.
 	///
 	/// This storage item is a mirror of the corresponding value for the current parachain from the
@@ -928,10 +945,6 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type ReservedDmpWeightOverride<T: Config> = StorageValue<_, Weight>;
 
-	/// The next authorized upgrade, if there is one.
-	#[pallet::storage]
-	pub(super) type AuthorizedUpgrade<T: Config> = StorageValue<_, CodeUpgradeAuthorization<T>>;
-
 	/// A custom head data that should be returned as result of `validate_block`.
 	///
 	/// See `Pallet::set_custom_validation_head_data` for more information.
@@ -982,7 +995,10 @@ pub mod pallet {
 
 		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
 			if let Call::enact_authorized_upgrade { ref code } = call {
-				if let Ok(hash) = Self::validate_authorized_upgrade(code) {
+				if <BlockedUpgrade<T>>::exists() {
+					return Err(InvalidTransaction::Stale.into())
+				}
+				if let Ok(hash) = frame_system::Pallet::<T>::validate_authorized_upgrade(code) {
 					return Ok(ValidTransaction {
 						priority: 100,
 						requires: Vec::new(),
@@ -1001,21 +1017,6 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
-	fn validate_authorized_upgrade(code: &[u8]) -> Result<T::Hash, DispatchError> {
-		let authorization = AuthorizedUpgrade::<T>::get().ok_or(Error::<T>::NothingAuthorized)?;
-
-		// ensure that the actual hash matches the authorized hash
-		let actual_hash = T::Hashing::hash(code);
-		ensure!(actual_hash == authorization.code_hash, Error::<T>::Unauthorized);
-
-		// check versions if required as part of the authorization
-		if authorization.check_version {
-			frame_system::Pallet::<T>::can_set_code(code)?;
-		}
-
-		Ok(actual_hash)
-	}
-
 	/// Get the unincluded segment size after the given hash.
 	///
 	/// If the unincluded segment doesn't contain the given hash, this returns the
@@ -1426,6 +1427,42 @@ impl<T: Config> Pallet<T> {
 		<HostConfiguration<T>>::get().map(|cfg| cfg.max_code_size)
 	}
 
+	/// The upgrade restriction the relay chain imposed on this parachain, as of the last block.
+	///
+	/// While this is `Some`, the parachain can't schedule a code upgrade.
+	pub fn upgrade_restriction() -> Option<relay_chain::UpgradeRestriction> {
+		<LastUpgradeRestriction<T>>::get()
+	}
+
+	/// Schedule the [`BlockedUpgrade`], if any, once the relay chain lifted its restriction and
+	/// there is enough weight left for it.
+	fn retry_blocked_upgrade(remaining_weight: Weight) -> Weight {
+		let mut weight = T::DbWeight::get().reads(1);
+		if !<BlockedUpgrade<T>>::exists() {
+			return weight
+		}
+
+		weight.saturating_accrue(T::DbWeight::get().reads(2));
+		if <UpgradeRestrictionSignal<T>>::get().is_some() || <PendingValidationCode<T>>::exists() {
+			return weight
+		}
+
+		let apply_weight = T::SystemWeightInfo::apply_authorized_upgrade()
+			.saturating_add(T::DbWeight::get().writes(1));
+		if !remaining_weight.all_gte(weight.saturating_add(apply_weight)) {
+			return weight
+		}
+
+		let Some(code) = <BlockedUpgrade<T>>::take() else { return weight };
+		let code_hash = T::Hashing::hash(&code);
+		match frame_system::Pallet::<T>::do_apply_authorize_upgrade(code) {
+			Ok(_) => Self::deposit_event(Event::BlockedUpgradeEnacted { code_hash }),
+			Err(error) => Self::deposit_event(Event::BlockedUpgradeDiscarded { code_hash, error }),
+		}
+
+		weight.saturating_add(apply_weight)
+	}
+
 	/// The implementation of the runtime upgrade functionality for parachains.
 	pub fn schedule_code_upgrade(validation_function: Vec<u8>) -> DispatchResult {
 		// Ensure that `ValidationData` exists. We do not care about the validation data per se,
@@ -22,7 +22,7 @@ use frame_support::{
 };
 
 /// The current storage version.
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 /// Migrates the pallet storage to the most recent version.
 pub struct Migration<T: Config>(PhantomData<T>);
@@ -45,10 +45,48 @@ impl<T: Config> OnRuntimeUpgrade for Migration<T> {
 			StorageVersion::new(2).put::<Pallet<T>>();
 		}
 
+		if StorageVersion::get::<Pallet<T>>() == 2 {
+			weight = weight
+				.saturating_add(v3::migrate::<T>())
+				.saturating_add(T::DbWeight::get().writes(1));
+			StorageVersion::new(3).put::<Pallet<T>>();
+		}
+
 		weight
 	}
 }
 
+/// V3: The authorized upgrade moved to `frame_system`, so a pending authorization is moved there.
+pub(crate) mod v3 {
+	use super::*;
+	use codec::{Decode, Encode};
+
+	/// The authorized upgrade, as stored by the pallet before V3.
+	#[derive(Decode, Encode)]
+	pub(crate) struct CodeUpgradeAuthorization<Hash> {
+		pub(crate) code_hash: Hash,
+		pub(crate) check_version: bool,
+	}
+
+	#[frame_support::storage_alias]
+	pub(crate) type AuthorizedUpgrade<T: Config> = StorageValue<
+		Pallet<T>,
+		CodeUpgradeAuthorization<<T as frame_system::Config>::Hash>,
+		OptionQuery,
+	>;
+
+	pub fn migrate<T: Config>() -> Weight {
+		if let Some(authorization) = AuthorizedUpgrade::<T>::take() {
+			frame_system::Pallet::<T>::do_authorize_upgrade(
+				authorization.code_hash,
+				authorization.check_version,
+			);
+		}
+
+		T::DbWeight::get().reads_writes(1, 2)
+	}
+}
+
 /// V2: Migrate to 2D weights for ReservedXcmpWeightOverride and ReservedDmpWeightOverride.
 mod v2 {
 	use super::*;
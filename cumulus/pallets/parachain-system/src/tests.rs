@@ -1136,6 +1136,75 @@ fn upgrade_version_checks_should_work() {
 	}
 }
 
+#[test]
+fn blocked_upgrade_is_enacted_once_restriction_is_lifted() {
+	use frame_support::traits::Hooks;
+	use relay_chain::UpgradeRestriction;
+
+	let code = vec![1, 2, 3, 4];
+	let code_hash = H256(sp_core::blake2_256(&code));
+
+	BlockTests::new()
+		.with_relay_sproof_builder(|_, block_number, builder| {
+			if block_number < 1000 {
+				builder.upgrade_restriction = Some(UpgradeRestriction::Present);
+			}
+		})
+		.add_with_post_test(
+			123,
+			move || {
+				assert_ok!(System::authorize_upgrade_without_checks(
+					RawOrigin::Root.into(),
+					code_hash
+				));
+				assert_eq!(
+					ParachainSystem::upgrade_restriction(),
+					Some(UpgradeRestriction::Present)
+				);
+				assert_ok!(ParachainSystem::enact_authorized_upgrade(
+					RawOrigin::None.into(),
+					code.clone()
+				));
+				// Only one blocked upgrade at a time.
+				assert_eq!(
+					ParachainSystem::enact_authorized_upgrade(RawOrigin::None.into(), code.clone())
+						.map_err(|e| e.error),
+					Err(Error::<Test>::OverlappingUpgrades.into()),
+				);
+				// Still restricted, so it isn't retried.
+				ParachainSystem::on_idle(123, Weight::MAX);
+			},
+			move || {
+				System::assert_has_event(
+					crate::Event::UpgradeBlockedByRelay {
+						code_hash,
+						restriction: UpgradeRestriction::Present,
+					}
+					.into(),
+				);
+				assert!(<BlockedUpgrade<Test>>::exists());
+				assert!(!<PendingValidationCode<Test>>::exists());
+				assert_eq!(
+					ParachainSystem::upgrade_restriction(),
+					Some(UpgradeRestriction::Present)
+				);
+			},
+		)
+		.add_with_post_test(
+			1000,
+			|| {
+				ParachainSystem::on_idle(1000, Weight::MAX);
+			},
+			move || {
+				assert_eq!(ParachainSystem::upgrade_restriction(), None);
+				System::assert_has_event(crate::Event::BlockedUpgradeEnacted { code_hash }.into());
+				System::assert_has_event(crate::Event::ValidationFunctionStored.into());
+				assert!(!<BlockedUpgrade<Test>>::exists());
+				assert!(<PendingValidationCode<Test>>::exists());
+			},
+		);
+}
+
 #[test]
 fn deposits_relay_parent_storage_root() {
 	BlockTests::new().add_with_post_test(
@@ -1200,3 +1269,25 @@ fn ump_fee_factor_increases_and_decreases() {
 			},
 		);
 }
+
+#[test]
+fn migration_moves_authorized_upgrade_to_frame_system() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+	use sp_runtime::traits::Hash as _;
+
+	new_test_ext().execute_with(|| {
+		let code = vec![1, 2, 3];
+		let code_hash = <Test as frame_system::Config>::Hashing::hash(&code);
+		StorageVersion::new(2).put::<ParachainSystem>();
+		migration::v3::AuthorizedUpgrade::<Test>::put(migration::v3::CodeUpgradeAuthorization {
+			code_hash,
+			check_version: false,
+		});
+
+		migration::Migration::<Test>::on_runtime_upgrade();
+
+		assert!(!migration::v3::AuthorizedUpgrade::<Test>::exists());
+		assert_eq!(System::validate_authorized_upgrade(&code), Ok(code_hash));
+		assert_eq!(StorageVersion::get::<ParachainSystem>(), migration::STORAGE_VERSION);
+	});
+}
@@ -23,21 +23,27 @@ use frame_system::RawOrigin;
 use pallet_session::*;
 use parity_scale_codec::Decode;
 pub struct Pallet<T: Config>(pallet_session::Pallet<T>);
-pub trait Config: pallet_session::Config {}
+pub trait Config: pallet_session::Config {
+	/// Generate session keys along with a proof of their ownership by `owner`.
+	///
+	/// The default implementation returns zeroed keys with an empty proof, which is only accepted
+	/// if the runtime doesn't enforce ownership proofs.
+	fn generate_session_keys_and_proof(_owner: &Self::AccountId) -> (Self::Keys, Vec<u8>) {
+		(Self::Keys::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes()).unwrap(), vec![])
+	}
+}
 
 benchmarks! {
 	set_keys {
 		let caller: T::AccountId = whitelisted_caller();
 		frame_system::Pallet::<T>::inc_providers(&caller);
-		let keys = T::Keys::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes()).unwrap();
-		let proof: Vec<u8> = vec![0,1,2,3];
+		let (keys, proof) = T::generate_session_keys_and_proof(&caller);
 	}: _(RawOrigin::Signed(caller), keys, proof)
 
 	purge_keys {
 		let caller: T::AccountId = whitelisted_caller();
 		frame_system::Pallet::<T>::inc_providers(&caller);
-		let keys = T::Keys::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes()).unwrap();
-		let proof: Vec<u8> = vec![0,1,2,3];
+		let (keys, proof) = T::generate_session_keys_and_proof(&caller);
 		let _t = pallet_session::Pallet::<T>::set_keys(RawOrigin::Signed(caller.clone()).into(), keys, proof);
 	}: _(RawOrigin::Signed(caller))
 }
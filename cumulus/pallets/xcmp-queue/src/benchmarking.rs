@@ -42,7 +42,7 @@ mod benchmarks {
 	#[benchmark]
 	fn enqueue_xcmp_message() {
 		assert!(QueueConfig::<T>::get().drop_threshold * MaxXcmpMessageLenOf::<T>::get() > 1000);
-		let msg = BoundedVec::<u8, MaxXcmpMessageLenOf<T>>::default();
+		let msg = BoundedSlice::<u8, MaxXcmpMessageLenOf<T>>::truncate_from(&[]);
 
 		#[block]
 		{
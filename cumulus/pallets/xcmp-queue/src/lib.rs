@@ -32,10 +32,17 @@
 //! It is defined in the channel configuration.
 //! - `THRESHOLD_FACTOR` just declares which percentage of the max size is the actual threshold.
 //! If it's 2, then the threshold is half of the max size, if it's 4, it's a quarter, and so on.
+//!
+//! Inbound pages are enqueued into the `XcmpQueue` as they were received, without decoding their
+//! XCMs. The XCMs of a page are decoded and executed one by one by [`ProcessXcmpPage`] once the
+//! page is serviced. The weight spent on decoding them per block is bounded by
+//! `MaxInboundDecodeWeight`. Only pages that exceed the maximal message length of the `XcmpQueue`
+//! are split into runs of whole XCMs that fit into it.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod migration;
+mod process_xcmp_page;
 
 #[cfg(test)]
 mod mock;
@@ -48,6 +55,7 @@ mod benchmarking;
 #[cfg(feature = "bridging")]
 pub mod bridging;
 pub mod weights;
+pub use process_xcmp_page::ProcessXcmpPage;
 pub use weights::WeightInfo;
 
 use bounded_collections::BoundedBTreeSet;
@@ -61,15 +69,18 @@ use frame_support::{
 	defensive, defensive_assert,
 	traits::{EnqueueMessage, EnsureOrigin, Get, QueueFootprint, QueuePausedQuery},
 	weights::{Weight, WeightMeter},
-	BoundedVec,
+	BoundedSlice,
 };
 use pallet_message_queue::OnQueueChanged;
 use polkadot_runtime_common::xcm_sender::PriceForMessageDelivery;
 use polkadot_runtime_parachains::FeeTracker;
 use scale_info::TypeInfo;
 use sp_core::MAX_POSSIBLE_ALLOCATION;
-use sp_runtime::{FixedU128, RuntimeDebug, Saturating};
-use sp_std::prelude::*;
+use sp_runtime::{traits::Zero, FixedU128, RuntimeDebug, SaturatedConversion, Saturating};
+use sp_std::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	prelude::*,
+};
 use xcm::{latest::prelude::*, VersionedXcm, WrapVersion, MAX_XCM_DECODE_DEPTH};
 use xcm_executor::traits::ConvertOrigin;
 
@@ -122,6 +133,8 @@ pub mod pallet {
 		///
 		/// This defines the maximal message length via [`crate::MaxXcmpMessageLenOf`]. The pallet
 		/// assumes that this hook will eventually process all the pushed messages.
+		///
+		/// The messages are pages of concatenated XCMs, which are processed by [`ProcessXcmpPage`].
 		type XcmpQueue: EnqueueMessage<ParaId>;
 
 		/// The maximum number of inbound XCMP channels that can be suspended simultaneously.
@@ -132,6 +145,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxInboundSuspended: Get<u32>;
 
+		/// The maximal weight that is spent per block on decoding the XCMs of inbound messages
+		/// in [`ProcessXcmpPage`].
+		///
+		/// Once it is exhausted, the processing of messages is postponed to the following blocks.
+		/// This bounds the cost of large bursts of inbound messages.
+		#[pallet::constant]
+		type MaxInboundDecodeWeight: Get<Weight>;
+
 		/// The origin that is allowed to resume or suspend the XCMP queue.
 		type ControllerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -233,6 +254,24 @@ pub mod pallet {
 				data.validate::<T>()
 			})
 		}
+
+		/// Overwrites the number of blocks between two [`Event::QueueDepth`] events.
+		///
+		/// No events are emitted if the interval is zero, which is the default.
+		///
+		/// - `origin`: Must pass `Root`.
+		/// - `new`: Desired value for [`QueueDepthReportInterval`]
+		#[pallet::call_index(6)]
+		#[pallet::weight((T::WeightInfo::set_config_with_u32(), DispatchClass::Operational,))]
+		pub fn update_queue_depth_report_interval(
+			origin: OriginFor<T>,
+			new: BlockNumberFor<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			QueueDepthReportInterval::<T>::put(new);
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -241,6 +280,34 @@ pub mod pallet {
 			let w = Self::on_idle_weight();
 			assert!(w != Weight::zero());
 			assert!(w.all_lte(T::BlockWeights::get().max_block));
+			assert!(
+				T::WeightInfo::take_first_concatenated_xcm()
+					.all_lte(T::MaxInboundDecodeWeight::get()),
+				"MaxInboundDecodeWeight must allow to decode at least one XCM per block"
+			);
+		}
+
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let interval = QueueDepthReportInterval::<T>::get();
+			if interval.is_zero() || !(n % interval).is_zero() {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let channels = Self::queue_depths();
+			// The interval, the outbound channels, the suspended inbound channels, every outbound
+			// page and the inbound queue footprint of every sibling are read.
+			let (reads, outbound_bytes) =
+				channels.iter().fold((3u64, 0u64), |(reads, bytes), channel| {
+					(
+						reads.saturating_add(channel.outbound_pages.into()).saturating_add(1),
+						bytes.saturating_add(channel.outbound_bytes.into()),
+					)
+				});
+			Self::deposit_event(Event::QueueDepth { channels });
+
+			T::DbWeight::get()
+				.reads_writes(reads, 1)
+				.saturating_add(Weight::from_parts(0, outbound_bytes))
 		}
 
 		fn on_idle(_block: BlockNumberFor<T>, limit: Weight) -> Weight {
@@ -266,6 +333,8 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// An HRMP message was sent to a sibling parachain.
 		XcmpMessageSent { message_hash: XcmHash },
+		/// Periodic summary of the non-empty XCMP queues.
+		QueueDepth { channels: Vec<ChannelQueueDepth> },
 	}
 
 	#[pallet::error]
@@ -290,6 +359,19 @@ pub mod pallet {
 	pub type InboundXcmpSuspended<T: Config> =
 		StorageValue<_, BoundedBTreeSet<ParaId, T::MaxInboundSuspended>, ValueQuery>;
 
+	/// The progress of the messages whose processing by [`ProcessXcmpPage`] was interrupted,
+	/// indexed by the hash of the message.
+	#[pallet::storage]
+	pub(super) type InboundXcmpPageCursors<T: Config> =
+		StorageMap<_, Identity, XcmHash, PageCursor, OptionQuery>;
+
+	/// The block number and the weight that was spent on decoding inbound XCMs in that block.
+	///
+	/// Bounded by [`Config::MaxInboundDecodeWeight`] per block.
+	#[pallet::storage]
+	pub(super) type InboundDecodeWeight<T: Config> =
+		StorageValue<_, (BlockNumberFor<T>, Weight), ValueQuery>;
+
 	/// The non-empty XCMP channels in order of becoming non-empty, and the index of the first
 	/// and last outbound message. If the two indices are equal, then it indicates an empty
 	/// queue and there must be a non-`Ok` `OutboundStatus`. We assume queues grow no greater
@@ -329,6 +411,11 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type DeliveryFeeFactor<T: Config> =
 		StorageMap<_, Twox64Concat, ParaId, FixedU128, ValueQuery, InitialFactor>;
+
+	/// The number of blocks between two [`Event::QueueDepth`] events. Zero disables them.
+	#[pallet::storage]
+	pub(super) type QueueDepthReportInterval<T: Config> =
+		StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -375,6 +462,30 @@ impl OutboundChannelDetails {
 	}
 }
 
+/// The progress of a message that is partially processed by [`ProcessXcmpPage`].
+#[derive(Copy, Clone, Default, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PageCursor {
+	/// The number of bytes at the start of the message whose XCMs were already processed.
+	pub offset: u32,
+	/// Whether any of the processed XCMs was not executed successfully.
+	pub failed: bool,
+}
+
+/// The queued pages of an XCMP channel with a sibling parachain.
+#[derive(Clone, Default, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ChannelQueueDepth {
+	/// The sibling parachain.
+	pub sibling: ParaId,
+	/// The number of outbound pages that wait to be sent to the sibling.
+	pub outbound_pages: u32,
+	/// The total size of the outbound pages in bytes.
+	pub outbound_bytes: u32,
+	/// The number of inbound pages from the sibling that wait in the `XcmpQueue` to be processed.
+	pub inbound_pages: u32,
+	/// The total size of the inbound pages in bytes.
+	pub inbound_bytes: u32,
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct QueueConfigData {
 	/// The number of pages which must be in the queue for the other side to be told to suspend
@@ -589,7 +700,7 @@ impl<T: Config> Pallet<T> {
 
 	fn enqueue_xcmp_message(
 		sender: ParaId,
-		xcm: BoundedVec<u8, MaxXcmpMessageLenOf<T>>,
+		xcm: BoundedSlice<u8, MaxXcmpMessageLenOf<T>>,
 		meter: &mut WeightMeter,
 	) -> Result<(), ()> {
 		if meter.try_consume(T::WeightInfo::enqueue_xcmp_message()).is_err() {
@@ -608,18 +719,19 @@ impl<T: Config> Pallet<T> {
 			return Err(())
 		}
 
-		T::XcmpQueue::enqueue_message(xcm.as_bounded_slice(), sender);
+		T::XcmpQueue::enqueue_message(xcm, sender);
 		Ok(())
 	}
 
 	/// Split concatenated encoded `VersionedXcm`s or `MaybeDoubleEncodedVersionedXcm`s into
 	/// individual items.
 	///
-	/// We directly encode them again since that is needed later on.
-	pub(crate) fn take_first_concatenated_xcm(
-		data: &mut &[u8],
+	/// The XCM is only decoded to find its end; the returned item references its encoding in
+	/// `data` as it was received.
+	pub(crate) fn take_first_concatenated_xcm<'a>(
+		data: &mut &'a [u8],
 		meter: &mut WeightMeter,
-	) -> Result<BoundedVec<u8, MaxXcmpMessageLenOf<T>>, ()> {
+	) -> Result<BoundedSlice<'a, u8, MaxXcmpMessageLenOf<T>>, ()> {
 		if data.is_empty() {
 			return Err(())
 		}
@@ -629,9 +741,56 @@ impl<T: Config> Pallet<T> {
 			return Err(())
 		}
 
-		let xcm = VersionedXcm::<()>::decode_with_depth_limit(MAX_XCM_DECODE_DEPTH, data)
-			.map_err(|_| ())?;
-		xcm.encode().try_into().map_err(|_| ())
+		let start = *data;
+		VersionedXcm::<()>::decode_with_depth_limit(MAX_XCM_DECODE_DEPTH, data).map_err(|_| ())?;
+		let encoded = &start[..start.len() - data.len()];
+		BoundedSlice::try_from(encoded).map_err(|_| ())
+	}
+
+	/// Enqueue the concatenated XCMs of an inbound page of `sender`.
+	///
+	/// The page is enqueued as one message without decoding its XCMs. A page that exceeds the
+	/// maximal message length of the `XcmpQueue` is split into the longest runs of whole XCMs that
+	/// fit into it.
+	pub(crate) fn enqueue_page(
+		sender: ParaId,
+		mut data: &[u8],
+		meter: &mut WeightMeter,
+	) -> Result<(), ()> {
+		if data.is_empty() {
+			return Ok(())
+		}
+		if let Ok(page) = BoundedSlice::try_from(data) {
+			return Self::enqueue_xcmp_message(sender, page, meter)
+		}
+
+		while !data.is_empty() {
+			let xcms = Self::take_concatenated_xcms(&mut data, meter)?;
+			Self::enqueue_xcmp_message(sender, xcms, meter)?;
+		}
+
+		Ok(())
+	}
+
+	/// Take the longest run of concatenated XCMs from `data` that fits into one message of the
+	/// `XcmpQueue`.
+	pub(crate) fn take_concatenated_xcms<'a>(
+		data: &mut &'a [u8],
+		meter: &mut WeightMeter,
+	) -> Result<BoundedSlice<'a, u8, MaxXcmpMessageLenOf<T>>, ()> {
+		let start = *data;
+		let max_len = MaxXcmpMessageLenOf::<T>::get() as usize;
+		let mut len = 0;
+		while len < start.len() {
+			let xcm = Self::take_first_concatenated_xcm(&mut &start[len..], meter)?;
+			if len.saturating_add(xcm.len()) > max_len {
+				break
+			}
+			len.saturating_accrue(xcm.len());
+		}
+
+		*data = &start[len..];
+		BoundedSlice::try_from(&start[..len]).map_err(|_| ())
 	}
 
 	/// The worst-case weight of `on_idle`.
@@ -640,6 +799,42 @@ impl<T: Config> Pallet<T> {
 			.max(<T as crate::Config>::WeightInfo::on_idle_large_msg())
 	}
 
+	/// The queued outbound and inbound pages of every non-empty XCMP channel, ordered by the
+	/// sibling `ParaId`.
+	///
+	/// The inbound pages are read from the footprint of the `XcmpQueue`. They are only reported
+	/// for siblings with queued outbound pages or a suspended inbound channel.
+	pub fn queue_depths() -> Vec<ChannelQueueDepth> {
+		let mut channels = BTreeMap::<ParaId, ChannelQueueDepth>::new();
+		for details in <OutboundXcmpStatus<T>>::get() {
+			if details.last_index <= details.first_index {
+				continue
+			}
+
+			let outbound_bytes = (details.first_index..details.last_index)
+				.filter_map(|index| OutboundXcmpMessages::<T>::decode_len(details.recipient, index))
+				.fold(0u32, |total, len| total.saturating_add(len as u32));
+			let channel = channels.entry(details.recipient).or_default();
+			channel.outbound_pages = (details.last_index - details.first_index) as u32;
+			channel.outbound_bytes = outbound_bytes;
+		}
+		let mut siblings = channels.keys().copied().collect::<BTreeSet<_>>();
+		siblings.extend(<InboundXcmpSuspended<T>>::get());
+		for sibling in siblings {
+			let fp = T::XcmpQueue::footprint(sibling);
+			if fp.storage.count > 0 {
+				let channel = channels.entry(sibling).or_default();
+				channel.inbound_pages = fp.storage.count.saturated_into();
+				channel.inbound_bytes = fp.storage.size.saturated_into();
+			}
+		}
+
+		channels
+			.into_iter()
+			.map(|(sibling, channel)| ChannelQueueDepth { sibling, ..channel })
+			.collect()
+	}
+
 	#[cfg(feature = "bridging")]
 	fn is_inbound_channel_suspended(sender: ParaId) -> bool {
 		<InboundXcmpSuspended<T>>::get().iter().any(|c| c == &sender)
@@ -738,20 +933,11 @@ impl<T: Config> XcmpMessageHandler for Pallet<T> {
 						}
 					},
 				XcmpMessageFormat::ConcatenatedVersionedXcm =>
-					while !data.is_empty() {
-						let Ok(xcm) = Self::take_first_concatenated_xcm(&mut data, &mut meter)
-						else {
-							defensive!("HRMP inbound decode stream broke; page will be dropped.",);
-							break
-						};
-
-						if let Err(()) = Self::enqueue_xcmp_message(sender, xcm, &mut meter) {
-							defensive!(
-								"Could not enqueue XCMP messages. Used weight: ",
-								meter.consumed_ratio()
-							);
-							break
-						}
+					if let Err(()) = Self::enqueue_page(sender, data, &mut meter) {
+						defensive!(
+							"Could not enqueue XCMP page; dropping it. Used weight: ",
+							meter.consumed_ratio()
+						);
 					},
 				XcmpMessageFormat::ConcatenatedEncodedBlob => {
 					defensive!("Blob messages are unhandled - dropping");
@@ -980,3 +1166,11 @@ impl<T: Config> FeeTracker for Pallet<T> {
 		})
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// API for monitoring the XCMP queues of `cumulus-pallet-xcmp-queue`.
+	pub trait XcmpQueueApi {
+		/// Returns the queued outbound and inbound pages of every non-empty XCMP channel.
+		fn queue_depths() -> Vec<ChannelQueueDepth>;
+	}
+}
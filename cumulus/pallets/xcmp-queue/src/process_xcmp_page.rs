@@ -0,0 +1,187 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Implementation of `ProcessMessage` for pages of concatenated XCMs.
+
+use codec::{DecodeLimit, FullCodec, MaxEncodedLen};
+use frame_support::{
+	traits::{Get, ProcessMessage, ProcessMessageError},
+	weights::{Weight, WeightMeter},
+};
+use scale_info::TypeInfo;
+use sp_runtime::Saturating;
+use sp_std::{fmt::Debug, marker::PhantomData};
+use xcm::{prelude::*, MAX_XCM_DECODE_DEPTH};
+
+use crate::{
+	Config, InboundDecodeWeight, InboundXcmpPageCursors, PageCursor, WeightInfo, LOG_TARGET,
+};
+
+/// A message processor that executes the concatenated `VersionedXcm`s of a message with an
+/// `XcmExecutor`.
+///
+/// This processes the inbound XCMP pages that are enqueued by this pallet without being decoded.
+/// A message with a single XCM, like a downward or upward message, is processed like
+/// `xcm_builder::ProcessXcmMessage` does.
+///
+/// The XCMs of a message are decoded and executed one by one. Decoding an XCM is charged with
+/// the `take_first_concatenated_xcm` weight, both to the weight of the message and to the
+/// `MaxInboundDecodeWeight` of the block. Once an XCM does not fit into the remaining weight, the
+/// progress is stored in [`InboundXcmpPageCursors`] and the message reports the weight of that XCM
+/// as overweight; the message queue then continues the message with this XCM in a later block or
+/// with `execute_overweight`. The processing yields once the decode budget of the block is
+/// exhausted.
+///
+/// An XCM that cannot be prepared or fails to execute does not stop the execution of the other
+/// XCMs of the message; the message is reported as not successful in that case.
+pub struct ProcessXcmpPage<T, MessageOrigin, XcmExecutor, Call>(
+	PhantomData<(T, MessageOrigin, XcmExecutor, Call)>,
+);
+impl<
+		T: Config,
+		MessageOrigin: Into<MultiLocation> + FullCodec + MaxEncodedLen + Clone + Eq + PartialEq + TypeInfo + Debug,
+		XcmExecutor: ExecuteXcm<Call>,
+		Call,
+	> ProcessMessage for ProcessXcmpPage<T, MessageOrigin, XcmExecutor, Call>
+{
+	type Origin = MessageOrigin;
+
+	/// Process the XCMs of the given message, using no more than the remaining `weight` to do so.
+	fn process_message(
+		message: &[u8],
+		origin: Self::Origin,
+		meter: &mut WeightMeter,
+		id: &mut XcmHash,
+	) -> Result<bool, ProcessMessageError> {
+		// The cursor and the decode weight of the block are read and written.
+		let storage_weight = T::DbWeight::get().reads_writes(2, 2);
+		if meter.try_consume(storage_weight).is_err() {
+			return Err(ProcessMessageError::Overweight(storage_weight))
+		}
+
+		let page_id = *id;
+		let mut cursor = InboundXcmpPageCursors::<T>::get(page_id).unwrap_or_default();
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let (block, mut decoded) = InboundDecodeWeight::<T>::get();
+		if block != now {
+			decoded = Weight::zero();
+		}
+		let mut budget = WeightMeter::with_limit(T::MaxInboundDecodeWeight::get());
+		budget.consume(decoded);
+
+		let result = Self::process_xcms(message, origin, meter, id, &mut cursor, &mut budget);
+
+		InboundDecodeWeight::<T>::put((now, budget.consumed()));
+		match result {
+			Err(ProcessMessageError::Overweight(_) | ProcessMessageError::Yield)
+				if cursor.offset > 0 =>
+				InboundXcmpPageCursors::<T>::insert(page_id, cursor),
+			_ => InboundXcmpPageCursors::<T>::remove(page_id),
+		}
+
+		result
+	}
+}
+
+impl<
+		T: Config,
+		MessageOrigin: Into<MultiLocation> + FullCodec + MaxEncodedLen + Clone + Eq + PartialEq + TypeInfo + Debug,
+		XcmExecutor: ExecuteXcm<Call>,
+		Call,
+	> ProcessXcmpPage<T, MessageOrigin, XcmExecutor, Call>
+{
+	/// Decode and execute the XCMs of `message` that follow the `cursor`, advancing it past
+	/// every XCM that was processed.
+	fn process_xcms(
+		message: &[u8],
+		origin: MessageOrigin,
+		meter: &mut WeightMeter,
+		id: &mut XcmHash,
+		cursor: &mut PageCursor,
+		budget: &mut WeightMeter,
+	) -> Result<bool, ProcessMessageError> {
+		let mut data = message.get(cursor.offset as usize..).ok_or(ProcessMessageError::Corrupt)?;
+		if data.is_empty() {
+			return Err(ProcessMessageError::Corrupt)
+		}
+
+		let decode_weight = T::WeightInfo::take_first_concatenated_xcm();
+		while !data.is_empty() {
+			if !budget.can_consume(decode_weight) {
+				log::debug!(target: LOG_TARGET, "Decode budget of the block is exhausted");
+				return Err(ProcessMessageError::Yield)
+			}
+			if !meter.can_consume(decode_weight) {
+				return Err(ProcessMessageError::Overweight(decode_weight))
+			}
+
+			let start = data;
+			let xcm =
+				VersionedXcm::<Call>::decode_with_depth_limit(MAX_XCM_DECODE_DEPTH, &mut data)
+					.map_err(|_| ProcessMessageError::Corrupt)?;
+			budget.consume(decode_weight);
+			meter.consume(decode_weight);
+
+			let encoded = &start[..start.len() - data.len()];
+			let single = cursor.offset == 0 && data.is_empty();
+			let pre = Xcm::<Call>::try_from(xcm)
+				.map_err(|_| ())
+				.and_then(|xcm| XcmExecutor::prepare(xcm).map_err(|_| ()));
+			let pre = match pre {
+				Ok(pre) => pre,
+				Err(()) if single => return Err(ProcessMessageError::Unsupported),
+				Err(()) => {
+					log::debug!(target: LOG_TARGET, "Skipping unsupported XCM of page");
+					cursor.offset.saturating_accrue(encoded.len() as u32);
+					cursor.failed = true;
+					continue
+				},
+			};
+
+			let required = pre.weight_of();
+			if !meter.can_consume(required) {
+				return Err(ProcessMessageError::Overweight(required))
+			}
+
+			let mut xcm_id = if single { *id } else { sp_io::hashing::blake2_256(encoded) };
+			let consumed =
+				match XcmExecutor::execute(origin.clone(), pre, &mut xcm_id, Weight::zero()) {
+					Outcome::Complete(w) => w,
+					Outcome::Incomplete(w, _) => {
+						cursor.failed = true;
+						w
+					},
+					// In the error-case we assume the worst case and consume all possible weight.
+					Outcome::Error(_) if single => {
+						meter.consume(required);
+						return Err(ProcessMessageError::Unsupported)
+					},
+					Outcome::Error(_) => {
+						cursor.failed = true;
+						required
+					},
+				};
+			meter.consume(consumed);
+			cursor.offset.saturating_accrue(encoded.len() as u32);
+			if single {
+				*id = xcm_id;
+			}
+		}
+
+		Ok(!cursor.failed)
+	}
+}
@@ -22,7 +22,8 @@ use XcmpMessageFormat::*;
 use codec::Input;
 use cumulus_primitives_core::{ParaId, XcmpMessageHandler};
 use frame_support::{
-	assert_err, assert_noop, assert_ok, assert_storage_noop, hypothetically, traits::Hooks,
+	assert_err, assert_noop, assert_ok, assert_storage_noop, hypothetically,
+	traits::{Hooks, ProcessMessage, ProcessMessageError},
 	StorageNoopGuard,
 };
 use mock::{new_test_ext, ParachainSystem, RuntimeOrigin as Origin, Test, XcmpQueue};
@@ -63,10 +64,8 @@ fn xcm_enqueueing_many_works() {
 
 		XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, data.as_slice())), Weight::MAX);
 
-		assert_eq!(
-			EnqueuedMessages::get(),
-			encoded_xcms.into_iter().map(|xcm| (1000.into(), xcm)).collect::<Vec<_>>(),
-		);
+		// The page is enqueued as a whole:
+		assert_eq!(EnqueuedMessages::get(), vec![(1000.into(), encoded_xcms.concat())]);
 	})
 }
 
@@ -83,23 +82,15 @@ fn xcm_enqueueing_multiple_times_works() {
 
 		for i in 0..10 {
 			XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, data.as_slice())), Weight::MAX);
-			assert_eq!((i + 1) * 10, EnqueuedMessages::get().len());
+			assert_eq!(i + 1, EnqueuedMessages::get().len());
 		}
 
-		assert_eq!(
-			EnqueuedMessages::get(),
-			encoded_xcms
-				.into_iter()
-				.map(|xcm| (1000.into(), xcm))
-				.cycle()
-				.take(100)
-				.collect::<Vec<_>>(),
-		);
+		assert_eq!(EnqueuedMessages::get(), vec![(1000.into(), encoded_xcms.concat()); 10]);
 	})
 }
 
 #[test]
-#[cfg_attr(debug_assertions, should_panic = "Could not enqueue XCMP messages.")]
+#[cfg_attr(debug_assertions, should_panic = "Could not enqueue XCMP page")]
 fn xcm_enqueueing_starts_dropping_on_overflow() {
 	new_test_ext().execute_with(|| {
 		let xcm = VersionedXcm::<Test>::from(Xcm::<Test>(vec![ClearOrigin]));
@@ -114,56 +105,58 @@ fn xcm_enqueueing_starts_dropping_on_overflow() {
 	})
 }
 
-/// First enqueue 10 good, 1 bad and then 10 good XCMs.
-///
-/// It should only process the first 10 good though.
+/// Pages are enqueued without decoding their XCMs; a broken page is only detected once it is
+/// processed.
 #[test]
-#[cfg(not(debug_assertions))]
-fn xcm_enqueueing_broken_xcm_works() {
+fn xcm_enqueueing_does_not_decode_pages() {
 	new_test_ext().execute_with(|| {
-		let mut encoded_xcms = vec![];
-		for _ in 0..10 {
-			let xcm = VersionedXcm::<Test>::from(Xcm::<Test>(vec![ClearOrigin]));
-			encoded_xcms.push(xcm.encode());
-		}
-		let mut good = ConcatenatedVersionedXcm.encode();
-		good.extend(encoded_xcms.iter().flatten());
-
-		let mut bad = ConcatenatedVersionedXcm.encode();
-		bad.extend(vec![0u8].into_iter());
+		let xcm = VersionedXcm::<Test>::from(Xcm::<Test>(vec![ClearOrigin])).encode();
+		let good = [ConcatenatedVersionedXcm.encode(), xcm.repeat(10)].concat();
+		let bad = [ConcatenatedVersionedXcm.encode(), vec![0u8]].concat();
 
-		// Of we enqueue them in multiple pages, then its fine.
-		XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, good.as_slice())), Weight::MAX);
-		XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, bad.as_slice())), Weight::MAX);
-		XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, good.as_slice())), Weight::MAX);
-		assert_eq!(20, EnqueuedMessages::get().len());
+		XcmpQueue::handle_xcmp_messages(
+			vec![
+				(1000.into(), 1, good.as_slice()),
+				(1000.into(), 1, bad.as_slice()),
+				(1000.into(), 1, good.as_slice()),
+			]
+			.into_iter(),
+			Weight::MAX,
+		);
 
 		assert_eq!(
 			EnqueuedMessages::get(),
-			encoded_xcms
-				.clone()
-				.into_iter()
-				.map(|xcm| (1000.into(), xcm))
-				.cycle()
-				.take(20)
-				.collect::<Vec<_>>(),
+			vec![
+				(1000.into(), xcm.repeat(10)),
+				(1000.into(), vec![0u8]),
+				(1000.into(), xcm.repeat(10))
+			],
 		);
-		EnqueuedMessages::take();
+	})
+}
+
+/// Pages that exceed the maximal message length of the queue are split into runs of whole XCMs.
+#[test]
+fn xcm_enqueueing_splits_oversized_pages() {
+	new_test_ext().execute_with(|| {
+		let xcm = VersionedXcm::<Test>::from(Xcm::<Test>(vec![Transact {
+			origin_kind: OriginKind::Native,
+			require_weight_at_most: Weight::zero(),
+			call: vec![0u8; 10_000].into(),
+		}]))
+		.encode();
+		let data = [ConcatenatedVersionedXcm.encode(), xcm.repeat(10)].concat();
+		// Six of the XCMs fit into one message.
+		assert!(xcm.len() * 6 <= MaxXcmpMessageLenOf::<Test>::get() as usize);
+		assert!(xcm.len() * 7 > MaxXcmpMessageLenOf::<Test>::get() as usize);
+		// The mocked queue counts a page per 16 bytes.
+		QueueConfig::<Test>::mutate(|config| config.drop_threshold = u32::MAX);
+
+		XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, data.as_slice())), Weight::MAX);
 
-		// But if we do it all in one page, then it only uses the first 10:
-		XcmpQueue::handle_xcmp_messages(
-			vec![(1000.into(), 1, good.as_slice()), (1000.into(), 1, bad.as_slice())].into_iter(),
-			Weight::MAX,
-		);
-		assert_eq!(10, EnqueuedMessages::get().len());
 		assert_eq!(
 			EnqueuedMessages::get(),
-			encoded_xcms
-				.into_iter()
-				.map(|xcm| (1000.into(), xcm))
-				.cycle()
-				.take(10)
-				.collect::<Vec<_>>(),
+			vec![(1000.into(), xcm.repeat(6)), (1000.into(), xcm.repeat(4))],
 		);
 	})
 }
@@ -194,13 +187,18 @@ fn bad_blob_message_no_panic() {
 	});
 }
 
-/// Invalid concatenated XCMs panic in debug mode.
+/// Invalid concatenated XCMs of a page that has to be split panic in debug mode.
 #[test]
-#[should_panic = "HRMP inbound decode stream broke; page will be dropped."]
+#[should_panic = "Could not enqueue XCMP page"]
 #[cfg(debug_assertions)]
 fn handle_invalid_data_panics() {
 	new_test_ext().execute_with(|| {
-		let data = [ConcatenatedVersionedXcm.encode(), Xcm::<Test>(vec![]).encode()].concat();
+		// The page is too large to be enqueued as a whole and has to be split.
+		let data = [
+			ConcatenatedVersionedXcm.encode(),
+			vec![0u8; MaxXcmpMessageLenOf::<Test>::get() as usize + 1],
+		]
+		.concat();
 
 		XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, data.as_slice())), Weight::MAX);
 	});
@@ -211,7 +209,12 @@ fn handle_invalid_data_panics() {
 #[cfg(not(debug_assertions))]
 fn handle_invalid_data_no_panic() {
 	new_test_ext().execute_with(|| {
-		let data = [ConcatenatedVersionedXcm.encode(), Xcm::<Test>(vec![]).encode()].concat();
+		// The page is too large to be enqueued as a whole and has to be split.
+		let data = [
+			ConcatenatedVersionedXcm.encode(),
+			vec![0u8; MaxXcmpMessageLenOf::<Test>::get() as usize + 1],
+		]
+		.concat();
 
 		frame_support::assert_storage_noop!(XcmpQueue::handle_xcmp_messages(
 			once((1000.into(), 1, data.as_slice())),
@@ -570,7 +573,7 @@ fn take_first_concatenated_xcm_works() {
 
 	for i in 0..100 {
 		let xcm = XcmpQueue::take_first_concatenated_xcm(input, &mut WeightMeter::new()).unwrap();
-		match (i % 2, xcm) {
+		match (i % 2, xcm.to_vec()) {
 			(0, data) | (2, data) => {
 				assert_eq!(data, v2_xcm().encode());
 			},
@@ -607,7 +610,8 @@ fn take_first_concatenated_xcm_good_bad_depth_errors() {
 
 	let page = bad.encode();
 	assert_err!(
-		XcmpQueue::take_first_concatenated_xcm(&mut &page[..], &mut WeightMeter::new()),
+		XcmpQueue::take_first_concatenated_xcm(&mut &page[..], &mut WeightMeter::new())
+			.map(|xcm| xcm.to_vec()),
 		()
 	);
 }
@@ -844,3 +848,259 @@ fn verify_fee_factor_increase_and_decrease() {
 		assert!(DeliveryFeeFactor::<Test>::get(sibling_para_id) < FixedU128::from_float(1.63));
 	});
 }
+
+#[test]
+fn queue_depth_is_reported_periodically() {
+	use cumulus_primitives_core::AbridgedHrmpChannel;
+
+	let sibling_para_id = ParaId::from(12345);
+	let destination = (Parent, Parachain(sibling_para_id.into())).into();
+	let xcm = Xcm(vec![ClearOrigin; 100]);
+
+	new_test_ext().execute_with(|| {
+		ParachainSystem::open_custom_outbound_hrmp_channel_for_benchmarks_or_tests(
+			sibling_para_id,
+			AbridgedHrmpChannel {
+				max_capacity: 10,
+				max_total_size: 1000,
+				max_message_size: 104,
+				msg_count: 0,
+				total_size: 0,
+				mqc_head: None,
+			},
+		);
+		assert!(XcmpQueue::queue_depths().is_empty());
+
+		// Every message ends up on its own page of 104 bytes.
+		assert_ok!(send_xcm::<XcmpQueue>(destination, xcm.clone()));
+		assert_ok!(send_xcm::<XcmpQueue>(destination, xcm.clone()));
+		assert_ok!(send_xcm::<XcmpQueue>(destination, xcm));
+		// Inbound pages are only reported for siblings with outbound pages or a suspended
+		// inbound channel.
+		InboundXcmpSuspended::<Test>::put(
+			BoundedBTreeSet::try_from(BTreeSet::from([ParaId::from(2000)])).unwrap(),
+		);
+		EnqueuedMessages::set(vec![
+			(2000.into(), vec![0u8; 100]),
+			(2000.into(), vec![0u8; 40]),
+			(sibling_para_id, vec![0u8; 10]),
+			(3000.into(), vec![0u8; 5]),
+		]);
+
+		let expected = vec![
+			ChannelQueueDepth {
+				sibling: 2000.into(),
+				outbound_pages: 0,
+				outbound_bytes: 0,
+				inbound_pages: 2,
+				inbound_bytes: 140,
+			},
+			ChannelQueueDepth {
+				sibling: sibling_para_id,
+				outbound_pages: 3,
+				outbound_bytes: 312,
+				inbound_pages: 1,
+				inbound_bytes: 10,
+			},
+		];
+		assert_eq!(XcmpQueue::queue_depths(), expected);
+
+		// No reports by default.
+		mock::System::set_block_number(2);
+		mock::System::reset_events();
+		XcmpQueue::on_initialize(2);
+		assert!(mock::System::events().is_empty());
+
+		assert_noop!(
+			XcmpQueue::update_queue_depth_report_interval(Origin::signed(2), 2),
+			BadOrigin
+		);
+		assert_ok!(XcmpQueue::update_queue_depth_report_interval(Origin::root(), 2));
+
+		mock::System::set_block_number(3);
+		XcmpQueue::on_initialize(3);
+		assert!(mock::System::events().is_empty());
+
+		mock::System::set_block_number(4);
+		XcmpQueue::on_initialize(4);
+		mock::System::assert_last_event(Event::QueueDepth { channels: expected }.into());
+	});
+}
+
+/// The processor to use for tests.
+type Processor =
+	ProcessXcmpPage<Test, Junction, xcm_executor::XcmExecutor<mock::XcmConfig>, mock::RuntimeCall>;
+
+/// Concatenate the encoded XCMs into a page.
+fn xcmp_page(xcms: Vec<Xcm<()>>) -> Vec<u8> {
+	xcms.into_iter()
+		.flat_map(|xcm| VersionedXcm::<()>::from(xcm).encode())
+		.collect()
+}
+
+/// Process the page with the id that the message queue assigns to it.
+fn process_xcmp_page(page: &[u8], meter: &mut WeightMeter) -> Result<bool, ProcessMessageError> {
+	Processor::process_message(page, Parachain(1000), meter, &mut sp_io::hashing::blake2_256(page))
+}
+
+/// The weight of decoding one XCM of a page.
+fn decode_weight() -> Weight {
+	<() as WeightInfo>::take_first_concatenated_xcm()
+}
+
+#[test]
+fn process_xcmp_page_executes_all_xcms() {
+	new_test_ext().execute_with(|| {
+		let page = xcmp_page(vec![Xcm(vec![ClearOrigin]), Xcm(vec![ClearOrigin; 2])]);
+		let meter = &mut WeightMeter::new();
+
+		assert_eq!(process_xcmp_page(&page, meter), Ok(true));
+		assert_eq!(
+			meter.consumed(),
+			mock::UnitWeightCost::get().saturating_mul(3) + decode_weight().saturating_mul(2)
+		);
+		assert_eq!(InboundXcmpPageCursors::<Test>::iter().count(), 0);
+	})
+}
+
+#[test]
+fn process_xcmp_page_overweight_first_xcm_executes_nothing() {
+	new_test_ext().execute_with(|| {
+		let page = xcmp_page(vec![Xcm(vec![ClearOrigin; 2]), Xcm(vec![ClearOrigin])]);
+		let required = mock::UnitWeightCost::get().saturating_mul(2);
+		let meter = &mut WeightMeter::with_limit(mock::UnitWeightCost::get() + decode_weight());
+
+		assert_eq!(process_xcmp_page(&page, meter), Err(ProcessMessageError::Overweight(required)));
+		// Only the decoding of the first XCM was paid for:
+		assert_eq!(meter.consumed(), decode_weight());
+		assert_eq!(InboundXcmpPageCursors::<Test>::iter().count(), 0);
+	})
+}
+
+#[test]
+fn process_xcmp_page_continues_after_overweight_xcm() {
+	new_test_ext().execute_with(|| {
+		let first = xcmp_page(vec![Xcm(vec![ClearOrigin])]);
+		let page = xcmp_page(vec![Xcm(vec![ClearOrigin]), Xcm(vec![ClearOrigin; 2])]);
+		let page_id = sp_io::hashing::blake2_256(&page);
+		let unit = mock::UnitWeightCost::get();
+
+		// Only the first XCM fits:
+		let meter = &mut WeightMeter::with_limit(
+			unit.saturating_mul(2) + decode_weight().saturating_mul(2),
+		);
+		assert_eq!(
+			process_xcmp_page(&page, meter),
+			Err(ProcessMessageError::Overweight(unit.saturating_mul(2)))
+		);
+		assert_eq!(meter.consumed(), unit + decode_weight().saturating_mul(2));
+		assert_eq!(
+			InboundXcmpPageCursors::<Test>::get(page_id),
+			Some(PageCursor { offset: first.len() as u32, failed: false })
+		);
+
+		// The second XCM is executed once there is enough weight:
+		let meter = &mut WeightMeter::new();
+		assert_eq!(process_xcmp_page(&page, meter), Ok(true));
+		assert_eq!(meter.consumed(), unit.saturating_mul(2) + decode_weight());
+		assert_eq!(InboundXcmpPageCursors::<Test>::get(page_id), None);
+	})
+}
+
+#[test]
+fn process_xcmp_page_respects_decode_budget() {
+	new_test_ext().execute_with(|| {
+		mock::System::set_block_number(1);
+		mock::MaxInboundDecodeWeight::set(decode_weight().saturating_mul(3));
+		let page = xcmp_page(vec![Xcm(vec![ClearOrigin]); 2]);
+		let page_id = sp_io::hashing::blake2_256(&page);
+
+		assert_eq!(process_xcmp_page(&page, &mut WeightMeter::new()), Ok(true));
+		// The budget of the block only allows to decode one more XCM:
+		assert_eq!(
+			process_xcmp_page(&page, &mut WeightMeter::new()),
+			Err(ProcessMessageError::Yield)
+		);
+		let cursor = InboundXcmpPageCursors::<Test>::get(page_id).unwrap();
+		assert_eq!(cursor.offset as usize, page.len() / 2);
+		assert_eq!(
+			process_xcmp_page(&page, &mut WeightMeter::new()),
+			Err(ProcessMessageError::Yield)
+		);
+		assert_eq!(InboundXcmpPageCursors::<Test>::get(page_id), Some(cursor));
+		assert_eq!(InboundDecodeWeight::<Test>::get(), (1, decode_weight().saturating_mul(3)));
+
+		// The budget is renewed in the next block:
+		mock::System::set_block_number(2);
+		let meter = &mut WeightMeter::new();
+		assert_eq!(process_xcmp_page(&page, meter), Ok(true));
+		assert_eq!(meter.consumed(), mock::UnitWeightCost::get() + decode_weight());
+		assert_eq!(InboundXcmpPageCursors::<Test>::get(page_id), None);
+		assert_eq!(InboundDecodeWeight::<Test>::get(), (2, decode_weight()));
+	})
+}
+
+#[test]
+fn process_xcmp_page_corrupted_fails() {
+	new_test_ext().execute_with(|| {
+		for page in [vec![], vec![0u8]] {
+			let meter = &mut WeightMeter::new();
+			assert_eq!(process_xcmp_page(&page, meter), Err(ProcessMessageError::Corrupt));
+			assert!(meter.consumed().is_zero());
+		}
+
+		// The XCMs before the corruption are executed:
+		let good = xcmp_page(vec![Xcm(vec![ClearOrigin])]);
+		let meter = &mut WeightMeter::new();
+		assert_eq!(
+			process_xcmp_page(&[good, vec![0u8]].concat(), meter),
+			Err(ProcessMessageError::Corrupt)
+		);
+		assert_eq!(meter.consumed(), mock::UnitWeightCost::get() + decode_weight());
+		assert_eq!(InboundXcmpPageCursors::<Test>::iter().count(), 0);
+	})
+}
+
+#[test]
+fn process_xcmp_page_failed_xcms_do_not_stop_the_page() {
+	new_test_ext().execute_with(|| {
+		// Exceeds the `MaxInstructions` of the weigher.
+		let unsupported = Xcm(vec![SetAppendix(Xcm(vec![ClearOrigin; 99])); 2]);
+
+		// A page with a single XCM fails like a single message:
+		let page = xcmp_page(vec![unsupported.clone()]);
+		assert_eq!(
+			process_xcmp_page(&page, &mut WeightMeter::new()),
+			Err(ProcessMessageError::Unsupported)
+		);
+
+		let page = xcmp_page(vec![
+			Xcm(vec![ClearOrigin]),
+			unsupported,
+			Xcm(vec![Trap(1)]),
+			Xcm(vec![ClearOrigin]),
+		]);
+		let meter = &mut WeightMeter::new();
+		assert_eq!(process_xcmp_page(&page, meter), Ok(false));
+		assert!(meter.consumed().all_gte(mock::UnitWeightCost::get().saturating_mul(2)));
+	})
+}
+
+#[test]
+fn process_xcmp_page_remembers_failures_across_blocks() {
+	new_test_ext().execute_with(|| {
+		let page = xcmp_page(vec![Xcm(vec![Trap(1)]), Xcm(vec![ClearOrigin; 2])]);
+		let unit = mock::UnitWeightCost::get();
+
+		let meter = &mut WeightMeter::with_limit(
+			unit.saturating_mul(2) + decode_weight().saturating_mul(2),
+		);
+		assert_eq!(
+			process_xcmp_page(&page, meter),
+			Err(ProcessMessageError::Overweight(unit.saturating_mul(2)))
+		);
+
+		// The trapped XCM of the previous block fails the page:
+		assert_eq!(process_xcmp_page(&page, &mut WeightMeter::new()), Ok(false));
+	})
+}
@@ -12,6 +12,7 @@ use sc_service::config::{BasePath, PrometheusConfig};
 use sp_runtime::traits::AccountIdConversion;
 
 use crate::{
+	benchmarking::{inherent_benchmark_data, RemarkBuilder},
 	chain_spec,
 	cli::{Cli, RelayChainCli, Subcommand},
 	service::new_partial,
@@ -208,6 +209,22 @@ pub fn run() -> Result<()> {
 					let storage = partials.backend.expose_storage();
 					cmd.run(config, partials.client.clone(), db, storage)
 				}),
+				BenchmarkCmd::Overhead(cmd) => runner.sync_run(|config| {
+					let para_id = cmd.params.para_id.ok_or(
+						"Parachain runtimes must be benchmarked with `--para-id` to include the \
+						proof size and the relay parent processing in the weights.",
+					)?;
+					let partials = new_partial(&config)?;
+					let ext_builder = RemarkBuilder::new(partials.client.clone());
+
+					cmd.run(
+						config,
+						partials.client,
+						inherent_benchmark_data(ParaId::from(para_id))?,
+						Vec::new(),
+						&ext_builder,
+					)
+				}),
 				BenchmarkCmd::Machine(cmd) =>
 					runner.sync_run(|config| cmd.run(&config, SUBSTRATE_REFERENCE_HARDWARE.clone())),
 				// NOTE: this allows the Client to leniently implement
@@ -349,6 +366,10 @@ impl CliConfiguration<Self> for RelayChainCli {
 		self.base.base.trie_cache_maximum_size()
 	}
 
+	fn trie_cache_settings(&self) -> Result<sc_service::config::TrieCacheSettings> {
+		self.base.base.trie_cache_settings()
+	}
+
 	fn rpc_methods(&self) -> Result<sc_service::config::RpcMethods> {
 		self.base.base.rpc_methods()
 	}
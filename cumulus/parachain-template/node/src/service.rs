@@ -53,7 +53,7 @@ impl sc_executor::NativeExecutionDispatch for ParachainNativeExecutor {
 
 type ParachainExecutor = NativeElseWasmExecutor<ParachainNativeExecutor>;
 
-type ParachainClient = TFullClient<Block, RuntimeApi, ParachainExecutor>;
+pub type ParachainClient = TFullClient<Block, RuntimeApi, ParachainExecutor>;
 
 type ParachainBackend = TFullBackend<Block>;
 
@@ -390,7 +390,7 @@ fn start_consensus(
 		telemetry.clone(),
 	);
 
-	let proposer = Proposer::new(proposer_factory);
+	let proposer = Proposer::new(proposer_factory).with_adaptive_duration(prometheus_registry);
 
 	let collator_service = CollatorService::new(
 		client.clone(),
@@ -0,0 +1,203 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Consolidated view of an account, assembled from the system pallets of the runtime.
+//!
+//! Wallets need to combine the balances, holds, freezes, locks, proxies, multisig operations and
+//! vesting schedules of an account to present it to the user. The helpers in this module do that
+//! using the same logic as the pallets themselves, so the runtime may expose the result through
+//! the [`AccountSummaryApi`].
+
+use codec::{Codec, Decode, Encode};
+use frame_support::traits::{
+	fungible::Inspect,
+	tokens::{Fortitude, Preservation},
+	StoredMap,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_proxy::ProxyDefinition;
+use pallet_vesting::VestingInfo;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Pending multisig operation that the account participates in.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct MultisigParticipation<AccountId, Balance> {
+	/// The multisig account.
+	pub multisig: AccountId,
+	/// Hash of the call that is being approved.
+	pub call_hash: [u8; 32],
+	/// Whether the account has opened the operation and holds its deposit.
+	pub depositor: bool,
+	/// The deposit held for the operation, if the account is its depositor.
+	pub deposit: Balance,
+	/// Whether the account has approved the operation.
+	pub approved: bool,
+}
+
+/// Consolidated view of an account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct AccountSummary<AccountId, Balance, BlockNumber, HoldReason, FreezeReason, ProxyType> {
+	/// The free balance of the account.
+	pub free: Balance,
+	/// The reserved (including held) balance of the account.
+	pub reserved: Balance,
+	/// The largest freeze or lock placed on the account.
+	pub frozen: Balance,
+	/// The balance that may be transferred out of the account, taking holds, freezes, locks and
+	/// the existential deposit into account.
+	pub transferable: Balance,
+	/// Active holds with their reasons.
+	pub holds: Vec<(HoldReason, Balance)>,
+	/// Active freezes with their reasons.
+	pub freezes: Vec<(FreezeReason, Balance)>,
+	/// Active (legacy) locks with their identifiers.
+	pub locks: Vec<([u8; 8], Balance)>,
+	/// Accounts that may act on behalf of the account.
+	pub proxies: Vec<ProxyDefinition<AccountId, ProxyType, BlockNumber>>,
+	/// Pending multisig operations that the account has opened or approved.
+	pub multisigs: Vec<MultisigParticipation<AccountId, Balance>>,
+	/// Scheduled vesting of the account.
+	pub vesting: Vec<VestingInfo<Balance, BlockNumber>>,
+}
+
+impl<AccountId, Balance: Default, BlockNumber, HoldReason, FreezeReason, ProxyType> Default
+	for AccountSummary<AccountId, Balance, BlockNumber, HoldReason, FreezeReason, ProxyType>
+{
+	fn default() -> Self {
+		Self {
+			free: Default::default(),
+			reserved: Default::default(),
+			frozen: Default::default(),
+			transferable: Default::default(),
+			holds: Vec::new(),
+			freezes: Vec::new(),
+			locks: Vec::new(),
+			proxies: Vec::new(),
+			multisigs: Vec::new(),
+			vesting: Vec::new(),
+		}
+	}
+}
+
+/// [`AccountSummary`] of the runtime, using the native `Balances` instance.
+pub type AccountSummaryOf<Runtime, ProxyType> = AccountSummary<
+	<Runtime as frame_system::Config>::AccountId,
+	<Runtime as pallet_balances::Config>::Balance,
+	BlockNumberFor<Runtime>,
+	<Runtime as pallet_balances::Config>::RuntimeHoldReason,
+	<Runtime as pallet_balances::Config>::FreezeIdentifier,
+	ProxyType,
+>;
+
+/// Fill balances, holds, freezes and locks of `who` from the `pallet-balances` instance `I`.
+pub fn fill_balances<Runtime, I, ProxyType>(
+	who: &Runtime::AccountId,
+	summary: &mut AccountSummary<
+		Runtime::AccountId,
+		Runtime::Balance,
+		BlockNumberFor<Runtime>,
+		Runtime::RuntimeHoldReason,
+		Runtime::FreezeIdentifier,
+		ProxyType,
+	>,
+) where
+	Runtime: pallet_balances::Config<I>,
+	I: 'static,
+{
+	let account = <Runtime as pallet_balances::Config<I>>::AccountStore::get(who);
+	summary.free = account.free;
+	summary.reserved = account.reserved;
+	summary.frozen = account.frozen;
+	summary.transferable = <pallet_balances::Pallet<Runtime, I> as Inspect<_>>::reducible_balance(
+		who,
+		Preservation::Expendable,
+		Fortitude::Polite,
+	);
+	summary.holds = pallet_balances::Holds::<Runtime, I>::get(who)
+		.into_iter()
+		.map(|hold| (hold.id, hold.amount))
+		.collect();
+	summary.freezes = pallet_balances::Freezes::<Runtime, I>::get(who)
+		.into_iter()
+		.map(|freeze| (freeze.id, freeze.amount))
+		.collect();
+	summary.locks = pallet_balances::Locks::<Runtime, I>::get(who)
+		.into_iter()
+		.map(|lock| (lock.id, lock.amount))
+		.collect();
+}
+
+/// Return proxies that may act on behalf of `who`.
+pub fn proxies<Runtime: pallet_proxy::Config>(
+	who: &Runtime::AccountId,
+) -> Vec<ProxyDefinition<Runtime::AccountId, Runtime::ProxyType, BlockNumberFor<Runtime>>> {
+	pallet_proxy::Proxies::<Runtime>::get(who).0.into_inner()
+}
+
+/// Return pending multisig operations that `who` has opened or approved.
+///
+/// Operations are not indexed by their signatories, so this iterates over all of them and must
+/// only be used off-chain.
+pub fn multisig_participations<Runtime: pallet_multisig::Config>(
+	who: &Runtime::AccountId,
+) -> Vec<MultisigParticipation<Runtime::AccountId, pallet_multisig::BalanceOf<Runtime>>> {
+	pallet_multisig::Multisigs::<Runtime>::iter()
+		.filter_map(|(multisig, call_hash, operation)| {
+			let depositor = operation.depositor() == who;
+			let approved = operation.approvals().contains(who);
+			if !depositor && !approved {
+				return None
+			}
+
+			Some(MultisigParticipation {
+				multisig,
+				call_hash,
+				depositor,
+				deposit: if depositor { operation.deposit() } else { Default::default() },
+				approved,
+			})
+		})
+		.collect()
+}
+
+/// Return vesting schedules of `who`.
+pub fn vesting_schedules<Runtime: pallet_vesting::Config>(
+	who: &Runtime::AccountId,
+) -> Vec<VestingInfo<pallet_vesting::BalanceOf<Runtime>, BlockNumberFor<Runtime>>> {
+	pallet_vesting::Vesting::<Runtime>::get(who)
+		.map(|s| s.into_inner())
+		.unwrap_or_default()
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API for querying a consolidated view of an account.
+	pub trait AccountSummaryApi<AccountId, Balance, BlockNumber, HoldReason, FreezeReason, ProxyType>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+		HoldReason: Codec,
+		FreezeReason: Codec,
+		ProxyType: Codec,
+	{
+		/// Returns balances, holds, freezes, locks, proxies, multisig operations and vesting
+		/// schedules of `who`, as far as the corresponding pallets are configured in the runtime.
+		fn account_summary(
+			who: AccountId,
+		) -> AccountSummary<AccountId, Balance, BlockNumber, HoldReason, FreezeReason, ProxyType>;
+	}
+}
@@ -15,6 +15,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod account_summary;
 pub mod impls;
 pub mod kusama;
 pub mod message_queue;
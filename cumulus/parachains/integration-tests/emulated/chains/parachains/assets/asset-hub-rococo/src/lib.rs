@@ -34,11 +34,6 @@ decl_test_parachains! {
 			asset_hub_rococo_runtime::AuraExt::on_initialize(1);
 		},
 		runtime = asset_hub_rococo_runtime,
-		core = {
-			XcmpMessageHandler: asset_hub_rococo_runtime::XcmpQueue,
-			LocationToAccountId: asset_hub_rococo_runtime::xcm_config::LocationToAccountId,
-			ParachainInfo: asset_hub_rococo_runtime::ParachainInfo,
-		},
 		pallets = {
 			PolkadotXcm: asset_hub_rococo_runtime::PolkadotXcm,
 			Assets: asset_hub_rococo_runtime::Assets,
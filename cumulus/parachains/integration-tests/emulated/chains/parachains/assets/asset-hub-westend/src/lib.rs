@@ -34,11 +34,6 @@ decl_test_parachains! {
 			asset_hub_westend_runtime::AuraExt::on_initialize(1);
 		},
 		runtime = asset_hub_westend_runtime,
-		core = {
-			XcmpMessageHandler: asset_hub_westend_runtime::XcmpQueue,
-			LocationToAccountId: asset_hub_westend_runtime::xcm_config::LocationToAccountId,
-			ParachainInfo: asset_hub_westend_runtime::ParachainInfo,
-		},
 		pallets = {
 			PolkadotXcm: asset_hub_westend_runtime::PolkadotXcm,
 			Balances: asset_hub_westend_runtime::Balances,
@@ -32,11 +32,6 @@ decl_test_parachains! {
 			bridge_hub_westend_runtime::AuraExt::on_initialize(1);
 		},
 		runtime = bridge_hub_westend_runtime,
-		core = {
-			XcmpMessageHandler: bridge_hub_westend_runtime::XcmpQueue,
-			LocationToAccountId: bridge_hub_westend_runtime::xcm_config::LocationToAccountId,
-			ParachainInfo: bridge_hub_westend_runtime::ParachainInfo,
-		},
 		pallets = {
 			PolkadotXcm: bridge_hub_westend_runtime::PolkadotXcm,
 			Balances: bridge_hub_westend_runtime::Balances,
@@ -32,11 +32,6 @@ decl_test_parachains! {
 			collectives_westend_runtime::AuraExt::on_initialize(1);
 		},
 		runtime = collectives_westend_runtime,
-		core = {
-			XcmpMessageHandler: collectives_westend_runtime::XcmpQueue,
-			LocationToAccountId: collectives_westend_runtime::xcm_config::LocationToAccountId,
-			ParachainInfo: collectives_westend_runtime::ParachainInfo,
-		},
 		pallets = {
 			PolkadotXcm: collectives_westend_runtime::PolkadotXcm,
 			Balances: collectives_westend_runtime::Balances,
@@ -36,11 +36,6 @@ decl_test_parachains! {
 			penpal_runtime::AuraExt::on_initialize(1);
 		},
 		runtime = penpal_runtime,
-		core = {
-			XcmpMessageHandler: penpal_runtime::XcmpQueue,
-			LocationToAccountId: penpal_runtime::xcm_config::LocationToAccountId,
-			ParachainInfo: penpal_runtime::ParachainInfo,
-		},
 		pallets = {
 			PolkadotXcm: penpal_runtime::PolkadotXcm,
 			Assets: penpal_runtime::Assets,
@@ -53,11 +48,6 @@ decl_test_parachains! {
 			penpal_runtime::AuraExt::on_initialize(1);
 		},
 		runtime = penpal_runtime,
-		core = {
-			XcmpMessageHandler: penpal_runtime::XcmpQueue,
-			LocationToAccountId: penpal_runtime::xcm_config::LocationToAccountId,
-			ParachainInfo: penpal_runtime::ParachainInfo,
-		},
 		pallets = {
 			PolkadotXcm: penpal_runtime::PolkadotXcm,
 			Assets: penpal_runtime::Assets,
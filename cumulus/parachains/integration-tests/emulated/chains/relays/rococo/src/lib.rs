@@ -27,11 +27,7 @@ decl_test_relay_chains! {
 	#[api_version(10)]
 	pub struct Rococo {
 		genesis = genesis::genesis(),
-		on_init = (),
 		runtime = rococo_runtime,
-		core = {
-			SovereignAccountOf: rococo_runtime::xcm_config::LocationConverter,
-		},
 		pallets = {
 			XcmPallet: rococo_runtime::XcmPallet,
 			Sudo: rococo_runtime::Sudo,
@@ -27,11 +27,7 @@ decl_test_relay_chains! {
 	#[api_version(10)]
 	pub struct Westend {
 		genesis = genesis::genesis(),
-		on_init = (),
 		runtime = westend_runtime,
-		core = {
-			SovereignAccountOf: westend_runtime::xcm_config::LocationConverter,
-		},
 		pallets = {
 			XcmPallet: westend_runtime::XcmPallet,
 			Sudo: westend_runtime::Sudo,
@@ -36,8 +36,9 @@ pub use emulated_integration_tests_common::{
 	impls::Inspect,
 	test_parachain_is_trusted_teleporter,
 	xcm_emulator::{
-		assert_expected_events, bx, helpers::weight_within_threshold, Chain, Parachain as Para,
-		RelayChain as Relay, Test, TestArgs, TestContext, TestExt,
+		assert_expected_events, bx, expected_event, helpers::weight_within_threshold, Chain,
+		ExpectedEffects, Parachain as Para, RelayChain as Relay, Test, TestArgs, TestContext,
+		TestExt,
 	},
 	xcm_helpers::{xcm_transact_paid_execution, xcm_transact_unpaid_execution},
 	PROOF_SIZE_THRESHOLD, REF_TIME_THRESHOLD, XCM_V3,
@@ -14,6 +14,7 @@
 // limitations under the License.
 
 use crate::tests::*;
+use bridge_hub_rococo_runtime::bridge_to_westend_config::XCM_LANE_FOR_ASSET_HUB_ROCOCO_TO_ASSET_HUB_WESTEND;
 
 fn send_asset_from_asset_hub_rococo_to_asset_hub_westend(id: MultiLocation, amount: u128) {
 	let destination = asset_hub_westend_location();
@@ -27,8 +28,33 @@ fn send_asset_from_asset_hub_rococo_to_asset_hub_westend(id: MultiLocation, amou
 
 	// send message over bridge
 	assert_ok!(send_asset_from_asset_hub_rococo(destination, (id, amount)));
-	assert_bridge_hub_rococo_message_accepted(true);
-	assert_bridge_hub_westend_message_received();
+	ExpectedEffects::new()
+		// pay for bridge fees
+		.expect(expected_event!(
+			BridgeHubRococo,
+			RuntimeEvent::Balances(pallet_balances::Event::Withdraw { .. })
+		))
+		// message exported on the lane of the asset hubs
+		.expect(expected_event!(
+			BridgeHubRococo,
+			RuntimeEvent::BridgeWestendMessages(
+				pallet_bridge_messages::Event::MessageAccepted { lane_id, .. }
+			) if *lane_id == XCM_LANE_FOR_ASSET_HUB_ROCOCO_TO_ASSET_HUB_WESTEND
+		))
+		// message processed successfully
+		.expect(expected_event!(
+			BridgeHubRococo,
+			RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed {
+				success: true,
+				..
+			})
+		))
+		// message sent to destination
+		.expect(expected_event!(
+			BridgeHubWestend,
+			RuntimeEvent::XcmpQueue(cumulus_pallet_xcmp_queue::Event::XcmpMessageSent { .. })
+		))
+		.assert();
 }
 
 #[test]
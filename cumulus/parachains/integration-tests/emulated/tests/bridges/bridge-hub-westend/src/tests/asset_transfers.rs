@@ -168,3 +168,160 @@ fn send_rocs_from_asset_hub_westend_to_asset_hub_rococo() {
 	// Reserve balance is reduced by sent amount
 	assert_eq!(rocs_in_reserve_on_ahr_after, rocs_in_reserve_on_ahr_before - amount_to_send);
 }
+
+fn send_asset_from_asset_hub_rococo_to_asset_hub_westend(
+	sender: AccountId,
+	beneficiary: AccountId,
+	id: MultiLocation,
+	amount: u128,
+) {
+	// fund the AHR's SA on BHR for paying bridge transport fees
+	BridgeHubRococo::fund_para_sovereign(AssetHubRococo::para_id(), 10_000_000_000_000u128);
+
+	// set XCM versions
+	AssetHubRococo::force_xcm_version(asset_hub_westend_location(), XCM_VERSION);
+	BridgeHubRococo::force_xcm_version(bridge_hub_westend_location(), XCM_VERSION);
+
+	// send message over bridge
+	assert_ok!(send_asset_from_asset_hub_rococo(sender, beneficiary, (id, amount)));
+	assert_bridge_hub_rococo_message_accepted();
+	assert_bridge_hub_westend_message_received();
+}
+
+/// Reserve-transfers `amount` of WNDs from AHW to AHR and then part of the minted derivatives
+/// back to AHW, checking that:
+/// - the reserve (AHR's SA on AHW) grows by exactly the transferred amount,
+/// - no more derivatives are minted on AHR than what has been locked in the reserve,
+/// - derivatives sent back are burned on AHR and released from the reserve on AHW,
+/// - derivatives on AHR stay fully backed by the reserve on AHW after the round trip.
+fn assert_wnds_reserve_accounting_on_round_trip(amount: u128) {
+	let wnd_at_asset_hub_westend: MultiLocation = Parent.into();
+	let wnd_at_asset_hub_rococo =
+		MultiLocation { parents: 2, interior: X1(GlobalConsensus(NetworkId::Westend)) };
+	let sov_ahr_on_ahw = AssetHubWestend::sovereign_account_of_parachain_on_other_global_consensus(
+		NetworkId::Rococo,
+		AssetHubRococo::para_id(),
+	);
+	let wnds_on_ahr = || {
+		AssetHubRococo::execute_with(|| {
+			type Assets = <AssetHubRococo as AssetHubRococoPallet>::ForeignAssets;
+			(
+				<Assets as Inspect<_>>::total_issuance(wnd_at_asset_hub_rococo),
+				<Assets as Inspect<_>>::balance(
+					wnd_at_asset_hub_rococo,
+					&AssetHubRococoReceiver::get(),
+				),
+			)
+		})
+	};
+
+	// 1. AHW -> AHR: WNDs locked in the reserve, derivatives minted
+	let reserve_before = <AssetHubWestend as Chain>::account_data_of(sov_ahr_on_ahw.clone()).free;
+	let (issuance_before, receiver_before) = wnds_on_ahr();
+
+	send_asset_from_asset_hub_westend_to_asset_hub_rococo(wnd_at_asset_hub_westend, amount);
+	AssetHubRococo::execute_with(|| {
+		type RuntimeEvent = <AssetHubRococo as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			AssetHubRococo,
+			vec![
+				// derivative WNDs minted to beneficiary
+				RuntimeEvent::ForeignAssets(pallet_assets::Event::Issued { asset_id, owner, .. }) => {
+					asset_id: *asset_id == wnd_at_asset_hub_rococo,
+					owner: *owner == AssetHubRococoReceiver::get(),
+				},
+				// message processed successfully
+				RuntimeEvent::MessageQueue(
+					pallet_message_queue::Event::Processed { success: true, .. }
+				) => {},
+			]
+		);
+	});
+
+	let reserve_after_transfer =
+		<AssetHubWestend as Chain>::account_data_of(sov_ahr_on_ahw.clone()).free;
+	let (issuance_after_transfer, receiver_after_transfer) = wnds_on_ahr();
+	let minted = issuance_after_transfer - issuance_before;
+	let received = receiver_after_transfer - receiver_before;
+
+	// reserve is increased by exactly the sent amount
+	assert_eq!(reserve_after_transfer, reserve_before + amount);
+	// beneficiary got the derivatives, minus the execution fees paid on AHR
+	assert!(received > 0);
+	assert!(received <= minted);
+	// nothing is minted on top of what has been locked in the reserve
+	assert!(minted <= amount, "minted: {minted}, locked in reserve: {amount}");
+
+	// 2. AHR -> AHW: derivatives burned, WNDs released from the reserve
+	let amount_back = received / 2;
+	send_asset_from_asset_hub_rococo_to_asset_hub_westend(
+		AssetHubRococoReceiver::get(),
+		AssetHubWestendReceiver::get(),
+		wnd_at_asset_hub_rococo,
+		amount_back,
+	);
+	AssetHubRococo::execute_with(|| {
+		type RuntimeEvent = <AssetHubRococo as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			AssetHubRococo,
+			vec![
+				// derivative WNDs burned
+				RuntimeEvent::ForeignAssets(
+					pallet_assets::Event::Burned { asset_id, owner, balance }
+				) => {
+					asset_id: *asset_id == wnd_at_asset_hub_rococo,
+					owner: *owner == AssetHubRococoReceiver::get(),
+					balance: *balance == amount_back,
+				},
+			]
+		);
+	});
+	AssetHubWestend::execute_with(|| {
+		type RuntimeEvent = <AssetHubWestend as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			AssetHubWestend,
+			vec![
+				// WNDs released from AHR's SA on AHW
+				RuntimeEvent::Balances(
+					pallet_balances::Event::Withdraw { who, amount }
+				) => {
+					who: *who == sov_ahr_on_ahw,
+					amount: *amount == amount_back,
+				},
+				// message processed successfully
+				RuntimeEvent::MessageQueue(
+					pallet_message_queue::Event::Processed { success: true, .. }
+				) => {},
+			]
+		);
+	});
+
+	let reserve_after_withdrawal = <AssetHubWestend as Chain>::account_data_of(sov_ahr_on_ahw).free;
+	let (issuance_after_withdrawal, receiver_after_withdrawal) = wnds_on_ahr();
+
+	// derivatives are burned on AHR
+	assert_eq!(issuance_after_withdrawal, issuance_after_transfer - amount_back);
+	assert_eq!(receiver_after_withdrawal, receiver_after_transfer - amount_back);
+	// and released from the reserve on AHW
+	assert_eq!(reserve_after_withdrawal, reserve_after_transfer - amount_back);
+	// derivatives on AHR are still fully backed by the reserve on AHW
+	assert!(
+		issuance_after_withdrawal - issuance_before <= reserve_after_withdrawal - reserve_before
+	);
+}
+
+#[test]
+fn wnds_reserve_accounting_on_round_trip_works() {
+	let wnd_at_asset_hub_rococo =
+		MultiLocation { parents: 2, interior: X1(GlobalConsensus(NetworkId::Westend)) };
+	let owner: AccountId = AssetHubRococo::account_id_of(ALICE);
+	AssetHubRococo::force_create_foreign_asset(
+		wnd_at_asset_hub_rococo,
+		owner,
+		true,
+		ASSET_MIN_BALANCE,
+		vec![],
+	);
+
+	assert_wnds_reserve_accounting_on_round_trip(ASSET_HUB_WESTEND_ED * 1_000);
+}
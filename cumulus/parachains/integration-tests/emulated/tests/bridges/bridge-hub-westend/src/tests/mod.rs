@@ -39,6 +39,26 @@ pub(crate) fn bridge_hub_rococo_location() -> MultiLocation {
 	}
 }
 
+pub(crate) fn asset_hub_westend_location() -> MultiLocation {
+	MultiLocation {
+		parents: 2,
+		interior: X2(
+			GlobalConsensus(NetworkId::Westend),
+			Parachain(AssetHubWestend::para_id().into()),
+		),
+	}
+}
+
+pub(crate) fn bridge_hub_westend_location() -> MultiLocation {
+	MultiLocation {
+		parents: 2,
+		interior: X2(
+			GlobalConsensus(NetworkId::Westend),
+			Parachain(BridgeHubWestend::para_id().into()),
+		),
+	}
+}
+
 pub(crate) fn send_asset_from_asset_hub_westend(
 	destination: MultiLocation,
 	(id, amount): (MultiLocation, u128),
@@ -64,6 +84,32 @@ pub(crate) fn send_asset_from_asset_hub_westend(
 	})
 }
 
+pub(crate) fn send_asset_from_asset_hub_rococo(
+	sender: AccountId,
+	beneficiary: AccountId,
+	(id, amount): (MultiLocation, u128),
+) -> DispatchResult {
+	let signed_origin = <AssetHubRococo as Chain>::RuntimeOrigin::signed(sender);
+
+	let destination = asset_hub_westend_location();
+	let beneficiary: MultiLocation =
+		AccountId32Junction { network: None, id: beneficiary.into() }.into();
+
+	let assets: MultiAssets = (id, amount).into();
+	let fee_asset_item = 0;
+
+	AssetHubRococo::execute_with(|| {
+		<AssetHubRococo as AssetHubRococoPallet>::PolkadotXcm::limited_reserve_transfer_assets(
+			signed_origin,
+			bx!(destination.into()),
+			bx!(beneficiary.into()),
+			bx!(assets.into()),
+			fee_asset_item,
+			WeightLimit::Unlimited,
+		)
+	})
+}
+
 pub(crate) fn assert_bridge_hub_westend_message_accepted(expected_processed: bool) {
 	BridgeHubWestend::execute_with(|| {
 		type RuntimeEvent = <BridgeHubWestend as Chain>::RuntimeEvent;
@@ -98,6 +144,40 @@ pub(crate) fn assert_bridge_hub_westend_message_accepted(expected_processed: boo
 	});
 }
 
+pub(crate) fn assert_bridge_hub_rococo_message_accepted() {
+	BridgeHubRococo::execute_with(|| {
+		type RuntimeEvent = <BridgeHubRococo as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			BridgeHubRococo,
+			vec![
+				// message exported
+				RuntimeEvent::BridgeWestendMessages(
+					pallet_bridge_messages::Event::MessageAccepted { .. }
+				) => {},
+				// message processed successfully
+				RuntimeEvent::MessageQueue(
+					pallet_message_queue::Event::Processed { success: true, .. }
+				) => {},
+			]
+		);
+	});
+}
+
+pub(crate) fn assert_bridge_hub_westend_message_received() {
+	BridgeHubWestend::execute_with(|| {
+		type RuntimeEvent = <BridgeHubWestend as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			BridgeHubWestend,
+			vec![
+				// message sent to destination
+				RuntimeEvent::XcmpQueue(
+					cumulus_pallet_xcmp_queue::Event::XcmpMessageSent { .. }
+				) => {},
+			]
+		);
+	})
+}
+
 pub(crate) fn assert_bridge_hub_rococo_message_received() {
 	BridgeHubRococo::execute_with(|| {
 		type RuntimeEvent = <BridgeHubRococo as Chain>::RuntimeEvent;
@@ -58,7 +58,7 @@ use frame_support::{
 	ord_parameter_types, parameter_types,
 	traits::{
 		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, EitherOfDiverse,
-		Equals, InstanceFilter, TransformOrigin,
+		Equals, InstanceFilter, Nothing, TransformOrigin,
 	},
 	weights::{ConstantMultiplier, Weight},
 	BoundedVec, PalletId,
@@ -216,8 +216,11 @@ impl pallet_balances::Config for Runtime {
 	type FreezeIdentifier = ();
 	// We allow each account to have holds on it from:
 	//   - `NftFractionalization`: 1
-	type MaxHolds = ConstU32<1>;
-	type MaxFreezes = ConstU32<0>;
+	//   - `AssetConversion`: 1
+	type MaxHolds = ConstU32<2>;
+	// We allow each account to have freezes on it from:
+	//   - `AssetsFreezer`: 1
+	type MaxFreezes = ConstU32<1>;
 }
 
 parameter_types! {
@@ -267,7 +270,7 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = AssetsStringLimit;
-	type Freezer = ();
+	type Freezer = AssetsFreezer;
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_local::WeightInfo<Runtime>;
 	type CallbackHandle = ();
@@ -277,11 +280,26 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type BenchmarkHelper = ();
 }
 
+// Allows freezing trust backed assets, for the freeze reasons whitelisted by governance.
+impl pallet_assets_freezer::Config<TrustBackedAssetsInstance> for Runtime {
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeEvent = RuntimeEvent;
+	type WhitelistOrigin = AssetsForceOrigin;
+	type ForceOrigin = AssetsForceOrigin;
+	type WeightInfo = weights::pallet_assets_freezer::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = pallet_assets_freezer::ForcedReason<TrustBackedAssetsInstance>;
+}
+
 parameter_types! {
 	pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
 	pub const AllowMultiAssetPools: bool = false;
 	// should be non-zero if AllowMultiAssetPools is true, otherwise can be zero
 	pub const LiquidityWithdrawalFee: Permill = Permill::from_percent(0);
+	// a pool creates an lp token asset, so we charge the same deposit as for creating an asset
+	pub const PoolCreationDeposit: Balance = AssetDeposit::get();
+	// e.g. 1 UNIT against 1 unit of an asset with 6 decimals
+	pub const MinInitialLiquidity: Balance = UNITS / 1_000;
 }
 
 ord_parameter_types! {
@@ -320,6 +338,7 @@ impl pallet_asset_conversion::Config for Runtime {
 	type Balance = Balance;
 	type HigherPrecisionBalance = sp_core::U256;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type AssetBalance = Balance;
 	type AssetId = MultiLocation;
 	type Assets = LocalAndForeignAssets<
@@ -341,6 +360,11 @@ impl pallet_asset_conversion::Config for Runtime {
 	type MultiAssetIdConverter =
 		MultiLocationConverter<TokenLocation, LocalAndForeignAssetsMultiLocationMatcher>;
 	type MintMinLiquidity = ConstU128<100>;
+	type PoolCreationDeposit = PoolCreationDeposit;
+	type MinInitialLiquidity = MinInitialLiquidity;
+	// no asset needs to be allowlisted for pool creation
+	type RestrictedAssets = Nothing;
+	type AllowlistOrigin = AssetsForceOrigin;
 	type WeightInfo = weights::pallet_asset_conversion::WeightInfo<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper =
@@ -593,6 +617,7 @@ impl pallet_proxy::Config for Runtime {
 
 parameter_types! {
 	pub const ReservedXcmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
+	pub const MaxInboundDecodeWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(10);
 	pub const ReservedDmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
 }
 
@@ -627,7 +652,8 @@ impl pallet_message_queue::Config for Runtime {
 		cumulus_primitives_core::AggregateMessageOrigin,
 	>;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type MessageProcessor = xcm_builder::ProcessXcmMessage<
+	type MessageProcessor = cumulus_pallet_xcmp_queue::ProcessXcmpPage<
+		Runtime,
 		AggregateMessageOrigin,
 		xcm_executor::XcmExecutor<xcm_config::XcmConfig>,
 		RuntimeCall,
@@ -666,6 +692,7 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
 	type VersionWrapper = PolkadotXcm;
 	type XcmpQueue = TransformOrigin<MessageQueue, AggregateMessageOrigin, ParaId, ParaIdToSibling>;
 	type MaxInboundSuspended = sp_core::ConstU32<1_000>;
+	type MaxInboundDecodeWeight = MaxInboundDecodeWeight;
 	type ControllerOrigin = EnsureRoot<AccountId>;
 	type ControllerOriginConverter = xcm_config::XcmOriginToTransactDispatchOrigin;
 	type PriceForSiblingDelivery = PriceForSiblingParachainDelivery;
@@ -691,6 +718,7 @@ impl pallet_session::Config for Runtime {
 	// Essentially just Aura, but let's be pedantic.
 	type SessionHandler = <SessionKeys as sp_runtime::traits::OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = SessionKeys;
+	type EnforceOwnershipProof = frame_support::traits::ConstBool<false>;
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 }
 
@@ -841,6 +869,7 @@ impl pallet_nfts::Config for Runtime {
 /// consensus with dynamic fees and back-pressure.
 pub type ToWestendXcmRouterInstance = pallet_xcm_bridge_hub_router::Instance3;
 impl pallet_xcm_bridge_hub_router::Config<ToWestendXcmRouterInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_xcm_bridge_hub_router::WeightInfo<Runtime>;
 
 	type UniversalLocation = xcm_config::UniversalLocation;
@@ -867,6 +896,8 @@ impl pallet_xcm_bridge_hub_router::Config<ToWestendXcmRouterInstance> for Runtim
 
 	type ByteFee = xcm_config::bridging::XcmBridgeHubRouterByteFee;
 	type FeeAsset = xcm_config::bridging::XcmBridgeHubRouterFeeAssetId;
+
+	type AdminOrigin = EnsureRoot<AccountId>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -874,7 +905,7 @@ construct_runtime!(
 	pub enum Runtime
 	{
 		// System support stuff.
-		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>} = 0,
+		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>, ValidateUnsigned} = 0,
 		ParachainSystem: cumulus_pallet_parachain_system::{
 			Pallet, Call, Config<T>, Storage, Inherent, Event<T>, ValidateUnsigned,
 		} = 1,
@@ -905,7 +936,7 @@ construct_runtime!(
 		Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>} = 42,
 
 		// Bridge utilities.
-		ToWestendXcmRouter: pallet_xcm_bridge_hub_router::<Instance3>::{Pallet, Storage, Call} = 45,
+		ToWestendXcmRouter: pallet_xcm_bridge_hub_router::<Instance3>::{Pallet, Storage, Call, Event<T>} = 45,
 
 		// The main stage.
 		Assets: pallet_assets::<Instance1>::{Pallet, Call, Storage, Event<T>} = 50,
@@ -915,7 +946,8 @@ construct_runtime!(
 		NftFractionalization: pallet_nft_fractionalization::{Pallet, Call, Storage, Event<T>, HoldReason} = 54,
 
 		PoolAssets: pallet_assets::<Instance3>::{Pallet, Call, Storage, Event<T>} = 55,
-		AssetConversion: pallet_asset_conversion::{Pallet, Call, Storage, Event<T>} = 56,
+		AssetConversion: pallet_asset_conversion::{Pallet, Call, Storage, Event<T>, HoldReason} = 56,
+		AssetsFreezer: pallet_assets_freezer::<Instance1>::{Pallet, Call, Storage, Event<T>, FreezeReason} = 57,
 
 		#[cfg(feature = "state-trie-version-1")]
 		StateTrieMigration: pallet_state_trie_migration = 70,
@@ -950,6 +982,8 @@ pub type Migrations = (
 	InitStorageVersions,
 	// unreleased
 	cumulus_pallet_xcmp_queue::migration::v4::MigrationToV4<Runtime>,
+	// unreleased
+	cumulus_pallet_parachain_system::migration::Migration<Runtime>,
 );
 
 /// Migration to initialize storage versions for pallets added after genesis.
@@ -1026,6 +1060,7 @@ mod benches {
 		[pallet_assets, Foreign]
 		[pallet_assets, Pool]
 		[pallet_asset_conversion, AssetConversion]
+		[pallet_assets_freezer, AssetsFreezer]
 		[pallet_balances, Balances]
 		[pallet_multisig, Multisig]
 		[pallet_nft_fractionalization, NftFractionalization]
@@ -1122,11 +1157,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
 			SessionKeys::generate(seed)
 		}
 
+		fn generate_session_keys_with_ownership_proof(
+			owner: Vec<u8>,
+			seed: Option<Vec<u8>>,
+		) -> sp_session::OpaqueGeneratedSessionKeys {
+			let (keys, proof) = SessionKeys::generate_with_ownership_proof(&owner, seed);
+			sp_session::OpaqueGeneratedSessionKeys { keys, proof }
+		}
+
 		fn decode_session_keys(
 			encoded: Vec<u8>,
 		) -> Option<Vec<(Vec<u8>, KeyTypeId)>> {
@@ -1140,6 +1184,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_asset_conversion::AssetConversionApi<
 		Block,
 		Balance,
@@ -1156,6 +1201,20 @@ impl_runtime_apis! {
 		fn get_reserves(asset1: Box<MultiLocation>, asset2: Box<MultiLocation>) -> Option<(Balance, Balance)> {
 			AssetConversion::get_reserves(&asset1, &asset2).ok()
 		}
+
+		fn quote_swap_exact_tokens_for_tokens(asset1: Box<MultiLocation>, asset2: Box<MultiLocation>, amount: u128) -> Option<pallet_asset_conversion::SwapQuote<Box<MultiLocation>, u128>> {
+			AssetConversion::quote_swap_exact_tokens_for_tokens(asset1, asset2, amount)
+		}
+
+		fn quote_swap_tokens_for_exact_tokens(asset1: Box<MultiLocation>, asset2: Box<MultiLocation>, amount: u128) -> Option<pallet_asset_conversion::SwapQuote<Box<MultiLocation>, u128>> {
+			AssetConversion::quote_swap_tokens_for_exact_tokens(asset1, asset2, amount)
+		}
+	}
+
+	impl pallet_xcm::XcmQueryApi<Block, BlockNumber> for Runtime {
+		fn query_status(query_id: xcm::latest::QueryId) -> pallet_xcm::QueryStatusInfo<BlockNumber> {
+			PolkadotXcm::query_status(query_id)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
@@ -1202,6 +1261,44 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl parachains_common::account_summary::AccountSummaryApi<
+		Block,
+		AccountId,
+		Balance,
+		BlockNumber,
+		RuntimeHoldReason,
+		(),
+		ProxyType,
+	> for Runtime
+	{
+		fn account_summary(
+			who: AccountId,
+		) -> parachains_common::account_summary::AccountSummaryOf<Runtime, ProxyType> {
+			use parachains_common::account_summary;
+
+			let mut summary = account_summary::AccountSummaryOf::<Runtime, ProxyType>::default();
+			account_summary::fill_balances::<Runtime, (), _>(&who, &mut summary);
+			summary.proxies = account_summary::proxies::<Runtime>(&who);
+			summary.multisigs = account_summary::multisig_participations::<Runtime>(&who);
+			summary
+		}
+	}
+
+	impl assets_common::runtime_api::AssetFreezesApi<
+		Block,
+		AssetIdForTrustBackedAssets,
+		AccountId,
+		RuntimeFreezeReason,
+		Balance,
+	> for Runtime
+	{
+		fn asset_freezes(
+			asset: AssetIdForTrustBackedAssets,
+		) -> Vec<(AccountId, RuntimeFreezeReason, Balance)> {
+			AssetsFreezer::asset_freezes(asset)
+		}
+	}
+
 	impl assets_common::runtime_api::FungiblesApi<
 		Block,
 		AccountId,
@@ -1248,6 +1345,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl cumulus_pallet_xcmp_queue::XcmpQueueApi<Block> for Runtime {
+		fn queue_depths() -> Vec<cumulus_pallet_xcmp_queue::ChannelQueueDepth> {
+			XcmpQueue::queue_depths()
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
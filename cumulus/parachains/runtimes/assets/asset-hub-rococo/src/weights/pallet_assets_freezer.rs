@@ -0,0 +1,65 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_assets_freezer`.
+//!
+//! PLACEHOLDER: these weights were not generated by the benchmarks of `pallet_assets_freezer` yet.
+//! Regenerate this file with the `benchmark pallet` command of the node before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_assets_freezer`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_assets_freezer::WeightInfo for WeightInfo<T> {
+	/// Storage: `AssetsFreezer::AllowedFreezeReasons` (r:1 w:1)
+	/// Proof: `AssetsFreezer::AllowedFreezeReasons` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	fn allow_freeze_reason() -> Weight {
+		Weight::from_parts(10_617_000, 0)
+			.saturating_add(Weight::from_parts(0, 3483))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AssetsFreezer::AllowedFreezeReasons` (r:1 w:1)
+	/// Proof: `AssetsFreezer::AllowedFreezeReasons` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	fn disallow_freeze_reason() -> Weight {
+		Weight::from_parts(11_390_000, 0)
+			.saturating_add(Weight::from_parts(0, 3483))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Storage: `AssetsFreezer::AllowedFreezeReasons` (r:1 w:0)
+	fn force_freeze() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4000))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	fn force_thaw() -> Weight {
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4000))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
@@ -55,10 +55,7 @@ impl<T: frame_system::Config> pallet_session::WeightInfo for WeightInfo<T> {
 	/// Storage: `Session::KeyOwner` (r:1 w:1)
 	/// Proof: `Session::KeyOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn set_keys() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `270`
-		//  Estimated: `3735`
-		// Minimum execution time: 16_932_000 picoseconds.
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
 		Weight::from_parts(17_357_000, 0)
 			.saturating_add(Weight::from_parts(0, 3735))
 			.saturating_add(T::DbWeight::get().reads(2))
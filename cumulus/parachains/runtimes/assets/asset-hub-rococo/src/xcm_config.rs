@@ -43,15 +43,15 @@ use sp_runtime::traits::{AccountIdConversion, ConvertInto};
 use xcm::latest::prelude::*;
 use xcm_builder::{
 	AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses,
-	AllowSubscriptionsFrom, AllowTopLevelPaidExecutionFrom, CurrencyAdapter,
-	DenyReserveTransferToRelayChain, DenyThenTry, DescribeAllTerminal, DescribeFamily,
-	EnsureXcmOrigin, FungiblesAdapter, GlobalConsensusParachainConvertsFor, HashedDescription,
-	IsConcrete, LocalMint, NetworkExportTableItem, NoChecking, ParentAsSuperuser, ParentIsPreset,
-	RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
-	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, StartsWith,
-	StartsWithExplicitGlobalConsensus, TakeWeightCredit, TrailingSetTopicAsId, UsingComponents,
-	WeightInfoBounds, WithComputedOrigin, WithUniqueTopic, XcmFeeManagerFromComponents,
-	XcmFeeToAccount,
+	AllowSubscriptionsFrom, AllowTopLevelPaidExecutionFrom, ChargeFeesWithSwapFallback,
+	CurrencyAdapter, DenyReserveTransferToRelayChain, DenyThenTry, DescribeAllTerminal,
+	DescribeFamily, EnsureXcmOrigin, FungiblesAdapter, GlobalConsensusParachainConvertsFor,
+	HashedDescription, IsConcrete, LocalMint, NetworkExportTableItem, NoChecking,
+	ParentAsSuperuser, ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative,
+	SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32,
+	SovereignSignedViaLocation, StartsWith, StartsWithExplicitGlobalConsensus, TakeWeightCredit,
+	TrailingSetTopicAsId, UsingComponents, WeightInfoBounds, WithComputedOrigin, WithUniqueTopic,
+	XcmFeeManagerFromComponents, XcmFeeToAccount,
 };
 use xcm_executor::{traits::WithOriginFilter, XcmExecutor};
 
@@ -233,6 +233,26 @@ parameter_types! {
 	pub XcmAssetFeesReceiver: Option<AccountId> = Authorship::author();
 }
 
+parameter_types! {
+	/// Assets which are swapped (using `pallet-asset-conversion`) for the native asset when the
+	/// origin of `pallet-xcm` extrinsic has not enough native asset to pay the delivery fees.
+	/// Empty by default, can be changed by governance (via `system::set_storage`).
+	pub storage DeliveryFeeSwapCandidates: sp_std::vec::Vec<MultiLocation> = sp_std::vec::Vec::new();
+}
+
+/// Acquires the delivery fees by swapping one of the [`DeliveryFeeSwapCandidates`] for the
+/// native asset.
+pub type DeliveryFeesAcquirer = cumulus_primitives_utility::SwapAssetsForNativeFees<
+	AccountId,
+	LocationToAccountId,
+	crate::AssetConversion,
+	sp_core::U256,
+	sp_std::boxed::Box<MultiLocation>,
+	TokenLocation,
+	crate::ExistentialDeposit,
+	DeliveryFeeSwapCandidates,
+>;
+
 match_types! {
 	pub type ParentOrParentsPlurality: impl Contains<MultiLocation> = {
 		MultiLocation { parents: 1, interior: Here } |
@@ -387,7 +407,13 @@ impl Contains<RuntimeCall> for SafeCallFilter {
 					pallet_asset_conversion::Call::add_liquidity { .. } |
 					pallet_asset_conversion::Call::remove_liquidity { .. } |
 					pallet_asset_conversion::Call::swap_tokens_for_exact_tokens { .. } |
-					pallet_asset_conversion::Call::swap_exact_tokens_for_tokens { .. },
+					pallet_asset_conversion::Call::swap_exact_tokens_for_tokens { .. } |
+					pallet_asset_conversion::Call::set_asset_allowed { .. },
+			) | RuntimeCall::AssetsFreezer(
+				pallet_assets_freezer::Call::allow_freeze_reason { .. } |
+					pallet_assets_freezer::Call::disallow_freeze_reason { .. } |
+					pallet_assets_freezer::Call::force_freeze { .. } |
+					pallet_assets_freezer::Call::force_thaw { .. },
 			) | RuntimeCall::NftFractionalization(
 				pallet_nft_fractionalization::Call::fractionalize { .. } |
 					pallet_nft_fractionalization::Call::unify { .. },
@@ -590,6 +616,9 @@ impl xcm_executor::Config for XcmConfig {
 	type CallDispatcher = WithOriginFilter<SafeCallFilter>;
 	type SafeCallFilter = SafeCallFilter;
 	type Aliasers = Nothing;
+	type HrmpNewChannelOpenRequestHandler = ();
+	type HrmpChannelAcceptedHandler = ();
+	type HrmpChannelClosingHandler = ();
 }
 
 /// Converts a local signed origin into an XCM multilocation.
@@ -626,7 +655,7 @@ impl pallet_xcm::Config for Runtime {
 	// ... but disallow generic XCM execution. As a result only teleports and reserve transfers are
 	// allowed.
 	type XcmExecuteFilter = Nothing;
-	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmExecutor = ChargeFeesWithSwapFallback<XcmExecutor<XcmConfig>, DeliveryFeesAcquirer>;
 	type XcmTeleportFilter = Everything;
 	type XcmReserveTransferFilter = Everything;
 	type Weigher = WeightInfoBounds<
@@ -635,6 +664,8 @@ impl pallet_xcm::Config for Runtime {
 		MaxInstructions,
 	>;
 	type UniversalLocation = UniversalLocation;
+	type BridgeExporters = ToWestendXcmRouter;
+	type BridgedReserveHops = bridging::BridgedReserveHops;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
@@ -734,6 +765,12 @@ pub mod bridging {
 			sp_std::vec::Vec::new().into_iter()
 			.chain(to_westend::BridgeTable::get())
 			.collect();
+
+		/// Bridged Asset Hubs, which forward reserve transfers from this chain to the other chains
+		/// of their network.
+		pub BridgedReserveHops: sp_std::vec::Vec<MultiLocation> = sp_std::vec![
+			to_westend::AssetHubWestend::get(),
+		];
 	}
 
 	pub type NetworkExportTable = xcm_builder::NetworkExportTable<BridgeTable>;
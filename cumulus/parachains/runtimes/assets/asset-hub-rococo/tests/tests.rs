@@ -26,10 +26,10 @@ pub use asset_hub_rococo_runtime::{
 		self, bridging, CheckingAccount, ForeignCreatorsSovereignAccountOf, LocationToAccountId,
 		XcmConfig,
 	},
-	AllPalletsWithoutSystem, AssetDeposit, Assets, Balances, ExistentialDeposit, ForeignAssets,
-	ForeignAssetsInstance, MetadataDepositBase, MetadataDepositPerByte, ParachainSystem, Runtime,
-	RuntimeCall, RuntimeEvent, SessionKeys, System, ToWestendXcmRouterInstance,
-	TrustBackedAssetsInstance, XcmpQueue,
+	AllPalletsWithoutSystem, AssetDeposit, Assets, Balances, CollatorSelection, ExistentialDeposit,
+	ForeignAssets, ForeignAssetsInstance, MetadataDepositBase, MetadataDepositPerByte,
+	ParachainSystem, Runtime, RuntimeCall, RuntimeEvent, SessionKeys, System,
+	ToWestendXcmRouterInstance, TrustBackedAssetsInstance, XcmpQueue,
 };
 use asset_test_utils::{
 	test_cases_over_bridge::TestBridgingConfig, CollatorSessionKey, CollatorSessionKeys, ExtBuilder,
@@ -865,3 +865,105 @@ fn change_xcm_bridge_hub_router_byte_fee_by_governance_works() {
 		},
 	)
 }
+
+#[test]
+fn xcm_payment_in_each_accepted_fee_asset_works() {
+	use asset_test_utils::test_cases::AcceptedFeeAsset;
+	use frame_support::traits::fungible::Mutate;
+
+	let block_author = AccountId::from(ALICE);
+	let asset_owner = AccountId::from(SOME_ASSET_ADMIN);
+	let fee_amount = 10_000_000_000_000_u128;
+	let minimum_asset_balance = 3333333_u128;
+	let local_asset_id = 1;
+	let foreign_asset_id_multilocation =
+		MultiLocation { parents: 1, interior: X2(Parachain(1313), GeneralIndex(12345)) };
+
+	asset_test_utils::test_cases::xcm_payment_in_each_accepted_fee_asset_works::<
+		Runtime,
+		AllPalletsWithoutSystem,
+		XcmConfig,
+	>(
+		collator_session_keys(),
+		block_author.clone(),
+		AccountId::from([3u8; 32]),
+		vec![
+			// native asset, fees go to the staking pot
+			AcceptedFeeAsset {
+				id: TokenLocation::get(),
+				fee_amount,
+				prepare: Box::new(move |payer| {
+					Balances::mint_into(payer, 2 * fee_amount).unwrap();
+					Balances::mint_into(
+						&CollatorSelection::account_id(),
+						ExistentialDeposit::get(),
+					)
+					.unwrap();
+				}),
+				balance_of: Box::new(|who| Balances::free_balance(who)),
+				fee_receiver: CollatorSelection::account_id(),
+			},
+			// sufficient trust backed asset, fees go to the block author
+			AcceptedFeeAsset {
+				id: AssetIdForTrustBackedAssetsConvert::convert_back(&local_asset_id).unwrap(),
+				fee_amount,
+				prepare: {
+					let block_author = block_author.clone();
+					let asset_owner = asset_owner.clone();
+					Box::new(move |payer| {
+						assert_ok!(Assets::force_create(
+							RuntimeHelper::root_origin(),
+							local_asset_id.into(),
+							asset_owner.clone().into(),
+							true,
+							minimum_asset_balance
+						));
+						for (who, amount) in
+							[(payer, 2 * fee_amount), (&block_author, minimum_asset_balance)]
+						{
+							assert_ok!(Assets::mint(
+								RuntimeHelper::origin_of(asset_owner.clone()),
+								local_asset_id.into(),
+								who.clone().into(),
+								amount
+							));
+						}
+					})
+				},
+				balance_of: Box::new(move |who| Assets::balance(local_asset_id, who)),
+				fee_receiver: block_author.clone(),
+			},
+			// sufficient foreign asset, fees go to the block author
+			AcceptedFeeAsset {
+				id: foreign_asset_id_multilocation,
+				fee_amount,
+				prepare: {
+					let block_author = block_author.clone();
+					Box::new(move |payer| {
+						assert_ok!(ForeignAssets::force_create(
+							RuntimeHelper::root_origin(),
+							foreign_asset_id_multilocation,
+							asset_owner.clone().into(),
+							true,
+							minimum_asset_balance
+						));
+						for (who, amount) in
+							[(payer, 2 * fee_amount), (&block_author, minimum_asset_balance)]
+						{
+							assert_ok!(ForeignAssets::mint(
+								RuntimeHelper::origin_of(asset_owner.clone()),
+								foreign_asset_id_multilocation,
+								who.clone().into(),
+								amount
+							));
+						}
+					})
+				},
+				balance_of: Box::new(move |who| {
+					ForeignAssets::balance(foreign_asset_id_multilocation, who)
+				}),
+				fee_receiver: block_author,
+			},
+		],
+	)
+}
@@ -20,6 +20,7 @@ pub mod extrinsic_weights;
 pub mod frame_system;
 pub mod pallet_asset_conversion;
 pub mod pallet_assets_foreign;
+pub mod pallet_assets_freezer;
 pub mod pallet_assets_local;
 pub mod pallet_assets_pool;
 pub mod pallet_balances;
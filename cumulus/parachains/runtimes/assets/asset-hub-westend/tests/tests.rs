@@ -23,9 +23,10 @@ use asset_hub_westend_runtime::{
 		ForeignCreatorsSovereignAccountOf, LocationToAccountId, TrustBackedAssetsPalletLocation,
 		WestendLocation, XcmConfig,
 	},
-	AllPalletsWithoutSystem, AssetDeposit, Assets, Balances, ExistentialDeposit, ForeignAssets,
-	ForeignAssetsInstance, MetadataDepositBase, MetadataDepositPerByte, ParachainSystem,
-	PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, SessionKeys,
+	AllPalletsWithoutSystem, AssetDeposit, Assets, Aura, Balances, CollatorSelection,
+	ExistentialDeposit, ForeignAssets, ForeignAssetsInstance, MetadataDepositBase,
+	MetadataDepositPerByte, ParachainSystem, PolkadotXcm, Proxy, ProxyType, Runtime, RuntimeCall,
+	RuntimeEvent, RuntimeOrigin, Session, SessionKeys, System, Timestamp,
 	ToRococoXcmRouterInstance, TrustBackedAssetsInstance, XcmpQueue,
 };
 use asset_test_utils::{
@@ -40,6 +41,7 @@ use frame_support::{
 };
 use parachains_common::{
 	westend::fee::WeightToFee, AccountId, AssetIdForTrustBackedAssets, AuraId, Balance,
+	SLOT_DURATION,
 };
 use sp_runtime::traits::MaybeEquivalence;
 use std::convert::Into;
@@ -634,6 +636,75 @@ asset_test_utils::include_create_and_manage_foreign_assets_for_local_consensus_p
 	})
 );
 
+#[test]
+fn test_account_summary_api_works() {
+	use parachains_common::account_summary::runtime_decl_for_account_summary_api::AccountSummaryApi;
+
+	ExtBuilder::<Runtime>::default()
+		.with_collators(vec![AccountId::from(ALICE)])
+		.with_session_keys(vec![(
+			AccountId::from(ALICE),
+			AccountId::from(ALICE),
+			SessionKeys { aura: AuraId::from(sp_core::sr25519::Public::from_raw(ALICE)) },
+		)])
+		.build()
+		.execute_with(|| {
+			let summary = Runtime::account_summary(AccountId::from(ALICE));
+			assert_eq!(summary.free, 0);
+			assert!(summary.proxies.is_empty());
+
+			use frame_support::traits::fungible::Mutate;
+			let initial_balance = 100 * ExistentialDeposit::get();
+			Balances::mint_into(&AccountId::from(ALICE), initial_balance).unwrap();
+			assert_ok!(Proxy::add_proxy(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				AccountId::from(SOME_ASSET_ADMIN).into(),
+				ProxyType::Any,
+				0,
+			));
+
+			let summary = Runtime::account_summary(AccountId::from(ALICE));
+			assert_eq!(summary.reserved, Balances::reserved_balance(AccountId::from(ALICE)));
+			assert!(summary.reserved > 0);
+			assert_eq!(summary.free, initial_balance - summary.reserved);
+			// the account has a deposit reserved, so it must be kept alive
+			assert_eq!(summary.transferable, summary.free - ExistentialDeposit::get());
+			assert_eq!(summary.proxies.len(), 1);
+			assert_eq!(summary.proxies[0].delegate, AccountId::from(SOME_ASSET_ADMIN));
+			assert_eq!(summary.proxies[0].proxy_type, ProxyType::Any);
+			assert!(summary.multisigs.is_empty());
+			assert!(summary.vesting.is_empty());
+		});
+}
+
+#[test]
+fn runtime_helper_controls_slot_and_timestamp() {
+	use sp_consensus_aura::Slot;
+
+	ExtBuilder::<Runtime>::default()
+		.with_collators(vec![AccountId::from(ALICE)])
+		.with_session_keys(vec![(
+			AccountId::from(ALICE),
+			AccountId::from(ALICE),
+			SessionKeys { aura: AuraId::from(sp_core::sr25519::Public::from_raw(ALICE)) },
+		)])
+		.build()
+		.execute_with(|| {
+			RuntimeHelper::next_block_at_slot(Slot::from(10));
+			assert_eq!(Aura::current_slot(), Slot::from(10));
+			assert_eq!(Timestamp::now(), 10 * SLOT_DURATION);
+
+			RuntimeHelper::run_to_block_at_slots(5, |n| Slot::from(10 + 2 * n as u64));
+			assert_eq!(System::block_number(), 5);
+			assert_eq!(Aura::current_slot(), Slot::from(20));
+			assert_eq!(Timestamp::now(), 20 * SLOT_DURATION);
+
+			let session_index = Session::current_index();
+			RuntimeHelper::run_sessions(1);
+			assert_eq!(Session::current_index(), session_index + 1);
+		});
+}
+
 fn bridging_to_asset_hub_rococo() -> TestBridgingConfig {
 	let _ = PolkadotXcm::force_xcm_version(
 		RuntimeOrigin::root(),
@@ -844,3 +915,105 @@ fn reserve_transfer_native_asset_to_non_teleport_para_works() {
 		WeightLimit::Unlimited,
 	);
 }
+
+#[test]
+fn xcm_payment_in_each_accepted_fee_asset_works() {
+	use asset_test_utils::test_cases::AcceptedFeeAsset;
+	use frame_support::traits::fungible::Mutate;
+
+	let block_author = AccountId::from(ALICE);
+	let asset_owner = AccountId::from(SOME_ASSET_ADMIN);
+	let fee_amount = 10_000_000_000_000_u128;
+	let minimum_asset_balance = 3333333_u128;
+	let local_asset_id = 1;
+	let foreign_asset_id_multilocation =
+		MultiLocation { parents: 1, interior: X2(Parachain(1313), GeneralIndex(12345)) };
+
+	asset_test_utils::test_cases::xcm_payment_in_each_accepted_fee_asset_works::<
+		Runtime,
+		AllPalletsWithoutSystem,
+		XcmConfig,
+	>(
+		collator_session_keys(),
+		block_author.clone(),
+		AccountId::from([3u8; 32]),
+		vec![
+			// native asset, fees go to the staking pot
+			AcceptedFeeAsset {
+				id: WestendLocation::get(),
+				fee_amount,
+				prepare: Box::new(move |payer| {
+					Balances::mint_into(payer, 2 * fee_amount).unwrap();
+					Balances::mint_into(
+						&CollatorSelection::account_id(),
+						ExistentialDeposit::get(),
+					)
+					.unwrap();
+				}),
+				balance_of: Box::new(|who| Balances::free_balance(who)),
+				fee_receiver: CollatorSelection::account_id(),
+			},
+			// sufficient trust backed asset, fees go to the block author
+			AcceptedFeeAsset {
+				id: AssetIdForTrustBackedAssetsConvert::convert_back(&local_asset_id).unwrap(),
+				fee_amount,
+				prepare: {
+					let block_author = block_author.clone();
+					let asset_owner = asset_owner.clone();
+					Box::new(move |payer| {
+						assert_ok!(Assets::force_create(
+							RuntimeHelper::root_origin(),
+							local_asset_id.into(),
+							asset_owner.clone().into(),
+							true,
+							minimum_asset_balance
+						));
+						for (who, amount) in
+							[(payer, 2 * fee_amount), (&block_author, minimum_asset_balance)]
+						{
+							assert_ok!(Assets::mint(
+								RuntimeHelper::origin_of(asset_owner.clone()),
+								local_asset_id.into(),
+								who.clone().into(),
+								amount
+							));
+						}
+					})
+				},
+				balance_of: Box::new(move |who| Assets::balance(local_asset_id, who)),
+				fee_receiver: block_author.clone(),
+			},
+			// sufficient foreign asset, fees go to the block author
+			AcceptedFeeAsset {
+				id: foreign_asset_id_multilocation,
+				fee_amount,
+				prepare: {
+					let block_author = block_author.clone();
+					Box::new(move |payer| {
+						assert_ok!(ForeignAssets::force_create(
+							RuntimeHelper::root_origin(),
+							foreign_asset_id_multilocation,
+							asset_owner.clone().into(),
+							true,
+							minimum_asset_balance
+						));
+						for (who, amount) in
+							[(payer, 2 * fee_amount), (&block_author, minimum_asset_balance)]
+						{
+							assert_ok!(ForeignAssets::mint(
+								RuntimeHelper::origin_of(asset_owner.clone()),
+								foreign_asset_id_multilocation,
+								who.clone().into(),
+								amount
+							));
+						}
+					})
+				},
+				balance_of: Box::new(move |who| {
+					ForeignAssets::balance(foreign_asset_id_multilocation, who)
+				}),
+				fee_receiver: block_author,
+			},
+		],
+	)
+}
@@ -17,8 +17,9 @@
 
 use codec::{Codec, Decode, Encode};
 use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
 #[cfg(feature = "std")]
-use {sp_std::vec::Vec, xcm::latest::MultiAsset};
+use xcm::latest::MultiAsset;
 
 /// The possible errors that can happen querying the storage of assets.
 #[derive(Eq, PartialEq, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
@@ -43,4 +44,16 @@ sp_api::decl_runtime_apis! {
 		/// Returns the list of all [`MultiAsset`] that an `AccountId` has.
 		fn query_account_balances(account: AccountId) -> Result<xcm::VersionedMultiAssets, FungiblesAccessError>;
 	}
+
+	/// The API for querying the freezes placed on assets.
+	pub trait AssetFreezesApi<AssetId, AccountId, FreezeReason, Balance>
+	where
+		AssetId: Codec,
+		AccountId: Codec,
+		FreezeReason: Codec,
+		Balance: Codec,
+	{
+		/// Returns all freezes placed on `asset`, as `(account, reason, amount)`.
+		fn asset_freezes(asset: AssetId) -> Vec<(AccountId, FreezeReason, Balance)>;
+	}
 }
@@ -1572,3 +1572,111 @@ pub fn reserve_transfer_native_asset_to_non_teleport_para_works<
 			);
 		})
 }
+
+/// An asset accepted by the `Runtime`'s XCM `Trader` for paying execution fees, as checked by
+/// [`xcm_payment_in_each_accepted_fee_asset_works`].
+pub struct AcceptedFeeAsset<Runtime: frame_system::Config> {
+	/// The location of the asset.
+	pub id: MultiLocation,
+	/// The amount of the asset withdrawn to pay for the execution.
+	pub fee_amount: u128,
+	/// Sets up the asset (e.g. creates it as sufficient) and gives the payer enough of it to pay
+	/// `fee_amount` and still stay alive. Also makes sure that `fee_receiver` can receive the
+	/// fees.
+	pub prepare: Box<dyn Fn(&AccountIdOf<Runtime>)>,
+	/// Returns the balance of an account in the asset.
+	pub balance_of: Box<dyn Fn(&AccountIdOf<Runtime>) -> u128>,
+	/// The account expected to receive the fees paid in the asset.
+	pub fee_receiver: AccountIdOf<Runtime>,
+}
+
+/// Test-case makes sure that `Runtime` can pay for XCM execution with each of the assets accepted
+/// by its `Trader`, and that the paid fees end up with the expected receiver
+pub fn xcm_payment_in_each_accepted_fee_asset_works<Runtime, AllPalletsWithoutSystem, XcmConfig>(
+	collator_session_keys: CollatorSessionKeys<Runtime>,
+	block_author: AccountIdOf<Runtime>,
+	payer: AccountIdOf<Runtime>,
+	accepted_fee_assets: Vec<AcceptedFeeAsset<Runtime>>,
+) where
+	Runtime: frame_system::Config
+		+ pallet_balances::Config
+		+ pallet_session::Config
+		+ pallet_xcm::Config
+		+ parachain_info::Config
+		+ pallet_collator_selection::Config
+		+ cumulus_pallet_parachain_system::Config,
+	AllPalletsWithoutSystem:
+		OnInitialize<BlockNumberFor<Runtime>> + OnFinalize<BlockNumberFor<Runtime>>,
+	AccountIdOf<Runtime>: Into<[u8; 32]>,
+	ValidatorIdOf<Runtime>: From<AccountIdOf<Runtime>>,
+	BalanceOf<Runtime>: From<Balance>,
+	XcmConfig: xcm_executor::Config,
+	<Runtime as frame_system::Config>::AccountId:
+		Into<<<Runtime as frame_system::Config>::RuntimeOrigin as OriginTrait>::AccountId>,
+	<<Runtime as frame_system::Config>::Lookup as StaticLookup>::Source:
+		From<<Runtime as frame_system::Config>::AccountId>,
+{
+	assert!(!accepted_fee_assets.is_empty(), "no accepted fee assets to check");
+
+	for fee_asset in accepted_fee_assets {
+		ExtBuilder::<Runtime>::default()
+			.with_collators(collator_session_keys.collators())
+			.with_session_keys(collator_session_keys.session_keys())
+			.with_tracing()
+			.build()
+			.execute_with(|| {
+				// the block author may be the receiver of the fees
+				RuntimeHelper::<Runtime, AllPalletsWithoutSystem>::run_to_block(
+					2,
+					block_author.clone(),
+				);
+
+				(fee_asset.prepare)(&payer);
+				let payer_balance_before = (fee_asset.balance_of)(&payer);
+				let fee_receiver_balance_before = (fee_asset.balance_of)(&fee_asset.fee_receiver);
+
+				// withdraw the fee asset, pay for the execution with it and deposit the rest back
+				let payer_location = MultiLocation {
+					parents: 0,
+					interior: X1(AccountId32 { network: None, id: payer.clone().into() }),
+				};
+				let fees =
+					MultiAsset { id: Concrete(fee_asset.id), fun: Fungible(fee_asset.fee_amount) };
+				let xcm = Xcm(vec![
+					WithdrawAsset(fees.clone().into()),
+					BuyExecution { fees, weight_limit: Unlimited },
+					DepositAsset { assets: Wild(AllCounted(1)), beneficiary: payer_location },
+				]);
+
+				let hash = xcm.using_encoded(sp_io::hashing::blake2_256);
+
+				let outcome = XcmExecutor::<XcmConfig>::execute_xcm(
+					payer_location,
+					xcm,
+					hash,
+					RuntimeHelper::<Runtime>::xcm_max_weight(XcmReceivedFrom::Sibling),
+				);
+				assert!(
+					matches!(outcome, Outcome::Complete(_)),
+					"paying fees with {:?} failed: {:?}",
+					fee_asset.id,
+					outcome
+				);
+
+				// check that the payer paid the fees and the fee receiver got all of them
+				let paid = payer_balance_before.saturating_sub((fee_asset.balance_of)(&payer));
+				assert!(paid > 0, "no fees were paid with {:?}", fee_asset.id);
+				assert!(
+					paid <= fee_asset.fee_amount,
+					"more than the fees were taken with {:?}",
+					fee_asset.id
+				);
+				assert_eq!(
+					(fee_asset.balance_of)(&fee_asset.fee_receiver),
+					fee_receiver_balance_before + paid,
+					"fees paid with {:?} did not reach the fee receiver",
+					fee_asset.id
+				);
+			})
+	}
+}
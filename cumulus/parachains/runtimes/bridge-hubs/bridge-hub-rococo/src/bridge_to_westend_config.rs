@@ -70,6 +70,8 @@ parameter_types! {
 
 	// Lanes
 	pub ActiveOutboundLanesToBridgeHubWestend: &'static [bp_messages::LaneId] = &[XCM_LANE_FOR_ASSET_HUB_ROCOCO_TO_ASSET_HUB_WESTEND];
+	// the lane is considered stalled if no deliveries are confirmed for this number of blocks
+	pub const LaneStallThreshold: parachains_common::BlockNumber = 2 * parachains_common::HOURS;
 	pub const AssetHubRococoToAssetHubWestendMessagesLane: bp_messages::LaneId = XCM_LANE_FOR_ASSET_HUB_ROCOCO_TO_ASSET_HUB_WESTEND;
 	pub FromAssetHubRococoToAssetHubWestendRoute: SenderAndLane = SenderAndLane::new(
 		ParentThen(X1(Parachain(AssetHubRococoParaId::get().into()))).into(),
@@ -239,6 +241,8 @@ impl pallet_bridge_messages::Config<WithBridgeHubWestendMessagesInstance> for Ru
 		>,
 	>;
 	type OnMessagesDelivered = OnMessagesDeliveredFromWestend;
+	type LaneStallThreshold = LaneStallThreshold;
+	type OnLaneStalled = ();
 }
 
 /// Add support for the export and dispatch of XCM programs.
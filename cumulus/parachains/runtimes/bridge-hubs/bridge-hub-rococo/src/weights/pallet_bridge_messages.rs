@@ -239,4 +239,34 @@ impl<T: frame_system::Config> pallet_bridge_messages::WeightInfo for WeightInfo<
 			.saturating_add(T::DbWeight::get().reads(10))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: `BridgeWestendMessages::PalletOperatingMode` (r:1 w:0)
+	/// Proof: `BridgeWestendMessages::PalletOperatingMode` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `BridgeWestendParachains::ImportedParaHeads` (r:1 w:0)
+	/// Proof: `BridgeWestendParachains::ImportedParaHeads` (`max_values`: Some(64), `max_size`: Some(196), added: 1186, mode: `MaxEncodedLen`)
+	/// Storage: `BridgeWestendMessages::InboundLanes` (r:1 w:1)
+	/// Proof: `BridgeWestendMessages::InboundLanes` (`max_values`: None, `max_size`: Some(49180), added: 51655, mode: `MaxEncodedLen`)
+	/// The range of component `i` is `[128, 2048]`.
+	fn receive_single_message_proof_without_dispatch(i: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(41_102_315, 0)
+			.saturating_add(Weight::from_parts(0, 52645))
+			.saturating_add(Weight::from_parts(2_214, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `BridgeWestendMessages::PalletOperatingMode` (r:1 w:0)
+	/// Proof: `BridgeWestendMessages::PalletOperatingMode` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `BridgeWestendMessages::OutboundLanesStalled` (r:1 w:1)
+	/// Proof: `BridgeWestendMessages::OutboundLanesStalled` (`max_values`: Some(1), `max_size`: Some(21), added: 516, mode: `MaxEncodedLen`)
+	/// Storage: `BridgeWestendMessages::OutboundLanes` (r:1 w:0)
+	/// Proof: `BridgeWestendMessages::OutboundLanes` (`max_values`: Some(1), `max_size`: Some(44), added: 539, mode: `MaxEncodedLen`)
+	/// Storage: `BridgeWestendMessages::OutboundLanesLastActivity` (r:1 w:0)
+	/// Proof: `BridgeWestendMessages::OutboundLanesLastActivity` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	fn mark_lane_stalled() -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(25_102_000, 0)
+			.saturating_add(Weight::from_parts(0, 1529))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
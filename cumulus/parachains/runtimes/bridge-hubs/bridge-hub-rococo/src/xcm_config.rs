@@ -275,6 +275,9 @@ impl xcm_executor::Config for XcmConfig {
 	type CallDispatcher = WithOriginFilter<SafeCallFilter>;
 	type SafeCallFilter = SafeCallFilter;
 	type Aliasers = Nothing;
+	type HrmpNewChannelOpenRequestHandler = ();
+	type HrmpChannelAcceptedHandler = ();
+	type HrmpChannelClosingHandler = ();
 }
 
 pub type PriceForParentDelivery =
@@ -310,6 +313,8 @@ impl pallet_xcm::Config for Runtime {
 		MaxInstructions,
 	>;
 	type UniversalLocation = UniversalLocation;
+	type BridgeExporters = ();
+	type BridgedReserveHops = ();
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
@@ -24,19 +24,26 @@ use bridge_hub_rococo_runtime::{
 	ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, SessionKeys,
 	SignedExtra, TransactionPayment, UncheckedExtrinsic,
 };
+use bridge_hub_test_utils::test_cases::BridgeTestScenario;
 use codec::{Decode, Encode};
 use frame_support::{dispatch::GetDispatchInfo, parameter_types, traits::ConstU8};
 use parachains_common::{rococo::fee::WeightToFee, AccountId, AuraId, Balance};
 use sp_keyring::AccountKeyring::Alice;
 use sp_runtime::{
 	generic::{Era, SignedPayload},
-	AccountId32,
+	AccountId32, Percent,
 };
 use xcm::latest::prelude::*;
 
 // Para id of sibling chain used in tests.
 pub const SIBLING_PARACHAIN_ID: u32 = 1000;
 
+// Chain id of the Ethereum network used in tests.
+pub const ETHEREUM_CHAIN_ID: u64 = 11155111;
+
+// Maximal drift of the estimated fees between runs, see `bridge_hub_test_utils::fee_snapshot`.
+pub const MAX_FEE_DRIFT: Percent = Percent::from_percent(10);
+
 parameter_types! {
 	pub CheckingAccount: AccountId = PolkadotXcm::check_account();
 }
@@ -137,6 +144,24 @@ mod bridge_hub_rococo_tests {
 		)
 	}
 
+	#[test]
+	fn grandpa_authority_set_handoff_works() {
+		// for Westend finality
+		bridge_hub_test_utils::test_cases::grandpa_authority_set_handoff_works::<
+			Runtime,
+			BridgeGrandpaWestendInstance,
+		>(collator_session_keys(), bp_bridge_hub_rococo::BRIDGE_HUB_ROCOCO_PARACHAIN_ID)
+	}
+
+	#[test]
+	fn grandpa_mandatory_headers_are_free() {
+		// for Westend finality
+		bridge_hub_test_utils::test_cases::grandpa_mandatory_headers_are_free::<
+			Runtime,
+			BridgeGrandpaWestendInstance,
+		>(collator_session_keys(), bp_bridge_hub_rococo::BRIDGE_HUB_ROCOCO_PARACHAIN_ID)
+	}
+
 	#[test]
 	fn change_delivery_reward_by_governance_works() {
 		bridge_hub_test_utils::test_cases::change_storage_constant_by_governance_works::<
@@ -173,64 +198,158 @@ mod bridge_hub_rococo_tests {
 	}
 
 	#[test]
-	fn handle_export_message_from_system_parachain_add_to_outbound_queue_works() {
-		// for Westend
-		bridge_hub_test_utils::test_cases::handle_export_message_from_system_parachain_to_outbound_queue_works::<
-			Runtime,
-			XcmConfig,
-			WithBridgeHubWestendMessagesInstance,
-		>(
+	fn relayer_rewards_are_paid_in_native_token() {
+		use bridge_hub_rococo_runtime::Balances;
+		use frame_support::{assert_ok, traits::fungible::Mutate};
+
+		let rewards_account_params = bp_relayers::RewardsAccountParams::new(
+			XCM_LANE_FOR_ASSET_HUB_ROCOCO_TO_ASSET_HUB_WESTEND,
+			BridgeHubWestendChainId::get(),
+			bp_relayers::RewardsAccountOwner::ThisChain,
+		);
+		bridge_hub_test_utils::test_cases::relayer_rewards_are_paid_in_configured_asset::<Runtime>(
 			collator_session_keys(),
 			bp_bridge_hub_rococo::BRIDGE_HUB_ROCOCO_PARACHAIN_ID,
-			SIBLING_PARACHAIN_ID,
-			Box::new(|runtime_event_encoded: Vec<u8>| {
-				match RuntimeEvent::decode(&mut &runtime_event_encoded[..]) {
-					Ok(RuntimeEvent::BridgeWestendMessages(event)) => Some(event),
-					_ => None,
-				}
-			}),
-			|| ExportMessage { network: Westend, destination: X1(Parachain(bridge_to_westend_config::AssetHubWestendParaId::get().into())), xcm: Xcm(vec![]) },
-			XCM_LANE_FOR_ASSET_HUB_ROCOCO_TO_ASSET_HUB_WESTEND,
+			AccountId::from(Alice),
+			rewards_account_params,
+			ExistentialDeposit::get() * 10,
+			|params, reward| {
+				let rewards_account =
+					bp_relayers::PayRewardFromAccount::<Balances, AccountId>::rewards_account(
+						params,
+					);
+				assert_ok!(Balances::mint_into(&rewards_account, reward));
+			},
+			|who| Balances::free_balance(who),
+		)
+	}
+
+	fn bridge_test_scenario() -> BridgeTestScenario<
+		Runtime,
+		AllPalletsWithoutSystem,
+		XcmConfig,
+		ParachainSystem,
+		WithBridgeHubWestendMessagesInstance,
+	> {
+		BridgeTestScenario::new(
+			collator_session_keys,
+			bp_bridge_hub_rococo::BRIDGE_HUB_ROCOCO_PARACHAIN_ID,
+		)
+		.with_sibling_parachain(SIBLING_PARACHAIN_ID)
+		.with_lane(XCM_LANE_FOR_ASSET_HUB_ROCOCO_TO_ASSET_HUB_WESTEND)
+		.with_bridge_messages_event(|runtime_event_encoded: Vec<u8>| {
+			match RuntimeEvent::decode(&mut &runtime_event_encoded[..]) {
+				Ok(RuntimeEvent::BridgeWestendMessages(event)) => Some(event),
+				_ => None,
+			}
+		})
+		.with_parachain_system_event(|runtime_event_encoded: Vec<u8>| {
+			match RuntimeEvent::decode(&mut &runtime_event_encoded[..]) {
+				Ok(RuntimeEvent::ParachainSystem(event)) => Some(event),
+				_ => None,
+			}
+		})
+		.with_xcmp_queue_event(|runtime_event_encoded: Vec<u8>| {
+			match RuntimeEvent::decode(&mut &runtime_event_encoded[..]) {
+				Ok(RuntimeEvent::XcmpQueue(event)) => Some(event),
+				_ => None,
+			}
+		})
+		.with_prepare_configuration(|| {
+			PolkadotXcm::force_xcm_version(
+				RuntimeOrigin::root(),
+				Box::new(BridgeHubWestendLocation::get()),
+				XCM_VERSION,
+			)
+			.expect("version saved!")
+		})
+	}
+
+	#[test]
+	fn handle_export_message_from_system_parachain_add_to_outbound_queue_works() {
+		// for Westend
+		bridge_test_scenario().run_export_message_case(
+			|| ExportMessage {
+				network: Westend,
+				destination: X1(Parachain(
+					bridge_to_westend_config::AssetHubWestendParaId::get().into(),
+				)),
+				xcm: Xcm(vec![]),
+			},
 			Some((TokenLocation::get(), ExistentialDeposit::get()).into()),
-			// value should be >= than value generated by `can_calculate_weight_for_paid_export_message_with_reserve_transfer`
-			Some((TokenLocation::get(), bp_bridge_hub_rococo::BridgeHubRococoBaseXcmFeeInRocs::get()).into()),
-			|| PolkadotXcm::force_xcm_version(RuntimeOrigin::root(), Box::new(BridgeHubWestendLocation::get()), XCM_VERSION).expect("version saved!"),
+			// value should be >= than value generated by
+			// `can_calculate_weight_for_paid_export_message_with_reserve_transfer`
+			Some(
+				(
+					TokenLocation::get(),
+					bp_bridge_hub_rococo::BridgeHubRococoBaseXcmFeeInRocs::get(),
+				)
+					.into(),
+			),
 		)
 	}
 
 	#[test]
-	fn message_dispatch_routing_works() {
-		// from Westend
-		bridge_hub_test_utils::test_cases::message_dispatch_routing_works::<
-			Runtime,
-			AllPalletsWithoutSystem,
-			XcmConfig,
-			ParachainSystem,
-			WithBridgeHubWestendMessagesInstance,
-			RelayNetwork,
-			WestendGlobalConsensusNetwork,
-			ConstU8<2>,
-		>(
+	fn send_unpaid_transfer_token_message_to_ethereum_fails() {
+		use bridge_hub_test_utils::test_cases::from_ethereum;
+
+		from_ethereum::send_unpaid_transfer_token_message_to_ethereum_fails::<Runtime, XcmConfig>(
 			collator_session_keys(),
 			bp_bridge_hub_rococo::BRIDGE_HUB_ROCOCO_PARACHAIN_ID,
 			SIBLING_PARACHAIN_ID,
+			Ethereum { chain_id: ETHEREUM_CHAIN_ID },
+			|network| ExportMessage { network, destination: Here, xcm: Xcm(vec![]) },
+			(TokenLocation::get(), bp_bridge_hub_rococo::BridgeHubRococoBaseXcmFeeInRocs::get())
+				.into(),
 			Box::new(|runtime_event_encoded: Vec<u8>| {
-				match RuntimeEvent::decode(&mut &runtime_event_encoded[..]) {
-					Ok(RuntimeEvent::ParachainSystem(event)) => Some(event),
-					_ => None,
-				}
-			}),
-			Box::new(|runtime_event_encoded: Vec<u8>| {
-				match RuntimeEvent::decode(&mut &runtime_event_encoded[..]) {
-					Ok(RuntimeEvent::XcmpQueue(event)) => Some(event),
-					_ => None,
-				}
+				matches!(
+					RuntimeEvent::decode(&mut &runtime_event_encoded[..]),
+					Ok(RuntimeEvent::BridgeWestendMessages(
+						pallet_bridge_messages::Event::MessageAccepted { .. }
+					))
+				)
 			}),
-			XCM_LANE_FOR_ASSET_HUB_ROCOCO_TO_ASSET_HUB_WESTEND,
 			|| (),
 		)
 	}
 
+	#[test]
+	fn message_dispatch_routing_works() {
+		// from Westend
+		bridge_test_scenario()
+			.run_dispatch_routing_case::<RelayNetwork, WestendGlobalConsensusNetwork, ConstU8<2>>()
+	}
+
+	#[test]
+	fn bridge_congestion_signal_works() {
+		// to Westend
+		fn report_bridge_status(is_congested: bool) -> Xcm<()> {
+			Xcm(vec![
+				UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+				Transact {
+					origin_kind: OriginKind::Xcm,
+					require_weight_at_most:
+						bp_asset_hub_rococo::XcmBridgeHubRouterTransactCallMaxWeight::get(),
+					call: bp_asset_hub_rococo::Call::ToWestendXcmRouter(
+						bp_asset_hub_rococo::XcmBridgeHubRouterCall::report_bridge_status {
+							bridge_id: Default::default(),
+							is_congested,
+						},
+					)
+					.encode()
+					.into(),
+				},
+			])
+		}
+
+		bridge_test_scenario().run_congestion_signal_case(
+			Westend,
+			X1(Parachain(bridge_to_westend_config::AssetHubWestendParaId::get().into())),
+			|| report_bridge_status(true),
+			|| report_bridge_status(false),
+		)
+	}
+
 	#[test]
 	fn relayed_incoming_message_works() {
 		// from Westend
@@ -255,6 +374,16 @@ mod bridge_hub_rococo_tests {
 		)
 	}
 
+	#[test]
+	fn inbound_messages_proof_size_limits_are_enforced() {
+		// from Westend
+		bridge_hub_test_utils::test_cases::inbound_messages_proof_size_limits_are_enforced::<
+			Runtime,
+			WithBridgeHubWestendMessagesInstance,
+			WithBridgeHubWestendMessageBridge,
+		>(collator_session_keys(), bp_bridge_hub_rococo::BRIDGE_HUB_ROCOCO_PARACHAIN_ID)
+	}
+
 	#[test]
 	pub fn complex_relay_extrinsic_works() {
 		// for Westend
@@ -296,6 +425,13 @@ mod bridge_hub_rococo_tests {
 			estimated,
 			max_expected
 		);
+
+		// check that the estimated value has not drifted since the previous run
+		bridge_hub_test_utils::fee_snapshot::check_and_record_fee::<Runtime>(
+			"paid_export_message_with_reserve_transfer",
+			estimated,
+			MAX_FEE_DRIFT,
+		);
 	}
 
 	#[test]
@@ -319,6 +455,13 @@ mod bridge_hub_rococo_tests {
 			estimated,
 			max_expected
 		);
+
+		// check that the estimated value has not drifted since the previous run
+		bridge_hub_test_utils::fee_snapshot::check_and_record_fee::<Runtime>(
+			"complex_message_delivery_transaction",
+			estimated,
+			MAX_FEE_DRIFT,
+		);
 	}
 
 	#[test]
@@ -342,5 +485,12 @@ mod bridge_hub_rococo_tests {
 			estimated,
 			max_expected
 		);
+
+		// check that the estimated value has not drifted since the previous run
+		bridge_hub_test_utils::fee_snapshot::check_and_record_fee::<Runtime>(
+			"complex_message_confirmation_transaction",
+			estimated,
+			MAX_FEE_DRIFT,
+		);
 	}
 }
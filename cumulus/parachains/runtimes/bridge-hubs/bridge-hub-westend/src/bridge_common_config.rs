@@ -46,5 +46,6 @@ impl pallet_bridge_relayers::Config for Runtime {
 		RequiredStakeForStakeAndSlash,
 		RelayerStakeLease,
 	>;
+	type BridgedRelayerOrigin = frame_system::EnsureNever<AccountId>;
 	type WeightInfo = weights::pallet_bridge_relayers::WeightInfo<Runtime>;
 }
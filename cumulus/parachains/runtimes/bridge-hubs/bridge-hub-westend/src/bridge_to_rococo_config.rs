@@ -77,6 +77,8 @@ parameter_types! {
 
 	// Lanes
 	pub ActiveOutboundLanesToBridgeHubRococo: &'static [bp_messages::LaneId] = &[XCM_LANE_FOR_ASSET_HUB_WESTEND_TO_ASSET_HUB_ROCOCO];
+	// the lane is considered stalled if no deliveries are confirmed for this number of blocks
+	pub const LaneStallThreshold: parachains_common::BlockNumber = 2 * parachains_common::HOURS;
 	pub const AssetHubWestendToAssetHubRococoMessagesLane: bp_messages::LaneId = XCM_LANE_FOR_ASSET_HUB_WESTEND_TO_ASSET_HUB_ROCOCO;
 	pub FromAssetHubWestendToAssetHubRococoRoute: SenderAndLane = SenderAndLane::new(
 		ParentThen(X1(Parachain(AssetHubWestendParaId::get().into()))).into(),
@@ -266,6 +268,8 @@ impl pallet_bridge_messages::Config<WithBridgeHubRococoMessagesInstance> for Run
 		>,
 	>;
 	type OnMessagesDelivered = OnMessagesDelivered;
+	type LaneStallThreshold = LaneStallThreshold;
+	type OnLaneStalled = ();
 }
 
 /// Add support for the export and dispatch of XCM programs.
@@ -18,6 +18,7 @@
 
 use bp_polkadot_core::Signature;
 use bridge_common_config::{DeliveryRewardInBalance, RequiredStakeForStakeAndSlash};
+use bridge_hub_test_utils::test_cases::BridgeTestScenario;
 use bridge_hub_westend_runtime::{
 	bridge_common_config, bridge_to_rococo_config,
 	xcm_config::{RelayNetwork, WestendLocation, XcmConfig},
@@ -36,13 +37,19 @@ use parachains_common::{westend::fee::WeightToFee, AccountId, AuraId, Balance};
 use sp_keyring::AccountKeyring::Alice;
 use sp_runtime::{
 	generic::{Era, SignedPayload},
-	AccountId32,
+	AccountId32, Percent,
 };
 use xcm::latest::prelude::*;
 
 // Para id of sibling chain used in tests.
 pub const SIBLING_PARACHAIN_ID: u32 = 1000;
 
+// Chain id of the Ethereum network used in tests.
+pub const ETHEREUM_CHAIN_ID: u64 = 11155111;
+
+// Maximal drift of the estimated fees between runs, see `bridge_hub_test_utils::fee_snapshot`.
+pub const MAX_FEE_DRIFT: Percent = Percent::from_percent(10);
+
 parameter_types! {
 	pub CheckingAccount: AccountId = PolkadotXcm::check_account();
 }
@@ -130,6 +137,22 @@ fn initialize_bridge_by_governance_works() {
 	)
 }
 
+#[test]
+fn grandpa_authority_set_handoff_works() {
+	bridge_hub_test_utils::test_cases::grandpa_authority_set_handoff_works::<
+		Runtime,
+		BridgeGrandpaRococoInstance,
+	>(collator_session_keys(), bp_bridge_hub_westend::BRIDGE_HUB_WESTEND_PARACHAIN_ID)
+}
+
+#[test]
+fn grandpa_mandatory_headers_are_free() {
+	bridge_hub_test_utils::test_cases::grandpa_mandatory_headers_are_free::<
+		Runtime,
+		BridgeGrandpaRococoInstance,
+	>(collator_session_keys(), bp_bridge_hub_westend::BRIDGE_HUB_WESTEND_PARACHAIN_ID)
+}
+
 #[test]
 fn change_delivery_reward_by_governance_works() {
 	bridge_hub_test_utils::test_cases::change_storage_constant_by_governance_works::<
@@ -161,62 +184,154 @@ fn change_required_stake_by_governance_works() {
 }
 
 #[test]
-fn handle_export_message_from_system_parachain_add_to_outbound_queue_works() {
-	bridge_hub_test_utils::test_cases::handle_export_message_from_system_parachain_to_outbound_queue_works::<
-			Runtime,
-			XcmConfig,
-			WithBridgeHubRococoMessagesInstance,
-		>(
-			collator_session_keys(),
-			bp_bridge_hub_westend::BRIDGE_HUB_WESTEND_PARACHAIN_ID,
-			SIBLING_PARACHAIN_ID,
-			Box::new(|runtime_event_encoded: Vec<u8>| {
-				match RuntimeEvent::decode(&mut &runtime_event_encoded[..]) {
-					Ok(RuntimeEvent::BridgeRococoMessages(event)) => Some(event),
-					_ => None,
-				}
-			}),
-			|| ExportMessage { network: Rococo, destination: X1(Parachain(bridge_to_rococo_config::AssetHubRococoParaId::get().into())), xcm: Xcm(vec![]) },
-			XCM_LANE_FOR_ASSET_HUB_WESTEND_TO_ASSET_HUB_ROCOCO,
-			Some((WestendLocation::get(), ExistentialDeposit::get()).into()),
-			// value should be >= than value generated by `can_calculate_weight_for_paid_export_message_with_reserve_transfer`
-			Some((WestendLocation::get(), bp_bridge_hub_westend::BridgeHubWestendBaseXcmFeeInWnds::get()).into()),
-			|| PolkadotXcm::force_xcm_version(RuntimeOrigin::root(), Box::new(BridgeHubRococoLocation::get()), XCM_VERSION).expect("version saved!"),
+fn relayer_rewards_are_paid_in_native_token() {
+	use bridge_hub_westend_runtime::Balances;
+	use frame_support::{assert_ok, traits::fungible::Mutate};
+
+	let rewards_account_params = bp_relayers::RewardsAccountParams::new(
+		XCM_LANE_FOR_ASSET_HUB_WESTEND_TO_ASSET_HUB_ROCOCO,
+		BridgeHubRococoChainId::get(),
+		bp_relayers::RewardsAccountOwner::ThisChain,
+	);
+	bridge_hub_test_utils::test_cases::relayer_rewards_are_paid_in_configured_asset::<Runtime>(
+		collator_session_keys(),
+		bp_bridge_hub_westend::BRIDGE_HUB_WESTEND_PARACHAIN_ID,
+		AccountId::from(Alice),
+		rewards_account_params,
+		ExistentialDeposit::get() * 10,
+		|params, reward| {
+			let rewards_account =
+				bp_relayers::PayRewardFromAccount::<Balances, AccountId>::rewards_account(params);
+			assert_ok!(Balances::mint_into(&rewards_account, reward));
+		},
+		|who| Balances::free_balance(who),
+	)
+}
+
+fn bridge_test_scenario() -> BridgeTestScenario<
+	Runtime,
+	AllPalletsWithoutSystem,
+	XcmConfig,
+	ParachainSystem,
+	WithBridgeHubRococoMessagesInstance,
+> {
+	BridgeTestScenario::new(
+		collator_session_keys,
+		bp_bridge_hub_westend::BRIDGE_HUB_WESTEND_PARACHAIN_ID,
+	)
+	.with_sibling_parachain(SIBLING_PARACHAIN_ID)
+	.with_lane(XCM_LANE_FOR_ASSET_HUB_WESTEND_TO_ASSET_HUB_ROCOCO)
+	.with_bridge_messages_event(|runtime_event_encoded: Vec<u8>| {
+		match RuntimeEvent::decode(&mut &runtime_event_encoded[..]) {
+			Ok(RuntimeEvent::BridgeRococoMessages(event)) => Some(event),
+			_ => None,
+		}
+	})
+	.with_parachain_system_event(|runtime_event_encoded: Vec<u8>| {
+		match RuntimeEvent::decode(&mut &runtime_event_encoded[..]) {
+			Ok(RuntimeEvent::ParachainSystem(event)) => Some(event),
+			_ => None,
+		}
+	})
+	.with_xcmp_queue_event(|runtime_event_encoded: Vec<u8>| {
+		match RuntimeEvent::decode(&mut &runtime_event_encoded[..]) {
+			Ok(RuntimeEvent::XcmpQueue(event)) => Some(event),
+			_ => None,
+		}
+	})
+	.with_prepare_configuration(|| {
+		PolkadotXcm::force_xcm_version(
+			RuntimeOrigin::root(),
+			Box::new(BridgeHubRococoLocation::get()),
+			XCM_VERSION,
 		)
+		.expect("version saved!")
+	})
 }
 
 #[test]
-fn message_dispatch_routing_works() {
-	bridge_hub_test_utils::test_cases::message_dispatch_routing_works::<
-		Runtime,
-		AllPalletsWithoutSystem,
-		XcmConfig,
-		ParachainSystem,
-		WithBridgeHubRococoMessagesInstance,
-		RelayNetwork,
-		bridge_to_rococo_config::RococoGlobalConsensusNetwork,
-		ConstU8<2>,
-	>(
+fn handle_export_message_from_system_parachain_add_to_outbound_queue_works() {
+	bridge_test_scenario().run_export_message_case(
+		|| ExportMessage {
+			network: Rococo,
+			destination: X1(Parachain(bridge_to_rococo_config::AssetHubRococoParaId::get().into())),
+			xcm: Xcm(vec![]),
+		},
+		Some((WestendLocation::get(), ExistentialDeposit::get()).into()),
+		// value should be >= than value generated by
+		// `can_calculate_weight_for_paid_export_message_with_reserve_transfer`
+		Some(
+			(
+				WestendLocation::get(),
+				bp_bridge_hub_westend::BridgeHubWestendBaseXcmFeeInWnds::get(),
+			)
+				.into(),
+		),
+	)
+}
+
+#[test]
+fn send_unpaid_transfer_token_message_to_ethereum_fails() {
+	use bridge_hub_test_utils::test_cases::from_ethereum;
+
+	from_ethereum::send_unpaid_transfer_token_message_to_ethereum_fails::<Runtime, XcmConfig>(
 		collator_session_keys(),
 		bp_bridge_hub_westend::BRIDGE_HUB_WESTEND_PARACHAIN_ID,
 		SIBLING_PARACHAIN_ID,
+		Ethereum { chain_id: ETHEREUM_CHAIN_ID },
+		|network| ExportMessage { network, destination: Here, xcm: Xcm(vec![]) },
+		(WestendLocation::get(), bp_bridge_hub_westend::BridgeHubWestendBaseXcmFeeInWnds::get())
+			.into(),
 		Box::new(|runtime_event_encoded: Vec<u8>| {
-			match RuntimeEvent::decode(&mut &runtime_event_encoded[..]) {
-				Ok(RuntimeEvent::ParachainSystem(event)) => Some(event),
-				_ => None,
-			}
-		}),
-		Box::new(|runtime_event_encoded: Vec<u8>| {
-			match RuntimeEvent::decode(&mut &runtime_event_encoded[..]) {
-				Ok(RuntimeEvent::XcmpQueue(event)) => Some(event),
-				_ => None,
-			}
+			matches!(
+				RuntimeEvent::decode(&mut &runtime_event_encoded[..]),
+				Ok(RuntimeEvent::BridgeRococoMessages(
+					pallet_bridge_messages::Event::MessageAccepted { .. }
+				))
+			)
 		}),
-		XCM_LANE_FOR_ASSET_HUB_WESTEND_TO_ASSET_HUB_ROCOCO,
 		|| (),
 	)
 }
 
+#[test]
+fn message_dispatch_routing_works() {
+	bridge_test_scenario().run_dispatch_routing_case::<
+		RelayNetwork,
+		bridge_to_rococo_config::RococoGlobalConsensusNetwork,
+		ConstU8<2>,
+	>()
+}
+
+#[test]
+fn bridge_congestion_signal_works() {
+	fn report_bridge_status(is_congested: bool) -> Xcm<()> {
+		Xcm(vec![
+			UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+			Transact {
+				origin_kind: OriginKind::Xcm,
+				require_weight_at_most:
+					bp_asset_hub_westend::XcmBridgeHubRouterTransactCallMaxWeight::get(),
+				call: bp_asset_hub_westend::Call::ToRococoXcmRouter(
+					bp_asset_hub_westend::XcmBridgeHubRouterCall::report_bridge_status {
+						bridge_id: Default::default(),
+						is_congested,
+					},
+				)
+				.encode()
+				.into(),
+			},
+		])
+	}
+
+	bridge_test_scenario().run_congestion_signal_case(
+		Rococo,
+		X1(Parachain(bridge_to_rococo_config::AssetHubRococoParaId::get().into())),
+		|| report_bridge_status(true),
+		|| report_bridge_status(false),
+	)
+}
+
 #[test]
 fn relayed_incoming_message_works() {
 	bridge_hub_test_utils::test_cases::from_parachain::relayed_incoming_message_works::<
@@ -240,6 +355,15 @@ fn relayed_incoming_message_works() {
 	)
 }
 
+#[test]
+fn inbound_messages_proof_size_limits_are_enforced() {
+	bridge_hub_test_utils::test_cases::inbound_messages_proof_size_limits_are_enforced::<
+		Runtime,
+		WithBridgeHubRococoMessagesInstance,
+		WithBridgeHubRococoMessageBridge,
+	>(collator_session_keys(), bp_bridge_hub_westend::BRIDGE_HUB_WESTEND_PARACHAIN_ID)
+}
+
 #[test]
 pub fn complex_relay_extrinsic_works() {
 	bridge_hub_test_utils::test_cases::from_parachain::complex_relay_extrinsic_works::<
@@ -280,6 +404,13 @@ pub fn can_calculate_weight_for_paid_export_message_with_reserve_transfer() {
 			estimated,
 			max_expected
 		);
+
+	// check that the estimated value has not drifted since the previous run
+	bridge_hub_test_utils::fee_snapshot::check_and_record_fee::<Runtime>(
+		"paid_export_message_with_reserve_transfer",
+		estimated,
+		MAX_FEE_DRIFT,
+	);
 }
 
 #[test]
@@ -303,6 +434,13 @@ pub fn can_calculate_fee_for_complex_message_delivery_transaction() {
 		estimated,
 		max_expected
 	);
+
+	// check that the estimated value has not drifted since the previous run
+	bridge_hub_test_utils::fee_snapshot::check_and_record_fee::<Runtime>(
+		"complex_message_delivery_transaction",
+		estimated,
+		MAX_FEE_DRIFT,
+	);
 }
 
 #[test]
@@ -326,4 +464,11 @@ pub fn can_calculate_fee_for_complex_message_confirmation_transaction() {
 		estimated,
 		max_expected
 	);
+
+	// check that the estimated value has not drifted since the previous run
+	bridge_hub_test_utils::fee_snapshot::check_and_record_fee::<Runtime>(
+		"complex_message_confirmation_transaction",
+		estimated,
+		MAX_FEE_DRIFT,
+	);
 }
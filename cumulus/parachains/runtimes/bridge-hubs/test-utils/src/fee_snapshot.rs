@@ -0,0 +1,157 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Snapshots of the fees, estimated by the test-cases of bridge runtimes.
+//!
+//! The estimated fees are stored in a JSON file, keyed by the runtime spec name, the runtime spec
+//! version and the name of the estimated fee, e.g.:
+//!
+//! ```json
+//! {
+//!   "bridge-hub-rococo": {
+//!     "1005000": {
+//!       "complex_message_delivery_transaction": 1234567890
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! The file is kept between runs, so that every new estimation is compared with the previously
+//! recorded one and fee regressions are caught automatically.
+
+use frame_support::traits::Get;
+use serde::{Deserialize, Serialize};
+use sp_runtime::Percent;
+use sp_version::RuntimeVersion;
+use std::{
+	collections::BTreeMap,
+	path::{Path, PathBuf},
+	sync::Mutex,
+};
+
+/// Environment variable with the path of the snapshot file, used by [`check_and_record_fee`].
+pub const FEE_SNAPSHOT_PATH_ENV: &str = "BRIDGE_HUB_FEE_SNAPSHOT";
+
+/// Fees of a single runtime version, keyed by the name of the fee.
+pub type VersionFees = BTreeMap<String, u128>;
+
+/// Serializes access to the snapshot file from tests that are running in parallel.
+static SNAPSHOT_FILE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Estimated fees of runtimes, keyed by the runtime spec name and spec version.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeSnapshot(BTreeMap<String, BTreeMap<u32, VersionFees>>);
+
+impl FeeSnapshot {
+	/// Load the snapshot from the JSON file at `path`.
+	///
+	/// Returns an empty snapshot if the file does not exist yet.
+	pub fn load(path: &Path) -> Self {
+		match std::fs::read(path) {
+			Ok(content) => serde_json::from_slice(&content).unwrap_or_else(|e| {
+				panic!("fee snapshot at {} is not valid: {:?}", path.display(), e)
+			}),
+			Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::default(),
+			Err(e) => panic!("failed to read fee snapshot at {}: {:?}", path.display(), e),
+		}
+	}
+
+	/// Save the snapshot as a JSON file at `path`.
+	pub fn save(&self, path: &Path) {
+		let content =
+			serde_json::to_vec_pretty(self).expect("fee snapshot is always serializable; qed");
+		std::fs::write(path, content).unwrap_or_else(|e| {
+			panic!("failed to write fee snapshot to {}: {:?}", path.display(), e)
+		})
+	}
+
+	/// Return the fee `name`, recorded for the given runtime `version`.
+	pub fn fee(&self, version: &RuntimeVersion, name: &str) -> Option<u128> {
+		self.0
+			.get(&version.spec_name.to_string())
+			.and_then(|versions| versions.get(&version.spec_version))
+			.and_then(|fees| fees.get(name))
+			.copied()
+	}
+
+	/// Return the fee `name`, recorded for the latest spec version of the runtime that is not
+	/// newer than the given `version`.
+	pub fn previous_fee(&self, version: &RuntimeVersion, name: &str) -> Option<u128> {
+		self.0
+			.get(&version.spec_name.to_string())?
+			.range(..=version.spec_version)
+			.rev()
+			.find_map(|(_, fees)| fees.get(name).copied())
+	}
+
+	/// Record the fee `name` for the given runtime `version`.
+	pub fn record(&mut self, version: &RuntimeVersion, name: &str, fee: u128) {
+		self.0
+			.entry(version.spec_name.to_string())
+			.or_default()
+			.entry(version.spec_version)
+			.or_default()
+			.insert(name.into(), fee);
+	}
+}
+
+/// Asserts that the `current` fee differs from the `previous` fee by at most `max_drift` of the
+/// `previous` fee.
+pub fn assert_fee_drift_within(name: &str, previous: u128, current: u128, max_drift: Percent) {
+	let drift = previous.abs_diff(current);
+	let max_allowed_drift = max_drift.mul_ceil(previous);
+	assert!(
+		drift <= max_allowed_drift,
+		"fee `{}` has changed from {} to {}, which is more than {:?} of the previous fee. Please \
+		check if the change is expected and update the fee snapshot",
+		name,
+		previous,
+		current,
+		max_drift,
+	);
+}
+
+/// Compares the estimated `fee` of the `Runtime` with the previously recorded one and records it
+/// into the snapshot file at the path from the [`FEE_SNAPSHOT_PATH_ENV`] environment variable.
+///
+/// The fee is compared with the fee of the latest recorded runtime version, that is not newer
+/// than the version of the `Runtime`. If it has drifted by more than `max_drift`, the snapshot
+/// is left untouched and the call panics.
+///
+/// Does nothing if the environment variable is not set.
+pub fn check_and_record_fee<Runtime: frame_system::Config>(
+	name: &str,
+	fee: u128,
+	max_drift: Percent,
+) {
+	let Some(path) = std::env::var_os(FEE_SNAPSHOT_PATH_ENV).map(PathBuf::from) else {
+		log::info!(
+			target: "bridges::estimate",
+			"Fee snapshot is not checked, because `{}` is not set",
+			FEE_SNAPSHOT_PATH_ENV,
+		);
+		return
+	};
+
+	let _lock = SNAPSHOT_FILE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+	let version = Runtime::Version::get();
+	let mut snapshot = FeeSnapshot::load(&path);
+	if let Some(previous) = snapshot.previous_fee(&version, name) {
+		assert_fee_drift_within(name, previous, fee, max_drift);
+	}
+	snapshot.record(&version, name, fee);
+	snapshot.save(&path);
+}
@@ -16,6 +16,7 @@
 
 //! Module contains predefined test-case scenarios for "BridgeHub" `Runtime`s.
 
+pub mod fee_snapshot;
 pub mod test_cases;
 pub mod test_data;
 
@@ -0,0 +1,78 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Module contains predefined test-case scenarios for `Runtime` with bridging capabilities
+//! with the Ethereum network.
+//!
+//! Unlike GRANDPA-based bridges, the Ethereum bridge does not use `pallet-bridge-messages`, so
+//! the queue-specific parts (e.g. checking that the message has been enqueued) are provided by
+//! the runtime as closures.
+
+use crate::test_cases::{run_test, RuntimeHelper};
+
+use asset_test_utils::BasicParachainRuntime;
+use codec::Encode;
+use parachains_runtimes_test_utils::{
+	AccountIdOf, CollatorSessionKeys, ValidatorIdOf, XcmReceivedFrom,
+};
+use xcm::latest::prelude::*;
+use xcm_executor::XcmExecutor;
+
+/// Test-case makes sure that `Runtime` rejects `ExportMessage` targeting Ethereum when the
+/// sender is not able to pay the fees.
+pub fn send_unpaid_transfer_token_message_to_ethereum_fails<Runtime, XcmConfig>(
+	collator_session_key: CollatorSessionKeys<Runtime>,
+	runtime_para_id: u32,
+	sibling_parachain_id: u32,
+	ethereum_network: NetworkId,
+	export_message_instruction: fn(NetworkId) -> Instruction<XcmConfig::RuntimeCall>,
+	fee: MultiAsset,
+	is_message_enqueued: Box<dyn Fn(Vec<u8>) -> bool>,
+	prepare_configuration: impl Fn(),
+) where
+	Runtime: BasicParachainRuntime,
+	XcmConfig: xcm_executor::Config,
+	ValidatorIdOf<Runtime>: From<AccountIdOf<Runtime>>,
+{
+	assert_ne!(runtime_para_id, sibling_parachain_id);
+	let sibling_parachain_location = MultiLocation::new(1, Parachain(sibling_parachain_id));
+
+	run_test::<Runtime, _>(collator_session_key, runtime_para_id, vec![], || {
+		prepare_configuration();
+
+		// sibling's sovereign account has no funds at all
+		let xcm = Xcm(vec![
+			WithdrawAsset(MultiAssets::from(vec![fee.clone()])),
+			BuyExecution { fees: fee, weight_limit: Unlimited },
+			export_message_instruction(ethereum_network),
+		]);
+
+		// execute XCM
+		let hash = xcm.using_encoded(sp_io::hashing::blake2_256);
+		let outcome = XcmExecutor::<XcmConfig>::execute_xcm(
+			sibling_parachain_location,
+			xcm,
+			hash,
+			RuntimeHelper::<Runtime>::xcm_max_weight(XcmReceivedFrom::Sibling),
+		);
+		assert!(outcome.ensure_complete().is_err());
+
+		// check that nothing has been enqueued
+		assert!(!<frame_system::Pallet<Runtime>>::events()
+			.into_iter()
+			.any(|e| is_message_enqueued(e.event.encode())));
+	})
+}
@@ -16,11 +16,17 @@
 
 //! Module contains predefined test-case scenarios for `Runtime` with bridging capabilities.
 //!
-//! This file contains tests, suitable for all bridge runtimes. See `from_parachain` and
-//! `from_grandpa_chain` submodules for tests, that are specific to the bridged chain type.
+//! This file contains tests, suitable for all bridge runtimes. See `from_parachain`,
+//! `from_grandpa_chain` and `from_ethereum` submodules for tests, that are specific to the
+//! bridged chain type.
+//!
+//! The `scenario` submodule allows to configure a bridge once and then run the test-cases of
+//! this file with that configuration.
 
+pub mod from_ethereum;
 pub mod from_grandpa_chain;
 pub mod from_parachain;
+pub mod scenario;
 
 pub(crate) mod helpers;
 
@@ -28,31 +34,56 @@ use crate::test_data;
 
 use asset_test_utils::BasicParachainRuntime;
 use bp_messages::{
-	target_chain::{DispatchMessage, DispatchMessageData, MessageDispatch},
+	source_chain::OnMessagesDelivered,
+	target_chain::{DispatchMessage, DispatchMessageData, MessageDispatch, SourceHeaderChain},
 	LaneId, MessageKey, OutboundLaneData,
 };
-use bridge_runtime_common::messages_xcm_extension::{
-	XcmAsPlainPayload, XcmBlobMessageDispatchResult,
+use bp_relayers::RewardsAccountParams;
+use bp_runtime::{Chain, HashOf, StorageProofSize, UnderlyingChainOf};
+use bp_test_utils::{JustificationGeneratorParams, ALICE, DAVE, EVE, FERDIE};
+use bridge_runtime_common::{
+	messages::{
+		target::{
+			maximal_incoming_message_dispatch_weight, maximal_incoming_message_size,
+			FromBridgedChainMessagesProof,
+		},
+		BridgedChain as MessageBridgedChain, MessageBridge, ThisChain as MessageThisChain,
+	},
+	messages_generation::{encode_all_messages, encode_lane_data, prepare_messages_storage_proof},
+	messages_xcm_extension::{
+		XcmAsPlainPayload, XcmBlobMessageDispatchResult, OUTBOUND_LANE_CONGESTED_THRESHOLD,
+		OUTBOUND_LANE_UNCONGESTED_THRESHOLD,
+	},
 };
 use codec::Encode;
 use frame_support::{
 	assert_ok,
+	dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays, PostDispatchInfo},
 	traits::{Get, OnFinalize, OnInitialize, OriginTrait},
 };
 use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_bridge_grandpa::{BridgedBlockNumber, BridgedHeader};
 use parachains_common::AccountId;
 use parachains_runtimes_test_utils::{
 	mock_open_hrmp_channel, AccountIdOf, BalanceOf, CollatorSessionKeys, ExtBuilder, ValidatorIdOf,
 	XcmReceivedFrom,
 };
-use sp_runtime::{traits::Zero, AccountId32};
+use sp_consensus_grandpa::{AuthorityId, ConsensusLog, ScheduledChange, GRANDPA_ENGINE_ID};
+use sp_keyring::AccountKeyring::Dave;
+use sp_runtime::{
+	traits::{Dispatchable, Header as HeaderT, Zero},
+	transaction_validity::InvalidTransaction,
+	AccountId32, DigestItem,
+};
 use xcm::{latest::prelude::*, AlwaysLatest};
 use xcm_builder::DispatchBlobError;
 use xcm_executor::{
-	traits::{TransactAsset, WeightBounds},
+	traits::{export_xcm, TransactAsset, WeightBounds},
 	XcmExecutor,
 };
 
+pub use scenario::BridgeTestScenario;
+
 // Re-export test_case from assets
 pub use asset_test_utils::include_teleports_for_native_asset_works;
 
@@ -130,6 +161,301 @@ pub fn initialize_bridge_by_governance_works<Runtime, GrandpaPalletInstance>(
 	})
 }
 
+/// Test-case makes sure that the bridge GRANDPA pallet follows a chain of authority set handoffs
+/// of the bridged chain.
+///
+/// Mandatory headers (headers that enact a scheduled authority set change) are imported one after
+/// another. After every handoff the tracked authority set must be the scheduled one, regular
+/// headers must be accepted with justifications of the new set and rejected with justifications
+/// of the stale set.
+pub fn grandpa_authority_set_handoff_works<Runtime, GrandpaPalletInstance>(
+	collator_session_key: CollatorSessionKeys<Runtime>,
+	runtime_para_id: u32,
+) where
+	Runtime: BasicParachainRuntime + pallet_bridge_grandpa::Config<GrandpaPalletInstance>,
+	GrandpaPalletInstance: 'static,
+	ValidatorIdOf<Runtime>: From<AccountIdOf<Runtime>>,
+	AccountIdOf<Runtime>: From<AccountId32>,
+{
+	run_test::<Runtime, _>(collator_session_key, runtime_para_id, vec![], || {
+		let relayer: AccountIdOf<Runtime> = AccountId32::from([1u8; 32]).into();
+		let submit = |header: BridgedHeader<Runtime, GrandpaPalletInstance>,
+		              set_id: u64,
+		              authorities: &[(bp_test_utils::Account, u64)]| {
+			let justification =
+				bp_test_utils::make_justification_for_header(JustificationGeneratorParams {
+					header: header.clone(),
+					set_id,
+					authorities: authorities.to_vec(),
+					..Default::default()
+				});
+			pallet_bridge_grandpa::Pallet::<Runtime, GrandpaPalletInstance>::submit_finality_proof(
+				frame_system::RawOrigin::Signed(relayer.clone()).into(),
+				Box::new(header),
+				justification,
+			)
+		};
+
+		// start with the default authority set (#1) at block #12345
+		let initial_block_number = 12345u32;
+		helpers::initialize_bridge_grandpa_pallet::<Runtime, GrandpaPalletInstance>(
+			test_data::initialization_data::<Runtime, GrandpaPalletInstance>(initial_block_number),
+		);
+
+		let handoffs = vec![
+			vec![(DAVE, 1), (EVE, 1), (FERDIE, 1)],
+			vec![(ALICE, 1), (DAVE, 1), (FERDIE, 1), (bp_test_utils::Account(6), 1)],
+			vec![(bp_test_utils::Account(7), 1), (bp_test_utils::Account(8), 1)],
+		];
+
+		let mut block_number = initial_block_number;
+		let mut set_id = 1;
+		let mut authorities = bp_test_utils::test_keyring();
+		for next_authorities in handoffs {
+			// regular header, finalized by the current set, is accepted
+			block_number += 1;
+			assert_ok!(submit(
+				bp_test_utils::test_header(block_number.into()),
+				set_id,
+				&authorities
+			));
+
+			// mandatory header is finalized by the current set and enacts the next set
+			block_number += 1;
+			let header =
+				mandatory_header::<Runtime, GrandpaPalletInstance>(block_number, &next_authorities);
+			let mandatory_header_hash = header.hash();
+			assert_ok!(submit(header, set_id, &authorities));
+			assert_eq!(
+				pallet_bridge_grandpa::BestFinalized::<Runtime, GrandpaPalletInstance>::get()
+					.map(|id| id.hash()),
+				Some(mandatory_header_hash),
+			);
+
+			// the tracked authority set has been advanced
+			let stale_set_id = set_id;
+			let stale_authorities = core::mem::replace(&mut authorities, next_authorities);
+			set_id += 1;
+			let current_set =
+				pallet_bridge_grandpa::CurrentAuthoritySet::<Runtime, GrandpaPalletInstance>::get();
+			assert_eq!(current_set.set_id, set_id);
+			assert_eq!(
+				current_set.authorities.into_inner(),
+				authorities
+					.iter()
+					.map(|(id, w)| (AuthorityId::from(*id), *w))
+					.collect::<Vec<_>>(),
+			);
+
+			// regular header, finalized by the stale set, is rejected
+			let stale_result = submit(
+				bp_test_utils::test_header((block_number + 1).into()),
+				stale_set_id,
+				&stale_authorities,
+			);
+			assert_eq!(
+				stale_result.map_err(|e| e.error),
+				Err(pallet_bridge_grandpa::Error::<Runtime, GrandpaPalletInstance>::InvalidJustification
+					.into()),
+			);
+		}
+
+		// headers of the last set are accepted
+		block_number += 1;
+		assert_ok!(submit(bp_test_utils::test_header(block_number.into()), set_id, &authorities));
+		assert_eq!(
+			pallet_bridge_grandpa::BestFinalized::<Runtime, GrandpaPalletInstance>::get()
+				.map(|id| id.number()),
+			Some(block_number.into()),
+		);
+	})
+}
+
+/// Test-case makes sure that relayers don't pay for the import of mandatory headers of the bridged
+/// chain, while regular headers are paid.
+///
+/// Only `MaxFreeMandatoryHeadersPerBlock` mandatory headers are free in every block. Further
+/// mandatory headers of the same block are paid like regular headers. There's no other interval
+/// of free headers, so a regular header is never free.
+pub fn grandpa_mandatory_headers_are_free<Runtime, GrandpaPalletInstance>(
+	collator_session_key: CollatorSessionKeys<Runtime>,
+	runtime_para_id: u32,
+) where
+	Runtime: BasicParachainRuntime + pallet_bridge_grandpa::Config<GrandpaPalletInstance>,
+	GrandpaPalletInstance: 'static,
+	ValidatorIdOf<Runtime>: From<AccountIdOf<Runtime>>,
+	AccountIdOf<Runtime>: From<AccountId32>,
+{
+	run_test::<Runtime, _>(collator_session_key, runtime_para_id, vec![], || {
+		type GrandpaPallet<Runtime, I> = pallet_bridge_grandpa::Pallet<Runtime, I>;
+
+		let relayer: AccountIdOf<Runtime> = AccountId32::from([1u8; 32]).into();
+		let authorities = bp_test_utils::test_keyring();
+		let submit = |header: BridgedHeader<Runtime, GrandpaPalletInstance>, set_id: u64| {
+			let justification =
+				bp_test_utils::make_justification_for_header(JustificationGeneratorParams {
+					header: header.clone(),
+					set_id,
+					authorities: authorities.clone(),
+					..Default::default()
+				});
+			GrandpaPallet::<Runtime, GrandpaPalletInstance>::submit_finality_proof(
+				frame_system::RawOrigin::Signed(relayer.clone()).into(),
+				Box::new(header),
+				justification,
+			)
+			.map(|post_info| post_info.pays_fee)
+		};
+
+		// start with the default authority set (#1) at block #12345
+		let mut block_number = 12345u32;
+		let mut set_id = 1;
+		helpers::initialize_bridge_grandpa_pallet::<Runtime, GrandpaPalletInstance>(
+			test_data::initialization_data::<Runtime, GrandpaPalletInstance>(block_number),
+		);
+		let this_block_number = frame_system::Pallet::<Runtime>::block_number();
+		GrandpaPallet::<Runtime, GrandpaPalletInstance>::on_initialize(this_block_number);
+
+		// regular header is paid
+		block_number += 1;
+		assert_eq!(submit(bp_test_utils::test_header(block_number.into()), set_id), Ok(Pays::Yes));
+
+		// mandatory headers are free, up to the limit of the block. The next set is the same as
+		// the current one, so that the same authorities may finalize all headers
+		let max_free_mandatory_headers = <Runtime as pallet_bridge_grandpa::Config<
+			GrandpaPalletInstance,
+		>>::MaxFreeMandatoryHeadersPerBlock::get();
+		for _ in 0..max_free_mandatory_headers {
+			block_number += 1;
+			assert_eq!(
+				submit(
+					mandatory_header::<Runtime, GrandpaPalletInstance>(block_number, &authorities),
+					set_id
+				),
+				Ok(Pays::No),
+			);
+			set_id += 1;
+		}
+
+		// the following mandatory header of the same block is paid
+		block_number += 1;
+		assert_eq!(
+			submit(
+				mandatory_header::<Runtime, GrandpaPalletInstance>(block_number, &authorities),
+				set_id
+			),
+			Ok(Pays::Yes),
+		);
+		set_id += 1;
+
+		// mandatory headers are free again in the next block
+		GrandpaPallet::<Runtime, GrandpaPalletInstance>::on_finalize(this_block_number);
+		GrandpaPallet::<Runtime, GrandpaPalletInstance>::on_initialize(
+			this_block_number + 1u32.into(),
+		);
+		block_number += 1;
+		assert_eq!(
+			submit(
+				mandatory_header::<Runtime, GrandpaPalletInstance>(block_number, &authorities),
+				set_id
+			),
+			Ok(Pays::No),
+		);
+	})
+}
+
+/// Make a header of the bridged chain, that enacts the `next_authorities` set.
+fn mandatory_header<Runtime, GrandpaPalletInstance>(
+	block_number: u32,
+	next_authorities: &[(bp_test_utils::Account, u64)],
+) -> BridgedHeader<Runtime, GrandpaPalletInstance>
+where
+	Runtime: pallet_bridge_grandpa::Config<GrandpaPalletInstance>,
+	GrandpaPalletInstance: 'static,
+{
+	let mut header: BridgedHeader<Runtime, GrandpaPalletInstance> =
+		bp_test_utils::test_header(block_number.into());
+	let consensus_log =
+		ConsensusLog::<BridgedBlockNumber<Runtime, GrandpaPalletInstance>>::ScheduledChange(
+			ScheduledChange {
+				next_authorities: next_authorities
+					.iter()
+					.map(|(id, w)| (AuthorityId::from(*id), *w))
+					.collect(),
+				delay: Zero::zero(),
+			},
+		);
+	header
+		.digest_mut()
+		.push(DigestItem::Consensus(GRANDPA_ENGINE_ID, consensus_log.encode()));
+	header
+}
+
+/// Test-case makes sure that relayer rewards, registered by `pallet-bridge-relayers`, may be
+/// claimed and are paid by the `PaymentProcedure` of the `Runtime`.
+///
+/// The rewards may be paid in any asset, not only in the native token. `prepare_rewards_account`
+/// must make `reward` available to the payment procedure (e.g. mint the bridged token to the
+/// rewards account) and `reward_balance_of` must return the balance of the relayer in that asset.
+pub fn relayer_rewards_are_paid_in_configured_asset<Runtime>(
+	collator_session_key: CollatorSessionKeys<Runtime>,
+	runtime_para_id: u32,
+	relayer: AccountIdOf<Runtime>,
+	rewards_account_params: RewardsAccountParams,
+	reward: Runtime::Reward,
+	prepare_rewards_account: impl FnOnce(RewardsAccountParams, Runtime::Reward),
+	reward_balance_of: impl Fn(&AccountIdOf<Runtime>) -> Runtime::Reward,
+) where
+	Runtime: BasicParachainRuntime + pallet_bridge_relayers::Config,
+	ValidatorIdOf<Runtime>: From<AccountIdOf<Runtime>>,
+	<Runtime as frame_system::Config>::AccountId:
+		Into<<<Runtime as frame_system::Config>::RuntimeOrigin as OriginTrait>::AccountId>,
+{
+	// the relayer has some native balance, so it may hold non-sufficient assets
+	let balances =
+		vec![(relayer.clone(), <Runtime as pallet_balances::Config>::ExistentialDeposit::get())];
+	run_test::<Runtime, _>(collator_session_key, runtime_para_id, balances, || {
+		// register reward, as the message delivery transaction extension does
+		pallet_bridge_relayers::Pallet::<Runtime>::register_relayer_reward(
+			rewards_account_params,
+			&relayer,
+			reward,
+		);
+		assert_eq!(
+			pallet_bridge_relayers::RelayerRewards::<Runtime>::get(
+				&relayer,
+				rewards_account_params
+			),
+			Some(reward),
+		);
+
+		// claim reward
+		prepare_rewards_account(rewards_account_params, reward);
+		let balance_before = reward_balance_of(&relayer);
+		assert_ok!(pallet_bridge_relayers::Pallet::<Runtime>::claim_rewards(
+			RuntimeHelper::<Runtime>::origin_of(relayer.clone()),
+			rewards_account_params,
+		));
+
+		// check the reward is paid in the configured asset, and only once
+		assert_eq!(reward_balance_of(&relayer), balance_before + reward);
+		assert_eq!(
+			pallet_bridge_relayers::RelayerRewards::<Runtime>::get(
+				&relayer,
+				rewards_account_params
+			),
+			None,
+		);
+		assert_eq!(
+			pallet_bridge_relayers::Pallet::<Runtime>::claim_rewards(
+				RuntimeHelper::<Runtime>::origin_of(relayer.clone()),
+				rewards_account_params,
+			),
+			Err(pallet_bridge_relayers::Error::<Runtime>::NoRewardForRelayer.into()),
+		);
+	})
+}
+
 /// Test-case makes sure that `Runtime` can handle xcm `ExportMessage`:
 /// Checks if received XCM messages is correctly added to the message outbound queue for delivery.
 /// For SystemParachains we expect unpaid execution.
@@ -155,78 +481,279 @@ pub fn handle_export_message_from_system_parachain_to_outbound_queue_works<
 	MessagesPalletInstance: 'static,
 	ValidatorIdOf<Runtime>: From<AccountIdOf<Runtime>>,
 {
-	assert_ne!(runtime_para_id, sibling_parachain_id);
-	let sibling_parachain_location = MultiLocation::new(1, Parachain(sibling_parachain_id));
+	handle_export_message_from_system_parachains_to_outbound_lanes_works::<
+		Runtime,
+		XcmConfig,
+		MessagesPalletInstance,
+	>(
+		collator_session_key,
+		runtime_para_id,
+		vec![(sibling_parachain_id, expected_lane_id)],
+		unwrap_pallet_bridge_messages_event,
+		export_message_instruction,
+		existential_deposit,
+		maybe_paid_export_message,
+		prepare_configuration,
+	)
+}
+
+/// Test-case makes sure that `Runtime` can handle xcm `ExportMessage` from several sibling
+/// parachains:
+/// Checks if received XCM messages are added to the outbound queue of the lane of their origin.
+/// `expected_lanes` holds the `(sibling_parachain_id, expected_lane_id)` of every sibling, and
+/// the messages of different siblings must land on distinct lanes.
+/// For SystemParachains we expect unpaid execution.
+pub fn handle_export_message_from_system_parachains_to_outbound_lanes_works<
+	Runtime,
+	XcmConfig,
+	MessagesPalletInstance,
+>(
+	collator_session_key: CollatorSessionKeys<Runtime>,
+	runtime_para_id: u32,
+	expected_lanes: Vec<(u32, LaneId)>,
+	unwrap_pallet_bridge_messages_event: Box<
+		dyn Fn(Vec<u8>) -> Option<pallet_bridge_messages::Event<Runtime, MessagesPalletInstance>>,
+	>,
+	export_message_instruction: fn() -> Instruction<XcmConfig::RuntimeCall>,
+	existential_deposit: Option<MultiAsset>,
+	maybe_paid_export_message: Option<MultiAsset>,
+	prepare_configuration: impl Fn(),
+) where
+	Runtime: BasicParachainRuntime + pallet_bridge_messages::Config<MessagesPalletInstance>,
+	XcmConfig: xcm_executor::Config,
+	MessagesPalletInstance: 'static,
+	ValidatorIdOf<Runtime>: From<AccountIdOf<Runtime>>,
+{
+	assert!(!expected_lanes.is_empty());
+	for (i, (sibling_parachain_id, expected_lane_id)) in expected_lanes.iter().enumerate() {
+		assert_ne!(runtime_para_id, *sibling_parachain_id);
+		assert!(
+			expected_lanes[..i]
+				.iter()
+				.all(|(para_id, lane_id)| para_id != sibling_parachain_id &&
+					lane_id != expected_lane_id),
+			"sibling parachains and their lanes must be distinct: {:?}",
+			expected_lanes,
+		);
+	}
 
 	run_test::<Runtime, _>(collator_session_key, runtime_para_id, vec![], || {
 		prepare_configuration();
 
-		// check queue before
-		assert_eq!(
+		let outbound_lane = |lane_id| {
 			pallet_bridge_messages::OutboundLanes::<Runtime, MessagesPalletInstance>::try_get(
-				expected_lane_id
-			),
-			Err(())
-		);
+				lane_id,
+			)
+		};
+
+		// check queues before
+		for (_, expected_lane_id) in &expected_lanes {
+			assert_eq!(outbound_lane(*expected_lane_id), Err(()));
+		}
 
-		// prepare `ExportMessage`
-		let xcm = if let Some(fee) = maybe_paid_export_message {
-			// deposit ED to origin (if needed)
-			if let Some(ed) = existential_deposit {
+		for (i, (sibling_parachain_id, expected_lane_id)) in expected_lanes.iter().enumerate() {
+			let sibling_parachain_location =
+				MultiLocation::new(1, Parachain(*sibling_parachain_id));
+
+			// prepare `ExportMessage`
+			let xcm = if let Some(fee) = maybe_paid_export_message.clone() {
+				// deposit ED to origin (if needed)
+				if let Some(ed) = &existential_deposit {
+					XcmConfig::AssetTransactor::deposit_asset(
+						ed,
+						&sibling_parachain_location,
+						Some(&XcmContext::with_message_id([0; 32])),
+					)
+					.expect("deposited ed");
+				}
+				// deposit fee to origin
 				XcmConfig::AssetTransactor::deposit_asset(
-					&ed,
+					&fee,
 					&sibling_parachain_location,
 					Some(&XcmContext::with_message_id([0; 32])),
 				)
-				.expect("deposited ed");
-			}
-			// deposit fee to origin
-			XcmConfig::AssetTransactor::deposit_asset(
-				&fee,
-				&sibling_parachain_location,
-				Some(&XcmContext::with_message_id([0; 32])),
+				.expect("deposited fee");
+
+				Xcm(vec![
+					WithdrawAsset(MultiAssets::from(vec![fee.clone()])),
+					BuyExecution { fees: fee, weight_limit: Unlimited },
+					export_message_instruction(),
+				])
+			} else {
+				Xcm(vec![
+					UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+					export_message_instruction(),
+				])
+			};
+
+			// execute XCM
+			let hash = xcm.using_encoded(sp_io::hashing::blake2_256);
+			assert_ok!(XcmExecutor::<XcmConfig>::execute_xcm(
+				sibling_parachain_location,
+				xcm,
+				hash,
+				RuntimeHelper::<Runtime>::xcm_max_weight(XcmReceivedFrom::Sibling),
 			)
-			.expect("deposited fee");
-
-			Xcm(vec![
-				WithdrawAsset(MultiAssets::from(vec![fee.clone()])),
-				BuyExecution { fees: fee, weight_limit: Unlimited },
-				export_message_instruction(),
-			])
-		} else {
-			Xcm(vec![
-				UnpaidExecution { weight_limit: Unlimited, check_origin: None },
-				export_message_instruction(),
-			])
-		};
+			.ensure_complete());
 
-		// execute XCM
-		let hash = xcm.using_encoded(sp_io::hashing::blake2_256);
-		assert_ok!(XcmExecutor::<XcmConfig>::execute_xcm(
-			sibling_parachain_location,
-			xcm,
-			hash,
-			RuntimeHelper::<Runtime>::xcm_max_weight(XcmReceivedFrom::Sibling),
-		)
-		.ensure_complete());
+			// check queue after
+			assert_eq!(
+				outbound_lane(*expected_lane_id),
+				Ok(OutboundLaneData {
+					oldest_unpruned_nonce: 1,
+					latest_received_nonce: 0,
+					latest_generated_nonce: 1,
+				})
+			);
+			// the lanes of the other siblings are not used
+			for (_, other_lane_id) in &expected_lanes[i + 1..] {
+				assert_eq!(outbound_lane(*other_lane_id), Err(()));
+			}
 
-		// check queue after
-		assert_eq!(
-			pallet_bridge_messages::OutboundLanes::<Runtime, MessagesPalletInstance>::try_get(
-				expected_lane_id
+			// check events
+			let mut events = <frame_system::Pallet<Runtime>>::events()
+				.into_iter()
+				.filter_map(|e| unwrap_pallet_bridge_messages_event(e.event.encode()));
+			assert!(events.any(|e| matches!(
+				e,
+				pallet_bridge_messages::Event::MessageAccepted { lane_id, .. }
+					if lane_id == *expected_lane_id
+			)));
+		}
+	})
+}
+
+/// Test-case makes sure that `Runtime` signals the congestion of an outbound bridge lane to the
+/// `pallet_xcm_bridge_hub_router` at `router_location`, which then raises its fee factor:
+///     1. once the lane holds more messages than the congestion threshold, the `congested` XCM is
+///        sent to the router (only once);
+///     2. once enough messages are delivered, the `uncongested` XCM is sent to the router.
+///
+/// The handling of these signals by the router is covered by
+/// `asset_test_utils::test_cases_over_bridge::report_bridge_status_from_xcm_bridge_router_works`.
+pub fn bridge_congestion_signal_works<
+	Runtime,
+	AllPalletsWithoutSystem,
+	XcmConfig,
+	HrmpChannelOpener,
+	MessagesPalletInstance,
+>(
+	collator_session_key: CollatorSessionKeys<Runtime>,
+	runtime_para_id: u32,
+	router_location: MultiLocation,
+	bridged_network: NetworkId,
+	bridged_destination: InteriorMultiLocation,
+	expected_lane_id: LaneId,
+	congested_message: fn() -> Xcm<()>,
+	uncongested_message: fn() -> Xcm<()>,
+	prepare_configuration: impl Fn(),
+) where
+	Runtime: BasicParachainRuntime
+		+ cumulus_pallet_xcmp_queue::Config
+		+ pallet_bridge_messages::Config<MessagesPalletInstance>,
+	AllPalletsWithoutSystem:
+		OnInitialize<BlockNumberFor<Runtime>> + OnFinalize<BlockNumberFor<Runtime>>,
+	<Runtime as frame_system::Config>::AccountId:
+		Into<<<Runtime as frame_system::Config>::RuntimeOrigin as OriginTrait>::AccountId>,
+	XcmConfig: xcm_executor::Config,
+	MessagesPalletInstance: 'static,
+	ValidatorIdOf<Runtime>: From<AccountIdOf<Runtime>>,
+	<Runtime as frame_system::Config>::AccountId: From<AccountId32>,
+	HrmpChannelOpener: frame_support::inherent::ProvideInherent<
+		Call = cumulus_pallet_parachain_system::Call<Runtime>,
+	>,
+{
+	let router_para_id = match router_location {
+		MultiLocation { parents: 1, interior: X1(Parachain(para_id)) } => para_id,
+		_ => panic!("router is expected at a sibling parachain, got: {:?}", router_location),
+	};
+	assert_ne!(runtime_para_id, router_para_id);
+
+	run_test::<Runtime, _>(collator_session_key, runtime_para_id, vec![], || {
+		prepare_configuration();
+
+		// signals are sent to the router over HRMP
+		let mut alice = [0u8; 32];
+		alice[0] = 1;
+		let included_head = RuntimeHelper::<Runtime, AllPalletsWithoutSystem>::run_to_block(
+			2,
+			AccountId::from(alice).into(),
+		);
+		mock_open_hrmp_channel::<Runtime, HrmpChannelOpener>(
+			runtime_para_id.into(),
+			router_para_id.into(),
+			included_head,
+			&alice,
+		);
+
+		let universal_source = X2(
+			GlobalConsensus(
+				XcmConfig::UniversalLocation::get()
+					.global_consensus()
+					.expect("runtime universal location has global consensus"),
 			),
-			Ok(OutboundLaneData {
+			Parachain(router_para_id),
+		);
+		let export_message = || {
+			export_xcm::<XcmConfig::MessageExporter>(
+				bridged_network,
+				0,
+				universal_source,
+				bridged_destination,
+				Xcm(vec![]),
+			)
+		};
+		let take_signal = || {
+			RuntimeHelper::<cumulus_pallet_xcmp_queue::Pallet<Runtime>>::take_xcm(
+				router_para_id.into(),
+			)
+			.map(|xcm| {
+				let mut xcm = Xcm::<()>::try_from(xcm).expect("latest xcm version");
+				// the runtime router may append a unique topic
+				if matches!(xcm.0.last(), Some(SetTopic(_))) {
+					xcm.0.pop();
+				}
+				xcm
+			})
+		};
+		let is_congested_signal_sent = || {
+			pallet_bridge_messages::OutboundLanesCongestedSignals::<
+				Runtime,
+				MessagesPalletInstance,
+			>::get(expected_lane_id)
+		};
+
+		// fill the lane up to the congestion threshold
+		pallet_bridge_messages::OutboundLanes::<Runtime, MessagesPalletInstance>::insert(
+			expected_lane_id,
+			OutboundLaneData {
 				oldest_unpruned_nonce: 1,
 				latest_received_nonce: 0,
-				latest_generated_nonce: 1,
-			})
+				latest_generated_nonce: OUTBOUND_LANE_CONGESTED_THRESHOLD,
+			},
 		);
 
-		// check events
-		let mut events = <frame_system::Pallet<Runtime>>::events()
-			.into_iter()
-			.filter_map(|e| unwrap_pallet_bridge_messages_event(e.event.encode()));
-		assert!(events.any(|e| matches!(e, pallet_bridge_messages::Event::MessageAccepted { .. })));
+		// 1. the next message congests the lane
+		assert_ok!(export_message());
+		assert!(is_congested_signal_sent());
+		assert_eq!(take_signal(), Some(congested_message()));
+
+		// the signal is not sent again
+		assert_ok!(export_message());
+		assert_eq!(take_signal(), None);
+
+		// 2. delivered messages uncongest the lane
+		let enqueued_messages = OUTBOUND_LANE_UNCONGESTED_THRESHOLD;
+		pallet_bridge_messages::OutboundLanes::<Runtime, MessagesPalletInstance>::mutate(
+			expected_lane_id,
+			|data| data.latest_received_nonce = data.latest_generated_nonce - enqueued_messages,
+		);
+		<<Runtime as pallet_bridge_messages::Config<MessagesPalletInstance>>::OnMessagesDelivered as OnMessagesDelivered>::on_messages_delivered(
+			expected_lane_id,
+			enqueued_messages,
+		);
+		assert!(!is_congested_signal_sent());
+		assert_eq!(take_signal(), Some(uncongested_message()));
 	})
 }
 
@@ -483,3 +1010,103 @@ where
 
 	estimated_fee.into()
 }
+
+/// Test-case makes sure that the runtime is able to receive the proof of the largest message that
+/// the bridged chain may send, and that it rejects larger proofs.
+///
+/// The bridged chain refuses to send messages that are larger than
+/// [`maximal_incoming_message_size`] of the maximal extrinsic size of this chain. The remaining
+/// part of the extrinsic is reserved for the storage proof overhead, the signature and the signed
+/// extensions. So the delivery transaction with the proof of such message must fit both the length
+/// and the weight limits of this chain. The proof is not verified during transaction validation,
+/// so there's no need to import the bridged header here.
+///
+/// Delivery transactions that are larger than the maximal extrinsic length of this chain must be
+/// rejected by [`frame_system::CheckWeight`] with [`InvalidTransaction::ExhaustsResources`].
+pub fn inbound_messages_proof_size_limits_are_enforced<Runtime, MPI, MB>(
+	collator_session_key: CollatorSessionKeys<Runtime>,
+	runtime_para_id: u32,
+) where
+	Runtime: BasicParachainRuntime
+		+ pallet_bridge_messages::Config<MPI, InboundPayload = XcmAsPlainPayload>
+		+ Send
+		+ Sync,
+	MPI: 'static,
+	MB: MessageBridge,
+	MessageBridgedChain<MB>: Send + Sync + 'static,
+	ValidatorIdOf<Runtime>: From<AccountIdOf<Runtime>>,
+	<<Runtime as pallet_bridge_messages::Config<MPI>>::SourceHeaderChain as SourceHeaderChain>::MessagesProof:
+		From<FromBridgedChainMessagesProof<HashOf<MessageBridgedChain<MB>>>>,
+	<Runtime as pallet_bridge_messages::Config<MPI>>::InboundRelayer: From<AccountId32>,
+	<Runtime as frame_system::Config>::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>
+		+ GetDispatchInfo
+		+ From<pallet_bridge_messages::Call<Runtime, MPI>>,
+{
+	run_test::<Runtime, _>(collator_session_key, runtime_para_id, vec![], || {
+		let max_extrinsic_size = UnderlyingChainOf::<MessageThisChain<MB>>::max_extrinsic_size();
+		let max_extrinsic_weight =
+			UnderlyingChainOf::<MessageThisChain<MB>>::max_extrinsic_weight();
+		let max_extrinsic_length = *<Runtime as frame_system::Config>::BlockLength::get()
+			.max
+			.get(DispatchClass::Normal);
+		assert!(
+			max_extrinsic_size <= max_extrinsic_length,
+			"maximal extrinsic size of the chain: {}, is larger than the runtime limit: {}",
+			max_extrinsic_size,
+			max_extrinsic_length,
+		);
+
+		// prepare the delivery call with the proof of a single message, where the message is
+		// (approximately) `message_size` bytes large
+		let receive_messages_proof = |message_size: u32| {
+			let (_, storage_proof) = prepare_messages_storage_proof::<MB>(
+				LaneId::default(),
+				1..=1,
+				None,
+				StorageProofSize::HasLargeLeaf(message_size),
+				vec![42],
+				encode_all_messages,
+				encode_lane_data,
+			);
+			let proof = FromBridgedChainMessagesProof {
+				bridged_header_hash: Default::default(),
+				storage_proof,
+				lane: LaneId::default(),
+				nonces_start: 1,
+				nonces_end: 1,
+			};
+			let call = pallet_bridge_messages::Call::<Runtime, MPI>::receive_messages_proof {
+				relayer_id_at_bridged_chain: AccountId32::from(Dave.public()).into(),
+				proof: proof.into(),
+				messages_count: 1,
+				dispatch_weight: maximal_incoming_message_dispatch_weight(max_extrinsic_weight),
+			};
+			<Runtime as frame_system::Config>::RuntimeCall::from(call)
+		};
+		let validate = |call: &<Runtime as frame_system::Config>::RuntimeCall| {
+			frame_system::CheckWeight::<Runtime>::do_validate(
+				&call.get_dispatch_info(),
+				call.encoded_size(),
+			)
+		};
+
+		// the proof of the largest message fits into the delivery transaction
+		let max_message_size = maximal_incoming_message_size(max_extrinsic_size);
+		let call = receive_messages_proof(max_message_size);
+		let call_size = call.encoded_size() as u32;
+		assert!(
+			call_size <= max_extrinsic_size,
+			"delivery call size: {}, is larger than the maximal extrinsic size: {}",
+			call_size,
+			max_extrinsic_size,
+		);
+		assert_ok!(validate(&call));
+
+		// the proof that is just above the maximal extrinsic length is rejected
+		let oversized_message_size =
+			max_message_size.saturating_add(max_extrinsic_length - call_size + 1);
+		let call = receive_messages_proof(oversized_message_size);
+		assert!(call.encoded_size() as u32 > max_extrinsic_length);
+		assert_eq!(validate(&call), Err(InvalidTransaction::ExhaustsResources.into()));
+	})
+}
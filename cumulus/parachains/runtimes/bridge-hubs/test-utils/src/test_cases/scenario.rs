@@ -0,0 +1,269 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Builder for the test-cases of a bridge, which are configured once and then run one by one.
+
+use crate::test_cases::{
+	bridge_congestion_signal_works,
+	handle_export_message_from_system_parachain_to_outbound_queue_works,
+	message_dispatch_routing_works,
+};
+
+use asset_test_utils::BasicParachainRuntime;
+use bp_messages::LaneId;
+use bridge_runtime_common::messages_xcm_extension::XcmAsPlainPayload;
+use frame_support::traits::{Get, OnFinalize, OnInitialize, OriginTrait};
+use frame_system::pallet_prelude::BlockNumberFor;
+use parachains_runtimes_test_utils::{AccountIdOf, CollatorSessionKeys, ValidatorIdOf};
+use sp_runtime::AccountId32;
+use sp_std::marker::PhantomData;
+use xcm::latest::prelude::*;
+
+/// Unwraps the event of a pallet from an encoded runtime event.
+pub type UnwrapEvent<Event> = fn(Vec<u8>) -> Option<Event>;
+
+/// Test scenario for the bridge of `Runtime` that uses the messages pallet instance
+/// `MessagesPalletInstance`.
+///
+/// The lane, the sibling parachain and the event unwrappers are set once with the `with_*`
+/// methods, and are then shared by all test-cases run with the `run_*` methods, e.g.:
+///
+/// ```ignore
+/// BridgeTestScenario::<Runtime, AllPalletsWithoutSystem, XcmConfig, ParachainSystem, Instance>::new(
+/// 	collator_session_keys,
+/// 	BRIDGE_HUB_PARACHAIN_ID,
+/// )
+/// .with_sibling_parachain(SIBLING_PARACHAIN_ID)
+/// .with_lane(XCM_LANE)
+/// .with_bridge_messages_event(|encoded| ...)
+/// .run_dispatch_routing_case::<RelayNetwork, BridgedNetwork, ConstU8<2>>();
+/// ```
+pub struct BridgeTestScenario<
+	Runtime,
+	AllPalletsWithoutSystem,
+	XcmConfig,
+	HrmpChannelOpener,
+	MessagesPalletInstance,
+> where
+	Runtime: BasicParachainRuntime
+		+ cumulus_pallet_xcmp_queue::Config
+		+ pallet_bridge_messages::Config<MessagesPalletInstance>,
+	MessagesPalletInstance: 'static,
+{
+	collator_session_keys: fn() -> CollatorSessionKeys<Runtime>,
+	runtime_para_id: u32,
+	sibling_parachain_id: Option<u32>,
+	lane_id: Option<LaneId>,
+	unwrap_pallet_bridge_messages_event:
+		Option<UnwrapEvent<pallet_bridge_messages::Event<Runtime, MessagesPalletInstance>>>,
+	unwrap_cumulus_pallet_parachain_system_event:
+		Option<UnwrapEvent<cumulus_pallet_parachain_system::Event<Runtime>>>,
+	unwrap_cumulus_pallet_xcmp_queue_event:
+		Option<UnwrapEvent<cumulus_pallet_xcmp_queue::Event<Runtime>>>,
+	prepare_configuration: fn(),
+	_marker: PhantomData<(AllPalletsWithoutSystem, XcmConfig, HrmpChannelOpener)>,
+}
+
+impl<Runtime, AllPalletsWithoutSystem, XcmConfig, HrmpChannelOpener, MessagesPalletInstance>
+	BridgeTestScenario<
+		Runtime,
+		AllPalletsWithoutSystem,
+		XcmConfig,
+		HrmpChannelOpener,
+		MessagesPalletInstance,
+	>
+where
+	Runtime: BasicParachainRuntime
+		+ cumulus_pallet_xcmp_queue::Config
+		+ pallet_bridge_messages::Config<MessagesPalletInstance, InboundPayload = XcmAsPlainPayload>,
+	AllPalletsWithoutSystem:
+		OnInitialize<BlockNumberFor<Runtime>> + OnFinalize<BlockNumberFor<Runtime>>,
+	<Runtime as frame_system::Config>::AccountId:
+		Into<<<Runtime as frame_system::Config>::RuntimeOrigin as OriginTrait>::AccountId>,
+	XcmConfig: xcm_executor::Config,
+	MessagesPalletInstance: 'static,
+	ValidatorIdOf<Runtime>: From<AccountIdOf<Runtime>>,
+	<Runtime as frame_system::Config>::AccountId: From<AccountId32>,
+	HrmpChannelOpener: frame_support::inherent::ProvideInherent<
+		Call = cumulus_pallet_parachain_system::Call<Runtime>,
+	>,
+{
+	/// Create a scenario for the bridge hub parachain `runtime_para_id`, whose collators are
+	/// given by `collator_session_keys`.
+	pub fn new(
+		collator_session_keys: fn() -> CollatorSessionKeys<Runtime>,
+		runtime_para_id: u32,
+	) -> Self {
+		Self {
+			collator_session_keys,
+			runtime_para_id,
+			sibling_parachain_id: None,
+			lane_id: None,
+			unwrap_pallet_bridge_messages_event: None,
+			unwrap_cumulus_pallet_parachain_system_event: None,
+			unwrap_cumulus_pallet_xcmp_queue_event: None,
+			prepare_configuration: || (),
+			_marker: PhantomData,
+		}
+	}
+
+	/// Set the sibling parachain that sends and receives the bridged messages.
+	///
+	/// The sibling also hosts the `pallet_xcm_bridge_hub_router` of the bridge.
+	pub fn with_sibling_parachain(mut self, sibling_parachain_id: u32) -> Self {
+		self.sibling_parachain_id = Some(sibling_parachain_id);
+		self
+	}
+
+	/// Set the lane of the bridge, that is used by the sibling parachain.
+	pub fn with_lane(mut self, lane_id: LaneId) -> Self {
+		self.lane_id = Some(lane_id);
+		self
+	}
+
+	/// Set the unwrapper of the events of `pallet_bridge_messages`.
+	pub fn with_bridge_messages_event(
+		mut self,
+		unwrap: UnwrapEvent<pallet_bridge_messages::Event<Runtime, MessagesPalletInstance>>,
+	) -> Self {
+		self.unwrap_pallet_bridge_messages_event = Some(unwrap);
+		self
+	}
+
+	/// Set the unwrapper of the events of `cumulus_pallet_parachain_system`.
+	pub fn with_parachain_system_event(
+		mut self,
+		unwrap: UnwrapEvent<cumulus_pallet_parachain_system::Event<Runtime>>,
+	) -> Self {
+		self.unwrap_cumulus_pallet_parachain_system_event = Some(unwrap);
+		self
+	}
+
+	/// Set the unwrapper of the events of `cumulus_pallet_xcmp_queue`.
+	pub fn with_xcmp_queue_event(
+		mut self,
+		unwrap: UnwrapEvent<cumulus_pallet_xcmp_queue::Event<Runtime>>,
+	) -> Self {
+		self.unwrap_cumulus_pallet_xcmp_queue_event = Some(unwrap);
+		self
+	}
+
+	/// Set the configuration to prepare at the start of every test-case, e.g. the XCM version of
+	/// the bridged location.
+	pub fn with_prepare_configuration(mut self, prepare_configuration: fn()) -> Self {
+		self.prepare_configuration = prepare_configuration;
+		self
+	}
+
+	/// Run [`handle_export_message_from_system_parachain_to_outbound_queue_works`] for the
+	/// sibling parachain and the lane of the scenario.
+	pub fn run_export_message_case(
+		&self,
+		export_message_instruction: fn() -> Instruction<XcmConfig::RuntimeCall>,
+		existential_deposit: Option<MultiAsset>,
+		maybe_paid_export_message: Option<MultiAsset>,
+	) {
+		handle_export_message_from_system_parachain_to_outbound_queue_works::<
+			Runtime,
+			XcmConfig,
+			MessagesPalletInstance,
+		>(
+			(self.collator_session_keys)(),
+			self.runtime_para_id,
+			self.sibling_parachain_id(),
+			Box::new(
+				self.unwrap_pallet_bridge_messages_event
+					.expect("set by `with_bridge_messages_event`"),
+			),
+			export_message_instruction,
+			self.lane_id(),
+			existential_deposit,
+			maybe_paid_export_message,
+			self.prepare_configuration,
+		)
+	}
+
+	/// Run [`message_dispatch_routing_works`] for the sibling parachain and the lane of the
+	/// scenario.
+	pub fn run_dispatch_routing_case<RuntimeNetwork, BridgedNetwork, NetworkDistanceAsParentCount>(
+		&self,
+	) where
+		RuntimeNetwork: Get<NetworkId>,
+		BridgedNetwork: Get<NetworkId>,
+		NetworkDistanceAsParentCount: Get<u8>,
+	{
+		message_dispatch_routing_works::<
+			Runtime,
+			AllPalletsWithoutSystem,
+			XcmConfig,
+			HrmpChannelOpener,
+			MessagesPalletInstance,
+			RuntimeNetwork,
+			BridgedNetwork,
+			NetworkDistanceAsParentCount,
+		>(
+			(self.collator_session_keys)(),
+			self.runtime_para_id,
+			self.sibling_parachain_id(),
+			Box::new(
+				self.unwrap_cumulus_pallet_parachain_system_event
+					.expect("set by `with_parachain_system_event`"),
+			),
+			Box::new(
+				self.unwrap_cumulus_pallet_xcmp_queue_event
+					.expect("set by `with_xcmp_queue_event`"),
+			),
+			self.lane_id(),
+			self.prepare_configuration,
+		)
+	}
+
+	/// Run [`bridge_congestion_signal_works`] for the lane of the scenario, with the router at
+	/// the sibling parachain.
+	pub fn run_congestion_signal_case(
+		&self,
+		bridged_network: NetworkId,
+		bridged_destination: InteriorMultiLocation,
+		congested_message: fn() -> Xcm<()>,
+		uncongested_message: fn() -> Xcm<()>,
+	) {
+		bridge_congestion_signal_works::<
+			Runtime,
+			AllPalletsWithoutSystem,
+			XcmConfig,
+			HrmpChannelOpener,
+			MessagesPalletInstance,
+		>(
+			(self.collator_session_keys)(),
+			self.runtime_para_id,
+			MultiLocation::new(1, Parachain(self.sibling_parachain_id())),
+			bridged_network,
+			bridged_destination,
+			self.lane_id(),
+			congested_message,
+			uncongested_message,
+			self.prepare_configuration,
+		)
+	}
+
+	fn sibling_parachain_id(&self) -> u32 {
+		self.sibling_parachain_id.expect("set by `with_sibling_parachain`")
+	}
+
+	fn lane_id(&self) -> LaneId {
+		self.lane_id.expect("set by `with_lane`")
+	}
+}
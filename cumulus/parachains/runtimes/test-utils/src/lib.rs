@@ -25,7 +25,7 @@ use frame_support::{
 	dispatch::{DispatchResult, RawOrigin},
 	inherent::{InherentData, ProvideInherent},
 	pallet_prelude::Get,
-	traits::{OnFinalize, OnInitialize, OriginTrait, UnfilteredDispatchable},
+	traits::{OnFinalize, OnInitialize, OriginTrait, PalletInfoAccess, UnfilteredDispatchable},
 	weights::Weight,
 };
 use frame_system::pallet_prelude::{BlockNumberFor, HeaderFor};
@@ -35,7 +35,10 @@ use polkadot_parachain_primitives::primitives::{
 };
 use sp_consensus_aura::{SlotDuration, AURA_ENGINE_ID};
 use sp_core::Encode;
-use sp_runtime::{traits::Header, BuildStorage, Digest, DigestItem};
+use sp_runtime::{
+	traits::{Header, SaturatedConversion},
+	BuildStorage, Digest, DigestItem,
+};
 use xcm::{
 	latest::{MultiAsset, MultiLocation, XcmContext, XcmHash},
 	prelude::*,
@@ -302,6 +305,88 @@ where
 	}
 }
 
+/// Utility functions that advance the chain with explicit control over the Aura slot and the
+/// timestamp of every produced block.
+impl<Runtime, AllPalletsWithoutSystem> RuntimeHelper<Runtime, AllPalletsWithoutSystem>
+where
+	Runtime: pallet_aura::Config + pallet_timestamp::Config + pallet_session::Config,
+	AllPalletsWithoutSystem:
+		OnInitialize<BlockNumberFor<Runtime>> + OnFinalize<BlockNumberFor<Runtime>>,
+{
+	/// Produce the next block at the given Aura `slot`.
+	///
+	/// The Aura pre-runtime digest of the block carries the `slot` and the timestamp inherent is
+	/// set to the start of that slot, so the checks of `pallet-aura` and of the consensus hooks
+	/// that rely on it hold. Returns the header of the finalized (previous) block.
+	pub fn next_block_at_slot(slot: Slot) -> HeaderFor<Runtime> {
+		let slot_duration = pallet_aura::Pallet::<Runtime>::slot_duration();
+		Self::next_block_at(slot, slot_duration.saturating_mul(u64::from(slot).saturated_into()))
+	}
+
+	/// Produce the next block at the given Aura `slot` and with the given `timestamp`.
+	///
+	/// `pallet-aura` requires the `timestamp` to fall into the `slot`, so this should only be used
+	/// to test what happens within the slot.
+	pub fn next_block_at(slot: Slot, timestamp: Runtime::Moment) -> HeaderFor<Runtime> {
+		// `pallet-timestamp` allows setting the timestamp only once per block and resets that in
+		// `on_finalize`, which we don't call, because blocks produced by `run_to_block` don't set
+		// the timestamp at all
+		let did_update_key = frame_support::storage::storage_prefix(
+			<pallet_timestamp::Pallet<Runtime> as PalletInfoAccess>::name().as_bytes(),
+			b"DidUpdate",
+		);
+		frame_support::storage::unhashed::kill(&did_update_key);
+
+		let header = frame_system::Pallet::<Runtime>::finalize();
+
+		let pre_digest =
+			Digest { logs: vec![DigestItem::PreRuntime(AURA_ENGINE_ID, slot.encode())] };
+		frame_system::Pallet::<Runtime>::reset_events();
+
+		let next_block_number = frame_system::Pallet::<Runtime>::block_number() + 1u32.into();
+		frame_system::Pallet::<Runtime>::initialize(
+			&next_block_number,
+			&header.hash(),
+			&pre_digest,
+		);
+		AllPalletsWithoutSystem::on_initialize(next_block_number);
+
+		pallet_timestamp::Pallet::<Runtime>::set(RawOrigin::None.into(), timestamp)
+			.expect("timestamp inherent is valid");
+
+		header
+	}
+
+	/// Advance the chain to block `n`, producing every block at the slot returned by `slot_of`.
+	///
+	/// `slot_of` is called with the number of the block being produced and must return increasing
+	/// slots.
+	pub fn run_to_block_at_slots(n: u32, slot_of: impl Fn(u32) -> Slot) -> HeaderFor<Runtime> {
+		let mut last_header = None;
+		loop {
+			let block_number = frame_system::Pallet::<Runtime>::block_number();
+			if block_number >= n.into() {
+				break
+			}
+
+			let next_block_number: u32 = (block_number + 1u32.into()).saturated_into();
+			last_header = Some(Self::next_block_at_slot(slot_of(next_block_number)));
+		}
+		last_header.expect("run_to_block_at_slots empty block range")
+	}
+
+	/// Produce blocks at consecutive slots until `sessions` new sessions have started.
+	pub fn run_sessions(sessions: u32) -> HeaderFor<Runtime> {
+		let target_session = pallet_session::Pallet::<Runtime>::current_index() + sessions;
+		let mut last_header = None;
+		while pallet_session::Pallet::<Runtime>::current_index() < target_session {
+			let next_slot = pallet_aura::Pallet::<Runtime>::current_slot() + 1;
+			last_header = Some(Self::next_block_at_slot(next_slot));
+		}
+		last_header.expect("run_sessions with zero sessions")
+	}
+}
+
 impl<XcmConfig: xcm_executor::Config, AllPalletsWithoutSystem>
 	RuntimeHelper<XcmConfig, AllPalletsWithoutSystem>
 {
@@ -16,6 +16,8 @@
 
 //! Module contains predefined test-case scenarios for `Runtime` with common functionality.
 
+pub mod identity;
+
 use crate::{AccountIdOf, CollatorSessionKeys, ExtBuilder, ValidatorIdOf};
 use codec::Encode;
 use frame_support::{assert_ok, traits::Get};
@@ -0,0 +1,291 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Module contains predefined test-case scenarios for `Runtime` with `pallet-identity`, e.g. the
+//! people chains.
+
+use crate::{AccountIdOf, BalanceOf, BasicParachainRuntime, CollatorSessionKeys, ExtBuilder};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use pallet_identity::{Data, Judgement};
+use polkadot_runtime_common::identity_migrator;
+use sp_runtime::traits::{Hash, StaticLookup, Zero};
+
+type RuntimeHelper<Runtime, AllPalletsWithoutSystem = ()> =
+	crate::RuntimeHelper<Runtime, AllPalletsWithoutSystem>;
+
+type IdentityInfoOf<Runtime> = <Runtime as pallet_identity::Config>::IdentityInformation;
+
+/// The deposit `pallet-identity` is expected to hold for `info` and `subs` sub-accounts.
+fn expected_deposit<Runtime>(info: &IdentityInfoOf<Runtime>, subs: u32) -> BalanceOf<Runtime>
+where
+	Runtime: pallet_identity::Config<Currency = pallet_balances::Pallet<Runtime>>
+		+ pallet_balances::Config,
+{
+	let bytes: BalanceOf<Runtime> = (info.encoded_size() as u32).into();
+	let subs: BalanceOf<Runtime> = subs.into();
+	<Runtime as pallet_identity::Config>::BasicDeposit::get() +
+		<Runtime as pallet_identity::Config>::ByteDeposit::get() * bytes +
+		<Runtime as pallet_identity::Config>::SubAccountDeposit::get() * subs
+}
+
+/// Enough balance for `account` to cover every deposit and fee in these test-cases.
+fn endowment<Runtime>(info: &IdentityInfoOf<Runtime>) -> BalanceOf<Runtime>
+where
+	Runtime: pallet_identity::Config<Currency = pallet_balances::Pallet<Runtime>>
+		+ pallet_balances::Config,
+{
+	let ten: BalanceOf<Runtime> = 10u32.into();
+	(<Runtime as pallet_balances::Config>::ExistentialDeposit::get() +
+		expected_deposit::<Runtime>(
+			info,
+			<Runtime as pallet_identity::Config>::MaxSubAccounts::get(),
+		)) * ten
+}
+
+/// Test-case makes sure that an account can set and clear its identity and sub-accounts, with
+/// the expected deposits reserved and returned.
+pub fn set_and_clear_identity_works<Runtime>(
+	collator_session_key: CollatorSessionKeys<Runtime>,
+	runtime_para_id: u32,
+	account: AccountIdOf<Runtime>,
+	sub_account: AccountIdOf<Runtime>,
+	identity_info: fn() -> IdentityInfoOf<Runtime>,
+) where
+	Runtime: BasicParachainRuntime
+		+ pallet_identity::Config<Currency = pallet_balances::Pallet<Runtime>>,
+{
+	let info = identity_info();
+	ExtBuilder::<Runtime>::default()
+		.with_collators(collator_session_key.collators())
+		.with_session_keys(collator_session_key.session_keys())
+		.with_para_id(runtime_para_id.into())
+		.with_balances(vec![(account.clone(), endowment::<Runtime>(&info))])
+		.with_tracing()
+		.build()
+		.execute_with(|| {
+			let free_before = <pallet_balances::Pallet<Runtime>>::free_balance(&account);
+			assert!(<pallet_balances::Pallet<Runtime>>::reserved_balance(&account).is_zero());
+
+			// set identity
+			assert_ok!(pallet_identity::Pallet::<Runtime>::set_identity(
+				RuntimeHelper::<Runtime>::origin_of(account.clone()),
+				Box::new(info.clone()),
+			));
+			let registration = pallet_identity::Pallet::<Runtime>::identity(&account)
+				.expect("identity was just set");
+			assert_eq!(registration.info, info);
+			assert_eq!(registration.deposit, expected_deposit::<Runtime>(&info, 0));
+			assert_eq!(
+				<pallet_balances::Pallet<Runtime>>::reserved_balance(&account),
+				expected_deposit::<Runtime>(&info, 0),
+			);
+
+			// set sub-accounts
+			assert_ok!(pallet_identity::Pallet::<Runtime>::set_subs(
+				RuntimeHelper::<Runtime>::origin_of(account.clone()),
+				vec![(sub_account.clone(), Data::Raw(b"sub".to_vec().try_into().unwrap()))],
+			));
+			assert_eq!(
+				pallet_identity::Pallet::<Runtime>::super_of(&sub_account).map(|(main, _)| main),
+				Some(account.clone()),
+			);
+			assert_eq!(
+				<pallet_balances::Pallet<Runtime>>::reserved_balance(&account),
+				expected_deposit::<Runtime>(&info, 1),
+			);
+
+			// clear identity, which removes the sub-accounts and returns all deposits
+			assert_ok!(pallet_identity::Pallet::<Runtime>::clear_identity(
+				RuntimeHelper::<Runtime>::origin_of(account.clone()),
+			));
+			assert!(pallet_identity::Pallet::<Runtime>::identity(&account).is_none());
+			assert!(pallet_identity::Pallet::<Runtime>::super_of(&sub_account).is_none());
+			assert!(<pallet_balances::Pallet<Runtime>>::reserved_balance(&account).is_zero());
+			assert_eq!(<pallet_balances::Pallet<Runtime>>::free_balance(&account), free_before);
+
+			// nothing left to clear
+			assert_noop!(
+				pallet_identity::Pallet::<Runtime>::clear_identity(
+					RuntimeHelper::<Runtime>::origin_of(account.clone()),
+				),
+				pallet_identity::Error::<Runtime>::NotNamed,
+			);
+		})
+}
+
+/// Test-case makes sure that a registrar added by `registrar_origin` can be paid for and provide
+/// a judgement on an identity.
+pub fn request_and_provide_judgement_works<Runtime>(
+	collator_session_key: CollatorSessionKeys<Runtime>,
+	runtime_para_id: u32,
+	registrar_origin: fn() -> <Runtime as frame_system::Config>::RuntimeOrigin,
+	registrar: AccountIdOf<Runtime>,
+	account: AccountIdOf<Runtime>,
+	identity_info: fn() -> IdentityInfoOf<Runtime>,
+) where
+	Runtime: BasicParachainRuntime
+		+ pallet_identity::Config<Currency = pallet_balances::Pallet<Runtime>>,
+{
+	let info = identity_info();
+	ExtBuilder::<Runtime>::default()
+		.with_collators(collator_session_key.collators())
+		.with_session_keys(collator_session_key.session_keys())
+		.with_para_id(runtime_para_id.into())
+		.with_balances(vec![
+			(registrar.clone(), endowment::<Runtime>(&info)),
+			(account.clone(), endowment::<Runtime>(&info)),
+		])
+		.with_tracing()
+		.build()
+		.execute_with(|| {
+			let fee = <Runtime as pallet_balances::Config>::ExistentialDeposit::get();
+			let registrar_free_before =
+				<pallet_balances::Pallet<Runtime>>::free_balance(&registrar);
+
+			// add registrar and set its fee
+			assert_ok!(pallet_identity::Pallet::<Runtime>::add_registrar(
+				registrar_origin(),
+				<Runtime as frame_system::Config>::Lookup::unlookup(registrar.clone()),
+			));
+			let reg_index = pallet_identity::Pallet::<Runtime>::registrars().len() as u32 - 1;
+			assert_ok!(pallet_identity::Pallet::<Runtime>::set_fee(
+				RuntimeHelper::<Runtime>::origin_of(registrar.clone()),
+				reg_index,
+				fee,
+			));
+
+			// set identity and request judgement
+			assert_ok!(pallet_identity::Pallet::<Runtime>::set_identity(
+				RuntimeHelper::<Runtime>::origin_of(account.clone()),
+				Box::new(info.clone()),
+			));
+			assert_ok!(pallet_identity::Pallet::<Runtime>::request_judgement(
+				RuntimeHelper::<Runtime>::origin_of(account.clone()),
+				reg_index,
+				fee,
+			));
+			assert_eq!(
+				<pallet_balances::Pallet<Runtime>>::reserved_balance(&account),
+				expected_deposit::<Runtime>(&info, 0) + fee,
+			);
+
+			// judgement for a different identity is rejected
+			assert_noop!(
+				pallet_identity::Pallet::<Runtime>::provide_judgement(
+					RuntimeHelper::<Runtime>::origin_of(registrar.clone()),
+					reg_index,
+					<Runtime as frame_system::Config>::Lookup::unlookup(account.clone()),
+					Judgement::Reasonable,
+					Default::default(),
+				),
+				pallet_identity::Error::<Runtime>::JudgementForDifferentIdentity,
+			);
+
+			// provide judgement, which pays the registrar
+			assert_ok!(pallet_identity::Pallet::<Runtime>::provide_judgement(
+				RuntimeHelper::<Runtime>::origin_of(registrar.clone()),
+				reg_index,
+				<Runtime as frame_system::Config>::Lookup::unlookup(account.clone()),
+				Judgement::Reasonable,
+				<Runtime as frame_system::Config>::Hashing::hash_of(&info),
+			));
+			let registration =
+				pallet_identity::Pallet::<Runtime>::identity(&account).expect("identity was set");
+			assert_eq!(
+				registration.judgements.into_inner(),
+				vec![(reg_index, Judgement::Reasonable)]
+			);
+			assert_eq!(
+				<pallet_balances::Pallet<Runtime>>::reserved_balance(&account),
+				expected_deposit::<Runtime>(&info, 0),
+			);
+			assert_eq!(
+				<pallet_balances::Pallet<Runtime>>::free_balance(&registrar),
+				registrar_free_before + fee,
+			);
+		})
+}
+
+/// Test-case makes sure that the relay chain can update the identity deposits of an account
+/// migrated to `Runtime`, by sending `identity_migrator::Call::poke_deposit` as governance.
+pub fn identity_migrator_poke_deposit_works<Runtime>(
+	collator_session_key: CollatorSessionKeys<Runtime>,
+	runtime_para_id: u32,
+	runtime_call_encode: Box<dyn Fn(identity_migrator::Call<Runtime>) -> Vec<u8>>,
+	account: AccountIdOf<Runtime>,
+	sub_account: AccountIdOf<Runtime>,
+	identity_info: fn() -> IdentityInfoOf<Runtime>,
+) where
+	Runtime: BasicParachainRuntime
+		+ pallet_identity::Config<Currency = pallet_balances::Pallet<Runtime>>
+		+ identity_migrator::Config,
+{
+	let info = identity_info();
+	ExtBuilder::<Runtime>::default()
+		.with_collators(collator_session_key.collators())
+		.with_session_keys(collator_session_key.session_keys())
+		.with_para_id(runtime_para_id.into())
+		.with_balances(vec![(account.clone(), endowment::<Runtime>(&info))])
+		.with_tracing()
+		.build()
+		.execute_with(|| {
+			assert_ok!(pallet_identity::Pallet::<Runtime>::set_identity(
+				RuntimeHelper::<Runtime>::origin_of(account.clone()),
+				Box::new(info.clone()),
+			));
+			assert_ok!(pallet_identity::Pallet::<Runtime>::set_subs(
+				RuntimeHelper::<Runtime>::origin_of(account.clone()),
+				vec![(sub_account.clone(), Data::None)],
+			));
+			let reserved_before = <pallet_balances::Pallet<Runtime>>::reserved_balance(&account);
+			assert_eq!(reserved_before, expected_deposit::<Runtime>(&info, 1));
+
+			// only the relay chain (as governance) may poke deposits
+			assert!(identity_migrator::Pallet::<Runtime>::poke_deposit(
+				RuntimeHelper::<Runtime>::origin_of(account.clone()),
+				account.clone(),
+			)
+			.is_err());
+
+			// execute XCM with Transact to `poke_deposit` as the relay chain does
+			use identity_migrator::WeightInfo;
+			let require_weight_at_most =
+				<Runtime as identity_migrator::Config>::WeightInfo::poke_deposit();
+			let poke_deposit_call =
+				runtime_call_encode(identity_migrator::Call::<Runtime>::poke_deposit {
+					who: account.clone(),
+				});
+			assert_ok!(RuntimeHelper::<Runtime>::execute_as_governance(
+				poke_deposit_call,
+				require_weight_at_most
+			)
+			.ensure_complete());
+
+			// the deposits match what `Runtime` requires for the identity
+			let registration =
+				pallet_identity::Pallet::<Runtime>::identity(&account).expect("identity is kept");
+			assert_eq!(registration.deposit, expected_deposit::<Runtime>(&info, 0));
+			assert_eq!(
+				pallet_identity::Pallet::<Runtime>::subs_of(&account).0,
+				<Runtime as pallet_identity::Config>::SubAccountDeposit::get(),
+			);
+			assert_eq!(
+				<pallet_balances::Pallet<Runtime>>::reserved_balance(&account),
+				reserved_before,
+			);
+		})
+}
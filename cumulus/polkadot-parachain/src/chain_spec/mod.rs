@@ -26,6 +26,7 @@ pub mod collectives;
 pub mod contracts;
 pub mod glutton;
 pub mod penpal;
+pub mod remote;
 pub mod rococo_parachain;
 pub mod seedling;
 pub mod shell;
@@ -0,0 +1,228 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Support for chain specs downloaded from a remote location.
+//!
+//! A remote chain spec is given as `--chain https://<url>[#sha256=<hex>]`. The spec is downloaded
+//! once, validated against the (optional) expected SHA-256 hash of its content and stored in the
+//! local cache directory, so that subsequent starts don't depend on the remote server.
+
+use sp_core::hexdisplay::HexDisplay;
+use std::{
+	path::{Path, PathBuf},
+	time::Duration,
+};
+
+/// Prefix of the chain ids that are treated as remote chain specs.
+const REMOTE_PREFIX: &str = "https://";
+/// Fragment used to pass the expected hash of the chain spec.
+const SHA256_FRAGMENT: &str = "#sha256=";
+/// Timeout of the chain spec download.
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// A chain spec which has to be downloaded before it can be loaded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteChainSpec {
+	url: String,
+	expected_sha256: Option<[u8; 32]>,
+}
+
+impl RemoteChainSpec {
+	/// Parses the `--chain` value.
+	///
+	/// Returns `None` if the value does not represent a remote chain spec.
+	pub fn parse(id: &str) -> Option<Result<Self, String>> {
+		if !id.starts_with(REMOTE_PREFIX) {
+			return None
+		}
+
+		let (url, expected_sha256) = match id.split_once(SHA256_FRAGMENT) {
+			Some((url, hash)) => match parse_sha256(hash) {
+				Ok(hash) => (url, Some(hash)),
+				Err(e) => return Some(Err(e)),
+			},
+			None => (id, None),
+		};
+
+		Some(Ok(Self { url: url.into(), expected_sha256 }))
+	}
+
+	/// Returns the path of the local copy of the chain spec, downloading it if needed.
+	///
+	/// When the expected hash is known and the cached copy matches it, the chain spec is not
+	/// downloaded again. Otherwise the chain spec is downloaded and, if the download fails, the
+	/// previously cached copy (if any) is used.
+	pub fn fetch(&self, cache_dir: &Path) -> Result<PathBuf, String> {
+		let path = self.cache_path(cache_dir);
+
+		if let Some(expected) = self.expected_sha256 {
+			if std::fs::read(&path).map_or(false, |cached| sha256(&cached) == expected) {
+				log::info!("Using cached chain spec {} for {}", path.display(), self.url);
+				return Ok(path)
+			}
+		}
+
+		match self.download() {
+			Ok(content) => {
+				self.store(&path, &content)?;
+				log::info!("Downloaded chain spec from {} to {}", self.url, path.display());
+			},
+			Err(e) if self.expected_sha256.is_none() && path.exists() => {
+				log::warn!(
+					"Failed to download chain spec from {}: {}. Using cached copy {}",
+					self.url,
+					e,
+					path.display(),
+				);
+			},
+			Err(e) => return Err(e),
+		}
+
+		Ok(path)
+	}
+
+	/// Path of the cached copy of the chain spec.
+	fn cache_path(&self, cache_dir: &Path) -> PathBuf {
+		let url_hash = sha256(self.url.as_bytes());
+		cache_dir.join(format!("{}.json", HexDisplay::from(&&url_hash[..8])))
+	}
+
+	/// Downloads the chain spec and checks its content.
+	fn download(&self) -> Result<Vec<u8>, String> {
+		let client = reqwest::blocking::Client::builder()
+			.timeout(DOWNLOAD_TIMEOUT)
+			.https_only(true)
+			.build()
+			.map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+		let content = client
+			.get(&self.url)
+			.send()
+			.and_then(|response| response.error_for_status())
+			.and_then(|response| response.bytes())
+			.map_err(|e| format!("Failed to download chain spec from {}: {}", self.url, e))?
+			.to_vec();
+
+		self.check(&content)?;
+		Ok(content)
+	}
+
+	/// Checks that `content` is a chain spec with the expected hash.
+	fn check(&self, content: &[u8]) -> Result<(), String> {
+		if let Some(expected) = self.expected_sha256 {
+			let actual = sha256(content);
+			if actual != expected {
+				return Err(format!(
+					"Chain spec downloaded from {} has unexpected hash: expected 0x{}, got 0x{}",
+					self.url,
+					HexDisplay::from(&expected),
+					HexDisplay::from(&actual),
+				))
+			}
+		}
+
+		serde_json::from_slice::<serde_json::Value>(content).map(|_| ()).map_err(|e| {
+			format!("Chain spec downloaded from {} is not valid JSON: {}", self.url, e)
+		})
+	}
+
+	/// Atomically stores the downloaded chain spec in the cache.
+	fn store(&self, path: &Path, content: &[u8]) -> Result<(), String> {
+		let dir = path.parent().expect("cache path always has a parent; qed");
+		std::fs::create_dir_all(dir)
+			.map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+		let tmp_path = path.with_extension("json.tmp");
+		std::fs::write(&tmp_path, content)
+			.and_then(|_| std::fs::rename(&tmp_path, path))
+			.map_err(|e| format!("Failed to store chain spec in {}: {}", path.display(), e))
+	}
+}
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+	sp_core::hashing::sha2_256(data)
+}
+
+fn parse_sha256(hex: &str) -> Result<[u8; 32], String> {
+	sp_core::bytes::from_hex(hex)
+		.ok()
+		.and_then(|bytes| bytes.try_into().ok())
+		.ok_or_else(|| format!("Invalid chain spec SHA-256 hash: {}", hex))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const SPEC: &[u8] = br#"{"name":"Local Testnet","id":"asset-hub-westend-local"}"#;
+
+	#[test]
+	fn parse_works() {
+		assert_eq!(RemoteChainSpec::parse("asset-hub-westend"), None);
+		assert_eq!(RemoteChainSpec::parse("./specs/https.json"), None);
+		assert_eq!(
+			RemoteChainSpec::parse("https://example.com/spec.json"),
+			Some(Ok(RemoteChainSpec {
+				url: "https://example.com/spec.json".into(),
+				expected_sha256: None
+			})),
+		);
+
+		let hash = sha256(SPEC);
+		let id = format!("https://example.com/spec.json#sha256=0x{}", HexDisplay::from(&hash));
+		assert_eq!(
+			RemoteChainSpec::parse(&id),
+			Some(Ok(RemoteChainSpec {
+				url: "https://example.com/spec.json".into(),
+				expected_sha256: Some(hash)
+			})),
+		);
+		assert!(matches!(
+			RemoteChainSpec::parse("https://example.com/spec.json#sha256=0x1234"),
+			Some(Err(_))
+		));
+	}
+
+	#[test]
+	fn check_rejects_unexpected_content() {
+		let spec = RemoteChainSpec {
+			url: "https://example.com/spec.json".into(),
+			expected_sha256: Some(sha256(SPEC)),
+		};
+		assert_eq!(spec.check(SPEC), Ok(()));
+		assert!(spec.check(b"{}").is_err());
+
+		let spec = RemoteChainSpec { expected_sha256: None, ..spec };
+		assert_eq!(spec.check(b"{}"), Ok(()));
+		assert!(spec.check(b"not a chain spec").is_err());
+	}
+
+	#[test]
+	fn fetch_uses_matching_cached_copy() {
+		let cache_dir = tempfile::tempdir().expect("Failed to access tempdir");
+		// the host does not exist, so the cached copy has to be used
+		let spec = RemoteChainSpec {
+			url: "https://chain-spec.invalid/spec.json".into(),
+			expected_sha256: Some(sha256(SPEC)),
+		};
+		let path = spec.cache_path(cache_dir.path());
+		spec.store(&path, SPEC).unwrap();
+
+		assert_eq!(spec.fetch(cache_dir.path()), Ok(path.clone()));
+
+		// the cached copy doesn't match the expected hash, so it is downloaded again
+		std::fs::write(&path, b"{}").unwrap();
+		assert!(spec.fetch(cache_dir.path()).is_err());
+	}
+}
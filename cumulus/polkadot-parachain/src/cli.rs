@@ -44,11 +44,19 @@ pub enum Subcommand {
 	/// Remove the whole chain.
 	PurgeChain(cumulus_client_cli::PurgeChainCmd),
 
+	/// Export the genesis head data and the validation code of the parachain, as needed to
+	/// register it on the relay chain.
+	ExportGenesisArtifacts(cumulus_client_cli::ExportGenesisArtifactsCommand),
+
 	/// Export the genesis state of the parachain.
+	///
+	/// Deprecated, use `export-genesis-artifacts` instead.
 	#[command(alias = "export-genesis-state")]
 	ExportGenesisHead(cumulus_client_cli::ExportGenesisHeadCommand),
 
 	/// Export the genesis wasm of the parachain.
+	///
+	/// Deprecated, use `export-genesis-artifacts` instead.
 	ExportGenesisWasm(cumulus_client_cli::ExportGenesisWasmCommand),
 
 	/// Sub-commands concerned with benchmarking.
@@ -62,6 +70,15 @@ pub enum Subcommand {
 	TryRuntime,
 }
 
+/// Block authoring mode of the development node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum DevBlockMode {
+	/// Blocks are authored on request, using the `engine_createBlock` RPC.
+	Manual,
+	/// A block is authored as soon as a transaction enters the pool.
+	Instant,
+}
+
 const AFTER_HELP_EXAMPLE: &str = color_print::cstr!(
 	r#"<bold><underline>Examples:</></>
    <bold>polkadot-parachain --chain asset-hub-polkadot --sync warp -- --chain polkadot --sync warp</>
@@ -69,6 +86,11 @@ const AFTER_HELP_EXAMPLE: &str = color_print::cstr!(
    <bold>polkadot-parachain --chain asset-hub-polkadot --sync warp --relay-chain-rpc-url ws://rpc.example.com -- --chain polkadot</>
            Launch a warp-syncing full node of the <italic>Asset Hub</> parachain on the <italic>Polkadot</> Relay Chain.
            Uses <italic>ws://rpc.example.com</> as remote relay chain node.
+   <bold>polkadot-parachain --chain https://example.com/spec.json#sha256=0x<<hash> -- --chain westend</>
+           Launch a full node of the parachain whose chain spec is downloaded from <italic>https://example.com/spec.json</>.
+           The optional <italic>sha256</> is checked against the downloaded chain spec, which is cached in the base path.
+   <bold>polkadot-parachain --chain asset-hub-westend-dev --dev-block-mode instant --tmp</>
+           Launch a development node of the <italic>Asset Hub</> parachain without a relay chain, authoring a block for every transaction.
  "#
 );
 #[derive(Debug, clap::Parser)]
@@ -95,6 +117,13 @@ pub struct Cli {
 	#[arg(long)]
 	pub no_hardware_benchmarks: bool,
 
+	/// Run the parachain runtime without a relay chain, using the given block authoring mode.
+	///
+	/// Relay chain inherents are mocked, so no relay chain node is required. This is intended
+	/// for local runtime development only.
+	#[arg(long, value_enum)]
+	pub dev_block_mode: Option<DevBlockMode>,
+
 	/// Relay chain arguments
 	#[arg(raw = true)]
 	pub relaychain_args: Vec<String>,
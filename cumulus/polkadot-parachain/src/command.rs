@@ -21,6 +21,7 @@ use crate::{
 	fake_runtime_api::{
 		asset_hub_polkadot_aura::RuntimeApi as AssetHubPolkadotRuntimeApi, aura::RuntimeApi,
 	},
+	runtime_discovery::{discover_consensus, AuraConsensusId, Consensus},
 	service::{new_partial, Block},
 };
 use cumulus_primitives_core::ParaId;
@@ -55,6 +56,9 @@ enum Runtime {
 	Glutton,
 	GluttonWestend,
 	BridgeHub(chain_spec::bridge_hubs::BridgeHubRuntimeType),
+	/// Any other standard FRAME parachain runtime. Its consensus is discovered from the runtime
+	/// of the chain spec.
+	Omni,
 }
 
 trait RuntimeResolver {
@@ -117,9 +121,16 @@ fn runtime(id: &str) -> Runtime {
 		Runtime::GluttonWestend
 	} else if id.starts_with("glutton") {
 		Runtime::Glutton
-	} else {
-		log::warn!("No specific runtime was recognized for ChainSpec's id: '{}', so Runtime::default() will be used", id);
+	} else if id.starts_with("local-testnet") |
+		id.starts_with("staging-testnet") |
+		id.starts_with("tick") |
+		id.starts_with("trick") |
+		id.starts_with("track")
+	{
 		Runtime::default()
+	} else {
+		log::info!("No specific runtime was recognized for ChainSpec's id: '{}', so its consensus will be discovered from its runtime", id);
+		Runtime::Omni
 	}
 }
 
@@ -322,7 +333,27 @@ impl SubstrateCli for Cli {
 	}
 
 	fn load_spec(&self, id: &str) -> std::result::Result<Box<dyn ChainSpec>, String> {
-		load_spec(id)
+		match chain_spec::remote::RemoteChainSpec::parse(id) {
+			Some(remote) => {
+				let path = remote?.fetch(&self.chain_spec_cache_dir()?)?;
+				load_spec(&path.to_string_lossy())
+			},
+			None => load_spec(id),
+		}
+	}
+}
+
+impl Cli {
+	/// Directory where the chain specs downloaded from remote locations are cached.
+	fn chain_spec_cache_dir(&self) -> std::result::Result<PathBuf, String> {
+		let base_path = self
+			.run
+			.base
+			.shared_params
+			.base_path()
+			.map_err(|e| e.to_string())?
+			.unwrap_or_else(|| BasePath::from_project("", "", &Self::executable_name()));
+		Ok(base_path.path().join("chain-specs"))
 	}
 }
 
@@ -406,6 +437,29 @@ macro_rules! construct_partials {
 				)?;
 				$code
 			},
+			Runtime::Omni => match discover_consensus(&*$config.chain_spec)? {
+				Consensus::Aura { id: AuraConsensusId::Sr25519, .. } => {
+					let $partials = new_partial::<RuntimeApi, _>(
+						&$config,
+						crate::service::aura_build_import_queue::<_, AuraId>,
+					)?;
+					$code
+				},
+				Consensus::Aura { id: AuraConsensusId::Ed25519, .. } => {
+					let $partials = new_partial::<AssetHubPolkadotRuntimeApi, _>(
+						&$config,
+						crate::service::aura_build_import_queue::<_, AssetHubPolkadotAuraId>,
+					)?;
+					$code
+				},
+				Consensus::Relay => {
+					let $partials = new_partial::<RuntimeApi, _>(
+						&$config,
+						crate::service::shell_build_import_queue,
+					)?;
+					$code
+				},
+			},
 		}
 	};
 }
@@ -475,6 +529,38 @@ macro_rules! construct_async_run {
 					{ $( $code )* }.map(|v| (v, task_manager))
 				})
 			},
+			Runtime::Omni => match discover_consensus(&*runner.config().chain_spec)? {
+				Consensus::Aura { id: AuraConsensusId::Sr25519, .. } => {
+					runner.async_run(|$config| {
+						let $components = new_partial::<RuntimeApi, _>(
+							&$config,
+							crate::service::aura_build_import_queue::<_, AuraId>,
+						)?;
+						let task_manager = $components.task_manager;
+						{ $( $code )* }.map(|v| (v, task_manager))
+					})
+				},
+				Consensus::Aura { id: AuraConsensusId::Ed25519, .. } => {
+					runner.async_run(|$config| {
+						let $components = new_partial::<AssetHubPolkadotRuntimeApi, _>(
+							&$config,
+							crate::service::aura_build_import_queue::<_, AssetHubPolkadotAuraId>,
+						)?;
+						let task_manager = $components.task_manager;
+						{ $( $code )* }.map(|v| (v, task_manager))
+					})
+				},
+				Consensus::Relay => {
+					runner.async_run(|$config| {
+						let $components = new_partial::<RuntimeApi, _>(
+							&$config,
+							crate::service::shell_build_import_queue,
+						)?;
+						let task_manager = $components.task_manager;
+						{ $( $code )* }.map(|v| (v, task_manager))
+					})
+				},
+			},
 		}
 	}}
 }
@@ -530,15 +616,23 @@ pub fn run() -> Result<()> {
 				cmd.run(config, polkadot_config)
 			})
 		},
+		Some(Subcommand::ExportGenesisArtifacts(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.sync_run(|config| {
+				construct_partials!(config, |partials| cmd.run(&*config.chain_spec, partials.client))
+			})
+		},
 		Some(Subcommand::ExportGenesisHead(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			runner.sync_run(|config| {
+				log::warn!("`export-genesis-state` is deprecated, use `export-genesis-artifacts` instead");
 				construct_partials!(config, |partials| cmd.run(partials.client))
 			})
 		},
 		Some(Subcommand::ExportGenesisWasm(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			runner.sync_run(|_config| {
+				log::warn!("`export-genesis-wasm` is deprecated, use `export-genesis-artifacts` instead");
 				let spec = cli.load_spec(&cmd.shared_params.chain.clone().unwrap_or_default())?;
 				cmd.run(&*spec)
 			})
@@ -633,13 +727,34 @@ pub fn run() -> Result<()> {
 					.map(|e| e.para_id)
 					.ok_or("Could not find parachain extension in chain-spec.")?;
 
+				let id = ParaId::from(para_id);
+
+				if let Some(block_mode) = cli.dev_block_mode {
+					info!("Starting development node with {:?} block authoring", block_mode);
+
+					return match config.chain_spec.runtime() {
+						Runtime::AssetHubPolkadot |
+						Runtime::Shell |
+						Runtime::Seedling |
+						Runtime::Glutton => Err(
+							"Development block authoring is only supported for sr25519 Aura-based runtimes".into()
+						),
+						Runtime::Omni if !matches!(
+							discover_consensus(&*config.chain_spec)?,
+							Consensus::Aura { id: AuraConsensusId::Sr25519, .. }
+						) => Err(
+							"Development block authoring is only supported for sr25519 Aura-based runtimes".into()
+						),
+						_ => crate::service::start_dev_node(config, block_mode, id)
+							.map_err(Into::into),
+					}
+				}
+
 				let polkadot_cli = RelayChainCli::new(
 					&config,
 					[RelayChainCli::executable_name()].iter().chain(cli.relaychain_args.iter()),
 				);
 
-				let id = ParaId::from(para_id);
-
 				let parachain_account =
 					AccountIdConversion::<polkadot_primitives::AccountId>::into_account_truncating(&id);
 
@@ -791,6 +906,52 @@ pub fn run() -> Result<()> {
 						.await
 						.map(|r| r.0)
 						.map_err(Into::into),
+					Runtime::Omni => {
+						let consensus = discover_consensus(&*config.chain_spec)?;
+						info!("Discovered runtime consensus: {:?}", consensus);
+
+						match consensus {
+							Consensus::Aura { id: AuraConsensusId::Sr25519, async_backing: true } =>
+								crate::service::start_basic_lookahead_node::<
+									RuntimeApi,
+									AuraId,
+								>(config, polkadot_config, collator_options, id, hwbench)
+								.await
+								.map(|r| r.0),
+							Consensus::Aura { id: AuraConsensusId::Sr25519, async_backing: false } =>
+								crate::service::start_generic_aura_node::<
+									RuntimeApi,
+									AuraId,
+								>(config, polkadot_config, collator_options, id, hwbench)
+								.await
+								.map(|r| r.0),
+							Consensus::Aura { id: AuraConsensusId::Ed25519, async_backing: true } =>
+								crate::service::start_basic_lookahead_node::<
+									AssetHubPolkadotRuntimeApi,
+									AssetHubPolkadotAuraId,
+								>(config, polkadot_config, collator_options, id, hwbench)
+								.await
+								.map(|r| r.0),
+							Consensus::Aura { id: AuraConsensusId::Ed25519, async_backing: false } =>
+								crate::service::start_generic_aura_node::<
+									AssetHubPolkadotRuntimeApi,
+									AssetHubPolkadotAuraId,
+								>(config, polkadot_config, collator_options, id, hwbench)
+								.await
+								.map(|r| r.0),
+							Consensus::Relay =>
+								crate::service::start_shell_node::<RuntimeApi>(
+									config,
+									polkadot_config,
+									collator_options,
+									id,
+									hwbench,
+								)
+								.await
+								.map(|r| r.0),
+						}
+						.map_err(Into::into)
+					},
 				}
 			})
 		},
@@ -878,6 +1039,10 @@ impl CliConfiguration<Self> for RelayChainCli {
 		self.base.base.trie_cache_maximum_size()
 	}
 
+	fn trie_cache_settings(&self) -> Result<sc_service::config::TrieCacheSettings> {
+		self.base.base.trie_cache_settings()
+	}
+
 	fn rpc_methods(&self) -> Result<sc_service::config::RpcMethods> {
 		self.base.base.rpc_methods()
 	}
@@ -1023,5 +1188,11 @@ mod tests {
 			Box::new(crate::chain_spec::contracts::contracts_rococo_local_config()),
 		);
 		assert_eq!(Runtime::ContractsRococo, path.runtime());
+
+		let path = store_configuration(
+			&temp_dir,
+			Box::new(create_default_with_extensions("my-parachain", Extensions1::default())),
+		);
+		assert_eq!(Runtime::Omni, path.runtime());
 	}
 }
@@ -24,6 +24,7 @@ mod cli;
 mod command;
 mod fake_runtime_api;
 mod rpc;
+mod runtime_discovery;
 mod service;
 
 fn main() -> sc_cli::Result<()> {
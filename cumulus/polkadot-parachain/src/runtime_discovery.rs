@@ -0,0 +1,164 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Discovery of the consensus of a parachain runtime, that is not known to this node.
+//!
+//! The consensus is selected from the runtime APIs of the genesis runtime of the chain spec, so
+//! that any standard FRAME parachain may be run without a compiled-in runtime.
+
+use codec::Decode;
+use cumulus_primitives_aura::AuraUnincludedSegmentApi;
+use frame_metadata::{v14::StorageEntryType, RuntimeMetadata, RuntimeMetadataPrefixed};
+use parachains_common::AuraId;
+use polkadot_primitives::Block;
+use sc_chain_spec::ChainSpec;
+use sc_executor::{RuntimeVersionOf, WasmExecutor};
+use scale_info::{PortableRegistry, TypeDef};
+use sp_api::RuntimeApiInfo;
+use sp_consensus_aura::AuraApi;
+use sp_core::{
+	storage::well_known_keys::CODE,
+	traits::{CallContext, CodeExecutor, RuntimeCode, WrappedRuntimeCode},
+};
+use sp_state_machine::BasicExternalities;
+use sp_version::RuntimeVersion;
+
+/// Key type of the Aura authorities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuraConsensusId {
+	/// `sp_consensus_aura::sr25519::AuthorityId`.
+	Sr25519,
+	/// `sp_consensus_aura::ed25519::AuthorityId`, as used by Polkadot Asset Hub.
+	Ed25519,
+}
+
+/// Consensus of a parachain runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Consensus {
+	/// Aura, with the lookahead collator if the runtime supports async backing.
+	Aura { id: AuraConsensusId, async_backing: bool },
+	/// Free-for-all relay chain consensus, for runtimes without Aura.
+	Relay,
+}
+
+/// Discover the consensus of the genesis runtime of the `chain_spec`.
+///
+/// Aura is selected if the runtime implements `AuraApi`, with the key type of the `Authorities`
+/// storage of the `Aura` pallet. Async backing is enabled if the runtime also implements
+/// `AuraUnincludedSegmentApi`. Runtime upgrades that change the consensus are not followed.
+pub fn discover_consensus(chain_spec: &dyn ChainSpec) -> Result<Consensus, String> {
+	let storage = chain_spec.as_storage_builder().build_storage()?;
+	let code = storage
+		.top
+		.get(CODE)
+		.ok_or("Could not find the runtime code in the genesis of the chain spec")?;
+	let code_fetcher = WrappedRuntimeCode(code.as_slice().into());
+	let runtime_code = RuntimeCode {
+		code_fetcher: &code_fetcher,
+		heap_pages: None,
+		hash: sp_core::blake2_256(code).to_vec(),
+	};
+	let executor = WasmExecutor::<sp_io::SubstrateHostFunctions>::builder()
+		.with_allow_missing_host_functions(true)
+		.build();
+	let mut ext = BasicExternalities::new_empty();
+
+	let version = executor
+		.runtime_version(&mut ext, &runtime_code)
+		.map_err(|e| format!("Could not read the runtime version: {e}"))?;
+	if !has_api::<dyn AuraApi<Block, AuraId>>(&version) {
+		return Ok(Consensus::Relay)
+	}
+
+	let metadata = executor
+		.call(&mut ext, &runtime_code, "Metadata_metadata", &[], CallContext::Offchain)
+		.0
+		.map_err(|e| format!("Could not read the runtime metadata: {e}"))?;
+	let metadata = <Vec<u8>>::decode(&mut &metadata[..])
+		.and_then(|metadata| RuntimeMetadataPrefixed::decode(&mut &metadata[..]))
+		.map_err(|e| format!("Could not decode the runtime metadata: {e}"))?;
+
+	Ok(Consensus::Aura {
+		id: aura_consensus_id(&metadata.1)?,
+		async_backing: has_api::<dyn AuraUnincludedSegmentApi<Block>>(&version),
+	})
+}
+
+fn has_api<Api: RuntimeApiInfo + ?Sized>(version: &RuntimeVersion) -> bool {
+	version.has_api_with(&Api::ID, |_| true)
+}
+
+/// Find the key type of the `Authorities` storage of the `Aura` pallet.
+fn aura_consensus_id(metadata: &RuntimeMetadata) -> Result<AuraConsensusId, String> {
+	let RuntimeMetadata::V14(metadata) = metadata else {
+		return Err("Only V14 runtime metadata is supported".into())
+	};
+	let authorities = metadata
+		.pallets
+		.iter()
+		.filter(|pallet| pallet.name == "Aura")
+		.flat_map(|pallet| pallet.storage.iter().flat_map(|storage| storage.entries.iter()))
+		.find(|entry| entry.name == "Authorities")
+		.ok_or("Could not find the `Aura::Authorities` storage in the runtime metadata")?;
+	let StorageEntryType::Plain(ty) = &authorities.ty else {
+		return Err("`Aura::Authorities` storage is expected to be a plain value".into())
+	};
+
+	if mentions_path_segment(&metadata.types, ty.id, "sr25519") {
+		Ok(AuraConsensusId::Sr25519)
+	} else if mentions_path_segment(&metadata.types, ty.id, "ed25519") {
+		Ok(AuraConsensusId::Ed25519)
+	} else {
+		Err("Unsupported key type of the Aura authorities".into())
+	}
+}
+
+/// Returns true if the path of the type `id`, or of the types that it wraps, has the `segment`.
+fn mentions_path_segment(types: &PortableRegistry, id: u32, segment: &str) -> bool {
+	let Some(ty) = types.resolve(id) else { return false };
+	if ty.path.segments.iter().any(|s| s == segment) {
+		return true
+	}
+
+	match &ty.type_def {
+		TypeDef::Composite(composite) => composite
+			.fields
+			.iter()
+			.any(|field| mentions_path_segment(types, field.ty.id, segment)),
+		TypeDef::Sequence(sequence) =>
+			mentions_path_segment(types, sequence.type_param.id, segment),
+		TypeDef::Array(array) => mentions_path_segment(types, array.type_param.id, segment),
+		_ => false,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::chain_spec;
+
+	#[test]
+	fn discover_consensus_works() {
+		assert_eq!(
+			discover_consensus(&chain_spec::rococo_parachain::rococo_parachain_local_config()),
+			Ok(Consensus::Aura { id: AuraConsensusId::Sr25519, async_backing: true }),
+		);
+		assert_eq!(
+			discover_consensus(&chain_spec::seedling::get_seedling_chain_spec()),
+			Ok(Consensus::Aura { id: AuraConsensusId::Sr25519, async_backing: false }),
+		);
+	}
+}
@@ -40,7 +40,7 @@ use sp_core::Pair;
 
 use jsonrpsee::RpcModule;
 
-use crate::{fake_runtime_api::aura::RuntimeApi, rpc};
+use crate::{cli::DevBlockMode, fake_runtime_api::aura::RuntimeApi, rpc};
 pub use parachains_common::{AccountId, Balance, Block, BlockNumber, Hash, Header, Nonce};
 
 use cumulus_client_consensus_relay_chain::Verifier as RelayChainVerifier;
@@ -903,7 +903,8 @@ pub async fn start_rococo_parachain_node(
 				prometheus_registry,
 				telemetry.clone(),
 			);
-			let proposer = Proposer::new(proposer_factory);
+			let proposer =
+				Proposer::new(proposer_factory).with_adaptive_duration(prometheus_registry);
 
 			let collator_service = CollatorService::new(
 				client.clone(),
@@ -1301,7 +1302,8 @@ where
 				prometheus_registry,
 				telemetry.clone(),
 			);
-			let proposer = Proposer::new(proposer_factory);
+			let proposer =
+				Proposer::new(proposer_factory).with_adaptive_duration(prometheus_registry);
 
 			let collator_service = CollatorService::new(
 				client.clone(),
@@ -1406,6 +1408,8 @@ where
 				prometheus_registry,
 				telemetry.clone(),
 			);
+			let proposer =
+				Proposer::new(proposer_factory).with_adaptive_duration(prometheus_registry);
 
 			let collation_future = Box::pin(async move {
 				// Start collating with the `shell` runtime while waiting for an upgrade to an Aura
@@ -1450,8 +1454,6 @@ where
 					},
 				};
 
-				let proposer = Proposer::new(proposer_factory);
-
 				let params = BasicAuraParams {
 					create_inherent_data_providers: move |_, ()| async move { Ok(()) },
 					block_import,
@@ -1542,7 +1544,8 @@ where
 				prometheus_registry,
 				telemetry.clone(),
 			);
-			let proposer = Proposer::new(proposer_factory);
+			let proposer =
+				Proposer::new(proposer_factory).with_adaptive_duration(prometheus_registry);
 
 			let collator_service = CollatorService::new(
 				client.clone(),
@@ -1849,7 +1852,8 @@ pub async fn start_contracts_rococo_node(
 				prometheus_registry,
 				telemetry.clone(),
 			);
-			let proposer = Proposer::new(proposer_factory);
+			let proposer =
+				Proposer::new(proposer_factory).with_adaptive_duration(prometheus_registry);
 
 			let collator_service = CollatorService::new(
 				client.clone(),
@@ -1897,6 +1901,197 @@ pub async fn start_contracts_rococo_node(
 	.await
 }
 
+/// Build the import queue for the development node.
+fn dev_build_import_queue(
+	_client: Arc<ParachainClient<RuntimeApi>>,
+	block_import: ParachainBlockImport<RuntimeApi>,
+	config: &Configuration,
+	_telemetry_handle: Option<TelemetryHandle>,
+	task_manager: &TaskManager,
+) -> Result<sc_consensus::DefaultImportQueue<Block>, sc_service::Error> {
+	Ok(sc_consensus_manual_seal::import_queue(
+		Box::new(block_import),
+		&task_manager.spawn_essential_handle(),
+		config.prometheus_registry(),
+	))
+}
+
+/// Start a development node, that runs an Aura-based parachain runtime without a relay chain.
+///
+/// Blocks are authored using manual or instant seal. The relay chain inherents are mocked, so that
+/// the `ParachainSystem` inherent, including the inbound message queue heads, is still valid.
+pub fn start_dev_node(
+	config: Configuration,
+	block_mode: DevBlockMode,
+	para_id: ParaId,
+) -> sc_service::error::Result<TaskManager> {
+	use cumulus_primitives_parachain_inherent::{
+		MockValidationDataInherentDataProvider, MockXcmConfig,
+	};
+	use sc_consensus_manual_seal::{
+		consensus::{aura::AuraConsensusDataProvider, timestamp::SlotTimestampProvider},
+		rpc::{ManualSeal, ManualSealApiServer},
+	};
+	use sp_blockchain::HeaderBackend;
+
+	const RELAY_CHAIN_SLOT_DURATION_MILLIS: u64 = 6_000;
+
+	let params = new_partial::<RuntimeApi, _>(&config, dev_build_import_queue)?;
+	let (block_import, mut telemetry, _) = params.other;
+
+	let client = params.client.clone();
+	let backend = params.backend.clone();
+	let mut task_manager = params.task_manager;
+	let transaction_pool = params.transaction_pool.clone();
+	let prometheus_registry = config.prometheus_registry().cloned();
+	let net_config = FullNetworkConfiguration::new(&config.network);
+
+	let (network, system_rpc_tx, tx_handler_controller, start_network, sync_service) =
+		sc_service::build_network(sc_service::BuildNetworkParams {
+			config: &config,
+			net_config,
+			client: client.clone(),
+			transaction_pool: transaction_pool.clone(),
+			spawn_handle: task_manager.spawn_handle(),
+			import_queue: params.import_queue,
+			block_announce_validator_builder: None,
+			warp_sync_params: None,
+			block_relay: None,
+		})?;
+
+	let (manual_seal_sink, commands_stream) = futures::channel::mpsc::channel(1024);
+
+	let rpc_builder = {
+		let client = client.clone();
+		let transaction_pool = transaction_pool.clone();
+		let backend_for_rpc = backend.clone();
+		let manual_seal_sink = (block_mode == DevBlockMode::Manual).then_some(manual_seal_sink);
+
+		Box::new(move |deny_unsafe, _| {
+			let deps = rpc::FullDeps {
+				client: client.clone(),
+				pool: transaction_pool.clone(),
+				deny_unsafe,
+			};
+
+			let mut module = rpc::create_full(deps, backend_for_rpc.clone())?;
+			if let Some(sink) = manual_seal_sink.clone() {
+				module
+					.merge(ManualSeal::new(sink).into_rpc())
+					.map_err(|e| sc_service::Error::Application(Box::new(e)))?;
+			}
+			Ok(module)
+		})
+	};
+
+	sc_service::spawn_tasks(sc_service::SpawnTasksParams {
+		rpc_builder,
+		client: client.clone(),
+		transaction_pool: transaction_pool.clone(),
+		task_manager: &mut task_manager,
+		config,
+		keystore: params.keystore_container.keystore(),
+		backend: backend.clone(),
+		network,
+		sync_service,
+		system_rpc_tx,
+		tx_handler_controller,
+		telemetry: telemetry.as_mut(),
+	})?;
+
+	let proposer = sc_basic_authorship::ProposerFactory::new(
+		task_manager.spawn_handle(),
+		client.clone(),
+		transaction_pool.clone(),
+		prometheus_registry.as_ref(),
+		telemetry.as_ref().map(|x| x.handle()),
+	);
+
+	let create_inherent_data_providers = {
+		let client = client.clone();
+		move |parent: Hash, ()| {
+			let client = client.clone();
+			async move {
+				let parent_header =
+					client.header(parent)?.ok_or("Parent header is not found in the database")?;
+
+				// the timestamp is advanced by the parachain slot duration on every block and the
+				// relay chain slot must match it, or the consensus hook of the runtime will reject
+				// the block
+				let timestamp = SlotTimestampProvider::new_aura(client.clone())?;
+				let relay_chain_slot = sp_consensus_aura::Slot::from(
+					timestamp.timestamp().as_millis() / RELAY_CHAIN_SLOT_DURATION_MILLIS,
+				);
+
+				let mocked_parachain = MockValidationDataInherentDataProvider {
+					current_para_block: *parent_header.number(),
+					relay_offset: 1000,
+					relay_blocks_per_para_block: 2,
+					para_blocks_per_relay_epoch: 10,
+					relay_randomness_config: (),
+					xcm_config: MockXcmConfig::new(&*client, parent, para_id, Default::default()),
+					raw_downward_messages: Vec::new(),
+					raw_horizontal_messages: Vec::new(),
+					additional_key_values: Some(vec![(
+						cumulus_primitives_core::relay_chain::well_known_keys::CURRENT_SLOT
+							.to_vec(),
+						codec::Encode::encode(&relay_chain_slot),
+					)]),
+				};
+
+				Ok((timestamp, mocked_parachain))
+			}
+		}
+	};
+
+	let select_chain = sc_consensus::LongestChain::new(backend);
+	let consensus_data_provider = AuraConsensusDataProvider::new(client.clone());
+
+	match block_mode {
+		DevBlockMode::Manual => {
+			let authorship_future = sc_consensus_manual_seal::run_manual_seal(
+				sc_consensus_manual_seal::ManualSealParams {
+					block_import,
+					env: proposer,
+					client,
+					pool: transaction_pool,
+					commands_stream,
+					select_chain,
+					consensus_data_provider: Some(Box::new(consensus_data_provider)),
+					create_inherent_data_providers,
+				},
+			);
+			task_manager.spawn_essential_handle().spawn_blocking(
+				"manual-seal",
+				None,
+				authorship_future,
+			);
+		},
+		DevBlockMode::Instant => {
+			let authorship_future = sc_consensus_manual_seal::run_instant_seal(
+				sc_consensus_manual_seal::InstantSealParams {
+					block_import,
+					env: proposer,
+					client,
+					pool: transaction_pool,
+					select_chain,
+					consensus_data_provider: Some(Box::new(consensus_data_provider)),
+					create_inherent_data_providers,
+				},
+			);
+			task_manager.spawn_essential_handle().spawn_blocking(
+				"instant-seal",
+				None,
+				authorship_future,
+			);
+		},
+	}
+
+	start_network.start_network();
+
+	Ok(task_manager)
+}
+
 /// Checks that the hardware meets the requirements and print a warning otherwise.
 fn warn_if_slow_hardware(hwbench: &sc_sysinfo::HwBench) {
 	// Polkadot para-chains should generally use these requirements to ensure that the relay-chain
@@ -0,0 +1,53 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Substrate.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+use assert_cmd::cargo::cargo_bin;
+use std::{path::Path, process::Command};
+use tempfile::tempdir;
+
+/// `export-genesis-artifacts` writes the genesis head and wasm in all formats.
+#[test]
+fn export_genesis_artifacts_works() {
+	let tmp_dir = tempdir().expect("could not create a temp dir");
+	let output_dir = tmp_dir.path();
+
+	assert!(export_genesis_artifacts("hex", Some(output_dir)).status.success());
+	let head = std::fs::read_to_string(output_dir.join("genesis-head.hex")).unwrap();
+	let wasm = std::fs::read_to_string(output_dir.join("genesis-wasm.hex")).unwrap();
+	assert!(head.starts_with("0x") && wasm.starts_with("0x"));
+
+	assert!(export_genesis_artifacts("binary", Some(output_dir)).status.success());
+	let raw_head = std::fs::read(output_dir.join("genesis-head.bin")).unwrap();
+	assert_eq!(head.len(), 2 + 2 * raw_head.len());
+
+	// Without an output directory, only the JSON manifest can be exported.
+	assert!(!export_genesis_artifacts("hex", None).status.success());
+	let output = export_genesis_artifacts("json", None);
+	assert!(output.status.success());
+	let manifest: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+	assert_eq!(manifest["genesis_head"], head);
+	assert_eq!(manifest["validation_code"], wasm);
+}
+
+/// Invoke the `export-genesis-artifacts` sub-command with the given format.
+fn export_genesis_artifacts(format: &str, output_dir: Option<&Path>) -> std::process::Output {
+	let mut cmd = Command::new(cargo_bin("polkadot-parachain"));
+	cmd.args(["export-genesis-artifacts", "--chain", "asset-hub-westend-dev", "--format", format]);
+	if let Some(dir) = output_dir {
+		cmd.arg("--output-dir").arg(dir);
+	}
+	cmd.output().unwrap()
+}
@@ -386,4 +386,13 @@ sp_api::decl_runtime_apis! {
 		/// we are collecting the collation info for.
 		fn collect_collation_info(header: &Block::Header) -> CollationInfo;
 	}
+
+	/// Runtime api to query the code upgrade restriction imposed by the relay chain.
+	pub trait UpgradeRestrictionApi {
+		/// The upgrade restriction the relay chain imposed on this parachain as of the last block,
+		/// if any.
+		///
+		/// While this is `Some`, the parachain can't schedule a code upgrade.
+		fn upgrade_restriction() -> Option<relay_chain::UpgradeRestriction>;
+	}
 }
@@ -22,6 +22,7 @@
 use codec::Encode;
 use cumulus_primitives_core::{MessageSendError, UpwardMessageSender};
 use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
 	traits::{
 		tokens::{fungibles, fungibles::Inspect},
 		Get,
@@ -32,8 +33,8 @@ use polkadot_runtime_common::xcm_sender::PriceForMessageDelivery;
 use sp_runtime::{traits::Saturating, SaturatedConversion};
 use sp_std::{marker::PhantomData, prelude::*};
 use xcm::{latest::prelude::*, WrapVersion};
-use xcm_builder::TakeRevenue;
-use xcm_executor::traits::{MatchesFungibles, TransactAsset, WeightTrader};
+use xcm_builder::{AcquireFees, TakeRevenue};
+use xcm_executor::traits::{ConvertLocation, MatchesFungibles, TransactAsset, WeightTrader};
 
 /// Xcm router which recognises the `Parent` destination and handles it by sending the message into
 /// the given UMP `UpwardMessageSender` implementation. Thus this essentially adapts an
@@ -291,6 +292,115 @@ pub trait ChargeWeightInFungibles<AccountId, Assets: fungibles::Inspect<AccountI
 	) -> Result<<Assets as Inspect<AccountId>>::Balance, XcmError>;
 }
 
+/// An [`AcquireFees`] implementation which swaps (using `pallet-asset-conversion`) one of the
+/// `CandidateAssets` owned by the origin for the native asset, so that delivery fees can be paid
+/// by accounts that hold no native asset, but hold some sufficient asset instead.
+///
+/// Only fees in the native asset (identified by `NativeLocation`) can be acquired. The swapped
+/// amount is at least `NativeExistentialDeposit`, so that the account receiving the native asset
+/// can be created. Candidates are tried in order, the first successful swap wins.
+pub struct SwapAssetsForNativeFees<
+	AccountId,
+	AccountIdConverter,
+	Swapper,
+	Balance,
+	MultiAssetId,
+	NativeLocation,
+	NativeExistentialDeposit,
+	CandidateAssets,
+>(
+	PhantomData<(
+		AccountId,
+		AccountIdConverter,
+		Swapper,
+		Balance,
+		MultiAssetId,
+		NativeLocation,
+		NativeExistentialDeposit,
+		CandidateAssets,
+	)>,
+);
+
+impl<
+		AccountId: Clone,
+		AccountIdConverter: ConvertLocation<AccountId>,
+		Swapper: pallet_asset_conversion::Swap<AccountId, Balance, MultiAssetId>,
+		Balance: TryFrom<u128> + Clone,
+		MultiAssetId: From<MultiLocation>,
+		NativeLocation: Get<MultiLocation>,
+		NativeExistentialDeposit: Get<u128>,
+		CandidateAssets: Get<Vec<MultiLocation>>,
+	> AcquireFees
+	for SwapAssetsForNativeFees<
+		AccountId,
+		AccountIdConverter,
+		Swapper,
+		Balance,
+		MultiAssetId,
+		NativeLocation,
+		NativeExistentialDeposit,
+		CandidateAssets,
+	>
+{
+	fn acquire_fees(origin: &MultiLocation, fees: &MultiAssets) -> Result<(), XcmError> {
+		let who = AccountIdConverter::convert_location(origin).ok_or(XcmError::FeesNotMet)?;
+
+		// we only know how to acquire the native asset
+		let native = NativeLocation::get();
+		let mut required: u128 = 0;
+		for fee in fees.inner() {
+			match fee {
+				MultiAsset { id: Concrete(location), fun: Fungible(amount) }
+					if *location == native =>
+					required = required.saturating_add(*amount),
+				_ => return Err(XcmError::FeesNotMet),
+			}
+		}
+		let amount_out: Balance = required
+			.max(NativeExistentialDeposit::get())
+			.try_into()
+			.map_err(|_| XcmError::FeesNotMet)?;
+
+		for candidate in CandidateAssets::get() {
+			if candidate == native {
+				continue
+			}
+			let swapped = with_transaction(|| {
+				match Swapper::swap_tokens_for_exact_tokens(
+					who.clone(),
+					vec![candidate.into(), native.into()],
+					amount_out.clone(),
+					None,
+					who.clone(),
+					true,
+				) {
+					Ok(amount_in) => TransactionOutcome::Commit(Ok(amount_in)),
+					Err(error) => TransactionOutcome::Rollback(Err(error)),
+				}
+			});
+			match swapped {
+				Ok(_) => {
+					log::trace!(
+						target: "xcm::fees",
+						"Swapped {:?} for {:?} of native asset to pay fees of {:?}",
+						candidate, required, origin,
+					);
+					return Ok(())
+				},
+				Err(error) => {
+					log::trace!(
+						target: "xcm::fees",
+						"Unable to swap {:?} for native asset to pay fees of {:?}: {:?}",
+						candidate, origin, error,
+					);
+				},
+			}
+		}
+
+		Err(XcmError::FeesNotMet)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -17,7 +17,7 @@
 use cumulus_primitives_core::{
 	relay_chain, AbridgedHostConfiguration, AbridgedHrmpChannel, ParaId,
 };
-use polkadot_primitives::UpgradeGoAhead;
+use polkadot_primitives::{UpgradeGoAhead, UpgradeRestriction};
 use sp_runtime::traits::HashingFor;
 use sp_std::collections::btree_map::BTreeMap;
 use sp_trie::PrefixedMemoryDB;
@@ -38,6 +38,7 @@ pub struct RelayStateSproofBuilder {
 	pub host_config: AbridgedHostConfiguration,
 	pub dmq_mqc_head: Option<relay_chain::Hash>,
 	pub upgrade_go_ahead: Option<UpgradeGoAhead>,
+	pub upgrade_restriction: Option<UpgradeRestriction>,
 	pub relay_dispatch_queue_remaining_capacity: Option<(u32, u32)>,
 	pub hrmp_ingress_channel_index: Option<Vec<ParaId>>,
 	pub hrmp_egress_channel_index: Option<Vec<ParaId>>,
@@ -70,6 +71,7 @@ impl Default for RelayStateSproofBuilder {
 			},
 			dmq_mqc_head: None,
 			upgrade_go_ahead: None,
+			upgrade_restriction: None,
 			relay_dispatch_queue_remaining_capacity: None,
 			hrmp_ingress_channel_index: None,
 			hrmp_egress_channel_index: None,
@@ -171,6 +173,12 @@ impl RelayStateSproofBuilder {
 					upgrade_go_ahead.encode(),
 				);
 			}
+			if let Some(upgrade_restriction) = self.upgrade_restriction {
+				insert(
+					relay_chain::well_known_keys::upgrade_restriction_signal(self.para_id),
+					upgrade_restriction.encode(),
+				);
+			}
 			if let Some(hrmp_ingress_channel_index) = self.hrmp_ingress_channel_index {
 				let mut sorted = hrmp_ingress_channel_index.clone();
 				sorted.sort();
@@ -462,6 +462,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl cumulus_primitives_core::UpgradeRestrictionApi<Block> for Runtime {
+		fn upgrade_restriction() -> Option<cumulus_primitives_core::relay_chain::UpgradeRestriction> {
+			ParachainSystem::upgrade_restriction()
+		}
+	}
+
 	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
 		fn create_default_config() -> Vec<u8> {
 			create_default_config::<RuntimeGenesisConfig>()
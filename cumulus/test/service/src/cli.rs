@@ -165,6 +165,10 @@ impl CliConfiguration<Self> for RelayChainCli {
 		self.base.base.trie_cache_maximum_size()
 	}
 
+	fn trie_cache_settings(&self) -> CliResult<sc_service::config::TrieCacheSettings> {
+		self.base.base.trie_cache_settings()
+	}
+
 	fn rpc_methods(&self) -> CliResult<sc_service::config::RpcMethods> {
 		self.base.base.rpc_methods()
 	}
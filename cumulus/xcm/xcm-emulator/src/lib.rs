@@ -330,7 +330,42 @@ impl fmt::Display for BridgeMessageDispatchError {
 	}
 }
 
+/// Returns the first group of tokens if it is not empty, otherwise the second one.
+///
+/// Used by the `decl_test_*` macros to fall back to the defaults inferred from the runtime when
+/// an optional item is not provided.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __either_or_default {
+	({ $($value:tt)+ } { $($default:tt)* }) => {
+		$($value)+
+	};
+	({} { $($default:tt)* }) => {
+		$($default)*
+	};
+}
+
 // Relay Chain Implementation
+/// Declares emulated relay chains. Only `genesis` and `runtime` are mandatory, the rest is
+/// inferred from the runtime when omitted:
+///
+/// - `on_init` defaults to `()`;
+/// - `core.SovereignAccountOf` defaults to `$runtime::xcm_config::LocationConverter`;
+/// - `core.MessageProcessor` defaults to [`DefaultRelayMessageProcessor`];
+/// - `pallets` defaults to no pallets.
+///
+/// ```ignore
+/// decl_test_relay_chains! {
+/// 	#[api_version(10)]
+/// 	pub struct Westend {
+/// 		genesis = genesis::genesis(),
+/// 		runtime = westend_runtime,
+/// 		pallets = {
+/// 			XcmPallet: westend_runtime::XcmPallet,
+/// 		}
+/// 	},
+/// }
+/// ```
 #[macro_export]
 macro_rules! decl_test_relay_chains {
 	(
@@ -338,14 +373,16 @@ macro_rules! decl_test_relay_chains {
 			#[api_version($api_version:tt)]
 			pub struct $name:ident {
 				genesis = $genesis:expr,
-				on_init = $on_init:expr,
-				runtime = $runtime:ident,
-				core = {
-					SovereignAccountOf: $sovereign_acc_of:path,
-				},
-				pallets = {
+				$(on_init = $on_init:expr,)?
+				runtime = $runtime:ident
+				$(, core = {
+					$(SovereignAccountOf: $sovereign_acc_of:path,)?
+					$(MessageProcessor: $message_processor:path,)?
+				})?
+				$(, pallets = {
 					$($pallet_name:ident: $pallet_path:path,)*
-				}
+				})?
+				$(,)?
 			}
 		),
 		+
@@ -376,8 +413,14 @@ macro_rules! decl_test_relay_chains {
 			}
 
 			impl<N: $crate::Network> $crate::RelayChain for $name<N> {
-				type SovereignAccountOf = $sovereign_acc_of;
-				type MessageProcessor = $crate::DefaultRelayMessageProcessor<$name<N>>;
+				type SovereignAccountOf = $crate::__either_or_default!(
+					{ $($($sovereign_acc_of)?)? }
+					{ $runtime::xcm_config::LocationConverter }
+				);
+				type MessageProcessor = $crate::__either_or_default!(
+					{ $($($message_processor)?)? }
+					{ $crate::DefaultRelayMessageProcessor<$name<N>> }
+				);
 
 				fn init() {
 					use $crate::TestExt;
@@ -390,19 +433,25 @@ macro_rules! decl_test_relay_chains {
 
 			$crate::paste::paste! {
 				pub trait [<$name RelayPallet>] {
-					$(
+					$($(
 						type $pallet_name;
-					)?
+					)*)?
 				}
 
 				impl<N: $crate::Network> [<$name RelayPallet>] for $name<N> {
-					$(
+					$($(
 						type $pallet_name = $pallet_path;
-					)?
+					)*)?
 				}
 			}
 
-			$crate::__impl_test_ext_for_relay_chain!($name, N, $genesis, $on_init, $api_version);
+			$crate::__impl_test_ext_for_relay_chain!(
+				$name,
+				N,
+				$genesis,
+				$crate::__either_or_default!({ $($on_init)? } { () }),
+				$api_version
+			);
 			$crate::__impl_check_assertion!($name, N);
 		)+
 	};
@@ -564,23 +613,48 @@ macro_rules! __impl_test_ext_for_relay_chain {
 }
 
 // Parachain Implementation
+/// Declares emulated parachains. Only `genesis` and `runtime` are mandatory, the rest is
+/// inferred from the runtime when omitted:
+///
+/// - `on_init` defaults to `()`;
+/// - `core.XcmpMessageHandler` defaults to `$runtime::XcmpQueue`;
+/// - `core.LocationToAccountId` defaults to `$runtime::xcm_config::LocationToAccountId`;
+/// - `core.ParachainInfo` defaults to `$runtime::ParachainInfo`;
+/// - `core.MessageProcessor` defaults to [`DefaultParaMessageProcessor`];
+/// - `pallets` defaults to no pallets.
+///
+/// ```ignore
+/// decl_test_parachains! {
+/// 	pub struct AssetHubWestend {
+/// 		genesis = genesis::genesis(),
+/// 		on_init = {
+/// 			asset_hub_westend_runtime::AuraExt::on_initialize(1);
+/// 		},
+/// 		runtime = asset_hub_westend_runtime,
+/// 		pallets = {
+/// 			PolkadotXcm: asset_hub_westend_runtime::PolkadotXcm,
+/// 		}
+/// 	},
+/// }
+/// ```
 #[macro_export]
 macro_rules! decl_test_parachains {
 	(
 		$(
 			pub struct $name:ident {
 				genesis = $genesis:expr,
-				on_init = $on_init:expr,
-				runtime = $runtime:ident,
-				core = {
-					XcmpMessageHandler: $xcmp_message_handler:path,
-					LocationToAccountId: $location_to_account:path,
-					ParachainInfo: $parachain_info:path,
-					// MessageProcessor: $message_processor:path,
-				},
-				pallets = {
+				$(on_init = $on_init:expr,)?
+				runtime = $runtime:ident
+				$(, core = {
+					$(XcmpMessageHandler: $xcmp_message_handler:path,)?
+					$(LocationToAccountId: $location_to_account:path,)?
+					$(ParachainInfo: $parachain_info:path,)?
+					$(MessageProcessor: $message_processor:path,)?
+				})?
+				$(, pallets = {
 					$($pallet_name:ident: $pallet_path:path,)*
-				}
+				})?
+				$(,)?
 			}
 		),
 		+
@@ -611,11 +685,23 @@ macro_rules! decl_test_parachains {
 			}
 
 			impl<N: $crate::Network> $crate::Parachain for $name<N> {
-				type XcmpMessageHandler = $xcmp_message_handler;
-				type LocationToAccountId = $location_to_account;
+				type XcmpMessageHandler = $crate::__either_or_default!(
+					{ $($($xcmp_message_handler)?)? }
+					{ $runtime::XcmpQueue }
+				);
+				type LocationToAccountId = $crate::__either_or_default!(
+					{ $($($location_to_account)?)? }
+					{ $runtime::xcm_config::LocationToAccountId }
+				);
 				type ParachainSystem = $crate::ParachainSystemPallet<<Self as $crate::Chain>::Runtime>;
-				type ParachainInfo = $parachain_info;
-				type MessageProcessor = $crate::DefaultParaMessageProcessor<$name<N>>;
+				type ParachainInfo = $crate::__either_or_default!(
+					{ $($($parachain_info)?)? }
+					{ $runtime::ParachainInfo }
+				);
+				type MessageProcessor = $crate::__either_or_default!(
+					{ $($($message_processor)?)? }
+					{ $crate::DefaultParaMessageProcessor<$name<N>> }
+				);
 
 				// We run an empty block during initialisation to open HRMP channels
 				// and have them ready for the next block
@@ -695,19 +781,24 @@ macro_rules! decl_test_parachains {
 
 			$crate::paste::paste! {
 				pub trait [<$name ParaPallet>] {
-					$(
+					$($(
 						type $pallet_name;
-					)*
+					)*)?
 				}
 
 				impl<N: $crate::Network> [<$name ParaPallet>] for $name<N> {
-					$(
+					$($(
 						type $pallet_name = $pallet_path;
-					)*
+					)*)?
 				}
 			}
 
-			$crate::__impl_test_ext_for_parachain!($name, N, $genesis, $on_init);
+			$crate::__impl_test_ext_for_parachain!(
+				$name,
+				N,
+				$genesis,
+				$crate::__either_or_default!({ $($on_init)? } { () })
+			);
 			$crate::__impl_check_assertion!($name, N);
 		)+
 	};
@@ -1292,6 +1383,23 @@ macro_rules! assert_expected_events {
 	}
 }
 
+/// Creates an [`ExpectedEvent`] of `$chain`, that matches `$event_pat` and the optional guard.
+///
+/// Like in [`assert_expected_events`], the pattern may refer to the runtime event of the chain as
+/// `RuntimeEvent`.
+#[macro_export]
+macro_rules! expected_event {
+	( $chain:ident, $event_pat:pat $( if $guard:expr )? $(,)? ) => {
+		$crate::ExpectedEvent::new::<$chain>(
+			concat!(stringify!($event_pat) $(, " if ", stringify!($guard))?),
+			|event| {
+				type RuntimeEvent = <$chain as $crate::Chain>::RuntimeEvent;
+				matches!(event, $event_pat $( if $guard )?)
+			},
+		)
+	};
+}
+
 #[macro_export]
 macro_rules! bx {
 	($e:expr) => {
@@ -1518,6 +1626,198 @@ where
 	}
 }
 
+/// An event of a block, collected by [`collect_events`].
+struct CollectedEvent {
+	event: Box<dyn std::any::Any>,
+	debug: String,
+}
+
+/// Executes the given function in a new block of some chain and collects the events of the block.
+type CollectEvents = for<'b> fn(Box<dyn FnOnce() + 'b>) -> Vec<CollectedEvent>;
+
+fn collect_events<C>(execute: Box<dyn FnOnce() + '_>) -> Vec<CollectedEvent>
+where
+	C: Chain,
+	C::RuntimeEvent: Debug + 'static,
+{
+	C::execute_with(|| {
+		execute();
+		C::events()
+			.into_iter()
+			.map(|event| CollectedEvent { debug: format!("{:?}", event), event: Box::new(event) })
+			.collect()
+	})
+}
+
+/// Name of the chain `C`, without the module path and the generic arguments.
+fn chain_name<C>() -> &'static str {
+	let name = type_name::<C>();
+	let name = name.split('<').next().unwrap_or(name);
+	name.rsplit("::").next().unwrap_or(name)
+}
+
+/// An event that is expected to be emitted by some chain, see [`ExpectedEffects`].
+///
+/// Usually created with the [`expected_event`] macro.
+pub struct ExpectedEvent<'a> {
+	chain: &'static str,
+	collect_events: CollectEvents,
+	description: String,
+	matches: Box<dyn Fn(&(dyn std::any::Any + 'static)) -> bool + 'a>,
+}
+
+impl<'a> ExpectedEvent<'a> {
+	/// Creates an event of the chain `C`, which is expected to satisfy `matches`.
+	pub fn new<C>(
+		description: impl Into<String>,
+		matches: impl Fn(&C::RuntimeEvent) -> bool + 'a,
+	) -> Self
+	where
+		C: Chain + 'static,
+		C::RuntimeEvent: Debug + 'static,
+	{
+		ExpectedEvent {
+			chain: chain_name::<C>(),
+			collect_events: collect_events::<C>,
+			description: description.into(),
+			matches: Box::new(move |event: &(dyn std::any::Any + 'static)| {
+				event.downcast_ref::<C::RuntimeEvent>().map_or(false, &matches)
+			}),
+		}
+	}
+}
+
+/// Events that are expected in a single block of a chain.
+struct ExpectedBlock<'a> {
+	chain: &'static str,
+	collect_events: CollectEvents,
+	execute: Box<dyn FnOnce() + 'a>,
+	expected: Vec<ExpectedEvent<'a>>,
+}
+
+/// A chain of effects, that an XCM interaction is expected to cause on the chains of a network,
+/// e.g. "AH emits `Sent` → BH emits `MessageAccepted` on lane X → remote AH emits `Processed`".
+///
+/// Every chain is visited in a single new block, where its events are matched in the given order.
+/// Consecutive events of the same chain are expected in the same block, so the messages sent by
+/// a chain are only received by the chains that are visited after it. When the chain of effects
+/// is not observed, [`ExpectedEffects::assert`] panics with a diff between the expected and the
+/// emitted events of every block, e.g.:
+///
+/// ```ignore
+/// ExpectedEffects::new()
+/// 	.execute::<AssetHubRococo>(|| assert_ok!(send_message()))
+/// 	.expect(expected_event!(AssetHubRococo, RuntimeEvent::PolkadotXcm(pallet_xcm::Event::Sent { .. })))
+/// 	.expect(expected_event!(
+/// 		BridgeHubRococo,
+/// 		RuntimeEvent::BridgeWestendMessages(
+/// 			pallet_bridge_messages::Event::MessageAccepted { lane_id, .. }
+/// 		) if *lane_id == XCM_LANE
+/// 	))
+/// 	.expect(expected_event!(
+/// 		AssetHubWestend,
+/// 		RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed { success: true, .. })
+/// 	))
+/// 	.assert();
+/// ```
+#[derive(Default)]
+pub struct ExpectedEffects<'a> {
+	blocks: Vec<ExpectedBlock<'a>>,
+}
+
+impl<'a> ExpectedEffects<'a> {
+	/// Creates an empty chain of effects.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Executes `execute` in a new block of the chain `C`, where the events that are expected next
+	/// on `C` are emitted.
+	pub fn execute<C>(mut self, execute: impl FnOnce() + 'a) -> Self
+	where
+		C: Chain + 'static,
+		C::RuntimeEvent: Debug + 'static,
+	{
+		self.blocks.push(ExpectedBlock {
+			chain: chain_name::<C>(),
+			collect_events: collect_events::<C>,
+			execute: Box::new(execute),
+			expected: Vec::new(),
+		});
+		self
+	}
+
+	/// Expects `event` after the previous effect.
+	///
+	/// The event is expected in the block of the previous effect, if it is on the same chain, or
+	/// in a new block of its chain otherwise.
+	pub fn expect(mut self, event: ExpectedEvent<'a>) -> Self {
+		match self.blocks.last_mut() {
+			Some(block) if block.chain == event.chain => block.expected.push(event),
+			_ => self.blocks.push(ExpectedBlock {
+				chain: event.chain,
+				collect_events: event.collect_events,
+				execute: Box::new(|| ()),
+				expected: vec![event],
+			}),
+		}
+		self
+	}
+
+	/// Visits the chains in order and asserts that all the expected events are emitted.
+	pub fn assert(self) {
+		let mut observed = true;
+		let mut diff: Vec<String> = Vec::new();
+
+		for block in self.blocks {
+			let events = (block.collect_events)(block.execute);
+			let mut matched = vec![false; events.len()];
+			let mut next_index = 0;
+			let mut block_observed = true;
+
+			diff.push(format!("{}:", block.chain));
+			for expected in &block.expected {
+				let found = events
+					.iter()
+					.enumerate()
+					.skip(next_index)
+					.find(|(_, event)| (expected.matches)(&*event.event))
+					.map(|(index, _)| index);
+				match found {
+					Some(index) => {
+						matched[index] = true;
+						next_index = index + 1;
+						diff.push(format!("    {}", expected.description));
+					},
+					None => {
+						block_observed = false;
+						diff.push(format!("  - {}", expected.description));
+					},
+				}
+			}
+
+			if !block_observed {
+				observed = false;
+				diff.extend(
+					events
+						.iter()
+						.zip(matched)
+						.filter(|(_, matched)| !matched)
+						.map(|(event, _)| format!("  + {}", event.debug)),
+				);
+			}
+		}
+
+		if !observed {
+			panic!(
+				"\n\nExpected chain of effects was not observed (`-` expected events that are missing, \
+				`+` other events of the block):\n{}\n",
+				diff.join("\n")
+			)
+		}
+	}
+}
+
 pub mod helpers {
 	use super::*;
 
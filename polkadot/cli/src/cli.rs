@@ -120,6 +120,19 @@ pub struct RunCmd {
 	#[arg(long)]
 	pub no_hardware_benchmarks: bool,
 
+	/// The hardware requirements to check the results of the hardware benchmarks against.
+	///
+	/// Can be one of `relay-validator`, `parachain-collator` or `rpc-node`.
+	#[arg(long, default_value_t = sc_sysinfo::HardwareProfile::RelayValidator)]
+	pub hardware_profile: sc_sysinfo::HardwareProfile,
+
+	/// Refuse to start a validator whose hardware does not meet the requirements of the
+	/// `--hardware-profile`.
+	///
+	/// Otherwise only a warning is printed.
+	#[arg(long, conflicts_with = "no_hardware_benchmarks")]
+	pub fail_on_insufficient_hardware: bool,
+
 	/// Overseer message capacity override.
 	///
 	/// **Dangerous!** Do not touch unless explicitly adviced to.
@@ -248,6 +248,23 @@ where
 			}))
 			.flatten();
 
+		if let Some(ref hwbench) = hwbench {
+			let profile = cli.run.hardware_profile;
+			let requirements = profile.requirements(&SUBSTRATE_REFERENCE_HARDWARE);
+			if let Some(registry) = config.prometheus_registry() {
+				if let Err(err) =
+					sc_sysinfo::register_hwbench_metrics(registry, hwbench, &requirements)
+				{
+					warn!("Failed to register the hardware benchmark metrics: {}", err);
+				}
+			}
+			if let Err(failures) = requirements.check_hardware(hwbench) {
+				if cli.run.fail_on_insufficient_hardware && config.role.is_authority() {
+					return Err(Error::InsufficientHardware { profile, failures })
+				}
+			}
+		}
+
 		let database_source = config.database.clone();
 		let task_manager = service::build_full(
 			config,
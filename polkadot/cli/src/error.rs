@@ -53,6 +53,12 @@ pub enum Error {
 
 	#[error("This subcommand is only available when compiled with `{feature}`")]
 	FeatureNotEnabled { feature: &'static str },
+
+	#[error("The hardware does not meet the minimal requirements of the `{profile}` profile. {failures}")]
+	InsufficientHardware {
+		profile: sc_sysinfo::HardwareProfile,
+		failures: sc_sysinfo::CheckFailures,
+	},
 }
 
 impl From<String> for Error {
@@ -0,0 +1,106 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Compares the SIMD-accelerated code with the code of the crate root for large PoVs.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::time::Duration;
+
+const N_VALIDATORS: [usize; 3] = [300, 1000, 2000];
+const MB: usize = 1024 * 1024;
+const POV_SIZES: [usize; 2] = [5 * MB, 10 * MB];
+
+type ObtainChunks = fn(usize, &Vec<u8>) -> Vec<Vec<u8>>;
+type Reconstruct = fn(usize, Vec<(&[u8], usize)>) -> Vec<u8>;
+
+const BACKENDS: [(&str, ObtainChunks, Reconstruct); 2] = [
+	(
+		"novelpoly",
+		|n, pov| polkadot_erasure_coding::obtain_chunks(n, pov).unwrap(),
+		|n, chunks| polkadot_erasure_coding::reconstruct(n, chunks).unwrap(),
+	),
+	(
+		"simd",
+		|n, pov| polkadot_erasure_coding::simd::obtain_chunks(n, pov).unwrap(),
+		|n, chunks| polkadot_erasure_coding::simd::reconstruct(n, chunks).unwrap(),
+	),
+];
+
+fn construct(c: &mut Criterion) {
+	for pov_size in POV_SIZES {
+		let pov = vec![0xfe; pov_size];
+
+		let mut group = c.benchmark_group(format!("construct-{}mb", pov_size / MB));
+		group.throughput(Throughput::Bytes(pov.len() as u64));
+		for (name, obtain_chunks, _) in BACKENDS {
+			for n_validators in N_VALIDATORS {
+				group.bench_with_input(
+					BenchmarkId::new(name, n_validators),
+					&n_validators,
+					|b, &n| {
+						b.iter(|| obtain_chunks(n, &pov));
+					},
+				);
+			}
+		}
+		group.finish();
+	}
+}
+
+fn reconstruct(c: &mut Criterion) {
+	for pov_size in POV_SIZES {
+		let pov = vec![0xfe; pov_size];
+
+		let mut group = c.benchmark_group(format!("reconstruct-{}mb", pov_size / MB));
+		group.throughput(Throughput::Bytes(pov.len() as u64));
+		for (name, obtain_chunks, reconstruct) in BACKENDS {
+			for n_validators in N_VALIDATORS {
+				let all_chunks = obtain_chunks(n_validators, &pov);
+
+				// Use the last chunks, so that the systematic chunks have to be recovered.
+				let mut c: Vec<_> =
+					all_chunks.iter().enumerate().map(|(i, c)| (&c[..], i)).collect();
+				let last_chunks = c.split_off((c.len() - 1) * 2 / 3);
+
+				group.bench_with_input(
+					BenchmarkId::new(name, n_validators),
+					&n_validators,
+					|b, &n| {
+						b.iter(|| {
+							let reconstructed = reconstruct(n, last_chunks.clone());
+							assert_eq!(reconstructed, pov);
+						});
+					},
+				);
+			}
+		}
+		group.finish();
+	}
+}
+
+fn criterion_config() -> Criterion {
+	Criterion::default()
+		.sample_size(10)
+		.warm_up_time(Duration::from_millis(200))
+		.measurement_time(Duration::from_secs(5))
+}
+
+criterion_group!(
+	name = simd;
+	config = criterion_config();
+	targets = construct, reconstruct,
+);
+criterion_main!(simd);
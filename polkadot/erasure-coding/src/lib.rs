@@ -23,10 +23,17 @@
 //! Each of n validators stores their piece of data. We assume `n = 3f + k`, `0 < k ≤ 3`.
 //! f is the maximum number of faulty validators in the system.
 //! The data is coded so any f+1 chunks can be used to reconstruct the full data.
+//!
+//! The [`simd`] module provides a faster, SIMD-accelerated code with the same properties, whose
+//! chunks are not compatible with the chunks of the functions of the crate root. The code to use
+//! for a session is selected by the node features of the session, see [`ErasureCode`].
 
 use parity_scale_codec::{Decode, Encode};
 use polkadot_node_primitives::{AvailableData, Proof};
-use polkadot_primitives::{BlakeTwo256, Hash as H256, HashT};
+use polkadot_primitives::{
+	vstaging::{node_features::FeatureIndex, NodeFeatures},
+	BlakeTwo256, Hash as H256, HashT,
+};
 use sp_core::Blake2Hasher;
 use sp_trie::{
 	trie_types::{TrieDBBuilder, TrieDBMutBuilderV0 as TrieDBMutBuilder},
@@ -36,6 +43,8 @@ use thiserror::Error;
 
 use novelpoly::{CodeParams, WrappedShard};
 
+pub mod simd;
+
 // we are limited to the field order of GF(2^16), which is 65536
 const MAX_VALIDATORS: usize = novelpoly::f2e16::FIELD_SIZE;
 
@@ -113,6 +122,62 @@ fn code_params(n_validators: usize) -> Result<CodeParams, Error> {
 	})
 }
 
+/// The erasure code used to split the `AvailableData` of a candidate into chunks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ErasureCode {
+	/// The code of the functions of the crate root.
+	#[default]
+	NovelPoly,
+	/// The SIMD-accelerated code of the [`simd`] module.
+	Simd,
+}
+
+impl ErasureCode {
+	/// Returns the code enabled by the node features of a session.
+	pub fn from_node_features(node_features: &NodeFeatures) -> Self {
+		let simd = node_features
+			.get(FeatureIndex::SimdErasureCoding as usize)
+			.map_or(false, |enabled| *enabled);
+
+		if simd {
+			Self::Simd
+		} else {
+			Self::NovelPoly
+		}
+	}
+
+	/// Obtain erasure-coded chunks for v1 `AvailableData`, one for each validator.
+	///
+	/// Works only up to 65536 validators, and `n_validators` must be non-zero.
+	pub fn obtain_chunks_v1(
+		self,
+		n_validators: usize,
+		data: &AvailableData,
+	) -> Result<Vec<Vec<u8>>, Error> {
+		match self {
+			Self::NovelPoly => obtain_chunks(n_validators, data),
+			Self::Simd => simd::obtain_chunks(n_validators, data),
+		}
+	}
+
+	/// Reconstruct the v1 available data from a set of chunks.
+	///
+	/// See [`reconstruct_v1`].
+	pub fn reconstruct_v1<'a, I: 'a>(
+		self,
+		n_validators: usize,
+		chunks: I,
+	) -> Result<AvailableData, Error>
+	where
+		I: IntoIterator<Item = (&'a [u8], usize)>,
+	{
+		match self {
+			Self::NovelPoly => reconstruct(n_validators, chunks),
+			Self::Simd => simd::reconstruct(n_validators, chunks),
+		}
+	}
+}
+
 /// Obtain erasure-coded chunks for v1 `AvailableData`, one for each validator.
 ///
 /// Works only up to 65536 validators, and `n_validators` must be non-zero.
@@ -416,4 +481,34 @@ mod tests {
 			generate_trie_and_generate_proofs(i);
 		}
 	}
+
+	#[test]
+	fn erasure_code_is_selected_by_node_features() {
+		assert_eq!(ErasureCode::from_node_features(&NodeFeatures::EMPTY), ErasureCode::NovelPoly);
+
+		let mut node_features = NodeFeatures::EMPTY;
+		node_features.resize(FeatureIndex::FirstUnassigned as usize, false);
+		assert_eq!(ErasureCode::from_node_features(&node_features), ErasureCode::NovelPoly);
+
+		node_features.set(FeatureIndex::SimdErasureCoding as usize, true);
+		assert_eq!(ErasureCode::from_node_features(&node_features), ErasureCode::Simd);
+	}
+
+	#[test]
+	fn erasure_codes_are_not_compatible() {
+		let pov = PoV { block_data: BlockData((0..255).collect()) };
+		let available_data = AvailableData { pov: pov.into(), validation_data: Default::default() };
+
+		for code in [ErasureCode::NovelPoly, ErasureCode::Simd] {
+			let chunks = code.obtain_chunks_v1(10, &available_data).unwrap();
+			let reconstructed =
+				code.reconstruct_v1(10, chunks.iter().enumerate().map(|(i, c)| (&c[..], i)));
+			assert_eq!(reconstructed, Ok(available_data.clone()));
+		}
+
+		assert_ne!(
+			branches(&ErasureCode::NovelPoly.obtain_chunks_v1(10, &available_data).unwrap()).root(),
+			branches(&ErasureCode::Simd.obtain_chunks_v1(10, &available_data).unwrap()).root(),
+		);
+	}
 }
@@ -0,0 +1,233 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! SIMD-accelerated erasure coding.
+//!
+//! The code is a systematic Reed-Solomon code over `GF(2^16)`: the first `k` chunks are the
+//! payload itself, split into chunks of equal length, and the remaining `n - k` chunks are the
+//! recovery chunks. Any `k` chunks are enough to reconstruct the payload, like with the code of
+//! the crate root.
+//!
+//! The SIMD instructions to use (AVX2, SSSE3 or Neon) are detected at runtime, falling back to
+//! a scalar implementation on CPUs that don't support any of them.
+//!
+//! The chunks are **not** compatible with the chunks of the crate root, so all validators need to
+//! switch to this code at once.
+
+use super::{recovery_threshold, Error, ShardInput};
+use parity_scale_codec::{Decode, Encode};
+use std::collections::HashMap;
+
+/// The length of the chunks is a multiple of this many bytes.
+const SHARD_LEN_ALIGNMENT: usize = 64;
+
+/// Returns the length of each chunk of a payload of `payload_len` bytes split into
+/// `original_count` chunks.
+///
+/// The length of the chunks must be a multiple of [`SHARD_LEN_ALIGNMENT`], as required by
+/// `reed-solomon-simd`.
+fn shard_len(payload_len: usize, original_count: usize) -> usize {
+	let len = (payload_len + original_count - 1) / original_count;
+	(len + SHARD_LEN_ALIGNMENT - 1) / SHARD_LEN_ALIGNMENT * SHARD_LEN_ALIGNMENT
+}
+
+/// Obtain erasure-coded chunks, one for each validator.
+///
+/// Works only up to 65536 validators, and `n_validators` must be non-zero.
+pub fn obtain_chunks<T: Encode>(n_validators: usize, data: &T) -> Result<Vec<Vec<u8>>, Error> {
+	let original_count = recovery_threshold(n_validators)?;
+	let recovery_count = n_validators - original_count;
+	let mut encoded = data.encode();
+
+	if encoded.is_empty() {
+		return Err(Error::BadPayload)
+	}
+
+	let shard_len = shard_len(encoded.len(), original_count);
+	encoded.resize(shard_len * original_count, 0);
+
+	let mut chunks: Vec<Vec<u8>> = encoded.chunks(shard_len).map(|c| c.to_vec()).collect();
+	let recovery = reed_solomon_simd::encode(original_count, recovery_count, &chunks).expect(
+		"Payload non-empty, shard sizes are uniform and aligned, and validator numbers checked; qed",
+	);
+	chunks.extend(recovery);
+
+	Ok(chunks)
+}
+
+/// Reconstruct decodable data from a set of chunks.
+///
+/// Provide an iterator containing chunk data and the corresponding index.
+/// The indices of the present chunks must be indicated. If too few chunks
+/// are provided, recovery is not possible.
+///
+/// Works only up to 65536 validators, and `n_validators` must be non-zero.
+pub fn reconstruct<'a, I: 'a, T: Decode>(n_validators: usize, chunks: I) -> Result<T, Error>
+where
+	I: IntoIterator<Item = (&'a [u8], usize)>,
+{
+	let original_count = recovery_threshold(n_validators)?;
+	let recovery_count = n_validators - original_count;
+	let mut original: Vec<Option<&[u8]>> = vec![None; original_count];
+	let mut recovery: Vec<Option<&[u8]>> = vec![None; recovery_count];
+	let mut shard_len = None;
+	for (chunk_data, chunk_idx) in chunks.into_iter().take(n_validators) {
+		if chunk_idx >= n_validators {
+			return Err(Error::ChunkIndexOutOfBounds { chunk_index: chunk_idx, n_validators })
+		}
+
+		let shard_len = shard_len.get_or_insert_with(|| chunk_data.len());
+
+		if *shard_len % SHARD_LEN_ALIGNMENT != 0 {
+			return Err(Error::UnevenLength)
+		}
+
+		if *shard_len != chunk_data.len() || *shard_len == 0 {
+			return Err(Error::NonUniformChunks)
+		}
+
+		match chunk_idx.checked_sub(original_count) {
+			None => original[chunk_idx] = Some(chunk_data),
+			Some(recovery_idx) => recovery[recovery_idx] = Some(chunk_data),
+		}
+	}
+
+	// Nothing to decode if all the chunks of the payload are present.
+	let restored = if original.iter().all(Option::is_some) {
+		HashMap::new()
+	} else {
+		let present = |chunks: &[Option<&'a [u8]>]| {
+			chunks
+				.iter()
+				.enumerate()
+				.filter_map(|(i, c)| Some((i, (*c)?)))
+				.collect::<Vec<_>>()
+		};
+		reed_solomon_simd::decode(
+			original_count,
+			recovery_count,
+			present(&original),
+			present(&recovery),
+		)
+		.map_err(|e| match e {
+			reed_solomon_simd::Error::NotEnoughShards { .. } => Error::NotEnoughChunks,
+			reed_solomon_simd::Error::DifferentShardSize { .. } => Error::NonUniformChunks,
+			reed_solomon_simd::Error::InvalidShardSize { .. } => Error::UnevenLength,
+			reed_solomon_simd::Error::UnsupportedShardCount { .. } => Error::TooManyValidators,
+			_ => Error::UnknownReconstruction,
+		})?
+	};
+
+	let shards = original
+		.iter()
+		.enumerate()
+		.map(|(i, c)| c.or_else(|| restored.get(&i).map(|c| &c[..])))
+		.collect::<Option<Vec<_>>>()
+		.ok_or(Error::NotEnoughChunks)?;
+	let mut input = ShardInput {
+		remaining_len: shard_len.unwrap_or_default() * original_count,
+		shards: shards.into_iter(),
+		cur_shard: None,
+	};
+
+	Decode::decode(&mut input).map_err(|_| Error::BadPayload)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_node_primitives::{AvailableData, BlockData, PoV};
+
+	fn available_data(len: usize) -> AvailableData {
+		let pov = PoV { block_data: BlockData((0..len).map(|i| i as u8).collect()) };
+		AvailableData { pov: pov.into(), validation_data: Default::default() }
+	}
+
+	#[test]
+	fn round_trip_works() {
+		let available_data = available_data(255);
+		let chunks = obtain_chunks(10, &available_data).unwrap();
+
+		assert_eq!(chunks.len(), 10);
+
+		// any 4 chunks should work.
+		for indices in [[1, 4, 6, 9], [0, 1, 2, 3], [6, 7, 8, 9], [0, 3, 5, 8]] {
+			let reconstructed: AvailableData =
+				reconstruct(10, indices.iter().map(|&i| (&*chunks[i], i))).unwrap();
+			assert_eq!(reconstructed, available_data);
+		}
+
+		let reconstructed: Result<AvailableData, _> =
+			reconstruct(10, [1, 4, 9].iter().map(|&i| (&*chunks[i], i)));
+		assert_eq!(reconstructed, Err(Error::NotEnoughChunks));
+	}
+
+	#[test]
+	fn round_trip_works_for_any_payload_length() {
+		for len in [0, 1, 63, 64, 65, 255, 256, 1000, 4095] {
+			let available_data = available_data(len);
+			let chunks = obtain_chunks(10, &available_data).unwrap();
+			assert!(chunks.iter().all(|c| c.len() % SHARD_LEN_ALIGNMENT == 0));
+
+			let reconstructed: AvailableData =
+				reconstruct(10, chunks.iter().enumerate().skip(6).map(|(i, c)| (&c[..], i)))
+					.unwrap();
+			assert_eq!(reconstructed, available_data);
+		}
+	}
+
+	#[test]
+	fn reconstruct_rejects_unaligned_chunks() {
+		let chunks = obtain_chunks(10, &available_data(255)).unwrap();
+		let reconstructed: Result<AvailableData, _> =
+			reconstruct(10, chunks.iter().enumerate().map(|(i, c)| (&c[1..], i)));
+		assert_eq!(reconstructed, Err(Error::UnevenLength));
+	}
+
+	#[test]
+	fn first_chunks_contain_the_payload() {
+		let available_data = available_data(1000);
+		let chunks = obtain_chunks(10, &available_data).unwrap();
+
+		let encoded = available_data.encode();
+		let payload = chunks[..recovery_threshold(10).unwrap()].concat();
+		assert_eq!(&payload[..encoded.len()], &encoded[..]);
+		assert!(payload[encoded.len()..].iter().all(|b| *b == 0));
+	}
+
+	#[test]
+	fn round_trip_works_for_many_validators() {
+		let available_data = available_data(100_000);
+		for n_validators in [2, 3, 4, 100, 1000, 10_000] {
+			let chunks = obtain_chunks(n_validators, &available_data).unwrap();
+			assert_eq!(chunks.len(), n_validators);
+
+			let threshold = recovery_threshold(n_validators).unwrap();
+			let reconstructed: AvailableData = reconstruct(
+				n_validators,
+				chunks.iter().enumerate().rev().take(threshold).map(|(i, c)| (&c[..], i)),
+			)
+			.unwrap();
+			assert_eq!(reconstructed, available_data);
+		}
+	}
+
+	#[test]
+	fn reconstruct_does_not_panic_on_low_validator_count() {
+		let reconstructed: Result<AvailableData, _> = reconstruct(1, [].iter().cloned());
+		assert_eq!(reconstructed, Err(Error::NotEnoughValidators));
+	}
+}
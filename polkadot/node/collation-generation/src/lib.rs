@@ -33,6 +33,7 @@
 
 use futures::{channel::oneshot, future::FutureExt, join, select};
 use parity_scale_codec::Encode;
+use polkadot_erasure_coding::ErasureCode;
 use polkadot_node_primitives::{
 	AvailableData, Collation, CollationGenerationConfig, CollationSecondedSignal, PoV,
 	SubmitCollationParams,
@@ -44,12 +45,13 @@ use polkadot_node_subsystem::{
 };
 use polkadot_node_subsystem_util::{
 	request_async_backing_params, request_availability_cores, request_persisted_validation_data,
-	request_validation_code, request_validation_code_hash, request_validators,
+	request_session_index_for_child, request_validation_code, request_validation_code_hash,
+	request_validators, runtime::request_node_features,
 };
 use polkadot_primitives::{
-	collator_signature_payload, CandidateCommitments, CandidateDescriptor, CandidateReceipt,
-	CollatorPair, CoreState, Hash, Id as ParaId, OccupiedCoreAssumption, PersistedValidationData,
-	ValidationCodeHash,
+	collator_signature_payload, vstaging::NodeFeatures, CandidateCommitments, CandidateDescriptor,
+	CandidateReceipt, CollatorPair, CoreState, Hash, Id as ParaId, OccupiedCoreAssumption,
+	PersistedValidationData, ValidationCodeHash,
 };
 use sp_core::crypto::Pair;
 use std::sync::Arc;
@@ -213,6 +215,7 @@ async fn handle_new_activations<Context>(
 		let availability_cores = availability_cores??;
 		let n_validators = validators??.len();
 		let async_backing_params = async_backing_params?.ok();
+		let erasure_code = request_erasure_code(relay_parent, ctx.sender()).await?;
 
 		for (core_idx, core) in availability_cores.into_iter().enumerate() {
 			let _availability_core_timer = metrics.time_new_activations_availability_core();
@@ -348,6 +351,7 @@ async fn handle_new_activations<Context>(
 							validation_data,
 							validation_code_hash,
 							n_validators,
+							erasure_code,
 						},
 						task_config.key.clone(),
 						&mut task_sender,
@@ -382,6 +386,7 @@ async fn handle_submit_collation<Context>(
 
 	let validators = request_validators(relay_parent, ctx.sender()).await.await??;
 	let n_validators = validators.len();
+	let erasure_code = request_erasure_code(relay_parent, ctx.sender()).await?;
 
 	// We need to swap the parent-head data, but all other fields here will be correct.
 	let mut validation_data = match request_persisted_validation_data(
@@ -414,6 +419,7 @@ async fn handle_submit_collation<Context>(
 		validation_data,
 		validation_code_hash,
 		n_validators,
+		erasure_code,
 	};
 
 	construct_and_distribute_receipt(
@@ -435,6 +441,7 @@ struct PreparedCollation {
 	validation_data: PersistedValidationData,
 	validation_code_hash: ValidationCodeHash,
 	n_validators: usize,
+	erasure_code: ErasureCode,
 }
 
 /// Takes a prepared collation, along with its context, and produces a candidate receipt
@@ -453,6 +460,7 @@ async fn construct_and_distribute_receipt(
 		validation_data,
 		validation_code_hash,
 		n_validators,
+		erasure_code,
 	} = collation;
 
 	let persisted_validation_data_hash = validation_data.hash();
@@ -493,7 +501,8 @@ async fn construct_and_distribute_receipt(
 		&validation_code_hash,
 	);
 
-	let erasure_root = match erasure_root(n_validators, validation_data, pov.clone()) {
+	let erasure_root = match erasure_root(n_validators, erasure_code, validation_data, pov.clone())
+	{
 		Ok(erasure_root) => erasure_root,
 		Err(err) => {
 			gum::error!(
@@ -577,14 +586,28 @@ async fn obtain_validation_code_hash_with_assumption(
 	}
 }
 
+/// Returns the erasure code enabled in the session of the children of `relay_parent`.
+async fn request_erasure_code(
+	relay_parent: Hash,
+	sender: &mut impl overseer::CollationGenerationSenderTrait,
+) -> crate::error::Result<ErasureCode> {
+	let session_index = request_session_index_for_child(relay_parent, sender).await.await??;
+	let node_features = request_node_features(relay_parent, session_index, sender)
+		.await?
+		.unwrap_or(NodeFeatures::EMPTY);
+
+	Ok(ErasureCode::from_node_features(&node_features))
+}
+
 fn erasure_root(
 	n_validators: usize,
+	erasure_code: ErasureCode,
 	persisted_validation: PersistedValidationData,
 	pov: PoV,
 ) -> crate::error::Result<Hash> {
 	let available_data =
 		AvailableData { validation_data: persisted_validation, pov: Arc::new(pov) };
 
-	let chunks = polkadot_erasure_coding::obtain_chunks_v1(n_validators, &available_data)?;
+	let chunks = erasure_code.obtain_chunks_v1(n_validators, &available_data)?;
 	Ok(polkadot_erasure_coding::branches(&chunks).root())
 }
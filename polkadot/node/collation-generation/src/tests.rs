@@ -29,7 +29,8 @@ use polkadot_node_subsystem::{
 use polkadot_node_subsystem_test_helpers::{subsystem_test_harness, TestSubsystemContextHandle};
 use polkadot_node_subsystem_util::TimeoutExt;
 use polkadot_primitives::{
-	CollatorPair, HeadData, Id as ParaId, PersistedValidationData, ScheduledCore, ValidationCode,
+	vstaging::node_features::FeatureIndex, CollatorPair, HeadData, Id as ParaId,
+	PersistedValidationData, ScheduledCore, ValidationCode,
 };
 use sp_keyring::sr25519::Keyring as Sr25519Keyring;
 use std::pin::Pin;
@@ -151,6 +152,12 @@ fn requests_availability_per_relay_parent() {
 				Some(AllMessages::RuntimeApi(RuntimeApiMessage::Request(_hash, RuntimeApiRequest::Validators(tx)))) => {
 					tx.send(Ok(vec![dummy_validator(); 3])).unwrap();
 				}
+				Some(AllMessages::RuntimeApi(RuntimeApiMessage::Request(_hash, RuntimeApiRequest::SessionIndexForChild(tx)))) => {
+					tx.send(Ok(1)).unwrap();
+				}
+				Some(AllMessages::RuntimeApi(RuntimeApiMessage::Request(_hash, RuntimeApiRequest::NodeFeatures(_session_index, tx)))) => {
+					tx.send(Ok(NodeFeatures::EMPTY)).unwrap();
+				}
 				Some(AllMessages::RuntimeApi(RuntimeApiMessage::Request(
 					_hash,
 					RuntimeApiRequest::AsyncBackingParams(
@@ -233,6 +240,18 @@ fn requests_validation_data_for_scheduled_matches() {
 				))) => {
 					tx.send(Ok(vec![dummy_validator(); 3])).unwrap();
 				},
+				Some(AllMessages::RuntimeApi(RuntimeApiMessage::Request(
+					_hash,
+					RuntimeApiRequest::SessionIndexForChild(tx),
+				))) => {
+					tx.send(Ok(1)).unwrap();
+				},
+				Some(AllMessages::RuntimeApi(RuntimeApiMessage::Request(
+					_hash,
+					RuntimeApiRequest::NodeFeatures(_session_index, tx),
+				))) => {
+					tx.send(Ok(NodeFeatures::EMPTY)).unwrap();
+				},
 				Some(AllMessages::RuntimeApi(RuntimeApiMessage::Request(
 					_hash,
 					RuntimeApiRequest::AsyncBackingParams(tx),
@@ -330,6 +349,18 @@ fn sends_distribute_collation_message() {
 				))) => {
 					tx.send(Ok(Some(ValidationCode(vec![1, 2, 3]).hash()))).unwrap();
 				},
+				Some(AllMessages::RuntimeApi(RuntimeApiMessage::Request(
+					_hash,
+					RuntimeApiRequest::SessionIndexForChild(tx),
+				))) => {
+					tx.send(Ok(1)).unwrap();
+				},
+				Some(AllMessages::RuntimeApi(RuntimeApiMessage::Request(
+					_hash,
+					RuntimeApiRequest::NodeFeatures(_session_index, tx),
+				))) => {
+					tx.send(Ok(NodeFeatures::EMPTY)).unwrap();
+				},
 				Some(AllMessages::RuntimeApi(RuntimeApiMessage::Request(
 					_hash,
 					RuntimeApiRequest::AsyncBackingParams(tx),
@@ -492,6 +523,18 @@ fn fallback_when_no_validation_code_hash_api() {
 				))) => {
 					tx.send(Ok(Some(ValidationCode(vec![1, 2, 3])))).unwrap();
 				},
+				Some(AllMessages::RuntimeApi(RuntimeApiMessage::Request(
+					_hash,
+					RuntimeApiRequest::SessionIndexForChild(tx),
+				))) => {
+					tx.send(Ok(1)).unwrap();
+				},
+				Some(AllMessages::RuntimeApi(RuntimeApiMessage::Request(
+					_hash,
+					RuntimeApiRequest::NodeFeatures(_session_index, tx),
+				))) => {
+					tx.send(Ok(NodeFeatures::EMPTY)).unwrap();
+				},
 				Some(AllMessages::RuntimeApi(RuntimeApiMessage::Request(
 					_hash,
 					RuntimeApiRequest::AsyncBackingParams(tx),
@@ -561,6 +604,22 @@ fn submit_collation_is_no_op_before_initialization() {
 
 #[test]
 fn submit_collation_leads_to_distribution() {
+	submit_collation_leads_to_distribution_with(NodeFeatures::EMPTY, ErasureCode::NovelPoly);
+}
+
+#[test]
+fn submit_collation_uses_erasure_code_of_session() {
+	let mut node_features = NodeFeatures::EMPTY;
+	node_features.resize(FeatureIndex::FirstUnassigned as usize, false);
+	node_features.set(FeatureIndex::SimdErasureCoding as usize, true);
+
+	submit_collation_leads_to_distribution_with(node_features, ErasureCode::Simd);
+}
+
+fn submit_collation_leads_to_distribution_with(
+	node_features: NodeFeatures,
+	erasure_code: ErasureCode,
+) {
 	let relay_parent = Hash::repeat_byte(0);
 	let validation_code_hash = ValidationCodeHash::from(Hash::repeat_byte(42));
 	let parent_head = HeadData::from(vec![1, 2, 3]);
@@ -603,6 +662,22 @@ fn submit_collation_leads_to_distribution() {
 			}
 		);
 
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::RuntimeApi(RuntimeApiMessage::Request(rp, RuntimeApiRequest::SessionIndexForChild(tx))) => {
+				assert_eq!(rp, relay_parent);
+				let _ = tx.send(Ok(1));
+			}
+		);
+
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::RuntimeApi(RuntimeApiMessage::Request(rp, RuntimeApiRequest::NodeFeatures(1, tx))) => {
+				assert_eq!(rp, relay_parent);
+				let _ = tx.send(Ok(node_features));
+			}
+		);
+
 		assert_matches!(
 			overseer_recv(&mut virtual_overseer).await,
 			AllMessages::RuntimeApi(RuntimeApiMessage::Request(rp, RuntimeApiRequest::PersistedValidationData(id, a, tx))) => {
@@ -628,6 +703,11 @@ fn submit_collation_leads_to_distribution() {
 				assert_eq!(ccr.descriptor().persisted_validation_data_hash, expected_pvd.hash());
 				assert_eq!(ccr.descriptor().para_head, dummy_head_data().hash());
 				assert_eq!(ccr.descriptor().validation_code_hash, validation_code_hash);
+
+				let pov = test_collation().proof_of_validity.into_compressed();
+				let expected_erasure_root =
+					erasure_root(3, erasure_code, expected_pvd.clone(), pov).unwrap();
+				assert_eq!(ccr.descriptor().erasure_root, expected_erasure_root);
 			}
 		);
 
@@ -39,6 +39,7 @@ use polkadot_node_subsystem_util::database::{DBTransaction, Database};
 use sp_consensus::SyncOracle;
 
 use bitvec::{order::Lsb0 as BitOrderLsb0, vec::BitVec};
+use erasure::ErasureCode;
 use polkadot_node_jaeger as jaeger;
 use polkadot_node_primitives::{AvailableData, ErasureChunk};
 use polkadot_node_subsystem::{
@@ -1189,6 +1190,7 @@ fn process_message(
 		AvailabilityStoreMessage::StoreAvailableData {
 			candidate_hash,
 			n_validators,
+			node_features,
 			available_data,
 			expected_erasure_root,
 			tx,
@@ -1201,6 +1203,7 @@ fn process_message(
 				&subsystem,
 				candidate_hash,
 				n_validators as _,
+				ErasureCode::from_node_features(&node_features),
 				available_data,
 				expected_erasure_root,
 			);
@@ -1269,6 +1272,7 @@ fn store_available_data(
 	subsystem: &AvailabilityStoreSubsystem,
 	candidate_hash: CandidateHash,
 	n_validators: usize,
+	erasure_code: ErasureCode,
 	available_data: AvailableData,
 	expected_erasure_root: Hash,
 ) -> Result<(), Error> {
@@ -1303,7 +1307,7 @@ fn store_available_data(
 
 	// Important note: This check below is critical for consensus and the `backing` subsystem relies
 	// on it to ensure candidate validity.
-	let chunks = erasure::obtain_chunks_v1(n_validators, &available_data)?;
+	let chunks = erasure_code.obtain_chunks_v1(n_validators, &available_data)?;
 	let branches = erasure::branches(chunks.as_ref());
 
 	if branches.root() != expected_erasure_root {
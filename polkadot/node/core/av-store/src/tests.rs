@@ -31,6 +31,7 @@ use polkadot_node_subsystem::{
 use polkadot_node_subsystem_test_helpers as test_helpers;
 use polkadot_node_subsystem_util::{database::Database, TimeoutExt};
 use polkadot_primitives::{
+	vstaging::{node_features::FeatureIndex, NodeFeatures},
 	CandidateHash, CandidateReceipt, CoreIndex, GroupIndex, HeadData, Header,
 	PersistedValidationData, ValidatorId,
 };
@@ -430,6 +431,7 @@ fn store_available_data_erasure_mismatch() {
 		let block_msg = AvailabilityStoreMessage::StoreAvailableData {
 			candidate_hash,
 			n_validators,
+			node_features: NodeFeatures::EMPTY,
 			available_data: available_data.clone(),
 			tx,
 			// A dummy erasure root should lead to failure.
@@ -472,6 +474,7 @@ fn store_block_works() {
 		let block_msg = AvailabilityStoreMessage::StoreAvailableData {
 			candidate_hash,
 			n_validators,
+			node_features: NodeFeatures::EMPTY,
 			available_data: available_data.clone(),
 			tx,
 			expected_erasure_root: branches.root(),
@@ -499,6 +502,64 @@ fn store_block_works() {
 	});
 }
 
+#[test]
+fn store_block_uses_erasure_code_of_session() {
+	let store = test_store();
+	let test_state = TestState::default();
+	test_harness(test_state.clone(), store.clone(), |mut virtual_overseer| async move {
+		let candidate_hash = CandidateHash(Hash::repeat_byte(1));
+		let n_validators = 10;
+
+		let pov = PoV { block_data: BlockData(vec![4, 5, 6]) };
+
+		let available_data = AvailableData {
+			pov: Arc::new(pov),
+			validation_data: test_state.persisted_validation_data.clone(),
+		};
+
+		let mut node_features = NodeFeatures::EMPTY;
+		node_features.resize(FeatureIndex::FirstUnassigned as usize, false);
+		node_features.set(FeatureIndex::SimdErasureCoding as usize, true);
+
+		let erasure_root = |erasure_code: ErasureCode| {
+			let chunks = erasure_code.obtain_chunks_v1(10, &available_data).unwrap();
+			erasure::branches(chunks.as_ref()).root()
+		};
+
+		// The root of the chunks of the default code doesn't match.
+		let (tx, rx) = oneshot::channel();
+		let block_msg = AvailabilityStoreMessage::StoreAvailableData {
+			candidate_hash,
+			n_validators,
+			node_features: node_features.clone(),
+			available_data: available_data.clone(),
+			tx,
+			expected_erasure_root: erasure_root(ErasureCode::NovelPoly),
+		};
+
+		virtual_overseer.send(FromOrchestra::Communication { msg: block_msg }).await;
+		assert_eq!(rx.await.unwrap(), Err(StoreAvailableDataError::InvalidErasureRoot));
+
+		let (tx, rx) = oneshot::channel();
+		let block_msg = AvailabilityStoreMessage::StoreAvailableData {
+			candidate_hash,
+			n_validators,
+			node_features,
+			available_data: available_data.clone(),
+			tx,
+			expected_erasure_root: erasure_root(ErasureCode::Simd),
+		};
+
+		virtual_overseer.send(FromOrchestra::Communication { msg: block_msg }).await;
+		assert_eq!(rx.await.unwrap(), Ok(()));
+
+		let pov = query_available_data(&mut virtual_overseer, candidate_hash).await.unwrap();
+		assert_eq!(pov, available_data);
+
+		virtual_overseer
+	});
+}
+
 #[test]
 fn store_pov_and_query_chunk_works() {
 	let store = test_store();
@@ -523,6 +584,7 @@ fn store_pov_and_query_chunk_works() {
 		let block_msg = AvailabilityStoreMessage::StoreAvailableData {
 			candidate_hash,
 			n_validators,
+			node_features: NodeFeatures::EMPTY,
 			available_data,
 			tx,
 			expected_erasure_root: branches.root(),
@@ -573,6 +635,7 @@ fn query_all_chunks_works() {
 			let block_msg = AvailabilityStoreMessage::StoreAvailableData {
 				candidate_hash: candidate_hash_1,
 				n_validators,
+				node_features: NodeFeatures::EMPTY,
 				available_data,
 				tx,
 				expected_erasure_root: branches.root(),
@@ -665,6 +728,7 @@ fn stored_but_not_included_data_is_pruned() {
 		let block_msg = AvailabilityStoreMessage::StoreAvailableData {
 			candidate_hash,
 			n_validators,
+			node_features: NodeFeatures::EMPTY,
 			available_data: available_data.clone(),
 			tx,
 			expected_erasure_root: branches.root(),
@@ -721,6 +785,7 @@ fn stored_data_kept_until_finalized() {
 		let block_msg = AvailabilityStoreMessage::StoreAvailableData {
 			candidate_hash,
 			n_validators,
+			node_features: NodeFeatures::EMPTY,
 			available_data: available_data.clone(),
 			tx,
 			expected_erasure_root: branches.root(),
@@ -996,6 +1061,7 @@ fn forkfullness_works() {
 		let msg = AvailabilityStoreMessage::StoreAvailableData {
 			candidate_hash: candidate_1_hash,
 			n_validators,
+			node_features: NodeFeatures::EMPTY,
 			available_data: available_data_1.clone(),
 			tx,
 			expected_erasure_root: branches.root(),
@@ -1012,6 +1078,7 @@ fn forkfullness_works() {
 		let msg = AvailabilityStoreMessage::StoreAvailableData {
 			candidate_hash: candidate_2_hash,
 			n_validators,
+			node_features: NodeFeatures::EMPTY,
 			available_data: available_data_2.clone(),
 			tx,
 			expected_erasure_root: branches.root(),
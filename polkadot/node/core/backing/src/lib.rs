@@ -99,12 +99,13 @@ use polkadot_node_subsystem_util::{
 	executor_params_at_relay_parent, request_from_runtime, request_session_index_for_child,
 	request_validator_groups, request_validators,
 	runtime::{
-		self, prospective_parachains_mode, request_min_backing_votes, ProspectiveParachainsMode,
+		self, prospective_parachains_mode, request_min_backing_votes, request_node_features,
+		ProspectiveParachainsMode,
 	},
 	Validator,
 };
 use polkadot_primitives::{
-	BackedCandidate, CandidateCommitments, CandidateHash, CandidateReceipt,
+	vstaging::NodeFeatures, BackedCandidate, CandidateCommitments, CandidateHash, CandidateReceipt,
 	CommittedCandidateReceipt, CoreIndex, CoreState, ExecutorParams, Hash, Id as ParaId,
 	PersistedValidationData, PvfExecKind, SigningContext, ValidationCode, ValidatorId,
 	ValidatorIndex, ValidatorSignature, ValidityAttestation,
@@ -223,6 +224,8 @@ struct PerRelayParentState {
 	fallbacks: HashMap<CandidateHash, AttestingData>,
 	/// The minimum backing votes threshold.
 	minimum_backing_votes: u32,
+	/// The node features of the session.
+	node_features: NodeFeatures,
 }
 
 struct PerCandidateState {
@@ -476,6 +479,7 @@ fn table_attested_to_backed(
 async fn store_available_data(
 	sender: &mut impl overseer::CandidateBackingSenderTrait,
 	n_validators: u32,
+	node_features: NodeFeatures,
 	candidate_hash: CandidateHash,
 	available_data: AvailableData,
 	expected_erasure_root: Hash,
@@ -489,6 +493,7 @@ async fn store_available_data(
 		.send_message(AvailabilityStoreMessage::StoreAvailableData {
 			candidate_hash,
 			n_validators,
+			node_features,
 			available_data,
 			expected_erasure_root,
 			tx,
@@ -510,6 +515,7 @@ async fn store_available_data(
 async fn make_pov_available(
 	sender: &mut impl overseer::CandidateBackingSenderTrait,
 	n_validators: usize,
+	node_features: NodeFeatures,
 	pov: Arc<PoV>,
 	candidate_hash: CandidateHash,
 	validation_data: PersistedValidationData,
@@ -518,6 +524,7 @@ async fn make_pov_available(
 	store_available_data(
 		sender,
 		n_validators as u32,
+		node_features,
 		candidate_hash,
 		AvailableData { pov, validation_data },
 		expected_erasure_root,
@@ -594,6 +601,7 @@ struct BackgroundValidationParams<S: overseer::CandidateBackingSenderTrait, F> {
 	persisted_validation_data: PersistedValidationData,
 	pov: PoVData,
 	n_validators: usize,
+	node_features: NodeFeatures,
 	make_command: F,
 }
 
@@ -611,6 +619,7 @@ async fn validate_and_make_available(
 		persisted_validation_data,
 		pov,
 		n_validators,
+		node_features,
 		make_command,
 	} = params;
 
@@ -688,6 +697,7 @@ async fn validate_and_make_available(
 			let erasure_valid = make_pov_available(
 				&mut sender,
 				n_validators,
+				node_features,
 				pov.clone(),
 				candidate.hash(),
 				validation_data.clone(),
@@ -1009,6 +1019,9 @@ async fn construct_per_relay_parent_state<Context>(
 	let cores = try_runtime_api!(cores);
 	let minimum_backing_votes =
 		try_runtime_api!(request_min_backing_votes(parent, session_index, ctx.sender()).await);
+	let node_features =
+		try_runtime_api!(request_node_features(parent, session_index, ctx.sender()).await)
+			.unwrap_or(NodeFeatures::EMPTY);
 
 	let signing_context = SigningContext { parent_hash: parent, session_index };
 	let validator =
@@ -1073,6 +1086,7 @@ async fn construct_per_relay_parent_state<Context>(
 		awaiting_validation: HashSet::new(),
 		fallbacks: HashMap::new(),
 		minimum_backing_votes,
+		node_features,
 	}))
 }
 
@@ -1756,6 +1770,7 @@ async fn kick_off_validation_work<Context>(
 			persisted_validation_data,
 			pov,
 			n_validators: rp_state.table_context.validators.len(),
+			node_features: rp_state.node_features.clone(),
 			make_command: ValidatedCandidateCommand::Attest,
 		},
 	)
@@ -1898,6 +1913,7 @@ async fn validate_and_second<Context>(
 			persisted_validation_data,
 			pov: PoVData::Ready(pov),
 			n_validators: rp_state.table_context.validators.len(),
+			node_features: rp_state.node_features.clone(),
 			make_command: ValidatedCandidateCommand::Second,
 		},
 	)
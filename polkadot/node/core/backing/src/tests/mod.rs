@@ -293,6 +293,17 @@ async fn test_startup(virtual_overseer: &mut VirtualOverseer, test_state: &TestS
 			tx.send(Ok(test_state.minimum_backing_votes)).unwrap();
 		}
 	);
+
+	// Check that subsystem job issues a request for the node features.
+	assert_matches!(
+		virtual_overseer.recv().await,
+		AllMessages::RuntimeApi(RuntimeApiMessage::Request(
+			parent,
+			RuntimeApiRequest::NodeFeatures(session_index, tx),
+		)) if parent == test_state.relay_parent && session_index == test_state.signing_context.session_index => {
+			tx.send(Ok(NodeFeatures::EMPTY)).unwrap();
+		}
+	);
 }
 
 async fn assert_validation_requests(
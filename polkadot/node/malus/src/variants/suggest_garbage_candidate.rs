@@ -34,9 +34,11 @@ use polkadot_cli::{
 use polkadot_node_core_candidate_validation::find_validation_data;
 use polkadot_node_primitives::{AvailableData, BlockData, PoV};
 use polkadot_node_subsystem_types::DefaultSubsystemClient;
-use polkadot_primitives::{CandidateDescriptor, CandidateReceipt};
+use polkadot_primitives::{vstaging::NodeFeatures, CandidateDescriptor, CandidateReceipt};
 
-use polkadot_node_subsystem_util::request_validators;
+use polkadot_node_subsystem_util::{
+	request_session_index_for_child, request_validators, runtime::request_node_features,
+};
 use sp_core::traits::SpawnNamed;
 
 use rand::distributions::{Bernoulli, Distribution};
@@ -125,6 +127,19 @@ where
 								.unwrap()
 								.len();
 							gum::trace!(target: MALUS, "Validators {}", n_validators);
+							let session_index =
+								request_session_index_for_child(relay_parent, &mut new_sender)
+									.await
+									.await
+									.unwrap()
+									.unwrap();
+							let node_features =
+								request_node_features(relay_parent, session_index, &mut new_sender)
+									.await
+									.unwrap()
+									.unwrap_or(NodeFeatures::EMPTY);
+							let erasure_code =
+								erasure::ErasureCode::from_node_features(&node_features);
 							match find_validation_data(&mut new_sender, &_candidate.descriptor())
 								.await
 							{
@@ -134,6 +149,7 @@ where
 											validation_data,
 											validation_code,
 											n_validators,
+											erasure_code,
 										)))
 										.expect("channel is still open");
 								},
@@ -144,7 +160,7 @@ where
 						}),
 					);
 
-					let (validation_data, validation_code, n_validators) =
+					let (validation_data, validation_code, n_validators, erasure_code) =
 						receiver.recv().unwrap()?;
 
 					let validation_data_hash = validation_data.hash();
@@ -169,11 +185,9 @@ where
 
 					let pov_hash = pov.hash();
 					let erasure_root = {
-						let chunks = erasure::obtain_chunks_v1(
-							n_validators as usize,
-							&malicious_available_data,
-						)
-						.unwrap();
+						let chunks = erasure_code
+							.obtain_chunks_v1(n_validators as usize, &malicious_available_data)
+							.unwrap();
 
 						let branches = erasure::branches(chunks.as_ref());
 						branches.root()
@@ -39,7 +39,7 @@ use task::{FetchChunks, FetchChunksParams, FetchFull, FetchFullParams};
 
 use fatality::Nested;
 use polkadot_erasure_coding::{
-	branch_hash, branches, obtain_chunks_v1, recovery_threshold, Error as ErasureEncodingError,
+	branch_hash, branches, recovery_threshold, ErasureCode, Error as ErasureEncodingError,
 };
 use task::{RecoveryParams, RecoveryStrategy, RecoveryTask};
 
@@ -55,10 +55,10 @@ use polkadot_node_subsystem::{
 	overseer, ActiveLeavesUpdate, FromOrchestra, OverseerSignal, SpawnedSubsystem,
 	SubsystemContext, SubsystemError, SubsystemResult,
 };
-use polkadot_node_subsystem_util::request_session_info;
+use polkadot_node_subsystem_util::{request_session_info, runtime::request_node_features};
 use polkadot_primitives::{
-	BlakeTwo256, BlockNumber, CandidateHash, CandidateReceipt, GroupIndex, Hash, HashT,
-	SessionIndex, SessionInfo, ValidatorIndex,
+	vstaging::NodeFeatures, BlakeTwo256, BlockNumber, CandidateHash, CandidateReceipt, GroupIndex,
+	Hash, HashT, SessionIndex, SessionInfo, ValidatorIndex,
 };
 
 mod error;
@@ -120,15 +120,16 @@ pub enum PostRecoveryCheck {
 
 /// Expensive erasure coding computations that we want to run on a blocking thread.
 pub enum ErasureTask {
-	/// Reconstructs `AvailableData` from chunks given `n_validators`.
+	/// Reconstructs `AvailableData` from chunks given `n_validators` and the erasure code.
 	Reconstruct(
 		usize,
+		ErasureCode,
 		HashMap<ValidatorIndex, ErasureChunk>,
 		oneshot::Sender<Result<AvailableData, ErasureEncodingError>>,
 	),
 	/// Re-encode `AvailableData` into erasure chunks in order to verify the provided root hash of
 	/// the Merkle tree.
-	Reencode(usize, Hash, AvailableData, oneshot::Sender<Option<AvailableData>>),
+	Reencode(usize, ErasureCode, Hash, AvailableData, oneshot::Sender<Option<AvailableData>>),
 }
 
 const fn is_unavailable(
@@ -186,13 +187,14 @@ fn is_chunk_valid(params: &RecoveryParams, chunk: &ErasureChunk) -> bool {
 /// same for anyone anyways.
 fn reconstructed_data_matches_root(
 	n_validators: usize,
+	erasure_code: ErasureCode,
 	expected_root: &Hash,
 	data: &AvailableData,
 	metrics: &Metrics,
 ) -> bool {
 	let _timer = metrics.time_reencode_chunks();
 
-	let chunks = match obtain_chunks_v1(n_validators, data) {
+	let chunks = match erasure_code.obtain_chunks_v1(n_validators, data) {
 		Ok(chunks) => chunks,
 		Err(e) => {
 			gum::debug!(
@@ -350,6 +352,7 @@ async fn launch_recovery_task<Context>(
 	state: &mut State,
 	ctx: &mut Context,
 	session_info: SessionInfo,
+	erasure_code: ErasureCode,
 	receipt: CandidateReceipt,
 	response_sender: oneshot::Sender<Result<AvailableData, RecoveryError>>,
 	metrics: &Metrics,
@@ -362,6 +365,7 @@ async fn launch_recovery_task<Context>(
 		validator_authority_keys: session_info.discovery_keys.clone(),
 		n_validators: session_info.validators.len(),
 		threshold: recovery_threshold(session_info.validators.len())?,
+		erasure_code,
 		candidate_hash,
 		erasure_root: receipt.descriptor.erasure_root,
 		metrics: metrics.clone(),
@@ -440,6 +444,12 @@ async fn handle_recover<Context>(
 	let _span = span.child("session-info-ctx-received");
 	match session_info {
 		Some(session_info) => {
+			let node_features =
+				request_node_features(state.live_block.1, session_index, ctx.sender())
+					.await?
+					.unwrap_or(NodeFeatures::EMPTY);
+			let erasure_code = ErasureCode::from_node_features(&node_features);
+
 			let mut recovery_strategies: VecDeque<
 				Box<dyn RecoveryStrategy<<Context as SubsystemContext>::Sender>>,
 			> = VecDeque::with_capacity(2);
@@ -496,6 +506,7 @@ async fn handle_recover<Context>(
 				state,
 				ctx,
 				session_info,
+				erasure_code,
 				receipt,
 				response_sender,
 				metrics,
@@ -822,17 +833,24 @@ async fn erasure_task_thread(
 ) {
 	loop {
 		match ingress.next().await {
-			Some(ErasureTask::Reconstruct(n_validators, chunks, sender)) => {
-				let _ = sender.send(polkadot_erasure_coding::reconstruct_v1(
+			Some(ErasureTask::Reconstruct(n_validators, erasure_code, chunks, sender)) => {
+				let _ = sender.send(erasure_code.reconstruct_v1(
 					n_validators,
 					chunks.values().map(|c| (&c.chunk[..], c.index.0 as usize)),
 				));
 			},
-			Some(ErasureTask::Reencode(n_validators, root, available_data, sender)) => {
+			Some(ErasureTask::Reencode(
+				n_validators,
+				erasure_code,
+				root,
+				available_data,
+				sender,
+			)) => {
 				let metrics = metrics.clone();
 
 				let maybe_data = if reconstructed_data_matches_root(
 					n_validators,
+					erasure_code,
 					&root,
 					&available_data,
 					&metrics,
@@ -23,6 +23,7 @@ use crate::{
 	PostRecoveryCheck, LOG_TARGET,
 };
 use futures::{channel::oneshot, SinkExt};
+use polkadot_erasure_coding::ErasureCode;
 #[cfg(not(test))]
 use polkadot_node_network_protocol::request_response::CHUNK_REQUEST_TIMEOUT;
 use polkadot_node_network_protocol::request_response::{
@@ -84,6 +85,9 @@ pub struct RecoveryParams {
 	/// The number of chunks needed.
 	pub threshold: usize,
 
+	/// The erasure code of the session.
+	pub erasure_code: ErasureCode,
+
 	/// A hash of the relevant candidate.
 	pub candidate_hash: CandidateHash,
 
@@ -514,6 +518,7 @@ impl<Sender: overseer::AvailabilityRecoverySenderTrait> RecoveryStrategy<Sender>
 								.erasure_task_tx
 								.send(ErasureTask::Reencode(
 									common_params.n_validators,
+									common_params.erasure_code,
 									common_params.erasure_root,
 									data,
 									reencode_tx,
@@ -649,6 +654,7 @@ impl FetchChunks {
 		self.erasure_task_tx
 			.send(ErasureTask::Reconstruct(
 				common_params.n_validators,
+				common_params.erasure_code,
 				// Safe to leave an empty vec in place, as we're stopping the recovery process if
 				// this reconstruct fails.
 				std::mem::take(&mut state.received_chunks),
@@ -669,6 +675,7 @@ impl FetchChunks {
 						self.erasure_task_tx
 							.send(ErasureTask::Reencode(
 								common_params.n_validators,
+								common_params.erasure_code,
 								common_params.erasure_root,
 								data,
 								reencode_tx,
@@ -29,7 +29,7 @@ use super::*;
 
 use sc_network::{config::RequestResponseConfig, IfDisconnected, OutboundFailure, RequestFailure};
 
-use polkadot_erasure_coding::{branches, obtain_chunks_v1 as obtain_chunks};
+use polkadot_erasure_coding::branches;
 use polkadot_node_primitives::{BlockData, PoV, Proof};
 use polkadot_node_subsystem::messages::{
 	AllMessages, NetworkBridgeTxMessage, RuntimeApiMessage, RuntimeApiRequest,
@@ -39,7 +39,8 @@ use polkadot_node_subsystem_test_helpers::{
 };
 use polkadot_node_subsystem_util::TimeoutExt;
 use polkadot_primitives::{
-	AuthorityDiscoveryId, Hash, HeadData, IndexedVec, PersistedValidationData, ValidatorId,
+	vstaging::node_features::FeatureIndex, AuthorityDiscoveryId, Hash, HeadData, IndexedVec,
+	PersistedValidationData, ValidatorId,
 };
 use polkadot_primitives_test_helpers::{dummy_candidate_receipt, dummy_hash};
 
@@ -230,6 +231,7 @@ struct TestState {
 	current: Hash,
 	candidate: CandidateReceipt,
 	session_index: SessionIndex,
+	node_features: NodeFeatures,
 
 	persisted_validation_data: PersistedValidationData,
 
@@ -278,6 +280,21 @@ impl TestState {
 				}))).unwrap();
 			}
 		);
+		assert_matches!(
+			overseer_recv(virtual_overseer).await,
+			AllMessages::RuntimeApi(RuntimeApiMessage::Request(
+				relay_parent,
+				RuntimeApiRequest::NodeFeatures(
+					session_index,
+					tx,
+				)
+			)) => {
+				assert_eq!(relay_parent, self.current);
+				assert_eq!(session_index, self.session_index);
+
+				tx.send(Ok(self.node_features.clone())).unwrap();
+			}
+		);
 	}
 
 	async fn respond_to_available_data_query(
@@ -457,11 +474,13 @@ fn validator_authority_id(val_ids: &[Sr25519Keyring]) -> Vec<AuthorityDiscoveryI
 }
 
 fn derive_erasure_chunks_with_proofs_and_root(
+	erasure_code: ErasureCode,
 	n_validators: usize,
 	available_data: &AvailableData,
 	alter_chunk: impl Fn(usize, &mut Vec<u8>),
 ) -> (Vec<ErasureChunk>, Hash) {
-	let mut chunks: Vec<Vec<u8>> = obtain_chunks(n_validators, available_data).unwrap();
+	let mut chunks: Vec<Vec<u8>> =
+		erasure_code.obtain_chunks_v1(n_validators, available_data).unwrap();
 
 	for (i, chunk) in chunks.iter_mut().enumerate() {
 		alter_chunk(i, chunk)
@@ -485,6 +504,12 @@ fn derive_erasure_chunks_with_proofs_and_root(
 
 impl Default for TestState {
 	fn default() -> Self {
+		Self::with_node_features(NodeFeatures::EMPTY)
+	}
+}
+
+impl TestState {
+	fn with_node_features(node_features: NodeFeatures) -> Self {
 		let validators = vec![
 			Sr25519Keyring::Ferdie, // <- this node, role: validator
 			Sr25519Keyring::Alice,
@@ -517,6 +542,7 @@ impl Default for TestState {
 		};
 
 		let (chunks, erasure_root) = derive_erasure_chunks_with_proofs_and_root(
+			ErasureCode::from_node_features(&node_features),
 			validators.len(),
 			&available_data,
 			|_, _| {},
@@ -548,6 +574,7 @@ impl Default for TestState {
 			current,
 			candidate,
 			session_index,
+			node_features,
 			persisted_validation_data,
 			available_data,
 			chunks,
@@ -836,6 +863,58 @@ fn wrong_chunk_index_leads_to_recovery_error() {
 	});
 }
 
+#[test]
+fn availability_is_recovered_with_erasure_code_of_session() {
+	let mut node_features = NodeFeatures::EMPTY;
+	node_features.resize(FeatureIndex::FirstUnassigned as usize, false);
+	node_features.set(FeatureIndex::SimdErasureCoding as usize, true);
+	let test_state = TestState::with_node_features(node_features);
+
+	test_harness_chunks_only(|mut virtual_overseer, req_cfg| async move {
+		overseer_signal(
+			&mut virtual_overseer,
+			OverseerSignal::ActiveLeaves(ActiveLeavesUpdate::start_work(new_leaf(
+				test_state.current,
+				1,
+			))),
+		)
+		.await;
+
+		let (tx, rx) = oneshot::channel();
+
+		overseer_send(
+			&mut virtual_overseer,
+			AvailabilityRecoveryMessage::RecoverAvailableData(
+				test_state.candidate.clone(),
+				test_state.session_index,
+				None,
+				tx,
+			),
+		)
+		.await;
+
+		test_state.test_runtime_api(&mut virtual_overseer).await;
+
+		let candidate_hash = test_state.candidate.hash();
+
+		test_state.respond_to_available_data_query(&mut virtual_overseer, false).await;
+		test_state.respond_to_query_all_request(&mut virtual_overseer, |_| false).await;
+
+		test_state
+			.test_chunk_requests(
+				candidate_hash,
+				&mut virtual_overseer,
+				test_state.threshold(),
+				|_| Has::Yes,
+			)
+			.await;
+
+		// The chunks of the SIMD code are reconstructed and re-encoded with the SIMD code.
+		assert_eq!(rx.await.unwrap().unwrap(), test_state.available_data);
+		(virtual_overseer, req_cfg)
+	});
+}
+
 #[test]
 fn invalid_erasure_coding_leads_to_invalid_error() {
 	let mut test_state = TestState::default();
@@ -844,6 +923,7 @@ fn invalid_erasure_coding_leads_to_invalid_error() {
 		let pov = PoV { block_data: BlockData(vec![69; 64]) };
 
 		let (bad_chunks, bad_erasure_root) = derive_erasure_chunks_with_proofs_and_root(
+			ErasureCode::NovelPoly,
 			test_state.chunks.len(),
 			&AvailableData {
 				validation_data: test_state.persisted_validation_data.clone(),
@@ -230,6 +230,10 @@ pub enum Error {
 	#[error("Creating a custom database is required for validators")]
 	DatabasePathRequired,
 
+	#[cfg(feature = "full-node")]
+	#[error("Validators can't use a remote signer, it can't create VRF signatures")]
+	RemoteSignerNotSupported,
+
 	#[cfg(feature = "full-node")]
 	#[error("Expected at least one of polkadot, kusama, westend or rococo runtime feature")]
 	NoRuntime,
@@ -738,6 +742,12 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 
 	let is_offchain_indexing_enabled = config.offchain_worker.indexing_enabled;
 	let role = config.role.clone();
+	// The remote signer can't create the VRF signatures of BABE and of the approval assignments.
+	if role.is_authority() &&
+		matches!(config.keystore, service::config::KeystoreConfig::Remote { .. })
+	{
+		return Err(Error::RemoteSignerNotSupported)
+	}
 	let force_authoring = config.force_authoring;
 	let backoff_authoring_blocks = if !force_authoring_backoff &&
 		(config.chain_spec.is_polkadot() || config.chain_spec.is_kusama())
@@ -538,6 +538,8 @@ pub enum AvailabilityStoreMessage {
 		candidate_hash: CandidateHash,
 		/// The number of validators in the session.
 		n_validators: u32,
+		/// The node features of the session, which select the erasure code.
+		node_features: NodeFeatures,
 		/// The `AvailableData` itself.
 		available_data: AvailableData,
 		/// Erasure root we expect to get after chunking.
@@ -0,0 +1,184 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Introspection of what the availability cores of the relay chain are assigned to.
+//!
+//! The scheduler gives the first cores of a session to the parachains holding a slot lease, in
+//! the order of [`paras::Pallet::parachains`], and any further cores of its assignment provider to
+//! on-demand orders. The [`CoreAssignmentsApi`] reports this per core, together with the para
+//! currently served by the core, so that UIs don't need to decode the scheduler's storage.
+
+use crate::{slots, traits::Leaser};
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{Decode, Encode};
+use primitives::{CoreIndex, Id as ParaId};
+use runtime_parachains::{
+	paras,
+	scheduler::{self, common::AssignmentProvider, CoreOccupied},
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{CheckedAdd, CheckedMul},
+	RuntimeDebug,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// What an availability core is assigned to.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum CoreAssignmentKind {
+	/// The core serves a parachain, usually one holding a slot lease.
+	Lease,
+	/// The core serves on-demand orders.
+	OnDemand,
+	/// The core is not assigned to anything.
+	Idle,
+}
+
+/// The current assignment of an availability core.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CoreAssignmentStatus<BlockNumber> {
+	/// The core.
+	pub core: CoreIndex,
+	/// What the core is assigned to.
+	pub kind: CoreAssignmentKind,
+	/// The para with a candidate pending availability on the core, or else the next para
+	/// scheduled on it.
+	pub para_id: Option<ParaId>,
+	/// Whether a candidate of `para_id` is pending availability on the core.
+	pub occupied: bool,
+	/// The block at which the slot lease of `para_id` ends.
+	///
+	/// `None` unless the core is a `Lease` core and its para holds a slot lease.
+	pub lease_ends_at: Option<BlockNumber>,
+}
+
+/// Reads the current assignments of all availability cores from the scheduler.
+pub struct CoreAssignments<T>(PhantomData<T>);
+
+impl<T: scheduler::Config + slots::Config> CoreAssignments<T> {
+	/// Returns the current assignment of every availability core, ordered by core index.
+	pub fn core_assignments() -> Vec<CoreAssignmentStatus<BlockNumberFor<T>>> {
+		let parachains = paras::Pallet::<T>::parachains();
+		let assigned_cores =
+			<T::AssignmentProvider as AssignmentProvider<BlockNumberFor<T>>>::session_core_count();
+		let mut claim_queue = scheduler::Pallet::<T>::claimqueue();
+
+		scheduler::Pallet::<T>::availability_cores()
+			.into_iter()
+			.enumerate()
+			.map(|(i, occupied)| {
+				let core = CoreIndex(i as u32);
+				let kind = core_kind(i, parachains.len(), assigned_cores as usize);
+				let (para_id, occupied) = match occupied {
+					CoreOccupied::Paras(entry) => (Some(entry.para_id()), true),
+					CoreOccupied::Free => {
+						let next = claim_queue
+							.remove(&core)
+							.and_then(|queue| queue.into_iter().flatten().next())
+							.map(|entry| entry.para_id());
+						(next, false)
+					},
+				};
+				let para_id = match kind {
+					// A lease core always serves the same parachain, even if nothing is
+					// scheduled on it right now.
+					CoreAssignmentKind::Lease => para_id.or_else(|| parachains.get(i).copied()),
+					_ => para_id,
+				};
+				let lease_ends_at = match (kind, para_id) {
+					(CoreAssignmentKind::Lease, Some(para)) => Self::lease_ends_at(para),
+					_ => None,
+				};
+				CoreAssignmentStatus { core, kind, para_id, occupied, lease_ends_at }
+			})
+			.collect()
+	}
+
+	/// The block at which the slot lease of `para` ends, if it holds one.
+	fn lease_ends_at(para: ParaId) -> Option<BlockNumberFor<T>> {
+		let lease_periods = slots::Pallet::<T>::lease(para).len() as u32;
+		if lease_periods == 0 {
+			return None
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		let (current_period, _) = slots::Pallet::<T>::lease_period_index(now)?;
+		let (period_length, offset) = slots::Pallet::<T>::lease_period_length();
+		lease_end(current_period, lease_periods.into(), period_length, offset)
+	}
+}
+
+/// The kind of the core at `index`, given the number of parachains and the number of cores of
+/// the assignment provider.
+fn core_kind(index: usize, parachains: usize, assigned_cores: usize) -> CoreAssignmentKind {
+	if index < parachains {
+		CoreAssignmentKind::Lease
+	} else if index < assigned_cores {
+		CoreAssignmentKind::OnDemand
+	} else {
+		CoreAssignmentKind::Idle
+	}
+}
+
+/// The first block after `lease_periods` lease periods, starting with `current_period`.
+fn lease_end<N: CheckedAdd + CheckedMul>(
+	current_period: N,
+	lease_periods: N,
+	period_length: N,
+	offset: N,
+) -> Option<N> {
+	current_period
+		.checked_add(&lease_periods)?
+		.checked_mul(&period_length)?
+		.checked_add(&offset)
+}
+
+sp_api::decl_runtime_apis! {
+	/// API for parachain teams and Coretime marketplaces to display what the availability cores
+	/// are assigned to.
+	pub trait CoreAssignmentsApi<BlockNumber> where BlockNumber: parity_scale_codec::Codec {
+		/// Returns the current assignment of every availability core, ordered by core index.
+		fn core_assignments() -> Vec<CoreAssignmentStatus<BlockNumber>>;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn core_kind_follows_parachains_then_on_demand() {
+		let kinds: Vec<_> = (0..5).map(|i| core_kind(i, 2, 4)).collect();
+		assert_eq!(
+			kinds,
+			vec![
+				CoreAssignmentKind::Lease,
+				CoreAssignmentKind::Lease,
+				CoreAssignmentKind::OnDemand,
+				CoreAssignmentKind::OnDemand,
+				CoreAssignmentKind::Idle,
+			]
+		);
+		// Without on-demand cores, the cores beyond the parachains are idle.
+		assert_eq!(core_kind(2, 2, 2), CoreAssignmentKind::Idle);
+	}
+
+	#[test]
+	fn lease_end_works() {
+		// Periods 3 and 4 of 10 blocks each, starting at block 5.
+		assert_eq!(lease_end(3u32, 2, 10, 5), Some(55));
+		assert_eq!(lease_end(u32::MAX, 1, 10, 0), None);
+	}
+}
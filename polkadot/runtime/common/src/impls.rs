@@ -17,10 +17,17 @@
 //! Auxiliary `struct`/`enum`s for polkadot runtime.
 
 use crate::NegativeImbalance;
-use frame_support::traits::{Currency, Imbalance, OnUnbalanced};
+use frame_support::{
+	traits::{Currency, Get, Imbalance, OnUnbalanced},
+	weights::Weight,
+};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use primitives::Balance;
-use sp_runtime::{traits::TryConvert, Perquintill, RuntimeDebug};
+use sp_runtime::{
+	traits::{Convert, TryConvert},
+	Perquintill, RuntimeDebug,
+};
+use sp_std::vec::Vec;
 use xcm::VersionedMultiLocation;
 
 /// Logic for the author to get a portion of fees.
@@ -146,6 +153,51 @@ impl TryConvert<&VersionedMultiLocation, xcm::latest::MultiLocation>
 	}
 }
 
+/// Notifies `Destination` over XCM about treasury spends which expired without being paid.
+///
+/// The notification is an unpaid `Transact` of the call encoded by `Notification` for the index
+/// of the expired spend, dispatched with the `Xcm` origin of this chain and a weight limit of
+/// `NotificationWeight`. Failing to send it is only logged, since the spend is removed anyway.
+pub struct NotifySpendExpiredOverXcm<Router, Destination, Notification, NotificationWeight>(
+	sp_std::marker::PhantomData<(Router, Destination, Notification, NotificationWeight)>,
+);
+impl<
+		AssetKind,
+		AssetBalance,
+		Beneficiary,
+		Router: xcm::latest::SendXcm,
+		Destination: Get<xcm::latest::MultiLocation>,
+		Notification: Convert<pallet_treasury::SpendIndex, Vec<u8>>,
+		NotificationWeight: Get<Weight>,
+	> pallet_treasury::OnSpendExpired<AssetKind, AssetBalance, Beneficiary>
+	for NotifySpendExpiredOverXcm<Router, Destination, Notification, NotificationWeight>
+{
+	fn on_spend_expired(
+		index: pallet_treasury::SpendIndex,
+		_: &AssetKind,
+		_: &AssetBalance,
+		_: &Beneficiary,
+	) {
+		use xcm::latest::prelude::*;
+		let message = Xcm(sp_std::vec![
+			UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+			Transact {
+				origin_kind: OriginKind::Xcm,
+				require_weight_at_most: NotificationWeight::get(),
+				call: Notification::convert(index).into(),
+			},
+		]);
+		if let Err(e) = send_xcm::<Router>(Destination::get(), message) {
+			log::error!(
+				target: "runtime::treasury",
+				"Failed to notify about the expired spend {:?}: {:?}",
+				index,
+				e,
+			);
+		}
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarks {
 	use super::VersionedLocatableAsset;
@@ -324,6 +376,7 @@ mod tests {
 		type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
 		type BalanceConverter = UnityAssetBalanceConversion;
 		type PayoutPeriod = ConstU64<0>;
+		type OnSpendExpired = ();
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper = ();
 	}
@@ -408,4 +461,61 @@ mod tests {
 			(6, 4)
 		);
 	}
+
+	#[test]
+	fn notify_spend_expired_over_xcm_sends_the_notification() {
+		use pallet_treasury::OnSpendExpired;
+		use std::cell::RefCell;
+		use xcm::latest::prelude::*;
+
+		thread_local! {
+			static SENT: RefCell<Vec<(MultiLocation, Xcm<()>)>> = RefCell::new(Vec::new());
+		}
+		struct TestSender;
+		impl SendXcm for TestSender {
+			type Ticket = (MultiLocation, Xcm<()>);
+			fn validate(
+				dest: &mut Option<MultiLocation>,
+				msg: &mut Option<Xcm<()>>,
+			) -> SendResult<Self::Ticket> {
+				Ok(((dest.take().unwrap(), msg.take().unwrap()), MultiAssets::new()))
+			}
+			fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+				SENT.with(|s| s.borrow_mut().push(ticket));
+				Ok([0; 32])
+			}
+		}
+		struct EncodeIndex;
+		impl Convert<pallet_treasury::SpendIndex, Vec<u8>> for EncodeIndex {
+			fn convert(index: pallet_treasury::SpendIndex) -> Vec<u8> {
+				(42u8, index).encode()
+			}
+		}
+		parameter_types! {
+			pub Watcher: MultiLocation = MultiLocation::new(0, X1(Parachain(1000)));
+			pub const NotificationWeight: Weight = Weight::from_parts(1_000, 100);
+		}
+
+		NotifySpendExpiredOverXcm::<TestSender, Watcher, EncodeIndex, NotificationWeight>::on_spend_expired(
+			7,
+			&(),
+			&10u64,
+			&TEST_ACCOUNT,
+		);
+
+		assert_eq!(
+			SENT.with(|s| s.take()),
+			vec![(
+				Watcher::get(),
+				Xcm(vec![
+					UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+					Transact {
+						origin_kind: OriginKind::Xcm,
+						require_weight_at_most: NotificationWeight::get(),
+						call: (42u8, 7u32).encode().into(),
+					},
+				])
+			)]
+		);
+	}
 }
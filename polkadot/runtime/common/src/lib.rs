@@ -21,10 +21,12 @@
 pub mod assigned_slots;
 pub mod auctions;
 pub mod claims;
+pub mod core_assignments;
 pub mod crowdloan;
 pub mod elections;
 pub mod identity_migrator;
 pub mod impls;
+pub mod para_heads;
 pub mod paras_registrar;
 pub mod paras_sudo_wrapper;
 pub mod purchase;
@@ -0,0 +1,132 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Commitments to the parachain heads that are added to the BEEFY MMR leaves.
+//!
+//! Each MMR leaf commits to a keccak merkle root of all parachain heads (sorted by their
+//! parachain id) through its `leaf_extra` field. Ethereum light clients follow the relay chain
+//! through BEEFY commitments and use the [`BeefyParaHeadsApi`] proofs to prove the head of a
+//! parachain (e.g. a bridge hub) against such a leaf.
+
+use binary_merkle_tree::{merkle_proof, merkle_root};
+use parity_scale_codec::{Decode, Encode};
+use primitives::Id as ParaId;
+use runtime_parachains::paras;
+use scale_info::TypeInfo;
+use sp_consensus_beefy::mmr::BeefyDataProvider;
+use sp_core::H256;
+use sp_runtime::{traits::Keccak256, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// A keccak merkle proof of a parachain head against the para heads root of an MMR leaf.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ParaHeadProof {
+	/// The para heads root, i.e. the `leaf_extra` of the MMR leaf.
+	pub root: H256,
+	/// Hashes of the sibling nodes, from the leaf up to the root.
+	pub proof: Vec<H256>,
+	/// Number of parachain heads in the tree.
+	pub number_of_leaves: u32,
+	/// Index of the proven head in the tree.
+	pub leaf_index: u32,
+	/// The proven leaf, i.e. the SCALE-encoded `(para_id, head_data)` pair.
+	pub leaf: Vec<u8>,
+}
+
+/// A [`BeefyDataProvider`] that merkelizes all parachain heads at the current block, sorted by
+/// their parachain id.
+pub struct ParaHeadsRootProvider<T>(PhantomData<T>);
+
+impl<T: paras::Config> ParaHeadsRootProvider<T> {
+	/// The SCALE-encoded `(para_id, head_data)` leaves of the para heads tree.
+	fn leaves() -> Vec<(u32, Vec<u8>)> {
+		let mut para_heads: Vec<(u32, Vec<u8>)> = paras::Pallet::<T>::parachains()
+			.into_iter()
+			.filter_map(|id| paras::Pallet::<T>::para_head(&id).map(|head| (id.into(), head.0)))
+			.collect();
+		para_heads.sort_by_key(|k| k.0);
+		para_heads
+	}
+
+	/// Returns a proof of the head of `para_id` against the root of the current para heads.
+	///
+	/// MMR leaves are built from the state of the parent block, so the proof at block `N` is
+	/// valid against the `leaf_extra` of the MMR leaf that is added in block `N + 1`.
+	pub fn para_head_proof(para_id: ParaId) -> Option<ParaHeadProof> {
+		prove_para_head(Self::leaves(), para_id.into())
+	}
+}
+
+impl<T: paras::Config> BeefyDataProvider<H256> for ParaHeadsRootProvider<T> {
+	fn extra_data() -> H256 {
+		merkle_root::<Keccak256, _>(Self::leaves().into_iter().map(|pair| pair.encode()))
+	}
+}
+
+/// Builds the proof of the head of `para_id` from the sorted `para_heads`.
+fn prove_para_head(para_heads: Vec<(u32, Vec<u8>)>, para_id: u32) -> Option<ParaHeadProof> {
+	let leaf_index = para_heads.iter().position(|(id, _)| *id == para_id)?;
+	let proof = merkle_proof::<Keccak256, _, _>(
+		para_heads.into_iter().map(|pair| pair.encode()),
+		leaf_index,
+	);
+
+	Some(ParaHeadProof {
+		root: proof.root,
+		proof: proof.proof,
+		number_of_leaves: proof.number_of_leaves as u32,
+		leaf_index: proof.leaf_index as u32,
+		leaf: proof.leaf,
+	})
+}
+
+sp_api::decl_runtime_apis! {
+	/// API for light clients that verify parachain heads against BEEFY MMR leaves.
+	pub trait BeefyParaHeadsApi {
+		/// Returns a keccak merkle proof of the head of `para_id` against the para heads root
+		/// that the MMR leaf added in the next block commits to.
+		///
+		/// Returns `None` if the parachain has no head.
+		fn para_head_proof(para_id: ParaId) -> Option<ParaHeadProof>;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use binary_merkle_tree::verify_proof;
+
+	#[test]
+	fn para_head_proof_verifies_against_root() {
+		let para_heads = vec![(1000, vec![1; 32]), (1002, vec![2; 32]), (2000, vec![3; 64])];
+		let root = merkle_root::<Keccak256, _>(para_heads.iter().map(Encode::encode));
+
+		for (para_id, head) in &para_heads {
+			let proof = prove_para_head(para_heads.clone(), *para_id).unwrap();
+			assert_eq!(proof.root, root);
+			assert_eq!(proof.leaf, (*para_id, head.clone()).encode());
+			assert!(verify_proof::<Keccak256, _, _>(
+				&root,
+				proof.proof,
+				proof.number_of_leaves as usize,
+				proof.leaf_index as usize,
+				&proof.leaf,
+			));
+		}
+
+		assert_eq!(prove_para_head(para_heads, 1001), None);
+	}
+}
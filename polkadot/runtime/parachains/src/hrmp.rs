@@ -18,7 +18,11 @@ use crate::{
 	configuration::{self, HostConfiguration},
 	dmp, ensure_parachain, initializer, paras,
 };
-use frame_support::{pallet_prelude::*, traits::ReservableCurrency, DefaultNoBound};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Currency, ExistenceRequirement, ReservableCurrency},
+	DefaultNoBound,
+};
 use frame_system::pallet_prelude::*;
 use parity_scale_codec::{Decode, Encode};
 use polkadot_parachain_primitives::primitives::{HorizontalMessages, IsSystem};
@@ -28,7 +32,9 @@ use primitives::{
 };
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{AccountIdConversion, BlakeTwo256, Hash as HashT, UniqueSaturatedInto, Zero},
+	traits::{
+		AccountIdConversion, BlakeTwo256, Hash as HashT, Saturating, UniqueSaturatedInto, Zero,
+	},
 	ArithmeticError,
 };
 use sp_std::{
@@ -65,6 +71,7 @@ pub trait WeightInfo {
 	fn force_open_hrmp_channel(c: u32) -> Weight;
 	fn establish_system_channel() -> Weight;
 	fn poke_channel_deposits() -> Weight;
+	fn set_deposit_refund_account() -> Weight;
 }
 
 /// A weight info that is only suitable for testing.
@@ -104,6 +111,9 @@ impl WeightInfo for TestWeightInfo {
 	fn poke_channel_deposits() -> Weight {
 		Weight::MAX
 	}
+	fn set_deposit_refund_account() -> Weight {
+		Weight::MAX
+	}
 }
 
 /// A description of a request to open an HRMP channel.
@@ -306,6 +316,11 @@ pub mod pallet {
 		},
 		/// An HRMP channel's deposits were updated.
 		OpenChannelDepositsUpdated { sender: ParaId, recipient: ParaId },
+		/// A para designated the account its channel deposits are refunded to. `None` means
+		/// the deposits are refunded to the sovereign account of the para.
+		DepositRefundAccountSet { para: ParaId, account: Option<T::AccountId> },
+		/// A channel deposit held for `para` was refunded to `account`.
+		DepositRefunded { para: ParaId, account: T::AccountId, amount: Balance },
 	}
 
 	#[pallet::error]
@@ -454,6 +469,14 @@ pub mod pallet {
 	pub type HrmpChannelDigests<T: Config> =
 		StorageMap<_, Twox64Concat, ParaId, Vec<(BlockNumberFor<T>, Vec<ParaId>)>, ValueQuery>;
 
+	/// The account that channel deposits of a para are refunded to.
+	///
+	/// Deposits are always reserved from the sovereign account of the para. If no entry is present
+	/// for a para, the refunded deposits simply stay in its sovereign account.
+	#[pallet::storage]
+	pub type HrmpDepositRefundAccounts<T: Config> =
+		StorageMap<_, Twox64Concat, ParaId, T::AccountId>;
+
 	/// Preopen the given HRMP channels.
 	///
 	/// The values in the tuple corresponds to
@@ -788,12 +811,7 @@ pub mod pallet {
 						let amount = current_sender_deposit
 							.checked_sub(new_sender_deposit)
 							.ok_or(ArithmeticError::Underflow)?;
-						T::Currency::unreserve(
-							&channel_id.sender.into_account_truncating(),
-							// The difference should always be convertable into `Balance`, but be
-							// paranoid and do nothing in case.
-							amount.try_into().unwrap_or(Zero::zero()),
-						);
+						Self::refund_deposit(channel_id.sender, amount);
 					} else if current_sender_deposit < new_sender_deposit {
 						let amount = new_sender_deposit
 							.checked_sub(current_sender_deposit)
@@ -809,10 +827,7 @@ pub mod pallet {
 						let amount = current_recipient_deposit
 							.checked_sub(new_recipient_deposit)
 							.ok_or(ArithmeticError::Underflow)?;
-						T::Currency::unreserve(
-							&channel_id.recipient.into_account_truncating(),
-							amount.try_into().unwrap_or(Zero::zero()),
-						);
+						Self::refund_deposit(channel_id.recipient, amount);
 					} else if current_recipient_deposit < new_recipient_deposit {
 						let amount = new_recipient_deposit
 							.checked_sub(current_recipient_deposit)
@@ -836,6 +851,57 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Designate the account that the channel deposits of the origin para are refunded to,
+		/// instead of its sovereign account. Passing `None` restores refunds to the sovereign
+		/// account.
+		///
+		/// The account applies to all deposits that are refunded after this call, including the
+		/// ones of channels and requests that already exist.
+		///
+		/// The origin must be a parachain, e.g. its sovereign account acting via XCM.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_deposit_refund_account())]
+		pub fn set_deposit_refund_account(
+			origin: OriginFor<T>,
+			account: Option<T::AccountId>,
+		) -> DispatchResult {
+			let origin = ensure_parachain(<T as Config>::RuntimeOrigin::from(origin))?;
+			Self::do_set_deposit_refund_account(origin, account);
+			Ok(())
+		}
+
+		/// Same as [`hrmp_init_open_channel`](Pallet::hrmp_init_open_channel), but also designates
+		/// `refund_account` as the account that the channel deposits of the origin para are
+		/// refunded to. See [`set_deposit_refund_account`](Pallet::set_deposit_refund_account).
+		#[pallet::call_index(11)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::hrmp_init_open_channel()
+				.saturating_add(<T as Config>::WeightInfo::set_deposit_refund_account())
+		)]
+		pub fn hrmp_init_open_channel_with_refund_account(
+			origin: OriginFor<T>,
+			recipient: ParaId,
+			proposed_max_capacity: u32,
+			proposed_max_message_size: u32,
+			refund_account: T::AccountId,
+		) -> DispatchResult {
+			let origin = ensure_parachain(<T as Config>::RuntimeOrigin::from(origin))?;
+			Self::init_open_channel(
+				origin,
+				recipient,
+				proposed_max_capacity,
+				proposed_max_message_size,
+			)?;
+			Self::do_set_deposit_refund_account(origin, Some(refund_account));
+			Self::deposit_event(Event::OpenChannelRequested {
+				sender: origin,
+				recipient,
+				proposed_max_capacity,
+				proposed_max_message_size,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -943,10 +1009,7 @@ impl<T: Config> Pallet<T> {
 
 			// Return the deposit of the sender, but only if it is not the para being offboarded.
 			if !outgoing.contains(&req_id.sender) {
-				T::Currency::unreserve(
-					&req_id.sender.into_account_truncating(),
-					req_data.sender_deposit.unique_saturated_into(),
-				);
+				Self::refund_deposit(req_id.sender, req_data.sender_deposit);
 			}
 
 			// If the request was confirmed, then it means it was confirmed in the finished session.
@@ -956,10 +1019,7 @@ impl<T: Config> Pallet<T> {
 			// We still want to refund the deposit only if the para is not being offboarded.
 			if req_data.confirmed {
 				if !outgoing.contains(&req_id.recipient) {
-					T::Currency::unreserve(
-						&req_id.recipient.into_account_truncating(),
-						config.hrmp_recipient_deposit.unique_saturated_into(),
-					);
+					Self::refund_deposit(req_id.recipient, config.hrmp_recipient_deposit);
 				}
 				Self::decrease_accepted_channel_request_count(req_id.recipient);
 			}
@@ -986,6 +1046,9 @@ impl<T: Config> Pallet<T> {
 		for channel in to_close {
 			Self::close_hrmp_channel(&channel);
 		}
+
+		// The deposits of the closed channels are refunded by now.
+		HrmpDepositRefundAccounts::<T>::remove(outgoing_para);
 	}
 
 	/// Iterate over all open channel requests and:
@@ -1077,14 +1140,8 @@ impl<T: Config> Pallet<T> {
 		if let Some(HrmpChannel { sender_deposit, recipient_deposit, .. }) =
 			HrmpChannels::<T>::take(channel_id)
 		{
-			T::Currency::unreserve(
-				&channel_id.sender.into_account_truncating(),
-				sender_deposit.unique_saturated_into(),
-			);
-			T::Currency::unreserve(
-				&channel_id.recipient.into_account_truncating(),
-				recipient_deposit.unique_saturated_into(),
-			);
+			Self::refund_deposit(channel_id.sender, sender_deposit);
+			Self::refund_deposit(channel_id.recipient, recipient_deposit);
 		}
 
 		HrmpChannelContents::<T>::remove(channel_id);
@@ -1553,14 +1610,59 @@ impl<T: Config> Pallet<T> {
 
 		// Unreserve the sender's deposit. The recipient could not have left their deposit because
 		// we ensured that the request is not confirmed.
-		T::Currency::unreserve(
-			&channel_id.sender.into_account_truncating(),
-			open_channel_req.sender_deposit.unique_saturated_into(),
-		);
+		Self::refund_deposit(channel_id.sender, open_channel_req.sender_deposit);
 
 		Ok(())
 	}
 
+	fn do_set_deposit_refund_account(para: ParaId, account: Option<T::AccountId>) {
+		HrmpDepositRefundAccounts::<T>::set(para, account.clone());
+		Self::deposit_event(Event::DepositRefundAccountSet { para, account });
+	}
+
+	/// Unreserve the given channel deposit from the sovereign account of `para` and move it to
+	/// the refund account designated by the para, if any.
+	///
+	/// If the transfer to the designated account fails, the deposit stays in the sovereign
+	/// account. Either way, [`Event::DepositRefunded`] reports where the funds ended up.
+	fn refund_deposit(para: ParaId, deposit: Balance) {
+		if deposit.is_zero() {
+			return
+		}
+
+		let sovereign: T::AccountId = para.into_account_truncating();
+		let deposit = deposit.unique_saturated_into();
+		let not_unreserved = T::Currency::unreserve(&sovereign, deposit);
+		let amount = deposit.saturating_sub(not_unreserved);
+
+		let account = match HrmpDepositRefundAccounts::<T>::get(para) {
+			Some(refund_account) => match T::Currency::transfer(
+				&sovereign,
+				&refund_account,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			) {
+				Ok(()) => refund_account,
+				Err(err) => {
+					log::warn!(
+						target: "runtime::hrmp",
+						"failed to move the refunded deposit of {:?} to its refund account: {:?}",
+						para,
+						err,
+					);
+					sovereign
+				},
+			},
+			None => sovereign,
+		};
+
+		Self::deposit_event(Event::DepositRefunded {
+			para,
+			account,
+			amount: amount.unique_saturated_into(),
+		});
+	}
+
 	fn close_channel(origin: ParaId, channel_id: HrmpChannelId) -> Result<(), Error<T>> {
 		// check if the origin is allowed to close the channel.
 		ensure!(channel_id.is_participant(origin), Error::<T>::CloseHrmpChannelUnauthorized);
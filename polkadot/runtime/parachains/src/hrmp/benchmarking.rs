@@ -513,6 +513,21 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn set_deposit_refund_account() {
+		let para_id: ParaId = 1u32.into();
+		let para_origin: crate::Origin = 1u32.into();
+		let refund_account: T::AccountId = account("refund", 0, 0);
+
+		#[extrinsic_call]
+		_(para_origin, Some(refund_account.clone()));
+
+		assert_last_event::<T>(
+			Event::<T>::DepositRefundAccountSet { para: para_id, account: Some(refund_account) }
+				.into(),
+		);
+	}
+
 	impl_benchmark_test_suite!(
 		Hrmp,
 		crate::mock::new_test_ext(crate::hrmp::tests::GenesisConfigBuilder::default().build()),
@@ -827,6 +827,105 @@ fn cancel_pending_open_channel_request() {
 	});
 }
 
+#[test]
+fn refund_deposit_to_designated_account_on_closure() {
+	let para_a = 2032.into();
+	let para_a_origin: crate::Origin = 2032.into();
+	let para_b = 2064.into();
+	let para_b_origin: crate::Origin = 2064.into();
+	let treasury_a = 1032;
+	let treasury_b = 1064;
+
+	let mut genesis = GenesisConfigBuilder::default();
+	genesis.hrmp_sender_deposit = 20;
+	genesis.hrmp_recipient_deposit = 15;
+	new_test_ext(genesis.build()).execute_with(|| {
+		register_parachain_with_balance(para_a, 100);
+		register_parachain_with_balance(para_b, 110);
+		run_to_block(5, Some(vec![4, 5]));
+
+		// `para_a` designates its treasury when opening the channel, `para_b` separately.
+		Hrmp::hrmp_init_open_channel_with_refund_account(
+			para_a_origin.into(),
+			para_b,
+			2,
+			8,
+			treasury_a,
+		)
+		.unwrap();
+		Hrmp::set_deposit_refund_account(para_b_origin.into(), Some(treasury_b)).unwrap();
+		Hrmp::accept_open_channel(para_b, para_a).unwrap();
+		assert_eq!(HrmpDepositRefundAccounts::<Test>::get(para_a), Some(treasury_a));
+		assert!(System::events().iter().any(|record| record.event ==
+			MockEvent::Hrmp(Event::DepositRefundAccountSet {
+				para: para_b,
+				account: Some(treasury_b)
+			})));
+		run_to_block(8, Some(vec![8]));
+
+		// Close the channel and wait until the next session.
+		Hrmp::close_channel(para_b, HrmpChannelId { sender: para_a, recipient: para_b }).unwrap();
+		run_to_block(10, Some(vec![10]));
+		assert!(!channel_exists(para_a, para_b));
+
+		// The deposits ended up in the designated accounts rather than the sovereign ones.
+		assert_eq!(<Test as Config>::Currency::free_balance(&para_a.into_account_truncating()), 80);
+		assert_eq!(<Test as Config>::Currency::free_balance(&para_b.into_account_truncating()), 95);
+		assert_eq!(<Test as Config>::Currency::free_balance(&treasury_a), 20);
+		assert_eq!(<Test as Config>::Currency::free_balance(&treasury_b), 15);
+		assert!(System::events().iter().any(|record| record.event ==
+			MockEvent::Hrmp(Event::DepositRefunded {
+				para: para_a,
+				account: treasury_a,
+				amount: 20
+			})));
+		assert!(System::events().iter().any(|record| record.event ==
+			MockEvent::Hrmp(Event::DepositRefunded {
+				para: para_b,
+				account: treasury_b,
+				amount: 15
+			})));
+		Hrmp::assert_storage_consistency_exhaustive();
+	});
+}
+
+#[test]
+fn refund_deposit_to_sovereign_after_refund_account_reset() {
+	let para_a = 2032.into();
+	let para_a_origin: crate::Origin = 2032.into();
+	let para_b = 2064.into();
+	let treasury_a = 1032;
+
+	let mut genesis = GenesisConfigBuilder::default();
+	genesis.hrmp_sender_deposit = 20;
+	genesis.hrmp_recipient_deposit = 15;
+	new_test_ext(genesis.build()).execute_with(|| {
+		register_parachain_with_balance(para_a, 100);
+		register_parachain_with_balance(para_b, 110);
+		run_to_block(5, Some(vec![4, 5]));
+
+		Hrmp::set_deposit_refund_account(para_a_origin.clone().into(), Some(treasury_a)).unwrap();
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::set_deposit_refund_account(para_a_origin.into(), None).unwrap();
+		assert_eq!(HrmpDepositRefundAccounts::<Test>::get(para_a), None);
+
+		// Cancel the request, the deposit goes back to the sovereign account.
+		Hrmp::cancel_open_request(para_a, HrmpChannelId { sender: para_a, recipient: para_b })
+			.unwrap();
+		assert_eq!(
+			<Test as Config>::Currency::free_balance(&para_a.into_account_truncating()),
+			100
+		);
+		assert_eq!(<Test as Config>::Currency::free_balance(&treasury_a), 0);
+		assert!(System::events().iter().any(|record| record.event ==
+			MockEvent::Hrmp(Event::DepositRefunded {
+				para: para_a,
+				account: para_a.into_account_truncating(),
+				amount: 20
+			})));
+	});
+}
+
 #[test]
 fn watermark_maxed_out_at_relay_parent() {
 	let para_a = 2032.into();
@@ -32,10 +32,13 @@ use primitives::{
 	ValidationCode, ValidationCodeHash, ValidatorId, ValidatorIndex, PARACHAIN_KEY_TYPE_ID,
 };
 use runtime_common::{
-	assigned_slots, auctions, claims, crowdloan, identity_migrator, impl_runtime_weights,
+	assigned_slots, auctions, claims,
+	core_assignments::CoreAssignments,
+	crowdloan, identity_migrator, impl_runtime_weights,
 	impls::{
 		LocatableAssetConverter, ToAuthor, VersionedLocatableAsset, VersionedMultiLocationConverter,
 	},
+	para_heads::ParaHeadsRootProvider,
 	paras_registrar, paras_sudo_wrapper, prod_or_fast, slots, BlockHashCount, BlockLength,
 	SlowAdjustingFeeUpdate,
 };
@@ -61,7 +64,7 @@ use runtime_parachains::{
 use authority_discovery_primitives::AuthorityId as AuthorityDiscoveryId;
 use beefy_primitives::{
 	ecdsa_crypto::{AuthorityId as BeefyId, Signature as BeefySignature},
-	mmr::{BeefyDataProvider, MmrLeafVersion},
+	mmr::MmrLeafVersion,
 };
 
 use frame_support::{
@@ -208,6 +211,7 @@ parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
 		BlockWeights::get().max_block;
 	pub const MaxScheduledPerBlock: u32 = 50;
+	pub const MaxScheduledPerPage: u32 = 16;
 	pub const NoPreimagePostponement: Option<u32> = Some(10);
 }
 
@@ -239,6 +243,7 @@ impl pallet_scheduler::Config for Runtime {
 	// OpenGov to schedule periodic auctions.
 	type ScheduleOrigin = EitherOf<EnsureRoot<AccountId>, AuctionAdmin>;
 	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type MaxScheduledPerPage = MaxScheduledPerPage;
 	type WeightInfo = weights::pallet_scheduler::WeightInfo<Runtime>;
 	type OriginPrivilegeCmp = OriginPrivilegeCmp;
 	type Preimages = Preimage;
@@ -428,6 +433,7 @@ impl pallet_session::Config for Runtime {
 	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, ValidatorManager>;
 	type SessionHandler = <SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = SessionKeys;
+	type EnforceOwnershipProof = frame_support::traits::ConstBool<false>;
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 }
 
@@ -503,6 +509,7 @@ impl pallet_treasury::Config for Runtime {
 	>;
 	type BalanceConverter = AssetRate;
 	type PayoutPeriod = PayoutSpendPeriod;
+	type OnSpendExpired = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = runtime_common::impls::benchmarks::TreasuryArguments;
 }
@@ -536,12 +543,14 @@ impl pallet_bounties::Config for Runtime {
 parameter_types! {
 	pub const MaxActiveChildBountyCount: u32 = 100;
 	pub const ChildBountyValueMinimum: Balance = BountyValueMinimum::get() / 10;
+	pub const ParentCuratorFeeShare: Permill = Permill::from_percent(10);
 }
 
 impl pallet_child_bounties::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxActiveChildBountyCount = MaxActiveChildBountyCount;
 	type ChildBountyValueMinimum = ChildBountyValueMinimum;
+	type ParentCuratorFeeShare = ParentCuratorFeeShare;
 	type WeightInfo = weights::pallet_child_bounties::WeightInfo<Runtime>;
 }
 
@@ -1236,26 +1245,11 @@ parameter_types! {
 	pub LeafVersion: MmrLeafVersion = MmrLeafVersion::new(0, 0);
 }
 
-pub struct ParaHeadsRootProvider;
-impl BeefyDataProvider<H256> for ParaHeadsRootProvider {
-	fn extra_data() -> H256 {
-		let mut para_heads: Vec<(u32, Vec<u8>)> = Paras::parachains()
-			.into_iter()
-			.filter_map(|id| Paras::para_head(&id).map(|head| (id.into(), head.0)))
-			.collect();
-		para_heads.sort();
-		binary_merkle_tree::merkle_root::<mmr::Hashing, _>(
-			para_heads.into_iter().map(|pair| pair.encode()),
-		)
-		.into()
-	}
-}
-
 impl pallet_beefy_mmr::Config for Runtime {
 	type LeafVersion = LeafVersion;
 	type BeefyAuthorityToMerkleLeaf = pallet_beefy_mmr::BeefyEcdsaToEthereum;
 	type LeafExtra = H256;
-	type BeefyDataProvider = ParaHeadsRootProvider;
+	type BeefyDataProvider = ParaHeadsRootProvider<Runtime>;
 }
 
 impl paras_sudo_wrapper::Config for Runtime {}
@@ -1307,7 +1301,7 @@ construct_runtime! {
 	pub enum Runtime
 	{
 		// Basic stuff; balances is uncallable initially.
-		System: frame_system::{Pallet, Call, Storage, Config<T>, Event<T>} = 0,
+		System: frame_system::{Pallet, Call, Storage, Config<T>, Event<T>, ValidateUnsigned} = 0,
 
 		// Babe must be before session.
 		Babe: pallet_babe::{Pallet, Call, Storage, Config<T>, ValidateUnsigned} = 1,
@@ -1608,6 +1602,7 @@ pub mod migrations {
 		paras_registrar::migration::MigrateToV1<Runtime, ()>,
 		pallet_referenda::migration::v1::MigrateV0ToV1<Runtime, ()>,
 		pallet_referenda::migration::v1::MigrateV0ToV1<Runtime, pallet_referenda::Instance2>,
+		pallet_scheduler::migration::v5::MigrateV4ToV5<Runtime>,
 
 		// Unlock & unreserve Gov1 funds
 
@@ -2127,11 +2122,20 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
 			SessionKeys::generate(seed)
 		}
 
+		fn generate_session_keys_with_ownership_proof(
+			owner: Vec<u8>,
+			seed: Option<Vec<u8>>,
+		) -> sp_session::OpaqueGeneratedSessionKeys {
+			let (keys, proof) = SessionKeys::generate_with_ownership_proof(&owner, seed);
+			sp_session::OpaqueGeneratedSessionKeys { keys, proof }
+		}
+
 		fn decode_session_keys(
 			encoded: Vec<u8>,
 		) -> Option<Vec<(Vec<u8>, sp_core::crypto::KeyTypeId)>> {
@@ -2145,6 +2149,20 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_xcm::XcmQueryApi<Block, BlockNumber> for Runtime {
+		fn query_status(query_id: xcm::latest::QueryId) -> pallet_xcm::QueryStatusInfo<BlockNumber> {
+			XcmPallet::query_status(query_id)
+		}
+	}
+
+	impl pallet_scheduler::SchedulerApi<Block, BlockNumber, OriginCaller, RuntimeCall> for Runtime {
+		fn upcoming_tasks(
+			until: BlockNumber,
+		) -> Vec<pallet_scheduler::UpcomingTask<BlockNumber, OriginCaller, RuntimeCall>> {
+			Scheduler::upcoming_tasks(until)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -2173,6 +2191,21 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl runtime_common::para_heads::BeefyParaHeadsApi<Block> for Runtime {
+		fn para_head_proof(
+			para_id: ParaId,
+		) -> Option<runtime_common::para_heads::ParaHeadProof> {
+			ParaHeadsRootProvider::<Runtime>::para_head_proof(para_id)
+		}
+	}
+
+	impl runtime_common::core_assignments::CoreAssignmentsApi<Block, BlockNumber> for Runtime {
+		fn core_assignments(
+		) -> Vec<runtime_common::core_assignments::CoreAssignmentStatus<BlockNumber>> {
+			CoreAssignments::<Runtime>::core_assignments()
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
@@ -96,6 +96,12 @@ impl<T: frame_system::Config> pallet_child_bounties::WeightInfo for WeightInfo<T
 		Weight::from_parts(0, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 	}
+	/// The range of component `n` is `[1, 100]`.
+	fn claim_child_bounties(_n: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(0, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
 	fn close_child_bounty_added() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -101,10 +101,7 @@ impl<T: frame_system::Config> pallet_conviction_voting::WeightInfo for WeightInf
 	/// Storage: Scheduler Agenda (r:2 w:2)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
 	fn remove_vote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `13918`
-		//  Estimated: `83866`
-		// Minimum execution time: 205_017_000 picoseconds.
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
 		Weight::from_parts(216_594_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
 			.saturating_add(T::DbWeight::get().reads(4))
@@ -115,10 +112,7 @@ impl<T: frame_system::Config> pallet_conviction_voting::WeightInfo for WeightInf
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:0)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
 	fn remove_other_vote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `13004`
-		//  Estimated: `30706`
-		// Minimum execution time: 84_226_000 picoseconds.
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
 		Weight::from_parts(91_255_000, 0)
 			.saturating_add(Weight::from_parts(0, 30706))
 			.saturating_add(T::DbWeight::get().reads(2))
@@ -160,13 +154,9 @@ impl<T: frame_system::Config> pallet_conviction_voting::WeightInfo for WeightInf
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
 	/// The range of component `r` is `[0, 512]`.
 	fn undelegate(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `29555 + r * (365 ±0)`
-		//  Estimated: `83866 + r * (3411 ±0)`
-		// Minimum execution time: 45_232_000 picoseconds.
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
 		Weight::from_parts(2_045_021_014, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			// Standard Error: 185_130
 			.saturating_add(Weight::from_parts(47_896_011, 0).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
@@ -174,6 +164,26 @@ impl<T: frame_system::Config> pallet_conviction_voting::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 3411).saturating_mul(r.into()))
 	}
+	/// Storage: ConvictionVoting VotingFor (r:2 w:2)
+	/// Proof: ConvictionVoting VotingFor (max_values: None, max_size: Some(27241), added: 29716, mode: MaxEncodedLen)
+	/// Storage: ConvictionVoting PriorLocksFor (r:1 w:1)
+	/// Proof: ConvictionVoting PriorLocksFor (max_values: None, max_size: Some(10293), added: 12768, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumInfoFor (r:512 w:512)
+	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:2 w:2)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 512]`.
+	fn reduce_delegation(r: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(2_045_021_014, 0)
+			.saturating_add(Weight::from_parts(0, 83866))
+			.saturating_add(Weight::from_parts(47_896_011, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 3411).saturating_mul(r.into()))
+	}
 	/// Storage: ConvictionVoting VotingFor (r:1 w:1)
 	/// Proof: ConvictionVoting VotingFor (max_values: None, max_size: Some(27241), added: 29716, mode: MaxEncodedLen)
 	/// Storage: ConvictionVoting ClassLocksFor (r:1 w:1)
@@ -183,10 +193,7 @@ impl<T: frame_system::Config> pallet_conviction_voting::WeightInfo for WeightInf
 	/// Storage: Balances Freezes (r:1 w:0)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	fn unlock() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `12218`
-		//  Estimated: `30706`
-		// Minimum execution time: 116_446_000 picoseconds.
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
 		Weight::from_parts(124_043_000, 0)
 			.saturating_add(Weight::from_parts(0, 30706))
 			.saturating_add(T::DbWeight::get().reads(4))
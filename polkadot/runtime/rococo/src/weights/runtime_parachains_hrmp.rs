@@ -331,4 +331,12 @@ impl<T: frame_system::Config> runtime_parachains::hrmp::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Hrmp::HrmpDepositRefundAccounts` (r:0 w:1)
+	/// Proof: `Hrmp::HrmpDepositRefundAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_deposit_refund_account() -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(8_470_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -125,6 +125,9 @@ impl xcm_executor::Config for XcmConfig {
 	type CallDispatcher = super::RuntimeCall;
 	type SafeCallFilter = Everything;
 	type Aliasers = Nothing;
+	type HrmpNewChannelOpenRequestHandler = ();
+	type HrmpChannelAcceptedHandler = ();
+	type HrmpChannelClosingHandler = ();
 }
 
 impl pallet_xcm::Config for crate::Runtime {
@@ -133,6 +136,8 @@ impl pallet_xcm::Config for crate::Runtime {
 	type RuntimeEvent = crate::RuntimeEvent;
 	type ExecuteXcmOrigin = EnsureXcmOrigin<crate::RuntimeOrigin, LocalOriginToLocation>;
 	type UniversalLocation = UniversalLocation;
+	type BridgeExporters = ();
+	type BridgedReserveHops = ();
 	type SendXcmOrigin = EnsureXcmOrigin<crate::RuntimeOrigin, LocalOriginToLocation>;
 	type Weigher = FixedWeightBounds<BaseXcmWeight, crate::RuntimeCall, MaxInstructions>;
 	type XcmRouter = DoNothingRouter;
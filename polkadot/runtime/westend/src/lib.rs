@@ -23,7 +23,7 @@
 use authority_discovery_primitives::AuthorityId as AuthorityDiscoveryId;
 use beefy_primitives::{
 	ecdsa_crypto::{AuthorityId as BeefyId, Signature as BeefySignature},
-	mmr::{BeefyDataProvider, MmrLeafVersion},
+	mmr::MmrLeafVersion,
 };
 use frame_election_provider_support::{bounds::ElectionBoundsBuilder, onchain, SequentialPhragmen};
 use frame_support::{
@@ -55,12 +55,15 @@ use primitives::{
 	ValidatorSignature, PARACHAIN_KEY_TYPE_ID,
 };
 use runtime_common::{
-	assigned_slots, auctions, crowdloan,
+	assigned_slots, auctions,
+	core_assignments::CoreAssignments,
+	crowdloan,
 	elections::OnChainAccuracy,
 	identity_migrator, impl_runtime_weights,
 	impls::{
 		LocatableAssetConverter, ToAuthor, VersionedLocatableAsset, VersionedMultiLocationConverter,
 	},
+	para_heads::ParaHeadsRootProvider,
 	paras_registrar, paras_sudo_wrapper, prod_or_fast, slots, BalanceToU256, BlockHashCount,
 	BlockLength, CurrencyToVote, SlowAdjustingFeeUpdate, U256ToBalance,
 };
@@ -205,6 +208,7 @@ parameter_types! {
 	pub MaximumSchedulerWeight: frame_support::weights::Weight = Perbill::from_percent(80) *
 		BlockWeights::get().max_block;
 	pub const MaxScheduledPerBlock: u32 = 50;
+	pub const MaxScheduledPerPage: u32 = 16;
 	pub const NoPreimagePostponement: Option<u32> = Some(10);
 }
 
@@ -218,6 +222,7 @@ impl pallet_scheduler::Config for Runtime {
 	// OpenGov to schedule periodic auctions.
 	type ScheduleOrigin = EitherOf<EnsureRoot<AccountId>, AuctionAdmin>;
 	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type MaxScheduledPerPage = MaxScheduledPerPage;
 	type WeightInfo = weights::pallet_scheduler::WeightInfo<Runtime>;
 	type OriginPrivilegeCmp = frame_support::traits::EqualPrivilegeOnly;
 	type Preimages = Preimage;
@@ -358,28 +363,11 @@ parameter_types! {
 	pub LeafVersion: MmrLeafVersion = MmrLeafVersion::new(0, 0);
 }
 
-/// A BEEFY data provider that merkelizes all the parachain heads at the current block
-/// (sorted by their parachain id).
-pub struct ParaHeadsRootProvider;
-impl BeefyDataProvider<H256> for ParaHeadsRootProvider {
-	fn extra_data() -> H256 {
-		let mut para_heads: Vec<(u32, Vec<u8>)> = Paras::parachains()
-			.into_iter()
-			.filter_map(|id| Paras::para_head(&id).map(|head| (id.into(), head.0)))
-			.collect();
-		para_heads.sort_by_key(|k| k.0);
-		binary_merkle_tree::merkle_root::<mmr::Hashing, _>(
-			para_heads.into_iter().map(|pair| pair.encode()),
-		)
-		.into()
-	}
-}
-
 impl pallet_beefy_mmr::Config for Runtime {
 	type LeafVersion = LeafVersion;
 	type BeefyAuthorityToMerkleLeaf = pallet_beefy_mmr::BeefyEcdsaToEthereum;
 	type LeafExtra = H256;
-	type BeefyDataProvider = ParaHeadsRootProvider;
+	type BeefyDataProvider = ParaHeadsRootProvider<Runtime>;
 }
 
 parameter_types! {
@@ -490,6 +478,7 @@ impl pallet_session::Config for Runtime {
 	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, Staking>;
 	type SessionHandler = <SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = SessionKeys;
+	type EnforceOwnershipProof = frame_support::traits::ConstBool<false>;
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 }
 
@@ -705,6 +694,7 @@ impl pallet_staking::Config for Runtime {
 	type MaxControllersInDeprecationBatch = MaxControllersInDeprecationBatch;
 	type BenchmarkingConfig = runtime_common::StakingBenchmarkingConfig;
 	type EventListeners = NominationPools;
+	type RewardSuppression = ();
 	type WeightInfo = weights::pallet_staking::WeightInfo<Runtime>;
 }
 
@@ -774,6 +764,7 @@ impl pallet_treasury::Config for Runtime {
 	>;
 	type BalanceConverter = AssetRate;
 	type PayoutPeriod = PayoutSpendPeriod;
+	type OnSpendExpired = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = runtime_common::impls::benchmarks::TreasuryArguments;
 }
@@ -1396,7 +1387,7 @@ construct_runtime! {
 	pub enum Runtime
 	{
 		// Basic stuff; balances is uncallable initially.
-		System: frame_system::{Pallet, Call, Storage, Config<T>, Event<T>} = 0,
+		System: frame_system::{Pallet, Call, Storage, Config<T>, Event<T>, ValidateUnsigned} = 0,
 
 		// Babe must be before session.
 		Babe: pallet_babe::{Pallet, Call, Storage, Config<T>, ValidateUnsigned} = 1,
@@ -1637,6 +1628,7 @@ pub mod migrations {
 	pub type Unreleased = (
 		parachains_configuration::migration::v7::MigrateToV7<Runtime>,
 		pallet_staking::migrations::v14::MigrateToV14<Runtime>,
+		pallet_staking::migrations::v15::MigrateToV15<Runtime>,
 		assigned_slots::migration::v1::MigrateToV1<Runtime>,
 		parachains_scheduler::migration::v1::MigrateToV1<Runtime>,
 		parachains_configuration::migration::v8::MigrateToV8<Runtime>,
@@ -1652,6 +1644,7 @@ pub mod migrations {
 			<Runtime as frame_system::Config>::DbWeight,
 		>,
 		parachains_configuration::migration::v11::MigrateToV11<Runtime>,
+		pallet_scheduler::migration::v5::MigrateV4ToV5<Runtime>,
 	);
 }
 
@@ -2042,6 +2035,21 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl runtime_common::para_heads::BeefyParaHeadsApi<Block> for Runtime {
+		fn para_head_proof(
+			para_id: ParaId,
+		) -> Option<runtime_common::para_heads::ParaHeadProof> {
+			ParaHeadsRootProvider::<Runtime>::para_head_proof(para_id)
+		}
+	}
+
+	impl runtime_common::core_assignments::CoreAssignmentsApi<Block, BlockNumber> for Runtime {
+		fn core_assignments(
+		) -> Vec<runtime_common::core_assignments::CoreAssignmentStatus<BlockNumber>> {
+			CoreAssignments::<Runtime>::core_assignments()
+		}
+	}
+
 	impl fg_primitives::GrandpaApi<Block> for Runtime {
 		fn grandpa_authorities() -> Vec<(GrandpaId, u64)> {
 			Grandpa::grandpa_authorities()
@@ -2133,11 +2141,20 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
 			SessionKeys::generate(seed)
 		}
 
+		fn generate_session_keys_with_ownership_proof(
+			owner: Vec<u8>,
+			seed: Option<Vec<u8>>,
+		) -> sp_session::OpaqueGeneratedSessionKeys {
+			let (keys, proof) = SessionKeys::generate_with_ownership_proof(&owner, seed);
+			sp_session::OpaqueGeneratedSessionKeys { keys, proof }
+		}
+
 		fn decode_session_keys(
 			encoded: Vec<u8>,
 		) -> Option<Vec<(Vec<u8>, sp_core::crypto::KeyTypeId)>> {
@@ -2151,6 +2168,20 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_xcm::XcmQueryApi<Block, BlockNumber> for Runtime {
+		fn query_status(query_id: xcm::latest::QueryId) -> pallet_xcm::QueryStatusInfo<BlockNumber> {
+			XcmPallet::query_status(query_id)
+		}
+	}
+
+	impl pallet_scheduler::SchedulerApi<Block, BlockNumber, OriginCaller, RuntimeCall> for Runtime {
+		fn upcoming_tasks(
+			until: BlockNumber,
+		) -> Vec<pallet_scheduler::UpcomingTask<BlockNumber, OriginCaller, RuntimeCall>> {
+			Scheduler::upcoming_tasks(until)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -2202,6 +2233,10 @@ sp_api::impl_runtime_apis! {
 		fn balance_to_points(pool_id: pallet_nomination_pools::PoolId, new_funds: Balance) -> Balance {
 			NominationPools::api_balance_to_points(pool_id, new_funds)
 		}
+
+		fn member_total_balance(who: AccountId) -> Balance {
+			NominationPools::api_member_total_balance(who)
+		}
 	}
 
 	impl pallet_staking_runtime_api::StakingApi<Block, Balance, AccountId> for Runtime {
@@ -2278,7 +2313,16 @@ sp_api::impl_runtime_apis! {
 			use frame_system_benchmarking::Pallet as SystemBench;
 			use pallet_nomination_pools_benchmarking::Pallet as NominationPoolsBench;
 
-			impl pallet_session_benchmarking::Config for Runtime {}
+			impl pallet_session_benchmarking::Config for Runtime {
+				fn generate_session_keys_and_proof(owner: &AccountId) -> (SessionKeys, Vec<u8>) {
+					let keys = SessionKeys::decode(&mut &SessionKeys::generate(None)[..])
+						.expect("generated keys are decodable; qed");
+					let proof = keys
+						.create_ownership_proof(&owner.encode())
+						.expect("generated keys are in the keystore; qed");
+					(keys, proof)
+				}
+			}
 			impl pallet_offences_benchmarking::Config for Runtime {}
 			impl pallet_election_provider_support_benchmarking::Config for Runtime {}
 			impl pallet_xcm::benchmarking::Config for Runtime {
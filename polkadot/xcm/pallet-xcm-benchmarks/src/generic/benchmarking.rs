@@ -16,7 +16,7 @@
 
 use super::*;
 use crate::{account_and_location, new_executor, EnsureDelivery, XcmCallOf};
-use codec::Encode;
+use codec::{Compact, CompactLen, Encode};
 use frame_benchmarking::{benchmarks, BenchmarkError};
 use frame_support::{dispatch::GetDispatchInfo, traits::fungible::Inspect};
 use sp_std::vec;
@@ -549,13 +549,11 @@ benchmarks! {
 	}
 
 	export_message {
-		let x in 1 .. 1000;
-		// The `inner_xcm` influences `ExportMessage` total weight based on
-		// `inner_xcm.encoded_size()`, so for this benchmark use smallest encoded instruction
-		// to approximate weight per "unit" of encoded size; then actual weight can be estimated
-		// to be `inner_xcm.encoded_size() * benchmarked_unit`.
-		// Use `ClearOrigin` as the small encoded instruction.
-		let inner_xcm = Xcm(vec![ClearOrigin; x as usize]);
+		// The component is the encoded size of the `inner_xcm` in bytes, which is what
+		// `ExportMessage` total weight depends on. Runtimes should compute the weight using
+		// `inner_xcm.encoded_size()`.
+		let x in 1 .. T::export_message_max_inner_xcm_size();
+		let inner_xcm = inner_xcm_of_encoded_size(x);
 		// Get `origin`, `network` and `destination` from configured runtime.
 		let (origin, network, destination) = T::export_message_origin_and_destination()?;
 
@@ -753,3 +751,13 @@ benchmarks! {
 		crate::generic::mock::Test
 	);
 }
+
+/// Builds an XCM of `ClearOrigin` (the smallest encoded instruction), whose encoded size is as
+/// close as possible to, but not larger than, `size` bytes.
+fn inner_xcm_of_encoded_size(size: u32) -> Xcm<()> {
+	let mut count = size;
+	while count > 0 && count as usize + Compact::<u32>::compact_len(&count) > size as usize {
+		count -= 1;
+	}
+	Xcm(vec![ClearOrigin; count as usize])
+}
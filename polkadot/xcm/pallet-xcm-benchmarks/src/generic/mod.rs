@@ -86,6 +86,15 @@ pub mod pallet {
 		fn export_message_origin_and_destination(
 		) -> Result<(MultiLocation, NetworkId, InteriorMultiLocation), BenchmarkError>;
 
+		/// The maximal encoded size (in bytes) of the XCM exported by the `ExportMessage`
+		/// instruction. It is the upper bound of the `export_message` benchmark component.
+		///
+		/// By default returns `1000`. Runtimes may return the largest message accepted by their
+		/// `MessageExporter`.
+		fn export_message_max_inner_xcm_size() -> u32 {
+			1000
+		}
+
 		/// A `(MultiLocation, MultiLocation)` that is one of the `Aliasers` configured by the XCM
 		/// executor.
 		///
@@ -26,8 +26,11 @@ mod mock;
 mod tests;
 
 pub mod migration;
+mod throttle;
 
-use codec::{Decode, Encode, EncodeLike, MaxEncodedLen};
+pub use throttle::ThrottleXcmSend;
+
+use codec::{Codec, Decode, Encode, EncodeLike, MaxEncodedLen};
 use frame_support::{
 	dispatch::GetDispatchInfo,
 	pallet_prelude::*,
@@ -50,8 +53,8 @@ use sp_runtime::{
 use sp_std::{boxed::Box, marker::PhantomData, prelude::*, result::Result, vec};
 use xcm::{latest::QueryResponseInfo, prelude::*};
 use xcm_builder::{
-	ExecuteController, ExecuteControllerWeightInfo, QueryController, QueryControllerWeightInfo,
-	SendController, SendControllerWeightInfo,
+	ensure_is_remote, ExecuteController, ExecuteControllerWeightInfo, ExporterFor, QueryController,
+	QueryControllerWeightInfo, SendController, SendControllerWeightInfo,
 };
 use xcm_executor::{
 	traits::{
@@ -242,6 +245,15 @@ pub mod pallet {
 		/// This chain's Universal Location.
 		type UniversalLocation: Get<InteriorMultiLocation>;
 
+		/// The bridges this chain exports messages through, used to find out which locations on
+		/// bridged networks can be reached directly and to estimate the fees of reaching them.
+		type BridgeExporters: ExporterFor;
+
+		/// Locations on bridged networks (usually their Asset Hubs) through which asset transfers
+		/// to the other locations of their network are routed. They act as reserve of the
+		/// transferred assets for the final destination.
+		type BridgedReserveHops: Get<Vec<MultiLocation>>;
+
 		/// The runtime `Origin` type.
 		type RuntimeOrigin: From<Origin> + From<<Self as SysConfig>::RuntimeOrigin>;
 
@@ -483,6 +495,10 @@ pub mod pallet {
 		FeesPaid { paying: MultiLocation, fees: MultiAssets },
 		/// Some assets have been claimed from an asset trap
 		AssetsClaimed { hash: H256, origin: MultiLocation, assets: VersionedMultiAssets },
+		/// The discovery of the XCM version of `destination` was abandoned, either after
+		/// `attempts` failed requests for version notifications or to make room in the discovery
+		/// queue.
+		VersionDiscoveryAbandoned { destination: MultiLocation, attempts: u32 },
 	}
 
 	#[pallet::origin]
@@ -691,7 +707,8 @@ pub mod pallet {
 
 	/// Destinations whose latest XCM version we would like to know. Duplicates not allowed, and
 	/// the `u32` counter is the number of times that a send to the destination has been attempted,
-	/// which is used as a prioritization.
+	/// which is used as a prioritization. Ordered from the least to the most recently attempted
+	/// destination, the former being evicted first when the queue is full.
 	#[pallet::storage]
 	#[pallet::whitelist_storage]
 	pub(super) type VersionDiscoveryQueue<T: Config> = StorageValue<
@@ -700,6 +717,17 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The number of failed version notification requests of the destinations in the
+	/// `VersionDiscoveryQueue` and the block before which they are not retried.
+	#[pallet::storage]
+	pub(super) type VersionDiscoveryRetries<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		VersionedMultiLocation,
+		(u32, BlockNumberFor<T>),
+		OptionQuery,
+	>;
+
 	/// The current migration's stage, if any.
 	#[pallet::storage]
 	pub(super) type CurrentMigration<T: Config> =
@@ -755,6 +783,14 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type XcmExecutionSuspended<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// The start of the current throttling period of an account and the number of XCM send and
+	/// transfer calls it made since then.
+	///
+	/// Only used by the [`ThrottleXcmSend`] transaction extension.
+	#[pallet::storage]
+	pub(super) type XcmSendQuotaUsed<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (BlockNumberFor<T>, u32), OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		#[serde(skip)]
@@ -778,7 +814,7 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
 			let mut weight_used = Weight::zero();
 			if let Some(migration) = CurrentMigration::<T>::get() {
 				// Consume 10% of block at most
@@ -788,26 +824,7 @@ pub mod pallet {
 				weight_used.saturating_accrue(w);
 			}
 
-			// Here we aim to get one successful version negotiation request sent per block, ordered
-			// by the destinations being most sent to.
-			let mut q = VersionDiscoveryQueue::<T>::take().into_inner();
-			// TODO: correct weights.
-			weight_used.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
-			q.sort_by_key(|i| i.1);
-			while let Some((versioned_dest, _)) = q.pop() {
-				if let Ok(dest) = MultiLocation::try_from(versioned_dest) {
-					if Self::request_version_notify(dest).is_ok() {
-						// TODO: correct weights.
-						weight_used.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
-						break
-					}
-				}
-			}
-			// Should never fail since we only removed items. But better safe than panicking as it's
-			// way better to drop the queue than panic on initialize.
-			if let Ok(q) = BoundedVec::try_from(q) {
-				VersionDiscoveryQueue::<T>::put(q);
-			}
+			weight_used.saturating_accrue(Self::process_version_discovery_queue(n));
 			weight_used
 		}
 	}
@@ -1329,6 +1346,18 @@ pub mod pallet {
 			ensure!(assets.len() <= MAX_ASSETS_FOR_TRANSFER, Error::<T>::TooManyAssets);
 			let mut assets = assets.into_inner();
 			let fee_asset_item = fee_asset_item as usize;
+			// Bridged destinations which cannot be reached directly are reached through a hop.
+			if let Some((hop, _)) = Self::bridged_transfer_route(&dest) {
+				return Self::do_bridged_reserve_transfer(
+					origin,
+					hop,
+					dest,
+					beneficiary,
+					assets,
+					fee_asset_item,
+					weight_limit,
+				)
+			}
 			let fees = assets.get(fee_asset_item as usize).ok_or(Error::<T>::Empty)?.clone();
 			// Find transfer types for fee and non-fee assets.
 			let (fees_transfer_type, assets_transfer_type) =
@@ -1386,6 +1415,10 @@ pub mod pallet {
 /// The maximum number of distinct assets allowed to be transferred in a single helper extrinsic.
 const MAX_ASSETS_FOR_TRANSFER: usize = 2;
 
+/// The number of failed version notification requests after which the discovery of the XCM
+/// version of a destination is abandoned.
+pub const MAX_VERSION_DISCOVERY_ATTEMPTS: u32 = 5;
+
 /// Specify how assets used for fees are handled during asset transfers.
 #[derive(Clone, PartialEq)]
 enum FeesHandling<T: Config> {
@@ -1535,6 +1568,18 @@ impl<T: Config> Pallet<T> {
 		let (origin, assets) = value;
 
 		let fee_asset_item = fee_asset_item as usize;
+		// Bridged destinations which cannot be reached directly are reached through a hop.
+		if let Some((hop, _)) = Self::bridged_transfer_route(&dest) {
+			return Self::do_bridged_reserve_transfer(
+				origin,
+				hop,
+				dest,
+				beneficiary,
+				assets,
+				fee_asset_item,
+				weight_limit,
+			)
+		}
 		let fees = assets.get(fee_asset_item as usize).ok_or(Error::<T>::Empty)?.clone();
 
 		// Find transfer types for fee and non-fee assets.
@@ -1597,6 +1642,70 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	/// Returns the route of a transfer to `dest` when it lives on a bridged network and the
+	/// configured bridges cannot deliver messages to it directly.
+	///
+	/// The route consists of the hop on the bridged network (one of
+	/// [`Config::BridgedReserveHops`]) through which `dest` is reached, together with the fee the
+	/// bridge quotes for exporting an empty message to that hop. The fee actually charged for a
+	/// transfer also depends on the size of the exported program.
+	pub fn bridged_transfer_route(
+		dest: &MultiLocation,
+	) -> Option<(MultiLocation, Option<MultiAsset>)> {
+		let universal_location = T::UniversalLocation::get();
+		let (network, remote_location) = ensure_is_remote(universal_location, *dest).ok()?;
+		if T::BridgeExporters::exporter_for(&network, &remote_location, &Xcm(vec![])).is_some() {
+			// `dest` is directly reachable over the bridge, no hop needed
+			return None
+		}
+		T::BridgedReserveHops::get().into_iter().find_map(|hop| {
+			let (hop_network, hop_location) = ensure_is_remote(universal_location, hop).ok()?;
+			if hop_network != network {
+				return None
+			}
+			let (_, fee) =
+				T::BridgeExporters::exporter_for(&hop_network, &hop_location, &Xcm(vec![]))?;
+			Some((hop, fee))
+		})
+	}
+
+	/// Transfer `assets` to `dest` on a bridged network by reserve-transferring them to `hop`,
+	/// which then forwards them to `dest` acting as their reserve.
+	fn do_bridged_reserve_transfer(
+		origin: MultiLocation,
+		hop: MultiLocation,
+		dest: MultiLocation,
+		beneficiary: MultiLocation,
+		assets: Vec<MultiAsset>,
+		fee_asset_item: usize,
+		weight_limit: WeightLimit,
+	) -> DispatchResult {
+		log::debug!(
+			target: "xcm::pallet_xcm::do_bridged_reserve_transfer",
+			"origin {:?}, hop {:?}, dest {:?}, beneficiary {:?}, assets {:?}, fee-idx {:?}",
+			origin, hop, dest, beneficiary, assets, fee_asset_item,
+		);
+		let fees = assets.get(fee_asset_item).ok_or(Error::<T>::Empty)?.clone();
+		// Transfer types are relative to the hop, the leg from the hop to `dest` is always a
+		// reserve transfer with the hop as reserve.
+		let (fees_transfer_type, assets_transfer_type) =
+			Self::find_fee_and_assets_transfer_types(&assets, fee_asset_item, &hop)?;
+		// Fees are forwarded to `dest` together with the assets, so they need the same reserve.
+		ensure!(assets_transfer_type == fees_transfer_type, Error::<T>::TooManyReserves);
+
+		let (local_xcm, xcm_on_hop) = Self::bridged_reserve_transfer_programs(
+			origin,
+			hop,
+			dest,
+			beneficiary,
+			assets,
+			assets_transfer_type,
+			fees,
+			weight_limit,
+		)?;
+		Self::execute_xcm_transfer(origin, hop, local_xcm, Some(xcm_on_hop))
+	}
+
 	fn build_and_execute_xcm_transfer_type(
 		origin: MultiLocation,
 		dest: MultiLocation,
@@ -1612,7 +1721,7 @@ impl<T: Config> Pallet<T> {
 			fees_handling {:?}, weight_limit: {:?}",
 			origin, dest, beneficiary, assets, transfer_type, fees, weight_limit,
 		);
-		let (mut local_xcm, remote_xcm) = match transfer_type {
+		let (local_xcm, remote_xcm) = match transfer_type {
 			TransferType::LocalReserve => {
 				let (local, remote) = Self::local_reserve_transfer_programs(
 					origin,
@@ -1663,6 +1772,15 @@ impl<T: Config> Pallet<T> {
 				(local, Some(remote))
 			},
 		};
+		Self::execute_xcm_transfer(origin, dest, local_xcm, remote_xcm)
+	}
+
+	fn execute_xcm_transfer(
+		origin: MultiLocation,
+		dest: MultiLocation,
+		mut local_xcm: Xcm<<T as Config>::RuntimeCall>,
+		remote_xcm: Option<Xcm<()>>,
+	) -> DispatchResult {
 		let weight =
 			T::Weigher::weight(&mut local_xcm).map_err(|()| Error::<T>::UnweighableMessage)?;
 		let hash = local_xcm.using_encoded(sp_io::hashing::blake2_256);
@@ -1671,7 +1789,7 @@ impl<T: Config> Pallet<T> {
 		Self::deposit_event(Event::Attempted { outcome: outcome.clone() });
 		outcome.ensure_complete().map_err(|error| {
 			log::error!(
-				target: "xcm::pallet_xcm::build_and_execute_xcm_transfer_type",
+				target: "xcm::pallet_xcm::execute_xcm_transfer",
 				"XCM execution failed with error {:?}", error
 			);
 			Error::<T>::LocalExecutionIncomplete
@@ -1683,7 +1801,7 @@ impl<T: Config> Pallet<T> {
 			if origin != Here.into_location() {
 				Self::charge_fees(origin, price).map_err(|error| {
 					log::error!(
-						target: "xcm::pallet_xcm::build_and_execute_xcm_transfer_type",
+						target: "xcm::pallet_xcm::execute_xcm_transfer",
 						"Unable to charge fee with error {:?}", error
 					);
 					Error::<T>::FeesNotMet
@@ -1924,6 +2042,68 @@ impl<T: Config> Pallet<T> {
 		]))
 	}
 
+	// function assumes fees and assets have the same `transfer_type` to `hop`
+	fn bridged_reserve_transfer_programs(
+		origin: MultiLocation,
+		hop: MultiLocation,
+		dest: MultiLocation,
+		beneficiary: MultiLocation,
+		assets: Vec<MultiAsset>,
+		transfer_type: TransferType,
+		fees: MultiAsset,
+		weight_limit: WeightLimit,
+	) -> Result<(Xcm<<T as Config>::RuntimeCall>, Xcm<()>), Error<T>> {
+		let value = (origin, assets);
+		ensure!(T::XcmReserveTransferFilter::contains(&value), Error::<T>::Filtered);
+		let (_, assets) = value;
+
+		let max_assets = assets.len() as u32;
+		let context = T::UniversalLocation::get();
+		// we spend up to half of fees for execution on hop and other half for execution on
+		// destination
+		let (fees_half_1, fees_half_2) = Self::halve_fees(fees)?;
+		// identifies fee item as seen by `hop` - to be used at hop chain
+		let hop_fees =
+			fees_half_1.reanchored(&hop, context).map_err(|_| Error::<T>::CannotReanchor)?;
+		// identifies fee item as seen by `dest` - to be used at destination chain
+		let dest_fees =
+			fees_half_2.reanchored(&dest, context).map_err(|_| Error::<T>::CannotReanchor)?;
+		let assets: MultiAssets = assets.into();
+		let mut reanchored_assets = assets.clone();
+		reanchored_assets
+			.reanchor(&hop, context)
+			.map_err(|_| Error::<T>::CannotReanchor)?;
+		// identifies `dest` as seen by `hop`
+		let dest = dest.reanchored(&hop, context).map_err(|_| Error::<T>::CannotReanchor)?;
+
+		let (local_execute_xcm, mut xcm_on_hop) = match transfer_type {
+			TransferType::LocalReserve => (
+				// locally move `assets` to `hop`s local sovereign account
+				Xcm(vec![TransferAsset { assets, beneficiary: hop }]),
+				// let hop chain know assets are in its SA on reserve
+				Xcm(vec![ReserveAssetDeposited(reanchored_assets), ClearOrigin]),
+			),
+			TransferType::DestinationReserve => (
+				// withdraw and burn reserve-based assets
+				Xcm(vec![WithdrawAsset(assets.clone()), BurnAsset(assets)]),
+				// withdraw `assets` from origin chain's sovereign account on hop
+				Xcm(vec![WithdrawAsset(reanchored_assets), ClearOrigin]),
+			),
+			_ => return Err(Error::<T>::InvalidAssetUnsupportedReserve),
+		};
+		// xcm to be executed at dest
+		let xcm_on_dest = Xcm(vec![
+			BuyExecution { fees: dest_fees, weight_limit: weight_limit.clone() },
+			DepositAsset { assets: Wild(AllCounted(max_assets)), beneficiary },
+		]);
+		// hop pays for its execution and forwards the rest to dest, acting as reserve
+		xcm_on_hop.inner_mut().extend([
+			BuyExecution { fees: hop_fees, weight_limit },
+			DepositReserveAsset { assets: Wild(AllCounted(max_assets)), dest, xcm: xcm_on_dest },
+		]);
+		Ok((local_execute_xcm, xcm_on_hop))
+	}
+
 	fn teleport_fees_instructions(
 		origin: MultiLocation,
 		dest: MultiLocation,
@@ -2287,6 +2467,23 @@ impl<T: Config> Pallet<T> {
 		AccountIdConversion::<T::AccountId>::into_account_truncating(&ID)
 	}
 
+	/// Returns the status of the query `query_id` without taking its response.
+	///
+	/// Pending queries whose timeout has passed are reported as expired. Version notification
+	/// subscriptions are not reported and result in [`QueryStatusInfo::NotFound`].
+	pub fn query_status(query_id: QueryId) -> QueryStatusInfo<BlockNumberFor<T>> {
+		match Queries::<T>::get(query_id) {
+			Some(QueryStatus::Pending { timeout, .. }) =>
+				if frame_system::Pallet::<T>::block_number() > timeout {
+					QueryStatusInfo::Expired { timeout }
+				} else {
+					QueryStatusInfo::Pending { timeout }
+				},
+			Some(QueryStatus::Ready { response, at }) => QueryStatusInfo::Ready { response, at },
+			Some(QueryStatus::VersionNotifier { .. }) | None => QueryStatusInfo::NotFound,
+		}
+	}
+
 	/// Create a new expectation of a query response with the querier being here.
 	fn do_new_query(
 		responder: impl Into<MultiLocation>,
@@ -2375,15 +2572,93 @@ impl<T: Config> Pallet<T> {
 		);
 		let versioned_dest = VersionedMultiLocation::from(*dest);
 		VersionDiscoveryQueue::<T>::mutate(|q| {
-			if let Some(index) = q.iter().position(|i| &i.0 == &versioned_dest) {
-				// exists - just bump the count.
-				q[index].1.saturating_inc();
+			let count = if let Some(index) = q.iter().position(|i| &i.0 == &versioned_dest) {
+				// exists - bump the count and move it to the back as the most recently attempted.
+				q.remove(index).1.saturating_add(1)
 			} else {
-				let _ = q.try_push((versioned_dest, 1));
-			}
+				if !q.is_empty() && q.len() >= VersionDiscoveryQueueSize::<T>::get() as usize {
+					// full - evict the least recently attempted destination.
+					let (evicted, _) = q.remove(0);
+					let attempts = VersionDiscoveryRetries::<T>::get(&evicted)
+						.map_or(0, |(attempts, _)| attempts);
+					Self::abandon_version_discovery(evicted, attempts);
+				}
+				1
+			};
+			let _ = q.try_push((versioned_dest, count));
 		});
 	}
 
+	/// Send a version notification request to the most sent to destination of the
+	/// `VersionDiscoveryQueue` which is not backing off, aiming to get one request sent per block.
+	///
+	/// Destinations whose request fails are retried after an exponentially growing number of
+	/// blocks and dropped from the queue after `MAX_VERSION_DISCOVERY_ATTEMPTS` failures.
+	fn process_version_discovery_queue(now: BlockNumberFor<T>) -> Weight {
+		let mut q = VersionDiscoveryQueue::<T>::get();
+		// TODO: correct weights.
+		let mut weight_used = T::DbWeight::get().reads_writes(1, 1);
+		// Most sent to first, the most recently attempted of those first.
+		let mut candidates: Vec<usize> = (0..q.len()).collect();
+		candidates.sort_by_key(|&i| (q[i].1, i));
+		let mut done = Vec::new();
+		for index in candidates.into_iter().rev() {
+			let versioned_dest = q[index].0.clone();
+			let (attempts, retry_at) =
+				VersionDiscoveryRetries::<T>::get(&versioned_dest).unwrap_or((0, Zero::zero()));
+			// TODO: correct weights.
+			weight_used.saturating_accrue(T::DbWeight::get().reads_writes(2, 1));
+			if retry_at > now {
+				continue
+			}
+			let Ok(dest) = MultiLocation::try_from(versioned_dest.clone()) else {
+				done.push(index);
+				continue
+			};
+			if VersionNotifiers::<T>::contains_key(XCM_VERSION, &versioned_dest) {
+				// already requested, nothing left to discover.
+				VersionDiscoveryRetries::<T>::remove(&versioned_dest);
+				done.push(index);
+				continue
+			}
+			if Self::request_version_notify(dest).is_ok() {
+				VersionDiscoveryRetries::<T>::remove(&versioned_dest);
+				done.push(index);
+				break
+			}
+			let attempts = attempts.saturating_add(1);
+			if attempts >= MAX_VERSION_DISCOVERY_ATTEMPTS {
+				Self::abandon_version_discovery(versioned_dest, attempts);
+				done.push(index);
+			} else {
+				let backoff = BlockNumberFor::<T>::from(1u32 << attempts.min(31));
+				VersionDiscoveryRetries::<T>::insert(
+					&versioned_dest,
+					(attempts, now.saturating_add(backoff)),
+				);
+			}
+		}
+		if !done.is_empty() {
+			let mut index = 0;
+			q.retain(|_| {
+				let keep = !done.contains(&index);
+				index += 1;
+				keep
+			});
+		}
+		VersionDiscoveryQueue::<T>::put(q);
+		weight_used
+	}
+
+	/// Stop discovering the XCM version of `versioned_dest`, which was removed from the
+	/// `VersionDiscoveryQueue` after `attempts` failed requests.
+	fn abandon_version_discovery(versioned_dest: VersionedMultiLocation, attempts: u32) {
+		VersionDiscoveryRetries::<T>::remove(&versioned_dest);
+		if let Ok(destination) = MultiLocation::try_from(versioned_dest) {
+			Self::deposit_event(Event::VersionDiscoveryAbandoned { destination, attempts });
+		}
+	}
+
 	/// Withdraw given `assets` from the given `location` and pay as XCM fees.
 	///
 	/// Fails if:
@@ -2893,6 +3168,28 @@ impl<T: Config> CheckSuspension for Pallet<T> {
 	}
 }
 
+/// Status of a query, as reported by [`XcmQueryApi::query_status`].
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum QueryStatusInfo<BlockNumber> {
+	/// No response has been received yet, but it may still arrive until `timeout`.
+	Pending { timeout: BlockNumber },
+	/// No response has been received and the query timed out at `timeout`.
+	Expired { timeout: BlockNumber },
+	/// A response has been received at block `at` and is waiting to be taken.
+	Ready { response: VersionedResponse, at: BlockNumber },
+	/// There is no such query, or its response has already been taken.
+	NotFound,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API for polling the status of the XCM queries registered by `pallet-xcm`, e.g. the ones
+	/// created for `ReportHolding` or `ReportError` based flows.
+	pub trait XcmQueryApi<BlockNumber> where BlockNumber: Codec {
+		/// Returns the status of the query `query_id` and its response, if it is ready.
+		fn query_status(query_id: QueryId) -> QueryStatusInfo<BlockNumber>;
+	}
+}
+
 /// Ensure that the origin `o` represents an XCM (`Transact`) origin.
 ///
 /// Returns `Ok` with the location of the XCM sender or an `Err` otherwise.
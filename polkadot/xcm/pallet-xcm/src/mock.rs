@@ -36,8 +36,9 @@ use xcm_builder::{
 	AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
 	AllowTopLevelPaidExecutionFrom, Case, ChildParachainAsNative, ChildParachainConvertsVia,
 	ChildSystemParachainAsSuperuser, CurrencyAdapter as XcmCurrencyAdapter, DescribeAllTerminal,
-	FixedRateOfFungible, FixedWeightBounds, FungiblesAdapter, HashedDescription, IsConcrete,
-	MatchedConvertedConcreteId, NoChecking, SignedAccountId32AsNative, SignedToAccountId32,
+	FixedRateOfFungible, FixedWeightBounds, FungiblesAdapter, GlobalConsensusParachainConvertsFor,
+	HashedDescription, IsConcrete, MatchedConvertedConcreteId, NetworkExportTable,
+	NetworkExportTableItem, NoChecking, SignedAccountId32AsNative, SignedToAccountId32,
 	SovereignSignedViaLocation, TakeWeightCredit, XcmFeeManagerFromComponents, XcmFeeToAccount,
 };
 use xcm_executor::{
@@ -353,6 +354,13 @@ pub const OTHER_PARA_ID: u32 = 2009;
 // This child parachain is used for filtered/disallowed assets.
 pub const FILTERED_PARA_ID: u32 = 2010;
 
+// This parachain of `BridgedNetwork` is reachable over the bridge and forwards transfers to the
+// other chains of its network.
+pub const BRIDGED_RESERVE_HOP_PARA_ID: u32 = 1000;
+
+// This parachain of `BridgedNetwork` is only reachable through `BRIDGED_RESERVE_HOP_PARA_ID`.
+pub const BRIDGED_PARA_ID: u32 = 2000;
+
 parameter_types! {
 	pub const RelayLocation: MultiLocation = Here.into_location();
 	pub const NativeAsset: MultiAsset = MultiAsset {
@@ -408,7 +416,7 @@ parameter_types! {
 		}),
 	};
 	pub const AnyNetwork: Option<NetworkId> = None;
-	pub UniversalLocation: InteriorMultiLocation = Here;
+	pub static UniversalLocation: InteriorMultiLocation = Here;
 	pub UnitWeightCost: u64 = 1_000;
 	pub CheckingAccount: AccountId = XcmPallet::check_account();
 }
@@ -417,8 +425,29 @@ pub type SovereignAccountOf = (
 	ChildParachainConvertsVia<ParaId, AccountId>,
 	AccountId32Aliases<AnyNetwork, AccountId>,
 	HashedDescription<AccountId, DescribeAllTerminal>,
+	GlobalConsensusParachainConvertsFor<UniversalLocation, AccountId>,
 );
 
+parameter_types! {
+	pub const LocalNetwork: NetworkId = NetworkId::Polkadot;
+	pub const BridgedNetwork: NetworkId = NetworkId::Kusama;
+	pub const BridgeHubLocation: MultiLocation = MultiLocation {
+		parents: 0,
+		interior: X1(Parachain(SOME_SYSTEM_PARA))
+	};
+	pub BridgedReserveHop: MultiLocation = MultiLocation::new(
+		1,
+		X2(GlobalConsensus(BridgedNetwork::get()), Parachain(BRIDGED_RESERVE_HOP_PARA_ID)),
+	);
+	pub BridgedReserveHops: Vec<MultiLocation> = vec![BridgedReserveHop::get()];
+	pub BridgeTable: Vec<NetworkExportTableItem> = vec![NetworkExportTableItem::new(
+		BridgedNetwork::get(),
+		Some(vec![X1(Parachain(BRIDGED_RESERVE_HOP_PARA_ID))]),
+		BridgeHubLocation::get(),
+		None,
+	)];
+}
+
 pub type ForeignAssetsConvertedConcreteId = MatchedConvertedConcreteId<
 	MultiLocation,
 	Balance,
@@ -513,6 +542,9 @@ impl xcm_executor::Config for XcmConfig {
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
 	type Aliasers = Nothing;
+	type HrmpNewChannelOpenRequestHandler = ();
+	type HrmpChannelAcceptedHandler = ();
+	type HrmpChannelClosingHandler = ();
 }
 
 pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, AnyNetwork>;
@@ -540,6 +572,8 @@ impl pallet_xcm::Config for Test {
 	type XcmReserveTransferFilter = Everything;
 	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
 	type UniversalLocation = UniversalLocation;
+	type BridgeExporters = NetworkExportTable<BridgeTable>;
+	type BridgedReserveHops = BridgedReserveHops;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
@@ -2419,3 +2419,124 @@ fn teleport_assets_using_destination_reserve_fee_disallowed() {
 		expected_result,
 	);
 }
+
+// Helper function to deduplicate testing different bridged transfer types.
+/// Transferring native asset (local reserve) to a parachain of a bridged network which is only
+/// reachable through the bridged reserve hop. Using native asset for fees as well.
+///
+/// Verifies `expected_result`
+fn local_asset_reserve_to_bridged_destination_through_hop_call<Call>(
+	tested_call: Call,
+	expected_result: DispatchResult,
+) where
+	Call: FnOnce(
+		OriginFor<Test>,
+		Box<VersionedMultiLocation>,
+		Box<VersionedMultiLocation>,
+		Box<VersionedMultiAssets>,
+		u32,
+		WeightLimit,
+	) -> DispatchResult,
+{
+	UniversalLocation::set(X1(GlobalConsensus(LocalNetwork::get())));
+	let hop = BridgedReserveHop::get();
+	let hop_sovereign_account = SovereignAccountOf::convert_location(&hop).unwrap();
+	let balances = vec![(ALICE, INITIAL_BALANCE), (hop_sovereign_account.clone(), INITIAL_BALANCE)];
+	let beneficiary: MultiLocation = AccountId32 { network: None, id: ALICE.into() }.into();
+	let weight_limit = WeightLimit::Limited(Weight::from_parts(5000, 5000));
+	let expected_weight_limit = weight_limit.clone();
+	let dest = MultiLocation::new(
+		1,
+		X2(GlobalConsensus(BridgedNetwork::get()), Parachain(BRIDGED_PARA_ID)),
+	);
+
+	new_test_ext_with_balances(balances).execute_with(|| {
+		// destination is reached through the hop, whose bridge fee is unknown
+		assert_eq!(XcmPallet::bridged_transfer_route(&dest), Some((hop, None)));
+		// the hop itself is reachable directly
+		assert_eq!(XcmPallet::bridged_transfer_route(&hop), None);
+
+		let weight = BaseXcmWeight::get();
+		// call extrinsic
+		let result = tested_call(
+			RuntimeOrigin::signed(ALICE),
+			Box::new(dest.into()),
+			Box::new(beneficiary.into()),
+			Box::new((Here, SEND_AMOUNT).into()),
+			0,
+			weight_limit,
+		);
+		assert_eq!(result, expected_result);
+		if expected_result.is_err() {
+			// short-circuit here for tests where we expect failure
+			return
+		}
+		// Alice spent amount
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - SEND_AMOUNT);
+		// Hop's sovereign account has amount
+		assert_eq!(Balances::free_balance(hop_sovereign_account), INITIAL_BALANCE + SEND_AMOUNT);
+		// Native asset as seen by the bridged chains
+		let native_asset = MultiLocation::new(2, X1(GlobalConsensus(LocalNetwork::get())));
+		// Hop pays for its execution with half the fees and forwards the rest to the destination
+		assert_eq!(
+			sent_xcm(),
+			vec![(
+				hop,
+				Xcm(vec![
+					ReserveAssetDeposited((native_asset, SEND_AMOUNT).into()),
+					ClearOrigin,
+					buy_limited_execution(
+						(native_asset, SEND_AMOUNT / 2),
+						expected_weight_limit.clone()
+					),
+					DepositReserveAsset {
+						assets: AllCounted(1).into(),
+						dest: MultiLocation::new(1, X1(Parachain(BRIDGED_PARA_ID))),
+						xcm: Xcm(vec![
+							buy_limited_execution(
+								(native_asset, SEND_AMOUNT / 2),
+								expected_weight_limit
+							),
+							DepositAsset { assets: AllCounted(1).into(), beneficiary },
+						]),
+					},
+				]),
+			)]
+		);
+		let mut last_events = last_events(3).into_iter();
+		assert_eq!(
+			last_events.next().unwrap(),
+			RuntimeEvent::XcmPallet(crate::Event::Attempted { outcome: Outcome::Complete(weight) })
+		);
+		assert!(matches!(
+			last_events.next().unwrap(),
+			RuntimeEvent::XcmPallet(crate::Event::FeesPaid { .. })
+		));
+		assert!(matches!(
+			last_events.next().unwrap(),
+			RuntimeEvent::XcmPallet(crate::Event::Sent { destination, .. }) if destination == hop
+		));
+	});
+}
+
+/// Test `transfer_assets` to a bridged destination reachable only through the bridged reserve hop
+/// works.
+#[test]
+fn transfer_assets_to_bridged_destination_through_hop_works() {
+	let expected_result = Ok(());
+	local_asset_reserve_to_bridged_destination_through_hop_call(
+		XcmPallet::transfer_assets,
+		expected_result,
+	);
+}
+
+/// Test `limited_reserve_transfer_assets` to a bridged destination reachable only through the
+/// bridged reserve hop works.
+#[test]
+fn reserve_transfer_assets_to_bridged_destination_through_hop_works() {
+	let expected_result = Ok(());
+	local_asset_reserve_to_bridged_destination_through_hop_call(
+		XcmPallet::limited_reserve_transfer_assets,
+		expected_result,
+	);
+}
@@ -20,8 +20,8 @@ pub(crate) mod assets_transfer;
 
 use crate::{
 	mock::*, AssetTraps, CurrentMigration, Error, LatestVersionedMultiLocation, Queries,
-	QueryStatus, VersionDiscoveryQueue, VersionMigrationStage, VersionNotifiers,
-	VersionNotifyTargets,
+	QueryStatus, QueryStatusInfo, VersionDiscoveryQueue, VersionDiscoveryRetries,
+	VersionMigrationStage, VersionNotifiers, VersionNotifyTargets, MAX_VERSION_DISCOVERY_ATTEMPTS,
 };
 use frame_support::{
 	assert_noop, assert_ok,
@@ -279,6 +279,53 @@ fn custom_querier_works() {
 	});
 }
 
+#[test]
+fn query_status_works() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let responder: MultiLocation = Parachain(OTHER_PARA_ID).into();
+		assert_eq!(XcmPallet::query_status(0), QueryStatusInfo::NotFound);
+
+		// a query which is never answered expires
+		let expiring = XcmPallet::new_query(responder, 10, Here);
+		assert_eq!(XcmPallet::query_status(expiring), QueryStatusInfo::Pending { timeout: 10 });
+
+		// a query which is answered becomes ready
+		let answered = XcmPallet::new_query(responder, 100, Here);
+		let message = Xcm(vec![QueryResponse {
+			query_id: answered,
+			response: Response::ExecutionResult(None),
+			max_weight: Weight::zero(),
+			querier: Some(Here.into()),
+		}]);
+		let hash = fake_message_hash(&message);
+		let r = XcmExecutor::<XcmConfig>::execute_xcm(
+			responder,
+			message,
+			hash,
+			Weight::from_parts(1_000_000_000, 1_000_000_000),
+		);
+		assert_eq!(r, Outcome::Complete(Weight::from_parts(1_000, 1_000)));
+		assert_eq!(
+			XcmPallet::query_status(answered),
+			QueryStatusInfo::Ready { response: Response::ExecutionResult(None).into(), at: 1 },
+		);
+
+		System::set_block_number(11);
+		assert_eq!(XcmPallet::query_status(expiring), QueryStatusInfo::Expired { timeout: 10 });
+
+		// the response is still available until taken
+		assert_eq!(
+			XcmPallet::query_status(answered),
+			QueryStatusInfo::Ready { response: Response::ExecutionResult(None).into(), at: 1 },
+		);
+		assert_eq!(
+			XcmPallet::take_response(answered),
+			QueryResponseStatus::Ready { response: Response::ExecutionResult(None), at: 1 },
+		);
+		assert_eq!(XcmPallet::query_status(answered), QueryStatusInfo::NotFound);
+	});
+}
+
 /// Test sending an `XCM` message (`XCM::ReserveAssetDeposit`)
 ///
 /// Asserts that the expected message is sent and the event is emitted
@@ -1064,3 +1111,129 @@ fn get_and_wrap_version_works() {
 		assert_eq!(VersionDiscoveryQueue::<Test>::get().into_inner(), vec![(remote_b.into(), 2)]);
 	})
 }
+
+#[test]
+fn version_discovery_queue_evicts_least_recently_attempted() {
+	new_test_ext_with_balances_and_xcm_version(vec![], None).execute_with(|| {
+		let size = <Test as crate::Config>::VERSION_DISCOVERY_QUEUE_SIZE;
+		for para in 0..size {
+			XcmPallet::note_unknown_version(&Parachain(para).into());
+		}
+		// attempting to send to the first destination again makes it the most recently attempted
+		XcmPallet::note_unknown_version(&Parachain(0).into());
+		XcmPallet::note_unknown_version(&Parachain(size).into());
+
+		let q = VersionDiscoveryQueue::<Test>::get().into_inner();
+		assert_eq!(q.len(), size as usize);
+		assert_eq!(q[0], (Parachain(2).into_versioned(), 1));
+		assert_eq!(
+			q[q.len() - 2..],
+			[(Parachain(0).into_versioned(), 2), (Parachain(size).into_versioned(), 1)]
+		);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::XcmPallet(crate::Event::VersionDiscoveryAbandoned {
+				destination: Parachain(1).into(),
+				attempts: 0,
+			})
+		);
+	});
+}
+
+#[test]
+fn version_discovery_backs_off_and_abandons_unreachable_destinations() {
+	new_test_ext_with_balances_and_xcm_version(vec![], None).execute_with(|| {
+		let remote: MultiLocation = Parachain(1000).into();
+		XcmPallet::note_unknown_version(&remote);
+		set_send_xcm_artificial_failure(true);
+
+		// failed requests are retried after 2, 4, 8 and 16 blocks
+		let mut now = 1;
+		for attempts in 1..MAX_VERSION_DISCOVERY_ATTEMPTS {
+			let retry_at = now + (1 << attempts);
+			XcmPallet::on_initialize(now);
+			assert_eq!(
+				VersionDiscoveryRetries::<Test>::get(remote.into_versioned()),
+				Some((attempts, retry_at))
+			);
+			XcmPallet::on_initialize(retry_at - 1);
+			assert_eq!(
+				VersionDiscoveryRetries::<Test>::get(remote.into_versioned()),
+				Some((attempts, retry_at))
+			);
+			now = retry_at;
+		}
+		assert_eq!(VersionDiscoveryQueue::<Test>::get().into_inner(), vec![(remote.into(), 1)]);
+
+		// and abandoned after the last attempt fails
+		XcmPallet::on_initialize(now);
+		assert_eq!(VersionDiscoveryQueue::<Test>::get().into_inner(), vec![]);
+		assert_eq!(VersionDiscoveryRetries::<Test>::get(remote.into_versioned()), None);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::XcmPallet(crate::Event::VersionDiscoveryAbandoned {
+				destination: remote,
+				attempts: MAX_VERSION_DISCOVERY_ATTEMPTS,
+			})
+		);
+
+		// a destination which becomes reachable is requested and removed from the queue
+		XcmPallet::note_unknown_version(&remote);
+		XcmPallet::on_initialize(now + 1);
+		assert!(VersionDiscoveryRetries::<Test>::get(remote.into_versioned()).is_some());
+		set_send_xcm_artificial_failure(false);
+		XcmPallet::on_initialize(now + 3);
+		assert_eq!(VersionDiscoveryQueue::<Test>::get().into_inner(), vec![]);
+		assert_eq!(VersionDiscoveryRetries::<Test>::get(remote.into_versioned()), None);
+		assert!(VersionNotifiers::<Test>::contains_key(XCM_VERSION, remote.into_versioned()));
+	});
+}
+
+#[test]
+fn throttle_xcm_send_limits_calls_per_period() {
+	use crate::ThrottleXcmSend;
+	use frame_support::{dispatch::DispatchInfo, parameter_types, traits::Equals};
+	use sp_runtime::{
+		traits::SignedExtension,
+		transaction_validity::{InvalidTransaction, TransactionValidityError},
+	};
+
+	parameter_types! {
+		pub const Period: u64 = 10;
+		pub const Quota: u32 = 2;
+		pub const Exempt: AccountId = BOB;
+	}
+	type Throttle = ThrottleXcmSend<Test, Period, Quota, Equals<Exempt>>;
+
+	let send = RuntimeCall::XcmPallet(crate::Call::send {
+		dest: Box::new(Parachain(OTHER_PARA_ID).into_versioned()),
+		message: Box::new(VersionedXcm::from(Xcm(vec![ClearOrigin]))),
+	});
+	let other = RuntimeCall::XcmPallet(crate::Call::force_suspension { suspended: true });
+	let info = DispatchInfo::default();
+	let exhausted = TransactionValidityError::Invalid(InvalidTransaction::ExhaustsResources);
+
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		System::set_block_number(12);
+		for _ in 0..2 {
+			assert_ok!(Throttle::new().validate(&ALICE, &send, &info, 0));
+			assert_ok!(Throttle::new().pre_dispatch(&ALICE, &send, &info, 0));
+		}
+		assert_eq!(Throttle::new().validate(&ALICE, &send, &info, 0), Err(exhausted));
+		assert_eq!(Throttle::new().pre_dispatch(&ALICE, &send, &info, 0), Err(exhausted));
+		// other calls and exempt accounts are not throttled
+		assert_ok!(Throttle::new().pre_dispatch(&ALICE, &other, &info, 0));
+		for _ in 0..3 {
+			assert_ok!(Throttle::new().pre_dispatch(&BOB, &send, &info, 0));
+		}
+
+		// the quota is still used up until the end of the period
+		System::set_block_number(19);
+		assert_eq!(Throttle::new().pre_dispatch(&ALICE, &send, &info, 0), Err(exhausted));
+
+		// and is restored with the next period
+		System::set_block_number(20);
+		assert_ok!(Throttle::new().pre_dispatch(&ALICE, &send, &info, 0));
+		assert_eq!(crate::XcmSendQuotaUsed::<Test>::get(&ALICE), Some((20, 1)));
+	});
+}
@@ -0,0 +1,144 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A transaction extension throttling the XCMs sent by signed accounts.
+
+use crate::{Call, Config, XcmSendQuotaUsed};
+use codec::{Decode, Encode};
+use frame_support::{
+	traits::{Contains, Get, IsSubType},
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, One, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+};
+use sp_std::marker::PhantomData;
+
+/// Limits the number of `pallet-xcm` send and transfer calls an account may dispatch.
+///
+/// Every account not contained in `Exempt` may dispatch at most `Quota` of `send`,
+/// `teleport_assets`, `reserve_transfer_assets`, their `limited_` variants and `transfer_assets`
+/// per period of `Period` blocks. Further calls are rejected as exhausting the resources until the
+/// next period starts. Use the session length as `Period` to throttle per session.
+///
+/// Only calls signed by the account are counted, not the ones it dispatches through wrappers like
+/// `pallet-utility` or `pallet-proxy`. Runtimes relying on this extension should keep the throttled
+/// calls out of those wrappers through their call filters.
+#[derive(
+	Encode, Decode, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(T, Period, Quota, Exempt))]
+pub struct ThrottleXcmSend<T, Period, Quota, Exempt>(PhantomData<(T, Period, Quota, Exempt)>);
+
+impl<T, Period, Quota, Exempt> ThrottleXcmSend<T, Period, Quota, Exempt> {
+	/// Create new `SignedExtension` to throttle XCM send and transfer calls.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T, Period, Quota, Exempt> ThrottleXcmSend<T, Period, Quota, Exempt>
+where
+	T: Config,
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+	Period: Get<BlockNumberFor<T>>,
+	Quota: Get<u32>,
+	Exempt: Contains<T::AccountId>,
+{
+	/// Returns the start of the current period and the number of throttled calls `who` made in it
+	/// including `call`, or `None` if `call` is not throttled for `who`.
+	///
+	/// Fails if `who` has already used up its quota for the current period.
+	fn quota_used(
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+	) -> Result<Option<(BlockNumberFor<T>, u32)>, TransactionValidityError> {
+		let throttled = matches!(
+			call.is_sub_type(),
+			Some(
+				Call::send { .. } |
+					Call::teleport_assets { .. } |
+					Call::reserve_transfer_assets { .. } |
+					Call::limited_teleport_assets { .. } |
+					Call::limited_reserve_transfer_assets { .. } |
+					Call::transfer_assets { .. }
+			)
+		);
+		if !throttled || Exempt::contains(who) {
+			return Ok(None)
+		}
+
+		let period = Period::get().max(One::one());
+		let now = frame_system::Pallet::<T>::block_number();
+		let period_start = now - now % period;
+		let used = match XcmSendQuotaUsed::<T>::get(who) {
+			Some((start, used)) if start == period_start => used,
+			_ => 0,
+		};
+		if used >= Quota::get() {
+			return Err(InvalidTransaction::ExhaustsResources.into())
+		}
+		Ok(Some((period_start, used.saturating_add(1))))
+	}
+}
+
+impl<T, Period, Quota, Exempt> SignedExtension for ThrottleXcmSend<T, Period, Quota, Exempt>
+where
+	T: Config + Send + Sync,
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+	Period: Get<BlockNumberFor<T>> + Send + Sync + 'static,
+	Quota: Get<u32> + Send + Sync + 'static,
+	Exempt: Contains<T::AccountId> + Send + Sync + 'static,
+{
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+	const IDENTIFIER: &'static str = "ThrottleXcmSend";
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		if let Some(used) = Self::quota_used(who, call)? {
+			XcmSendQuotaUsed::<T>::insert(who, used);
+		}
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		Self::quota_used(who, call)?;
+		Ok(ValidTransaction::default())
+	}
+}
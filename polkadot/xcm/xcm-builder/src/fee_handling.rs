@@ -17,7 +17,7 @@
 use core::marker::PhantomData;
 use frame_support::traits::{Contains, Get};
 use xcm::prelude::*;
-use xcm_executor::traits::{FeeManager, FeeReason, TransactAsset};
+use xcm_executor::traits::{FeeManager, FeeReason, TransactAsset, XcmAssetTransfers};
 
 /// Handles the fees that are taken by certain XCM instructions.
 pub trait HandleFee {
@@ -119,3 +119,86 @@ impl<
 		MultiAssets::new()
 	}
 }
+
+/// Acquires assets required to pay delivery fees on behalf of some location, by exchanging
+/// other assets that the location holds.
+pub trait AcquireFees {
+	/// Make sure that `fees` can be withdrawn from the `origin`, e.g. by swapping some other
+	/// asset owned by the `origin` into the assets of `fees`.
+	///
+	/// Returns an error if the `fees` can't be acquired. In this case no changes should be made.
+	fn acquire_fees(origin: &MultiLocation, fees: &MultiAssets) -> Result<(), XcmError>;
+}
+
+// Default `AcquireFees` implementation that never acquires anything.
+impl AcquireFees for () {
+	fn acquire_fees(_: &MultiLocation, _: &MultiAssets) -> Result<(), XcmError> {
+		Err(XcmError::FeesNotMet)
+	}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(1, 30)]
+impl AcquireFees for Tuple {
+	fn acquire_fees(origin: &MultiLocation, fees: &MultiAssets) -> Result<(), XcmError> {
+		for_tuples!( #(
+			if Tuple::acquire_fees(origin, fees).is_ok() {
+				return Ok(());
+			}
+		)* );
+
+		Err(XcmError::FeesNotMet)
+	}
+}
+
+/// An `ExecuteXcm` implementation that wraps the `Executor` and, when the origin is unable to
+/// pay the delivery fees in the requested assets (usually the native token), tries to acquire
+/// them using `FeesAcquirer` before charging the fees again.
+///
+/// This allows e.g. users that only hold a sufficient asset to pay for delivery of messages
+/// sent using `pallet-xcm`. It is meant to be used as `pallet_xcm::Config::XcmExecutor`.
+pub struct ChargeFeesWithSwapFallback<Executor, FeesAcquirer>(
+	PhantomData<(Executor, FeesAcquirer)>,
+);
+
+impl<Call, Executor: ExecuteXcm<Call>, FeesAcquirer: AcquireFees> ExecuteXcm<Call>
+	for ChargeFeesWithSwapFallback<Executor, FeesAcquirer>
+{
+	type Prepared = Executor::Prepared;
+
+	fn prepare(message: Xcm<Call>) -> Result<Self::Prepared, Xcm<Call>> {
+		Executor::prepare(message)
+	}
+
+	fn execute(
+		origin: impl Into<MultiLocation>,
+		pre: Self::Prepared,
+		id: &mut XcmHash,
+		weight_credit: Weight,
+	) -> Outcome {
+		Executor::execute(origin, pre, id, weight_credit)
+	}
+
+	fn charge_fees(origin: impl Into<MultiLocation>, fees: MultiAssets) -> XcmResult {
+		let origin = origin.into();
+		match Executor::charge_fees(origin, fees.clone()) {
+			Ok(()) => Ok(()),
+			Err(error) => {
+				log::trace!(
+					target: "xcm::fees",
+					"Unable to charge fees {:?} from {:?}: {:?}. Trying to acquire them.",
+					fees, origin, error,
+				);
+				FeesAcquirer::acquire_fees(&origin, &fees).map_err(|_| error)?;
+				Executor::charge_fees(origin, fees)
+			},
+		}
+	}
+}
+
+impl<Executor: XcmAssetTransfers, FeesAcquirer> XcmAssetTransfers
+	for ChargeFeesWithSwapFallback<Executor, FeesAcquirer>
+{
+	type IsReserve = Executor::IsReserve;
+	type IsTeleporter = Executor::IsTeleporter;
+	type AssetTransactor = Executor::AssetTransactor;
+}
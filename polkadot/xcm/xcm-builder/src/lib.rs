@@ -69,7 +69,8 @@ pub use currency_adapter::CurrencyAdapter;
 
 mod fee_handling;
 pub use fee_handling::{
-	deposit_or_burn_fee, HandleFee, XcmFeeManagerFromComponents, XcmFeeToAccount,
+	deposit_or_burn_fee, AcquireFees, ChargeFeesWithSwapFallback, HandleFee,
+	XcmFeeManagerFromComponents, XcmFeeToAccount,
 };
 
 mod fungibles_adapter;
@@ -15,6 +15,7 @@
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
 use super::*;
+use xcm_executor::conservation::{assert_assets_conserved, record_assets_flow};
 
 #[test]
 fn exchange_asset_should_work() {
@@ -209,6 +210,69 @@ fn burn_should_work() {
 	assert_eq!(sent_xcm(), vec![]);
 }
 
+#[test]
+fn paying_reserve_deposit_conserves_assets() {
+	AllowPaidFrom::set(vec![Parent.into()]);
+	add_reserve(Parent.into(), (Parent, WildFungible).into());
+	WeightPrice::set((Parent.into(), 1_000_000_000_000, 1024 * 1024));
+
+	let fees = (Parent, 60u128).into();
+	let message = Xcm(vec![
+		ReserveAssetDeposited((Parent, 100u128).into()),
+		BuyExecution { fees, weight_limit: Limited(Weight::from_parts(30, 30)) },
+		DepositAsset { assets: AllCounted(1).into(), beneficiary: Here.into() },
+	]);
+	let hash = fake_message_hash(&message);
+	let weight_limit = Weight::from_parts(50, 50);
+	let (r, flow) = record_assets_flow(|| {
+		XcmExecutor::<TestConfig>::execute_xcm(Parent, message, hash, weight_limit)
+	});
+	assert_eq!(r, Outcome::Complete(Weight::from_parts(30, 30)));
+	assert!(flow.is_conserved());
+	assert_eq!(flow.minted, Assets::from(MultiAsset::from((Parent, 100u128))));
+	assert_eq!(flow.deposited, Assets::from(MultiAsset::from((Parent, 40u128))));
+	assert_eq!(flow.fees, Assets::from(MultiAsset::from((Parent, 60u128))));
+	assert!(flow.trapped.is_empty());
+}
+
+#[test]
+fn burnt_and_trapped_assets_are_recorded() {
+	AllowUnpaidFrom::set(vec![X1(Parachain(1)).into()]);
+	add_asset(Parachain(1), (Here, 1000));
+	// burn 100, deposit 800 and leave 100 in holding to be trapped.
+	let message = Xcm(vec![
+		WithdrawAsset((Here, 1000u128).into()),
+		BurnAsset((Here, 100u128).into()),
+		DepositAsset { assets: Definite((Here, 800u128).into()), beneficiary: Parachain(1).into() },
+	]);
+	let hash = fake_message_hash(&message);
+	let r = assert_assets_conserved(|| {
+		XcmExecutor::<TestConfig>::execute_xcm(
+			Parachain(1),
+			message,
+			hash,
+			Weight::from_parts(50, 50),
+		)
+	});
+	assert_eq!(r, Outcome::Complete(Weight::from_parts(30, 30)));
+
+	let (_, flow) = record_assets_flow(|| {
+		let message =
+			Xcm(vec![WithdrawAsset((Here, 800u128).into()), BurnAsset((Here, 300u128).into())]);
+		let hash = fake_message_hash(&message);
+		XcmExecutor::<TestConfig>::execute_xcm(
+			Parachain(1),
+			message,
+			hash,
+			Weight::from_parts(50, 50),
+		)
+	});
+	assert!(flow.is_conserved());
+	assert_eq!(flow.minted, Assets::from(MultiAsset::from((Here, 800u128))));
+	assert_eq!(flow.burnt, Assets::from(MultiAsset::from((Here, 300u128))));
+	assert_eq!(flow.trapped, Assets::from(MultiAsset::from((Here, 500u128))));
+}
+
 #[test]
 fn basic_asset_trap_should_work() {
 	// we'll let them have message execution for free.
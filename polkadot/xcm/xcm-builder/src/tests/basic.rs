@@ -104,3 +104,18 @@ fn code_registers_should_work() {
 	assert_eq!(asset_list(Here), vec![(Here, 1u128).into()]);
 	assert_eq!(sent_xcm(), vec![]);
 }
+
+#[test]
+fn hrmp_notifications_are_ignored_by_default() {
+	AllowUnpaidFrom::set(vec![Parent.into()]);
+	let message = Xcm(vec![
+		HrmpNewChannelOpenRequest { sender: 1000, max_message_size: 1024, max_capacity: 8 },
+		HrmpChannelAccepted { recipient: 1000 },
+		HrmpChannelClosing { initiator: 1000, sender: 1000, recipient: 2000 },
+	]);
+	let hash = fake_message_hash(&message);
+	let r =
+		XcmExecutor::<TestConfig>::execute_xcm(Parent, message, hash, Weight::from_parts(30, 30));
+	assert_eq!(r, Outcome::Complete(Weight::from_parts(30, 30)));
+	assert_eq!(sent_xcm(), vec![]);
+}
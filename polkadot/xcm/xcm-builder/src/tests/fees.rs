@@ -0,0 +1,82 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Acquires native fees by exchanging the same amount of the relay chain asset 1:1.
+pub struct ExchangeParentAssetForFees;
+impl AcquireFees for ExchangeParentAssetForFees {
+	fn acquire_fees(origin: &MultiLocation, fees: &MultiAssets) -> Result<(), XcmError> {
+		for fee in fees.inner() {
+			let MultiAsset { id: Concrete(MultiLocation { parents: 0, interior: Here }), fun } =
+				fee
+			else {
+				return Err(XcmError::FeesNotMet)
+			};
+			let Fungible(amount) = fun else { return Err(XcmError::FeesNotMet) };
+			TestAssetTransactor::withdraw_asset(&(Parent, *amount).into(), origin, None)?;
+			TestAssetTransactor::deposit_asset(fee, origin, None)?;
+		}
+		Ok(())
+	}
+}
+
+type ExecutorWithSwapFallback =
+	ChargeFeesWithSwapFallback<XcmExecutor<TestConfig>, ExchangeParentAssetForFees>;
+
+#[test]
+fn charge_fees_with_native_asset_does_not_acquire_fees() {
+	add_asset(Parachain(1), (Here, 100));
+	add_asset(Parachain(1), (Parent, 100));
+
+	assert_eq!(ExecutorWithSwapFallback::charge_fees(Parachain(1), (Here, 10).into()), Ok(()));
+	assert_eq!(asset_list(Parachain(1)), vec![(Here, 90).into(), (Parent, 100).into()]);
+}
+
+#[test]
+fn charge_fees_acquires_missing_fees() {
+	add_asset(Parachain(1), (Parent, 100));
+
+	// the executor alone is not able to charge fees
+	assert_eq!(
+		XcmExecutor::<TestConfig>::charge_fees(Parachain(1), (Here, 10).into()),
+		Err(XcmError::NotWithdrawable),
+	);
+	assert_eq!(asset_list(Parachain(1)), vec![(Parent, 100).into()]);
+
+	// while the wrapper acquires them first
+	assert_eq!(ExecutorWithSwapFallback::charge_fees(Parachain(1), (Here, 10).into()), Ok(()));
+	assert_eq!(asset_list(Parachain(1)), vec![(Parent, 90).into()]);
+}
+
+#[test]
+fn charge_fees_fails_with_original_error_if_fees_cannot_be_acquired() {
+	add_asset(Parachain(1), (Parent, 5));
+
+	assert_eq!(
+		ExecutorWithSwapFallback::charge_fees(Parachain(1), (Here, 10).into()),
+		Err(XcmError::NotWithdrawable),
+	);
+	assert_eq!(asset_list(Parachain(1)), vec![(Parent, 5).into()]);
+
+	assert_eq!(
+		ChargeFeesWithSwapFallback::<XcmExecutor<TestConfig>, ()>::charge_fees(
+			Parachain(1),
+			(Here, 10).into()
+		),
+		Err(XcmError::NotWithdrawable),
+	);
+}
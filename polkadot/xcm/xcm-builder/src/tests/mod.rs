@@ -32,6 +32,7 @@ mod barriers;
 mod basic;
 mod bridging;
 mod expecting;
+mod fees;
 mod locking;
 mod origins;
 mod pay;
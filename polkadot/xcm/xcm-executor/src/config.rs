@@ -16,8 +16,9 @@
 
 use crate::traits::{
 	AssetExchange, AssetLock, CallDispatcher, ClaimAssets, ConvertOrigin, DropAssets, ExportXcm,
-	FeeManager, OnResponse, ShouldExecute, TransactAsset, VersionChangeNotifier, WeightBounds,
-	WeightTrader,
+	FeeManager, HandleHrmpChannelAccepted, HandleHrmpChannelClosing,
+	HandleHrmpNewChannelOpenRequest, OnResponse, ShouldExecute, TransactAsset,
+	VersionChangeNotifier, WeightBounds, WeightTrader,
 };
 use frame_support::{
 	dispatch::{GetDispatchInfo, Parameter, PostDispatchInfo},
@@ -111,4 +112,13 @@ pub trait Config {
 	/// Use this type to explicitly whitelist calls that cannot undergo recursion. This is a
 	/// temporary measure until we properly account for proof size weights for XCM instructions.
 	type SafeCallFilter: Contains<Self::RuntimeCall>;
+
+	/// A handler for `HrmpNewChannelOpenRequest` notifications. Use `()` to ignore them.
+	type HrmpNewChannelOpenRequestHandler: HandleHrmpNewChannelOpenRequest;
+
+	/// A handler for `HrmpChannelAccepted` notifications. Use `()` to ignore them.
+	type HrmpChannelAcceptedHandler: HandleHrmpChannelAccepted;
+
+	/// A handler for `HrmpChannelClosing` notifications. Use `()` to ignore them.
+	type HrmpChannelClosingHandler: HandleHrmpChannelClosing;
 }
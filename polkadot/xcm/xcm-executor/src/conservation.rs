@@ -0,0 +1,117 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Recording of the assets flowing through the XCM executor, for use in tests.
+//!
+//! Running XCM executions inside [`record_assets_flow`] records every asset the executor places
+//! into the holding register or takes out of it, including the assets it moves through the asset
+//! transactor. Outside of it nothing is recorded. [`assert_assets_conserved`] builds on it to
+//! catch configurations which silently mint or burn assets during XCM execution.
+
+use crate::Assets;
+use sp_runtime::RuntimeDebug;
+use xcm::latest::prelude::*;
+
+environmental::environmental!(assets_flow: AssetsFlow);
+
+/// The assets which flowed through the XCM executor while recording.
+#[derive(Default, Clone, RuntimeDebug, Eq, PartialEq)]
+pub struct AssetsFlow {
+	/// Assets placed into holding: withdrawn from accounts, received as reserve deposits or
+	/// teleports, claimed from asset traps or received from an exchange.
+	pub minted: Assets,
+	/// Assets taken out of holding and deposited to accounts, sent to other chains or given to an
+	/// exchange.
+	pub deposited: Assets,
+	/// Assets burnt with `BurnAsset`.
+	pub burnt: Assets,
+	/// Assets left in holding at the end of execution and handed to the asset trap.
+	pub trapped: Assets,
+	/// Assets paid for execution and delivery, net of the refunds of unused weight.
+	pub fees: Assets,
+}
+
+impl AssetsFlow {
+	/// Returns `true` if all minted assets were either deposited, burnt, trapped or paid as fees.
+	pub fn is_conserved(&self) -> bool {
+		let mut spent = self.deposited.clone();
+		spent.subsume_assets(self.burnt.clone());
+		spent.subsume_assets(self.trapped.clone());
+		spent.subsume_assets(self.fees.clone());
+		spent == self.minted
+	}
+}
+
+/// Execute `f`, recording the assets flowing through every XCM executed by it.
+pub fn record_assets_flow<R>(f: impl FnOnce() -> R) -> (R, AssetsFlow) {
+	let mut flow = AssetsFlow::default();
+	let result = assets_flow::using(&mut flow, f);
+	(result, flow)
+}
+
+/// Execute `f` and panic if the XCMs it executes did not conserve assets.
+pub fn assert_assets_conserved<R>(f: impl FnOnce() -> R) -> R {
+	let (result, flow) = record_assets_flow(f);
+	assert!(flow.is_conserved(), "XCM execution did not conserve assets: {:?}", flow);
+	result
+}
+
+pub(crate) fn note_minted(assets: impl IntoIterator<Item = MultiAsset>) {
+	assets_flow::with(|flow| assets.into_iter().for_each(|asset| flow.minted.subsume(asset)));
+}
+
+pub(crate) fn note_deposited(assets: impl IntoIterator<Item = MultiAsset>) {
+	assets_flow::with(|flow| assets.into_iter().for_each(|asset| flow.deposited.subsume(asset)));
+}
+
+/// Note `assets` taken out of holding to be deposited went back into it instead.
+pub(crate) fn note_deposit_returned(assets: impl IntoIterator<Item = MultiAsset>) {
+	assets_flow::with(|flow| {
+		for asset in assets {
+			flow.deposited =
+				flow.deposited.clone().checked_sub(asset.clone()).unwrap_or_else(|deposited| {
+					// more returned than deposited, so it is minted
+					flow.minted.subsume(asset);
+					deposited
+				});
+		}
+	});
+}
+
+pub(crate) fn note_burnt(assets: impl IntoIterator<Item = MultiAsset>) {
+	assets_flow::with(|flow| assets.into_iter().for_each(|asset| flow.burnt.subsume(asset)));
+}
+
+pub(crate) fn note_trapped(assets: impl IntoIterator<Item = MultiAsset>) {
+	assets_flow::with(|flow| assets.into_iter().for_each(|asset| flow.trapped.subsume(asset)));
+}
+
+pub(crate) fn note_fees(assets: impl IntoIterator<Item = MultiAsset>) {
+	assets_flow::with(|flow| assets.into_iter().for_each(|asset| flow.fees.subsume(asset)));
+}
+
+/// Note `assets` paid as fees were refunded into holding.
+pub(crate) fn note_fees_refunded(assets: impl IntoIterator<Item = MultiAsset>) {
+	assets_flow::with(|flow| {
+		for asset in assets {
+			flow.fees = flow.fees.clone().checked_sub(asset.clone()).unwrap_or_else(|fees| {
+				// more refunded than paid, so it is minted
+				flow.minted.subsume(asset);
+				fees
+			});
+		}
+	});
+}
@@ -31,14 +31,17 @@ use xcm::latest::prelude::*;
 pub mod traits;
 use traits::{
 	validate_export, AssetExchange, AssetLock, CallDispatcher, ClaimAssets, ConvertOrigin,
-	DropAssets, Enact, ExportXcm, FeeManager, FeeReason, OnResponse, Properties, ShouldExecute,
-	TransactAsset, VersionChangeNotifier, WeightBounds, WeightTrader, XcmAssetTransfers,
+	DropAssets, Enact, ExportXcm, FeeManager, FeeReason, HandleHrmpChannelAccepted,
+	HandleHrmpChannelClosing, HandleHrmpNewChannelOpenRequest, OnResponse, Properties,
+	ShouldExecute, TransactAsset, VersionChangeNotifier, WeightBounds, WeightTrader,
+	XcmAssetTransfers,
 };
 
 mod assets;
 pub use assets::Assets;
 mod config;
 pub use config::Config;
+pub mod conservation;
 
 /// A struct to specify how fees are being paid.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -248,6 +251,8 @@ impl<Config: config::Config> ExecuteXcm<Config::RuntimeCall> for XcmExecutor<Con
 			for asset in fees.inner() {
 				Config::AssetTransactor::withdraw_asset(&asset, &origin, None)?;
 			}
+			conservation::note_minted(fees.inner().iter().cloned());
+			conservation::note_fees(fees.inner().iter().cloned());
 			Config::FeeManager::handle_fee(fees, None, FeeReason::ChargeFees);
 		}
 		Ok(())
@@ -379,6 +384,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 				self.holding, self.context, self.original_origin,
 			);
 			let effective_origin = self.context.origin.as_ref().unwrap_or(&self.original_origin);
+			conservation::note_trapped(self.holding.assets_iter());
 			let trap_weight =
 				Config::AssetTrap::drop_assets(effective_origin, self.holding, &self.context);
 			weight_used.saturating_accrue(trap_weight);
@@ -462,6 +468,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 		if current_surplus.any_gt(Weight::zero()) {
 			self.total_refunded.saturating_accrue(current_surplus);
 			if let Some(w) = self.trader.refund_weight(current_surplus, &self.context) {
+				conservation::note_fees_refunded([w.clone()]);
 				self.subsume_asset(w)?;
 			}
 		}
@@ -484,6 +491,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 				let origin = *self.origin_ref().ok_or(XcmError::BadOrigin)?;
 				for asset in assets.into_inner().into_iter() {
 					Config::AssetTransactor::withdraw_asset(&asset, &origin, Some(&self.context))?;
+					conservation::note_minted([asset.clone()]);
 					self.subsume_asset(asset)?;
 				}
 				Ok(())
@@ -497,6 +505,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 						Config::IsReserve::contains(&asset, &origin),
 						XcmError::UntrustedReserveLocation
 					);
+					conservation::note_minted([asset.clone()]);
 					self.subsume_asset(asset)?;
 				}
 				Ok(())
@@ -545,6 +554,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 				}
 				for asset in assets.into_inner().into_iter() {
 					Config::AssetTransactor::check_in(&origin, &asset, &self.context);
+					conservation::note_minted([asset.clone()]);
 					self.subsume_asset(asset)?;
 				}
 				Ok(())
@@ -626,6 +636,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 						&beneficiary,
 						Some(&self.context),
 					)?;
+					conservation::note_deposited([asset]);
 				}
 				Ok(())
 			},
@@ -633,6 +644,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 				let deposited = self.holding.saturating_take(assets);
 				for asset in deposited.assets_iter() {
 					Config::AssetTransactor::deposit_asset(&asset, &dest, Some(&self.context))?;
+					conservation::note_deposited([asset]);
 				}
 				// Note that we pass `None` as `maybe_failed_bin` and drop any assets which cannot
 				// be reanchored  because we have already called `deposit_asset` on all assets.
@@ -643,13 +655,14 @@ impl<Config: config::Config> XcmExecutor<Config> {
 				Ok(())
 			},
 			InitiateReserveWithdraw { assets, reserve, xcm } => {
+				let assets = self.holding.saturating_take(assets);
+				conservation::note_deposited(assets.assets_iter());
 				// Note that here we are able to place any assets which could not be reanchored
 				// back into Holding.
-				let assets = Self::reanchored(
-					self.holding.saturating_take(assets),
-					&reserve,
-					Some(&mut self.holding),
-				);
+				let mut unanchored = Assets::new();
+				let assets = Self::reanchored(assets, &reserve, Some(&mut unanchored));
+				conservation::note_deposit_returned(unanchored.assets_iter());
+				self.holding.subsume_assets(unanchored);
 				let mut message = vec![WithdrawAsset(assets), ClearOrigin];
 				message.extend(xcm.0.into_iter());
 				self.send(reserve, Xcm(message), FeeReason::InitiateReserveWithdraw)?;
@@ -668,6 +681,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 				for asset in assets.assets_iter() {
 					Config::AssetTransactor::check_out(&dest, &asset, &self.context);
 				}
+				conservation::note_deposited(assets.assets_iter());
 				// Note that we pass `None` as `maybe_failed_bin` and drop any assets which cannot
 				// be reanchored  because we have already checked all assets out.
 				let assets = Self::reanchored(assets, &dest, None);
@@ -698,7 +712,9 @@ impl<Config: config::Config> XcmExecutor<Config> {
 					// pay for `weight` using up to `fees` of the holding register.
 					let max_fee =
 						self.holding.try_take(fees.into()).map_err(|_| XcmError::NotHoldingFees)?;
+					conservation::note_fees(max_fee.assets_iter());
 					let unspent = self.trader.buy_weight(weight, max_fee, &self.context)?;
+					conservation::note_fees_refunded(unspent.assets_iter());
 					self.subsume_assets(unspent)?;
 				}
 				Ok(())
@@ -729,6 +745,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 				let ok = Config::AssetClaims::claim_assets(origin, &ticket, &assets, &self.context);
 				ensure!(ok, XcmError::UnknownClaim);
 				for asset in assets.into_inner().into_iter() {
+					conservation::note_minted([asset.clone()]);
 					self.subsume_asset(asset)?;
 				}
 				Ok(())
@@ -752,7 +769,8 @@ impl<Config: config::Config> XcmExecutor<Config> {
 				Config::SubscriptionService::stop(origin, &self.context)
 			},
 			BurnAsset(assets) => {
-				self.holding.saturating_take(assets.into());
+				let burnt = self.holding.saturating_take(assets.into());
+				conservation::note_burnt(burnt.into_assets_iter());
 				Ok(())
 			},
 			ExpectAsset(assets) =>
@@ -900,10 +918,12 @@ impl<Config: config::Config> XcmExecutor<Config> {
 			},
 			ExchangeAsset { give, want, maximal } => {
 				let give = self.holding.saturating_take(give);
+				conservation::note_deposited(give.assets_iter());
 				let r =
 					Config::AssetExchanger::exchange_asset(self.origin_ref(), give, &want, maximal);
 				let completed = r.is_ok();
 				let received = r.unwrap_or_else(|a| a);
+				conservation::note_minted(received.assets_iter());
 				for asset in received.into_assets_iter() {
 					self.holding.subsume(asset);
 				}
@@ -941,9 +961,16 @@ impl<Config: config::Config> XcmExecutor<Config> {
 				);
 				Ok(())
 			},
-			HrmpNewChannelOpenRequest { .. } => Err(XcmError::Unimplemented),
-			HrmpChannelAccepted { .. } => Err(XcmError::Unimplemented),
-			HrmpChannelClosing { .. } => Err(XcmError::Unimplemented),
+			HrmpNewChannelOpenRequest { sender, max_message_size, max_capacity } =>
+				Config::HrmpNewChannelOpenRequestHandler::handle(
+					sender,
+					max_message_size,
+					max_capacity,
+				),
+			HrmpChannelAccepted { recipient } =>
+				Config::HrmpChannelAcceptedHandler::handle(recipient),
+			HrmpChannelClosing { initiator, sender, recipient } =>
+				Config::HrmpChannelClosingHandler::handle(initiator, sender, recipient),
 		}
 	}
 
@@ -964,10 +991,12 @@ impl<Config: config::Config> XcmExecutor<Config> {
 			for asset in fee.inner() {
 				Config::AssetTransactor::withdraw_asset(&asset, origin, Some(&self.context))?;
 			}
+			conservation::note_minted(fee.inner().iter().cloned());
 			fee
 		} else {
 			self.holding.try_take(fee.into()).map_err(|_| XcmError::NotHoldingFees)?.into()
 		};
+		conservation::note_fees(paid.inner().iter().cloned());
 		Config::FeeManager::handle_fee(paid, Some(&self.context), reason);
 		Ok(())
 	}
@@ -0,0 +1,38 @@
+async function run(nodeName, networkInfo, jsArgs) {
+    const {wsUri, userDefinedTypes} = networkInfo.nodesByName[nodeName];
+    const api = await zombie.connect(wsUri, userDefinedTypes);
+    const featureIndex = parseInt(jsArgs[0]);
+
+    await zombie.util.cryptoWaitReady();
+
+    // account to submit tx
+    const keyring = new zombie.Keyring({ type: "sr25519" });
+    const alice = keyring.addFromUri("//Alice");
+
+    await new Promise(async (resolve, reject) => {
+        const unsub = await api.tx.sudo
+          .sudo(api.tx.configuration.setNodeFeature(featureIndex, true))
+          .signAndSend(alice, (result) => {
+            console.log(`Current status is ${result.status}`);
+            if (result.status.isInBlock) {
+              console.log(
+                `Transaction included at blockHash ${result.status.asInBlock}`
+              );
+            } else if (result.status.isFinalized) {
+              console.log(
+                `Transaction finalized at blockHash ${result.status.asFinalized}`
+              );
+              unsub();
+              return resolve();
+            } else if (result.isError) {
+              console.log(`Transaction Error`);
+              unsub();
+              return reject();
+            }
+          });
+    });
+
+    return 0;
+}
+
+module.exports = { run }
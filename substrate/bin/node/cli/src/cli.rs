@@ -31,6 +31,10 @@ pub struct Cli {
 	#[clap(flatten)]
 	pub mixnet_params: sc_cli::MixnetParams,
 
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub statement_store_params: sc_cli::StatementStoreParams,
+
 	/// Disable automatic hardware benchmarks.
 	///
 	/// By default these benchmarks are automatically ran at startup and measure
@@ -91,9 +95,15 @@ pub enum Subcommand {
 	/// Export the state of a given block into a chain spec.
 	ExportState(sc_cli::ExportStateCmd),
 
+	/// Export a snapshot of the state of the latest finalized block into a directory.
+	ExportStateSnapshot(sc_cli::ExportStateSnapshotCmd),
+
 	/// Import blocks.
 	ImportBlocks(sc_cli::ImportBlocksCmd),
 
+	/// Start a new node at the block of a state snapshot.
+	ImportStateSnapshot(sc_cli::ImportStateSnapshotCmd),
+
 	/// Remove the whole chain.
 	PurgeChain(sc_cli::PurgeChainCmd),
 
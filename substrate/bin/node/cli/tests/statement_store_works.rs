@@ -0,0 +1,84 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(unix)]
+
+use assert_cmd::cargo::cargo_bin;
+use codec::Encode;
+use sp_core::Bytes;
+use sp_keyring::Sr25519Keyring;
+use sp_statement_store::Statement;
+use std::{
+	process::{Command, Stdio},
+	time::Duration,
+};
+use substrate_rpc_client::{rpc_params, ws_client, ClientT};
+
+use substrate_cli_test_utils as common;
+
+#[tokio::test]
+async fn statement_store_works() {
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let mut child = common::KillChildOnDrop(
+			Command::new(cargo_bin("substrate-node"))
+				.args(&[
+					"--dev",
+					"--tmp",
+					"--no-hardware-benchmarks",
+					"--statement-store-max-statements=100",
+					"--statement-store-purge-after=60",
+				])
+				.stdout(Stdio::piped())
+				.stderr(Stdio::piped())
+				.spawn()
+				.unwrap(),
+		);
+
+		let stderr = child.stderr.take().unwrap();
+		let ws_url = common::extract_info_from_output(stderr).0.ws_url;
+		common::wait_n_finalized_blocks(1, &ws_url).await;
+		let rpc = ws_client(&ws_url).await.unwrap();
+
+		// A statement signed by an endowed account is validated by the runtime and stored.
+		let mut statement = Statement::new();
+		statement.set_topic(0, [1; 32]);
+		statement.set_plain_data(b"hello".to_vec());
+		statement.sign_sr25519_private(&Sr25519Keyring::Alice.pair());
+		rpc.request::<(), _>("statement_submit", rpc_params![Bytes(statement.encode())])
+			.await
+			.unwrap();
+
+		let dump: Vec<Bytes> = rpc.request("statement_dump", rpc_params![]).await.unwrap();
+		assert_eq!(dump, vec![Bytes(statement.encode())]);
+		let broadcasts: Vec<Bytes> =
+			rpc.request("statement_broadcasts", rpc_params![vec![[1u8; 32]]]).await.unwrap();
+		assert_eq!(broadcasts, vec![Bytes(b"hello".to_vec())]);
+
+		// A statement without a proof is rejected.
+		let mut unsigned = Statement::new();
+		unsigned.set_plain_data(b"unsigned".to_vec());
+		assert!(rpc
+			.request::<(), _>("statement_submit", rpc_params![Bytes(unsigned.encode())])
+			.await
+			.is_err());
+
+		child.assert_still_running();
+		child.stop();
+	})
+	.await;
+}
@@ -59,4 +59,16 @@ pub enum InspectSubCmd {
 		#[arg(value_name = "BLOCK:INDEX or BYTES")]
 		input: String,
 	},
+	/// Print the size of the state and the number of storage items, grouped by pallet.
+	///
+	/// The storage prefixes are resolved to pallet names with the metadata of the runtime at the
+	/// inspected block. Child tries are not included.
+	StateSize {
+		/// Block at which the state is inspected, the best block by default.
+		///
+		/// Can be either a block hash (no 0x prefix) or a number. The state of historical blocks
+		/// is only available on archive nodes.
+		#[arg(long, value_name = "HASH or NUMBER")]
+		at: Option<String>,
+	},
 }
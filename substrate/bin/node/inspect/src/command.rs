@@ -20,11 +20,15 @@
 
 use crate::{
 	cli::{InspectCmd, InspectSubCmd},
-	Inspector,
+	state_size::{pallet_prefixes, StateSize},
+	BlockAddress, BlockAddressFor, Inspector,
 };
 use sc_cli::{CliConfiguration, ImportParams, Result, SharedParams};
+use sc_client_api::{CallExecutor, ExecutorProvider, StorageProvider};
 use sc_service::Configuration;
-use sp_runtime::traits::Block;
+use sp_blockchain::HeaderBackend;
+use sp_core::traits::CallContext;
+use sp_runtime::{generic::BlockId, traits::Block};
 
 type HostFunctions =
 	(sp_io::SubstrateHostFunctions, sp_statement_store::runtime_api::HostFunctions);
@@ -38,21 +42,44 @@ impl InspectCmd {
 	{
 		let executor = sc_service::new_wasm_executor::<HostFunctions>(&config);
 		let client = sc_service::new_full_client::<B, RA, _>(&config, None, executor)?;
-		let inspect = Inspector::<B>::new(client);
 
 		match &self.command {
 			InspectSubCmd::Block { input } => {
+				let inspect = Inspector::<B>::new(client);
 				let input = input.parse()?;
 				let res = inspect.block(input).map_err(|e| e.to_string())?;
 				println!("{res}");
 				Ok(())
 			},
 			InspectSubCmd::Extrinsic { input } => {
+				let inspect = Inspector::<B>::new(client);
 				let input = input.parse()?;
 				let res = inspect.extrinsic(input).map_err(|e| e.to_string())?;
 				println!("{res}");
 				Ok(())
 			},
+			InspectSubCmd::StateSize { at } => {
+				let hash =
+					match at.as_ref().map(|at| at.parse::<BlockAddressFor<B>>()).transpose()? {
+						None => client.info().best_hash,
+						Some(BlockAddress::Hash(hash)) => hash,
+						Some(BlockAddress::Number(number)) =>
+							client.expect_block_hash_from_id(&BlockId::number(number))?,
+						Some(BlockAddress::Bytes(_)) =>
+							return Err("Expected a block hash or number".into()),
+					};
+
+				let metadata = client.executor().call(
+					hash,
+					"Metadata_metadata",
+					&[],
+					CallContext::Offchain,
+				)?;
+				let pallets = pallet_prefixes(&metadata).map_err(|e| e.to_string())?;
+				let size = StateSize::from_pairs(client.storage_pairs(hash, None, None)?, &pallets);
+				println!("State size at {hash:?}:\n{size}");
+				Ok(())
+			},
 		}
 	}
 }
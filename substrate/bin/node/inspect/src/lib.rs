@@ -21,11 +21,14 @@
 //!
 //! The blocks and extrinsics can either be retrieved from the database (on-chain),
 //! or a raw SCALE-encoding can be provided.
+//!
+//! Additionally, the size of the state can be inspected per pallet, see [`state_size`].
 
 #![warn(missing_docs)]
 
 pub mod cli;
 pub mod command;
+pub mod state_size;
 
 use codec::{Decode, Encode};
 use sc_client_api::BlockBackend;
@@ -89,6 +92,9 @@ pub enum Error {
 	/// Given block has not been found.
 	#[error("{0}")]
 	NotFound(String),
+	/// The runtime metadata has an unsupported version.
+	#[error("Unsupported metadata version: {0}")]
+	UnsupportedMetadata(u32),
 }
 
 /// A helper trait to access block headers and bodies.
@@ -0,0 +1,188 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Size of the state grouped by the pallet that owns it.
+//!
+//! The storage of a pallet is stored under the `twox_128` hash of its storage prefix, which is
+//! resolved to the pallet name with the metadata of the runtime.
+
+use crate::Error;
+use codec::Decode;
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use sp_core::{
+	hexdisplay::HexDisplay,
+	storage::{StorageData, StorageKey},
+	twox_128,
+};
+use std::{collections::BTreeMap, fmt};
+
+/// Length of a pallet prefix.
+const PREFIX_LEN: usize = 16;
+/// Name of the group of the well-known keys, e.g. `:code`.
+const WELL_KNOWN: &str = "<well-known keys>";
+
+/// Size of the state under one prefix.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PrefixSize {
+	/// Number of storage items.
+	pub items: u64,
+	/// Total size of the keys in bytes.
+	pub key_bytes: u64,
+	/// Total size of the values in bytes.
+	pub value_bytes: u64,
+}
+
+impl PrefixSize {
+	fn add(&mut self, key: &[u8], value: &[u8]) {
+		self.items += 1;
+		self.key_bytes += key.len() as u64;
+		self.value_bytes += value.len() as u64;
+	}
+
+	/// Size of the keys and values in bytes.
+	pub fn total_bytes(&self) -> u64 {
+		self.key_bytes + self.value_bytes
+	}
+}
+
+/// Size of the state grouped by pallet.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StateSize {
+	/// The size of each group, keyed by the pallet name.
+	///
+	/// Keys with an unknown prefix are grouped by the hex encoded prefix.
+	pub groups: BTreeMap<String, PrefixSize>,
+	/// The size of the whole state.
+	pub total: PrefixSize,
+}
+
+impl StateSize {
+	/// Aggregates the size of the given storage pairs.
+	///
+	/// `pallets` maps the pallet prefixes to the names of the pallets, see [`pallet_prefixes`].
+	pub fn from_pairs(
+		pairs: impl Iterator<Item = (StorageKey, StorageData)>,
+		pallets: &BTreeMap<[u8; PREFIX_LEN], String>,
+	) -> Self {
+		let mut size = Self::default();
+		for (StorageKey(key), StorageData(value)) in pairs {
+			let group = if key.starts_with(b":") {
+				WELL_KNOWN.to_string()
+			} else {
+				key.get(..PREFIX_LEN)
+					.and_then(|prefix| pallets.get(prefix))
+					.cloned()
+					.unwrap_or_else(|| {
+						format!("0x{}", HexDisplay::from(&&key[..key.len().min(PREFIX_LEN)]))
+					})
+			};
+
+			size.groups.entry(group).or_default().add(&key, &value);
+			size.total.add(&key, &value);
+		}
+		size
+	}
+}
+
+impl fmt::Display for StateSize {
+	fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+		let mut groups =
+			self.groups.iter().map(|(name, size)| (name.as_str(), size)).collect::<Vec<_>>();
+		groups.sort_by_key(|(_, size)| std::cmp::Reverse(size.total_bytes()));
+		groups.push(("Total", &self.total));
+
+		writeln!(
+			fmt,
+			"{:<40} {:>12} {:>16} {:>16} {:>8}",
+			"Pallet", "Items", "Keys", "Values", "Share"
+		)?;
+		for (name, size) in groups {
+			let share = size.total_bytes() as f64 * 100.0 / self.total.total_bytes().max(1) as f64;
+			writeln!(
+				fmt,
+				"{:<40} {:>12} {:>16} {:>16} {:>7.2}%",
+				name, size.items, size.key_bytes, size.value_bytes, share
+			)?;
+		}
+		Ok(())
+	}
+}
+
+/// Maps the storage prefixes of the pallets to their names, using the encoded runtime metadata as
+/// returned by `Metadata_metadata`.
+pub fn pallet_prefixes(
+	encoded_metadata: &[u8],
+) -> Result<BTreeMap<[u8; PREFIX_LEN], String>, Error> {
+	// The runtime API returns the `OpaqueMetadata`.
+	let metadata = Vec::<u8>::decode(&mut &encoded_metadata[..])?;
+	let metadata = RuntimeMetadataPrefixed::decode(&mut &metadata[..])?;
+
+	let prefixes: Vec<(String, String)> = match metadata.1 {
+		RuntimeMetadata::V14(metadata) => metadata
+			.pallets
+			.into_iter()
+			.filter_map(|p| p.storage.map(|storage| (p.name, storage.prefix)))
+			.collect(),
+		RuntimeMetadata::V15(metadata) => metadata
+			.pallets
+			.into_iter()
+			.filter_map(|p| p.storage.map(|storage| (p.name, storage.prefix)))
+			.collect(),
+		metadata => return Err(Error::UnsupportedMetadata(metadata.version())),
+	};
+
+	Ok(prefixes
+		.into_iter()
+		.map(|(name, prefix)| (twox_128(prefix.as_bytes()), name))
+		.collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn state_size_groups_by_pallet() {
+		let pallets = [(twox_128(b"System"), "System".to_string())].into_iter().collect();
+		let system_key = [&twox_128(b"System")[..], &twox_128(b"Number")[..]].concat();
+		let unknown_key = [&twox_128(b"Unknown")[..], &b"key"[..]].concat();
+		let pairs = vec![
+			(StorageKey(b":code".to_vec()), StorageData(vec![0; 100])),
+			(StorageKey(system_key.clone()), StorageData(vec![0; 4])),
+			(StorageKey(system_key.clone()), StorageData(vec![0; 6])),
+			(StorageKey(unknown_key.clone()), StorageData(vec![0; 1])),
+		];
+
+		let size = StateSize::from_pairs(pairs.into_iter(), &pallets);
+
+		assert_eq!(
+			size.groups.get("System"),
+			Some(&PrefixSize { items: 2, key_bytes: 64, value_bytes: 10 })
+		);
+		assert_eq!(
+			size.groups.get(WELL_KNOWN),
+			Some(&PrefixSize { items: 1, key_bytes: 5, value_bytes: 100 })
+		);
+		let unknown = format!("0x{}", HexDisplay::from(&twox_128(b"Unknown")));
+		assert_eq!(
+			size.groups.get(&unknown),
+			Some(&PrefixSize { items: 1, key_bytes: 19, value_bytes: 1 })
+		);
+		assert_eq!(size.total, PrefixSize { items: 4, key_bytes: 88, value_bytes: 111 });
+	}
+}
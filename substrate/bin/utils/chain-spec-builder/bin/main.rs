@@ -17,8 +17,8 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use chain_spec_builder::{
-	generate_chain_spec_for_runtime, ChainSpecBuilder, ChainSpecBuilderCmd, ConvertToRawCmd,
-	UpdateCodeCmd, VerifyCmd,
+	generate_chain_spec_for_runtime, patch_genesis_in_chain_spec, verify_raw_chain_spec,
+	ChainSpecBuilder, ChainSpecBuilderCmd, ConvertToRawCmd, UpdateCodeCmd, VerifyCmd,
 };
 use clap::Parser;
 use sc_chain_spec::{update_code_in_json_chain_spec, GenericChainSpec};
@@ -71,6 +71,19 @@ fn main() -> Result<(), String> {
 			let _ = serde_json::from_str::<serde_json::Value>(&chain_spec.as_json(true)?)
 				.map_err(|e| format!("Conversion to json failed: {e}"))?;
 		},
+		ChainSpecBuilderCmd::PatchGenesis(ref cmd) => {
+			let chain_spec_json = patch_genesis_in_chain_spec(cmd)?;
+			fs::write(chain_spec_path, chain_spec_json).map_err(|err| err.to_string())?;
+		},
+		ChainSpecBuilderCmd::VerifyRaw(ref cmd) =>
+			if let Some(diff) = verify_raw_chain_spec(cmd)? {
+				println!(
+					"{}",
+					serde_json::to_string_pretty(&diff)
+						.map_err(|e| format!("to pretty failed: {e}"))?
+				);
+				return Err("raw chain spec does not match the runtime".into())
+			},
 	};
 	Ok(())
 }
@@ -68,8 +68,22 @@
 //! chain-spec-builder create -r runtime.wasm full full-genesis-config.json
 //! ```
 //! 
+//! ##### Patch the genesis config of an existing chain spec.
+//! ```text
+//! chain-spec-builder patch-genesis chain_spec.json patch.json
+//! ```
+//! 
+//! ##### Verify raw chain spec against the runtime.
+//!
+//! Check that the raw storage of given chain spec matches the storage built by the runtime
+//! (optionally using the provided genesis config patch). Differences are printed as JSON:
+//! ```text
+//! chain-spec-builder verify-raw -r runtime.wasm raw_chain_spec.json -p patch.json
+//! ```
+//! 
 //! ##### Extra tools.
-//! The `chain-spec-builder` provides also some extra utilities: [`VerifyCmd`], [`ConvertToRawCmd`], [`UpdateCodeCmd`].
+//! The `chain-spec-builder` provides also some extra utilities: [`VerifyCmd`], [`ConvertToRawCmd`],
+//! [`UpdateCodeCmd`], [`PatchGenesisCmd`], [`VerifyRawCmd`].
 //!
 //! [`sc-chain-spec`]: ../sc_chain_spec/index.html
 //! [`node-cli`]: ../node_cli/index.html
@@ -77,11 +91,11 @@
 //! [sp-genesis-builder-create]: ../sp_genesis_builder/trait.GenesisBuilder.html#method.create_default_config
 //! [sp-genesis-builder-build]: ../sp_genesis_builder/trait.GenesisBuilder.html#method.build_config
 
-use std::{fs, path::PathBuf};
+use std::{collections::BTreeSet, fs, path::PathBuf};
 
 use clap::{Parser, Subcommand};
-use sc_chain_spec::{GenericChainSpec, GenesisConfigBuilderRuntimeCaller};
-use serde_json::Value;
+use sc_chain_spec::{json_merge, GenericChainSpec, GenesisConfigBuilderRuntimeCaller};
+use serde_json::{json, Value};
 
 /// A utility to easily create a chain spec definition.
 #[derive(Debug, Parser)]
@@ -101,6 +115,8 @@ pub enum ChainSpecBuilderCmd {
 	Verify(VerifyCmd),
 	UpdateCode(UpdateCodeCmd),
 	ConvertToRaw(ConvertToRawCmd),
+	PatchGenesis(PatchGenesisCmd),
+	VerifyRaw(VerifyRawCmd),
 }
 
 /// Create a new chain spec by interacting with the provided runtime wasm blob.
@@ -187,6 +203,35 @@ pub struct VerifyCmd {
 	pub input_chain_spec: PathBuf,
 }
 
+/// Patches the genesis config of the provided input chain spec.
+///
+/// The patch is merged into the genesis config patch (or full genesis config) of the chain spec.
+/// Keys set to `null` in the patch are removed. Raw chain specs cannot be patched.
+#[derive(Parser, Debug, Clone)]
+pub struct PatchGenesisCmd {
+	/// Chain spec to be patched.
+	pub input_chain_spec: PathBuf,
+	/// The path to the genesis config patch.
+	pub patch_path: PathBuf,
+}
+
+/// Verifies that the raw storage of the provided chain spec matches the runtime.
+///
+/// The expected raw storage is built by the runtime's `GenesisBuilder` from its default genesis
+/// config, patched with the optional patch. The differences are printed as JSON, with the
+/// `missing`, `unexpected` and `mismatched` storage keys of the input chain spec.
+#[derive(Parser, Debug, Clone)]
+pub struct VerifyRawCmd {
+	/// Raw chain spec to be verified.
+	pub input_chain_spec: PathBuf,
+	/// The path to runtime wasm blob.
+	#[arg(long, short)]
+	pub runtime_wasm_path: PathBuf,
+	/// The path to the genesis config patch applied to the runtime's default genesis config.
+	#[arg(long, short)]
+	pub patch_path: Option<PathBuf>,
+}
+
 /// Processes `CreateCmd` and returns JSON version of `ChainSpec`.
 pub fn generate_chain_spec_for_runtime(cmd: &CreateCmd) -> Result<String, String> {
 	let code = fs::read(cmd.runtime_wasm_path.as_path())
@@ -238,3 +283,87 @@ pub fn generate_chain_spec_for_runtime(cmd: &CreateCmd) -> Result<String, String
 		(false, false) => chain_spec.as_json(false),
 	}
 }
+
+/// Processes `PatchGenesisCmd` and returns JSON version of the patched `ChainSpec`.
+pub fn patch_genesis_in_chain_spec(cmd: &PatchGenesisCmd) -> Result<String, String> {
+	let PatchGenesisCmd { ref input_chain_spec, ref patch_path } = cmd;
+	let chain_spec = GenericChainSpec::<()>::from_json_file(input_chain_spec.clone())?;
+	let mut chain_spec_json = serde_json::from_str::<Value>(&chain_spec.as_json(false)?)
+		.map_err(|e| format!("Conversion to json failed: {e}"))?;
+
+	let patch = fs::read(patch_path.as_path())
+		.map_err(|e| format!("patch file {patch_path:?} shall be readable: {e}"))?;
+	let patch = serde_json::from_slice::<Value>(&patch[..])
+		.map_err(|e| format!("patch file {patch_path:?} shall contain a valid json: {e}"))?;
+
+	let genesis = chain_spec_json
+		.pointer_mut("/genesis/runtimeGenesis")
+		.and_then(Value::as_object_mut)
+		.ok_or_else(|| {
+			"chain spec shall contain the runtime genesis config (raw chain specs can't be patched)"
+				.to_string()
+		})?;
+	let config = ["patch", "config"]
+		.into_iter()
+		.find_map(|key| genesis.get_mut(key))
+		.ok_or_else(|| "chain spec shall contain the genesis config or patch".to_string())?;
+	json_merge(config, patch);
+
+	serde_json::to_string_pretty(&chain_spec_json).map_err(|e| format!("to pretty failed: {e}"))
+}
+
+/// Processes `VerifyRawCmd`.
+///
+/// Returns the differences between the raw storage of the input chain spec and the storage built
+/// by the runtime, or `None` if there are no differences.
+pub fn verify_raw_chain_spec(cmd: &VerifyRawCmd) -> Result<Option<Value>, String> {
+	let VerifyRawCmd { ref input_chain_spec, ref runtime_wasm_path, ref patch_path } = cmd;
+	let chain_spec = GenericChainSpec::<()>::from_json_file(input_chain_spec.clone())?;
+	let actual = serde_json::from_str::<Value>(&chain_spec.as_json(true)?)
+		.map_err(|e| format!("Conversion to json failed: {e}"))?;
+
+	let code = fs::read(runtime_wasm_path.as_path())
+		.map_err(|e| format!("wasm blob shall be readable {e}"))?;
+	let patch = match patch_path {
+		Some(patch_path) => {
+			let patch = fs::read(patch_path.as_path())
+				.map_err(|e| format!("patch file {patch_path:?} shall be readable: {e}"))?;
+			serde_json::from_slice::<Value>(&patch[..])
+				.map_err(|e| format!("patch file {patch_path:?} shall contain a valid json: {e}"))?
+		},
+		None => json!({}),
+	};
+	let expected = GenericChainSpec::<()>::builder(&code[..], Default::default())
+		.with_genesis_config_patch(patch)
+		.build()
+		.as_json(true)?;
+	let expected = serde_json::from_str::<Value>(&expected)
+		.map_err(|e| format!("Conversion to json failed: {e}"))?;
+
+	let top = |chain_spec: &Value| {
+		chain_spec
+			.pointer("/genesis/raw/top")
+			.and_then(Value::as_object)
+			.cloned()
+			.ok_or_else(|| "raw chain spec shall contain the top storage".to_string())
+	};
+	let (expected, actual) = (top(&expected)?, top(&actual)?);
+
+	let keys = expected.keys().chain(actual.keys()).collect::<BTreeSet<_>>();
+	let (mut missing, mut unexpected, mut mismatched) = (vec![], vec![], vec![]);
+	for key in keys {
+		match (expected.get(key), actual.get(key)) {
+			(Some(_), None) => missing.push(json!(key)),
+			(None, Some(_)) => unexpected.push(json!(key)),
+			(Some(expected), Some(actual)) if expected != actual =>
+				mismatched.push(json!({ "key": key, "expected": expected, "actual": actual })),
+			_ => {},
+		}
+	}
+
+	if missing.is_empty() && unexpected.is_empty() && mismatched.is_empty() {
+		Ok(None)
+	} else {
+		Ok(Some(json!({ "missing": missing, "unexpected": unexpected, "mismatched": mismatched })))
+	}
+}
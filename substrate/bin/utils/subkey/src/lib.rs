@@ -221,6 +221,28 @@
 //!   SS58 Address:     5Hpm9fq3W3dQgwWpAwDS2ZHKAdnk86QRCu7iX4GnmDxycrte
 //! ```
 //!
+//! ### BEEFY keys
+//!
+//! Besides `sr25519`, `ed25519` and `ecdsa`, `subkey` supports the BLS crypto schemes used by
+//! BEEFY: `bls377`, `bls381` and the `(ECDSA,BLS12-377)` paired crypto `ecdsa-bls377`. Those keys
+//! can't be used by accounts, so the account related fields are shown as `n/a`:
+//!
+//! ```bash
+//! subkey generate --scheme ecdsa-bls377
+//! subkey inspect --scheme ecdsa-bls377 <mnemonic | seed>
+//! ```
+//!
+//! The resulting keys can be inserted into the keystore of the node with the `key insert`
+//! command, using the `beef` key type (the node has to be built with the `bls-experimental`
+//! feature of `sc-cli`):
+//!
+//! ```bash
+//! <node> key insert --key-type beef --scheme ecdsa-bls377 --suri <mnemonic | seed>
+//! ```
+//!
+//! **NOTE**: The BLS crypto schemes are experimental and are only available with the
+//! `bls-experimental` feature, which is enabled by default for `subkey`.
+//!
 //! ### Signing
 //!
 //! `subkey` allows using a **secret key** to sign a random message. The signature can then be
@@ -253,6 +253,8 @@ pub struct UsageInfo {
 	pub memory: MemoryInfo,
 	/// I/O statistics.
 	pub io: IoInfo,
+	/// Hit/miss statistics of the trie cache since the start of the client.
+	pub trie_cache: sp_trie::cache::TrieCacheStats,
 }
 
 impl fmt::Display for UsageInfo {
@@ -33,7 +33,11 @@ pub use crate::{
 	worker::{AuthorityDiscovery, NetworkProvider, Role, Worker},
 };
 
-use std::{collections::HashSet, sync::Arc, time::Duration};
+use std::{
+	collections::{HashMap, HashSet},
+	sync::Arc,
+	time::Duration,
+};
 
 use futures::{
 	channel::{mpsc, oneshot},
@@ -167,4 +171,6 @@ pub(crate) enum ServicetoWorkerMsg {
 	GetAddressesByAuthorityId(AuthorityId, oneshot::Sender<Option<HashSet<Multiaddr>>>),
 	/// See [`Service::get_authority_ids_by_peer_id`].
 	GetAuthorityIdsByPeerId(PeerId, oneshot::Sender<Option<HashSet<AuthorityId>>>),
+	/// See [`Service::get_known_addresses`].
+	GetKnownAddresses(oneshot::Sender<HashMap<AuthorityId, HashSet<Multiaddr>>>),
 }
@@ -16,7 +16,10 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use std::{collections::HashSet, fmt::Debug};
+use std::{
+	collections::{HashMap, HashSet},
+	fmt::Debug,
+};
 
 use crate::ServicetoWorkerMsg;
 
@@ -91,4 +94,20 @@ impl Service {
 
 		rx.await.ok().flatten()
 	}
+
+	/// Get all [`AuthorityId`]s together with their addresses from the local address cache.
+	///
+	/// The cache only contains the authorities of the current and the next session, for which
+	/// a record has been found on the DHT.
+	///
+	/// Returns `None` if the connection to the [`crate::Worker`] failed.
+	pub async fn get_known_addresses(
+		&mut self,
+	) -> Option<HashMap<AuthorityId, HashSet<Multiaddr>>> {
+		let (tx, rx) = oneshot::channel();
+
+		self.to_worker.send(ServicetoWorkerMsg::GetKnownAddresses(tx)).await.ok()?;
+
+		rx.await.ok()
+	}
 }
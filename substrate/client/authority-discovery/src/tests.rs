@@ -30,7 +30,10 @@ use libp2p::{
 	identity::ed25519,
 	PeerId,
 };
-use std::{collections::HashSet, sync::Arc};
+use std::{
+	collections::{HashMap, HashSet},
+	sync::Arc,
+};
 
 use sp_authority_discovery::AuthorityId;
 use sp_core::crypto::key_types;
@@ -73,13 +76,17 @@ fn get_addresses_and_authority_id() {
 
 	pool.run_until(async {
 		assert_eq!(
-			Some(HashSet::from([remote_addr])),
+			Some(HashSet::from([remote_addr.clone()])),
 			service.get_addresses_by_authority_id(remote_authority_id.clone()).await,
 		);
 		assert_eq!(
-			Some(HashSet::from([remote_authority_id])),
+			Some(HashSet::from([remote_authority_id.clone()])),
 			service.get_authority_ids_by_peer_id(remote_peer_id).await,
 		);
+		assert_eq!(
+			Some(HashMap::from([(remote_authority_id, HashSet::from([remote_addr]))])),
+			service.get_known_addresses().await,
+		);
 	});
 }
 
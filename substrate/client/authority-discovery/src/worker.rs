@@ -300,6 +300,9 @@ where
 				let _ = sender
 					.send(self.addr_cache.get_authority_ids_by_peer_id(&peer_id).map(Clone::clone));
 			},
+			ServicetoWorkerMsg::GetKnownAddresses(sender) => {
+				let _ = sender.send(self.addr_cache.authority_ids_with_addresses().clone());
+			},
 		}
 	}
 
@@ -122,6 +122,11 @@ impl AddrCache {
 		self.authority_id_to_addresses.get(authority_id)
 	}
 
+	/// Returns all known [`AuthorityId`]s together with their addresses.
+	pub fn authority_ids_with_addresses(&self) -> &HashMap<AuthorityId, HashSet<Multiaddr>> {
+		&self.authority_id_to_addresses
+	}
+
 	/// Returns the [`AuthorityId`]s for the given [`PeerId`].
 	///
 	/// As the authority id can change between sessions, one [`PeerId`] can be mapped to
@@ -127,6 +127,15 @@ pub enum CryptoScheme {
 	Sr25519,
 	/// Use ecdsa.
 	Ecdsa,
+	/// Use BLS12-377.
+	#[cfg(feature = "bls-experimental")]
+	Bls377,
+	/// Use BLS12-381.
+	#[cfg(feature = "bls-experimental")]
+	Bls381,
+	/// Use the (ECDSA,BLS12-377) paired crypto, used by BEEFY.
+	#[cfg(feature = "bls-experimental")]
+	EcdsaBls377,
 }
 
 /// The type of the output format.
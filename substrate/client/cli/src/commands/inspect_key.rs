@@ -175,6 +175,29 @@ mod tests {
 		assert!(inspect.run().is_ok());
 	}
 
+	#[cfg(feature = "bls-experimental")]
+	#[test]
+	fn inspect_bls_keys() {
+		let words =
+			"remember fiber forum demise paper uniform squirrel feel access exclude casual effort";
+
+		for scheme in ["bls377", "bls381", "ecdsa-bls377"] {
+			let inspect = InspectKeyCmd::parse_from(&["inspect-key", "--scheme", scheme, words]);
+			assert!(inspect.run().is_ok());
+		}
+
+		let public = sp_core::ecdsa_bls377::Pair::from_string(words, None).unwrap().public();
+		let public = array_bytes::bytes2hex("0x", public.as_slice());
+		let inspect = InspectKeyCmd::parse_from(&[
+			"inspect-key",
+			"--scheme",
+			"ecdsa-bls377",
+			"--public",
+			&public,
+		]);
+		assert!(inspect.run().is_ok());
+	}
+
 	#[test]
 	fn inspect_with_expected_public_key() {
 		let check_cmd = |seed, expected_public, success| {
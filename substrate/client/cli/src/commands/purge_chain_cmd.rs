@@ -30,12 +30,21 @@ use std::{
 };
 
 /// The `purge-chain` command used to remove the whole chain.
+///
+/// Only the database is removed, the keystore and the network key of the node are kept.
 #[derive(Debug, Clone, Parser)]
 pub struct PurgeChainCmd {
 	/// Skip interactive prompt by answering yes automatically.
 	#[arg(short = 'y')]
 	pub yes: bool,
 
+	/// Keep the offchain storage, e.g. the data of the offchain workers.
+	///
+	/// Only the blocks and the state are removed from the database, instead of removing the whole
+	/// database directory.
+	#[arg(long)]
+	pub keep_offchain_storage: bool,
+
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub shared_params: SharedParams,
@@ -53,7 +62,11 @@ impl PurgeChainCmd {
 		})?;
 
 		if !self.yes {
-			print!("Are you sure to remove {:?}? [y/N]: ", &db_path);
+			if self.keep_offchain_storage {
+				print!("Are you sure to remove the blocks and state of {:?}? [y/N]: ", &db_path);
+			} else {
+				print!("Are you sure to remove {:?}? [y/N]: ", &db_path);
+			}
 			io::stdout().flush().expect("failed to flush stdout");
 
 			let mut input = String::new();
@@ -69,6 +82,16 @@ impl PurgeChainCmd {
 			}
 		}
 
+		if self.keep_offchain_storage {
+			if !db_path.exists() {
+				eprintln!("{:?} did not exist.", &db_path);
+				return Ok(())
+			}
+			sc_client_db::purge_database(&database_config, true)?;
+			println!("Blocks and state of {:?} removed, offchain storage kept.", &db_path);
+			return Ok(())
+		}
+
 		match fs::remove_dir_all(&db_path) {
 			Ok(_) => {
 				println!("{:?} removed.", &db_path);
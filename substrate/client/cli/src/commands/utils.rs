@@ -30,7 +30,7 @@ use sp_core::{
 	hexdisplay::HexDisplay,
 	Pair,
 };
-use sp_runtime::{traits::IdentifyAccount, MultiSigner};
+use sp_runtime::{traits::IdentifyAccount, AccountId32, MultiSigner};
 use std::path::PathBuf;
 
 /// Public key type for Runtime
@@ -38,6 +38,49 @@ pub type PublicFor<P> = <P as sp_core::Pair>::Public;
 /// Seed type for Runtime
 pub type SeedFor<P> = <P as sp_core::Pair>::Seed;
 
+/// Public key which can be used to derive an account id.
+///
+/// The public keys of the crypto schemes that are not used by the accounts (e.g. BLS keys used by
+/// BEEFY) have no account id.
+pub trait PublicKeyAccountId {
+	/// Returns the account id corresponding to the public key, if any.
+	fn account_id(&self) -> Option<AccountId32>;
+}
+
+macro_rules! impl_account_id_from_multi_signer {
+	($($public:ty),*) => {
+		$(
+			impl PublicKeyAccountId for $public {
+				fn account_id(&self) -> Option<AccountId32> {
+					Some(MultiSigner::from(self.clone()).into_account())
+				}
+			}
+		)*
+	};
+}
+
+impl_account_id_from_multi_signer!(
+	sp_core::ed25519::Public,
+	sp_core::sr25519::Public,
+	sp_core::ecdsa::Public
+);
+
+#[cfg(feature = "bls-experimental")]
+impl<T> PublicKeyAccountId for sp_core::bls::Public<T> {
+	fn account_id(&self) -> Option<AccountId32> {
+		None
+	}
+}
+
+#[cfg(feature = "bls-experimental")]
+impl<const LEFT_PLUS_RIGHT_LEN: usize> PublicKeyAccountId
+	for sp_core::paired_crypto::Public<LEFT_PLUS_RIGHT_LEN>
+{
+	fn account_id(&self) -> Option<AccountId32> {
+		None
+	}
+}
+
 /// helper method to fetch uri from `Option<String>` either as a file or read from stdin
 pub fn read_uri(uri: Option<&String>) -> error::Result<String> {
 	let uri = if let Some(uri) = uri {
@@ -70,7 +113,7 @@ pub fn print_from_uri<Pair>(
 	output: OutputType,
 ) where
 	Pair: sp_core::Pair,
-	Pair::Public: Into<MultiSigner>,
+	Pair::Public: PublicKeyAccountId,
 {
 	let password = password.as_ref().map(|s| s.expose_secret().as_str());
 	let network_id = String::from(unwrap_or_default_ss58_version(network_override));
@@ -86,8 +129,8 @@ pub fn print_from_uri<Pair>(
 					"secretSeed": format_seed::<Pair>(seed),
 					"publicKey": format_public_key::<Pair>(public_key.clone()),
 					"ss58PublicKey": public_key.to_ss58check_with_version(network_override),
-					"accountId": format_account_id::<Pair>(public_key),
-					"ss58Address": pair.public().into().into_account().to_ss58check_with_version(network_override),
+					"accountId": format_account_id::<Pair>(public_key.clone()),
+					"ss58Address": format_ss58_address::<Pair>(public_key.clone(), network_override),
 				});
 				println!(
 					"{}",
@@ -109,7 +152,7 @@ pub fn print_from_uri<Pair>(
 					format_public_key::<Pair>(public_key.clone()),
 					format_account_id::<Pair>(public_key.clone()),
 					public_key.to_ss58check_with_version(network_override),
-					pair.public().into().into_account().to_ss58check_with_version(network_override),
+					format_ss58_address::<Pair>(public_key.clone(), network_override),
 				);
 			},
 		}
@@ -125,8 +168,8 @@ pub fn print_from_uri<Pair>(
 					"secretSeed": if let Some(seed) = seed { format_seed::<Pair>(seed) } else { "n/a".into() },
 					"publicKey": format_public_key::<Pair>(public_key.clone()),
 					"ss58PublicKey": public_key.to_ss58check_with_version(network_override),
-					"accountId": format_account_id::<Pair>(public_key),
-					"ss58Address": pair.public().into().into_account().to_ss58check_with_version(network_override),
+					"accountId": format_account_id::<Pair>(public_key.clone()),
+					"ss58Address": format_ss58_address::<Pair>(public_key.clone(), network_override),
 				});
 				println!(
 					"{}",
@@ -148,7 +191,7 @@ pub fn print_from_uri<Pair>(
 					format_public_key::<Pair>(public_key.clone()),
 					format_account_id::<Pair>(public_key.clone()),
 					public_key.to_ss58check_with_version(network_override),
-					pair.public().into().into_account().to_ss58check_with_version(network_override),
+					format_ss58_address::<Pair>(public_key.clone(), network_override),
 				);
 			},
 		}
@@ -201,7 +244,7 @@ pub fn print_from_public<Pair>(
 ) -> Result<(), Error>
 where
 	Pair: sp_core::Pair,
-	Pair::Public: Into<MultiSigner>,
+	Pair::Public: PublicKeyAccountId,
 {
 	let public = array_bytes::hex2bytes(public_str)?;
 
@@ -268,9 +311,26 @@ fn format_public_key<P: sp_core::Pair>(public_key: PublicFor<P>) -> String {
 /// formats public key as accountId as hex
 fn format_account_id<P: sp_core::Pair>(public_key: PublicFor<P>) -> String
 where
-	PublicFor<P>: Into<MultiSigner>,
+	PublicFor<P>: PublicKeyAccountId,
+{
+	public_key
+		.account_id()
+		.map(|account_id| format!("0x{}", HexDisplay::from(&account_id.as_ref())))
+		.unwrap_or_else(|| "n/a".into())
+}
+
+/// formats accountId of the public key as SS58 address
+fn format_ss58_address<P: sp_core::Pair>(
+	public_key: PublicFor<P>,
+	network: Ss58AddressFormat,
+) -> String
+where
+	PublicFor<P>: PublicKeyAccountId,
 {
-	format!("0x{}", HexDisplay::from(&public_key.into().into_account().as_ref()))
+	public_key
+		.account_id()
+		.map(|account_id| account_id.to_ss58check_with_version(network))
+		.unwrap_or_else(|| "n/a".into())
 }
 
 /// Allows for calling $method with appropriate crypto impl.
@@ -296,6 +356,47 @@ macro_rules! with_crypto_scheme {
 			$crate::CryptoScheme::Ed25519 => {
 				$method::<sp_core::ed25519::Pair, $($generics),*>($($params),*)
 			}
+			#[allow(unreachable_patterns)]
+			_scheme => {
+				$crate::__with_bls_crypto_scheme!(_scheme, $method<$($generics),*>($($params),*))
+			}
+		}
+	};
+}
+
+/// Calls $method with the BLS crypto impl, if the BLS schemes are enabled.
+#[cfg(feature = "bls-experimental")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __with_bls_crypto_scheme {
+	(
+		$scheme:expr,
+		$method:ident<$($generics:ty),*>( $( $params:expr ),* )
+	) => {
+		match $scheme {
+			$crate::CryptoScheme::Bls377 => {
+				$method::<sp_core::bls377::Pair, $($generics),*>($($params),*)
+			}
+			$crate::CryptoScheme::Bls381 => {
+				$method::<sp_core::bls381::Pair, $($generics),*>($($params),*)
+			}
+			$crate::CryptoScheme::EcdsaBls377 => {
+				$method::<sp_core::ecdsa_bls377::Pair, $($generics),*>($($params),*)
+			}
+			_ => unreachable!("non-BLS schemes are handled by `with_crypto_scheme`; qed"),
 		}
 	};
 }
+
+/// Calls $method with the BLS crypto impl, if the BLS schemes are enabled.
+#[cfg(not(feature = "bls-experimental"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __with_bls_crypto_scheme {
+	(
+		$scheme:expr,
+		$method:ident<$($generics:ty),*>( $( $params:expr ),* )
+	) => {
+		unreachable!("all schemes are handled by `with_crypto_scheme`; qed")
+	};
+}
@@ -24,8 +24,7 @@ use crate::{
 use clap::Parser;
 use rand::{rngs::OsRng, RngCore};
 use sp_core::crypto::{unwrap_or_default_ss58_version, Ss58AddressFormat, Ss58Codec};
-use sp_runtime::traits::IdentifyAccount;
-use utils::print_from_uri;
+use utils::{print_from_uri, PublicKeyAccountId};
 
 /// The `vanity` command
 #[derive(Debug, Clone, Parser)]
@@ -79,8 +78,7 @@ fn generate_key<Pair>(
 ) -> Result<String, &'static str>
 where
 	Pair: sp_core::Pair,
-	Pair::Public: IdentifyAccount,
-	<Pair::Public as IdentifyAccount>::AccountId: Ss58Codec,
+	Pair::Public: PublicKeyAccountId,
 {
 	println!("Generating key containing pattern '{}'", desired);
 
@@ -97,7 +95,10 @@ where
 		}
 
 		let p = Pair::from_seed(&seed);
-		let ss58 = p.public().into_account().to_ss58check_with_version(network_override);
+		let Some(account_id) = p.public().account_id() else {
+			return Err("vanity addresses are not supported by the given crypto scheme")
+		};
+		let ss58 = account_id.to_ss58check_with_version(network_override);
 		let score = calculate_score(desired, &ss58);
 		if score > best || desired.len() < 2 {
 			best = score;
@@ -185,6 +186,12 @@ mod tests {
 			.contains("ab"));
 	}
 
+	#[cfg(feature = "bls-experimental")]
+	#[test]
+	fn generate_key_rejects_schemes_without_accounts() {
+		assert!(generate_key::<sp_core::bls381::Pair>("ab", default_ss58_version()).is_err());
+	}
+
 	#[test]
 	fn generate_key_respects_network_override() {
 		let seed =
@@ -24,6 +24,7 @@ use crate::{
 	params::{DatabaseParams, PruningParams},
 };
 use clap::Args;
+use sc_service::config::TrieCacheSettings;
 use std::path::PathBuf;
 
 /// Parameters for block import.
@@ -81,6 +82,19 @@ pub struct ImportParams {
 	#[arg(long, value_name = "Bytes", default_value_t = 67108864)]
 	pub trie_cache_size: usize,
 
+	/// Specify the maximum size of the trie node cache of a single state instance.
+	///
+	/// Every runtime call uses its own local cache on top of the shared state cache, which is
+	/// merged into the shared cache when the call is finished. If not given, 8 MiB are used.
+	#[arg(long, value_name = "Bytes")]
+	pub trie_cache_local_node_size: Option<usize>,
+
+	/// Specify the maximum size of the trie value cache of a single state instance.
+	///
+	/// If not given, 2 MiB are used.
+	#[arg(long, value_name = "Bytes")]
+	pub trie_cache_local_value_size: Option<usize>,
+
 	/// DEPRECATED: switch to `--trie-cache-size`.
 	#[arg(long)]
 	state_cache_size: Option<usize>,
@@ -100,6 +114,18 @@ impl ImportParams {
 		}
 	}
 
+	/// Specify the trie cache settings, besides its maximum size.
+	pub fn trie_cache_settings(&self) -> TrieCacheSettings {
+		let mut settings = TrieCacheSettings::default();
+		if let Some(size) = self.trie_cache_local_node_size {
+			settings.local_limits.max_node_heap_size = size;
+		}
+		if let Some(size) = self.trie_cache_local_value_size {
+			settings.local_limits.max_value_heap_size = size;
+		}
+		settings
+	}
+
 	/// Get the WASM execution method from the parameters
 	pub fn wasm_method(&self) -> sc_service::config::WasmExecutionMethod {
 		self.execution_strategies.check_usage_and_print_deprecation_warning();
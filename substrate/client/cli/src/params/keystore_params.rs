@@ -56,6 +56,25 @@ pub struct KeystoreParams {
 		conflicts_with_all = &["password_interactive", "password"]
 	)]
 	pub password_filename: Option<PathBuf>,
+
+	/// URI of a remote signer that holds the keys instead of the local keystore.
+	///
+	/// Either `<host>:<port>`, `tcp://<host>:<port>` or `unix:<path>`. The keys never leave the
+	/// remote signer, which can for example keep them in an HSM. VRF signatures, as used by BABE
+	/// and the parachain approval assignments, can't be created by the remote signer, so it can't
+	/// be used by BABE authorities and parachain validators.
+	#[arg(
+		long,
+		value_name = "URI",
+		conflicts_with_all = &["keystore_path", "password_interactive", "password", "password_filename"]
+	)]
+	pub keystore_remote_signer: Option<String>,
+
+	/// File that contains the token used to authenticate to the remote signer.
+	///
+	/// The token is not encrypted, so it can only be used with a `unix:<path>` remote signer.
+	#[arg(long, value_name = "PATH", requires = "keystore_remote_signer")]
+	pub keystore_remote_signer_token_file: Option<PathBuf>,
 }
 
 /// Parse a secret string, returning a displayable error.
@@ -66,6 +85,17 @@ pub fn secret_string_from_str(s: &str) -> std::result::Result<SecretString, Stri
 impl KeystoreParams {
 	/// Get the keystore configuration for the parameters
 	pub fn keystore_config(&self, config_dir: &Path) -> Result<KeystoreConfig> {
+		if let Some(ref uri) = self.keystore_remote_signer {
+			let token = match self.keystore_remote_signer_token_file {
+				Some(ref file) => {
+					let token = fs::read_to_string(file).map_err(|e| format!("{}", e))?;
+					Some(SecretString::new(token.trim_end().to_string()))
+				},
+				None => None,
+			};
+			return Ok(KeystoreConfig::Remote { uri: uri.clone(), token })
+		}
+
 		let password = if self.password_interactive {
 			Some(SecretString::new(input_keystore_password()?))
 		} else if let Some(ref file) = self.password_filename {
@@ -27,6 +27,7 @@ mod prometheus_params;
 mod pruning_params;
 mod runtime_params;
 mod shared_params;
+mod statement_store_params;
 mod telemetry_params;
 mod transaction_pool_params;
 
@@ -42,8 +43,8 @@ use std::{fmt::Debug, str::FromStr};
 pub use crate::params::{
 	database_params::*, import_params::*, keystore_params::*, message_params::*, mixnet_params::*,
 	network_params::*, node_key_params::*, offchain_worker_params::*, prometheus_params::*,
-	pruning_params::*, runtime_params::*, shared_params::*, telemetry_params::*,
-	transaction_pool_params::*,
+	pruning_params::*, runtime_params::*, shared_params::*, statement_store_params::*,
+	telemetry_params::*, transaction_pool_params::*,
 };
 
 /// Parse Ss58AddressFormat
@@ -0,0 +1,54 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use clap::Args;
+
+/// Parameters used to configure the statement store.
+#[derive(Debug, Clone, Args)]
+pub struct StatementStoreParams {
+	/// Maximum number of statements kept in the statement store.
+	///
+	/// Once the limit is reached, the statements with the lowest priority are evicted.
+	#[arg(long, value_name = "COUNT")]
+	pub statement_store_max_statements: Option<usize>,
+
+	/// Maximum total size of the data of the statements kept in the statement store, in bytes.
+	///
+	/// Once the limit is reached, the statements with the lowest priority are evicted.
+	#[arg(long, value_name = "BYTES")]
+	pub statement_store_max_size: Option<usize>,
+
+	/// Number of seconds for which removed statements are not allowed to be added back, before
+	/// they are pruned from the statement store.
+	#[arg(long, value_name = "SECONDS")]
+	pub statement_store_purge_after: Option<u64>,
+}
+
+impl StatementStoreParams {
+	/// Returns the statement store options, using the defaults for the ones not given.
+	pub fn options(&self) -> sc_statement_store::Options {
+		let default = sc_statement_store::Options::default();
+		sc_statement_store::Options {
+			max_total_statements: self
+				.statement_store_max_statements
+				.unwrap_or(default.max_total_statements),
+			max_total_size: self.statement_store_max_size.unwrap_or(default.max_total_size),
+			purge_after_sec: self.statement_store_purge_after.unwrap_or(default.purge_after_sec),
+		}
+	}
+}
@@ -249,6 +249,7 @@ mod tests {
 				keystore: sc_service::config::KeystoreConfig::InMemory,
 				database: sc_client_db::DatabaseSource::ParityDb { path: root.clone() },
 				trie_cache_maximum_size: None,
+				trie_cache_settings: Default::default(),
 				state_pruning: None,
 				blocks_pruning: sc_client_db::BlocksPruning::KeepAll,
 				chain_spec: Box::new(
@@ -285,6 +286,7 @@ mod tests {
 				data_path: root,
 				informant_output_format: Default::default(),
 				runtime_cache_size: 2,
+				pending_runtime_code_keys: Default::default(),
 			},
 			runtime,
 			Signals::dummy(),
@@ -35,7 +35,7 @@ use crate::{
 		AuthoritySet, AuthoritySetChanges, DelayKind, PendingChange, SharedAuthoritySet,
 	},
 	environment::{
-		CompletedRound, CompletedRounds, CurrentRounds, HasVoted, SharedVoterSetState,
+		CompletedRound, CompletedRounds, CurrentRounds, HasVoted, RoundVotes, SharedVoterSetState,
 		VoterSetState,
 	},
 	GrandpaJustification, NewAuthoritySet, LOG_TARGET,
@@ -46,6 +46,7 @@ const SET_STATE_KEY: &[u8] = b"grandpa_completed_round";
 const CONCLUDED_ROUNDS: &[u8] = b"grandpa_concluded_rounds";
 const AUTHORITY_SET_KEY: &[u8] = b"grandpa_voters";
 const BEST_JUSTIFICATION: &[u8] = b"grandpa_best_justification";
+const ROUND_VOTES_KEY: &[u8] = b"grandpa_round_votes";
 
 const CURRENT_VERSION: u32 = 3;
 
@@ -491,6 +492,24 @@ pub(crate) fn write_concluded_round<Block: BlockT, B: AuxStore>(
 	backend.insert_aux(&[(&key[..], round_data.encode().as_slice())], &[])
 }
 
+/// Write the votes observed so far in the latest round.
+///
+/// Only a single round is kept, writing the votes of a new round replaces
+/// the previously stored ones.
+pub(crate) fn write_round_votes<Block: BlockT, B: AuxStore>(
+	backend: &B,
+	round_votes: &RoundVotes<Block>,
+) -> ClientResult<()> {
+	backend.insert_aux(&[(ROUND_VOTES_KEY, round_votes.encode().as_slice())], &[])
+}
+
+/// Load the votes observed in the latest round, if any were stored.
+pub(crate) fn load_round_votes<Block: BlockT, B: AuxStore>(
+	backend: &B,
+) -> ClientResult<Option<RoundVotes<Block>>> {
+	load_decode(backend, ROUND_VOTES_KEY)
+}
+
 #[cfg(test)]
 pub(crate) fn load_authorities<B: AuxStore, H: Decode, N: Decode + Clone + Ord>(
 	backend: &B,
@@ -786,4 +805,28 @@ mod test {
 			Some(completed_round),
 		);
 	}
+
+	#[test]
+	fn write_read_round_votes() {
+		let client = substrate_test_runtime_client::new();
+
+		assert_eq!(
+			load_round_votes::<substrate_test_runtime_client::runtime::Block, _>(&client).unwrap(),
+			None,
+		);
+
+		let round_votes = RoundVotes::<substrate_test_runtime_client::runtime::Block> {
+			set_id: 3,
+			number: 42,
+			votes: vec![],
+		};
+
+		assert!(write_round_votes(&client, &round_votes).is_ok());
+		assert_eq!(load_round_votes(&client).unwrap(), Some(round_votes.clone()));
+
+		let next_round_votes = RoundVotes { number: 43, ..round_votes };
+
+		assert!(write_round_votes(&client, &next_round_votes).is_ok());
+		assert_eq!(load_round_votes(&client).unwrap(), Some(next_round_votes));
+	}
 }
@@ -36,7 +36,7 @@ use parking_lot::RwLock;
 use prometheus_endpoint::{register, Counter, Gauge, PrometheusError, U64};
 
 use sc_client_api::{
-	backend::{apply_aux, Backend as BackendT},
+	backend::{apply_aux, AuxStore, Backend as BackendT},
 	utils::is_descendent_of,
 };
 use sc_telemetry::{telemetry, TelemetryHandle, CONSENSUS_DEBUG, CONSENSUS_INFO};
@@ -83,6 +83,59 @@ pub struct CompletedRound<Block: BlockT> {
 	pub votes: Vec<SignedMessage<Block::Header>>,
 }
 
+/// The votes observed so far in a round that hasn't completed yet. These are
+/// persisted periodically so that a restarted voter can rejoin the round
+/// without having to wait for the votes to be gossiped again.
+#[derive(Debug, Clone, Decode, Encode, PartialEq)]
+pub(crate) struct RoundVotes<Block: BlockT> {
+	/// The voter set id of the round.
+	pub set_id: SetId,
+	/// The round number.
+	pub number: RoundNumber,
+	/// The votes observed in the round.
+	pub votes: Vec<SignedMessage<Block::Header>>,
+}
+
+/// Number of new votes observed in a round after which they are persisted.
+const PERSIST_ROUND_VOTES_EVERY: usize = 16;
+
+/// Keeps track of the votes observed in a round and periodically writes them
+/// to the aux-db.
+struct RoundVotesRecorder<Block: BlockT, C> {
+	client: Arc<C>,
+	voter_set_state: SharedVoterSetState<Block>,
+	round_votes: RoundVotes<Block>,
+	unpersisted: usize,
+}
+
+impl<Block: BlockT, C: AuxStore> RoundVotesRecorder<Block, C> {
+	fn record(&mut self, vote: &SignedMessage<Block::Header>) {
+		if self.round_votes.votes.contains(vote) {
+			return
+		}
+
+		self.round_votes.votes.push(vote.clone());
+		self.unpersisted += 1;
+
+		// only the latest round is persisted, a background round that is still
+		// receiving votes must not overwrite the votes of the current one.
+		if self.unpersisted < PERSIST_ROUND_VOTES_EVERY ||
+			!self.voter_set_state.is_latest_round(self.round_votes.number)
+		{
+			return
+		}
+
+		if let Err(e) = crate::aux_schema::write_round_votes(&*self.client, &self.round_votes) {
+			warn!(
+				target: LOG_TARGET,
+				"Failed to persist votes of round {}: {}", self.round_votes.number, e,
+			);
+		}
+
+		self.unpersisted = 0;
+	}
+}
+
 // Data about last completed rounds within a single voter set. Stores
 // NUM_LAST_COMPLETED_ROUNDS and always contains data about at least one round
 // (genesis).
@@ -380,6 +433,15 @@ impl<Block: BlockT> SharedVoterSetState<Block> {
 		}
 	}
 
+	/// Return whether the given round is the latest round the voter is live on.
+	pub(crate) fn is_latest_round(&self, round: RoundNumber) -> bool {
+		match &*self.inner.read() {
+			VoterSetState::Live { current_rounds, .. } =>
+				current_rounds.keys().next_back() == Some(&round),
+			_ => false,
+		}
+	}
+
 	// NOTE: not exposed outside of this module intentionally.
 	fn with<F, R>(&self, f: F) -> R
 	where
@@ -768,6 +830,39 @@ where
 			has_voted,
 		);
 
+		// votes observed in this round before a restart are replayed ahead of
+		// the ones received from the network, so that we can rejoin the round
+		// without having to catch up.
+		let restored = match crate::aux_schema::load_round_votes::<Block, _>(&*self.client) {
+			Ok(Some(round_votes))
+				if round_votes.set_id == self.set_id && round_votes.number == round =>
+				round_votes.votes,
+			Ok(_) => Vec::new(),
+			Err(e) => {
+				warn!(target: LOG_TARGET, "Failed to load persisted votes of round {}: {}", round, e);
+				Vec::new()
+			},
+		};
+
+		if !restored.is_empty() {
+			debug!(
+				target: LOG_TARGET,
+				"Restored {} persisted votes for round {}",
+				restored.len(),
+				round,
+			);
+		}
+
+		let mut recorder = RoundVotesRecorder {
+			client: self.client.clone(),
+			voter_set_state: self.voter_set_state.clone(),
+			round_votes: RoundVotes { set_id: self.set_id, number: round, votes: restored.clone() },
+			unpersisted: 0,
+		};
+
+		let incoming = futures::stream::iter(restored)
+			.chain(incoming.inspect(move |vote| recorder.record(vote)));
+
 		// schedule incoming messages from the network to be held until
 		// corresponding blocks are imported.
 		let incoming = Box::pin(
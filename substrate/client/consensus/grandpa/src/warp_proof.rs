@@ -314,6 +314,31 @@ where
 	fn current_authorities(&self) -> AuthorityList {
 		self.authority_set.inner().current_authorities.clone()
 	}
+
+	fn generate_complete(
+		&self,
+		start: Block::Hash,
+	) -> Result<(Vec<EncodedProof>, Block::Header), Box<dyn std::error::Error + Send + Sync>> {
+		let set_changes = self.authority_set.authority_set_changes();
+		let mut begin = start;
+		let mut proofs = Vec::new();
+		loop {
+			let proof = WarpSyncProof::<Block>::generate(&*self.backend, begin, &set_changes)
+				.map_err(Box::new)?;
+			let last_header = proof
+				.proofs
+				.last()
+				.map(|p| p.header.clone())
+				.ok_or_else(|| "Empty proof".to_string())?;
+			let is_finished = proof.is_finished;
+			proofs.push(EncodedProof(proof.encode()));
+			if is_finished {
+				return Ok((proofs, last_header))
+			}
+			// The next proof starts at the last block proven by this one, like during warp sync.
+			begin = last_header.hash();
+		}
+	}
 }
 
 #[cfg(test)]
@@ -0,0 +1,312 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Removal of the states of pruned forks.
+//!
+//! An archive node running with [`PruningMode::ArchiveAll`](crate::PruningMode::ArchiveAll)
+//! writes the trie nodes of every imported block directly to the database. When such a node is
+//! switched to [`PruningMode::ArchiveCanonical`](crate::PruningMode::ArchiveCanonical), the nodes
+//! that were inserted by the blocks of pruned forks are removed in place.
+//!
+//! The nodes inserted by a block are the nodes of its state that are not found at the same
+//! position in the state of its parent. Removing them is only safe if the database counts the
+//! references of the nodes, as the same node may have been inserted by a canonical block too.
+
+use crate::{columns, DbHash};
+use sp_blockchain::{Error as ClientError, Result as ClientResult};
+use sp_core::{hexdisplay::HexDisplay, storage::well_known_keys, Hasher};
+use sp_database::Database;
+use sp_trie::NodeCodec;
+use std::{collections::BTreeSet, marker::PhantomData};
+use trie_db::{
+	node::{Node, NodeHandle, Value},
+	NibbleSlice, NodeCodec as _,
+};
+
+/// A trie node handle or value that doesn't borrow the encoding of its parent.
+#[derive(PartialEq, Eq)]
+enum Owned {
+	/// The hash of a node stored in the database.
+	Hash(Vec<u8>),
+	/// A node or value that is inlined in its parent.
+	Inline(Vec<u8>),
+}
+
+impl From<NodeHandle<'_>> for Owned {
+	fn from(handle: NodeHandle) -> Self {
+		match handle {
+			NodeHandle::Hash(hash) => Owned::Hash(hash.to_vec()),
+			NodeHandle::Inline(data) => Owned::Inline(data.to_vec()),
+		}
+	}
+}
+
+impl From<Value<'_>> for Owned {
+	fn from(value: Value) -> Self {
+		match value {
+			Value::Node(hash) => Owned::Hash(hash.to_vec()),
+			Value::Inline(data) => Owned::Inline(data.to_vec()),
+		}
+	}
+}
+
+/// The parts of a decoded trie node that are needed to walk the trie.
+struct Parts<'a> {
+	partial: Vec<u8>,
+	children: Vec<(u8, NodeHandle<'a>)>,
+	value: Option<Value<'a>>,
+}
+
+/// Collects the trie nodes inserted by a block, i.e. the nodes of its state that are not part of
+/// the state of its parent.
+pub(crate) struct InsertedNodes<'a, H> {
+	db: &'a dyn Database<DbHash>,
+	_hasher: PhantomData<H>,
+}
+
+impl<'a, H: Hasher> InsertedNodes<'a, H> {
+	/// Creates a new instance reading the nodes from the state column of `db`.
+	///
+	/// The database must not prefix the keys of the nodes.
+	pub(crate) fn new(db: &'a dyn Database<DbHash>) -> Self {
+		Self { db, _hasher: PhantomData }
+	}
+
+	/// Returns the keys of the nodes, including the value nodes and the nodes of child tries,
+	/// that the state with `root` inserted on top of the state with `parent_root`.
+	pub(crate) fn collect(
+		&self,
+		root: &[u8],
+		parent_root: &[u8],
+	) -> ClientResult<BTreeSet<Vec<u8>>> {
+		let mut inserted = BTreeSet::new();
+		self.walk(Owned::Hash(root.to_vec()), Vec::new(), Some(parent_root), true, &mut inserted)?;
+		Ok(inserted)
+	}
+
+	/// Walks the node at the nibble path `start`, which is known to be part of a modified branch.
+	fn walk(
+		&self,
+		node: Owned,
+		start: Vec<u8>,
+		parent_root: Option<&[u8]>,
+		is_top: bool,
+		inserted: &mut BTreeSet<Vec<u8>>,
+	) -> ClientResult<()> {
+		if let Owned::Hash(ref hash) = node {
+			let parent_node = match parent_root {
+				Some(parent_root) => self.lookup(parent_root, &start, false)?,
+				None => None,
+			};
+			if parent_node.as_ref() == Some(&node) {
+				return Ok(())
+			}
+			inserted.insert(hash.clone());
+		}
+
+		let data = self.fetch(&node)?;
+		let parts = decode::<H>(&data)?;
+		let mut path = start;
+		path.extend_from_slice(&parts.partial);
+
+		if let Some(value) = parts.value {
+			let value = Owned::from(value);
+			let parent_value = match parent_root {
+				Some(parent_root) => self.lookup(parent_root, &path, true)?,
+				None => None,
+			};
+			if parent_value.as_ref() != Some(&value) {
+				if let Owned::Hash(ref hash) = value {
+					inserted.insert(hash.clone());
+				}
+				if is_top {
+					self.walk_child_trie(&path, &value, parent_value.as_ref(), inserted)?;
+				}
+			}
+		}
+
+		for (index, child) in parts.children {
+			let mut child_start = path.clone();
+			child_start.push(index);
+			self.walk(child.into(), child_start, parent_root, is_top, inserted)?;
+		}
+
+		Ok(())
+	}
+
+	/// Walks the child trie whose root was changed to `value` under the nibble path `path`.
+	fn walk_child_trie(
+		&self,
+		path: &[u8],
+		value: &Owned,
+		parent_value: Option<&Owned>,
+		inserted: &mut BTreeSet<Vec<u8>>,
+	) -> ClientResult<()> {
+		let key = nibbles_to_key(path);
+		if !key.starts_with(well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX) {
+			return Ok(())
+		}
+
+		let Owned::Inline(root) = value else { return Ok(()) };
+		let parent_root = match parent_value {
+			Some(Owned::Inline(parent_root)) => Some(&parent_root[..]),
+			_ => None,
+		};
+		self.walk(Owned::Hash(root.clone()), Vec::new(), parent_root, false, inserted)
+	}
+
+	/// Looks up the trie with `root` for the node starting at the nibble path `path`, or for the
+	/// value stored under it if `value` is `true`.
+	fn lookup(&self, root: &[u8], path: &[u8], value: bool) -> ClientResult<Option<Owned>> {
+		let mut node = Owned::Hash(root.to_vec());
+		let mut start = 0;
+
+		loop {
+			if !value && start == path.len() {
+				return Ok(Some(node))
+			}
+
+			let data = self.fetch(&node)?;
+			let parts = decode::<H>(&data)?;
+			if !path[start..].starts_with(&parts.partial) {
+				return Ok(None)
+			}
+
+			let end = start + parts.partial.len();
+			if end == path.len() {
+				return Ok(if value { parts.value.map(Into::into) } else { None })
+			}
+
+			let Some((_, child)) = parts.children.into_iter().find(|(i, _)| *i == path[end]) else {
+				return Ok(None)
+			};
+			node = child.into();
+			start = end + 1;
+		}
+	}
+
+	/// Returns the encoding of `node`.
+	fn fetch(&self, node: &Owned) -> ClientResult<Vec<u8>> {
+		match node {
+			Owned::Hash(hash) => self.db.get(columns::STATE, hash).ok_or_else(|| {
+				ClientError::Backend(format!("Missing trie node {}", HexDisplay::from(hash)))
+			}),
+			Owned::Inline(data) => Ok(data.clone()),
+		}
+	}
+}
+
+/// Decodes the parts of the trie node encoded in `data`.
+fn decode<H: Hasher>(data: &[u8]) -> ClientResult<Parts<'_>> {
+	let node = NodeCodec::<H>::decode(data)
+		.map_err(|e| ClientError::Backend(format!("Invalid trie node: {:?}", e)))?;
+
+	let (partial, children, value) = match node {
+		Node::Empty => (Vec::new(), Default::default(), None),
+		Node::Leaf(partial, value) => (nibbles(&partial), Default::default(), Some(value)),
+		Node::Branch(children, value) => (Vec::new(), children, value),
+		Node::NibbledBranch(partial, children, value) => (nibbles(&partial), children, value),
+		// Not used by the trie layouts of Substrate.
+		Node::Extension(..) =>
+			return Err(ClientError::Backend("Unexpected trie extension node".into())),
+	};
+
+	Ok(Parts {
+		partial,
+		children: children
+			.into_iter()
+			.enumerate()
+			.filter_map(|(i, child)| Some((i as u8, child?)))
+			.collect(),
+		value,
+	})
+}
+
+/// Returns the nibbles of `partial`.
+fn nibbles(partial: &NibbleSlice) -> Vec<u8> {
+	(0..partial.len()).map(|i| partial.at(i)).collect()
+}
+
+/// Converts a path of nibbles to the storage key it represents.
+fn nibbles_to_key(path: &[u8]) -> Vec<u8> {
+	path.chunks(2).map(|n| (n[0] << 4) | n.get(1).copied().unwrap_or(0)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::{blake2_256, Blake2Hasher};
+	use sp_database::{MemDb, Transaction};
+	use sp_trie::{trie_types::TrieDBMutBuilderV1, MemoryDB, TrieMut};
+	use std::collections::HashSet;
+
+	fn key(i: u32) -> [u8; 32] {
+		blake2_256(&i.to_le_bytes())
+	}
+
+	#[test]
+	fn collects_nodes_inserted_on_top_of_parent() {
+		let mut parent_db = MemoryDB::<Blake2Hasher>::default();
+		let mut parent_root = Default::default();
+		{
+			let mut trie = TrieDBMutBuilderV1::new(&mut parent_db, &mut parent_root).build();
+			for i in 0..100 {
+				// Values bigger than 32 bytes are stored in value nodes.
+				trie.insert(&key(i), &[i as u8; 40]).unwrap();
+			}
+		}
+		let parent_nodes = parent_db.keys().into_keys().collect::<HashSet<_>>();
+
+		let mut fork_db = parent_db.clone();
+		let mut fork_root = parent_root;
+		{
+			let mut trie = TrieDBMutBuilderV1::from_existing(&mut fork_db, &mut fork_root).build();
+			for i in 0..5 {
+				trie.insert(&key(i), &[i as u8 + 1; 40]).unwrap();
+			}
+			trie.insert(&key(100), &[100; 40]).unwrap();
+			trie.insert(&key(101), &[101; 4]).unwrap();
+			trie.remove(&key(50)).unwrap();
+		}
+		let expected = fork_db
+			.keys()
+			.into_iter()
+			.filter(|(key, rc)| *rc > 0 && !parent_nodes.contains(key))
+			.map(|(key, _)| key.as_ref().to_vec())
+			.collect::<BTreeSet<_>>();
+		assert!(!expected.is_empty());
+
+		let db = MemDb::default();
+		let mut transaction = Transaction::new();
+		for (key, (value, rc)) in fork_db.drain() {
+			if rc > 0 {
+				transaction.set_from_vec(columns::STATE, key.as_ref(), value);
+			}
+		}
+		Database::<DbHash>::commit(&db, transaction).unwrap();
+
+		let inserted = InsertedNodes::<Blake2Hasher>::new(&db)
+			.collect(fork_root.as_ref(), parent_root.as_ref())
+			.unwrap();
+		assert_eq!(inserted, expected);
+		assert!(InsertedNodes::<Blake2Hasher>::new(&db)
+			.collect(parent_root.as_ref(), parent_root.as_ref())
+			.unwrap()
+			.is_empty());
+	}
+}
@@ -33,6 +33,7 @@ pub mod offchain;
 pub mod bench;
 
 mod children;
+mod fork_states;
 mod parity_db;
 mod pinned_blocks_cache;
 mod record_stats_state;
@@ -42,7 +43,7 @@ mod upgrade;
 mod utils;
 
 use linked_hash_map::LinkedHashMap;
-use log::{debug, trace, warn};
+use log::{debug, info, trace, warn};
 use parking_lot::{Mutex, RwLock};
 use std::{
 	collections::{HashMap, HashSet},
@@ -90,7 +91,10 @@ use sp_state_machine::{
 	OffchainChangesCollection, StateMachineStats, StorageCollection, StorageIterator, StorageKey,
 	StorageValue, UsageInfo as StateUsageInfo,
 };
-use sp_trie::{cache::SharedTrieCache, prefixed_key, MemoryDB, MerkleValue, PrefixedMemoryDB};
+use sp_trie::{
+	cache::{LocalCacheLimits, SharedTrieCache},
+	prefixed_key, MemoryDB, MerkleValue, PrefixedMemoryDB,
+};
 
 // Re-export the Database trait so that one can pass an implementation of it.
 pub use sc_state_db::PruningMode;
@@ -293,12 +297,21 @@ impl<B: BlockT> AsTrieBackend<HashingFor<B>> for RefTrackingState<B> {
 	}
 }
 
+/// Trie cache settings, besides the maximum size of the cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrieCacheSettings {
+	/// The limits of the local cache used per state instance, e.g. per runtime call.
+	pub local_limits: LocalCacheLimits,
+}
+
 /// Database settings.
 pub struct DatabaseSettings {
 	/// The maximum trie cache size in bytes.
 	///
 	/// If `None` is given, the cache is disabled.
 	pub trie_cache_maximum_size: Option<usize>,
+	/// The trie cache settings, only used when the trie cache is enabled.
+	pub trie_cache_settings: TrieCacheSettings,
 	/// Requested state pruning mode.
 	pub state_pruning: Option<PruningMode>,
 	/// Where to find the database.
@@ -408,6 +421,26 @@ impl std::fmt::Display for DatabaseSource {
 	}
 }
 
+/// Opens a full node database at the given source and returns it together with its state column.
+///
+/// The database is created if it doesn't exist. Should only be needed for benchmarking the
+/// database backend, e.g. by the hardware benchmarks.
+pub fn open_database_for_benchmarks<Block: BlockT>(
+	source: &DatabaseSource,
+) -> ClientResult<(Arc<dyn sp_database::Database<DbHash>>, sp_database::ColumnId)> {
+	let db = utils::open_database::<Block>(source, DatabaseType::Full, true)?;
+	Ok((db, columns::STATE))
+}
+
+/// Removes the blocks and the state from the database at the given source.
+///
+/// The offchain storage is kept if `keep_offchain_storage` is set, otherwise the database is left
+/// empty. The database must not be opened while it is purged.
+pub fn purge_database(source: &DatabaseSource, keep_offchain_storage: bool) -> ClientResult<()> {
+	let keep: &[u32] = if keep_offchain_storage { &[columns::OFFCHAIN] } else { &[] };
+	Ok(utils::purge_database_columns(source, keep)?)
+}
+
 pub(crate) mod columns {
 	pub const META: u32 = crate::utils::COLUMN_META;
 	pub const STATE: u32 = 1;
@@ -1170,6 +1203,7 @@ impl<Block: BlockT> Backend<Block> {
 		};
 		let db_setting = DatabaseSettings {
 			trie_cache_maximum_size: Some(16 * 1024 * 1024),
+			trie_cache_settings: Default::default(),
 			state_pruning: Some(state_pruning),
 			source: DatabaseSource::Custom { db, require_create_flag: true },
 			blocks_pruning,
@@ -1206,6 +1240,8 @@ impl<Block: BlockT> Backend<Block> {
 		let requested_state_pruning = config.state_pruning.clone();
 		let state_meta_db = StateMetaDb(db.clone());
 		let map_e = sp_blockchain::Error::from_state_db;
+		let stored_state_pruning =
+			sc_state_db::fetch_stored_pruning_mode(&state_meta_db).map_err(map_e)?;
 
 		let (state_db_init_commit_set, state_db) = StateDb::open(
 			state_meta_db,
@@ -1239,6 +1275,7 @@ impl<Block: BlockT> Backend<Block> {
 			genesis_state: RwLock::new(None),
 			shared_trie_cache: config.trie_cache_maximum_size.map(|maximum_size| {
 				SharedTrieCache::new(sp_trie::cache::CacheSize::new(maximum_size))
+					.with_local_cache_limits(config.trie_cache_settings.local_limits)
 			}),
 		};
 
@@ -1260,11 +1297,116 @@ impl<Block: BlockT> Backend<Block> {
 			});
 		}
 
+		if stored_state_pruning == Some(PruningMode::ArchiveAll) &&
+			state_pruning_used == PruningMode::ArchiveCanonical
+		{
+			backend.schedule_fork_states_cleanup(&mut db_init_transaction)?;
+		}
+
 		db.commit(db_init_transaction)?;
 
+		backend.cleanup_fork_states()?;
+
 		Ok(backend)
 	}
 
+	/// Schedules the removal of the states of the pruned forks, after an archive was switched from
+	/// [`PruningMode::ArchiveAll`] to [`PruningMode::ArchiveCanonical`].
+	///
+	/// A fork is pruned if it branches off the canonical chain at or below the finalized block.
+	fn schedule_fork_states_cleanup(
+		&self,
+		transaction: &mut Transaction<DbHash>,
+	) -> ClientResult<()> {
+		if !self.storage.db.supports_ref_counting() {
+			warn!(
+				target: "db",
+				"The database doesn't count references of the state nodes, \
+				 keeping the states of the forks pruned before switching to `archive-canonical`",
+			);
+			return Ok(())
+		}
+
+		let finalized_number = self.blockchain.info().finalized_number;
+		let mut forks = HashSet::new();
+		for leaf in self.blockchain.leaves()? {
+			let mut branch = Vec::new();
+			let mut hash = leaf;
+			loop {
+				let meta = self.blockchain.header_metadata(hash)?;
+				if self.blockchain.hash(meta.number)? == Some(hash) {
+					break
+				}
+				branch.push((meta.number, hash));
+				hash = meta.parent;
+			}
+
+			if branch.last().map_or(false, |(number, _)| *number <= finalized_number) {
+				forks.extend(branch);
+			}
+		}
+
+		let mut forks = forks.into_iter().collect::<Vec<_>>();
+		// The blocks are removed from the end, children before their parents.
+		forks.sort();
+		let forks = forks.into_iter().map(|(_, hash)| hash).collect::<Vec<_>>();
+
+		info!(
+			target: "db",
+			"Switched to `archive-canonical`, removing the states of {} pruned fork blocks",
+			forks.len(),
+		);
+		transaction.set_from_vec(columns::META, meta_keys::FORK_STATES_CLEANUP, forks.encode());
+		Ok(())
+	}
+
+	/// Removes the states of the blocks scheduled by [`Self::schedule_fork_states_cleanup`].
+	///
+	/// The progress is committed after every block, so an interrupted cleanup is resumed on the
+	/// next start.
+	fn cleanup_fork_states(&self) -> ClientResult<()> {
+		let Some(pending) = self.storage.db.get(columns::META, meta_keys::FORK_STATES_CLEANUP)
+		else {
+			return Ok(())
+		};
+		let mut pending = Vec::<Block::Hash>::decode(&mut &pending[..]).map_err(|e| {
+			sp_blockchain::Error::Backend(format!("Error decoding pruned fork blocks: {}", e))
+		})?;
+
+		let inserted_nodes =
+			fork_states::InsertedNodes::<HashingFor<Block>>::new(&*self.storage.db);
+		while let Some(hash) = pending.pop() {
+			let mut transaction = Transaction::new();
+
+			let header = self.blockchain.expect_header(hash)?;
+			let parent = self.blockchain.expect_header(*header.parent_hash())?;
+			match inserted_nodes.collect(header.state_root().as_ref(), parent.state_root().as_ref())
+			{
+				Ok(nodes) => {
+					debug!(target: "db", "Removing {} state nodes of pruned block {hash:?}", nodes.len());
+					for node in nodes {
+						transaction.remove(columns::STATE, &node);
+					}
+				},
+				Err(e) => warn!(target: "db", "Keeping the state of pruned block {hash:?}: {e}"),
+			}
+
+			if pending.is_empty() {
+				transaction.remove(columns::META, meta_keys::FORK_STATES_CLEANUP);
+				info!(target: "db", "Removed the states of the pruned fork blocks");
+			} else {
+				transaction.set_from_vec(
+					columns::META,
+					meta_keys::FORK_STATES_CLEANUP,
+					pending.encode(),
+				);
+			}
+			self.storage.db.commit(transaction)?;
+		}
+
+		Ok(())
+	}
+
 	/// Handle setting head within a transaction. `route_to` should be the last
 	/// block that existed in the database. `best_to` should be the best block
 	/// to be set.
@@ -1701,7 +1843,8 @@ impl<Block: BlockT> Backend<Block> {
 						);
 					}
 				} else if number > best_num + One::one() &&
-					number > One::one() && self.blockchain.header(parent_hash)?.is_none()
+					number > One::one() &&
+					self.blockchain.header(parent_hash)?.is_none()
 				{
 					let gap = (best_num + One::one(), number - One::one());
 					transaction.set(columns::META, meta_keys::BLOCK_GAP, &gap.encode());
@@ -2186,8 +2329,11 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 			self.shared_trie_cache.as_ref().map_or(0, |c| c.used_memory_size()),
 		);
 
+		let trie_cache = self.shared_trie_cache.as_ref().map(|c| c.stats()).unwrap_or_default();
+
 		Some(UsageInfo {
 			memory: MemoryInfo { state_cache, database_cache },
+			trie_cache,
 			io: IoInfo {
 				transactions: io_stats.transactions,
 				bytes_read: io_stats.bytes_read,
@@ -2688,6 +2834,7 @@ pub(crate) mod tests {
 		let backend = Backend::<Block>::new(
 			DatabaseSettings {
 				trie_cache_maximum_size: Some(16 * 1024 * 1024),
+				trie_cache_settings: Default::default(),
 				state_pruning: Some(PruningMode::blocks_pruning(1)),
 				source: DatabaseSource::Custom { db: backing, require_create_flag: false },
 				blocks_pruning: BlocksPruning::KeepFinalized,
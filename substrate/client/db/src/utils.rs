@@ -56,6 +56,8 @@ pub mod meta_keys {
 	pub const LEAF_PREFIX: &[u8; 4] = b"leaf";
 	/// Children prefix list key.
 	pub const CHILDREN_PREFIX: &[u8; 8] = b"children";
+	/// Pruned fork blocks whose states are still to be removed.
+	pub const FORK_STATES_CLEANUP: &[u8; 6] = b"fclean";
 }
 
 /// Database metadata.
@@ -353,6 +355,58 @@ fn open_kvdb_rocksdb<Block: BlockT>(
 	Err(OpenDbError::NotEnabled("with-kvdb-rocksdb"))
 }
 
+/// Removes all data of the configured database, except for the data of the `keep` columns.
+///
+/// The database must not be opened while it is purged.
+pub fn purge_database_columns(db_source: &DatabaseSource, keep: &[u32]) -> Result<(), OpenDbError> {
+	match db_source {
+		DatabaseSource::ParityDb { path } => purge_parity_db_columns(path, keep),
+		#[cfg(feature = "rocksdb")]
+		DatabaseSource::RocksDb { path, .. } => purge_kvdb_rocksdb_columns(path, keep),
+		DatabaseSource::Custom { .. } =>
+			Err(OpenDbError::Internal("Cannot purge custom database implementation".into())),
+		DatabaseSource::Auto { paritydb_path, rocksdb_path, .. } =>
+			match purge_kvdb_rocksdb_columns(rocksdb_path, keep) {
+				Err(OpenDbError::NotEnabled(_)) | Err(OpenDbError::DoesNotExist) =>
+					purge_parity_db_columns(paritydb_path, keep),
+				result => result,
+			},
+	}
+}
+
+fn purge_parity_db_columns(path: &Path, keep: &[u32]) -> Result<(), OpenDbError> {
+	if !path.exists() {
+		return Err(OpenDbError::DoesNotExist)
+	}
+
+	for column in (0..NUM_COLUMNS).filter(|column| !keep.contains(column)) {
+		parity_db::clear_column(path, column as u8)?;
+	}
+	Ok(())
+}
+
+#[cfg(any(feature = "rocksdb", test))]
+fn purge_kvdb_rocksdb_columns(path: &Path, keep: &[u32]) -> Result<(), OpenDbError> {
+	use kvdb::KeyValueDB;
+
+	let mut db_config = kvdb_rocksdb::DatabaseConfig::with_columns(NUM_COLUMNS);
+	db_config.create_if_missing = false;
+	let db = kvdb_rocksdb::Database::open(&db_config, path)?;
+
+	let mut transaction = kvdb::DBTransaction::new();
+	for column in (0..NUM_COLUMNS).filter(|column| !keep.contains(column)) {
+		// An empty prefix matches all keys of the column.
+		transaction.delete_prefix(column, &[]);
+	}
+	db.write(transaction)?;
+	Ok(())
+}
+
+#[cfg(not(any(feature = "rocksdb", test)))]
+fn purge_kvdb_rocksdb_columns(_path: &Path, _keep: &[u32]) -> Result<(), OpenDbError> {
+	Err(OpenDbError::NotEnabled("with-kvdb-rocksdb"))
+}
+
 /// Check database type.
 pub fn check_database_type(
 	db: &dyn Database<DbHash>,
@@ -824,4 +878,47 @@ mod tests {
 			assert!(db_res.is_ok(), "Existing parity database should be reopened");
 		}
 	}
+
+	fn purge_database_columns_works(source: DatabaseSource) {
+		let (header, offchain) = (crate::columns::HEADER, crate::columns::OFFCHAIN);
+		{
+			let db = open_database::<Block>(&source, DatabaseType::Full, true).unwrap();
+			let mut transaction = Transaction::new();
+			transaction.set(header, b"header", b"value");
+			transaction.set(offchain, b"offchain", b"value");
+			db.commit(transaction).unwrap();
+		}
+
+		purge_database_columns(&source, &[offchain]).unwrap();
+
+		let db = open_database::<Block>(&source, DatabaseType::Full, false).unwrap();
+		assert_eq!(db.get(header, b"header"), None);
+		assert_eq!(db.get(offchain, b"offchain"), Some(b"value".to_vec()));
+	}
+
+	#[test]
+	fn purge_database_columns_works_for_paritydb() {
+		let db_dir = tempfile::TempDir::new().unwrap();
+		let source = DatabaseSource::ParityDb { path: db_dir.path().join("paritydb") };
+
+		purge_database_columns_works(source);
+	}
+
+	#[cfg(feature = "rocksdb")]
+	#[test]
+	fn purge_database_columns_works_for_rocksdb() {
+		let db_dir = tempfile::TempDir::new().unwrap();
+		let source =
+			DatabaseSource::RocksDb { path: db_dir.path().join("rocksdb"), cache_size: 128 };
+
+		purge_database_columns_works(source);
+	}
+
+	#[test]
+	fn purge_database_columns_fails_for_missing_database() {
+		let db_dir = tempfile::TempDir::new().unwrap();
+		let source = DatabaseSource::ParityDb { path: db_dir.path().join("paritydb") };
+
+		assert!(matches!(purge_database_columns(&source, &[]), Err(OpenDbError::DoesNotExist)));
+	}
 }
@@ -35,6 +35,7 @@ use sp_version::RuntimeVersion;
 use sp_wasm_interface::HostFunctions;
 
 use std::{
+	collections::HashMap,
 	panic::AssertUnwindSafe,
 	path::{Path, PathBuf},
 	sync::Arc,
@@ -159,11 +160,20 @@ impl VersionedRuntime {
 /// request.
 ///
 /// The size of cache is configurable via the cli option `--runtime-cache-size`.
+///
+/// Runtimes are compiled without holding the lock of the cache, so compiling a new runtime (e.g.
+/// one that is precompiled ahead of a runtime upgrade) doesn't block the execution of the cached
+/// ones.
 pub struct RuntimeCache {
 	/// A cache of runtimes along with metadata.
 	///
 	/// Runtimes sorted by recent usage. The most recently used is at the front.
 	runtimes: Mutex<LruMap<VersionedRuntimeId, Arc<VersionedRuntime>>>,
+	/// The runtimes that are currently being compiled.
+	///
+	/// Requests for a runtime that is being compiled wait on its lock instead of compiling the
+	/// runtime a second time.
+	compiling: Mutex<HashMap<VersionedRuntimeId, Arc<Mutex<()>>>>,
 	/// The size of the instances cache for each runtime.
 	max_runtime_instances: usize,
 	cache_path: Option<PathBuf>,
@@ -186,7 +196,12 @@ impl RuntimeCache {
 		runtime_cache_size: u8,
 	) -> RuntimeCache {
 		let cap = ByLength::new(runtime_cache_size.max(1) as u32);
-		RuntimeCache { runtimes: Mutex::new(LruMap::new(cap)), max_runtime_instances, cache_path }
+		RuntimeCache {
+			runtimes: Mutex::new(LruMap::new(cap)),
+			compiling: Default::default(),
+			max_runtime_instances,
+			cache_path,
+		}
 	}
 
 	/// Prepares a WASM module instance and executes given function for it.
@@ -238,51 +253,77 @@ impl RuntimeCache {
 		let versioned_runtime_id =
 			VersionedRuntimeId { code_hash: code_hash.clone(), heap_alloc_strategy, wasm_method };
 
-		let mut runtimes = self.runtimes.lock(); // this must be released prior to calling f
-		let versioned_runtime = if let Some(versioned_runtime) = runtimes.get(&versioned_runtime_id)
-		{
-			versioned_runtime.clone()
-		} else {
-			let code = runtime_code.fetch_runtime_code().ok_or(WasmError::CodeNotFound)?;
-
-			let time = std::time::Instant::now();
-
-			let result = create_versioned_wasm_runtime::<H>(
-				&code,
-				ext,
-				wasm_method,
-				heap_alloc_strategy,
-				allow_missing_func_imports,
-				self.max_runtime_instances,
-				self.cache_path.as_deref(),
-			);
-
-			match result {
-				Ok(ref result) => {
-					tracing::debug!(
-						target: "wasm-runtime",
-						"Prepared new runtime version {:?} in {} ms.",
-						result.version,
-						time.elapsed().as_millis(),
-					);
-				},
-				Err(ref err) => {
-					tracing::warn!(target: "wasm-runtime", error = ?err, "Cannot create a runtime");
-				},
-			}
+		let versioned_runtime = self.get_or_create_runtime::<H>(
+			versioned_runtime_id,
+			runtime_code,
+			ext,
+			allow_missing_func_imports,
+		)?;
 
-			let versioned_runtime = Arc::new(result?);
+		Ok(versioned_runtime.with_instance(ext, f))
+	}
 
-			// Save new versioned wasm runtime in cache
-			runtimes.insert(versioned_runtime_id, versioned_runtime.clone());
+	/// Returns the cached runtime with the given id or compiles and caches it.
+	fn get_or_create_runtime<H: HostFunctions>(
+		&self,
+		versioned_runtime_id: VersionedRuntimeId,
+		runtime_code: &RuntimeCode,
+		ext: &mut dyn Externalities,
+		allow_missing_func_imports: bool,
+	) -> Result<Arc<VersionedRuntime>, Error> {
+		if let Some(versioned_runtime) = self.runtimes.lock().get(&versioned_runtime_id) {
+			return Ok(versioned_runtime.clone())
+		}
+
+		let compile_lock =
+			self.compiling.lock().entry(versioned_runtime_id.clone()).or_default().clone();
+		let _compile_guard = compile_lock.lock();
+
+		// The runtime may have been compiled while we were waiting for the lock.
+		if let Some(versioned_runtime) = self.runtimes.lock().get(&versioned_runtime_id) {
+			return Ok(versioned_runtime.clone())
+		}
 
+		let code = runtime_code.fetch_runtime_code().ok_or(WasmError::CodeNotFound)?;
+
+		let time = std::time::Instant::now();
+
+		let result = create_versioned_wasm_runtime::<H>(
+			&code,
+			ext,
+			versioned_runtime_id.wasm_method,
+			versioned_runtime_id.heap_alloc_strategy,
+			allow_missing_func_imports,
+			self.max_runtime_instances,
+			self.cache_path.as_deref(),
+		);
+
+		match result {
+			Ok(ref result) => {
+				tracing::debug!(
+					target: "wasm-runtime",
+					"Prepared new runtime version {:?} in {} ms.",
+					result.version,
+					time.elapsed().as_millis(),
+				);
+			},
+			Err(ref err) => {
+				tracing::warn!(target: "wasm-runtime", error = ?err, "Cannot create a runtime");
+			},
+		}
+
+		let result = result.map(|versioned_runtime| {
+			let versioned_runtime = Arc::new(versioned_runtime);
+			// Save new versioned wasm runtime in cache
+			self.runtimes
+				.lock()
+				.insert(versioned_runtime_id.clone(), versioned_runtime.clone());
 			versioned_runtime
-		};
+		});
 
-		// Lock must be released prior to calling f
-		drop(runtimes);
+		self.compiling.lock().remove(&versioned_runtime_id);
 
-		Ok(versioned_runtime.with_instance(ext, f))
+		result.map_err(Into::into)
 	}
 }
 
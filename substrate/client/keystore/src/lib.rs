@@ -25,7 +25,10 @@ use std::io;
 
 /// Local keystore implementation
 mod local;
+/// Remote signer keystore implementation
+mod remote;
 pub use local::LocalKeystore;
+pub use remote::RemoteKeystore;
 pub use sp_keystore::Keystore;
 
 /// Keystore error.
@@ -55,6 +58,9 @@ pub enum Error {
 	/// Keystore unavailable
 	#[error("Keystore unavailable")]
 	Unavailable,
+	/// Remote signer error
+	#[error("Remote signer: {0}")]
+	RemoteSigner(String),
 }
 
 /// Keystore Result
@@ -69,6 +75,7 @@ impl From<Error> for TraitError {
 			Error::Unavailable => TraitError::Unavailable,
 			Error::Io(e) => TraitError::Other(e.to_string()),
 			Error::Json(e) => TraitError::Other(e.to_string()),
+			Error::RemoteSigner(e) => TraitError::Other(e),
 		}
 	}
 }
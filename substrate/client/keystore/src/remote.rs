@@ -0,0 +1,777 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Keystore that delegates the signing to a remote signer.
+//!
+//! The private keys never leave the remote signer, which can for example keep them in an HSM.
+//! The node connects to the signer over TCP (`<host>:<port>` or `tcp://<host>:<port>`) or over a
+//! Unix socket (`unix:<path>`) and sends [JSON-RPC 2.0](https://www.jsonrpc.org/specification)
+//! requests, one JSON object per line. Each request is answered with one line.
+//!
+//! Binary data is `0x` prefixed hex, key types are the four character key type ids (e.g. `aura`)
+//! and the crypto schemes are named `sr25519`, `ed25519`, `ecdsa`, `bandersnatch`, `bls377`,
+//! `bls381` and `ecdsa_bls377`. The methods are:
+//!
+//! - `signer_authenticate` with `{"token"}`, sent as the first request of every connection when a
+//!   token is configured. Returns `true` if the token is accepted. As the connection is not
+//!   encrypted, a token can only be used with a Unix socket.
+//! - `signer_publicKeys` with `{"keyType", "crypto"}`. Returns the list of public keys.
+//! - `signer_generate` with `{"keyType", "crypto"}`. Generates a new key and returns its public
+//!   key.
+//! - `signer_sign` with `{"keyType", "crypto", "public", "message", "prehashed"}`. Returns the
+//!   signature, or `null` if the signer doesn't have the key. `prehashed` is only `true` for ecdsa
+//!   signatures of 32 byte message hashes.
+//!
+//! VRF signatures can't be created by the remote signer, as the VRF transcripts can't be
+//! serialized. Nodes that author BABE blocks or validate parachains can't use it. Keys can't be
+//! inserted either, as their secrets would have to be sent to the signer.
+
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use sp_core::{
+	crypto::{ByteArray, ExposeSecret, KeyTypeId, Pair as CorePair, SecretString},
+	ecdsa, ed25519, sr25519,
+};
+use sp_keystore::{Error as TraitError, Keystore};
+use std::{
+	io::{self, BufRead, BufReader, Read, Write},
+	net::{TcpStream, ToSocketAddrs},
+	time::Duration,
+};
+
+sp_keystore::bandersnatch_experimental_enabled! {
+use sp_core::bandersnatch;
+}
+
+sp_keystore::bls_experimental_enabled! {
+use sp_core::{bls377, bls381, ecdsa_bls377};
+}
+
+use crate::{Error, Result};
+
+const LOG_TARGET: &str = "keystore";
+
+/// Timeout of connecting to the signer and of each request.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The crypto schemes whose keys are returned by [`Keystore::keys`].
+const KEYS_CRYPTO: [&str; 3] = [SR25519, ED25519, ECDSA];
+
+const SR25519: &str = "sr25519";
+const ED25519: &str = "ed25519";
+const ECDSA: &str = "ecdsa";
+
+/// Address of the remote signer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Endpoint {
+	Tcp(String),
+	#[cfg(unix)]
+	Unix(std::path::PathBuf),
+}
+
+impl Endpoint {
+	fn parse(uri: &str) -> Result<Self> {
+		if let Some(path) = uri.strip_prefix("unix:") {
+			#[cfg(unix)]
+			return Ok(Self::Unix(path.into()));
+			#[cfg(not(unix))]
+			return Err(Error::RemoteSigner(format!("Unix sockets are not supported: {}", path)));
+		}
+
+		let address = uri.strip_prefix("tcp://").unwrap_or(uri);
+		if address.is_empty() {
+			return Err(Error::RemoteSigner("The remote signer URI is empty".into()))
+		}
+		Ok(Self::Tcp(address.into()))
+	}
+
+	fn is_unix(&self) -> bool {
+		match self {
+			Self::Tcp(_) => false,
+			#[cfg(unix)]
+			Self::Unix(_) => true,
+		}
+	}
+
+	fn connect(&self) -> Result<Connection> {
+		match self {
+			Self::Tcp(address) => {
+				let mut last_error = None;
+				for address in address.to_socket_addrs()? {
+					match TcpStream::connect_timeout(&address, TIMEOUT) {
+						Ok(stream) => {
+							stream.set_nodelay(true)?;
+							stream.set_read_timeout(Some(TIMEOUT))?;
+							stream.set_write_timeout(Some(TIMEOUT))?;
+							return Ok(Connection::new(
+								Box::new(stream.try_clone()?),
+								Box::new(stream),
+							))
+						},
+						Err(e) => last_error = Some(e),
+					}
+				}
+				Err(last_error
+					.unwrap_or_else(|| {
+						io::Error::new(io::ErrorKind::NotFound, "Address resolved to nothing")
+					})
+					.into())
+			},
+			#[cfg(unix)]
+			Self::Unix(path) => {
+				let stream = std::os::unix::net::UnixStream::connect(path)?;
+				stream.set_read_timeout(Some(TIMEOUT))?;
+				stream.set_write_timeout(Some(TIMEOUT))?;
+				Ok(Connection::new(Box::new(stream.try_clone()?), Box::new(stream)))
+			},
+		}
+	}
+}
+
+/// An established connection to the signer.
+struct Connection {
+	reader: BufReader<Box<dyn Read + Send>>,
+	writer: Box<dyn Write + Send>,
+	next_id: u64,
+}
+
+impl Connection {
+	fn new(reader: Box<dyn Read + Send>, writer: Box<dyn Write + Send>) -> Self {
+		Self { reader: BufReader::new(reader), writer, next_id: 0 }
+	}
+
+	/// Sends the request and waits for its response.
+	///
+	/// Returns the error message if the signer answered with an error. Any other error means
+	/// that the connection can't be used anymore.
+	fn call(&mut self, method: &str, params: Value) -> Result<std::result::Result<Value, String>> {
+		self.next_id += 1;
+		let request = json!({
+			"jsonrpc": "2.0",
+			"id": self.next_id,
+			"method": method,
+			"params": params,
+		});
+		serde_json::to_writer(&mut self.writer, &request)?;
+		self.writer.write_all(b"\n")?;
+		self.writer.flush()?;
+
+		let mut line = String::new();
+		if self.reader.read_line(&mut line)? == 0 {
+			return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into())
+		}
+
+		let mut response: Value = serde_json::from_str(&line)?;
+		if response["id"] != self.next_id {
+			return Err(Error::RemoteSigner(format!("Unexpected response: {}", line.trim())))
+		}
+		if let Some(error) = response.get("error") {
+			return Ok(Err(error["message"].as_str().unwrap_or("unknown error").into()))
+		}
+		Ok(Ok(response["result"].take()))
+	}
+}
+
+/// A keystore that delegates the signing to a remote signer.
+///
+/// See the [module documentation](self) for the protocol.
+pub struct RemoteKeystore {
+	endpoint: Endpoint,
+	token: Option<SecretString>,
+	/// Idle connections. Concurrent requests use separate connections.
+	connections: Mutex<Vec<Connection>>,
+}
+
+impl RemoteKeystore {
+	/// Connects to the remote signer at `uri`.
+	///
+	/// The `token` is sent to authenticate every new connection. As it is sent in plaintext, a
+	/// token can only be used with a Unix socket.
+	pub fn open(uri: &str, token: Option<SecretString>) -> Result<Self> {
+		let endpoint = Endpoint::parse(uri)?;
+		if token.is_some() && !endpoint.is_unix() {
+			return Err(Error::RemoteSigner(
+				"A token can only be sent to a remote signer over a Unix socket (`unix:<path>`)"
+					.into(),
+			))
+		}
+
+		let keystore = Self { endpoint, token, connections: Mutex::new(Vec::new()) };
+		// Fail early if the signer is not reachable or the token is not accepted.
+		let connection = keystore.connect()?;
+		keystore.connections.lock().push(connection);
+		Ok(keystore)
+	}
+
+	fn connect(&self) -> Result<Connection> {
+		let mut connection = self.endpoint.connect()?;
+		if let Some(token) = &self.token {
+			let authenticated = connection
+				.call("signer_authenticate", json!({ "token": token.expose_secret() }))?;
+			if authenticated != Ok(Value::Bool(true)) {
+				return Err(Error::RemoteSigner("The token was not accepted".into()))
+			}
+		}
+		Ok(connection)
+	}
+
+	/// Sends the request to the signer.
+	///
+	/// The keystore is called from async tasks, so the request is sent with
+	/// [`tokio::task::block_in_place`] when running on a multi-threaded tokio runtime. This way
+	/// the other tasks of the worker thread are not blocked while waiting for the signer.
+	fn request(&self, method: &str, params: Value) -> Result<Value> {
+		match tokio::runtime::Handle::try_current() {
+			Ok(handle) if handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread =>
+				tokio::task::block_in_place(|| self.blocking_request(method, params)),
+			_ => self.blocking_request(method, params),
+		}
+	}
+
+	/// Sends the request to the signer over an idle connection, or over a new one.
+	///
+	/// An idle connection may have been closed by the signer, in which case the request is sent
+	/// again over a new connection.
+	fn blocking_request(&self, method: &str, params: Value) -> Result<Value> {
+		let idle = self.connections.lock().pop();
+		let (connection, response) = match idle {
+			Some(mut connection) => match connection.call(method, params.clone()) {
+				Ok(response) => (connection, response),
+				Err(e) => {
+					log::debug!(target: LOG_TARGET, "Reconnecting to the remote signer: {}", e);
+					let mut connection = self.connect()?;
+					let response = connection.call(method, params)?;
+					(connection, response)
+				},
+			},
+			None => {
+				let mut connection = self.connect()?;
+				let response = connection.call(method, params)?;
+				(connection, response)
+			},
+		};
+		self.connections.lock().push(connection);
+
+		response.map_err(|e| Error::RemoteSigner(format!("Request `{}` failed: {}", method, e)))
+	}
+
+	fn raw_public_keys(&self, crypto: &str, key_type: KeyTypeId) -> Result<Vec<Vec<u8>>> {
+		let keys = self.request(
+			"signer_publicKeys",
+			json!({ "keyType": key_type_str(key_type), "crypto": crypto }),
+		)?;
+		serde_json::from_value::<Vec<String>>(keys)?
+			.iter()
+			.map(|key| decode_hex(key))
+			.collect()
+	}
+
+	fn public_keys<T: CorePair>(&self, crypto: &str, key_type: KeyTypeId) -> Vec<T::Public> {
+		match self.raw_public_keys(crypto, key_type) {
+			Ok(keys) => keys.iter().filter_map(|key| T::Public::from_slice(key).ok()).collect(),
+			Err(e) => {
+				log::warn!(
+					target: LOG_TARGET,
+					"Failed to get the {} keys of type {:?} from the remote signer: {}",
+					crypto,
+					key_type,
+					e,
+				);
+				Vec::new()
+			},
+		}
+	}
+
+	fn generate_new<T: CorePair>(
+		&self,
+		crypto: &str,
+		key_type: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<T::Public, TraitError> {
+		if seed.is_some() {
+			return Err(TraitError::Other(
+				"The remote signer can't generate keys from a seed".into(),
+			))
+		}
+
+		let public = self.request(
+			"signer_generate",
+			json!({ "keyType": key_type_str(key_type), "crypto": crypto }),
+		)?;
+		let public = decode_hex(&serde_json::from_value::<String>(public).map_err(Error::from)?)?;
+		T::Public::from_slice(&public)
+			.map_err(|_| Error::RemoteSigner(format!("Invalid {} public key", crypto)).into())
+	}
+
+	fn sign<T: CorePair>(
+		&self,
+		crypto: &str,
+		key_type: KeyTypeId,
+		public: &T::Public,
+		msg: &[u8],
+		prehashed: bool,
+	) -> std::result::Result<Option<T::Signature>, TraitError>
+	where
+		T::Signature: for<'a> TryFrom<&'a [u8]>,
+	{
+		let signature = self.request(
+			"signer_sign",
+			json!({
+				"keyType": key_type_str(key_type),
+				"crypto": crypto,
+				"public": array_bytes::bytes2hex("0x", public.as_slice()),
+				"message": array_bytes::bytes2hex("0x", msg),
+				"prehashed": prehashed,
+			}),
+		)?;
+		let Some(signature) =
+			serde_json::from_value::<Option<String>>(signature).map_err(Error::from)?
+		else {
+			return Ok(None)
+		};
+
+		let signature = decode_hex(&signature)?;
+		T::Signature::try_from(&signature[..])
+			.map(Some)
+			.map_err(|_| Error::RemoteSigner(format!("Invalid {} signature", crypto)).into())
+	}
+
+	fn vrf_unsupported<T>() -> std::result::Result<T, TraitError> {
+		Err(TraitError::Other("VRF signatures are not supported by the remote signer".into()))
+	}
+}
+
+fn key_type_str(key_type: KeyTypeId) -> String {
+	String::from_utf8_lossy(&key_type.0).into_owned()
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>> {
+	array_bytes::hex2bytes(hex).map_err(|_| Error::RemoteSigner(format!("Invalid hex: {}", hex)))
+}
+
+impl Keystore for RemoteKeystore {
+	fn insert(
+		&self,
+		key_type: KeyTypeId,
+		_suri: &str,
+		_public: &[u8],
+	) -> std::result::Result<(), ()> {
+		log::warn!(
+			target: LOG_TARGET,
+			"Can't insert a key of type {:?}: the remote signer doesn't accept secret keys",
+			key_type,
+		);
+		Err(())
+	}
+
+	fn keys(&self, key_type: KeyTypeId) -> std::result::Result<Vec<Vec<u8>>, TraitError> {
+		let mut keys = Vec::new();
+		for crypto in KEYS_CRYPTO {
+			keys.extend(self.raw_public_keys(crypto, key_type)?);
+		}
+		Ok(keys)
+	}
+
+	fn has_keys(&self, public_keys: &[(Vec<u8>, KeyTypeId)]) -> bool {
+		public_keys.iter().all(|(public, key_type)| {
+			self.keys(*key_type).map_or(false, |keys| keys.contains(public))
+		})
+	}
+
+	fn sr25519_public_keys(&self, key_type: KeyTypeId) -> Vec<sr25519::Public> {
+		self.public_keys::<sr25519::Pair>(SR25519, key_type)
+	}
+
+	fn sr25519_generate_new(
+		&self,
+		key_type: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<sr25519::Public, TraitError> {
+		self.generate_new::<sr25519::Pair>(SR25519, key_type, seed)
+	}
+
+	fn sr25519_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &sr25519::Public,
+		msg: &[u8],
+	) -> std::result::Result<Option<sr25519::Signature>, TraitError> {
+		self.sign::<sr25519::Pair>(SR25519, key_type, public, msg, false)
+	}
+
+	fn sr25519_vrf_sign(
+		&self,
+		_key_type: KeyTypeId,
+		_public: &sr25519::Public,
+		_data: &sr25519::vrf::VrfSignData,
+	) -> std::result::Result<Option<sr25519::vrf::VrfSignature>, TraitError> {
+		Self::vrf_unsupported()
+	}
+
+	fn sr25519_vrf_pre_output(
+		&self,
+		_key_type: KeyTypeId,
+		_public: &sr25519::Public,
+		_input: &sr25519::vrf::VrfInput,
+	) -> std::result::Result<Option<sr25519::vrf::VrfPreOutput>, TraitError> {
+		Self::vrf_unsupported()
+	}
+
+	fn ed25519_public_keys(&self, key_type: KeyTypeId) -> Vec<ed25519::Public> {
+		self.public_keys::<ed25519::Pair>(ED25519, key_type)
+	}
+
+	fn ed25519_generate_new(
+		&self,
+		key_type: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<ed25519::Public, TraitError> {
+		self.generate_new::<ed25519::Pair>(ED25519, key_type, seed)
+	}
+
+	fn ed25519_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &ed25519::Public,
+		msg: &[u8],
+	) -> std::result::Result<Option<ed25519::Signature>, TraitError> {
+		self.sign::<ed25519::Pair>(ED25519, key_type, public, msg, false)
+	}
+
+	fn ecdsa_public_keys(&self, key_type: KeyTypeId) -> Vec<ecdsa::Public> {
+		self.public_keys::<ecdsa::Pair>(ECDSA, key_type)
+	}
+
+	fn ecdsa_generate_new(
+		&self,
+		key_type: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<ecdsa::Public, TraitError> {
+		self.generate_new::<ecdsa::Pair>(ECDSA, key_type, seed)
+	}
+
+	fn ecdsa_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &ecdsa::Public,
+		msg: &[u8],
+	) -> std::result::Result<Option<ecdsa::Signature>, TraitError> {
+		self.sign::<ecdsa::Pair>(ECDSA, key_type, public, msg, false)
+	}
+
+	fn ecdsa_sign_prehashed(
+		&self,
+		key_type: KeyTypeId,
+		public: &ecdsa::Public,
+		msg: &[u8; 32],
+	) -> std::result::Result<Option<ecdsa::Signature>, TraitError> {
+		self.sign::<ecdsa::Pair>(ECDSA, key_type, public, msg, true)
+	}
+
+	sp_keystore::bandersnatch_experimental_enabled! {
+		fn bandersnatch_public_keys(&self, key_type: KeyTypeId) -> Vec<bandersnatch::Public> {
+			self.public_keys::<bandersnatch::Pair>("bandersnatch", key_type)
+		}
+
+		fn bandersnatch_generate_new(
+			&self,
+			key_type: KeyTypeId,
+			seed: Option<&str>,
+		) -> std::result::Result<bandersnatch::Public, TraitError> {
+			self.generate_new::<bandersnatch::Pair>("bandersnatch", key_type, seed)
+		}
+
+		fn bandersnatch_sign(
+			&self,
+			key_type: KeyTypeId,
+			public: &bandersnatch::Public,
+			msg: &[u8],
+		) -> std::result::Result<Option<bandersnatch::Signature>, TraitError> {
+			self.sign::<bandersnatch::Pair>("bandersnatch", key_type, public, msg, false)
+		}
+
+		fn bandersnatch_vrf_sign(
+			&self,
+			_key_type: KeyTypeId,
+			_public: &bandersnatch::Public,
+			_data: &bandersnatch::vrf::VrfSignData,
+		) -> std::result::Result<Option<bandersnatch::vrf::VrfSignature>, TraitError> {
+			Self::vrf_unsupported()
+		}
+
+		fn bandersnatch_vrf_pre_output(
+			&self,
+			_key_type: KeyTypeId,
+			_public: &bandersnatch::Public,
+			_input: &bandersnatch::vrf::VrfInput,
+		) -> std::result::Result<Option<bandersnatch::vrf::VrfPreOutput>, TraitError> {
+			Self::vrf_unsupported()
+		}
+
+		fn bandersnatch_ring_vrf_sign(
+			&self,
+			_key_type: KeyTypeId,
+			_public: &bandersnatch::Public,
+			_data: &bandersnatch::vrf::VrfSignData,
+			_prover: &bandersnatch::ring_vrf::RingProver,
+		) -> std::result::Result<Option<bandersnatch::ring_vrf::RingVrfSignature>, TraitError> {
+			Self::vrf_unsupported()
+		}
+	}
+
+	sp_keystore::bls_experimental_enabled! {
+		fn bls381_public_keys(&self, key_type: KeyTypeId) -> Vec<bls381::Public> {
+			self.public_keys::<bls381::Pair>("bls381", key_type)
+		}
+
+		fn bls381_generate_new(
+			&self,
+			key_type: KeyTypeId,
+			seed: Option<&str>,
+		) -> std::result::Result<bls381::Public, TraitError> {
+			self.generate_new::<bls381::Pair>("bls381", key_type, seed)
+		}
+
+		fn bls381_sign(
+			&self,
+			key_type: KeyTypeId,
+			public: &bls381::Public,
+			msg: &[u8],
+		) -> std::result::Result<Option<bls381::Signature>, TraitError> {
+			self.sign::<bls381::Pair>("bls381", key_type, public, msg, false)
+		}
+
+		fn bls377_public_keys(&self, key_type: KeyTypeId) -> Vec<bls377::Public> {
+			self.public_keys::<bls377::Pair>("bls377", key_type)
+		}
+
+		fn bls377_generate_new(
+			&self,
+			key_type: KeyTypeId,
+			seed: Option<&str>,
+		) -> std::result::Result<bls377::Public, TraitError> {
+			self.generate_new::<bls377::Pair>("bls377", key_type, seed)
+		}
+
+		fn bls377_sign(
+			&self,
+			key_type: KeyTypeId,
+			public: &bls377::Public,
+			msg: &[u8],
+		) -> std::result::Result<Option<bls377::Signature>, TraitError> {
+			self.sign::<bls377::Pair>("bls377", key_type, public, msg, false)
+		}
+
+		fn ecdsa_bls377_public_keys(&self, key_type: KeyTypeId) -> Vec<ecdsa_bls377::Public> {
+			self.public_keys::<ecdsa_bls377::Pair>("ecdsa_bls377", key_type)
+		}
+
+		fn ecdsa_bls377_generate_new(
+			&self,
+			key_type: KeyTypeId,
+			seed: Option<&str>,
+		) -> std::result::Result<ecdsa_bls377::Public, TraitError> {
+			self.generate_new::<ecdsa_bls377::Pair>("ecdsa_bls377", key_type, seed)
+		}
+
+		fn ecdsa_bls377_sign(
+			&self,
+			key_type: KeyTypeId,
+			public: &ecdsa_bls377::Public,
+			msg: &[u8],
+		) -> std::result::Result<Option<ecdsa_bls377::Signature>, TraitError> {
+			self.sign::<ecdsa_bls377::Pair>("ecdsa_bls377", key_type, public, msg, false)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::LocalKeystore;
+	use sp_core::Pair;
+	use std::{net::TcpListener, sync::Arc};
+
+	const AURA: KeyTypeId = KeyTypeId(*b"aura");
+	const TOKEN: &str = "secret";
+
+	/// Answers a single request of the test signer, which is backed by `keystore`.
+	fn answer(keystore: &LocalKeystore, request: &Value, authenticated: &mut bool) -> Value {
+		let params = &request["params"];
+		let key_type = || KeyTypeId::try_from(params["keyType"].as_str().unwrap()).unwrap();
+		let result = match request["method"].as_str().unwrap() {
+			"signer_authenticate" => {
+				*authenticated = params["token"] == TOKEN;
+				json!(*authenticated)
+			},
+			_ if !*authenticated => return json!({ "message": "not authenticated" }),
+			"signer_publicKeys" if params["crypto"] == SR25519 => json!(keystore
+				.sr25519_public_keys(key_type())
+				.iter()
+				.map(|key| array_bytes::bytes2hex("0x", key))
+				.collect::<Vec<_>>()),
+			"signer_publicKeys" => json!([]),
+			"signer_generate" => json!(array_bytes::bytes2hex(
+				"0x",
+				keystore.sr25519_generate_new(key_type(), None).unwrap()
+			)),
+			"signer_sign" => {
+				let public = decode_hex(params["public"].as_str().unwrap()).unwrap();
+				let public = sr25519::Public::from_slice(&public).unwrap();
+				let message = decode_hex(params["message"].as_str().unwrap()).unwrap();
+				json!(keystore
+					.sr25519_sign(key_type(), &public, &message)
+					.unwrap()
+					.map(|signature| array_bytes::bytes2hex("0x", signature)))
+			},
+			_ => return json!({ "message": "unknown method" }),
+		};
+		json!({ "result": result })
+	}
+
+	/// Answers the requests received over a connection to the test signer.
+	fn serve(keystore: &LocalKeystore, reader: impl Read, mut writer: impl Write) {
+		let mut authenticated = false;
+		for line in BufReader::new(reader).lines() {
+			let request: Value = serde_json::from_str(&line.unwrap()).unwrap();
+			let mut response = answer(keystore, &request, &mut authenticated);
+			if response.get("result").is_none() {
+				response = json!({ "error": response });
+			}
+			response["jsonrpc"] = json!("2.0");
+			response["id"] = request["id"].clone();
+			writeln!(writer, "{}", response).unwrap();
+		}
+	}
+
+	/// Starts a signer on a local TCP port and returns its address.
+	fn start_tcp_signer(keystore: Arc<LocalKeystore>) -> String {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let address = listener.local_addr().unwrap().to_string();
+		std::thread::spawn(move || {
+			for stream in listener.incoming() {
+				let stream = stream.unwrap();
+				let keystore = keystore.clone();
+				std::thread::spawn(move || serve(&keystore, stream.try_clone().unwrap(), stream));
+			}
+		});
+		address
+	}
+
+	/// Starts a signer on a Unix socket in `dir` and returns its URI.
+	#[cfg(unix)]
+	fn start_unix_signer(keystore: Arc<LocalKeystore>, dir: &tempfile::TempDir) -> String {
+		let path = dir.path().join("signer.sock");
+		let listener = std::os::unix::net::UnixListener::bind(&path).unwrap();
+		std::thread::spawn(move || {
+			for stream in listener.incoming() {
+				let stream = stream.unwrap();
+				let keystore = keystore.clone();
+				std::thread::spawn(move || serve(&keystore, stream.try_clone().unwrap(), stream));
+			}
+		});
+		format!("unix:{}", path.display())
+	}
+
+	#[test]
+	fn remote_signing_works() {
+		let local = Arc::new(LocalKeystore::in_memory());
+		let public = local.sr25519_generate_new(AURA, None).unwrap();
+		let address = start_tcp_signer(local.clone());
+
+		let remote = RemoteKeystore::open(&address, None).unwrap();
+
+		assert_eq!(remote.sr25519_public_keys(AURA), vec![public]);
+		assert!(remote.has_keys(&[(public.to_raw_vec(), AURA)]));
+		assert!(remote.ed25519_public_keys(AURA).is_empty());
+
+		let signature = remote.sr25519_sign(AURA, &public, b"message").unwrap().unwrap();
+		assert!(sr25519::Pair::verify(&signature, b"message", &public));
+
+		let unknown = sr25519::Pair::generate().0.public();
+		assert_eq!(remote.sr25519_sign(AURA, &unknown, b"message").unwrap(), None);
+
+		let generated = remote.sr25519_generate_new(AURA, None).unwrap();
+		assert!(local.has_keys(&[(generated.to_raw_vec(), AURA)]));
+		assert!(remote.sr25519_generate_new(AURA, Some("//Alice")).is_err());
+		assert!(remote.insert(AURA, "//Alice", public.as_ref()).is_err());
+	}
+
+	#[test]
+	fn remote_signing_works_on_multi_threaded_runtime() {
+		let local = Arc::new(LocalKeystore::in_memory());
+		let public = local.sr25519_generate_new(AURA, None).unwrap();
+		let remote = Arc::new(RemoteKeystore::open(&start_tcp_signer(local), None).unwrap());
+
+		let runtime = tokio::runtime::Builder::new_multi_thread().build().unwrap();
+		let signature = runtime
+			.block_on(runtime.spawn(async move { remote.sr25519_sign(AURA, &public, b"message") }))
+			.unwrap()
+			.unwrap()
+			.unwrap();
+		assert!(sr25519::Pair::verify(&signature, b"message", &public));
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn remote_signer_authentication_works() {
+		let dir = tempfile::tempdir().unwrap();
+		let local = Arc::new(LocalKeystore::in_memory());
+		let public = local.sr25519_generate_new(AURA, None).unwrap();
+		let uri = start_unix_signer(local, &dir);
+
+		let remote = RemoteKeystore::open(&uri, Some(SecretString::new(TOKEN.into()))).unwrap();
+		assert_eq!(remote.sr25519_public_keys(AURA), vec![public]);
+
+		assert!(matches!(
+			RemoteKeystore::open(&uri, Some(SecretString::new("wrong".into()))),
+			Err(Error::RemoteSigner(_))
+		));
+
+		let remote = RemoteKeystore::open(&uri, None).unwrap();
+		assert!(remote.keys(AURA).is_err());
+	}
+
+	#[test]
+	fn remote_signer_token_requires_unix_socket() {
+		let address = start_tcp_signer(Arc::new(LocalKeystore::in_memory()));
+
+		assert!(matches!(
+			RemoteKeystore::open(&address, Some(SecretString::new(TOKEN.into()))),
+			Err(Error::RemoteSigner(_))
+		));
+	}
+
+	#[test]
+	fn endpoint_parsing_works() {
+		assert_eq!(
+			Endpoint::parse("127.0.0.1:4000").unwrap(),
+			Endpoint::Tcp("127.0.0.1:4000".into())
+		);
+		assert_eq!(
+			Endpoint::parse("tcp://signer:4000").unwrap(),
+			Endpoint::Tcp("signer:4000".into())
+		);
+		#[cfg(unix)]
+		assert_eq!(
+			Endpoint::parse("unix:/run/signer.sock").unwrap(),
+			Endpoint::Unix("/run/signer.sock".into())
+		);
+		assert!(Endpoint::parse("tcp://").is_err());
+	}
+}
@@ -194,6 +194,42 @@ impl<B: BlockT> BlockCollection<B> {
 		Some(range)
 	}
 
+	/// Returns the first range of the collection if it is still being downloaded from less than
+	/// `max_parallel` peers that are all slower than `who`, according to `is_faster`. The returned
+	/// range is marked as being downloaded from `who` as well.
+	///
+	/// The blocks above the first range can't be imported before it is downloaded, so when it is
+	/// downloaded from a slow peer, requesting it from a faster one too unblocks the import.
+	pub fn needed_stalled_blocks(
+		&mut self,
+		who: PeerId,
+		peer_best: NumberFor<B>,
+		common: NumberFor<B>,
+		max_parallel: u32,
+		is_faster: impl Fn(&PeerId) -> bool,
+	) -> Option<Range<NumberFor<B>>> {
+		let (&start, state) = self.blocks.iter_mut().next()?;
+		let BlockRangeState::Downloading { len, ref mut downloading } = *state else { return None };
+		let range = start..start + len;
+		if *downloading >= max_parallel ||
+			range.start <= common ||
+			range.end > peer_best + One::one()
+		{
+			return None
+		}
+
+		let mut downloaders =
+			self.peer_requests.iter().filter(|(_, s)| **s == start).map(|(p, _)| p);
+		if self.peer_requests.get(&who) == Some(&start) || !downloaders.all(|p| is_faster(p)) {
+			return None
+		}
+
+		trace!(target: "sync", "Requesting stalled range {:?} from {}", range, who);
+		*downloading += 1;
+		self.peer_requests.insert(who, start);
+		Some(range)
+	}
+
 	/// Get a valid chain of blocks ordered in descending order and ready for importing into
 	/// the blockchain.
 	/// `from` is the maximum block number for the start of the range that we are interested in.
@@ -389,6 +425,43 @@ mod test {
 		);
 	}
 
+	#[test]
+	fn stalled_range_is_requested_from_faster_peer() {
+		let mut bc: BlockCollection<Block> = BlockCollection::new();
+		let slow = PeerId::random();
+		let fast = PeerId::random();
+		let other = PeerId::random();
+
+		assert_eq!(bc.needed_blocks(slow, 10, 100, 0, 1, 20), Some(1..11));
+		assert_eq!(bc.needed_blocks(fast, 10, 100, 0, 1, 20), Some(11..21));
+		assert_eq!(bc.needed_blocks(fast, 10, 100, 0, 1, 20), Some(21..31));
+		// too far ahead
+		assert_eq!(bc.needed_blocks(fast, 10, 100, 0, 1, 20), None);
+
+		// not faster than the peer downloading the first range
+		assert_eq!(bc.needed_stalled_blocks(fast, 100, 0, 2, |_| false), None);
+		// the first range is above the peer best or below the common number
+		assert_eq!(bc.needed_stalled_blocks(fast, 5, 0, 2, |_| true), None);
+		assert_eq!(bc.needed_stalled_blocks(fast, 100, 1, 2, |_| true), None);
+
+		assert_eq!(bc.needed_stalled_blocks(fast, 100, 0, 2, |p| *p == slow), Some(1..11));
+		// already downloaded from `max_parallel` peers
+		assert_eq!(bc.needed_stalled_blocks(other, 100, 0, 2, |_| true), None);
+
+		// the response of the fast peer completes the range
+		let blocks = generate_blocks(10);
+		bc.clear_peer_download(&fast);
+		bc.insert(1, blocks.clone(), fast);
+		assert_eq!(bc.needed_stalled_blocks(other, 100, 0, 3, |_| true), None);
+		assert_eq!(
+			bc.ready_blocks(1),
+			blocks
+				.into_iter()
+				.map(|b| BlockData { block: b, origin: Some(fast) })
+				.collect::<Vec<_>>()
+		);
+	}
+
 	#[test]
 	fn no_duplicate_requests_on_fork() {
 		let mut bc = BlockCollection::new();
@@ -31,6 +31,7 @@
 use crate::{
 	blocks::BlockCollection,
 	extra_requests::ExtraRequests,
+	peer_bandwidth::PeerBandwidth,
 	schema::v1::StateResponse,
 	state::{ImportResult, StateSync},
 	types::{
@@ -67,6 +68,7 @@ use std::{
 	collections::{HashMap, HashSet},
 	ops::Range,
 	sync::Arc,
+	time::Instant,
 };
 
 #[cfg(test)]
@@ -98,6 +100,10 @@ const MAJOR_SYNC_BLOCKS: u8 = 5;
 /// Number of peers that need to be connected before warp sync is started.
 const MIN_PEERS_TO_START_WARP_SYNC: usize = 3;
 
+/// Maximum number of peers to download the range blocking the import from, when the peers already
+/// downloading it are slow.
+const MAX_STALLED_RANGE_DOWNLOADS: u32 = 2;
+
 mod rep {
 	use sc_network::ReputationChange as Rep;
 	/// Reputation change when a peer sent us a message that led to a
@@ -236,6 +242,8 @@ pub struct ChainSync<B: BlockT, Client> {
 	max_parallel_downloads: u32,
 	/// Maximum blocks per request.
 	max_blocks_per_request: u32,
+	/// Download bandwidth of the peers, used to size the block requests.
+	peer_bandwidth: PeerBandwidth,
 	/// Total number of downloaded blocks.
 	downloaded_blocks: usize,
 	/// State sync in progress, if any.
@@ -362,6 +370,7 @@ where
 			allowed_requests: Default::default(),
 			max_parallel_downloads,
 			max_blocks_per_request,
+			peer_bandwidth: Default::default(),
 			downloaded_blocks: 0,
 			state_sync: None,
 			warp_sync: None,
@@ -690,6 +699,17 @@ where
 			}
 			self.allowed_requests.add(peer_id);
 			if let Some(request) = request {
+				if matches!(
+					peer.state,
+					PeerSyncState::DownloadingNew(_) | PeerSyncState::DownloadingGap(_)
+				) {
+					self.peer_bandwidth.response_received(
+						peer_id,
+						blocks.iter().map(|b| b.encoded_size()).sum(),
+						blocks.len(),
+						Instant::now(),
+					);
+				}
 				match &mut peer.state {
 					PeerSyncState::DownloadingNew(_) => {
 						self.blocks.clear_peer_download(peer_id);
@@ -1159,6 +1179,7 @@ where
 	/// Notify that a sync peer has disconnected.
 	pub fn peer_disconnected(&mut self, peer_id: &PeerId) {
 		self.blocks.clear_peer_download(peer_id);
+		self.peer_bandwidth.peer_disconnected(peer_id);
 		if let Some(gap_sync) = &mut self.gap_sync {
 			gap_sync.blocks.clear_peer_download(peer_id)
 		}
@@ -1591,6 +1612,7 @@ where
 		let max_parallel = if is_major_syncing { 1 } else { self.max_parallel_downloads };
 		let max_blocks_per_request = self.max_blocks_per_request;
 		let gap_sync = &mut self.gap_sync;
+		let peer_bandwidth = &mut self.peer_bandwidth;
 		self.peers
 			.iter_mut()
 			.filter_map(move |(&id, peer)| {
@@ -1632,8 +1654,10 @@ where
 					max_blocks_per_request,
 					last_finalized,
 					best_queued,
+					peer_bandwidth,
 				) {
 					peer.state = PeerSyncState::DownloadingNew(range.start);
+					peer_bandwidth.request_sent(id, Instant::now());
 					trace!(
 						target: LOG_TARGET,
 						"New block request for {}, (best:{}, common:{}) {:?}",
@@ -1669,10 +1693,11 @@ where
 						attrs,
 						sync.target,
 						sync.best_queued_number,
-						max_blocks_per_request,
+						peer_bandwidth.blocks_per_request(&id, max_blocks_per_request),
 					)
 				}) {
 					peer.state = PeerSyncState::DownloadingGap(range.start);
+					peer_bandwidth.request_sent(id, Instant::now());
 					trace!(
 						target: LOG_TARGET,
 						"New gap block request for {}, (best:{}, common:{}) {:?}",
@@ -2151,6 +2176,10 @@ fn handle_ancestor_search_state<B: BlockT>(
 }
 
 /// Get a new block request for the peer if any.
+///
+/// The size of the request is derived from the bandwidth of the peer. If the peer is idle because
+/// the blocks downloaded from slower peers are waiting for the range that blocks their import, that
+/// range is requested from the peer as well.
 fn peer_block_request<B: BlockT>(
 	id: &PeerId,
 	peer: &PeerSync<B>,
@@ -2160,6 +2189,7 @@ fn peer_block_request<B: BlockT>(
 	max_blocks_per_request: u32,
 	finalized: NumberFor<B>,
 	best_num: NumberFor<B>,
+	peer_bandwidth: &PeerBandwidth,
 ) -> Option<(Range<NumberFor<B>>, BlockRequest<B>)> {
 	if best_num >= peer.best_number {
 		// Will be downloaded as alternative fork instead.
@@ -2171,14 +2201,24 @@ fn peer_block_request<B: BlockT>(
 			id, peer.common_number, finalized, peer.best_number, best_num,
 		);
 	}
-	let range = blocks.needed_blocks(
-		*id,
-		max_blocks_per_request,
-		peer.best_number,
-		peer.common_number,
-		max_parallel_downloads,
-		MAX_DOWNLOAD_AHEAD,
-	)?;
+	let range = blocks
+		.needed_blocks(
+			*id,
+			peer_bandwidth.blocks_per_request(id, max_blocks_per_request),
+			peer.best_number,
+			peer.common_number,
+			max_parallel_downloads,
+			MAX_DOWNLOAD_AHEAD,
+		)
+		.or_else(|| {
+			blocks.needed_stalled_blocks(
+				*id,
+				peer.best_number,
+				peer.common_number,
+				max_parallel_downloads.max(MAX_STALLED_RANGE_DOWNLOADS),
+				|other| peer_bandwidth.is_faster(id, other),
+			)
+		})?;
 
 	// The end is not part of the range.
 	let last = range.end.saturating_sub(One::one());
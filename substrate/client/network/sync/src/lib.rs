@@ -25,6 +25,7 @@ mod block_announce_validator;
 mod chain_sync;
 mod extra_requests;
 mod futures_stream;
+mod peer_bandwidth;
 mod pending_responses;
 mod request_metrics;
 mod schema;
@@ -0,0 +1,199 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Estimation of the download bandwidth of the sync peers.
+//!
+//! The size of the block requests sent to a peer is derived from the rate at which it served the
+//! previous block responses, so that every request takes about [`TARGET_REQUEST_DURATION`]. This
+//! keeps slow peers from holding back the import of the blocks downloaded from faster peers.
+
+use libp2p::PeerId;
+use std::{
+	collections::HashMap,
+	time::{Duration, Instant},
+};
+
+/// Time a block request is expected to take.
+const TARGET_REQUEST_DURATION: Duration = Duration::from_secs(2);
+
+/// Minimum number of blocks to request from a peer, however slow it is.
+const MIN_BLOCKS_PER_REQUEST: u32 = 8;
+
+/// Weight of the latest response in the estimates.
+const SMOOTHING_FACTOR: f64 = 0.3;
+
+/// Factor by which a peer must be faster than another one to be considered faster.
+const FASTER_PEER_FACTOR: f64 = 2.0;
+
+/// Download rates of a peer, smoothed over the responses.
+#[derive(Debug, Clone, Copy)]
+struct Estimate {
+	/// Bytes received per second.
+	bytes_per_sec: f64,
+	/// Average size of a block in bytes.
+	block_size: f64,
+}
+
+/// Estimates the download bandwidth of the peers from their block responses.
+#[derive(Debug, Default)]
+pub(crate) struct PeerBandwidth {
+	/// Estimates of the peers that answered at least one block request.
+	estimates: HashMap<PeerId, Estimate>,
+	/// Time the pending block request to each peer was sent at.
+	pending: HashMap<PeerId, Instant>,
+}
+
+impl PeerBandwidth {
+	/// Record that a block request was sent to `who` at `now`.
+	pub(crate) fn request_sent(&mut self, who: PeerId, now: Instant) {
+		self.pending.insert(who, now);
+	}
+
+	/// Record that `who` answered its pending block request at `now` with `blocks` blocks
+	/// totalling `bytes` bytes.
+	pub(crate) fn response_received(
+		&mut self,
+		who: &PeerId,
+		bytes: usize,
+		blocks: usize,
+		now: Instant,
+	) {
+		let Some(sent) = self.pending.remove(who) else { return };
+		if blocks == 0 {
+			return
+		}
+
+		// Guard against a zero duration, in case the clock is too coarse.
+		let elapsed = now.saturating_duration_since(sent).max(Duration::from_millis(1));
+		let sample = Estimate {
+			bytes_per_sec: bytes as f64 / elapsed.as_secs_f64(),
+			block_size: (bytes as f64 / blocks as f64).max(1.0),
+		};
+		self.estimates
+			.entry(*who)
+			.and_modify(|estimate| {
+				estimate.bytes_per_sec +=
+					SMOOTHING_FACTOR * (sample.bytes_per_sec - estimate.bytes_per_sec);
+				estimate.block_size += SMOOTHING_FACTOR * (sample.block_size - estimate.block_size);
+			})
+			.or_insert(sample);
+	}
+
+	/// Forget everything about `who`.
+	pub(crate) fn peer_disconnected(&mut self, who: &PeerId) {
+		self.estimates.remove(who);
+		self.pending.remove(who);
+	}
+
+	/// Returns the number of blocks to request from `who`, at most `max_blocks_per_request`.
+	///
+	/// Peers that didn't answer a block request yet are assumed to be fast.
+	pub(crate) fn blocks_per_request(&self, who: &PeerId, max_blocks_per_request: u32) -> u32 {
+		let Some(estimate) = self.estimates.get(who) else { return max_blocks_per_request };
+		let blocks =
+			estimate.bytes_per_sec * TARGET_REQUEST_DURATION.as_secs_f64() / estimate.block_size;
+		(blocks as u32)
+			.clamp(MIN_BLOCKS_PER_REQUEST.min(max_blocks_per_request), max_blocks_per_request)
+	}
+
+	/// Returns `true` if `who` is known to be significantly faster than `other`.
+	pub(crate) fn is_faster(&self, who: &PeerId, other: &PeerId) -> bool {
+		match (self.estimates.get(who), self.estimates.get(other)) {
+			(Some(who), Some(other)) =>
+				who.bytes_per_sec > other.bytes_per_sec * FASTER_PEER_FACTOR,
+			_ => false,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const KB: usize = 1024;
+
+	fn respond(
+		bandwidth: &mut PeerBandwidth,
+		who: PeerId,
+		bytes: usize,
+		blocks: usize,
+		millis: u64,
+	) {
+		let now = Instant::now();
+		bandwidth.request_sent(who, now);
+		bandwidth.response_received(&who, bytes, blocks, now + Duration::from_millis(millis));
+	}
+
+	#[test]
+	fn unknown_peers_get_full_requests() {
+		let bandwidth = PeerBandwidth::default();
+		assert_eq!(bandwidth.blocks_per_request(&PeerId::random(), 64), 64);
+	}
+
+	#[test]
+	fn request_size_follows_bandwidth() {
+		let mut bandwidth = PeerBandwidth::default();
+		let fast = PeerId::random();
+		let slow = PeerId::random();
+		let very_slow = PeerId::random();
+
+		// 64 blocks of 10 KB in 100 ms: 1280 blocks in 2 seconds.
+		respond(&mut bandwidth, fast, 640 * KB, 64, 100);
+		// 64 blocks of 10 KB in 8 seconds: 16 blocks in 2 seconds.
+		respond(&mut bandwidth, slow, 640 * KB, 64, 8000);
+		// 64 blocks of 10 KB in 60 seconds: 2 blocks in 2 seconds.
+		respond(&mut bandwidth, very_slow, 640 * KB, 64, 60_000);
+
+		assert_eq!(bandwidth.blocks_per_request(&fast, 64), 64);
+		assert_eq!(bandwidth.blocks_per_request(&slow, 64), 16);
+		assert_eq!(bandwidth.blocks_per_request(&very_slow, 64), MIN_BLOCKS_PER_REQUEST);
+		assert_eq!(bandwidth.blocks_per_request(&very_slow, 4), 4);
+
+		assert!(bandwidth.is_faster(&fast, &slow));
+		assert!(!bandwidth.is_faster(&slow, &fast));
+		assert!(!bandwidth.is_faster(&fast, &PeerId::random()));
+
+		bandwidth.peer_disconnected(&slow);
+		assert_eq!(bandwidth.blocks_per_request(&slow, 64), 64);
+	}
+
+	#[test]
+	fn estimate_is_smoothed() {
+		let mut bandwidth = PeerBandwidth::default();
+		let peer = PeerId::random();
+
+		// 16 blocks in 2 seconds.
+		respond(&mut bandwidth, peer, 640 * KB, 64, 8000);
+		assert_eq!(bandwidth.blocks_per_request(&peer, 64), 16);
+
+		// A single fast response doesn't make the peer fast.
+		respond(&mut bandwidth, peer, 640 * KB, 64, 800);
+		let blocks = bandwidth.blocks_per_request(&peer, 64);
+		assert!(blocks > 16 && blocks < 64, "{blocks}");
+	}
+
+	#[test]
+	fn responses_without_request_or_blocks_are_ignored() {
+		let mut bandwidth = PeerBandwidth::default();
+		let peer = PeerId::random();
+
+		bandwidth.response_received(&peer, 640 * KB, 64, Instant::now());
+		respond(&mut bandwidth, peer, 0, 0, 60_000);
+		assert_eq!(bandwidth.blocks_per_request(&peer, 64), 64);
+	}
+}
@@ -74,6 +74,18 @@ pub trait WarpSyncProvider<Block: BlockT>: Send + Sync {
 	/// Get current list of authorities. This is supposed to be genesis authorities when starting
 	/// sync.
 	fn current_authorities(&self) -> AuthorityList;
+	/// Generate the proofs, each one starting where the previous one ends, that together prove the
+	/// finality of the latest finalized block starting at given block hash. Returns the proofs
+	/// and the header of the block whose finality they prove.
+	///
+	/// Not all providers support this, the default implementation returns an error.
+	fn generate_complete(
+		&self,
+		start: Block::Hash,
+	) -> Result<(Vec<EncodedProof>, Block::Header), Box<dyn std::error::Error + Send + Sync>> {
+		let _ = start;
+		Err("Generating complete warp sync proofs is not supported".into())
+	}
 }
 
 /// Reported warp sync phase.
@@ -340,7 +352,8 @@ where
 				let request = BlockRequest::<B> {
 					id: 0,
 					fields: BlockAttributes::HEADER |
-						BlockAttributes::BODY | BlockAttributes::JUSTIFICATION,
+						BlockAttributes::BODY |
+						BlockAttributes::JUSTIFICATION,
 					from: FromBlock::Hash(header.hash()),
 					direction: Direction::Ascending,
 					max: Some(1),
@@ -51,6 +51,9 @@ pub enum Error {
 	/// Invalid session keys encoding.
 	#[error("Session keys are not encoded correctly")]
 	InvalidSessionKeys,
+	/// The runtime can't generate session keys with an ownership proof.
+	#[error("The runtime does not support session keys ownership proofs")]
+	OwnershipProofUnsupported,
 	/// Call to an unsafe RPC was denied.
 	#[error(transparent)]
 	UnsafeRpcCalled(#[from] crate::policy::UnsafeRpcError),
@@ -20,11 +20,22 @@
 
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use sc_transaction_pool_api::TransactionStatus;
+use serde::{Deserialize, Serialize};
 use sp_core::Bytes;
 
 pub mod error;
 pub mod hash;
 
+/// Session keys generated by `author_rotateKeysWithOwner`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratedSessionKeys {
+	/// The concatenated SCALE encoded public keys.
+	pub keys: Bytes,
+	/// The proof that the owner holds the private keys.
+	pub proof: Bytes,
+}
+
 /// Substrate authoring RPC API
 #[rpc(client, server)]
 pub trait AuthorApi<Hash, BlockHash> {
@@ -40,6 +51,14 @@ pub trait AuthorApi<Hash, BlockHash> {
 	#[method(name = "author_rotateKeys")]
 	fn rotate_keys(&self) -> RpcResult<Bytes>;
 
+	/// Generate new session keys and returns the corresponding public keys, together with a proof
+	/// that `owner` holds their private keys.
+	///
+	/// `owner` is the SCALE encoded account that is going to submit the keys to `set_keys` of
+	/// `pallet-session`.
+	#[method(name = "author_rotateKeysWithOwner")]
+	fn rotate_keys_with_owner(&self, owner: Bytes) -> RpcResult<GeneratedSessionKeys>;
+
 	/// Checks if the keystore has private keys for the given session public keys.
 	///
 	/// `session_keys` is the SCALE encoded session keys object from the runtime.
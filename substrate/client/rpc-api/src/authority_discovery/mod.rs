@@ -0,0 +1,47 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrate authority discovery API.
+
+pub mod error;
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
+
+/// An authority together with the addresses under which it has been discovered.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorityRecord {
+	/// The SS58-encoded authority discovery id.
+	pub authority_id: String,
+	/// The base58-encoded peer ids of the authority.
+	pub peer_ids: Vec<String>,
+	/// The addresses of the authority.
+	pub addresses: Vec<String>,
+}
+
+/// Substrate authority discovery RPC API
+#[rpc(client, server)]
+pub trait AuthorityDiscoveryApi {
+	/// Returns the authorities of the current and the next session at the best block, together
+	/// with the peer ids and addresses found for them on the DHT.
+	///
+	/// Authorities without a known record are returned with empty peer ids and addresses.
+	#[method(name = "authorityDiscovery_authorities")]
+	async fn authorities(&self) -> RpcResult<Vec<AuthorityRecord>>;
+}
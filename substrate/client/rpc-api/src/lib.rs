@@ -28,6 +28,7 @@ mod policy;
 pub use policy::DenyUnsafe;
 
 pub mod author;
+pub mod authority_discovery;
 pub mod chain;
 pub mod child_state;
 pub mod dev;
@@ -19,7 +19,7 @@
 //! API trait for transactions.
 
 use crate::transaction::event::TransactionEvent;
-use jsonrpsee::proc_macros::rpc;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use sp_core::Bytes;
 
 #[rpc(client, server)]
@@ -29,9 +29,28 @@ pub trait TransactionApi<Hash: Clone> {
 	/// See [`TransactionEvent`](crate::transaction::event::TransactionEvent) for details on
 	/// transaction life cycle.
 	#[subscription(
-		name = "transaction_unstable_submitAndWatch" => "transaction_unstable_watchEvent",
-		unsubscribe = "transaction_unstable_unwatch",
+		name = "transactionWatch_unstable_submitAndWatch" => "transactionWatch_unstable_watchEvent",
+		unsubscribe = "transactionWatch_unstable_unwatch",
 		item = TransactionEvent<Hash>,
 	)]
 	fn submit_and_watch(&self, bytes: Bytes);
 }
+
+#[rpc(client, server)]
+pub trait TransactionBroadcastApi {
+	/// Broadcast an extrinsic to the peers of the node.
+	///
+	/// The extrinsic is submitted to the transaction pool at the best block. Whenever it leaves
+	/// the pool without being finalized, e.g. because it became invalid after a re-org, it is
+	/// submitted again at the next best block. This continues until the extrinsic is finalized,
+	/// it is usurped by another extrinsic or the broadcast is stopped. No events are generated.
+	///
+	/// Returns the operation ID that can be used to stop the broadcast, or `null` if the node
+	/// can't broadcast more extrinsics at the moment.
+	#[method(name = "transaction_unstable_broadcast")]
+	fn broadcast(&self, bytes: Bytes) -> RpcResult<Option<String>>;
+
+	/// Stop the broadcast of the extrinsic with the given operation ID.
+	#[method(name = "transaction_unstable_stop")]
+	fn stop_broadcast(&self, operation_id: String) -> RpcResult<()>;
+}
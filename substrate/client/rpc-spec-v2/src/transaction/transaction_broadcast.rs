@@ -0,0 +1,234 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! API implementation for broadcasting transactions.
+
+use crate::{
+	transaction::{api::TransactionBroadcastApiServer, error::ErrorBroadcast},
+	SubscriptionTaskExecutor,
+};
+use codec::Decode;
+use futures::{future::AbortHandle, FutureExt, Stream, StreamExt};
+use jsonrpsee::{
+	core::{id_providers::RandomStringIdProvider, traits::IdProvider, RpcResult},
+	types::SubscriptionId,
+};
+use parking_lot::RwLock;
+use sc_client_api::BlockchainEvents;
+use sc_transaction_pool_api::{
+	error::IntoPoolError, TransactionFor, TransactionPool, TransactionSource,
+};
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+use std::{collections::HashMap, sync::Arc};
+
+/// Currently we treat all RPC transactions as externals.
+const TX_SOURCE: TransactionSource = TransactionSource::External;
+
+/// The maximum number of extrinsics that are broadcasted at the same time.
+///
+/// Further calls to `transaction_unstable_broadcast` return `null` until some of the
+/// broadcasts are finished or stopped.
+const MAX_ACTIVE_BROADCASTS: usize = 512;
+
+/// Length of the randomly generated operation IDs.
+const OPERATION_ID_LEN: usize = 16;
+
+/// An API for transaction broadcast RPC calls.
+pub struct TransactionBroadcast<Pool, Client> {
+	/// Substrate client.
+	client: Arc<Client>,
+	/// Transactions pool.
+	pool: Arc<Pool>,
+	/// Executor to spawn the broadcast tasks.
+	executor: SubscriptionTaskExecutor,
+	/// Generates the operation IDs.
+	id_provider: RandomStringIdProvider,
+	/// The abort handles of the active broadcasts, keyed by their operation IDs.
+	broadcast_ids: Arc<RwLock<HashMap<String, AbortHandle>>>,
+}
+
+impl<Pool, Client> TransactionBroadcast<Pool, Client> {
+	/// Creates a new [`TransactionBroadcast`].
+	pub fn new(client: Arc<Client>, pool: Arc<Pool>, executor: SubscriptionTaskExecutor) -> Self {
+		TransactionBroadcast {
+			client,
+			pool,
+			executor,
+			id_provider: RandomStringIdProvider::new(OPERATION_ID_LEN),
+			broadcast_ids: Default::default(),
+		}
+	}
+
+	/// Generates an operation ID that is not used by any active broadcast.
+	fn generate_unique_id(&self, broadcast_ids: &HashMap<String, AbortHandle>) -> String {
+		loop {
+			let id = match self.id_provider.next_id() {
+				SubscriptionId::Num(num) => num.to_string(),
+				SubscriptionId::Str(id) => id.into_owned().into(),
+			};
+
+			if !broadcast_ids.contains_key(&id) {
+				return id
+			}
+		}
+	}
+}
+
+impl<Pool, Client> TransactionBroadcastApiServer for TransactionBroadcast<Pool, Client>
+where
+	Pool: TransactionPool + Sync + Send + 'static,
+	Client: HeaderBackend<Pool::Block> + BlockchainEvents<Pool::Block> + Send + Sync + 'static,
+{
+	fn broadcast(&self, bytes: Bytes) -> RpcResult<Option<String>> {
+		let mut broadcast_ids = self.broadcast_ids.write();
+		if broadcast_ids.len() >= MAX_ACTIVE_BROADCASTS {
+			return Ok(None)
+		}
+
+		let id = self.generate_unique_id(&broadcast_ids);
+
+		let pool = self.pool.clone();
+		let best_block_hash = self.client.info().best_hash;
+		// Subscribe before submitting, so that no best block is missed when resubmitting.
+		let best_block_import_stream = self
+			.client
+			.import_notification_stream()
+			.filter_map(|notification| async move {
+				notification.is_new_best.then_some(notification.hash)
+			})
+			.boxed();
+
+		let broadcast_transaction_fut = async move {
+			// There is nothing we could do with an extrinsic of invalid format.
+			let Ok(decoded_extrinsic) = TransactionFor::<Pool>::decode(&mut &bytes[..]) else {
+				return
+			};
+
+			broadcast_transaction(
+				pool,
+				decoded_extrinsic,
+				best_block_hash,
+				best_block_import_stream,
+			)
+			.await
+		};
+
+		// The broadcast can be aborted by `transaction_unstable_stop`.
+		let (fut, handle) = futures::future::abortable(broadcast_transaction_fut);
+		let drop_ids = self.broadcast_ids.clone();
+		let drop_id = id.clone();
+		// Remove the operation once the broadcast is finished, including when it was aborted.
+		let fut = fut.map(move |_| {
+			drop_ids.write().remove(&drop_id);
+		});
+
+		broadcast_ids.insert(id.clone(), handle);
+		drop(broadcast_ids);
+
+		self.executor
+			.spawn("substrate-rpc-transaction-broadcast", Some("rpc"), fut.boxed());
+
+		Ok(Some(id))
+	}
+
+	fn stop_broadcast(&self, operation_id: String) -> RpcResult<()> {
+		let Some(handle) = self.broadcast_ids.write().remove(&operation_id) else {
+			return Err(ErrorBroadcast::InvalidOperationID.into())
+		};
+
+		handle.abort();
+		Ok(())
+	}
+}
+
+/// Submits the extrinsic to the pool until it is finalized or can't be submitted again.
+///
+/// The extrinsic is first submitted at `best_block_hash`. Every resubmission happens at the most
+/// recent best block of `best_block_import_stream`.
+async fn broadcast_transaction<Pool, S>(
+	pool: Arc<Pool>,
+	extrinsic: TransactionFor<Pool>,
+	best_block_hash: <Pool::Block as BlockT>::Hash,
+	mut best_block_import_stream: S,
+) where
+	Pool: TransactionPool,
+	S: Stream<Item = <Pool::Block as BlockT>::Hash> + Unpin,
+{
+	let mut at = best_block_hash;
+
+	loop {
+		match pool.submit_and_watch(at, TX_SOURCE, extrinsic.clone()).await {
+			Ok(mut stream) => {
+				let mut resubmit = false;
+				while let Some(event) = stream.next().await {
+					if event.is_retriable() {
+						resubmit = true;
+						break
+					}
+
+					if event.is_final() {
+						break
+					}
+				}
+
+				if !resubmit {
+					return
+				}
+			},
+			// The transaction could not enter the pool.
+			Err(e) => match e.into_pool_error() {
+				Ok(e) if e.is_retriable() => (),
+				_ => return,
+			},
+		}
+
+		// Wait for a new best block before submitting the extrinsic again.
+		let Some(hash) = last_stream_element(&mut best_block_import_stream).await else { return };
+		at = hash;
+	}
+}
+
+/// Returns the most recent element of the stream, waiting for one if none is ready.
+///
+/// Returns `None` if the stream is terminated.
+async fn last_stream_element<S>(stream: &mut S) -> Option<S::Item>
+where
+	S: Stream + Unpin,
+{
+	let Some(Some(mut element)) = stream.next().now_or_never() else { return stream.next().await };
+
+	while let Some(Some(next)) = stream.next().now_or_never() {
+		element = next;
+	}
+
+	Some(element)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn last_stream_element_returns_most_recent() {
+		let mut stream = futures::stream::iter([1, 2, 3]);
+		assert_eq!(futures::executor::block_on(last_stream_element(&mut stream)), Some(3));
+		assert_eq!(futures::executor::block_on(last_stream_element(&mut stream)), None);
+	}
+}
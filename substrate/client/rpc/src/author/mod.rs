@@ -129,6 +129,30 @@ where
 			.map_err(|api_err| Error::Client(Box::new(api_err)).into())
 	}
 
+	fn rotate_keys_with_owner(&self, owner: Bytes) -> RpcResult<GeneratedSessionKeys> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let best_block_hash = self.client.info().best_hash;
+		let mut runtime_api = self.client.runtime_api();
+
+		let version = runtime_api
+			.api_version::<dyn SessionKeys<P::Block>>(best_block_hash)
+			.map_err(|e| Error::Client(Box::new(e)))?;
+		if version.map_or(true, |version| version < 2) {
+			return Err(Error::OwnershipProofUnsupported.into())
+		}
+
+		runtime_api.register_extension(KeystoreExt::from(self.keystore.clone()));
+
+		runtime_api
+			.generate_session_keys_with_ownership_proof(best_block_hash, owner.to_vec(), None)
+			.map(|generated| GeneratedSessionKeys {
+				keys: generated.keys.into(),
+				proof: generated.proof.into(),
+			})
+			.map_err(|api_err| Error::Client(Box::new(api_err)).into())
+	}
+
 	fn has_session_keys(&self, session_keys: Bytes) -> RpcResult<bool> {
 		self.deny_unsafe.check_if_safe()?;
 
@@ -0,0 +1,103 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrate authority discovery API.
+
+use std::{collections::BTreeSet, marker::PhantomData, sync::Arc};
+
+use jsonrpsee::core::{async_trait, RpcResult};
+use sc_authority_discovery::Service;
+use sc_network::{multiaddr::Protocol, PeerId};
+pub use sc_rpc_api::authority_discovery::{
+	error::Error, AuthorityDiscoveryApiServer, AuthorityRecord,
+};
+use sc_rpc_api::DenyUnsafe;
+use sp_api::ProvideRuntimeApi;
+use sp_authority_discovery::AuthorityDiscoveryApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::crypto::Ss58Codec;
+use sp_runtime::traits::Block as BlockT;
+
+/// Authority discovery API.
+pub struct AuthorityDiscovery<Block, Client> {
+	client: Arc<Client>,
+	service: Service,
+	deny_unsafe: DenyUnsafe,
+	_marker: PhantomData<Block>,
+}
+
+impl<Block, Client> AuthorityDiscovery<Block, Client> {
+	/// Create a new authority discovery API instance.
+	pub fn new(client: Arc<Client>, service: Service, deny_unsafe: DenyUnsafe) -> Self {
+		Self { client, service, deny_unsafe, _marker: Default::default() }
+	}
+}
+
+#[async_trait]
+impl<Block, Client> AuthorityDiscoveryApiServer for AuthorityDiscovery<Block, Client>
+where
+	Block: BlockT,
+	Client: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	Client::Api: AuthorityDiscoveryApi<Block>,
+{
+	async fn authorities(&self) -> RpcResult<Vec<AuthorityRecord>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let best_hash = self.client.info().best_hash;
+		let authorities = self
+			.client
+			.runtime_api()
+			.authorities(best_hash)
+			.map_err(|e| Error::Runtime(e.to_string()))?;
+
+		let mut known_addresses = self
+			.service
+			.clone()
+			.get_known_addresses()
+			.await
+			.ok_or(Error::WorkerUnavailable)?;
+
+		Ok(authorities
+			.into_iter()
+			.map(|authority_id| {
+				let addresses = known_addresses.remove(&authority_id).unwrap_or_default();
+				let peer_ids = addresses
+					.iter()
+					.filter_map(|address| {
+						address.iter().find_map(|protocol| match protocol {
+							Protocol::P2p(multihash) => PeerId::from_multihash(multihash).ok(),
+							_ => None,
+						})
+					})
+					.map(|peer_id| peer_id.to_base58())
+					.collect::<BTreeSet<_>>();
+
+				AuthorityRecord {
+					authority_id: authority_id.to_ss58check(),
+					peer_ids: peer_ids.into_iter().collect(),
+					addresses: addresses
+						.iter()
+						.map(ToString::to_string)
+						.collect::<BTreeSet<_>>()
+						.into_iter()
+						.collect(),
+				}
+			})
+			.collect())
+	}
+}
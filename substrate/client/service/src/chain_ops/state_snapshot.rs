@@ -0,0 +1,407 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Export and import of state snapshots.
+//!
+//! A state snapshot holds the state of a finalized block together with warp sync proofs of its
+//! finality, which a node only knowing the genesis block can verify. Importing a snapshot lets a
+//! new node start at that block, like after a warp sync, without downloading the state from the
+//! network.
+//!
+//! A snapshot is a directory with a `manifest.json` file and chunk files. Each chunk holds the
+//! SCALE-encoded key values of either the top trie or a single default child trie. Its hash is
+//! recorded in the manifest. The manifest is written after every chunk, so an interrupted export
+//! can be resumed.
+
+use crate::error::Error;
+use codec::{Decode, Encode};
+use futures::future;
+use futures_timer::Delay;
+use log::info;
+use sc_client_api::{BlockBackend, HeaderBackend, StorageProvider, UsageProvider};
+use sc_consensus::{
+	import_queue::{BlockImportError, BlockImportStatus, ImportQueue, IncomingBlock, Link},
+	ImportedState,
+};
+use sc_network_sync::warp::{EncodedProof, VerificationResult, WarpSyncProvider};
+use serde::{Deserialize, Serialize};
+use sp_consensus::BlockOrigin;
+use sp_core::{
+	hashing::blake2_256,
+	storage::{well_known_keys, ChildInfo, StorageKey},
+	Bytes, H256,
+};
+use sp_runtime::{
+	traits::{Block as BlockT, Header as HeaderT, NumberFor, Zero},
+	Justifications,
+};
+use std::{
+	collections::{BTreeMap, HashMap},
+	fs,
+	path::Path,
+	sync::Arc,
+	task::Poll,
+	time::Duration,
+};
+
+/// Name of the manifest file of a snapshot.
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Number of milliseconds to wait between two polls of the import queue.
+const DELAY_TIME: u64 = 200;
+
+/// The header, body and justifications of the block of a snapshot.
+type SnapshotBlock<B> =
+	(<B as BlockT>::Header, Option<Vec<<B as BlockT>::Extrinsic>>, Option<Justifications>);
+
+/// Key values of a chunk.
+type KeyValues = Vec<(Vec<u8>, Vec<u8>)>;
+
+/// Content of the manifest file of a snapshot.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Manifest {
+	/// SCALE-encoded [`SnapshotBlock`].
+	block: Bytes,
+	/// Warp sync proofs of the finality of the block, starting at the genesis block.
+	finality_proofs: Vec<Bytes>,
+	/// Chunks written so far.
+	chunks: Vec<ChunkInfo>,
+	/// Whether all the chunks were written.
+	complete: bool,
+}
+
+/// Description of a chunk in the manifest.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ChunkInfo {
+	/// Name of the chunk file.
+	file: String,
+	/// Blake2-256 hash of the content of the file.
+	hash: H256,
+	/// Storage key of the child trie of the key values, `None` for the top trie.
+	child_storage_key: Option<Bytes>,
+	/// Last key of the chunk.
+	last_key: Bytes,
+}
+
+impl Manifest {
+	fn read(dir: &Path) -> Result<Self, Error> {
+		let file = fs::File::open(dir.join(MANIFEST_FILE))?;
+		serde_json::from_reader(file)
+			.map_err(|e| Error::Other(format!("Invalid state snapshot manifest: {e}")))
+	}
+
+	/// Writes the manifest to a temporary file first, so that it is never left half written.
+	fn write(&self, dir: &Path) -> Result<(), Error> {
+		let tmp = dir.join(format!("{MANIFEST_FILE}.tmp"));
+		let json = serde_json::to_vec_pretty(self)
+			.map_err(|e| Error::Other(format!("Failed to encode the manifest: {e}")))?;
+		fs::write(&tmp, json)?;
+		fs::rename(tmp, dir.join(MANIFEST_FILE))?;
+		Ok(())
+	}
+
+	/// Writes the chunk of `key_values`, which must not be empty, and records it.
+	fn write_chunk(
+		&mut self,
+		dir: &Path,
+		child_storage_key: Option<&Vec<u8>>,
+		key_values: KeyValues,
+	) -> Result<(), Error> {
+		let last_key = key_values.last().expect("Chunks are never empty; qed").0.clone();
+		let encoded = key_values.encode();
+		let file = format!("chunk-{:06}.bin", self.chunks.len());
+		fs::write(dir.join(&file), &encoded)?;
+
+		self.chunks.push(ChunkInfo {
+			file,
+			hash: blake2_256(&encoded).into(),
+			child_storage_key: child_storage_key.cloned().map(Into::into),
+			last_key: last_key.into(),
+		});
+		self.write(dir)
+	}
+}
+
+/// Export a snapshot of the state of the latest finalized block proven by `warp_sync_provider`
+/// into the directory `dir`.
+///
+/// The chunks hold at most about `max_chunk_size` bytes of key values. If `dir` holds an
+/// incomplete snapshot, its export is resumed. Returns the hash of the block of the snapshot.
+pub fn export_state_snapshot<B, BA, C>(
+	client: Arc<C>,
+	warp_sync_provider: Arc<dyn WarpSyncProvider<B>>,
+	dir: &Path,
+	max_chunk_size: usize,
+) -> Result<B::Hash, Error>
+where
+	C: UsageProvider<B> + StorageProvider<B, BA> + HeaderBackend<B> + BlockBackend<B>,
+	B: BlockT,
+	BA: sc_client_api::backend::Backend<B>,
+{
+	fs::create_dir_all(dir)?;
+	let mut manifest = if dir.join(MANIFEST_FILE).exists() {
+		Manifest::read(dir)?
+	} else {
+		let (proofs, header) = warp_sync_provider.generate_complete(client.info().genesis_hash)?;
+		let hash = header.hash();
+		let block: SnapshotBlock<B> =
+			(header, client.block_body(hash)?, client.justifications(hash)?);
+		let manifest = Manifest {
+			block: block.encode().into(),
+			finality_proofs: proofs.into_iter().map(|EncodedProof(proof)| proof.into()).collect(),
+			chunks: Vec::new(),
+			complete: false,
+		};
+		manifest.write(dir)?;
+		manifest
+	};
+
+	let (header, _, _) = SnapshotBlock::<B>::decode(&mut &manifest.block[..])
+		.map_err(|e| Error::Other(format!("Invalid state snapshot block: {e}")))?;
+	let hash = header.hash();
+	if manifest.complete {
+		info!("State snapshot of block #{} ({hash:?}) is already exported", header.number());
+		return Ok(hash)
+	}
+
+	// The top trie first, then the default child tries in the order of their storage keys.
+	let child_prefix = StorageKey(well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX.to_vec());
+	let tries = std::iter::once(None)
+		.chain(client.storage_keys(hash, Some(&child_prefix), None)?.map(|key| Some(key.0)))
+		.collect::<Vec<_>>();
+
+	// Resume after the last chunk written.
+	let (first_trie, mut start_key) = match manifest.chunks.last() {
+		Some(chunk) => (
+			tries
+				.iter()
+				.position(|trie| trie.as_deref() == chunk.child_storage_key.as_deref())
+				.ok_or("Child trie of the last chunk is missing from the state")?,
+			Some(StorageKey(chunk.last_key.to_vec())),
+		),
+		None => (0, None),
+	};
+	info!(
+		"Exporting state snapshot of block #{} ({hash:?}), starting at chunk {}",
+		header.number(),
+		manifest.chunks.len(),
+	);
+
+	for child_storage_key in &tries[first_trie..] {
+		let start_key = start_key.take();
+		let key_values: Box<dyn Iterator<Item = Result<(Vec<u8>, Vec<u8>), Error>> + '_> =
+			match child_storage_key {
+				None => Box::new(
+					client
+						.storage_pairs(hash, None, start_key.as_ref())?
+						.map(|(key, value)| Ok((key.0, value.0))),
+				),
+				Some(child_storage_key) => {
+					let child_info = ChildInfo::new_default(
+						&child_storage_key
+							[well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX.len()..],
+					);
+					let keys = client.child_storage_keys(
+						hash,
+						child_info.clone(),
+						None,
+						start_key.as_ref(),
+					)?;
+					let client = &client;
+					Box::new(keys.map(move |key| {
+						let value = client
+							.child_storage(hash, &child_info, &key)?
+							.ok_or("Child storage value is missing")?;
+						Ok((key.0, value.0))
+					}))
+				},
+			};
+
+		let mut chunk = Vec::new();
+		let mut chunk_size = 0;
+		for key_value in key_values {
+			let (key, value) = key_value?;
+			chunk_size += key.len() + value.len();
+			chunk.push((key, value));
+			if chunk_size >= max_chunk_size {
+				manifest.write_chunk(
+					dir,
+					child_storage_key.as_ref(),
+					std::mem::take(&mut chunk),
+				)?;
+				chunk_size = 0;
+			}
+		}
+		if !chunk.is_empty() {
+			manifest.write_chunk(dir, child_storage_key.as_ref(), chunk)?;
+		}
+	}
+
+	manifest.complete = true;
+	manifest.write(dir)?;
+	info!("Exported state snapshot in {} chunks", manifest.chunks.len());
+
+	Ok(hash)
+}
+
+/// Import the state snapshot stored in the directory `dir`.
+///
+/// The finality of the block of the snapshot is verified with `warp_sync_provider`, starting at
+/// the genesis authorities, and the hashes of the chunks are checked against the manifest. The
+/// block is then imported with its state through `import_queue`, which checks the state root.
+/// Only a node that didn't finalize any block yet can import a snapshot.
+pub async fn import_state_snapshot<B, IQ, C>(
+	client: Arc<C>,
+	mut import_queue: IQ,
+	warp_sync_provider: Arc<dyn WarpSyncProvider<B>>,
+	dir: &Path,
+) -> Result<(), Error>
+where
+	C: HeaderBackend<B> + Send + Sync + 'static,
+	B: BlockT,
+	IQ: ImportQueue<B> + 'static,
+{
+	struct WaitLink {
+		result: Option<Result<(), String>>,
+	}
+
+	impl<B: BlockT> Link<B> for WaitLink {
+		fn blocks_processed(
+			&mut self,
+			_imported: usize,
+			_count: usize,
+			results: Vec<(Result<BlockImportStatus<NumberFor<B>>, BlockImportError>, B::Hash)>,
+		) {
+			if let Some((result, _)) = results.into_iter().next() {
+				self.result = Some(result.map(|_| ()).map_err(|e| e.to_string()));
+			}
+		}
+	}
+
+	if !client.info().finalized_number.is_zero() {
+		return Err("A state snapshot can only be imported into an empty database".into())
+	}
+
+	let manifest = Manifest::read(dir)?;
+	if !manifest.complete {
+		return Err("The state snapshot is incomplete, resume its export first".into())
+	}
+	let (header, body, justifications) = SnapshotBlock::<B>::decode(&mut &manifest.block[..])
+		.map_err(|e| Error::Other(format!("Invalid state snapshot block: {e}")))?;
+	let hash = header.hash();
+	let number = *header.number();
+
+	info!("Verifying the finality of block #{number} ({hash:?})");
+	let mut set_id = 0;
+	let mut authorities = warp_sync_provider.current_authorities();
+	let mut proven = None;
+	for proof in &manifest.finality_proofs {
+		if proven.is_some() {
+			return Err("Unexpected finality proof after the complete proof".into())
+		}
+		match warp_sync_provider.verify(&EncodedProof(proof.to_vec()), set_id, authorities)? {
+			VerificationResult::Partial(next_set_id, next_authorities, _) => {
+				set_id = next_set_id;
+				authorities = next_authorities;
+			},
+			VerificationResult::Complete(_, _, proven_header) => {
+				authorities = Vec::new();
+				proven = Some(proven_header.hash());
+			},
+		}
+	}
+	if proven != Some(hash) {
+		return Err(format!("The finality proofs don't prove the finality of block {hash:?}").into())
+	}
+
+	info!("Reading {} state chunks", manifest.chunks.len());
+	let mut top = Vec::new();
+	let mut children = BTreeMap::<Vec<u8>, KeyValues>::new();
+	for chunk in &manifest.chunks {
+		let encoded = fs::read(dir.join(&chunk.file))?;
+		if H256::from(blake2_256(&encoded)) != chunk.hash {
+			return Err(format!("State snapshot chunk {} is corrupted", chunk.file).into())
+		}
+		let key_values = KeyValues::decode(&mut &encoded[..])
+			.map_err(|e| Error::Other(format!("Invalid state snapshot chunk: {e}")))?;
+		match &chunk.child_storage_key {
+			None => top.extend(key_values),
+			Some(key) => children.entry(key.to_vec()).or_default().extend(key_values),
+		}
+	}
+
+	// Same layout as the state downloaded by state sync: the child trie roots are not part of the
+	// top trie, they are recalculated on import.
+	let mut child_roots = HashMap::new();
+	top.retain(|(key, value)| {
+		let is_child_root = well_known_keys::is_child_storage_key(key);
+		if is_child_root {
+			child_roots.insert(key.clone(), value.clone());
+		}
+		!is_child_root
+	});
+	let mut state = HashMap::<Vec<u8>, (KeyValues, Vec<Vec<u8>>)>::new();
+	state.insert(Vec::new(), (top, Vec::new()));
+	for (child_storage_key, key_values) in children {
+		let root = child_roots
+			.remove(&child_storage_key)
+			.ok_or("State snapshot chunk of an unknown child trie")?;
+		let (root_key_values, storage_keys) = state.entry(root).or_default();
+		// Child tries with the same root are only imported once.
+		if storage_keys.is_empty() {
+			*root_key_values = key_values;
+		}
+		storage_keys.push(child_storage_key);
+	}
+
+	info!("Importing state of block #{number} ({hash:?})");
+	import_queue.service_ref().import_blocks(
+		BlockOrigin::NetworkInitialSync,
+		vec![IncomingBlock {
+			hash,
+			header: Some(header),
+			body,
+			indexed_body: None,
+			justifications,
+			origin: None,
+			allow_missing_state: true,
+			import_existing: true,
+			skip_execution: true,
+			state: Some(ImportedState { block: hash, state: state.into() }),
+		}],
+	);
+
+	let mut link = WaitLink { result: None };
+	loop {
+		future::poll_fn(|cx| {
+			import_queue.poll_actions(cx, &mut link);
+			Poll::Ready(())
+		})
+		.await;
+		match link.result.take() {
+			Some(Ok(())) => break,
+			Some(Err(e)) => return Err(format!("Failed to import state snapshot: {e}").into()),
+			None => Delay::new(Duration::from_millis(DELAY_TIME)).await,
+		}
+	}
+
+	info!("Imported state snapshot of block #{number} ({hash:?})");
+	Ok(())
+}
@@ -18,7 +18,7 @@
 
 //! Service configuration.
 
-pub use sc_client_db::{BlocksPruning, Database, DatabaseSource, PruningMode};
+pub use sc_client_db::{BlocksPruning, Database, DatabaseSource, PruningMode, TrieCacheSettings};
 pub use sc_executor::{WasmExecutionMethod, WasmtimeInstantiationStrategy};
 pub use sc_network::{
 	config::{
@@ -35,7 +35,7 @@ use prometheus_endpoint::Registry;
 use sc_chain_spec::ChainSpec;
 pub use sc_telemetry::TelemetryEndpoints;
 pub use sc_transaction_pool::Options as TransactionPoolOptions;
-use sp_core::crypto::SecretString;
+use sp_core::{crypto::SecretString, storage::StorageKey};
 use std::{
 	io, iter,
 	net::SocketAddr,
@@ -66,6 +66,8 @@ pub struct Configuration {
 	///
 	/// If `None` is given the cache is disabled.
 	pub trie_cache_maximum_size: Option<usize>,
+	/// Trie cache settings, besides its maximum size.
+	pub trie_cache_settings: TrieCacheSettings,
 	/// State pruning settings.
 	pub state_pruning: Option<PruningMode>,
 	/// Number of blocks to keep in the db.
@@ -137,6 +139,11 @@ pub struct Configuration {
 	pub informant_output_format: sc_informant::OutputFormat,
 	/// Maximum number of different runtime versions that can be cached.
 	pub runtime_cache_size: u8,
+	/// Storage keys that may hold the code of an announced, but not yet enacted, runtime upgrade.
+	///
+	/// The code found under these keys at the best block is precompiled in the background, so
+	/// that enacting the upgrade doesn't stall on the compilation of the new runtime.
+	pub pending_runtime_code_keys: Vec<StorageKey>,
 }
 
 /// Type for tasks spawned by the executor.
@@ -160,6 +167,14 @@ pub enum KeystoreConfig {
 	},
 	/// In-memory keystore. Recommended for in-browser nodes.
 	InMemory,
+	/// Keystore that delegates the signing to a remote signer, see
+	/// [`sc_keystore::RemoteKeystore`].
+	Remote {
+		/// The URI of the remote signer.
+		uri: String,
+		/// The token used to authenticate to the remote signer.
+		token: Option<SecretString>,
+	},
 }
 
 impl KeystoreConfig {
@@ -167,7 +182,7 @@ impl KeystoreConfig {
 	pub fn path(&self) -> Option<&Path> {
 		match self {
 			Self::Path { path, .. } => Some(path),
-			Self::InMemory => None,
+			Self::InMemory | Self::Remote { .. } => None,
 		}
 	}
 }
@@ -238,6 +253,7 @@ impl Configuration {
 	pub fn db_config(&self) -> sc_client_db::DatabaseSettings {
 		sc_client_db::DatabaseSettings {
 			trie_cache_maximum_size: self.trie_cache_maximum_size,
+			trie_cache_settings: self.trie_cache_settings,
 			state_pruning: self.state_pruning.clone(),
 			source: self.database.clone(),
 			blocks_pruning: self.blocks_pruning,
@@ -20,7 +20,9 @@ use std::time::SystemTime;
 
 use crate::config::Configuration;
 use futures_timer::Delay;
-use prometheus_endpoint::{register, Gauge, GaugeVec, Opts, PrometheusError, Registry, U64};
+use prometheus_endpoint::{
+	register, CounterVec, Gauge, GaugeVec, Opts, PrometheusError, Registry, U64,
+};
 use sc_client_api::{ClientInfo, UsageProvider};
 use sc_network::{config::Role, NetworkStatus, NetworkStatusProvider};
 use sc_network_sync::{SyncStatus, SyncStatusProvider};
@@ -43,6 +45,7 @@ struct PrometheusMetrics {
 	// I/O
 	database_cache: Gauge<U64>,
 	state_cache: Gauge<U64>,
+	trie_cache_lookups: CounterVec<U64>,
 }
 
 impl PrometheusMetrics {
@@ -116,8 +119,33 @@ impl PrometheusMetrics {
 				Gauge::new("substrate_state_cache_bytes", "State cache size in bytes")?,
 				registry,
 			)?,
+			trie_cache_lookups: register(
+				CounterVec::new(
+					Opts::new(
+						"substrate_trie_cache_lookups_total",
+						"Number of trie cache lookups by cache (node, value) and result \
+						 (local_hit, shared_hit, miss)",
+					),
+					&["cache", "result"],
+				)?,
+				registry,
+			)?,
 		})
 	}
+
+	/// Update the trie cache lookup counters to the given totals.
+	fn update_trie_cache_lookups(&self, stats: &sp_trie::cache::TrieCacheStats) {
+		for (cache, stats) in [("node", &stats.node_cache), ("value", &stats.value_cache)] {
+			for (result, total) in [
+				("local_hit", stats.local_hits),
+				("shared_hit", stats.shared_hits),
+				("miss", stats.misses()),
+			] {
+				let counter = self.trie_cache_lookups.with_label_values(&[cache, result]);
+				counter.inc_by(total.saturating_sub(counter.get()));
+			}
+		}
+	}
 }
 
 /// A `MetricsService` periodically sends general client and
@@ -252,6 +280,7 @@ impl MetricsService {
 			if let Some(info) = info.usage.as_ref() {
 				metrics.database_cache.set(info.memory.database_cache.as_bytes() as u64);
 				metrics.state_cache.set(info.memory.state_cache.as_bytes() as u64);
+				metrics.update_trie_cache_lookups(&info.trie_cache);
 			}
 		}
 
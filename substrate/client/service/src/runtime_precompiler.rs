@@ -0,0 +1,205 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Background precompilation of announced runtime upgrades.
+//!
+//! Compiling a runtime takes a few seconds. Without precompilation this happens when the first
+//! block using the new runtime is executed, which stalls the import and the authoring of blocks.
+//! The precompiler looks up the code of announced runtime upgrades in the state of every new best
+//! block and compiles it in the background, so that it is already in the runtime cache of the
+//! executor when the upgrade is enacted.
+
+use codec::{Decode, Encode};
+use futures::StreamExt;
+use log::{debug, warn};
+use prometheus_endpoint::{
+	exponential_buckets, register, Counter, CounterVec, Histogram, HistogramOpts, Opts,
+	PrometheusError, Registry, U64,
+};
+use sc_client_api::{backend, BlockchainEvents, ExecutorProvider, StorageProvider};
+use sc_executor::RuntimeVersionOf;
+use sp_core::{
+	storage::{well_known_keys, StorageKey},
+	traits::{RuntimeCode, WrappedRuntimeCode},
+};
+use sp_runtime::traits::Block as BlockT;
+use sp_state_machine::BasicExternalities;
+use std::{collections::HashSet, sync::Arc, time::Instant};
+
+const LOG_TARGET: &str = "runtime-precompiler";
+
+/// Prometheus metrics of the [`run`] task.
+struct Metrics {
+	/// Time it took to precompile a runtime.
+	precompilation_time: Histogram,
+	/// Number of precompiled runtimes by result.
+	precompilations: CounterVec<U64>,
+	/// Number of enacted runtime upgrades that found their runtime precompiled.
+	reused: Counter<U64>,
+}
+
+impl Metrics {
+	fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			precompilation_time: register(
+				Histogram::with_opts(
+					HistogramOpts::new(
+						"substrate_runtime_precompilation_time_seconds",
+						"Time it took to precompile the runtime of an announced upgrade",
+					)
+					.buckets(exponential_buckets(0.25, 2.0, 8)?),
+				)?,
+				registry,
+			)?,
+			precompilations: register(
+				CounterVec::new(
+					Opts::new(
+						"substrate_runtime_precompilations_total",
+						"Number of precompiled runtimes of announced upgrades",
+					),
+					&["result"],
+				)?,
+				registry,
+			)?,
+			reused: register(
+				Counter::new(
+					"substrate_runtime_precompilation_reused_total",
+					"Number of enacted runtime upgrades that used a precompiled runtime",
+				)?,
+				registry,
+			)?,
+		})
+	}
+}
+
+/// Precompiles the runtime upgrades announced under `pending_code_keys`.
+///
+/// Every key is looked up in the state of each new best block. When it holds a runtime that wasn't
+/// compiled yet, the runtime is compiled and stored in the runtime cache of the executor of
+/// `client`. The compilation blocks the task, so it should be spawned as a blocking task.
+pub(crate) async fn run<Block, Backend, Client>(
+	client: Arc<Client>,
+	pending_code_keys: Vec<StorageKey>,
+	registry: Option<Registry>,
+) where
+	Block: BlockT,
+	Backend: backend::Backend<Block>,
+	Client: BlockchainEvents<Block> + StorageProvider<Block, Backend> + ExecutorProvider<Block>,
+{
+	let metrics = match registry.as_ref().map(Metrics::register).transpose() {
+		Ok(metrics) => metrics,
+		Err(err) => {
+			warn!(target: LOG_TARGET, "Failed to register runtime precompiler metrics: {err}");
+			None
+		},
+	};
+
+	// Hashes of the announced codes that were precompiled, or failed to compile.
+	let mut precompiled = HashSet::new();
+	let mut best_block_import_stream =
+		client.import_notification_stream().filter_map(|notification| async move {
+			notification.is_new_best.then_some(notification.hash)
+		});
+
+	while let Some(hash) = best_block_import_stream.next().await {
+		let pending = pending_code_keys
+			.iter()
+			.filter_map(|key| Some((key, client.storage_hash(hash, key).ok().flatten()?)))
+			.collect::<Vec<_>>();
+
+		if !precompiled.is_empty() {
+			let code_hash = client.storage_hash(hash, &StorageKey(well_known_keys::CODE.to_vec()));
+			if let Ok(Some(code_hash)) = code_hash {
+				if precompiled.remove(&code_hash) {
+					debug!(target: LOG_TARGET, "Enacted precompiled runtime {code_hash:?} at {hash:?}");
+					if let Some(metrics) = &metrics {
+						metrics.reused.inc();
+					}
+				}
+			}
+		}
+
+		// Forget about the upgrades that were enacted or dropped.
+		precompiled.retain(|code_hash| pending.iter().any(|(_, pending)| pending == code_hash));
+
+		for (key, code_hash) in pending {
+			if precompiled.insert(code_hash) {
+				precompile(&*client, hash, key, code_hash, metrics.as_ref());
+			}
+		}
+	}
+}
+
+/// Compiles the code stored under `key` at `hash` with the executor of `client`.
+fn precompile<Block, Backend, Client>(
+	client: &Client,
+	hash: Block::Hash,
+	key: &StorageKey,
+	code_hash: Block::Hash,
+	metrics: Option<&Metrics>,
+) where
+	Block: BlockT,
+	Backend: backend::Backend<Block>,
+	Client: StorageProvider<Block, Backend> + ExecutorProvider<Block>,
+{
+	let Ok(Some(code)) = client.storage(hash, key) else { return };
+	// The runtime is compiled with the heap pages of the current runtime, like the first block
+	// executed with the new runtime will do.
+	let heap_pages = client
+		.storage(hash, &StorageKey(well_known_keys::HEAP_PAGES.to_vec()))
+		.ok()
+		.flatten()
+		.and_then(|data| u64::decode(&mut &data.0[..]).ok());
+	let runtime_code = RuntimeCode {
+		code_fetcher: &WrappedRuntimeCode(code.0.into()),
+		// Must match the hash the state machine uses for `:code` once the upgrade is enacted.
+		hash: code_hash.encode(),
+		heap_pages,
+	};
+
+	debug!(target: LOG_TARGET, "Precompiling runtime {code_hash:?} announced at {hash:?}");
+
+	let start = Instant::now();
+	let result = RuntimeVersionOf::runtime_version(
+		client.executor(),
+		&mut BasicExternalities::default(),
+		&runtime_code,
+	);
+	let elapsed = start.elapsed();
+
+	match result {
+		Ok(version) => {
+			debug!(
+				target: LOG_TARGET,
+				"Precompiled runtime {code_hash:?} of version {} in {} ms",
+				version.spec_version,
+				elapsed.as_millis(),
+			);
+			if let Some(metrics) = metrics {
+				metrics.precompilation_time.observe(elapsed.as_secs_f64());
+				metrics.precompilations.with_label_values(&["success"]).inc();
+			}
+		},
+		Err(err) => {
+			warn!(target: LOG_TARGET, "Failed to precompile runtime {code_hash:?}: {err}");
+			if let Some(metrics) = metrics {
+				metrics.precompilations.with_label_values(&["failure"]).inc();
+			}
+		},
+	}
+}
@@ -27,7 +27,7 @@ use futures::{
 use parking_lot::Mutex;
 use prometheus_endpoint::{
 	exponential_buckets, register, CounterVec, HistogramOpts, HistogramVec, Opts, PrometheusError,
-	Registry, U64,
+	Registry, F64, U64,
 };
 use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedReceiver, TracingUnboundedSender};
 use std::{
@@ -153,12 +153,16 @@ impl SpawnTaskHandle {
 			if let Some(metrics) = metrics {
 				// Add some wrappers around `task`.
 				let task = {
-					let poll_duration =
-						metrics.poll_duration.with_label_values(&[name, group, task_type_label]);
-					let poll_start =
-						metrics.poll_start.with_label_values(&[name, group, task_type_label]);
-					let inner =
-						prometheus_future::with_poll_durations(poll_duration, poll_start, task);
+					let labels = [name, group, task_type_label];
+					let poll_metrics = prometheus_future::PollMetrics {
+						poll_duration: metrics.poll_duration.with_label_values(&labels),
+						poll_start: metrics.poll_start.with_label_values(&labels),
+						poll_cpu_time: metrics.poll_cpu_time.with_label_values(&labels),
+						wake_to_poll_duration: metrics
+							.wake_to_poll_duration
+							.with_label_values(&labels),
+					};
+					let inner = prometheus_future::with_poll_metrics(poll_metrics, task);
 					// The logic of `AssertUnwindSafe` here is ok considering that we throw
 					// away the `Future` after it has panicked.
 					panic::AssertUnwindSafe(inner).catch_unwind()
@@ -434,15 +438,24 @@ impl TaskManager {
 #[derive(Clone)]
 struct Metrics {
 	// This list is ordered alphabetically
+	poll_cpu_time: CounterVec<F64>,
 	poll_duration: HistogramVec,
 	poll_start: CounterVec<U64>,
 	tasks_spawned: CounterVec<U64>,
 	tasks_ended: CounterVec<U64>,
+	wake_to_poll_duration: HistogramVec,
 }
 
 impl Metrics {
 	fn register(registry: &Registry) -> Result<Self, PrometheusError> {
 		Ok(Self {
+			poll_cpu_time: register(CounterVec::new(
+				Opts::new(
+					"substrate_tasks_polling_cpu_seconds_total",
+					"Total CPU time in seconds spent by the polling thread in Future::poll"
+				),
+				&["task_name", "task_group", "kind"]
+			)?, registry)?,
 			poll_duration: register(HistogramVec::new(
 				HistogramOpts {
 					common_opts: Opts::new(
@@ -475,6 +488,17 @@ impl Metrics {
 				),
 				&["task_name", "reason", "task_group", "kind"]
 			)?, registry)?,
+			wake_to_poll_duration: register(HistogramVec::new(
+				HistogramOpts {
+					common_opts: Opts::new(
+						"substrate_tasks_wake_to_poll_duration",
+						"Duration in seconds between waking up a task and invoking Future::poll"
+					),
+					buckets: exponential_buckets(0.0001, 4.0, 9)
+						.expect("function parameters are constant and always valid; qed"),
+				},
+				&["task_name", "task_group", "kind"]
+			)?, registry)?,
 		})
 	}
 }
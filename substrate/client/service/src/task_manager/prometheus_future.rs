@@ -18,33 +18,65 @@
 
 //! Wrapper around a `Future` that reports statistics about when the `Future` is polled.
 
-use futures::prelude::*;
-use prometheus_endpoint::{Counter, Histogram, U64};
+use cpu_time::ThreadTime;
+use futures::{
+	prelude::*,
+	task::{waker_ref, ArcWake},
+};
+use parking_lot::Mutex;
+use prometheus_endpoint::{Counter, Histogram, F64, U64};
 use std::{
 	fmt,
 	pin::Pin,
-	task::{Context, Poll},
+	sync::Arc,
+	task::{Context, Poll, Waker},
+	time::Instant,
 };
 
-/// Wraps around a `Future`. Report the polling duration to the `Histogram` and when the polling
-/// starts to the `Counter`.
-pub fn with_poll_durations<T>(
-	poll_duration: Histogram,
-	poll_start: Counter<U64>,
-	inner: T,
-) -> PrometheusFuture<T> {
-	PrometheusFuture { inner, poll_duration, poll_start }
+/// The metrics reported about the polls of a `Future`.
+#[derive(Clone)]
+pub struct PollMetrics {
+	/// Observes the duration of each poll.
+	pub poll_duration: Histogram,
+	/// Counts the started polls.
+	pub poll_start: Counter<U64>,
+	/// Counts the CPU time in seconds spent by the thread while polling.
+	pub poll_cpu_time: Counter<F64>,
+	/// Observes the duration between waking up the `Future` and polling it again.
+	pub wake_to_poll_duration: Histogram,
+}
+
+/// Wraps around a `Future`. Report the polling statistics to the given metrics.
+pub fn with_poll_metrics<T>(metrics: PollMetrics, inner: T) -> PrometheusFuture<T> {
+	PrometheusFuture { inner, metrics, wake_tracker: Default::default() }
 }
 
 /// Wraps around `Future` and adds diagnostics to it.
 #[pin_project::pin_project]
-#[derive(Clone)]
 pub struct PrometheusFuture<T> {
 	/// The inner future doing the actual work.
 	#[pin]
 	inner: T,
-	poll_duration: Histogram,
-	poll_start: Counter<U64>,
+	metrics: PollMetrics,
+	wake_tracker: Arc<WakeTracker>,
+}
+
+/// A waker that records when the `Future` was woken up, before waking up the actual waker.
+#[derive(Default)]
+struct WakeTracker {
+	/// The waker of the last poll.
+	waker: Mutex<Option<Waker>>,
+	/// When the `Future` was first woken up since the last poll.
+	woken_at: Mutex<Option<Instant>>,
+}
+
+impl ArcWake for WakeTracker {
+	fn wake_by_ref(arc_self: &Arc<Self>) {
+		arc_self.woken_at.lock().get_or_insert_with(Instant::now);
+		if let Some(waker) = &*arc_self.waker.lock() {
+			waker.wake_by_ref();
+		}
+	}
 }
 
 impl<T> Future for PrometheusFuture<T>
@@ -56,9 +88,26 @@ where
 	fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
 		let this = self.project();
 
-		this.poll_start.inc();
-		let _timer = this.poll_duration.start_timer();
-		Future::poll(this.inner, cx)
+		if let Some(woken_at) = this.wake_tracker.woken_at.lock().take() {
+			this.metrics.wake_to_poll_duration.observe(woken_at.elapsed().as_secs_f64());
+		}
+		{
+			let mut waker = this.wake_tracker.waker.lock();
+			if !waker.as_ref().map_or(false, |waker| waker.will_wake(cx.waker())) {
+				*waker = Some(cx.waker().clone());
+			}
+		}
+		let waker = waker_ref(this.wake_tracker);
+		let mut cx = Context::from_waker(&waker);
+
+		this.metrics.poll_start.inc();
+		let cpu_time = ThreadTime::try_now().ok();
+		let _timer = this.metrics.poll_duration.start_timer();
+		let result = Future::poll(this.inner, &mut cx);
+		if let Some(elapsed) = cpu_time.and_then(|start| start.try_elapsed().ok()) {
+			this.metrics.poll_cpu_time.inc_by(elapsed.as_secs_f64());
+		}
+		result
 
 		// `_timer` is dropped here and will observe the duration
 	}
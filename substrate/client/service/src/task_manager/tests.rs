@@ -250,3 +250,34 @@ fn ensure_task_manager_future_continues_when_childs_not_essential_task_fails() {
 	}
 	drop_tester.wait_on_drop();
 }
+
+#[test]
+fn poll_metrics_are_reported() {
+	use super::prometheus_future::{with_poll_metrics, PollMetrics};
+	use prometheus_endpoint::{Counter, Histogram, HistogramOpts};
+
+	let metrics = PollMetrics {
+		poll_duration: Histogram::with_opts(HistogramOpts::new("poll_duration", "-")).unwrap(),
+		poll_start: Counter::new("poll_start", "-").unwrap(),
+		poll_cpu_time: Counter::new("poll_cpu_time", "-").unwrap(),
+		wake_to_poll_duration: Histogram::with_opts(HistogramOpts::new("wake_to_poll", "-"))
+			.unwrap(),
+	};
+
+	// Wakes itself up once before completing.
+	let mut woken = false;
+	let task = futures::future::poll_fn(|cx| {
+		if woken {
+			return std::task::Poll::Ready(())
+		}
+		woken = true;
+		cx.waker().wake_by_ref();
+		std::task::Poll::Pending
+	});
+	futures::executor::block_on(with_poll_metrics(metrics.clone(), task));
+
+	assert_eq!(metrics.poll_start.get(), 2);
+	assert_eq!(metrics.poll_duration.get_sample_count(), 2);
+	assert_eq!(metrics.wake_to_poll_duration.get_sample_count(), 1);
+	assert!(metrics.poll_cpu_time.get() >= 0.0);
+}
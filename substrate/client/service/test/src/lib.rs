@@ -239,6 +239,7 @@ fn node_config<
 		keystore: KeystoreConfig::Path { path: root.join("key"), password: None },
 		database: DatabaseSource::RocksDb { path: root.join("db"), cache_size: 128 },
 		trie_cache_maximum_size: Some(16 * 1024 * 1024),
+		trie_cache_settings: Default::default(),
 		state_pruning: Default::default(),
 		blocks_pruning: BlocksPruning::KeepFinalized,
 		chain_spec: Box::new((*spec).clone()),
@@ -268,6 +269,7 @@ fn node_config<
 		data_path: root,
 		informant_output_format: Default::default(),
 		runtime_cache_size: 2,
+		pending_runtime_code_keys: Default::default(),
 	}
 }
 
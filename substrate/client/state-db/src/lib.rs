@@ -234,6 +234,9 @@ pub enum PruningMode {
 	/// No pruning. Canonicalization is a no-op.
 	ArchiveAll,
 	/// Canonicalization discards non-canonical nodes. All the canonical nodes are kept in the DB.
+	///
+	/// An [`PruningMode::ArchiveAll`] database can be switched to this mode. The nodes of the
+	/// forks that were written before the switch are not removed by the state db.
 	ArchiveCanonical,
 }
 
@@ -539,7 +542,7 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDb<BlockHash, Key, D> {
 	) -> Result<(CommitSet<Key>, StateDb<BlockHash, Key, D>), Error<D::Error>> {
 		let stored_mode = fetch_stored_pruning_mode(&db)?;
 
-		let selected_mode = match (should_init, stored_mode, requested_mode) {
+		let selected_mode = match (should_init, stored_mode.clone(), requested_mode) {
 			(true, stored_mode, requested_mode) => {
 				assert!(stored_mode.is_none(), "The storage has just been initialized. No meta-data is expected to be found in it.");
 				requested_mode.unwrap_or_default()
@@ -556,7 +559,10 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDb<BlockHash, Key, D> {
 			(false, Some(stored), Some(requested)) => choose_pruning_mode(stored, requested)?,
 		};
 
-		let db_init_commit_set = if should_init {
+		// The stored mode is updated when an archive is switched to `ArchiveCanonical`.
+		let store_mode =
+			should_init || stored_mode.as_ref().map(|m| m.id()) != Some(selected_mode.id());
+		let db_init_commit_set = if store_mode {
 			let mut cs: CommitSet<Key> = Default::default();
 
 			let key = to_meta_key(PRUNING_MODE, &());
@@ -671,7 +677,10 @@ pub enum IsPruned {
 	MaybePruned,
 }
 
-fn fetch_stored_pruning_mode<D: MetaDb>(db: &D) -> Result<Option<PruningMode>, Error<D::Error>> {
+/// Returns the pruning mode stored in the database, if any.
+pub fn fetch_stored_pruning_mode<D: MetaDb>(
+	db: &D,
+) -> Result<Option<PruningMode>, Error<D::Error>> {
 	let meta_key_mode = to_meta_key(PRUNING_MODE, &());
 	if let Some(stored_mode) = db.get_meta(&meta_key_mode).map_err(Error::Db)? {
 		if let Some(mode) = PruningMode::from_id(&stored_mode) {
@@ -694,8 +703,10 @@ fn choose_pruning_mode(
 ) -> Result<PruningMode, StateDbError> {
 	match (stored, requested) {
 		(PruningMode::ArchiveAll, PruningMode::ArchiveAll) => Ok(PruningMode::ArchiveAll),
-		(PruningMode::ArchiveCanonical, PruningMode::ArchiveCanonical) =>
-			Ok(PruningMode::ArchiveCanonical),
+		(
+			PruningMode::ArchiveAll | PruningMode::ArchiveCanonical,
+			PruningMode::ArchiveCanonical,
+		) => Ok(PruningMode::ArchiveCanonical),
 		(PruningMode::Constrained(_), PruningMode::Constrained(requested)) =>
 			Ok(PruningMode::Constrained(requested)),
 		(stored, requested) => Err(StateDbError::IncompatiblePruningModes { requested, stored }),
@@ -931,7 +942,11 @@ mod tests {
 				Some(PruningMode::ArchiveAll),
 				Ok(PruningMode::ArchiveAll),
 			),
-			(Some(PruningMode::ArchiveAll), Some(PruningMode::ArchiveCanonical), Err(())),
+			(
+				Some(PruningMode::ArchiveAll),
+				Some(PruningMode::ArchiveCanonical),
+				Ok(PruningMode::ArchiveCanonical),
+			),
 			(Some(PruningMode::ArchiveCanonical), None, Ok(PruningMode::ArchiveCanonical)),
 			(Some(PruningMode::ArchiveCanonical), Some(PruningMode::blocks_pruning(256)), Err(())),
 			(Some(PruningMode::ArchiveCanonical), Some(PruningMode::blocks_pruning(128)), Err(())),
@@ -946,4 +961,48 @@ mod tests {
 			check_stored_and_requested_mode_compatibility(created, reopened, expected);
 		}
 	}
+
+	#[test]
+	fn switch_from_archive_all_to_archive_canonical_is_persisted() {
+		let (mut db, state_db) = make_test_db(PruningMode::ArchiveAll);
+		std::mem::drop(state_db);
+
+		let (state_db_init, state_db) = StateDb::<H256, H256, TestDb>::open(
+			db.clone(),
+			Some(PruningMode::ArchiveCanonical),
+			false,
+			false,
+		)
+		.unwrap();
+		db.commit(&state_db_init);
+		std::mem::drop(state_db);
+
+		let (_, state_db) =
+			StateDb::<H256, H256, TestDb>::open(db.clone(), None, false, false).unwrap();
+		assert_eq!(state_db.pruning_mode(), PruningMode::ArchiveCanonical);
+
+		// The fork of the archive is kept, new forks are discarded on canonicalization.
+		db.commit(
+			&state_db
+				.insert_block(
+					&H256::from_low_u64_be(51),
+					5,
+					&H256::from_low_u64_be(4),
+					make_changeset(&[51], &[]),
+				)
+				.unwrap(),
+		);
+		db.commit(
+			&state_db
+				.insert_block(
+					&H256::from_low_u64_be(52),
+					5,
+					&H256::from_low_u64_be(4),
+					make_changeset(&[52], &[]),
+				)
+				.unwrap(),
+		);
+		db.commit(&state_db.canonicalize_block(&H256::from_low_u64_be(51)).unwrap());
+		assert!(db.data_eq(&make_db(&[1, 21, 22, 3, 4, 51, 91, 921, 922, 93, 94])));
+	}
 }
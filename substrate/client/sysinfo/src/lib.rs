@@ -20,6 +20,7 @@
 //! and software telemetry information about the node on which we're running.
 
 use futures::prelude::*;
+use prometheus_endpoint::{register, Gauge, GaugeVec, Opts, PrometheusError, Registry, F64, U64};
 use std::time::Duration;
 
 mod sysinfo;
@@ -29,8 +30,8 @@ mod sysinfo_linux;
 pub use sysinfo::{
 	benchmark_cpu, benchmark_disk_random_writes, benchmark_disk_sequential_writes,
 	benchmark_memory, benchmark_sr25519_verify, gather_hwbench, gather_sysinfo,
-	serialize_throughput, serialize_throughput_option, Metric, Requirement, Requirements,
-	Throughput,
+	serialize_throughput, serialize_throughput_option, CheckFailure, CheckFailures,
+	HardwareProfile, Metric, Requirement, Requirements, Throughput,
 };
 
 /// The operating system part of the current target triplet.
@@ -162,3 +163,43 @@ pub fn initialize_hwbench_telemetry(
 		}
 	}
 }
+
+/// Registers the results of the hardware benchmarks as Prometheus metrics.
+///
+/// The scores are exported in MiB/s, together with whether they meet the `requirements`.
+pub fn register_hwbench_metrics(
+	registry: &Registry,
+	hwbench: &HwBench,
+	requirements: &Requirements,
+) -> Result<(), PrometheusError> {
+	let scores = register(
+		GaugeVec::<F64>::new(
+			Opts::new("substrate_hwbench_score", "Hardware benchmark scores in MiB/s"),
+			&["metric"],
+		)?,
+		registry,
+	)?;
+	let measured = [
+		(Metric::Blake2256, Some(hwbench.cpu_hashrate_score)),
+		(Metric::MemCopy, Some(hwbench.memory_memcpy_score)),
+		(Metric::DiskSeqWrite, hwbench.disk_sequential_write_score),
+		(Metric::DiskRndWrite, hwbench.disk_random_write_score),
+	];
+	for (metric, score) in measured {
+		if let Some(score) = score {
+			let label = format!("{} {}", metric.category(), metric.name());
+			scores.with_label_values(&[&label]).set(score.as_mibs());
+		}
+	}
+
+	let requirements_met = register(
+		Gauge::<U64>::new(
+			"substrate_hwbench_requirements_met",
+			"Whether the hardware meets the minimal requirements (1) or not (0)",
+		)?,
+		registry,
+	)?;
+	requirements_met.set(requirements.check_hardware(hwbench).is_ok() as u64);
+
+	Ok(())
+}
@@ -32,6 +32,7 @@ use std::{
 	io::{Seek, SeekFrom, Write},
 	ops::{Deref, DerefMut},
 	path::{Path, PathBuf},
+	str::FromStr,
 	time::{Duration, Instant},
 };
 
@@ -48,6 +49,14 @@ pub enum Metric {
 	DiskSeqWrite,
 	/// Disk random write.
 	DiskRndWrite,
+	/// Random reads of the RocksDB database.
+	RocksDbRndRead,
+	/// Random writes of the RocksDB database.
+	RocksDbRndWrite,
+	/// Random reads of the ParityDB database.
+	ParityDbRndRead,
+	/// Random writes of the ParityDB database.
+	ParityDbRndWrite,
 }
 
 /// Describes a checking failure for the hardware requirements.
@@ -88,6 +97,10 @@ impl Metric {
 			Self::Sr25519Verify | Self::Blake2256 => "CPU",
 			Self::MemCopy => "Memory",
 			Self::DiskSeqWrite | Self::DiskRndWrite => "Disk",
+			Self::RocksDbRndRead |
+			Self::RocksDbRndWrite |
+			Self::ParityDbRndRead |
+			Self::ParityDbRndWrite => "Database",
 		}
 	}
 
@@ -99,6 +112,10 @@ impl Metric {
 			Self::MemCopy => "Copy",
 			Self::DiskSeqWrite => "Seq Write",
 			Self::DiskRndWrite => "Rnd Write",
+			Self::RocksDbRndRead => "RocksDB Rnd Read",
+			Self::RocksDbRndWrite => "RocksDB Rnd Write",
+			Self::ParityDbRndRead => "ParityDB Rnd Read",
+			Self::ParityDbRndWrite => "ParityDB Rnd Write",
 		}
 	}
 }
@@ -255,6 +272,71 @@ pub struct Requirement {
 	pub minimum: Throughput,
 }
 
+/// A selection of the hardware requirements, depending on the role of the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HardwareProfile {
+	/// A relay chain validator, which needs to meet all of the requirements.
+	#[default]
+	RelayValidator,
+	/// A parachain collator, which needs to author and import blocks in time, but writes less.
+	ParachainCollator,
+	/// An RPC node, which mostly needs to serve data from its database.
+	RpcNode,
+}
+
+impl HardwareProfile {
+	/// All of the profiles.
+	pub const ALL: [Self; 3] = [Self::RelayValidator, Self::ParachainCollator, Self::RpcNode];
+
+	/// The name of the profile, as accepted by [`HardwareProfile::from_str`].
+	pub fn name(&self) -> &'static str {
+		match self {
+			Self::RelayValidator => "relay-validator",
+			Self::ParachainCollator => "parachain-collator",
+			Self::RpcNode => "rpc-node",
+		}
+	}
+
+	/// Whether the profile checks the given metric.
+	pub fn checks(&self, metric: Metric) -> bool {
+		match self {
+			Self::RelayValidator => true,
+			Self::ParachainCollator =>
+				matches!(metric, Metric::Sr25519Verify | Metric::Blake2256 | Metric::MemCopy),
+			Self::RpcNode => matches!(
+				metric,
+				Metric::MemCopy |
+					Metric::DiskSeqWrite |
+					Metric::DiskRndWrite |
+					Metric::RocksDbRndRead |
+					Metric::ParityDbRndRead
+			),
+		}
+	}
+
+	/// The requirements of the profile, taken from the `reference` requirements.
+	pub fn requirements(&self, reference: &Requirements) -> Requirements {
+		Requirements(reference.0.iter().filter(|r| self.checks(r.metric)).copied().collect())
+	}
+}
+
+impl Display for HardwareProfile {
+	fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+		write!(formatter, "{}", self.name())
+	}
+}
+
+impl FromStr for HardwareProfile {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::ALL.into_iter().find(|profile| profile.name() == s).ok_or_else(|| {
+			let names = Self::ALL.map(|profile| profile.name()).join(", ");
+			format!("unknown hardware profile `{}`, expected one of: {}", s, names)
+		})
+	}
+}
+
 #[inline(always)]
 pub(crate) fn benchmark<E>(
 	name: &str,
@@ -699,7 +781,13 @@ impl Requirements {
 							});
 						}
 					},
-				Metric::Sr25519Verify => {},
+				// The database benchmarks depend on the configured database backend, so they
+				// are only run by the `benchmark machine` command.
+				Metric::Sr25519Verify |
+				Metric::RocksDbRndRead |
+				Metric::RocksDbRndWrite |
+				Metric::ParityDbRndRead |
+				Metric::ParityDbRndWrite => {},
 			}
 		}
 		if failures.is_empty() {
@@ -776,6 +864,38 @@ mod tests {
 		assert_eq!("1.00 GiBs", mib.to_string());
 	}
 
+	#[test]
+	fn hardware_profiles_work() {
+		let reference = Requirements(vec![
+			Requirement { metric: Metric::Blake2256, minimum: Throughput::from_mibs(1.0) },
+			Requirement { metric: Metric::MemCopy, minimum: Throughput::from_mibs(1.0) },
+			Requirement { metric: Metric::DiskRndWrite, minimum: Throughput::from_mibs(1.0) },
+		]);
+		let metrics = |profile: HardwareProfile| {
+			profile
+				.requirements(&reference)
+				.0
+				.into_iter()
+				.map(|r| r.metric)
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(
+			metrics(HardwareProfile::RelayValidator),
+			vec![Metric::Blake2256, Metric::MemCopy, Metric::DiskRndWrite]
+		);
+		assert_eq!(
+			metrics(HardwareProfile::ParachainCollator),
+			vec![Metric::Blake2256, Metric::MemCopy]
+		);
+		assert_eq!(metrics(HardwareProfile::RpcNode), vec![Metric::MemCopy, Metric::DiskRndWrite]);
+
+		for profile in HardwareProfile::ALL {
+			assert_eq!(profile.to_string().parse::<HardwareProfile>(), Ok(profile));
+		}
+		assert!("validator".parse::<HardwareProfile>().is_err());
+	}
+
 	/// Test the [`HwBench`] serialization.
 	#[test]
 	fn hwbench_serialize_works() {
@@ -71,6 +71,23 @@ pub enum Error {
 	RejectedFutureTransaction,
 }
 
+impl Error {
+	/// Returns true if the transaction could be submitted again at a later time.
+	pub fn is_retriable(&self) -> bool {
+		match self {
+			// An invalid transaction is only banned temporarily, it might become valid later.
+			Error::TemporarilyBanned |
+			// The pool is full at the moment.
+			Error::ImmediatelyDropped |
+			// The block is not known yet, e.g. the node is lagging behind or syncing.
+			Error::InvalidBlockId(_) |
+			// Future transactions might become ready at a later block.
+			Error::RejectedFutureTransaction => true,
+			_ => false,
+		}
+	}
+}
+
 /// Transaction pool error conversion.
 pub trait IntoPoolError: std::error::Error + Send + Sized + Sync {
 	/// Try to extract original `Error`
@@ -131,6 +131,28 @@ pub enum TransactionStatus<Hash, BlockHash> {
 	Invalid,
 }
 
+impl<Hash, BlockHash> TransactionStatus<Hash, BlockHash> {
+	/// Returns true if this is the last event emitted by the transaction watcher.
+	pub fn is_final(&self) -> bool {
+		matches!(
+			self,
+			Self::Usurped(_) |
+				Self::Finalized(_) |
+				Self::FinalityTimeout(_) |
+				Self::Invalid |
+				Self::Dropped
+		)
+	}
+
+	/// Returns true if the transaction could be submitted again after this event.
+	///
+	/// This is the case for the final events that don't mean the transaction can never be
+	/// included, e.g. an invalid transaction might become valid again after a re-org.
+	pub fn is_retriable(&self) -> bool {
+		matches!(self, Self::FinalityTimeout(_) | Self::Invalid | Self::Dropped)
+	}
+}
+
 /// The stream of transaction events.
 pub type TransactionStatusStream<Hash, BlockHash> =
 	dyn Stream<Item = TransactionStatus<Hash, BlockHash>> + Send;
@@ -520,4 +542,29 @@ mod tests {
 		let event_dec: TransactionStatus<u8, u8> = serde_json::from_str(exp).unwrap();
 		assert_eq!(event_dec, TransactionStatus::Finalized((1, 0)));
 	}
+
+	#[test]
+	fn tx_status_final_and_retriable() {
+		let final_events: Vec<TransactionStatus<u8, u8>> = vec![
+			TransactionStatus::Usurped(1),
+			TransactionStatus::Finalized((1, 0)),
+			TransactionStatus::FinalityTimeout(1),
+			TransactionStatus::Invalid,
+			TransactionStatus::Dropped,
+		];
+		assert!(final_events.iter().all(|event| event.is_final()));
+		assert!(!TransactionStatus::<u8, u8>::Usurped(1).is_retriable());
+		assert!(!TransactionStatus::<u8, u8>::Finalized((1, 0)).is_retriable());
+
+		let intermediate_events: Vec<TransactionStatus<u8, u8>> = vec![
+			TransactionStatus::Future,
+			TransactionStatus::Ready,
+			TransactionStatus::Broadcast(vec![]),
+			TransactionStatus::InBlock((1, 0)),
+			TransactionStatus::Retracted(1),
+		];
+		assert!(intermediate_events
+			.iter()
+			.all(|event| !event.is_final() && !event.is_retriable()));
+	}
 }
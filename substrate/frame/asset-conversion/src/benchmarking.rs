@@ -18,7 +18,7 @@
 //! Asset Conversion pallet benchmarking.
 
 use super::*;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_benchmarking::{benchmarks, whitelisted_caller, BenchmarkError};
 use frame_support::{
 	assert_ok,
 	storage::bounded_vec::BoundedVec,
@@ -88,6 +88,17 @@ where
 	(lp_token, caller, caller_lookup)
 }
 
+/// Raise `amount` so that providing it for both assets of an empty pool mints at least
+/// `MinInitialLiquidity`.
+fn initial_liquidity<T: Config>(amount: u128) -> u128
+where
+	T::AssetBalance: Into<u128>,
+{
+	let min_amount: u128 = T::MinInitialLiquidity::get().into();
+	let mint_min_liquidity: u128 = T::MintMinLiquidity::get().into();
+	amount.max(min_amount.saturating_add(mint_min_liquidity))
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
@@ -127,11 +138,12 @@ benchmarks! {
 		let asset2 = T::BenchmarkHelper::multiasset_id(0);
 		let (lp_token, caller, _) = create_asset_and_pool::<T>(&asset1, &asset2);
 		let ed: u128 = T::Currency::minimum_balance().into();
-		let add_amount = 1000 + ed;
-	}: _(SystemOrigin::Signed(caller.clone()), asset1.clone(), asset2.clone(), add_amount.into(), 1000.into(), 0.into(), 0.into(), caller.clone())
+		let add_amount = initial_liquidity::<T>(1000 + ed);
+		let asset_amount = initial_liquidity::<T>(1000);
+	}: _(SystemOrigin::Signed(caller.clone()), asset1.clone(), asset2.clone(), add_amount.into(), asset_amount.into(), 0.into(), 0.into(), caller.clone())
 	verify {
 		let pool_id = (asset1.clone(), asset2.clone());
-		let lp_minted = AssetConversion::<T>::calc_lp_amount_for_zero_supply(&add_amount.into(), &1000.into()).unwrap().into();
+		let lp_minted = AssetConversion::<T>::calc_lp_amount_for_zero_supply(&add_amount.into(), &asset_amount.into()).unwrap().into();
 		assert_eq!(
 			T::PoolAssets::balance(lp_token, &caller),
 			lp_minted.into()
@@ -142,7 +154,7 @@ benchmarks! {
 		);
 		assert_eq!(
 			T::Assets::balance(T::BenchmarkHelper::asset_id(0), &AssetConversion::<T>::get_pool_account(&pool_id)),
-			1000.into()
+			asset_amount.into()
 		);
 	}
 
@@ -151,8 +163,9 @@ benchmarks! {
 		let asset2 = T::BenchmarkHelper::multiasset_id(0);
 		let (lp_token, caller, _) = create_asset_and_pool::<T>(&asset1, &asset2);
 		let ed: u128 = T::Currency::minimum_balance().into();
-		let add_amount = 100 * ed;
-		let lp_minted = AssetConversion::<T>::calc_lp_amount_for_zero_supply(&add_amount.into(), &1000.into()).unwrap().into();
+		let add_amount = initial_liquidity::<T>(100 * ed);
+		let asset_amount = initial_liquidity::<T>(1000);
+		let lp_minted = AssetConversion::<T>::calc_lp_amount_for_zero_supply(&add_amount.into(), &asset_amount.into()).unwrap().into();
 		let remove_lp_amount = lp_minted.checked_div(10).unwrap();
 
 		AssetConversion::<T>::add_liquidity(
@@ -160,7 +173,7 @@ benchmarks! {
 			asset1.clone(),
 			asset2.clone(),
 			add_amount.into(),
-			1000.into(),
+			asset_amount.into(),
 			0.into(),
 			0.into(),
 			caller.clone(),
@@ -187,8 +200,8 @@ benchmarks! {
 			SystemOrigin::Signed(caller.clone()).into(),
 			native.clone(),
 			asset1.clone(),
-			(100 * ed).into(),
-			200.into(),
+			initial_liquidity::<T>(100 * ed).into(),
+			initial_liquidity::<T>(200).into(),
 			0.into(),
 			0.into(),
 			caller.clone(),
@@ -204,8 +217,8 @@ benchmarks! {
 				SystemOrigin::Signed(caller.clone()).into(),
 				native.clone(),
 				asset2.clone(),
-				(500 * ed).into(),
-				1000.into(),
+				initial_liquidity::<T>(500 * ed).into(),
+				initial_liquidity::<T>(1000).into(),
 				0.into(),
 				0.into(),
 				caller.clone(),
@@ -222,8 +235,8 @@ benchmarks! {
 				SystemOrigin::Signed(caller.clone()).into(),
 				asset1.clone(),
 				asset2.clone(),
-				200.into(),
-				2000.into(),
+				initial_liquidity::<T>(200).into(),
+				initial_liquidity::<T>(2000).into(),
 				0.into(),
 				0.into(),
 				caller.clone(),
@@ -232,8 +245,8 @@ benchmarks! {
 				SystemOrigin::Signed(caller.clone()).into(),
 				asset2.clone(),
 				asset3.clone(),
-				2000.into(),
-				2000.into(),
+				initial_liquidity::<T>(2000).into(),
+				initial_liquidity::<T>(2000).into(),
 				0.into(),
 				0.into(),
 				caller.clone(),
@@ -268,8 +281,8 @@ benchmarks! {
 			SystemOrigin::Signed(caller.clone()).into(),
 			native.clone(),
 			asset1.clone(),
-			(1000 * ed).into(),
-			500.into(),
+			initial_liquidity::<T>(1000 * ed).into(),
+			initial_liquidity::<T>(500).into(),
 			0.into(),
 			0.into(),
 			caller.clone(),
@@ -284,8 +297,8 @@ benchmarks! {
 				SystemOrigin::Signed(caller.clone()).into(),
 				native.clone(),
 				asset2.clone(),
-				(500 * ed).into(),
-				1000.into(),
+				initial_liquidity::<T>(500 * ed).into(),
+				initial_liquidity::<T>(1000).into(),
 				0.into(),
 				0.into(),
 				caller.clone(),
@@ -301,8 +314,8 @@ benchmarks! {
 				SystemOrigin::Signed(caller.clone()).into(),
 				asset1.clone(),
 				asset2.clone(),
-				2000.into(),
-				2000.into(),
+				initial_liquidity::<T>(2000).into(),
+				initial_liquidity::<T>(2000).into(),
 				0.into(),
 				0.into(),
 				caller.clone(),
@@ -311,8 +324,8 @@ benchmarks! {
 				SystemOrigin::Signed(caller.clone()).into(),
 				asset2.clone(),
 				asset3.clone(),
-				2000.into(),
-				2000.into(),
+				initial_liquidity::<T>(2000).into(),
+				initial_liquidity::<T>(2000).into(),
 				0.into(),
 				0.into(),
 				caller.clone(),
@@ -334,5 +347,14 @@ benchmarks! {
 		}
 	}
 
+	set_asset_allowed {
+		let origin = T::AllowlistOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let asset = T::BenchmarkHelper::multiasset_id(0);
+	}: _<T::RuntimeOrigin>(origin, asset.clone(), true)
+	verify {
+		assert_last_event::<T>(Event::AssetAllowlistUpdated { asset, allowed: true }.into());
+	}
+
 	impl_benchmark_test_suite!(AssetConversion, crate::mock::new_test_ext(), crate::mock::Test);
 }
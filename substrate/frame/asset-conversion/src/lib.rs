@@ -94,14 +94,17 @@ pub mod pallet {
 	use frame_support::{
 		pallet_prelude::*,
 		traits::{
-			fungible::{Inspect as InspectFungible, Mutate as MutateFungible},
+			fungible::{
+				Inspect as InspectFungible, Mutate as MutateFungible,
+				MutateHold as MutateHoldFungible,
+			},
 			fungibles::{Create, Inspect, Mutate},
 			tokens::{
 				Fortitude::Polite,
 				Precision::Exact,
 				Preservation::{Expendable, Preserve},
 			},
-			AccountTouch, ContainsPair,
+			AccountTouch, Contains, ContainsPair,
 		},
 		BoundedBTreeSet, PalletId,
 	};
@@ -121,7 +124,11 @@ pub mod pallet {
 
 		/// Currency type that this works on.
 		type Currency: InspectFungible<Self::AccountId, Balance = Self::Balance>
-			+ MutateFungible<Self::AccountId>;
+			+ MutateFungible<Self::AccountId>
+			+ MutateHoldFungible<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
 
 		/// The `Currency::Balance` type of the native currency.
 		type Balance: Balance;
@@ -202,6 +209,30 @@ pub mod pallet {
 		#[pallet::constant]
 		type AllowMultiAssetPools: Get<bool>;
 
+		/// A deposit held from the creator of a pool for as long as the pool exists.
+		///
+		/// Pools can't be destroyed, so the deposit is never released. It works like a burned
+		/// fee: unlike `PoolSetupFee`, it isn't paid to anyone, but its payer can't spend it.
+		#[pallet::constant]
+		type PoolCreationDeposit: Get<Self::Balance>;
+
+		/// The minimum amount of lp tokens that the first liquidity provision of a pool has to
+		/// mint for the provider, i.e. the square root of the product of the initial reserves, less
+		/// `MintMinLiquidity`.
+		///
+		/// Prevents pools with dust liquidity, whose price can be moved for next to nothing.
+		#[pallet::constant]
+		type MinInitialLiquidity: Get<Self::AssetBalance>;
+
+		/// Assets with transfer restrictions, for which pools can only be created once they are
+		/// allowlisted by `AllowlistOrigin`.
+		///
+		/// Can be `Nothing` if pools may be created for all assets.
+		type RestrictedAssets: Contains<Self::MultiAssetId>;
+
+		/// The origin that can allowlist assets of `RestrictedAssets` for pool creation.
+		type AllowlistOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -221,6 +252,28 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NextPoolAssetId<T: Config> = StorageValue<_, T::PoolAssetId, OptionQuery>;
 
+	/// The account that created a pool and the deposit held from it, for pools created while the
+	/// `PoolCreationDeposit` was non-zero.
+	///
+	/// Nothing releases these deposits, as pools can't be destroyed. They are recorded so that
+	/// they can be released if pools can be destroyed one day.
+	#[pallet::storage]
+	pub type PoolDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolIdOf<T>, (T::AccountId, T::Balance), OptionQuery>;
+
+	/// The assets of `RestrictedAssets` that are allowlisted for pool creation.
+	#[pallet::storage]
+	pub type AllowedAssets<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultiAssetId, (), OptionQuery>;
+
+	/// A reason for the asset conversion pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The deposit for the creation of a pool.
+		#[codec(index = 0)]
+		PoolCreation,
+	}
+
 	// Pallet's events.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -302,6 +355,23 @@ pub mod pallet {
 			/// The amount of the asset that was transferred.
 			amount: T::AssetBalance,
 		},
+		/// The `PoolCreationDeposit` was held from the creator of a pool.
+		PoolDepositHeld {
+			/// The account that the deposit was held from.
+			who: T::AccountId,
+			/// The pool id of the pool that the deposit is held for.
+			pool_id: PoolIdOf<T>,
+			/// The amount of the deposit.
+			amount: T::Balance,
+		},
+		/// An asset with transfer restrictions was added to or removed from the allowlist for pool
+		/// creation.
+		AssetAllowlistUpdated {
+			/// The asset that was updated.
+			asset: T::MultiAssetId,
+			/// Whether pools can be created for the asset.
+			allowed: bool,
+		},
 	}
 
 	#[pallet::error]
@@ -365,6 +435,11 @@ pub mod pallet {
 		/// with another. For example, an array of assets constituting a `path` should have a
 		/// corresponding array of `amounts` along the path.
 		CorrespondenceError,
+		/// The lp tokens minted by the first liquidity provision of a pool are less than
+		/// `MinInitialLiquidity`.
+		InitialLiquidityTooLow,
+		/// The asset has transfer restrictions and isn't allowlisted for pool creation.
+		AssetNotAllowed,
 	}
 
 	#[pallet::hooks]
@@ -401,6 +476,10 @@ pub mod pallet {
 			if !T::AllowMultiAssetPools::get() && !T::MultiAssetIdConverter::is_native(asset1) {
 				Err(Error::<T>::PoolMustContainNativeCurrency)?;
 			}
+			ensure!(
+				Self::is_asset_allowed(asset1) && Self::is_asset_allowed(asset2),
+				Error::<T>::AssetNotAllowed
+			);
 
 			let pool_account = Self::get_pool_account(&pool_id);
 			frame_system::Pallet::<T>::inc_providers(&pool_account);
@@ -413,6 +492,18 @@ pub mod pallet {
 				Preserve,
 			)?;
 
+			// hold the deposit for the lifetime of the pool
+			let deposit = T::PoolCreationDeposit::get();
+			if !deposit.is_zero() {
+				T::Currency::hold(&HoldReason::PoolCreation.into(), &sender, deposit)?;
+				PoolDeposits::<T>::insert(&pool_id, (sender.clone(), deposit));
+				Self::deposit_event(Event::PoolDepositHeld {
+					who: sender.clone(),
+					pool_id: pool_id.clone(),
+					amount: deposit,
+				});
+			}
+
 			// try to convert both assets
 			match T::MultiAssetIdConverter::try_convert(asset1) {
 				MultiAssetIdConversionResult::Converted(asset) =>
@@ -540,6 +631,10 @@ pub mod pallet {
 			let lp_token_amount: T::AssetBalance;
 			if total_supply.is_zero() {
 				lp_token_amount = Self::calc_lp_amount_for_zero_supply(&amount1, &amount2)?;
+				ensure!(
+					lp_token_amount >= T::MinInitialLiquidity::get(),
+					Error::<T>::InitialLiquidityTooLow
+				);
 				T::PoolAssets::mint_into(
 					pool.lp_token.clone(),
 					&pool_account,
@@ -702,6 +797,27 @@ pub mod pallet {
 			)?;
 			Ok(())
 		}
+
+		/// Allow or disallow the creation of pools for `asset`, an asset with transfer
+		/// restrictions.
+		///
+		/// Must be called by `AllowlistOrigin`. Pools that already exist are not affected.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_asset_allowed())]
+		pub fn set_asset_allowed(
+			origin: OriginFor<T>,
+			asset: T::MultiAssetId,
+			allowed: bool,
+		) -> DispatchResult {
+			T::AllowlistOrigin::ensure_origin(origin)?;
+			if allowed {
+				AllowedAssets::<T>::insert(&asset, ());
+			} else {
+				AllowedAssets::<T>::remove(&asset);
+			}
+			Self::deposit_event(Event::AssetAllowlistUpdated { asset, allowed });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -1062,6 +1178,82 @@ pub mod pallet {
 			}
 		}
 
+		/// Used by the RPC service to quote swapping an exact `amount_in` of `asset1` for `asset2`.
+		///
+		/// Both the direct route and the route through the native asset are considered, and the
+		/// one giving the highest amount out is returned.
+		pub fn quote_swap_exact_tokens_for_tokens(
+			asset1: T::MultiAssetId,
+			asset2: T::MultiAssetId,
+			amount_in: T::AssetBalance,
+		) -> Option<SwapQuote<T::MultiAssetId, T::AssetBalance>> {
+			Self::swap_routes(asset1, asset2)
+				.into_iter()
+				.filter_map(|path| {
+					let amount_out = *Self::get_amounts_out(&amount_in, &path).ok()?.last()?;
+					Self::swap_quote(path, amount_in, amount_out).ok()
+				})
+				.max_by_key(|quote| quote.amount_out)
+		}
+
+		/// Used by the RPC service to quote swapping `asset1` for an exact `amount_out` of
+		/// `asset2`.
+		///
+		/// Both the direct route and the route through the native asset are considered, and the
+		/// one requiring the lowest amount in is returned.
+		pub fn quote_swap_tokens_for_exact_tokens(
+			asset1: T::MultiAssetId,
+			asset2: T::MultiAssetId,
+			amount_out: T::AssetBalance,
+		) -> Option<SwapQuote<T::MultiAssetId, T::AssetBalance>> {
+			Self::swap_routes(asset1, asset2)
+				.into_iter()
+				.filter_map(|path| {
+					let amount_in = *Self::get_amounts_in(&amount_out, &path).ok()?.first()?;
+					Self::swap_quote(path, amount_in, amount_out).ok()
+				})
+				.min_by_key(|quote| quote.amount_in)
+		}
+
+		/// The routes considered for a swap from `asset1` to `asset2`: the direct one and, if
+		/// neither asset is native, the one through the native asset.
+		fn swap_routes(
+			asset1: T::MultiAssetId,
+			asset2: T::MultiAssetId,
+		) -> Vec<BoundedVec<T::MultiAssetId, T::MaxSwapPathLength>> {
+			let mut routes = vec![vec![asset1.clone(), asset2.clone()]];
+			if !T::MultiAssetIdConverter::is_native(&asset1) &&
+				!T::MultiAssetIdConverter::is_native(&asset2)
+			{
+				routes.push(vec![asset1, T::MultiAssetIdConverter::get_native(), asset2]);
+			}
+			routes.into_iter().filter_map(|route| route.try_into().ok()).collect()
+		}
+
+		/// Builds the quote of swapping `amount_in` for `amount_out` along `path`, measuring the
+		/// price impact against the current spot price of the path.
+		fn swap_quote(
+			path: BoundedVec<T::MultiAssetId, T::MaxSwapPathLength>,
+			amount_in: T::AssetBalance,
+			amount_out: T::AssetBalance,
+		) -> Result<SwapQuote<T::MultiAssetId, T::AssetBalance>, DispatchError> {
+			let mut spot_amount_out = amount_in;
+			for assets_pair in path.windows(2) {
+				if let [asset1, asset2] = assets_pair {
+					let (reserve_in, reserve_out) = Self::get_reserves(asset1, asset2)?;
+					spot_amount_out = Self::quote(&spot_amount_out, &reserve_in, &reserve_out)?;
+				}
+			}
+
+			let price_impact = if spot_amount_out.is_zero() {
+				Permill::zero()
+			} else {
+				Permill::from_rational(spot_amount_out.saturating_sub(amount_out), spot_amount_out)
+			};
+
+			Ok(SwapQuote { path: path.into_inner(), amount_in, amount_out, price_impact })
+		}
+
 		/// Calculates the optimal amount from the reserves.
 		pub fn quote(
 			amount: &T::AssetBalance,
@@ -1207,6 +1399,12 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Whether pools can be created for `asset`, i.e. it has no transfer restrictions or is
+		/// allowlisted.
+		fn is_asset_allowed(asset: &T::MultiAssetId) -> bool {
+			!T::RestrictedAssets::contains(asset) || AllowedAssets::<T>::contains_key(asset)
+		}
+
 		/// Ensure that a path is valid.
 		fn validate_swap_path(
 			path: &BoundedVec<T::MultiAssetId, T::MaxSwapPathLength>,
@@ -1304,6 +1502,22 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns the size of the liquidity pool for the given asset pair.
 		fn get_reserves(asset1: AssetId, asset2: AssetId) -> Option<(Balance, Balance)>;
+
+		/// Quotes swapping an exact `amount` of `asset1` for `asset2`, including the fees.
+		///
+		/// The returned quote describes the route giving the highest amount out, either directly
+		/// or through the native asset, along with its price impact. Pass its path to
+		/// [`Pallet::swap_exact_tokens_for_tokens`].
+		#[api_version(2)]
+		fn quote_swap_exact_tokens_for_tokens(asset1: AssetId, asset2: AssetId, amount: AssetBalance) -> Option<SwapQuote<AssetId, AssetBalance>>;
+
+		/// Quotes swapping `asset1` for an exact `amount` of `asset2`, including the fees.
+		///
+		/// The returned quote describes the route requiring the lowest amount in, either directly
+		/// or through the native asset, along with its price impact. Pass its path to
+		/// [`Pallet::swap_tokens_for_exact_tokens`].
+		#[api_version(2)]
+		fn quote_swap_tokens_for_exact_tokens(asset1: AssetId, asset2: AssetId, amount: AssetBalance) -> Option<SwapQuote<AssetId, AssetBalance>>;
 	}
 }
 
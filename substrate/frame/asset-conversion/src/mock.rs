@@ -24,7 +24,7 @@ use frame_support::{
 	construct_runtime, derive_impl,
 	instances::{Instance1, Instance2},
 	ord_parameter_types, parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64},
+	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, Equals},
 	PalletId,
 };
 use frame_system::{EnsureSigned, EnsureSignedBy};
@@ -87,9 +87,9 @@ impl pallet_balances::Config for Test {
 	type ReserveIdentifier = [u8; 8];
 	type FreezeIdentifier = ();
 	type MaxFreezes = ();
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = ();
-	type MaxHolds = ();
+	type MaxHolds = ConstU32<1>;
 }
 
 impl pallet_assets::Config<Instance1> for Test {
@@ -145,8 +145,14 @@ parameter_types! {
 	pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
 	pub storage AllowMultiAssetPools: bool = true;
 	pub storage LiquidityWithdrawalFee: Permill = Permill::from_percent(0); // should be non-zero if AllowMultiAssetPools is true, otherwise can be zero
+	pub storage PoolCreationDeposit: u128 = 0;
+	pub storage MinInitialLiquidity: u128 = 100;
+	pub const RestrictedAsset: NativeOrAssetId<u32> = NativeOrAssetId::Asset(RESTRICTED_ASSET);
 }
 
+/// An asset with transfer restrictions, that has to be allowlisted for pool creation.
+pub(crate) const RESTRICTED_ASSET: u32 = 99;
+
 ord_parameter_types! {
 	pub const AssetConversionOrigin: u128 = AccountIdConversion::<u128>::into_account_truncating(&AssetConversionPalletId::get());
 }
@@ -154,6 +160,7 @@ ord_parameter_types! {
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type AssetBalance = <Self as pallet_balances::Config>::Balance;
 	type AssetId = u32;
 	type PoolAssetId = u32;
@@ -168,6 +175,10 @@ impl Config for Test {
 	type AllowMultiAssetPools = AllowMultiAssetPools;
 	type MaxSwapPathLength = ConstU32<4>;
 	type MintMinLiquidity = ConstU128<100>; // 100 is good enough when the main currency has 12 decimals.
+	type PoolCreationDeposit = PoolCreationDeposit;
+	type MinInitialLiquidity = MinInitialLiquidity;
+	type RestrictedAssets = Equals<RestrictedAsset>;
+	type AllowlistOrigin = frame_system::EnsureRoot<Self::AccountId>;
 
 	type Balance = u128;
 	type HigherPrecisionBalance = sp_core::U256;
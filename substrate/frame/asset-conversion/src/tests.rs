@@ -19,7 +19,12 @@ use crate::{mock::*, *};
 use frame_support::{
 	assert_noop, assert_ok,
 	instances::Instance1,
-	traits::{fungible::Inspect, fungibles::InspectEnumerable, Get},
+	traits::{
+		fungible::{Inspect, InspectHold},
+		fungibles::InspectEnumerable,
+		Get,
+	},
+	BoundedVec,
 };
 use sp_arithmetic::Permill;
 use sp_runtime::{DispatchError, TokenError};
@@ -193,6 +198,82 @@ fn can_create_pool() {
 	});
 }
 
+#[test]
+fn create_pool_holds_deposit() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrAssetId::Native;
+		let token_2 = NativeOrAssetId::Asset(2);
+		let pool_id = (token_1, token_2);
+		let deposit = 500;
+		PoolCreationDeposit::set(&deposit);
+
+		create_tokens(user, vec![token_2]);
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 1000));
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_1, token_2));
+
+		assert_eq!(Balances::balance_on_hold(&HoldReason::PoolCreation.into(), &user), deposit);
+		assert_eq!(PoolDeposits::<Test>::get(&pool_id), Some((user, deposit)));
+		assert_eq!(
+			events(),
+			[
+				Event::<Test>::PoolDepositHeld { who: user, pool_id, amount: deposit },
+				Event::<Test>::PoolCreated {
+					creator: user,
+					pool_id,
+					pool_account: AssetConversion::get_pool_account(&pool_id),
+					lp_token
+				},
+			]
+		);
+
+		// the deposit for another pool can't be paid
+		let token_3 = NativeOrAssetId::Asset(3);
+		create_tokens(user, vec![token_3]);
+		assert_noop!(
+			AssetConversion::create_pool(RuntimeOrigin::signed(user), token_1, token_3),
+			TokenError::FundsUnavailable
+		);
+	});
+}
+
+#[test]
+fn restricted_assets_need_to_be_allowlisted() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrAssetId::Native;
+		let token_2 = NativeOrAssetId::Asset(RESTRICTED_ASSET);
+
+		create_tokens(user, vec![token_2]);
+		assert_noop!(
+			AssetConversion::create_pool(RuntimeOrigin::signed(user), token_1, token_2),
+			Error::<Test>::AssetNotAllowed
+		);
+
+		assert_noop!(
+			AssetConversion::set_asset_allowed(RuntimeOrigin::signed(user), token_2, true),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(AssetConversion::set_asset_allowed(RuntimeOrigin::root(), token_2, true));
+		assert_eq!(
+			events(),
+			[Event::<Test>::AssetAllowlistUpdated { asset: token_2, allowed: true }]
+		);
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_1, token_2));
+
+		// the existing pool is kept, but no other pools can be created
+		assert_ok!(AssetConversion::set_asset_allowed(RuntimeOrigin::root(), token_2, false));
+		assert_eq!(pools(), vec![(token_1, token_2)]);
+		let token_3 = NativeOrAssetId::Asset(3);
+		create_tokens(user, vec![token_3]);
+		assert_noop!(
+			AssetConversion::create_pool(RuntimeOrigin::signed(user), token_2, token_3),
+			Error::<Test>::AssetNotAllowed
+		);
+	});
+}
+
 #[test]
 fn create_same_pool_twice_should_fail() {
 	new_test_ext().execute_with(|| {
@@ -384,6 +465,63 @@ fn add_tiny_liquidity_leads_to_insufficient_liquidity_minted_error() {
 	});
 }
 
+#[test]
+fn add_liquidity_to_empty_pool_requires_min_initial_liquidity() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrAssetId::Native;
+		let token_2 = NativeOrAssetId::Asset(2);
+		MinInitialLiquidity::set(&1000);
+
+		create_tokens(user, vec![token_2]);
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_1, token_2));
+
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 10000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 2000));
+
+		// sqrt(1000 * 100) - 100 = 216 lp tokens
+		assert_noop!(
+			AssetConversion::add_liquidity(
+				RuntimeOrigin::signed(user),
+				token_1,
+				token_2,
+				1000,
+				100,
+				1,
+				1,
+				user
+			),
+			Error::<Test>::InitialLiquidityTooLow
+		);
+
+		// sqrt(1100 * 1100) - 100 = 1000 lp tokens
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			token_1,
+			token_2,
+			1100,
+			1100,
+			1,
+			1,
+			user
+		));
+		assert_eq!(pool_balance(user, lp_token), 1000);
+
+		// the minimum only applies to the first liquidity provision
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			token_1,
+			token_2,
+			200,
+			200,
+			1,
+			1,
+			user
+		));
+	});
+}
+
 #[test]
 fn add_tiny_liquidity_directly_to_pool_address() {
 	new_test_ext().execute_with(|| {
@@ -756,6 +894,91 @@ fn can_quote_price() {
 	});
 }
 
+#[test]
+fn can_quote_swap_through_best_route() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrAssetId::Native;
+		let token_2 = NativeOrAssetId::Asset(2);
+		let token_3 = NativeOrAssetId::Asset(3);
+
+		create_tokens(user, vec![token_2, token_3]);
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_1, token_2));
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_1, token_3));
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_2, token_3));
+
+		let ed = get_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 20000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 11000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user, 11000));
+
+		// deep pools against the native asset and a thin direct pool.
+		for (asset1, asset2, amount1, amount2) in [
+			(token_1, token_2, 10000, 10000),
+			(token_1, token_3, 10000, 10000),
+			(token_2, token_3, 1000, 1000),
+		] {
+			assert_ok!(AssetConversion::add_liquidity(
+				RuntimeOrigin::signed(user),
+				asset1,
+				asset2,
+				amount1,
+				amount2,
+				1,
+				1,
+				user,
+			));
+		}
+
+		let amount_in = 500;
+		let quote =
+			AssetConversion::quote_swap_exact_tokens_for_tokens(token_2, token_3, amount_in)
+				.unwrap();
+		let path: BoundedVec<_, _> = bvec![token_2, token_1, token_3];
+		assert_eq!(quote.path, path.to_vec());
+		assert_eq!(quote.amount_in, amount_in);
+		assert_eq!(
+			quote.amount_out,
+			*AssetConversion::get_amounts_out(&amount_in, &path).unwrap().last().unwrap()
+		);
+		let direct_amount_out =
+			*AssetConversion::get_amounts_out(&amount_in, &bvec![token_2, token_3])
+				.unwrap()
+				.last()
+				.unwrap();
+		assert!(quote.amount_out > direct_amount_out);
+		assert!(quote.price_impact > Permill::zero());
+
+		let amount_out = 300;
+		let quote =
+			AssetConversion::quote_swap_tokens_for_exact_tokens(token_2, token_3, amount_out)
+				.unwrap();
+		assert_eq!(quote.path, path.to_vec());
+		assert_eq!(quote.amount_out, amount_out);
+		assert_eq!(
+			quote.amount_in,
+			*AssetConversion::get_amounts_in(&amount_out, &path).unwrap().first().unwrap()
+		);
+		assert!(quote.price_impact > Permill::zero());
+
+		// the native asset is never routed through itself.
+		let quote =
+			AssetConversion::quote_swap_exact_tokens_for_tokens(token_1, token_3, amount_in)
+				.unwrap();
+		assert_eq!(quote.path, vec![token_1, token_3]);
+
+		// no route without pools.
+		assert_eq!(
+			AssetConversion::quote_swap_exact_tokens_for_tokens(
+				token_2,
+				NativeOrAssetId::Asset(4),
+				amount_in
+			),
+			None
+		);
+	});
+}
+
 #[test]
 fn quote_price_exact_tokens_for_tokens_matches_execution() {
 	new_test_ext().execute_with(|| {
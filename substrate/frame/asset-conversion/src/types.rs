@@ -19,6 +19,8 @@ use super::*;
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+use sp_arithmetic::Permill;
+use sp_runtime::RuntimeDebug;
 use sp_std::{cmp::Ordering, marker::PhantomData};
 
 /// Pool ID.
@@ -34,6 +36,21 @@ pub struct PoolInfo<PoolAssetId> {
 	pub lp_token: PoolAssetId,
 }
 
+/// A quote for a swap along the route that gives the best price.
+#[derive(Decode, Encode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SwapQuote<MultiAssetId, Balance> {
+	/// The route of the swap, starting with the asset in and ending with the asset out. It either
+	/// goes directly from one asset to the other or through the native asset.
+	pub path: Vec<MultiAssetId>,
+	/// The amount of the first asset in the path that is swapped.
+	pub amount_in: Balance,
+	/// The amount of the last asset in the path that is received.
+	pub amount_out: Balance,
+	/// How much worse the price of the swap is compared to the current spot price of the route,
+	/// liquidity provider fees included.
+	pub price_impact: Permill,
+}
+
 /// A trait that converts between a MultiAssetId and either the native currency or an AssetId.
 pub trait MultiAssetIdConverter<MultiAssetId, AssetId> {
 	/// Returns the MultiAssetId representing the native currency of the chain.
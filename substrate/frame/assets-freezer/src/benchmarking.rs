@@ -0,0 +1,126 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The crate's benchmarks.
+
+use super::*;
+use crate::Pallet as AssetsFreezer;
+
+use frame_benchmarking::v2::*;
+use frame_support::{
+	assert_ok,
+	traits::fungibles::{InspectFreeze, MutateFreeze},
+};
+use pallet_assets::BenchmarkHelper as _;
+
+/// Trait describing how to get a freeze reason to benchmark with.
+pub trait BenchmarkHelper<FreezeReason> {
+	/// A freeze reason of the runtime, if there is any.
+	fn freeze_reason() -> Option<FreezeReason>;
+}
+impl<FreezeReason> BenchmarkHelper<FreezeReason> for () {
+	fn freeze_reason() -> Option<FreezeReason> {
+		None
+	}
+}
+
+/// Benchmarks with the [`FreezeReason::Forced`] freeze reason of the pallet.
+pub struct ForcedReason<I = ()>(PhantomData<I>);
+impl<R: From<FreezeReason<I>>, I: 'static> BenchmarkHelper<R> for ForcedReason<I> {
+	fn freeze_reason() -> Option<R> {
+		Some(FreezeReason::<I>::Forced.into())
+	}
+}
+
+fn freeze_reason<T: Config<I>, I: 'static>() -> Result<T::RuntimeFreezeReason, BenchmarkError> {
+	// Without any freeze reason, the calls can't succeed.
+	<T as Config<I>>::BenchmarkHelper::freeze_reason()
+		.ok_or(BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))
+}
+
+#[instance_benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn allow_freeze_reason() -> Result<(), BenchmarkError> {
+		let origin =
+			T::WhitelistOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let reason = freeze_reason::<T, I>()?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, reason);
+
+		assert!(AssetsFreezer::<T, I>::is_allowed(&reason));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn disallow_freeze_reason() -> Result<(), BenchmarkError> {
+		let origin =
+			T::WhitelistOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let reason = freeze_reason::<T, I>()?;
+		assert_ok!(AssetsFreezer::<T, I>::allow_freeze_reason(origin.clone(), reason));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, reason);
+
+		assert!(!AssetsFreezer::<T, I>::is_allowed(&reason));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_freeze() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let reason: T::RuntimeFreezeReason = FreezeReason::<I>::Forced.into();
+		AllowedFreezeReasons::<T, I>::insert(&reason, ());
+		let asset = <T as pallet_assets::Config<I>>::BenchmarkHelper::create_asset_id_parameter(0);
+		let who: T::AccountId = account("who", 0, 0);
+		let amount: T::Balance = 100u32.into();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, asset.clone(), T::Lookup::unlookup(who.clone()), amount);
+
+		assert_eq!(AssetsFreezer::<T, I>::balance_frozen(asset.into(), &reason, &who), amount);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_thaw() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let reason: T::RuntimeFreezeReason = FreezeReason::<I>::Forced.into();
+		AllowedFreezeReasons::<T, I>::insert(&reason, ());
+		let asset = <T as pallet_assets::Config<I>>::BenchmarkHelper::create_asset_id_parameter(0);
+		let who: T::AccountId = account("who", 0, 0);
+		assert_ok!(AssetsFreezer::<T, I>::set_freeze(
+			asset.clone().into(),
+			&reason,
+			&who,
+			100u32.into()
+		));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, asset.clone(), T::Lookup::unlookup(who.clone()));
+
+		assert!(AssetsFreezer::<T, I>::balance_frozen(asset.into(), &reason, &who).is_zero());
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(AssetsFreezer, crate::mock::new_test_ext(), crate::mock::Test);
+}
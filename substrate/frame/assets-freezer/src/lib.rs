@@ -0,0 +1,360 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Assets Freezer Pallet
+//!
+//! - [`Config`]
+//! - [`Call`]
+//!
+//! ## Overview
+//!
+//! The Assets Freezer pallet provides named freezes on the balances of an instance of
+//! `pallet-assets`, the same way `pallet-balances` does for the native currency.
+//!
+//! It is meant to be used as the [`pallet_assets::Config::Freezer`] of that same instance, and
+//! implements [`InspectFreeze`] and [`MutateFreeze`] so that other pallets
+//! can freeze funds of an account under their own [`Config::RuntimeFreezeReason`].
+//!
+//! ### Terminology
+//!
+//! * **Freeze**: An amount of an asset below which the balance of an account may not be reduced,
+//!   identified by a freeze reason. Freezes of the same account overlap: the frozen balance of an
+//!   account is the largest of its freezes.
+//! * **Allowed freeze reason**: A freeze reason which may be used to place new freezes. Only
+//!   [`Config::WhitelistOrigin`] can allow or disallow freeze reasons.
+//! * **Forced freeze**: A freeze placed by [`Config::ForceOrigin`] with the
+//!   [`FreezeReason::Forced`] reason of this pallet.
+//!
+//! ## Interface
+//!
+//! ### Permissioned Functions
+//!
+//! * `allow_freeze_reason`: Allow placing freezes with a freeze reason.
+//! * `disallow_freeze_reason`: Stop allowing new freezes with a freeze reason. Existing freezes are
+//!   kept and may still be changed or thawed.
+//! * `force_freeze`: Freeze an amount of an asset of an account.
+//! * `force_thaw`: Thaw the forced freeze of an account.
+//!
+//! Please refer to the [`Call`] enum and its associated variants for documentation on each
+//! function.
+//!
+//! [`InspectFreeze`]: frame_support::traits::fungibles::InspectFreeze
+//! [`MutateFreeze`]: frame_support::traits::fungibles::MutateFreeze
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{fungibles::MutateFreeze, VariantCount},
+};
+use frame_system::pallet_prelude::*;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Saturating, StaticLookup, Zero},
+	RuntimeDebug,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+mod impls;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub use benchmarking::{BenchmarkHelper, ForcedReason};
+
+/// An identifier and balance.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct IdAmount<Id, Balance> {
+	/// An identifier for this item.
+	pub id: Id,
+	/// Some amount for this item.
+	pub amount: Balance,
+}
+
+/// The number of variants of `T`, usable as a bound.
+pub struct VariantCountOf<T>(PhantomData<T>);
+impl<T: VariantCount> Get<u32> for VariantCountOf<T> {
+	fn get() -> u32 {
+		T::VARIANT_COUNT
+	}
+}
+
+type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+type AssetIdOf<T, I> = <T as pallet_assets::Config<I>>::AssetId;
+type AssetBalanceOf<T, I> = <T as pallet_assets::Config<I>>::Balance;
+type FreezesOf<T, I> = BoundedVec<
+	IdAmount<<T as Config<I>>::RuntimeFreezeReason, AssetBalanceOf<T, I>>,
+	VariantCountOf<<T as Config<I>>::RuntimeFreezeReason>,
+>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T, I = ()>(_);
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config + pallet_assets::Config<I> {
+		/// The overarching freeze reason.
+		///
+		/// It must have at least one variant, as an account may have as many freezes as there are
+		/// variants.
+		type RuntimeFreezeReason: Parameter
+			+ Member
+			+ MaxEncodedLen
+			+ Copy
+			+ VariantCount
+			+ From<FreezeReason<I>>;
+
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin which may allow or disallow freeze reasons.
+		type WhitelistOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The origin which may force freezes on, and thaw them from, any account.
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper type for benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::RuntimeFreezeReason>;
+	}
+
+	/// A reason for the pallet placing a freeze on funds.
+	#[pallet::composite_enum]
+	pub enum FreezeReason<I: 'static = ()> {
+		/// The funds were frozen by `T::ForceOrigin`.
+		#[codec(index = 0)]
+		Forced,
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// Number of freezes on an account would exceed the number of freeze reasons.
+		TooManyFreezes,
+		/// The freeze reason is not allowed to place freezes.
+		FreezeReasonNotAllowed,
+		/// The freeze reason is already allowed.
+		AlreadyAllowed,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// Freezes may now be placed with `reason`.
+		FreezeReasonAllowed { reason: T::RuntimeFreezeReason },
+		/// New freezes may no longer be placed with `reason`.
+		FreezeReasonDisallowed { reason: T::RuntimeFreezeReason },
+		/// The frozen balance of `who` for `asset_id` was increased by `amount`.
+		Frozen { who: T::AccountId, asset_id: T::AssetId, amount: T::Balance },
+		/// The frozen balance of `who` for `asset_id` was decreased by `amount`.
+		Thawed { who: T::AccountId, asset_id: T::AssetId, amount: T::Balance },
+	}
+
+	/// The freeze reasons that may be used to place new freezes.
+	#[pallet::storage]
+	pub type AllowedFreezeReasons<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::RuntimeFreezeReason, (), OptionQuery>;
+
+	/// The freezes placed on the balance of an account for a given asset.
+	#[pallet::storage]
+	pub type Freezes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		FreezesOf<T, I>,
+		ValueQuery,
+	>;
+
+	/// The frozen balance of an account for a given asset, i.e. the largest of its freezes.
+	#[pallet::storage]
+	pub type FrozenBalances<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+		OptionQuery,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn integrity_test() {
+			assert!(
+				T::RuntimeFreezeReason::VARIANT_COUNT > 0,
+				"`RuntimeFreezeReason` has no variants, so no freeze could ever be placed",
+			);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Allow placing freezes with `reason`.
+		///
+		/// The dispatch origin of this call must be `T::WhitelistOrigin`.
+		///
+		/// Emits `FreezeReasonAllowed` if successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::allow_freeze_reason())]
+		pub fn allow_freeze_reason(
+			origin: OriginFor<T>,
+			reason: T::RuntimeFreezeReason,
+		) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+			ensure!(
+				!AllowedFreezeReasons::<T, I>::contains_key(&reason),
+				Error::<T, I>::AlreadyAllowed
+			);
+
+			AllowedFreezeReasons::<T, I>::insert(&reason, ());
+			Self::deposit_event(Event::FreezeReasonAllowed { reason });
+			Ok(())
+		}
+
+		/// Stop allowing new freezes with `reason`.
+		///
+		/// Freezes already placed with `reason` are kept, and may still be changed or thawed.
+		///
+		/// The dispatch origin of this call must be `T::WhitelistOrigin`.
+		///
+		/// Emits `FreezeReasonDisallowed` if successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::disallow_freeze_reason())]
+		pub fn disallow_freeze_reason(
+			origin: OriginFor<T>,
+			reason: T::RuntimeFreezeReason,
+		) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+			ensure!(
+				AllowedFreezeReasons::<T, I>::contains_key(&reason),
+				Error::<T, I>::FreezeReasonNotAllowed
+			);
+
+			AllowedFreezeReasons::<T, I>::remove(&reason);
+			Self::deposit_event(Event::FreezeReasonDisallowed { reason });
+			Ok(())
+		}
+
+		/// Freeze `amount` of the `id` asset of `who`, replacing any previous forced freeze.
+		///
+		/// The `Forced` freeze reason of this pallet must be allowed to place a new freeze. A zero
+		/// `amount` thaws the forced freeze.
+		///
+		/// The dispatch origin of this call must be `T::ForceOrigin`.
+		///
+		/// Emits `Frozen` or `Thawed` if the frozen balance of `who` changed.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::force_freeze())]
+		pub fn force_freeze(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+			amount: T::Balance,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			Self::set_freeze(id.into(), &FreezeReason::<I>::Forced.into(), &who, amount)
+		}
+
+		/// Thaw the forced freeze on the `id` asset of `who`.
+		///
+		/// The dispatch origin of this call must be `T::ForceOrigin`.
+		///
+		/// Emits `Thawed` if the frozen balance of `who` decreased.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::force_thaw())]
+		pub fn force_thaw(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			Self::thaw(id.into(), &FreezeReason::<I>::Forced.into(), &who)
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Whether new freezes may be placed with `reason`.
+	pub fn is_allowed(reason: &T::RuntimeFreezeReason) -> bool {
+		AllowedFreezeReasons::<T, I>::contains_key(reason)
+	}
+
+	/// All freezes placed on `asset_id`, as `(account, reason, amount)`.
+	pub fn asset_freezes(
+		asset_id: T::AssetId,
+	) -> Vec<(T::AccountId, T::RuntimeFreezeReason, T::Balance)> {
+		Freezes::<T, I>::iter_prefix(asset_id)
+			.flat_map(|(who, freezes)| {
+				freezes.into_iter().map(move |f| (who.clone(), f.id, f.amount))
+			})
+			.collect()
+	}
+
+	/// Store the new `freezes` of `who` for `asset_id` and update its frozen balance.
+	fn update_freezes(
+		asset_id: AssetIdOf<T, I>,
+		who: &AccountIdOf<T>,
+		freezes: BoundedSlice<
+			IdAmount<T::RuntimeFreezeReason, AssetBalanceOf<T, I>>,
+			VariantCountOf<T::RuntimeFreezeReason>,
+		>,
+	) {
+		let prev_frozen = FrozenBalances::<T, I>::get(asset_id.clone(), who).unwrap_or_default();
+		let after_frozen = freezes.iter().map(|f| f.amount).max().unwrap_or_else(Zero::zero);
+
+		if freezes.is_empty() {
+			Freezes::<T, I>::remove(asset_id.clone(), who);
+		} else {
+			Freezes::<T, I>::insert(asset_id.clone(), who, freezes);
+		}
+		if after_frozen.is_zero() {
+			FrozenBalances::<T, I>::remove(asset_id.clone(), who);
+		} else {
+			FrozenBalances::<T, I>::insert(asset_id.clone(), who, after_frozen);
+		}
+
+		if prev_frozen > after_frozen {
+			let amount = prev_frozen.saturating_sub(after_frozen);
+			Self::deposit_event(Event::Thawed { who: who.clone(), asset_id, amount });
+		} else if after_frozen > prev_frozen {
+			let amount = after_frozen.saturating_sub(prev_frozen);
+			Self::deposit_event(Event::Frozen { who: who.clone(), asset_id, amount });
+		}
+	}
+}
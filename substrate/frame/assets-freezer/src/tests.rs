@@ -0,0 +1,246 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for Assets Freezer pallet.
+
+use super::*;
+use crate::mock::{DummyFreezeReason::*, *};
+
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::tokens::fungibles::{InspectFreeze, MutateFreeze},
+};
+use sp_runtime::DispatchError::BadOrigin;
+
+fn events() -> Vec<Event<Test>> {
+	let result = System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| if let RuntimeEvent::AssetsFreezer(inner) = e { Some(inner) } else { None })
+		.collect();
+
+	System::reset_events();
+
+	result
+}
+
+#[test]
+fn allow_and_disallow_freeze_reason_works() {
+	new_test_ext().execute_with(|| {
+		assert!(!AssetsFreezer::is_allowed(&Other));
+
+		assert_noop!(
+			AssetsFreezer::allow_freeze_reason(RuntimeOrigin::signed(OWNER), Other),
+			BadOrigin
+		);
+		assert_ok!(AssetsFreezer::allow_freeze_reason(RuntimeOrigin::root(), Other));
+		assert!(AssetsFreezer::is_allowed(&Other));
+		assert_noop!(
+			AssetsFreezer::allow_freeze_reason(RuntimeOrigin::root(), Other),
+			Error::<Test>::AlreadyAllowed
+		);
+
+		assert_ok!(AssetsFreezer::disallow_freeze_reason(RuntimeOrigin::root(), Other));
+		assert!(!AssetsFreezer::is_allowed(&Other));
+		assert_noop!(
+			AssetsFreezer::disallow_freeze_reason(RuntimeOrigin::root(), Other),
+			Error::<Test>::FreezeReasonNotAllowed
+		);
+
+		assert_eq!(
+			events(),
+			vec![
+				Event::FreezeReasonAllowed { reason: Other },
+				Event::FreezeReasonDisallowed { reason: Other },
+			]
+		);
+	});
+}
+
+#[test]
+fn set_freeze_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsFreezer::set_freeze(ASSET_ID, &Governance, &WHO, 10));
+		assert_eq!(AssetsFreezer::balance_frozen(ASSET_ID, &Governance, &WHO), 10);
+		assert_eq!(FrozenBalances::<Test>::get(ASSET_ID, WHO), Some(10));
+
+		// Freezes overlap: the frozen balance is the largest freeze.
+		assert_ok!(AssetsFreezer::set_freeze(ASSET_ID, &Staking, &WHO, 5));
+		assert_eq!(FrozenBalances::<Test>::get(ASSET_ID, WHO), Some(10));
+		assert_ok!(AssetsFreezer::set_freeze(ASSET_ID, &Governance, &WHO, 3));
+		assert_eq!(FrozenBalances::<Test>::get(ASSET_ID, WHO), Some(5));
+
+		assert_eq!(
+			events(),
+			vec![
+				Event::Frozen { who: WHO, asset_id: ASSET_ID, amount: 10 },
+				Event::Thawed { who: WHO, asset_id: ASSET_ID, amount: 5 },
+			]
+		);
+	});
+}
+
+#[test]
+fn extend_freeze_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsFreezer::set_freeze(ASSET_ID, &Governance, &WHO, 10));
+		assert_ok!(AssetsFreezer::extend_freeze(ASSET_ID, &Governance, &WHO, 5));
+		assert_eq!(AssetsFreezer::balance_frozen(ASSET_ID, &Governance, &WHO), 10);
+		assert_ok!(AssetsFreezer::extend_freeze(ASSET_ID, &Governance, &WHO, 15));
+		assert_eq!(AssetsFreezer::balance_frozen(ASSET_ID, &Governance, &WHO), 15);
+		assert_eq!(FrozenBalances::<Test>::get(ASSET_ID, WHO), Some(15));
+	});
+}
+
+#[test]
+fn thaw_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsFreezer::set_freeze(ASSET_ID, &Governance, &WHO, 10));
+		assert_ok!(AssetsFreezer::set_freeze(ASSET_ID, &Staking, &WHO, 5));
+
+		assert_ok!(AssetsFreezer::thaw(ASSET_ID, &Governance, &WHO));
+		assert_eq!(AssetsFreezer::balance_frozen(ASSET_ID, &Governance, &WHO), 0);
+		assert_eq!(FrozenBalances::<Test>::get(ASSET_ID, WHO), Some(5));
+
+		// Setting a freeze to zero thaws it.
+		assert_ok!(AssetsFreezer::set_freeze(ASSET_ID, &Staking, &WHO, 0));
+		assert_eq!(FrozenBalances::<Test>::get(ASSET_ID, WHO), None);
+		assert!(!Freezes::<Test>::contains_key(ASSET_ID, WHO));
+	});
+}
+
+#[test]
+fn only_allowed_reasons_can_freeze() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetsFreezer::set_freeze(ASSET_ID, &Other, &WHO, 10),
+			Error::<Test>::FreezeReasonNotAllowed
+		);
+		assert_noop!(
+			AssetsFreezer::extend_freeze(ASSET_ID, &Other, &WHO, 10),
+			Error::<Test>::FreezeReasonNotAllowed
+		);
+
+		// Existing freezes can still be changed or thawed once their reason is disallowed.
+		assert_ok!(AssetsFreezer::set_freeze(ASSET_ID, &Governance, &WHO, 10));
+		assert_ok!(AssetsFreezer::disallow_freeze_reason(RuntimeOrigin::root(), Governance));
+		assert_ok!(AssetsFreezer::set_freeze(ASSET_ID, &Governance, &WHO, 20));
+		assert_ok!(AssetsFreezer::extend_freeze(ASSET_ID, &Governance, &WHO, 30));
+		assert_eq!(FrozenBalances::<Test>::get(ASSET_ID, WHO), Some(30));
+		assert_ok!(AssetsFreezer::thaw(ASSET_ID, &Governance, &WHO));
+		assert_eq!(FrozenBalances::<Test>::get(ASSET_ID, WHO), None);
+
+		// But once thawed, they can't be placed again.
+		assert_noop!(
+			AssetsFreezer::set_freeze(ASSET_ID, &Governance, &WHO, 10),
+			Error::<Test>::FreezeReasonNotAllowed
+		);
+	});
+}
+
+#[test]
+fn force_freeze_and_thaw_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetsFreezer::force_freeze(RuntimeOrigin::signed(OWNER), ASSET_ID, WHO, 10),
+			BadOrigin
+		);
+		assert_ok!(AssetsFreezer::force_freeze(RuntimeOrigin::root(), ASSET_ID, WHO, 10));
+		// The mock maps the `Forced` reason of the pallet to `Governance`.
+		assert_eq!(AssetsFreezer::balance_frozen(ASSET_ID, &Governance, &WHO), 10);
+
+		assert_noop!(
+			AssetsFreezer::force_thaw(RuntimeOrigin::signed(OWNER), ASSET_ID, WHO),
+			BadOrigin
+		);
+		assert_ok!(AssetsFreezer::force_thaw(RuntimeOrigin::root(), ASSET_ID, WHO));
+		assert_eq!(FrozenBalances::<Test>::get(ASSET_ID, WHO), None);
+
+		// Forced freezes are subject to the allowed freeze reasons, too.
+		assert_ok!(AssetsFreezer::disallow_freeze_reason(RuntimeOrigin::root(), Governance));
+		assert_noop!(
+			AssetsFreezer::force_freeze(RuntimeOrigin::root(), ASSET_ID, WHO, 10),
+			Error::<Test>::FreezeReasonNotAllowed
+		);
+
+		assert_eq!(
+			events(),
+			vec![
+				Event::Frozen { who: WHO, asset_id: ASSET_ID, amount: 10 },
+				Event::Thawed { who: WHO, asset_id: ASSET_ID, amount: 10 },
+				Event::FreezeReasonDisallowed { reason: Governance },
+			]
+		);
+	});
+}
+
+#[test]
+fn can_freeze_is_bounded_by_variant_count() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsFreezer::allow_freeze_reason(RuntimeOrigin::root(), Other));
+		assert_ok!(AssetsFreezer::set_freeze(ASSET_ID, &Governance, &WHO, 10));
+		assert_ok!(AssetsFreezer::set_freeze(ASSET_ID, &Staking, &WHO, 10));
+
+		assert!(AssetsFreezer::can_freeze(ASSET_ID, &Governance, &WHO));
+		assert!(!AssetsFreezer::can_freeze(ASSET_ID, &Other, &WHO));
+		assert_noop!(
+			AssetsFreezer::set_freeze(ASSET_ID, &Other, &WHO, 10),
+			Error::<Test>::TooManyFreezes
+		);
+	});
+}
+
+#[test]
+fn frozen_balance_is_respected_by_assets() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsFreezer::set_freeze(ASSET_ID, &Governance, &WHO, 10));
+
+		// 100 minus the frozen balance and the minimum balance of 1.
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(WHO), ASSET_ID, DEST, 90),
+			pallet_assets::Error::<Test>::BalanceLow
+		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(WHO), ASSET_ID, DEST, 89));
+
+		assert_ok!(AssetsFreezer::thaw(ASSET_ID, &Governance, &WHO));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(WHO), ASSET_ID, DEST, 11));
+	});
+}
+
+#[test]
+fn died_clears_freezes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsFreezer::set_freeze(ASSET_ID, &Governance, &WHO, 10));
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(WHO), ASSET_ID, true));
+
+		assert_eq!(FrozenBalances::<Test>::get(ASSET_ID, WHO), None);
+		assert!(!Freezes::<Test>::contains_key(ASSET_ID, WHO));
+	});
+}
+
+#[test]
+fn asset_freezes_lists_all_freezes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsFreezer::set_freeze(ASSET_ID, &Governance, &WHO, 10));
+		assert_ok!(AssetsFreezer::set_freeze(ASSET_ID, &Staking, &WHO, 5));
+		assert_ok!(AssetsFreezer::set_freeze(ASSET_ID + 1, &Staking, &DEST, 1));
+
+		let mut freezes = AssetsFreezer::asset_freezes(ASSET_ID);
+		freezes.sort();
+		assert_eq!(freezes, vec![(WHO, Governance, 10), (WHO, Staking, 5)]);
+	});
+}
@@ -0,0 +1,104 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_assets_freezer`.
+//!
+//! PLACEHOLDER: these weights were not generated by the benchmarks of `pallet_assets_freezer` yet.
+//! Regenerate this file with the `benchmark pallet` command of the node before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_assets_freezer.
+pub trait WeightInfo {
+	fn allow_freeze_reason() -> Weight;
+	fn disallow_freeze_reason() -> Weight;
+	fn force_freeze() -> Weight;
+	fn force_thaw() -> Weight;
+}
+
+/// Weights for pallet_assets_freezer using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `AssetsFreezer::AllowedFreezeReasons` (r:1 w:1)
+	/// Proof: `AssetsFreezer::AllowedFreezeReasons` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	fn allow_freeze_reason() -> Weight {
+		Weight::from_parts(9_904_000, 3483)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetsFreezer::AllowedFreezeReasons` (r:1 w:1)
+	/// Proof: `AssetsFreezer::AllowedFreezeReasons` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	fn disallow_freeze_reason() -> Weight {
+		Weight::from_parts(10_702_000, 3483)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Storage: `AssetsFreezer::AllowedFreezeReasons` (r:1 w:0)
+	fn force_freeze() -> Weight {
+		Weight::from_parts(25_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	fn force_thaw() -> Weight {
+		Weight::from_parts(22_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `AssetsFreezer::AllowedFreezeReasons` (r:1 w:1)
+	/// Proof: `AssetsFreezer::AllowedFreezeReasons` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	fn allow_freeze_reason() -> Weight {
+		Weight::from_parts(9_904_000, 3483)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetsFreezer::AllowedFreezeReasons` (r:1 w:1)
+	/// Proof: `AssetsFreezer::AllowedFreezeReasons` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	fn disallow_freeze_reason() -> Weight {
+		Weight::from_parts(10_702_000, 3483)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Storage: `AssetsFreezer::AllowedFreezeReasons` (r:1 w:0)
+	fn force_freeze() -> Weight {
+		Weight::from_parts(25_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	fn force_thaw() -> Weight {
+		Weight::from_parts(22_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
@@ -204,6 +204,7 @@ mod tests {
 	impl pallet_session::Config for Test {
 		type SessionManager = ();
 		type Keys = UintAuthorityId;
+		type EnforceOwnershipProof = frame_support::traits::ConstBool<false>;
 		type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
 		type SessionHandler = TestSessionHandler;
 		type RuntimeEvent = RuntimeEvent;
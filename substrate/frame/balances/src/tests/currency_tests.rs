@@ -1403,3 +1403,153 @@ fn self_transfer_noop() {
 		}
 	});
 }
+
+#[test]
+fn reserves_to_holds_migration_works() {
+	use frame_support::{
+		migrations::{ReserveToHoldOutcome, ReservesToHolds, ReservesToHoldsConfig},
+		traits::{fungible::InspectHold, OnRuntimeUpgrade},
+	};
+
+	parameter_types! {
+		pub const DepositHoldReason: TestId = TestId::Foo;
+		pub static Migrated: Vec<(u64, u64, ReserveToHoldOutcome<u64>)> = vec![];
+	}
+
+	struct DepositsToHolds;
+	impl ReservesToHoldsConfig for DepositsToHolds {
+		type AccountId = u64;
+		type Currency = Balances;
+		type Fungible = Balances;
+		type HoldReason = DepositHoldReason;
+		type DbWeight = ();
+
+		fn deposits() -> (Vec<(u64, u64)>, Weight) {
+			(vec![(1, 50), (2, 30)], Weight::zero())
+		}
+
+		fn on_deposit_migrated(who: &u64, amount: u64, outcome: ReserveToHoldOutcome<u64>) {
+			Migrated::mutate(|m| m.push((*who, amount, outcome)));
+		}
+	}
+
+	ExtBuilder::default().build_and_execute_with(|| {
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(Balances::reserve(&1, 50));
+		// less than the deposit is reserved for 2.
+		let _ = Balances::deposit_creating(&2, 100);
+		assert_ok!(Balances::reserve(&2, 20));
+
+		ReservesToHolds::<DepositsToHolds>::on_runtime_upgrade();
+
+		assert_eq!(Balances::balance_on_hold(&TestId::Foo, &1), 50);
+		assert_eq!(Balances::reserved_balance(&1), 50);
+		assert_eq!(Balances::free_balance(&1), 50);
+		assert_eq!(Balances::balance_on_hold(&TestId::Foo, &2), 20);
+		assert_eq!(Balances::reserved_balance(&2), 20);
+		assert_eq!(Balances::free_balance(&2), 80);
+		assert_eq!(
+			Migrated::get(),
+			vec![
+				(1, 50, ReserveToHoldOutcome::Held),
+				(2, 30, ReserveToHoldOutcome::PartiallyHeld { held: 20 }),
+			]
+		);
+	});
+}
+
+#[test]
+fn reprice_reserved_deposit_works() {
+	use frame_support::migrations::{reprice_reserved_deposit, RepriceOutcome};
+
+	ExtBuilder::default().build_and_execute_with(|| {
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(Balances::reserve(&1, 20));
+
+		assert_eq!(
+			reprice_reserved_deposit::<_, Balances>(&1, 20, 20),
+			Ok(RepriceOutcome::Unchanged)
+		);
+		assert_eq!(
+			reprice_reserved_deposit::<_, Balances>(&1, 20, 50),
+			Ok(RepriceOutcome::Charged { amount: 30 })
+		);
+		assert_eq!(Balances::reserved_balance(&1), 50);
+		assert_eq!(
+			reprice_reserved_deposit::<_, Balances>(&1, 50, 10),
+			Ok(RepriceOutcome::Refunded { amount: 40 })
+		);
+		assert_eq!(Balances::reserved_balance(&1), 10);
+
+		// the increase can't be reserved.
+		assert_noop!(
+			reprice_reserved_deposit::<_, Balances>(&1, 10, 200),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_eq!(Balances::reserved_balance(&1), 10);
+	});
+}
+
+#[test]
+fn reprice_deposits_migration_works() {
+	use frame_support::{
+		migrations::{reprice_reserved_deposit, RepriceDeposits, RepriceDepositsConfig},
+		traits::OnRuntimeUpgrade,
+	};
+	use sp_runtime::DispatchResult;
+
+	parameter_types! {
+		// The deposits as stored by a pallet, and the current price of each.
+		pub static Deposits: Vec<(u64, u64)> = vec![(1, 20), (2, 50), (3, 20)];
+		pub const NewDeposit: u64 = 30;
+	}
+
+	struct TestDeposits;
+	impl RepriceDepositsConfig for TestDeposits {
+		type AccountId = u64;
+		type DbWeight = ();
+
+		fn accounts() -> (Vec<u64>, Weight) {
+			(Deposits::get().into_iter().map(|(who, _)| who).collect(), Weight::zero())
+		}
+
+		fn reprice(who: &u64) -> DispatchResult {
+			let mut deposits = Deposits::get();
+			let (_, deposit) = deposits.iter_mut().find(|(w, _)| w == who).unwrap();
+			reprice_reserved_deposit::<_, Balances>(who, *deposit, NewDeposit::get())?;
+			*deposit = NewDeposit::get();
+			Deposits::set(deposits);
+			Ok(())
+		}
+
+		fn reprice_weight() -> Weight {
+			Weight::from_parts(1, 0)
+		}
+	}
+
+	ExtBuilder::default().build_and_execute_with(|| {
+		for (who, balance) in [(1, 45), (2, 55), (3, 25)] {
+			let _ = Balances::deposit_creating(&who, balance);
+		}
+		for (who, deposit) in Deposits::get() {
+			assert_ok!(Balances::reserve(&who, deposit));
+		}
+
+		assert_eq!(RepriceDeposits::<TestDeposits>::on_runtime_upgrade(), Weight::from_parts(3, 0));
+
+		// 1 is charged, 2 is refunded.
+		assert_eq!(Balances::reserved_balance(&1), 30);
+		assert_eq!(Balances::free_balance(&1), 15);
+		assert_eq!(Balances::reserved_balance(&2), 30);
+		assert_eq!(Balances::free_balance(&2), 25);
+		// 3 can't pay the increase and keeps its old deposit.
+		assert_eq!(Balances::reserved_balance(&3), 20);
+		assert_eq!(Deposits::get(), vec![(1, 30), (2, 30), (3, 20)]);
+
+		// running it again changes nothing.
+		RepriceDeposits::<TestDeposits>::on_runtime_upgrade();
+		assert_eq!(Balances::reserved_balance(&1), 30);
+		assert_eq!(Balances::reserved_balance(&2), 30);
+		assert_eq!(Deposits::get(), vec![(1, 30), (2, 30), (3, 20)]);
+	});
+}
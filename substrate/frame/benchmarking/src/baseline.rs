@@ -108,6 +108,44 @@ benchmarks! {
 	);
 }
 
+/// Baseline of the secp256r1 (NIST P-256) signature verification, which is only available with
+/// the `secp256r1` feature.
+///
+/// This is a separate set of benchmarks, so that runtimes without the feature don't list it.
+#[cfg(feature = "secp256r1")]
+pub mod secp256r1 {
+	use crate::benchmarks;
+	use sp_core::p256;
+
+	pub use super::Config;
+
+	pub struct Pallet<T: Config>(super::System<T>);
+
+	benchmarks! {
+		// The signature is the RFC 6979 test vector for the message "sample".
+		p256_verification {
+			let i in 0 .. 100;
+
+			let public = p256::Public::from_raw(sp_core::hex2array!(
+				"0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6"
+			));
+			let sig = p256::Signature::from_raw(sp_core::hex2array!(
+				"efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8"
+			));
+		}: {
+			(0..i).for_each(|_| {
+				assert!(sp_io::crypto::p256_verify(&sig, b"sample", &public));
+			});
+		}
+
+		impl_benchmark_test_suite!(
+			Pallet,
+			super::mock::new_test_ext(),
+			super::mock::Test,
+		);
+	}
+}
+
 #[cfg(test)]
 pub mod mock {
 	use frame_support::derive_impl;
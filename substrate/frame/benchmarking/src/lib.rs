@@ -25,6 +25,8 @@ mod analysis;
 mod tests;
 #[cfg(test)]
 mod tests_instance;
+#[cfg(feature = "try-runtime")]
+mod try_runtime;
 mod utils;
 
 pub mod baseline;
@@ -48,6 +50,8 @@ pub mod __private {
 
 #[cfg(feature = "std")]
 pub use analysis::{Analysis, AnalysisChoice, BenchmarkSelector};
+#[cfg(feature = "try-runtime")]
+pub use try_runtime::MeasureWithHostFunctions;
 pub use utils::*;
 pub use v1::*;
 
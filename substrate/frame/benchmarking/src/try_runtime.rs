@@ -0,0 +1,52 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Measurement of the weight used by the block hooks of pallets in try-runtime.
+
+use crate::benchmarking;
+use frame_support::{
+	traits::MeasureWeight,
+	weights::{constants::WEIGHT_REF_TIME_PER_NANOS, Weight},
+};
+
+/// Measures the weight used by some code with the [`benchmarking`] host functions.
+///
+/// The `ref_time` is the time it takes to execute the code. The `proof_size` is the growth of the
+/// storage proof recorded meanwhile, or zero if no proof is recorded. Both are measured on the
+/// host, so that this works in the Wasm runtime, as long as the executor provides the
+/// [`benchmarking::HostFunctions`].
+///
+/// This is meant to be used with `Executive::try_execute_block_measuring_hooks`.
+pub struct MeasureWithHostFunctions;
+
+impl MeasureWeight for MeasureWithHostFunctions {
+	fn measure<R>(f: impl FnOnce() -> R) -> (R, Weight) {
+		let start_time = benchmarking::current_time();
+		let start_proof_size = benchmarking::proof_size();
+		let result = f();
+		let elapsed = benchmarking::current_time().saturating_sub(start_time);
+		let proof_size = match (start_proof_size, benchmarking::proof_size()) {
+			(Some(start), Some(end)) => end.saturating_sub(start),
+			_ => 0,
+		};
+
+		let ref_time = u64::try_from(elapsed)
+			.unwrap_or(u64::MAX)
+			.saturating_mul(WEIGHT_REF_TIME_PER_NANOS);
+		(result, Weight::from_parts(ref_time, proof_size.into()))
+	}
+}
@@ -58,6 +58,7 @@ pub mod pallet {
 		PalletId,
 	};
 	use frame_system::pallet_prelude::*;
+	use sp_arithmetic::Perbill;
 	use sp_runtime::traits::{Convert, ConvertBack};
 	use sp_std::vec::Vec;
 
@@ -77,9 +78,16 @@ pub mod pallet {
 		/// The origin test needed for administrating this pallet.
 		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
-		/// What to do with any revenues collected from the sale of Coretime.
+		/// What to do with any revenues collected from the sale of Coretime which are not split
+		/// off by `RevenueSplit`.
 		type OnRevenue: OnUnbalanced<Credit<Self::AccountId, Self::Currency>>;
 
+		/// The shares of the revenues collected from the sale of Coretime which are split off
+		/// before the rest is passed to `OnRevenue`, along with where they go.
+		///
+		/// Shares are taken in order, so any share beyond the revenue left is cut short.
+		type RevenueSplit: Get<Vec<(RevenueDestination<Self::AccountId>, Perbill)>>;
+
 		/// Relay chain's Coretime API used to interact with and instruct the low-level scheduling
 		/// system.
 		type Coretime: CoretimeInterface;
@@ -164,6 +172,17 @@ pub mod pallet {
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
+		/// Revenue collected from the sale of Coretime has been distributed.
+		RevenueDistributed {
+			/// The total amount of revenue collected.
+			revenue: BalanceOf<T>,
+			/// The amount of the revenue burnt.
+			burnt: BalanceOf<T>,
+			/// The accounts which received a share of the revenue and the amounts they received.
+			shared: Vec<(T::AccountId, BalanceOf<T>)>,
+			/// The amount of the revenue passed to `OnRevenue`.
+			remainder: BalanceOf<T>,
+		},
 		/// A Region of Bulk Coretime has been purchased.
 		Purchased {
 			/// The identity of the purchaser.
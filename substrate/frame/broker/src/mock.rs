@@ -69,6 +69,7 @@ parameter_types! {
 	pub static CoretimeInPool: CoreMaskBitCount = 0;
 	pub static NotifyCoreCount: Vec<u16> = Default::default();
 	pub static NotifyRevenueInfo: Vec<(u32, u64)> = Default::default();
+	pub static RevenueSplit: Vec<(RevenueDestination<u64>, Perbill)> = Default::default();
 }
 
 pub struct TestCoretimeProvider;
@@ -184,6 +185,7 @@ impl crate::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = ItemOf<TestFungibles<(), u64, (), ConstU64<0>, ()>, (), u64>;
 	type OnRevenue = IntoZero;
+	type RevenueSplit = RevenueSplit;
 	type TimeslicePeriod = ConstU32<2>;
 	type MaxLeasedCores = ConstU32<5>;
 	type MaxReservedCores = ConstU32<5>;
@@ -24,6 +24,7 @@ use frame_support::{
 	BoundedVec,
 };
 use frame_system::RawOrigin::Root;
+use sp_arithmetic::Perbill;
 use sp_runtime::traits::Get;
 use CoreAssignment::*;
 use CoretimeTraceItem::*;
@@ -526,6 +527,31 @@ fn purchase_works() {
 	});
 }
 
+#[test]
+fn revenue_split_works() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		RevenueSplit::set(vec![
+			(RevenueDestination::Burn, Perbill::from_percent(10)),
+			(RevenueDestination::Account(5), Perbill::from_percent(20)),
+		]);
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		assert_ok!(Broker::do_purchase(1, u64::max_value()));
+		assert_eq!(balance(1), 900);
+		assert_eq!(balance(5), 20);
+		assert_eq!(revenue(), 70);
+		System::assert_has_event(
+			Event::RevenueDistributed {
+				revenue: 100,
+				burnt: 10,
+				shared: vec![(5, 20)],
+				remainder: 70,
+			}
+			.into(),
+		);
+	});
+}
+
 #[test]
 fn partition_works() {
 	TestExt::new().endow(1, 1000).execute_with(|| {
@@ -19,17 +19,18 @@ use super::*;
 use frame_support::{
 	pallet_prelude::{DispatchResult, *},
 	traits::{
-		fungible::Balanced,
+		fungible::{Balanced, Credit},
 		tokens::{Fortitude::Polite, Precision::Exact, Preservation::Expendable},
-		OnUnbalanced,
+		Imbalance, OnUnbalanced,
 	},
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_arithmetic::{
-	traits::{SaturatedConversion, Saturating},
+	traits::{SaturatedConversion, Saturating, Zero},
 	FixedPointNumber, FixedU64,
 };
 use sp_runtime::traits::AccountIdConversion;
+use sp_std::vec::Vec;
 
 impl<T: Config> Pallet<T> {
 	pub fn current_timeslice() -> Timeslice {
@@ -68,10 +69,40 @@ impl<T: Config> Pallet<T> {
 
 	pub(crate) fn charge(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
 		let credit = T::Currency::withdraw(&who, amount, Exact, Expendable, Polite)?;
-		T::OnRevenue::on_unbalanced(credit);
+		Self::distribute_revenue(credit);
 		Ok(())
 	}
 
+	/// Split off the shares of `RevenueSplit` from the revenue `credit` and pass the rest to
+	/// `OnRevenue`.
+	pub(crate) fn distribute_revenue(mut credit: Credit<T::AccountId, T::Currency>) {
+		let revenue = credit.peek();
+		let mut burnt = BalanceOf::<T>::zero();
+		let mut shared = Vec::new();
+		for (destination, share) in T::RevenueSplit::get() {
+			let (part, rest) = credit.split(share * revenue);
+			credit = rest;
+			let amount = part.peek();
+			if amount.is_zero() {
+				continue
+			}
+			match destination {
+				RevenueDestination::Account(who) => match T::Currency::resolve(&who, part) {
+					Ok(()) => shared.push((who, amount)),
+					// The share cannot be deposited, e.g. as it is below the existential deposit.
+					Err(part) => credit.subsume(part),
+				},
+				RevenueDestination::Burn => {
+					drop(part);
+					burnt.saturating_accrue(amount);
+				},
+			}
+		}
+		let remainder = credit.peek();
+		T::OnRevenue::on_unbalanced(credit);
+		Self::deposit_event(Event::RevenueDistributed { revenue, burnt, shared, remainder });
+	}
+
 	pub(crate) fn issue(
 		core: CoreIndex,
 		begin: Timeslice,
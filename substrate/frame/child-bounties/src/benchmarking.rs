@@ -277,6 +277,58 @@ benchmarks! {
 			"Beneficiary didn't get paid.");
 	}
 
+	claim_child_bounties {
+		let n in 1 .. T::MaxActiveChildBountyCount::get();
+		setup_pot_account::<T>();
+		let bounty_setup = activate_bounty::<T>(0, T::MaximumReasonLength::get())?;
+		let child_curator_lookup = T::Lookup::unlookup(bounty_setup.child_curator.clone());
+		let child_bounty_value = (bounty_setup.value - bounty_setup.fee) / n.into();
+		let child_bounty_fee = child_bounty_value / 2u32.into();
+		let _ = T::Currency::make_free_balance_be(
+			&bounty_setup.child_curator,
+			T::Currency::minimum_balance().saturating_mul(1_000_000_000u32.into()),
+		);
+
+		let mut child_bounty_ids = Vec::new();
+		for i in 0 .. n {
+			ChildBounties::<T>::add_child_bounty(
+				RawOrigin::Signed(bounty_setup.curator.clone()).into(),
+				bounty_setup.bounty_id,
+				child_bounty_value,
+				bounty_setup.reason.clone(),
+			)?;
+			let child_bounty_id = ChildBountyCount::<T>::get() - 1;
+
+			ChildBounties::<T>::propose_curator(
+				RawOrigin::Signed(bounty_setup.curator.clone()).into(),
+				bounty_setup.bounty_id,
+				child_bounty_id,
+				child_curator_lookup.clone(),
+				child_bounty_fee,
+			)?;
+			ChildBounties::<T>::accept_curator(
+				RawOrigin::Signed(bounty_setup.child_curator.clone()).into(),
+				bounty_setup.bounty_id,
+				child_bounty_id,
+			)?;
+
+			let beneficiary_account: T::AccountId = account("beneficiary", i, SEED);
+			ChildBounties::<T>::award_child_bounty(
+				RawOrigin::Signed(bounty_setup.child_curator.clone()).into(),
+				bounty_setup.bounty_id,
+				child_bounty_id,
+				T::Lookup::unlookup(beneficiary_account),
+			)?;
+			child_bounty_ids.push(child_bounty_id);
+		}
+
+		frame_system::Pallet::<T>::set_block_number(T::BountyDepositPayoutDelay::get());
+	}: _(RawOrigin::Signed(bounty_setup.curator), bounty_setup.bounty_id, child_bounty_ids)
+	verify {
+		ensure!(ParentChildBounties::<T>::get(bounty_setup.bounty_id).is_zero(),
+			"Child-bounties weren't claimed.");
+	}
+
 	// Best case scenario.
 	close_child_bounty_added {
 		setup_pot_account::<T>();
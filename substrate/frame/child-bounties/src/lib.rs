@@ -40,6 +40,7 @@
 //!   curator deposit.
 //! - `award_child_bounty` - Close and pay out the specified amount for the completed work.
 //! - `claim_child_bounty` - Claim a specific child bounty amount from the payout address.
+//! - `claim_child_bounties` - Claim several awarded child bounties of a parent bounty at once.
 //! - `unassign_curator` - Unassign an accepted curator from a specific child bounty.
 //! - `close_child_bounty` - Cancel the child bounty for a specific treasury amount and close the
 //!   bounty.
@@ -66,7 +67,7 @@ use frame_support::traits::{
 
 use sp_runtime::{
 	traits::{AccountIdConversion, BadOrigin, CheckedSub, Saturating, StaticLookup, Zero},
-	DispatchResult, RuntimeDebug,
+	DispatchResult, Permill, RuntimeDebug,
 };
 
 use frame_support::pallet_prelude::*;
@@ -144,6 +145,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type ChildBountyValueMinimum: Get<BalanceOf<Self>>;
 
+		/// The share of a child-bounty curator fee paid to the parent bounty curator when the
+		/// child-bounty is claimed.
+		///
+		/// No share is taken when the parent bounty curator is the child-bounty curator, or when
+		/// the parent bounty has no active curator.
+		#[pallet::constant]
+		type ParentCuratorFeeShare: Get<Permill>;
+
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -632,7 +641,8 @@ pub mod pallet {
 		/// bounty to be in active state.
 		///
 		/// The Beneficiary is paid out with agreed bounty value. Curator fee is
-		/// paid & curator deposit is unreserved.
+		/// paid & curator deposit is unreserved. The parent bounty curator gets
+		/// [`Config::ParentCuratorFeeShare`] of the curator fee.
 		///
 		/// Child-bounty must be in "PendingPayout" state, for processing the
 		/// call. And instance of child-bounty is removed from the state on
@@ -649,83 +659,51 @@ pub mod pallet {
 		) -> DispatchResult {
 			let _ = ensure_signed(origin)?;
 
-			// Ensure child-bounty is in expected state.
-			ChildBounties::<T>::try_mutate_exists(
+			let parent_curator = Self::parent_curator(parent_bounty_id);
+			Self::impl_claim_child_bounty(
 				parent_bounty_id,
 				child_bounty_id,
-				|maybe_child_bounty| -> DispatchResult {
-					let child_bounty =
-						maybe_child_bounty.as_mut().ok_or(BountiesError::<T>::InvalidIndex)?;
-
-					if let ChildBountyStatus::PendingPayout {
-						ref curator,
-						ref beneficiary,
-						ref unlock_at,
-					} = child_bounty.status
-					{
-						// Ensure block number is elapsed for processing the
-						// claim.
-						ensure!(
-							frame_system::Pallet::<T>::block_number() >= *unlock_at,
-							BountiesError::<T>::Premature,
-						);
-
-						// Make curator fee payment.
-						let child_bounty_account = Self::child_bounty_account_id(child_bounty_id);
-						let balance = T::Currency::free_balance(&child_bounty_account);
-						let curator_fee = child_bounty.fee.min(balance);
-						let payout = balance.saturating_sub(curator_fee);
-
-						// Unreserve the curator deposit. Should not fail
-						// because the deposit is always reserved when curator is
-						// assigned.
-						let _ = T::Currency::unreserve(curator, child_bounty.curator_deposit);
-
-						// Make payout to child-bounty curator.
-						// Should not fail because curator fee is always less than bounty value.
-						let fee_transfer_result = T::Currency::transfer(
-							&child_bounty_account,
-							curator,
-							curator_fee,
-							AllowDeath,
-						);
-						debug_assert!(fee_transfer_result.is_ok());
-
-						// Make payout to beneficiary.
-						// Should not fail.
-						let payout_transfer_result = T::Currency::transfer(
-							&child_bounty_account,
-							beneficiary,
-							payout,
-							AllowDeath,
-						);
-						debug_assert!(payout_transfer_result.is_ok());
-
-						// Trigger the Claimed event.
-						Self::deposit_event(Event::<T>::Claimed {
-							index: parent_bounty_id,
-							child_index: child_bounty_id,
-							payout,
-							beneficiary: beneficiary.clone(),
-						});
-
-						// Update the active child-bounty tracking count.
-						<ParentChildBounties<T>>::mutate(parent_bounty_id, |count| {
-							count.saturating_dec()
-						});
+				parent_curator.as_ref(),
+			)
+		}
 
-						// Remove the child-bounty description.
-						<ChildBountyDescriptions<T>>::remove(child_bounty_id);
+		/// Claim the payouts from several awarded child-bounties of a parent
+		/// bounty after their payout delay.
+		///
+		/// The dispatch origin for this call may be any signed origin.
+		///
+		/// Each child-bounty is paid out as in [`Pallet::claim_child_bounty`].
+		/// The call fails, and nothing is paid out, if any of the child-bounties
+		/// can't be claimed.
+		///
+		/// - `parent_bounty_id`: Index of parent bounty.
+		/// - `child_bounty_ids`: Indices of the child bounties, at most
+		///   [`Config::MaxActiveChildBountyCount`].
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_child_bounties(
+			child_bounty_ids.len() as u32
+		))]
+		pub fn claim_child_bounties(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			child_bounty_ids: Vec<BountyIndex>,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
 
-						// Remove the child-bounty instance from the state.
-						*maybe_child_bounty = None;
+			ensure!(
+				child_bounty_ids.len() as u32 <= T::MaxActiveChildBountyCount::get(),
+				Error::<T>::TooManyChildBounties
+			);
 
-						Ok(())
-					} else {
-						Err(BountiesError::<T>::UnexpectedStatus.into())
-					}
-				},
-			)
+			let parent_curator = Self::parent_curator(parent_bounty_id);
+			for child_bounty_id in child_bounty_ids {
+				Self::impl_claim_child_bounty(
+					parent_bounty_id,
+					child_bounty_id,
+					parent_curator.as_ref(),
+				)?;
+			}
+			Ok(())
 		}
 
 		/// Cancel a proposed or active child-bounty. Child-bounty account funds
@@ -826,6 +804,112 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The curator of the parent bounty, if it is active.
+	fn parent_curator(parent_bounty_id: BountyIndex) -> Option<T::AccountId> {
+		Self::ensure_bounty_active(parent_bounty_id).ok().map(|(curator, _)| curator)
+	}
+
+	fn impl_claim_child_bounty(
+		parent_bounty_id: BountyIndex,
+		child_bounty_id: BountyIndex,
+		parent_curator: Option<&T::AccountId>,
+	) -> DispatchResult {
+		ChildBounties::<T>::try_mutate_exists(
+			parent_bounty_id,
+			child_bounty_id,
+			|maybe_child_bounty| -> DispatchResult {
+				let child_bounty =
+					maybe_child_bounty.as_mut().ok_or(BountiesError::<T>::InvalidIndex)?;
+
+				if let ChildBountyStatus::PendingPayout {
+					ref curator,
+					ref beneficiary,
+					ref unlock_at,
+				} = child_bounty.status
+				{
+					// Ensure block number is elapsed for processing the
+					// claim.
+					ensure!(
+						frame_system::Pallet::<T>::block_number() >= *unlock_at,
+						BountiesError::<T>::Premature,
+					);
+
+					// Make curator fee payment.
+					let child_bounty_account = Self::child_bounty_account_id(child_bounty_id);
+					let balance = T::Currency::free_balance(&child_bounty_account);
+					let curator_fee = child_bounty.fee.min(balance);
+					let payout = balance.saturating_sub(curator_fee);
+
+					// Unreserve the curator deposit. Should not fail
+					// because the deposit is always reserved when curator is
+					// assigned.
+					let _ = T::Currency::unreserve(curator, child_bounty.curator_deposit);
+
+					// Share the curator fee with the parent bounty curator, unless they
+					// curate the child-bounty themselves.
+					let parent_curator_fee = match parent_curator {
+						Some(parent_curator) if parent_curator != curator => {
+							let parent_curator_fee = T::ParentCuratorFeeShare::get() * curator_fee;
+							// The child-bounty curator gets the whole fee if the share can't be
+							// paid, e.g. because it is below the existential deposit.
+							T::Currency::transfer(
+								&child_bounty_account,
+								parent_curator,
+								parent_curator_fee,
+								AllowDeath,
+							)
+							.map_or(Zero::zero(), |_| parent_curator_fee)
+						},
+						_ => Zero::zero(),
+					};
+
+					// Make payout to child-bounty curator.
+					// Should not fail because curator fee is always less than bounty value.
+					let fee_transfer_result = T::Currency::transfer(
+						&child_bounty_account,
+						curator,
+						curator_fee.saturating_sub(parent_curator_fee),
+						AllowDeath,
+					);
+					debug_assert!(fee_transfer_result.is_ok());
+
+					// Make payout to beneficiary.
+					// Should not fail.
+					let payout_transfer_result = T::Currency::transfer(
+						&child_bounty_account,
+						beneficiary,
+						payout,
+						AllowDeath,
+					);
+					debug_assert!(payout_transfer_result.is_ok());
+
+					// Trigger the Claimed event.
+					Self::deposit_event(Event::<T>::Claimed {
+						index: parent_bounty_id,
+						child_index: child_bounty_id,
+						payout,
+						beneficiary: beneficiary.clone(),
+					});
+
+					// Update the active child-bounty tracking count.
+					<ParentChildBounties<T>>::mutate(parent_bounty_id, |count| {
+						count.saturating_dec()
+					});
+
+					// Remove the child-bounty description.
+					<ChildBountyDescriptions<T>>::remove(child_bounty_id);
+
+					// Remove the child-bounty instance from the state.
+					*maybe_child_bounty = None;
+
+					Ok(())
+				} else {
+					Err(BountiesError::<T>::UnexpectedStatus.into())
+				}
+			},
+		)
+	}
+
 	fn impl_close_child_bounty(
 		parent_bounty_id: BountyIndex,
 		child_bounty_id: BountyIndex,
@@ -136,6 +136,7 @@ impl pallet_treasury::Config for Test {
 	type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
 	type BalanceConverter = UnityAssetBalanceConversion;
 	type PayoutPeriod = ConstU64<10>;
+	type OnSpendExpired = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -144,7 +145,7 @@ parameter_types! {
 	pub const CuratorDepositMultiplier: Permill = Permill::from_percent(50);
 	pub const CuratorDepositMax: Balance = 1_000;
 	pub const CuratorDepositMin: Balance = 3;
-
+	pub static ParentCuratorFeeShare: Permill = Permill::zero();
 }
 impl pallet_bounties::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
@@ -164,6 +165,7 @@ impl pallet_child_bounties::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxActiveChildBountyCount = ConstU32<2>;
 	type ChildBountyValueMinimum = ConstU64<1>;
+	type ParentCuratorFeeShare = ParentCuratorFeeShare;
 	type WeightInfo = ();
 }
 
@@ -504,6 +506,157 @@ fn award_claim_child_bounty() {
 	});
 }
 
+#[test]
+fn claim_child_bounty_shares_curator_fee() {
+	new_test_ext().execute_with(|| {
+		ParentCuratorFeeShare::set(Permill::from_percent(25));
+
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// Child-bounty curated by another account.
+		let fee = 8;
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, fee));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 0, 7));
+
+		// Child-bounty curated by the parent curator.
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			10,
+			b"12345-p2".to_vec()
+		));
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 1, 4, fee));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(4), 0, 1));
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(4), 0, 1, 9));
+
+		System::set_block_number(9);
+		let parent_curator_balance = Balances::free_balance(4);
+
+		// A quarter of the fee goes to the parent curator.
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 0, 0));
+		assert_eq!(Balances::free_balance(4), parent_curator_balance + 2);
+		assert_eq!(Balances::free_balance(8), 101 + fee - 2);
+		assert_eq!(Balances::reserved_balance(8), 0);
+		assert_eq!(Balances::free_balance(7), 10 - fee);
+
+		// The parent curator gets the whole fee of their own child-bounty.
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(9), 0, 1));
+		assert_eq!(Balances::free_balance(4), parent_curator_balance + 2 + fee);
+		assert_eq!(Balances::free_balance(9), 10 - fee);
+
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0)), 0);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(1)), 0);
+		assert_eq!(ChildBounties::parent_child_bounties(0), 0);
+	});
+}
+
+#[test]
+fn claim_child_bounties_works() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// Two child-bounties, awarded one block apart.
+		let fee = 4;
+		for (child_bounty_id, beneficiary) in [(0, 7), (1, 9)] {
+			assert_ok!(ChildBounties::add_child_bounty(
+				RuntimeOrigin::signed(4),
+				0,
+				10,
+				b"12345-p1".to_vec()
+			));
+			assert_ok!(ChildBounties::propose_curator(
+				RuntimeOrigin::signed(4),
+				0,
+				child_bounty_id,
+				8,
+				fee
+			));
+			assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, child_bounty_id));
+			assert_ok!(ChildBounties::award_child_bounty(
+				RuntimeOrigin::signed(8),
+				0,
+				child_bounty_id,
+				beneficiary
+			));
+			System::set_block_number(System::block_number() + 1);
+		}
+
+		// At most `MaxActiveChildBountyCount` child-bounties can be claimed at once.
+		assert_noop!(
+			ChildBounties::claim_child_bounties(RuntimeOrigin::signed(7), 0, vec![0, 1, 2]),
+			Error::<Test>::TooManyChildBounties
+		);
+
+		// Nothing is paid out if one of the child-bounties can't be claimed yet.
+		System::set_block_number(5);
+		assert_noop!(
+			ChildBounties::claim_child_bounties(RuntimeOrigin::signed(7), 0, vec![0, 1]),
+			BountiesError::Premature
+		);
+
+		// Nor if a child-bounty is claimed twice.
+		System::set_block_number(6);
+		assert_noop!(
+			ChildBounties::claim_child_bounties(RuntimeOrigin::signed(7), 0, vec![0, 0]),
+			BountiesError::InvalidIndex
+		);
+
+		assert_ok!(ChildBounties::claim_child_bounties(RuntimeOrigin::signed(7), 0, vec![0, 1]));
+
+		// Both child-bounties are paid out.
+		assert_eq!(Balances::free_balance(8), 101 + 2 * fee);
+		assert_eq!(Balances::reserved_balance(8), 0);
+		assert_eq!(Balances::free_balance(7), 10 - fee);
+		assert_eq!(Balances::free_balance(9), 10 - fee);
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::Claimed {
+				index: 0,
+				child_index: 1,
+				payout: 10 - fee,
+				beneficiary: 9
+			}
+		);
+
+		assert!(ChildBounties::child_bounties(0, 0).is_none());
+		assert!(ChildBounties::child_bounties(0, 1).is_none());
+		assert_eq!(ChildBounties::parent_child_bounties(0), 0);
+	});
+}
+
 #[test]
 fn close_child_bounty_added() {
 	new_test_ext().execute_with(|| {
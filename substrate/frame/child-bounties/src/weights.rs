@@ -58,6 +58,7 @@ pub trait WeightInfo {
 	fn unassign_curator() -> Weight;
 	fn award_child_bounty() -> Weight;
 	fn claim_child_bounty() -> Weight;
+	fn claim_child_bounties(n: u32, ) -> Weight;
 	fn close_child_bounty_added() -> Weight;
 	fn close_child_bounty_active() -> Weight;
 }
@@ -145,22 +146,42 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Bounties Bounties (r:1 w:0)
+	/// Proof: Bounties Bounties (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
 	/// Storage: ChildBounties ChildBounties (r:1 w:1)
 	/// Proof: ChildBounties ChildBounties (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
-	/// Storage: System Account (r:3 w:3)
+	/// Storage: System Account (r:4 w:4)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: ChildBounties ParentChildBounties (r:1 w:1)
 	/// Proof: ChildBounties ParentChildBounties (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
 	/// Storage: ChildBounties ChildBountyDescriptions (r:0 w:1)
 	/// Proof: ChildBounties ChildBountyDescriptions (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
 	fn claim_child_bounty() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `682`
-		//  Estimated: `8799`
-		// Minimum execution time: 118_272_000 picoseconds.
-		Weight::from_parts(121_646_000, 8799)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(151_208_000, 11402)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: Bounties Bounties (r:1 w:0)
+	/// Proof: Bounties Bounties (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ChildBounties (r:5 w:5)
+	/// Proof: ChildBounties ChildBounties (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: System Account (r:16 w:16)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ParentChildBounties (r:1 w:1)
+	/// Proof: ChildBounties ParentChildBounties (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ChildBountyDescriptions (r:0 w:5)
+	/// Proof: ChildBounties ChildBountyDescriptions (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 5]`.
+	fn claim_child_bounties(n: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(24_913_000, 3642)
+			.saturating_add(Weight::from_parts(128_742_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7829).saturating_mul(n.into()))
 	}
 	/// Storage: Bounties Bounties (r:1 w:0)
 	/// Proof: Bounties Bounties (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
@@ -288,22 +309,42 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Bounties Bounties (r:1 w:0)
+	/// Proof: Bounties Bounties (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
 	/// Storage: ChildBounties ChildBounties (r:1 w:1)
 	/// Proof: ChildBounties ChildBounties (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
-	/// Storage: System Account (r:3 w:3)
+	/// Storage: System Account (r:4 w:4)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: ChildBounties ParentChildBounties (r:1 w:1)
 	/// Proof: ChildBounties ParentChildBounties (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
 	/// Storage: ChildBounties ChildBountyDescriptions (r:0 w:1)
 	/// Proof: ChildBounties ChildBountyDescriptions (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
 	fn claim_child_bounty() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `682`
-		//  Estimated: `8799`
-		// Minimum execution time: 118_272_000 picoseconds.
-		Weight::from_parts(121_646_000, 8799)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(151_208_000, 11402)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Bounties Bounties (r:1 w:0)
+	/// Proof: Bounties Bounties (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ChildBounties (r:5 w:5)
+	/// Proof: ChildBounties ChildBounties (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: System Account (r:16 w:16)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ParentChildBounties (r:1 w:1)
+	/// Proof: ChildBounties ParentChildBounties (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ChildBountyDescriptions (r:0 w:5)
+	/// Proof: ChildBounties ChildBountyDescriptions (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 5]`.
+	fn claim_child_bounties(n: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
+		Weight::from_parts(24_913_000, 3642)
+			.saturating_add(Weight::from_parts(128_742_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7829).saturating_mul(n.into()))
 	}
 	/// Storage: Bounties Bounties (r:1 w:0)
 	/// Proof: Bounties Bounties (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
@@ -163,6 +163,22 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The locks left behind by votes on finished polls and by ended delegations of an account in a
+	/// voting class, as `(unlock_at, amount)` ordered by `unlock_at`.
+	///
+	/// Each of them expires on its own, so funds become unlocked as soon as all the polls and
+	/// delegations they backed are over. If there are more than `MaxVotes` of them, new locks are
+	/// merged into the one expiring next.
+	#[pallet::storage]
+	pub type PriorLocksFor<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		ClassOf<T, I>,
+		BoundedVec<(BlockNumberFor<T>, BalanceOf<T, I>), T::MaxVotes>,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -170,6 +186,8 @@ pub mod pallet {
 		Delegated(T::AccountId, T::AccountId),
 		/// An \[account\] has cancelled a previous delegation operation.
 		Undelegated(T::AccountId),
+		/// An account has undelegated part of its delegated balance. \[who, amount\]
+		DelegationReduced(T::AccountId, BalanceOf<T, I>),
 	}
 
 	#[pallet::error]
@@ -199,6 +217,8 @@ pub mod pallet {
 		ClassNeeded,
 		/// The class ID supplied is invalid.
 		BadClass,
+		/// The amount to undelegate must be non-zero and less than the delegated balance.
+		BadUndelegationAmount,
 	}
 
 	#[pallet::call]
@@ -383,6 +403,35 @@ pub mod pallet {
 			Self::try_remove_vote(&target, index, Some(class), scope)?;
 			Ok(())
 		}
+
+		/// Undelegate part of the voting power of the sending account for a particular class of
+		/// polls.
+		///
+		/// The delegation continues with the remaining balance. The undelegated `amount` stays
+		/// locked for the lock period of the delegation's conviction, as after `undelegate`.
+		///
+		/// The dispatch origin of this call must be _Signed_ and the signing account must be
+		/// currently delegating.
+		///
+		/// - `class`: The class of polls to reduce the delegation for.
+		/// - `amount`: The amount of the delegated balance to undelegate. This must be less than
+		///   the delegated balance; use `undelegate` to undelegate all of it.
+		///
+		/// Emits `DelegationReduced`.
+		///
+		/// Weight: `O(R)` where R is the number of polls the voter delegating to has
+		///   voted on. Weight is initially charged as if maximum votes, but is refunded later.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::reduce_delegation(T::MaxVotes::get()))]
+		pub fn reduce_delegation(
+			origin: OriginFor<T>,
+			class: ClassOf<T, I>,
+			amount: BalanceOf<T, I>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let votes = Self::try_reduce_delegation(who, class, amount)?;
+			Ok(Some(T::WeightInfo::reduce_delegation(votes)).into())
+		}
 	}
 }
 
@@ -447,8 +496,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let class = class_hint
 			.or_else(|| Some(T::Polls::as_ongoing(poll_index)?.1))
 			.ok_or(Error::<T, I>::ClassNeeded)?;
-		VotingFor::<T, I>::try_mutate(who, class, |voting| {
-			if let Voting::Casting(Casting { ref mut votes, delegations, ref mut prior }) = voting {
+		VotingFor::<T, I>::try_mutate(who, &class, |voting| {
+			if let Voting::Casting(Casting { ref mut votes, delegations, .. }) = voting {
 				let i = votes
 					.binary_search_by_key(&poll_index, |i| i.0)
 					.map_err(|_| Error::<T, I>::NotVoter)?;
@@ -475,7 +524,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 									matches!(scope, UnvoteScope::Any),
 									Error::<T, I>::NoPermissionYet
 								);
-								prior.accumulate(unlock_at, balance)
+								Self::add_prior_lock(who, &class, unlock_at, balance)
 							}
 						}
 						Ok(())
@@ -602,7 +651,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						target,
 						conviction,
 						delegations,
-						mut prior,
+						prior,
 					}) => {
 						// remove any delegation votes to our current target.
 						let votes = Self::reduce_upstream_delegation(
@@ -610,14 +659,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 							&class,
 							conviction.votes(balance),
 						);
-						let now = frame_system::Pallet::<T>::block_number();
-						let lock_periods = conviction.lock_periods().into();
-						prior.accumulate(
-							now.saturating_add(
-								T::VoteLockingPeriod::get().saturating_mul(lock_periods),
-							),
-							balance,
-						);
+						Self::add_prior_lock(&who, &class, Self::unlock_at(conviction), balance);
 						voting.set_common(delegations, prior);
 
 						Ok(votes)
@@ -629,6 +671,101 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(votes)
 	}
 
+	/// Attempt to undelegate `amount` of the current delegation, keeping the rest delegated.
+	///
+	/// Return the number of votes of upstream.
+	fn try_reduce_delegation(
+		who: T::AccountId,
+		class: ClassOf<T, I>,
+		amount: BalanceOf<T, I>,
+	) -> Result<u32, DispatchError> {
+		let votes =
+			VotingFor::<T, I>::try_mutate(&who, &class, |voting| -> Result<u32, DispatchError> {
+				match voting {
+					Voting::Delegating(Delegating { balance, target, conviction, .. }) => {
+						ensure!(
+							!amount.is_zero() && amount < *balance,
+							Error::<T, I>::BadUndelegationAmount
+						);
+						let remaining = balance.saturating_sub(amount);
+						// remove the delegation votes of `amount` from our current target.
+						let votes = Self::reduce_upstream_delegation(
+							target,
+							&class,
+							conviction.votes(*balance).saturating_sub(conviction.votes(remaining)),
+						);
+						// The whole balance stays locked until the lock period of the undelegated
+						// amount is over, since the funds still delegated may be spent otherwise.
+						Self::add_prior_lock(&who, &class, Self::unlock_at(*conviction), *balance);
+						*balance = remaining;
+
+						Ok(votes)
+					},
+					Voting::Casting(_) => Err(Error::<T, I>::NotDelegating.into()),
+				}
+			})?;
+		Self::deposit_event(Event::<T, I>::DelegationReduced(who, amount));
+		Ok(votes)
+	}
+
+	/// The block at which funds undelegated now with `conviction` become unlocked.
+	fn unlock_at(conviction: Conviction) -> BlockNumberFor<T> {
+		let lock_periods = conviction.lock_periods().into();
+		frame_system::Pallet::<T>::block_number()
+			.saturating_add(T::VoteLockingPeriod::get().saturating_mul(lock_periods))
+	}
+
+	/// Lock `amount` of the balance of `who` for `class` until the block `until`.
+	///
+	/// The lock expires independently of the other prior locks of `who` for `class`, unless
+	/// there are too many of them already. In that case, it is merged into the lock expiring next
+	/// after it, which only ever extends locks.
+	fn add_prior_lock(
+		who: &T::AccountId,
+		class: &ClassOf<T, I>,
+		until: BlockNumberFor<T>,
+		amount: BalanceOf<T, I>,
+	) {
+		let now = frame_system::Pallet::<T>::block_number();
+		PriorLocksFor::<T, I>::mutate(who, class, |maybe_locks| {
+			let locks = maybe_locks.get_or_insert_with(Default::default);
+			locks.retain(|(unlock_at, _)| *unlock_at > now);
+			match locks.binary_search_by_key(&until, |(unlock_at, _)| *unlock_at) {
+				Ok(i) => locks[i].1 = locks[i].1.max(amount),
+				Err(i) =>
+					if locks.try_insert(i, (until, amount)).is_err() {
+						match locks.get_mut(i) {
+							Some(next) => next.1 = next.1.max(amount),
+							None => {
+								// The new lock expires after all the others: extend the last one.
+								if let Some(last) = locks.last_mut() {
+									*last = (until, last.1.max(amount));
+								}
+							},
+						}
+					},
+			}
+		});
+	}
+
+	/// Remove the expired prior locks of `who` for `class`, and return the amount locked by the
+	/// remaining ones.
+	fn rejig_prior_locks(
+		who: &T::AccountId,
+		class: &ClassOf<T, I>,
+		now: BlockNumberFor<T>,
+	) -> BalanceOf<T, I> {
+		PriorLocksFor::<T, I>::mutate_exists(who, class, |maybe_locks| {
+			let Some(locks) = maybe_locks else { return Zero::zero() };
+			locks.retain(|(unlock_at, _)| *unlock_at > now);
+			let locked = locks.iter().map(|(_, amount)| *amount).max().unwrap_or_else(Zero::zero);
+			if locks.is_empty() {
+				*maybe_locks = None;
+			}
+			locked
+		})
+	}
+
 	fn extend_lock(who: &T::AccountId, class: &ClassOf<T, I>, amount: BalanceOf<T, I>) {
 		ClassLocksFor::<T, I>::mutate(who, |locks| {
 			match locks.iter().position(|x| &x.0 == class) {
@@ -655,10 +792,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Rejig the lock on an account. It will never get more stringent (since that would indicate
 	/// a security hole) but may be reduced from what they are currently.
 	fn update_lock(class: &ClassOf<T, I>, who: &T::AccountId) {
+		let now = frame_system::Pallet::<T>::block_number();
 		let class_lock_needed = VotingFor::<T, I>::mutate(who, class, |voting| {
-			voting.rejig(frame_system::Pallet::<T>::block_number());
+			voting.rejig(now);
 			voting.locked_balance()
-		});
+		})
+		.max(Self::rejig_prior_locks(who, class, now));
 		let lock_needed = ClassLocksFor::<T, I>::mutate(who, |locks| {
 			locks.retain(|x| &x.0 != class);
 			if !class_lock_needed.is_zero() {
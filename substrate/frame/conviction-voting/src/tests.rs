@@ -700,6 +700,110 @@ fn lock_amalgamation_valid_with_move_roundtrip_to_casting() {
 	});
 }
 
+#[test]
+fn prior_locks_expire_independently() {
+	new_test_ext().execute_with(|| {
+		Polls::set(
+			vec![(0, Ongoing(Tally::new(0), 0)), (1, Ongoing(Tally::new(0), 0))]
+				.into_iter()
+				.collect(),
+		);
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 0, aye(10, 1)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 1, aye(5, 3)));
+		Polls::set(vec![(0, Completed(1, true)), (1, Completed(1, true))].into_iter().collect());
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(1), Some(0), 0));
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(1), Some(0), 1));
+		assert_eq!(PriorLocksFor::<Test>::get(1, 0).unwrap().into_inner(), vec![(4, 10), (13, 5)]);
+
+		run_to(3);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 0);
+
+		// The lock of the first poll is over, only the one of the second is left.
+		run_to(4);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 5);
+
+		run_to(13);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 10);
+		assert!(PriorLocksFor::<Test>::get(1, 0).is_none());
+	});
+}
+
+#[test]
+fn prior_locks_are_merged_when_too_many() {
+	new_test_ext().execute_with(|| {
+		for (conviction, balance) in [
+			(Conviction::Locked1x, 2),
+			(Conviction::Locked2x, 4),
+			(Conviction::Locked3x, 6),
+			(Conviction::Locked4x, 3),
+		] {
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, conviction, balance));
+			assert_ok!(Voting::undelegate(RuntimeOrigin::signed(1), 0));
+		}
+		// `MaxVotes` is 3, so the last lock extends the one expiring latest.
+		assert_eq!(
+			PriorLocksFor::<Test>::get(1, 0).unwrap().into_inner(),
+			vec![(4, 2), (7, 4), (25, 6)]
+		);
+
+		run_to(13);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 4);
+
+		run_to(25);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 10);
+	});
+}
+
+#[test]
+fn reduce_delegation_works() {
+	new_test_ext().execute_with(|| {
+		Polls::set(vec![(0, Ongoing(Tally::new(0), 0))].into_iter().collect());
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 10));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 0, aye(10, 0)));
+		assert_eq!(tally(0), Tally::from_parts(11, 0, 20));
+
+		assert_noop!(
+			Voting::reduce_delegation(RuntimeOrigin::signed(1), 0, 0),
+			Error::<Test>::BadUndelegationAmount
+		);
+		assert_noop!(
+			Voting::reduce_delegation(RuntimeOrigin::signed(1), 0, 10),
+			Error::<Test>::BadUndelegationAmount
+		);
+		assert_noop!(
+			Voting::reduce_delegation(RuntimeOrigin::signed(3), 0, 1),
+			Error::<Test>::NotDelegating
+		);
+
+		// The delegation continues with the remaining balance.
+		assert_ok!(Voting::reduce_delegation(RuntimeOrigin::signed(1), 0, 4));
+		System::assert_last_event(Event::DelegationReduced(1, 4).into());
+		assert_eq!(tally(0), Tally::from_parts(7, 0, 16));
+		assert!(matches!(
+			VotingFor::<Test>::get(1, 0),
+			Voting::Delegating(Delegating { balance: 6, target: 2, .. })
+		));
+
+		// The undelegated balance stays locked for the conviction's lock period.
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 0);
+		run_to(4);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 4);
+
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(1), 0));
+		assert_eq!(tally(0), Tally::from_parts(1, 0, 10));
+		run_to(7);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 10);
+	});
+}
+
 #[test]
 fn lock_aggregation_over_different_classes_with_delegation_works() {
 	new_test_ext().execute_with(|| {
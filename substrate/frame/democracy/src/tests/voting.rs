@@ -92,7 +92,7 @@ fn single_proposal_should_work() {
 		fast_forward_to(4);
 
 		assert_noop!(Democracy::referendum_status(0), Error::<Test>::ReferendumInvalid);
-		assert!(pallet_scheduler::Agenda::<Test>::get(6)[0].is_some());
+		assert!(pallet_scheduler::Pallet::<Test>::agenda(6)[0].is_some());
 
 		// referendum passes and wait another two blocks for enactment.
 		fast_forward_to(6);
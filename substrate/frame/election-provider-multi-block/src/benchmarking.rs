@@ -0,0 +1,211 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Multi-block election pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::{Pallet as MultiBlock, *};
+use frame_benchmarking::v2::*;
+use frame_election_provider_support::VoteWeight;
+use frame_support::traits::Hooks;
+use frame_system::RawOrigin;
+use sp_npos_elections::{Assignment, EvaluateSupport};
+use sp_runtime::{traits::Bounded, PerThing};
+
+const SEED: u32 = 999;
+
+/// The number of targets of the benchmarked elections, all of which are winners.
+fn winners<T: Config>() -> u32 {
+	T::MaxWinners::get().min(T::TargetSnapshotSize::get()).max(1)
+}
+
+fn fund<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+/// A page of a solution in which the `i`-th voter of the page backs the `i % targets`-th target.
+fn solution_page<T: Config>(voters: u32, targets: u32) -> SolutionOf<T> {
+	let assignments = (0..voters)
+		.map(|i| Assignment {
+			who: i,
+			distribution: vec![(i % targets, SolutionAccuracyOf::<T>::one())],
+		})
+		.collect::<Vec<_>>();
+
+	SolutionOf::<T>::from_assignment(
+		&assignments,
+		|v| SolutionVoterIndexOf::<T>::try_from(*v as usize).ok(),
+		|t| SolutionTargetIndexOf::<T>::try_from(*t as usize).ok(),
+	)
+	.expect("indices fit into the solution; qed")
+}
+
+/// Store a snapshot with `voters` voters in each page, and as many targets as [`winners`], which
+/// are all desired.
+fn setup_snapshot<T: Config>(voters: u32) {
+	let targets = (0..winners::<T>()).map(|i| account("target", i, SEED)).collect::<Vec<_>>();
+	for page in 0..T::Pages::get() {
+		let page_voters = (0..voters)
+			.map(|i| {
+				let who = account("voter", page * voters + i, SEED);
+				let target = targets[(i % winners::<T>()) as usize].clone();
+				let votes = vec![target].try_into().expect("at least one vote is allowed; qed");
+				(who, VoteWeight::MAX / 1_000, votes)
+			})
+			.collect::<Vec<_>>();
+		<VoterSnapshot<T>>::insert(page, page_voters);
+	}
+	<TargetSnapshot<T>>::put(targets);
+	<DesiredTargets<T>>::put(winners::<T>());
+}
+
+/// Register the maximum number of submissions, with scores that are worse than `better`.
+fn fill_submissions<T: Config>(better: ElectionScore) {
+	for i in 0..T::MaxSubmissions::get() {
+		let who = account("submitter", i, SEED);
+		fund::<T>(&who);
+		let score = ElectionScore { minimal_stake: better.minimal_stake / 2, ..Default::default() };
+		MultiBlock::<T>::do_register(who, score).expect("the queue is not full yet; qed");
+	}
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	// Nothing happens in this block.
+	#[benchmark]
+	fn on_initialize_nothing() {
+		assert_eq!(MultiBlock::<T>::current_phase(), Phase::Off);
+
+		#[block]
+		{
+			MultiBlock::<T>::on_initialize(1u32.into());
+		}
+	}
+
+	// The first page of the snapshot, which also fetches the targets, is created.
+	#[benchmark]
+	fn on_initialize_snapshot_page() {
+		let voters = (0..T::VoterSnapshotPerBlock::get())
+			.map(|i| {
+				let target = account("target", i % winners::<T>(), SEED);
+				let votes = vec![target].try_into().expect("at least one vote is allowed; qed");
+				(account("voter", i, SEED), VoteWeight::MAX / 1_000, votes)
+			})
+			.collect::<Vec<_>>();
+		let targets = (0..T::TargetSnapshotSize::get())
+			.map(|i| account("target", i, SEED))
+			.collect::<Vec<_>>();
+		T::DataProvider::put_snapshot(voters, targets, None);
+
+		#[block]
+		{
+			MultiBlock::<T>::create_snapshot_page(0);
+		}
+
+		assert!(<VoterSnapshot<T>>::contains_key(0));
+	}
+
+	// The last page of the best submission, with `v` voters, is verified and the solution is
+	// queued.
+	#[benchmark]
+	fn on_initialize_verify_page(v: Linear<1, { T::VoterSnapshotPerBlock::get() }>) {
+		setup_snapshot::<T>(v);
+		<DesiredTargets<T>>::put(v.min(winners::<T>()));
+		let page = T::Pages::get() - 1;
+		let solution = solution_page::<T>(v, winners::<T>());
+		let supports = MultiBlock::<T>::page_supports(page, solution.clone())
+			.expect("the solution matches the snapshot; qed");
+		let score = supports.evaluate();
+
+		let who: T::AccountId = account("submitter", 0, SEED);
+		fund::<T>(&who);
+		MultiBlock::<T>::do_register(who.clone(), score).expect("the queue is empty; qed");
+		<SubmissionPages<T>>::insert(&who, page, solution);
+		<VerifyingPage<T>>::put(page);
+		<CurrentPhase<T>>::put(Phase::SignedValidation);
+
+		#[block]
+		{
+			MultiBlock::<T>::verify_next_page();
+		}
+
+		assert_eq!(<QueuedSolutionScore<T>>::get(), Some(score));
+	}
+
+	// The queue of submissions is full, and the worst one is discarded.
+	#[benchmark]
+	fn register() {
+		let score = ElectionScore { minimal_stake: 1_000, ..Default::default() };
+		fill_submissions::<T>(score);
+		<CurrentPhase<T>>::put(Phase::Signed);
+
+		let who: T::AccountId = whitelisted_caller();
+		fund::<T>(&who);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), score);
+
+		assert!(<Submissions<T>>::contains_key(&who));
+	}
+
+	// A new page with `VoterSnapshotPerBlock` voters is stored.
+	#[benchmark]
+	fn submit_page() {
+		let who: T::AccountId = whitelisted_caller();
+		fund::<T>(&who);
+		MultiBlock::<T>::do_register(who.clone(), Default::default())
+			.expect("the queue is empty; qed");
+		<CurrentPhase<T>>::put(Phase::Signed);
+		let solution = solution_page::<T>(T::VoterSnapshotPerBlock::get(), winners::<T>());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), 0, Some(Box::new(solution)));
+
+		assert!(<SubmissionPages<T>>::contains_key(&who, 0));
+	}
+
+	// The queued solution has `p` pages with `VoterSnapshotPerBlock` voters each.
+	#[benchmark]
+	fn elect(p: Linear<1, { T::Pages::get() }>) {
+		let voters = T::VoterSnapshotPerBlock::get();
+		setup_snapshot::<T>(voters);
+		for page in 0..p {
+			let supports =
+				MultiBlock::<T>::page_supports(page, solution_page::<T>(voters, winners::<T>()))
+					.expect("the solution matches the snapshot; qed");
+			<QueuedSolutionPages<T>>::insert(page, supports);
+		}
+		<QueuedSolutionScore<T>>::put(ElectionScore::default());
+		<CurrentPhase<T>>::put(Phase::Export);
+
+		#[block]
+		{
+			assert!(<MultiBlock<T> as ElectionProvider>::elect().is_ok());
+		}
+
+		assert_eq!(MultiBlock::<T>::current_phase(), Phase::Off);
+	}
+
+	impl_benchmark_test_suite!(
+		MultiBlock,
+		crate::mock::ExtBuilder::default().build(),
+		crate::mock::Runtime
+	);
+}
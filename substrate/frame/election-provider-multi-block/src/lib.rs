@@ -0,0 +1,937 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Multi-block election provider pallet.
+//!
+//! An [`ElectionProvider`] that spreads the expensive parts of an election over several blocks,
+//! so that the number of voters is no longer limited by what fits in a single block. It can be
+//! used wherever `pallet-election-provider-multi-phase` is used.
+//!
+//! ## Phases
+//!
+//! At each block, [`ElectionDataProvider::next_election_prediction`] is used to estimate the
+//! time remaining until the next call to [`ElectionProvider::elect`]. Based on this, the pallet
+//! goes through the following phases (see [`Phase`]):
+//!
+//! ```ignore
+//!                                                                elect()
+//!                                                                   |
+//! +-------------------+-----------+--------------------+------------+
+//! |     Snapshot      |  Signed   |  SignedValidation  |   Export   |
+//! +-------------------+-----------+--------------------+------------+
+//! Off                                                               Off
+//! ```
+//!
+//! - [`Phase::Snapshot`]: the snapshot of the election is created, one page per block. The targets
+//!   and the desired number of targets are fetched along with the first page of voters. Each page
+//!   holds up to [`Config::VoterSnapshotPerBlock`] voters, fetched with
+//!   [`ElectionDataProvider::electing_voters_from`], starting right after the last voter of the
+//!   previous page. There are [`Config::Pages`] pages in total.
+//! - [`Phase::Signed`]: anyone can [`Pallet::register`] a solution by claiming its score and
+//!   reserving [`Config::DepositBase`], and then store each page of the solution with
+//!   [`Pallet::submit_page`], reserving [`Config::DepositPerPage`] per page. At most
+//!   [`Config::MaxSubmissions`] solutions are kept, sorted by their claimed score. When a better
+//!   one is registered while the queue is full, the worst one is discarded and its deposit is
+//!   returned.
+//! - [`Phase::SignedValidation`]: the best registered solution is verified, one page per block.
+//!   Once all pages are verified, the number of winners and the claimed score are checked. A valid
+//!   solution is queued, its submitter gets their deposit back plus [`Config::RewardBase`]. An
+//!   invalid solution is discarded, its deposit is slashed, and the next best one is verified.
+//! - [`Phase::Export`]: verification is over. The queued solution, if any, is returned by
+//!   [`ElectionProvider::elect`]. Otherwise [`Config::Fallback`] is used.
+//!
+//! [`Config::SignedValidationPhase`] must be at least [`Config::Pages`] blocks long, such that at
+//! least one solution can be verified.
+//!
+//! Calling [`ElectionProvider::elect`] ends the round in any phase: the deposits of the remaining
+//! submissions are returned, all data of the round is removed, and the pallet goes back to
+//! [`Phase::Off`].
+//!
+//! ## Paged solutions
+//!
+//! Each page of a solution is a [`Config::Solution`] in which the voter indices refer to the
+//! voters of the same page of the snapshot, and the target indices refer to the single target
+//! snapshot. A page that is not submitted is treated as an empty one, i.e. none of the voters of
+//! that page back any winner. The supports of each verified page are stored separately, along
+//! with the total backing of each target, which is enough to check the number of winners and the
+//! score. The pages are only merged when the result is returned by [`ElectionProvider::elect`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use frame_election_provider_support::{
+	bounds::{DataProviderBounds, ElectionBoundsBuilder},
+	BoundedSupportsOf, ElectionDataProvider, ElectionProvider, ElectionProviderBase,
+	InstantElectionProvider, NposSolution,
+};
+use frame_support::{
+	traits::{Currency, Get, OnUnbalanced, ReservableCurrency},
+	weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_npos_elections::{ElectionScore, ExtendedBalance, Supports};
+use sp_runtime::{traits::Zero, RuntimeDebug, Saturating};
+use sp_std::prelude::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[macro_use]
+pub mod helpers;
+
+const LOG_TARGET: &str = "runtime::election-provider-multi-block";
+
+pub mod signed;
+pub mod verifier;
+pub mod weights;
+
+pub use signed::SubmissionMetadata;
+pub use verifier::FeasibilityError;
+pub use weights::WeightInfo;
+
+/// The index of a page of the snapshot or of a solution.
+pub type PageIndex = u32;
+/// The solution type used by this pallet.
+pub type SolutionOf<T> = <T as Config>::Solution;
+/// The voter index type of the solution.
+pub type SolutionVoterIndexOf<T> = <SolutionOf<T> as NposSolution>::VoterIndex;
+/// The target index type of the solution.
+pub type SolutionTargetIndexOf<T> = <SolutionOf<T> as NposSolution>::TargetIndex;
+/// The accuracy of the solution.
+pub type SolutionAccuracyOf<T> = <SolutionOf<T> as NposSolution>::Accuracy;
+/// A voter, as provided by the data provider.
+pub type VoterOf<T> = frame_election_provider_support::VoterOf<<T as Config>::DataProvider>;
+/// The fallback election error type.
+pub type FallbackErrorOf<T> = <<T as Config>::Fallback as ElectionProviderBase>::Error;
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type PositiveImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::PositiveImbalance;
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
+/// Current phase of the pallet.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
+pub enum Phase {
+	/// Nothing is happening, and nothing will happen until the next election approaches.
+	#[default]
+	Off,
+	/// The snapshot is being created. Contains the index of the next page to create.
+	Snapshot(PageIndex),
+	/// Signed solutions can be registered and submitted.
+	Signed,
+	/// The best registered solutions are verified, one page per block.
+	SignedValidation,
+	/// Verification is over, waiting for [`ElectionProvider::elect`] to be called.
+	Export,
+}
+
+/// Internal errors of the pallet, returned by [`ElectionProvider::elect`].
+#[derive(frame_support::DebugNoBound)]
+pub enum ElectionError<T: Config> {
+	/// An error happened in the data provider.
+	DataProvider(&'static str),
+	/// An error happened in the fallback.
+	Fallback(FallbackErrorOf<T>),
+	/// The queued solution could not be exported.
+	Feasibility(FeasibilityError),
+}
+
+pub use pallet::*;
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_std::collections::btree_map::BTreeMap;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>
+			+ TryInto<Event<Self>>;
+
+		/// Currency type.
+		type Currency: ReservableCurrency<Self::AccountId> + Currency<Self::AccountId>;
+
+		/// Number of pages of the snapshot, and thus of each solution.
+		#[pallet::constant]
+		type Pages: Get<PageIndex>;
+
+		/// Maximum number of voters fetched in each page of the snapshot.
+		#[pallet::constant]
+		type VoterSnapshotPerBlock: Get<u32>;
+
+		/// Maximum number of targets in the target snapshot.
+		#[pallet::constant]
+		type TargetSnapshotSize: Get<u32>;
+
+		/// Duration of the signed phase.
+		#[pallet::constant]
+		type SignedPhase: Get<BlockNumberFor<Self>>;
+
+		/// Duration of the signed validation phase.
+		///
+		/// Must be at least [`Config::Pages`] blocks long.
+		#[pallet::constant]
+		type SignedValidationPhase: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of signed submissions that can be registered in a round.
+		#[pallet::constant]
+		type MaxSubmissions: Get<u32>;
+
+		/// Base deposit for registering a signed solution.
+		#[pallet::constant]
+		type DepositBase: Get<BalanceOf<Self>>;
+
+		/// Deposit for each submitted page of a signed solution.
+		#[pallet::constant]
+		type DepositPerPage: Get<BalanceOf<Self>>;
+
+		/// Base reward for a valid signed solution.
+		#[pallet::constant]
+		type RewardBase: Get<BalanceOf<Self>>;
+
+		/// Handler for the slashed deposits.
+		type SlashHandler: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Handler for the rewards.
+		type RewardHandler: OnUnbalanced<PositiveImbalanceOf<Self>>;
+
+		/// The solution type of a single page.
+		type Solution: codec::FullCodec
+			+ Default
+			+ PartialEq
+			+ Eq
+			+ Clone
+			+ sp_std::fmt::Debug
+			+ Ord
+			+ NposSolution
+			+ TypeInfo;
+
+		/// The maximum number of winners that can be elected.
+		#[pallet::constant]
+		type MaxWinners: Get<u32>;
+
+		/// Something that will provide the election data.
+		type DataProvider: ElectionDataProvider<
+			AccountId = Self::AccountId,
+			BlockNumber = BlockNumberFor<Self>,
+		>;
+
+		/// Election provider used when no signed solution could be verified.
+		type Fallback: InstantElectionProvider<
+			AccountId = Self::AccountId,
+			BlockNumber = BlockNumberFor<Self>,
+			DataProvider = Self::DataProvider,
+			MaxWinners = Self::MaxWinners,
+		>;
+
+		/// The weight of the pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let next_election = T::DataProvider::next_election_prediction(now).max(now);
+			let remaining = next_election - now;
+
+			let signed_validation_start = T::SignedValidationPhase::get();
+			let signed_start = signed_validation_start.saturating_add(T::SignedPhase::get());
+			let snapshot_start = signed_start.saturating_add(T::Pages::get().into());
+
+			match Self::current_phase() {
+				Phase::Off if remaining <= snapshot_start && !remaining.is_zero() =>
+					Self::create_snapshot_page(0),
+				Phase::Snapshot(page) => Self::create_snapshot_page(page),
+				Phase::Signed if remaining <= signed_validation_start => {
+					Self::phase_transition(Phase::SignedValidation);
+					Self::verify_next_page()
+				},
+				Phase::SignedValidation => Self::verify_next_page(),
+				_ => T::WeightInfo::on_initialize_nothing(),
+			}
+		}
+
+		fn integrity_test() {
+			assert!(!T::Pages::get().is_zero(), "there must be at least one page");
+			assert!(
+				!T::VoterSnapshotPerBlock::get().is_zero(),
+				"each page must be able to hold voters"
+			);
+			assert!(
+				T::SignedValidationPhase::get() >= T::Pages::get().into(),
+				"the signed validation phase must be long enough to verify a solution"
+			);
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a signed solution with the given claimed score.
+		///
+		/// The dispatch origin for this call must be __signed__, and can only register a single
+		/// solution per round. [`Config::DepositBase`] is reserved. The pages of the solution are
+		/// then stored with [`Pallet::submit_page`].
+		///
+		/// If the queue of submissions is full, the worst submission is discarded if the claimed
+		/// score is better, otherwise the call fails.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::register())]
+		pub fn register(origin: OriginFor<T>, claimed_score: ElectionScore) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::current_phase() == Phase::Signed, Error::<T>::PhaseNotSigned);
+
+			Self::do_register(who, claimed_score)
+		}
+
+		/// Store, replace or remove a page of a registered signed solution.
+		///
+		/// The dispatch origin for this call must be __signed__, and must have registered a
+		/// solution with [`Pallet::register`]. [`Config::DepositPerPage`] is reserved for each
+		/// stored page, and returned when the page is removed by passing `None`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::submit_page())]
+		pub fn submit_page(
+			origin: OriginFor<T>,
+			page: PageIndex,
+			maybe_solution: Option<Box<SolutionOf<T>>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::current_phase() == Phase::Signed, Error::<T>::PhaseNotSigned);
+			ensure!(page < T::Pages::get(), Error::<T>::InvalidPage);
+
+			Self::do_submit_page(who, page, maybe_solution.map(|s| *s))
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The phase of the pallet changed.
+		PhaseTransitioned { from: Phase, to: Phase },
+		/// A page of the voter snapshot was created.
+		SnapshotPageCreated { page: PageIndex, voters: u32 },
+		/// The snapshot could not be created, no solution can be verified in this round.
+		SnapshotFailed,
+		/// A signed solution was registered.
+		Registered { who: T::AccountId, claimed_score: ElectionScore },
+		/// A page of a signed solution was stored or removed.
+		PageSubmitted { who: T::AccountId, page: PageIndex, removed: bool },
+		/// A signed solution was discarded without being verified, its deposit was returned.
+		Discarded { who: T::AccountId },
+		/// A signed solution was verified and queued, its submitter was rewarded.
+		Verified { who: T::AccountId, score: ElectionScore },
+		/// A signed solution failed to verify, its deposit was slashed.
+		Rejected { who: T::AccountId },
+		/// The election was finalized. `score` is `None` if the fallback was used.
+		ElectionFinalized { score: Option<ElectionScore> },
+		/// The election failed, no winners were returned.
+		ElectionFailed,
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Signed submissions are only accepted in the signed phase.
+		PhaseNotSigned,
+		/// The origin already registered a solution.
+		Duplicate,
+		/// The queue is full and the claimed score is not better than any registered one.
+		QueueFull,
+		/// The deposit could not be reserved.
+		CannotPayDeposit,
+		/// The origin did not register a solution.
+		NotRegistered,
+		/// The page index is out of bounds.
+		InvalidPage,
+	}
+
+	/// Current phase.
+	#[pallet::storage]
+	#[pallet::getter(fn current_phase)]
+	pub type CurrentPhase<T: Config> = StorageValue<_, Phase, ValueQuery>;
+
+	/// The targets of the current election.
+	#[pallet::storage]
+	pub type TargetSnapshot<T: Config> = StorageValue<_, Vec<T::AccountId>>;
+
+	/// The pages of voters of the current election.
+	#[pallet::storage]
+	pub type VoterSnapshot<T: Config> = StorageMap<_, Twox64Concat, PageIndex, Vec<VoterOf<T>>>;
+
+	/// Desired number of targets to elect for the current election.
+	#[pallet::storage]
+	pub type DesiredTargets<T: Config> = StorageValue<_, u32>;
+
+	/// The registered signed submissions and their claimed scores, sorted in ascending order of
+	/// score. The best submission is thus the last one.
+	#[pallet::storage]
+	pub type SortedScores<T: Config> =
+		StorageValue<_, BoundedVec<(T::AccountId, ElectionScore), T::MaxSubmissions>, ValueQuery>;
+
+	/// Metadata of the registered signed submissions.
+	#[pallet::storage]
+	pub type Submissions<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, SubmissionMetadata<BalanceOf<T>>>;
+
+	/// The submitted pages of the signed solutions.
+	#[pallet::storage]
+	pub type SubmissionPages<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, PageIndex, SolutionOf<T>>;
+
+	/// The next page of the best submission to verify.
+	#[pallet::storage]
+	pub type VerifyingPage<T: Config> = StorageValue<_, PageIndex, ValueQuery>;
+
+	/// The supports of each verified page of the best submission.
+	///
+	/// Once all pages are verified, these are the supports of the queued solution.
+	#[pallet::storage]
+	pub type QueuedSolutionPages<T: Config> =
+		StorageMap<_, Twox64Concat, PageIndex, Supports<T::AccountId>>;
+
+	/// The total backing of each target in the verified pages of the best submission.
+	#[pallet::storage]
+	pub type QueuedSolutionBackings<T: Config> =
+		StorageValue<_, BTreeMap<T::AccountId, ExtendedBalance>, ValueQuery>;
+
+	/// The score of the verified solution, whose supports are returned by the next call to
+	/// [`ElectionProvider::elect`].
+	#[pallet::storage]
+	pub type QueuedSolutionScore<T: Config> = StorageValue<_, ElectionScore>;
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+}
+
+impl<T: Config> Pallet<T> {
+	/// Transition to the given phase.
+	fn phase_transition(to: Phase) {
+		let from = Self::current_phase();
+		log!(info, "Starting phase {:?}.", to);
+		Self::deposit_event(Event::PhaseTransitioned { from, to });
+		<CurrentPhase<T>>::put(to);
+	}
+
+	/// Create the given page of the snapshot and move to the next phase.
+	///
+	/// The targets and the desired targets are fetched along with the first page. If the snapshot
+	/// cannot be created, the pallet moves straight to [`Phase::Export`] and the fallback will be
+	/// used.
+	fn create_snapshot_page(page: PageIndex) -> Weight {
+		match Self::do_create_snapshot_page(page) {
+			Ok(voters) => {
+				log!(debug, "Created snapshot page {} with {} voters.", page, voters);
+				Self::deposit_event(Event::SnapshotPageCreated { page, voters });
+
+				if page.saturating_add(1) >= T::Pages::get() {
+					Self::phase_transition(Phase::Signed);
+				} else {
+					Self::phase_transition(Phase::Snapshot(page.saturating_add(1)));
+				}
+			},
+			Err(why) => {
+				log!(warn, "Failed to create snapshot page {}: {:?}", page, why);
+				Self::deposit_event(Event::SnapshotFailed);
+				Self::phase_transition(Phase::Export);
+			},
+		}
+
+		T::WeightInfo::on_initialize_snapshot_page()
+	}
+
+	fn do_create_snapshot_page(page: PageIndex) -> Result<u32, ElectionError<T>> {
+		let bounds = ElectionBoundsBuilder::default()
+			.voters_count(T::VoterSnapshotPerBlock::get().into())
+			.targets_count(T::TargetSnapshotSize::get().into())
+			.build();
+
+		if page.is_zero() {
+			let targets = T::DataProvider::electable_targets(bounds.targets)
+				.map_err(ElectionError::DataProvider)?;
+			let mut desired_targets =
+				<Pallet<T> as ElectionProviderBase>::desired_targets_checked()
+					.map_err(ElectionError::DataProvider)?;
+
+			// If `desired_targets` > `targets.len()`, cap `desired_targets` to that level and emit
+			// a warning
+			let max_desired_targets: u32 = targets.len() as u32;
+			if desired_targets > max_desired_targets {
+				log!(
+					warn,
+					"desired_targets: {} > targets.len(): {}, capping desired_targets",
+					desired_targets,
+					max_desired_targets
+				);
+				desired_targets = max_desired_targets;
+			}
+
+			<TargetSnapshot<T>>::put(targets);
+			<DesiredTargets<T>>::put(desired_targets);
+		}
+
+		// continue right after the last voter of the previous page. If that page is empty, all
+		// voters were already fetched.
+		let voters = if page.is_zero() {
+			T::DataProvider::electing_voters_from(bounds.voters, None)
+				.map_err(ElectionError::DataProvider)?
+		} else {
+			match <VoterSnapshot<T>>::get(page - 1).and_then(|voters| voters.last().cloned()) {
+				Some((last, _, _)) =>
+					T::DataProvider::electing_voters_from(bounds.voters, Some(&last))
+						.map_err(ElectionError::DataProvider)?,
+				None => Vec::new(),
+			}
+		};
+
+		let count = voters.len() as u32;
+		<VoterSnapshot<T>>::insert(page, voters);
+		Ok(count)
+	}
+
+	/// Remove all the data of the round, return the deposits of the remaining submissions and
+	/// move to [`Phase::Off`].
+	fn rotate_round() {
+		<TargetSnapshot<T>>::kill();
+		<DesiredTargets<T>>::kill();
+		let _ = <VoterSnapshot<T>>::clear(u32::MAX, None);
+
+		<VerifyingPage<T>>::kill();
+		<QueuedSolutionBackings<T>>::kill();
+		<QueuedSolutionScore<T>>::kill();
+		let _ = <QueuedSolutionPages<T>>::clear(u32::MAX, None);
+
+		<SortedScores<T>>::kill();
+		for (who, metadata) in <Submissions<T>>::drain() {
+			let _remaining = T::Currency::unreserve(&who, metadata.deposit);
+			debug_assert!(_remaining.is_zero());
+			Self::deposit_event(Event::Discarded { who });
+		}
+		let _ = <SubmissionPages<T>>::clear(u32::MAX, None);
+
+		Self::phase_transition(Phase::Off);
+	}
+
+	fn do_elect() -> Result<BoundedSupportsOf<Self>, ElectionError<T>> {
+		if let Some(score) = <QueuedSolutionScore<T>>::take() {
+			let supports = Self::queued_supports().map_err(ElectionError::Feasibility)?;
+			Self::deposit_event(Event::ElectionFinalized { score: Some(score) });
+			return Ok(supports)
+		}
+
+		T::Fallback::instant_elect(DataProviderBounds::default(), DataProviderBounds::default())
+			.map_err(ElectionError::Fallback)
+			.map(|supports| {
+				Self::deposit_event(Event::ElectionFinalized { score: None });
+				supports
+			})
+	}
+
+	#[cfg(any(feature = "try-runtime", test))]
+	pub(crate) fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		use frame_support::ensure;
+
+		let sorted = <SortedScores<T>>::get();
+		ensure!(
+			sorted.windows(2).all(|pair| pair[0].1 <= pair[1].1),
+			"submissions must be sorted by score"
+		);
+		ensure!(
+			sorted.len() == <Submissions<T>>::iter_keys().count(),
+			"every submission must be sorted"
+		);
+		ensure!(
+			sorted.iter().all(|(who, _)| <Submissions<T>>::contains_key(who)),
+			"every sorted submission must have metadata"
+		);
+
+		match Self::current_phase() {
+			Phase::Off => {
+				ensure!(<TargetSnapshot<T>>::get().is_none(), "no snapshot must exist when off");
+				ensure!(<Submissions<T>>::iter_keys().next().is_none(), "no submissions when off");
+			},
+			Phase::Snapshot(page) => ensure!(
+				(0..page).all(|page| <VoterSnapshot<T>>::contains_key(page)),
+				"previous snapshot pages must exist"
+			),
+			_ => {},
+		}
+
+		Ok(())
+	}
+}
+
+impl<T: Config> ElectionProviderBase for Pallet<T> {
+	type AccountId = T::AccountId;
+	type BlockNumber = BlockNumberFor<T>;
+	type Error = ElectionError<T>;
+	type MaxWinners = T::MaxWinners;
+	type DataProvider = T::DataProvider;
+}
+
+impl<T: Config> ElectionProvider for Pallet<T> {
+	fn ongoing() -> bool {
+		Self::current_phase() != Phase::Off
+	}
+
+	fn elect() -> Result<BoundedSupportsOf<Self>, Self::Error> {
+		let result = Self::do_elect();
+		if let Err(ref why) = result {
+			log!(error, "Failed to finalize the election: {:?}", why);
+			Self::deposit_event(Event::ElectionFailed);
+		}
+
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			T::WeightInfo::elect(T::Pages::get()),
+			frame_support::dispatch::DispatchClass::Mandatory,
+		);
+		Self::rotate_round();
+		result
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::*;
+	use frame_support::{assert_noop, assert_ok};
+
+	fn score(minimal_stake: u128) -> ElectionScore {
+		ElectionScore { minimal_stake, ..Default::default() }
+	}
+
+	fn submit_solution(who: AccountId, solution: Vec<SolutionOf<Runtime>>, score: ElectionScore) {
+		assert_ok!(MultiBlock::register(RuntimeOrigin::signed(who), score));
+		for (page, solution) in solution.into_iter().enumerate() {
+			assert_ok!(MultiBlock::submit_page(
+				RuntimeOrigin::signed(who),
+				page as PageIndex,
+				Some(Box::new(solution)),
+			));
+		}
+	}
+
+	#[test]
+	fn phases_and_paged_snapshot_work() {
+		ExtBuilder::default().build_and_execute(|| {
+			// next election at 30. 5 blocks of validation, 10 of signed phase and 3 pages.
+			roll_to(11);
+			assert_eq!(MultiBlock::current_phase(), Phase::Off);
+			assert!(!MultiBlock::ongoing());
+
+			roll_to(12);
+			assert_eq!(MultiBlock::current_phase(), Phase::Snapshot(1));
+			assert!(MultiBlock::ongoing());
+			assert_eq!(TargetSnapshot::<Runtime>::get().unwrap(), Targets::get());
+			assert_eq!(crate::DesiredTargets::<Runtime>::get(), Some(2));
+
+			roll_to(13);
+			assert_eq!(MultiBlock::current_phase(), Phase::Snapshot(2));
+
+			roll_to(14);
+			assert_eq!(MultiBlock::current_phase(), Phase::Signed);
+
+			// 8 voters in pages of 3, in order.
+			let pages = (0..Pages::get())
+				.map(|page| VoterSnapshot::<Runtime>::get(page).unwrap())
+				.collect::<Vec<_>>();
+			assert_eq!(pages.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![3, 3, 2]);
+			assert_eq!(pages.concat(), Voters::get());
+
+			roll_to(24);
+			assert_eq!(MultiBlock::current_phase(), Phase::Signed);
+
+			// nothing to verify.
+			roll_to(25);
+			assert_eq!(MultiBlock::current_phase(), Phase::Export);
+
+			assert_eq!(
+				multi_block_events(),
+				vec![
+					Event::SnapshotPageCreated { page: 0, voters: 3 },
+					Event::PhaseTransitioned { from: Phase::Off, to: Phase::Snapshot(1) },
+					Event::SnapshotPageCreated { page: 1, voters: 3 },
+					Event::PhaseTransitioned { from: Phase::Snapshot(1), to: Phase::Snapshot(2) },
+					Event::SnapshotPageCreated { page: 2, voters: 2 },
+					Event::PhaseTransitioned { from: Phase::Snapshot(2), to: Phase::Signed },
+					Event::PhaseTransitioned { from: Phase::Signed, to: Phase::SignedValidation },
+					Event::PhaseTransitioned { from: Phase::SignedValidation, to: Phase::Export },
+				]
+			);
+		})
+	}
+
+	#[test]
+	fn snapshot_pages_are_empty_once_voters_are_exhausted() {
+		ExtBuilder::default().pages(5).build_and_execute(|| {
+			roll_to_signed();
+
+			let pages = (0..Pages::get())
+				.map(|page| VoterSnapshot::<Runtime>::get(page).unwrap().len())
+				.collect::<Vec<_>>();
+			assert_eq!(pages, vec![3, 3, 2, 0, 0]);
+		})
+	}
+
+	#[test]
+	fn elect_uses_fallback_without_solution() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to(25);
+			assert_eq!(MultiBlock::current_phase(), Phase::Export);
+
+			let supports = MultiBlock::elect().unwrap();
+			assert_eq!(supports.len(), 2);
+			assert_eq!(MultiBlock::current_phase(), Phase::Off);
+			assert!(multi_block_events().contains(&Event::ElectionFinalized { score: None }));
+
+			// all the data of the round is gone.
+			assert!(TargetSnapshot::<Runtime>::get().is_none());
+			assert!(crate::DesiredTargets::<Runtime>::get().is_none());
+			assert_eq!(VoterSnapshot::<Runtime>::iter().count(), 0);
+		})
+	}
+
+	#[test]
+	fn elect_fails_if_fallback_fails() {
+		ExtBuilder::default().onchain_fallback(false).build_and_execute(|| {
+			roll_to(25);
+
+			assert!(matches!(MultiBlock::elect(), Err(ElectionError::Fallback(_))));
+			assert!(multi_block_events().contains(&Event::ElectionFailed));
+			assert_eq!(MultiBlock::current_phase(), Phase::Off);
+		})
+	}
+
+	#[test]
+	fn elect_ends_the_round_early() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to_signed();
+
+			let (solution, score) = mine_paged_solution();
+			submit_solution(99, solution, score);
+			assert_eq!(Balances::reserved_balance(99), 8);
+
+			// the signed solution was not verified, the fallback is used.
+			assert_ok!(MultiBlock::elect());
+			assert!(multi_block_events().contains(&Event::ElectionFinalized { score: None }));
+
+			// the deposit is returned, and all the data of the round is gone.
+			assert!(multi_block_events().contains(&Event::Discarded { who: 99 }));
+			assert_eq!(Balances::reserved_balance(99), 0);
+			assert_eq!(Balances::free_balance(99), 100);
+			assert_eq!(MultiBlock::current_phase(), Phase::Off);
+			assert!(SortedScores::<Runtime>::get().is_empty());
+			assert_eq!(SubmissionPages::<Runtime>::iter().count(), 0);
+		})
+	}
+
+	#[test]
+	fn valid_signed_solution_is_verified_and_exported() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to_signed();
+
+			let (solution, score) = mine_paged_solution();
+			let expected = merged_supports(&solution);
+			submit_solution(99, solution, score);
+			// base deposit and 3 pages.
+			assert_eq!(Balances::reserved_balance(99), 5 + 3);
+
+			// one page is verified per block.
+			roll_to(25);
+			assert_eq!(MultiBlock::current_phase(), Phase::SignedValidation);
+			assert_eq!(VerifyingPage::<Runtime>::get(), 1);
+
+			// the supports of each verified page are stored separately.
+			roll_to(26);
+			assert_eq!(VerifyingPage::<Runtime>::get(), 2);
+			assert_eq!(QueuedSolutionPages::<Runtime>::iter_keys().count(), 2);
+			assert!(QueuedSolutionScore::<Runtime>::get().is_none());
+
+			roll_to(27);
+			assert_eq!(MultiBlock::current_phase(), Phase::Export);
+			assert_eq!(QueuedSolutionPages::<Runtime>::iter_keys().count(), 3);
+			assert_eq!(QueuedSolutionScore::<Runtime>::get(), Some(score));
+			assert!(multi_block_events().contains(&Event::Verified { who: 99, score }));
+
+			// deposit returned, and rewarded.
+			assert_eq!(Balances::reserved_balance(99), 0);
+			assert_eq!(Balances::free_balance(99), 100 + 7);
+
+			let supports = MultiBlock::elect().unwrap();
+			assert_eq!(supports.into_inner(), expected);
+			assert!(multi_block_events().contains(&Event::ElectionFinalized { score: Some(score) }));
+		})
+	}
+
+	#[test]
+	fn invalid_signed_solution_is_slashed_and_next_one_verified() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to_signed();
+
+			let (solution, score) = mine_paged_solution();
+			submit_solution(99, solution, score);
+
+			// a better claimed score, with a voter that does not exist in the first page.
+			let invalid = TestNposSolution { votes1: vec![(7, 0)], ..Default::default() };
+			submit_solution(
+				100,
+				vec![invalid],
+				ElectionScore { minimal_stake: u128::MAX, ..score },
+			);
+			assert_eq!(Balances::reserved_balance(100), 5 + 1);
+
+			roll_to(25);
+			assert!(multi_block_events().contains(&Event::Rejected { who: 100 }));
+			assert_eq!(Balances::reserved_balance(100), 0);
+			assert_eq!(Balances::free_balance(100), 100 - 6);
+			assert_eq!(VerifyingPage::<Runtime>::get(), 0);
+			assert_eq!(MultiBlock::current_phase(), Phase::SignedValidation);
+
+			roll_to(28);
+			assert_eq!(MultiBlock::current_phase(), Phase::Export);
+			assert!(multi_block_events().contains(&Event::Verified { who: 99, score }));
+			assert_eq!(QueuedSolutionScore::<Runtime>::get(), Some(score));
+		})
+	}
+
+	#[test]
+	fn wrong_score_is_rejected_after_the_last_page() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to_signed();
+
+			let (solution, score) = mine_paged_solution();
+			submit_solution(99, solution, ElectionScore { sum_stake: 1, ..score });
+
+			roll_to(26);
+			assert_eq!(VerifyingPage::<Runtime>::get(), 2);
+
+			roll_to(27);
+			assert!(multi_block_events().contains(&Event::Rejected { who: 99 }));
+			assert_eq!(Balances::free_balance(99), 100 - 8);
+			assert!(QueuedSolutionScore::<Runtime>::get().is_none());
+			assert_eq!(QueuedSolutionPages::<Runtime>::iter_keys().count(), 0);
+
+			// nothing left to verify.
+			roll_to(28);
+			assert_eq!(MultiBlock::current_phase(), Phase::Export);
+			assert!(MultiBlock::elect().is_ok());
+			assert!(multi_block_events().contains(&Event::ElectionFinalized { score: None }));
+		})
+	}
+
+	#[test]
+	fn register_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_noop!(
+				MultiBlock::register(RuntimeOrigin::signed(99), score(10)),
+				Error::<Runtime>::PhaseNotSigned,
+			);
+
+			roll_to_signed();
+			assert_ok!(MultiBlock::register(RuntimeOrigin::signed(99), score(10)));
+			assert_ok!(MultiBlock::register(RuntimeOrigin::signed(100), score(30)));
+			assert_ok!(MultiBlock::register(RuntimeOrigin::signed(101), score(20)));
+			assert_eq!(Balances::reserved_balance(99), 5);
+
+			assert_noop!(
+				MultiBlock::register(RuntimeOrigin::signed(99), score(40)),
+				Error::<Runtime>::Duplicate,
+			);
+			assert_noop!(
+				MultiBlock::register(RuntimeOrigin::signed(1), score(40)),
+				Error::<Runtime>::CannotPayDeposit,
+			);
+
+			// the queue is full, a worse or equal score is not accepted.
+			assert_noop!(
+				MultiBlock::register(RuntimeOrigin::signed(102), score(10)),
+				Error::<Runtime>::QueueFull,
+			);
+
+			// a better one discards the worst.
+			assert_ok!(MultiBlock::register(RuntimeOrigin::signed(102), score(15)));
+			assert!(multi_block_events().contains(&Event::Discarded { who: 99 }));
+			assert_eq!(Balances::reserved_balance(99), 0);
+			assert!(!Submissions::<Runtime>::contains_key(99));
+			assert_eq!(
+				SortedScores::<Runtime>::get().into_inner(),
+				vec![(102, score(15)), (101, score(20)), (100, score(30))],
+			);
+
+			// among equal scores, the earliest registered is the best.
+			assert_ok!(MultiBlock::register(RuntimeOrigin::signed(103), score(30)));
+			assert_eq!(
+				SortedScores::<Runtime>::get().into_inner(),
+				vec![(101, score(20)), (103, score(30)), (100, score(30))],
+			);
+		})
+	}
+
+	#[test]
+	fn submit_page_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_noop!(
+				MultiBlock::submit_page(RuntimeOrigin::signed(99), 0, None),
+				Error::<Runtime>::PhaseNotSigned,
+			);
+
+			roll_to_signed();
+			assert_noop!(
+				MultiBlock::submit_page(RuntimeOrigin::signed(99), 0, None),
+				Error::<Runtime>::NotRegistered,
+			);
+
+			assert_ok!(MultiBlock::register(RuntimeOrigin::signed(99), score(10)));
+			assert_noop!(
+				MultiBlock::submit_page(RuntimeOrigin::signed(99), 3, None),
+				Error::<Runtime>::InvalidPage,
+			);
+
+			let page = || Some(Box::new(TestNposSolution::default()));
+
+			// storing a page reserves a deposit.
+			assert_ok!(MultiBlock::submit_page(RuntimeOrigin::signed(99), 1, page()));
+			assert_eq!(Balances::reserved_balance(99), 6);
+			assert_eq!(Submissions::<Runtime>::get(99).unwrap().deposit, 6);
+
+			// replacing it does not.
+			assert_ok!(MultiBlock::submit_page(RuntimeOrigin::signed(99), 1, page()));
+			assert_eq!(Balances::reserved_balance(99), 6);
+
+			// removing it returns the deposit.
+			assert_ok!(MultiBlock::submit_page(RuntimeOrigin::signed(99), 1, None));
+			assert_eq!(Balances::reserved_balance(99), 5);
+			assert!(!SubmissionPages::<Runtime>::contains_key(99, 1));
+
+			assert_ok!(MultiBlock::submit_page(RuntimeOrigin::signed(99), 1, None));
+			assert_eq!(Balances::reserved_balance(99), 5);
+			assert_eq!(Submissions::<Runtime>::get(99).unwrap().deposit, 5);
+		})
+	}
+}
@@ -0,0 +1,377 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{self as multi_block};
+use frame_election_provider_support::{
+	bounds::{DataProviderBounds, ElectionBounds},
+	data_provider, onchain, ElectionDataProvider, NposSolution, SequentialPhragmen,
+};
+pub use frame_support::{assert_noop, assert_ok, derive_impl};
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, Hooks},
+};
+use sp_core::H256;
+use sp_npos_elections::{seq_phragmen, ElectionResult, EvaluateSupport, Supports};
+use sp_runtime::{
+	bounded_vec,
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, PerU16,
+};
+
+pub type Block = sp_runtime::generic::Block<Header, UncheckedExtrinsic>;
+pub type UncheckedExtrinsic =
+	sp_runtime::generic::UncheckedExtrinsic<AccountId, RuntimeCall, (), ()>;
+
+frame_support::construct_runtime!(
+	pub struct Runtime
+	{
+		System: frame_system::{Pallet, Call, Event<T>, Config<T>},
+		Balances: pallet_balances::{Pallet, Call, Event<T>, Config<T>},
+		MultiBlock: multi_block::{Pallet, Call, Event<T>},
+	}
+);
+
+pub(crate) type Balance = u64;
+pub(crate) type AccountId = u64;
+pub(crate) type BlockNumber = u64;
+pub(crate) type VoterIndex = u32;
+pub(crate) type TargetIndex = u16;
+
+frame_election_provider_support::generate_solution_type!(
+	#[compact]
+	pub struct TestNposSolution::<
+		VoterIndex = VoterIndex,
+		TargetIndex = TargetIndex,
+		Accuracy = PerU16,
+		MaxVoters = ConstU32::<2_000>
+	>(16)
+);
+
+/// All events of this pallet.
+pub(crate) fn multi_block_events() -> Vec<super::Event<Runtime>> {
+	System::read_events_for_pallet::<super::Event<Runtime>>()
+}
+
+/// To from `now` to block `n`.
+pub fn roll_to(n: BlockNumber) {
+	let now = System::block_number();
+	for i in now + 1..=n {
+		System::set_block_number(i);
+		MultiBlock::on_initialize(i);
+	}
+}
+
+pub fn roll_to_signed() {
+	while !matches!(MultiBlock::current_phase(), Phase::Signed) {
+		roll_to(System::block_number() + 1);
+	}
+}
+
+/// Mine a solution over the whole snapshot, and split it into one solution per page.
+///
+/// The returned score is the one computed by the verification of these pages.
+pub fn mine_paged_solution() -> (Vec<SolutionOf<Runtime>>, ElectionScore) {
+	let targets = TargetSnapshot::<Runtime>::get().unwrap();
+	let desired_targets = crate::DesiredTargets::<Runtime>::get().unwrap();
+	let pages = (0..Pages::get())
+		.map(|page| VoterSnapshot::<Runtime>::get(page).unwrap())
+		.collect::<Vec<_>>();
+
+	let ElectionResult::<_, SolutionAccuracyOf<Runtime>> { winners: _, assignments } =
+		seq_phragmen(desired_targets as usize, targets.clone(), pages.concat(), None).unwrap();
+
+	let target_index = |who: &AccountId| {
+		targets
+			.iter()
+			.position(|x| x == who)
+			.and_then(|i| TargetIndex::try_from(i).ok())
+	};
+
+	let solutions = pages
+		.iter()
+		.map(|voters| {
+			let voter_index = |who: &AccountId| {
+				voters
+					.iter()
+					.position(|(x, _, _)| x == who)
+					.and_then(|i| VoterIndex::try_from(i).ok())
+			};
+			let page_assignments = assignments
+				.iter()
+				.filter(|a| voter_index(&a.who).is_some())
+				.cloned()
+				.collect::<Vec<_>>();
+
+			<SolutionOf<Runtime>>::from_assignment(&page_assignments, &voter_index, &target_index)
+				.unwrap()
+		})
+		.collect::<Vec<_>>();
+
+	let score = merged_supports(&solutions).evaluate();
+	(solutions, score)
+}
+
+/// The supports of the given pages, merged the same way the verification does.
+pub fn merged_supports(solutions: &[SolutionOf<Runtime>]) -> Supports<AccountId> {
+	let mut merged = sp_std::collections::btree_map::BTreeMap::<AccountId, _>::new();
+	for (page, solution) in solutions.iter().enumerate() {
+		for (target, support) in
+			MultiBlock::page_supports(page as PageIndex, solution.clone()).unwrap()
+		{
+			let entry: &mut sp_npos_elections::Support<AccountId> =
+				merged.entry(target).or_default();
+			entry.total += support.total;
+			entry.voters.extend(support.voters);
+		}
+	}
+	merged.into_iter().collect()
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type SS58Prefix = ();
+	type BaseCallFilter = frame_support::traits::Everything;
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ();
+	type DbWeight = ();
+	type BlockLength = ();
+	type BlockWeights = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type RuntimeFreezeReason = ();
+	type MaxHolds = ();
+}
+
+parameter_types! {
+	pub static Targets: Vec<AccountId> = vec![10, 20, 30, 40];
+	pub static Voters: Vec<VoterOf<Runtime>> = vec![
+		(1, 10, bounded_vec![10, 20]),
+		(2, 10, bounded_vec![30, 40]),
+		(3, 10, bounded_vec![40]),
+		(4, 10, bounded_vec![10, 20, 30, 40]),
+		// self votes.
+		(10, 10, bounded_vec![10]),
+		(20, 20, bounded_vec![20]),
+		(30, 30, bounded_vec![30]),
+		(40, 40, bounded_vec![40]),
+	];
+
+	pub static DesiredTargets: u32 = 2;
+	pub static Pages: PageIndex = 3;
+	pub static VoterSnapshotPerBlock: u32 = 3;
+	pub static TargetSnapshotSize: u32 = 100;
+	pub static SignedPhase: BlockNumber = 10;
+	pub static SignedValidationPhase: BlockNumber = 5;
+	pub static MaxSubmissions: u32 = 3;
+	pub static DepositBase: Balance = 5;
+	pub static DepositPerPage: Balance = 1;
+	pub static RewardBase: Balance = 7;
+
+	#[derive(Debug)]
+	pub static MaxWinners: u32 = 200;
+	pub static OnChainElectionsBounds: ElectionBounds = ElectionBoundsBuilder::default().build();
+	pub static EpochLength: u64 = 30;
+	pub static OnChainFallback: bool = true;
+
+	pub MaxNominations: u32 = <TestNposSolution as NposSolution>::LIMIT as u32;
+}
+
+pub struct OnChainSeqPhragmen;
+impl onchain::Config for OnChainSeqPhragmen {
+	type System = Runtime;
+	type Solver = SequentialPhragmen<AccountId, SolutionAccuracyOf<Runtime>>;
+	type DataProvider = StakingMock;
+	type WeightInfo = ();
+	type MaxWinners = MaxWinners;
+	type Bounds = OnChainElectionsBounds;
+}
+
+pub struct MockFallback;
+impl ElectionProviderBase for MockFallback {
+	type BlockNumber = BlockNumber;
+	type AccountId = AccountId;
+	type Error = &'static str;
+	type DataProvider = StakingMock;
+	type MaxWinners = MaxWinners;
+}
+
+impl InstantElectionProvider for MockFallback {
+	fn instant_elect(
+		voters_bounds: DataProviderBounds,
+		targets_bounds: DataProviderBounds,
+	) -> Result<BoundedSupportsOf<Self>, Self::Error> {
+		if OnChainFallback::get() {
+			onchain::OnChainExecution::<OnChainSeqPhragmen>::instant_elect(
+				voters_bounds,
+				targets_bounds,
+			)
+			.map_err(|_| "onchain::OnChainExecution failed.")
+		} else {
+			Err("NoFallback.")
+		}
+	}
+}
+
+impl crate::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Pages = Pages;
+	type VoterSnapshotPerBlock = VoterSnapshotPerBlock;
+	type TargetSnapshotSize = TargetSnapshotSize;
+	type SignedPhase = SignedPhase;
+	type SignedValidationPhase = SignedValidationPhase;
+	type MaxSubmissions = MaxSubmissions;
+	type DepositBase = DepositBase;
+	type DepositPerPage = DepositPerPage;
+	type RewardBase = RewardBase;
+	type SlashHandler = ();
+	type RewardHandler = ();
+	type Solution = TestNposSolution;
+	type MaxWinners = MaxWinners;
+	type DataProvider = StakingMock;
+	type Fallback = MockFallback;
+	type WeightInfo = ();
+}
+
+pub struct StakingMock;
+impl ElectionDataProvider for StakingMock {
+	type BlockNumber = BlockNumber;
+	type AccountId = AccountId;
+	type MaxVotesPerVoter = MaxNominations;
+
+	fn electable_targets(bounds: DataProviderBounds) -> data_provider::Result<Vec<AccountId>> {
+		let targets = Targets::get();
+
+		if bounds.count.map_or(false, |max_len| targets.len() > max_len.0 as usize) {
+			return Err("Targets too big")
+		}
+
+		Ok(targets)
+	}
+
+	fn electing_voters(bounds: DataProviderBounds) -> data_provider::Result<Vec<VoterOf<Runtime>>> {
+		Self::electing_voters_from(bounds, None)
+	}
+
+	fn electing_voters_from(
+		bounds: DataProviderBounds,
+		start_after: Option<&AccountId>,
+	) -> data_provider::Result<Vec<VoterOf<Runtime>>> {
+		let mut voters = Voters::get();
+
+		if let Some(start_after) = start_after {
+			let position = voters
+				.iter()
+				.position(|(who, _, _)| who == start_after)
+				.ok_or("Unknown voter")?;
+			voters.drain(..=position);
+		}
+		if let Some(max_len) = bounds.count {
+			voters.truncate(max_len.0 as usize)
+		}
+
+		Ok(voters)
+	}
+
+	fn desired_targets() -> data_provider::Result<u32> {
+		Ok(DesiredTargets::get())
+	}
+
+	fn next_election_prediction(now: u64) -> u64 {
+		now + EpochLength::get() - now % EpochLength::get()
+	}
+}
+
+#[derive(Default)]
+pub struct ExtBuilder {}
+
+impl ExtBuilder {
+	pub fn pages(self, pages: PageIndex) -> Self {
+		<Pages>::set(pages);
+		self
+	}
+	pub fn onchain_fallback(self, onchain: bool) -> Self {
+		<OnChainFallback>::set(onchain);
+		self
+	}
+	pub fn max_submissions(self, max: u32) -> Self {
+		<MaxSubmissions>::set(max);
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut storage =
+			frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+
+		let _ = pallet_balances::GenesisConfig::<Runtime> {
+			balances: vec![
+				// bunch of account for submitting stuff only.
+				(99, 100),
+				(100, 100),
+				(101, 100),
+				(102, 100),
+				(103, 100),
+				(999, 100),
+			],
+		}
+		.assimilate_storage(&mut storage);
+
+		sp_io::TestExternalities::from(storage)
+	}
+
+	pub fn build_and_execute(self, test: impl FnOnce() -> ()) {
+		let mut ext = self.build();
+		ext.execute_with(test);
+		ext.execute_with(|| assert_ok!(MultiBlock::do_try_state()));
+	}
+}
@@ -0,0 +1,148 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The signed phase: registration and submission of paged solutions.
+//!
+//! A submitter first [`Pallet::register`]s a solution by claiming its score, then stores any
+//! number of its pages with [`Pallet::submit_page`]. Registered solutions are kept in
+//! [`SortedScores`], sorted by claimed score, and only the best [`Config::MaxSubmissions`] ones
+//! are kept.
+
+use crate::{
+	BalanceOf, Config, Error, Event, PageIndex, Pallet, SolutionOf, SortedScores, SubmissionPages,
+	Submissions,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::{Currency, Get, OnUnbalanced, ReservableCurrency},
+};
+use scale_info::TypeInfo;
+use sp_npos_elections::ElectionScore;
+use sp_runtime::{traits::Zero, RuntimeDebug, Saturating};
+
+/// Metadata of a registered signed submission.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+pub struct SubmissionMetadata<Balance> {
+	/// The amount currently reserved for this submission.
+	pub deposit: Balance,
+	/// The score claimed by the submitter.
+	pub claimed_score: ElectionScore,
+}
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_register(who: T::AccountId, claimed_score: ElectionScore) -> DispatchResult {
+		ensure!(!<Submissions<T>>::contains_key(&who), Error::<T>::Duplicate);
+
+		// keep the scores sorted in ascending order. Among equal scores, the earliest one is
+		// considered the best.
+		let mut sorted = <SortedScores<T>>::get();
+		let index = sorted.partition_point(|(_, score)| *score < claimed_score);
+		let discarded = sorted
+			.force_insert_keep_right(index, (who.clone(), claimed_score))
+			.map_err(|_| Error::<T>::QueueFull)?;
+
+		let deposit = T::DepositBase::get();
+		T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::CannotPayDeposit)?;
+
+		if let Some((discarded, _)) = discarded {
+			Self::discard_submission(&discarded);
+		}
+
+		<SortedScores<T>>::put(sorted);
+		<Submissions<T>>::insert(&who, SubmissionMetadata { deposit, claimed_score });
+
+		log!(debug, "Registered a solution with score {:?}.", claimed_score);
+		Self::deposit_event(Event::Registered { who, claimed_score });
+		Ok(())
+	}
+
+	pub(crate) fn do_submit_page(
+		who: T::AccountId,
+		page: PageIndex,
+		maybe_solution: Option<SolutionOf<T>>,
+	) -> DispatchResult {
+		let mut metadata = <Submissions<T>>::get(&who).ok_or(Error::<T>::NotRegistered)?;
+		let exists = <SubmissionPages<T>>::contains_key(&who, page);
+
+		match (exists, maybe_solution.is_some()) {
+			(false, true) => {
+				let deposit = T::DepositPerPage::get();
+				T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::CannotPayDeposit)?;
+				metadata.deposit = metadata.deposit.saturating_add(deposit);
+			},
+			(true, false) => {
+				let deposit = T::DepositPerPage::get();
+				let _remaining = T::Currency::unreserve(&who, deposit);
+				debug_assert!(_remaining.is_zero());
+				metadata.deposit = metadata.deposit.saturating_sub(deposit);
+			},
+			_ => {},
+		}
+
+		let removed = maybe_solution.is_none();
+		<SubmissionPages<T>>::mutate_exists(&who, page, |maybe_page| *maybe_page = maybe_solution);
+		<Submissions<T>>::insert(&who, metadata);
+
+		Self::deposit_event(Event::PageSubmitted { who, page, removed });
+		Ok(())
+	}
+
+	/// Remove all the data of the submission of `who`, returning its deposit.
+	///
+	/// Does not touch [`SortedScores`].
+	pub(crate) fn discard_submission(who: &T::AccountId) {
+		if let Some(metadata) = Self::take_submission(who) {
+			let _remaining = T::Currency::unreserve(who, metadata.deposit);
+			debug_assert!(_remaining.is_zero());
+			Self::deposit_event(Event::Discarded { who: who.clone() });
+		}
+	}
+
+	/// Remove all the data of the submission of `who`, slashing its deposit.
+	///
+	/// Does not touch [`SortedScores`].
+	pub(crate) fn slash_submission(who: &T::AccountId) {
+		if let Some(metadata) = Self::take_submission(who) {
+			let (negative_imbalance, _remaining) =
+				T::Currency::slash_reserved(who, metadata.deposit);
+			debug_assert!(_remaining.is_zero());
+			T::SlashHandler::on_unbalanced(negative_imbalance);
+			Self::deposit_event(Event::Rejected { who: who.clone() });
+		}
+	}
+
+	/// Remove all the data of the submission of `who`, returning its deposit and rewarding it
+	/// with [`Config::RewardBase`].
+	///
+	/// Does not touch [`SortedScores`].
+	pub(crate) fn reward_submission(who: &T::AccountId, score: ElectionScore) {
+		if let Some(metadata) = Self::take_submission(who) {
+			let _remaining = T::Currency::unreserve(who, metadata.deposit);
+			debug_assert!(_remaining.is_zero());
+			let positive_imbalance = T::Currency::deposit_creating(who, T::RewardBase::get());
+			T::RewardHandler::on_unbalanced(positive_imbalance);
+			Self::deposit_event(Event::Verified { who: who.clone(), score });
+		}
+	}
+
+	fn take_submission(who: &T::AccountId) -> Option<SubmissionMetadata<BalanceOf<T>>> {
+		let _ = <SubmissionPages<T>>::clear_prefix(who, u32::MAX, None);
+		<Submissions<T>>::take(who)
+	}
+}
@@ -0,0 +1,205 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The signed validation phase: verification of the best signed solution, one page per block.
+//!
+//! Each page is checked against the same page of the voter snapshot, and its supports are stored
+//! in [`QueuedSolutionPages`]. Only the total backing of each target is accumulated across pages,
+//! in [`QueuedSolutionBackings`]. Once all pages are verified, the number of winners and the
+//! claimed score are checked against these backings.
+
+use crate::{
+	helpers, weights::WeightInfo, BoundedSupportsOf, Config, DesiredTargets, PageIndex, Pallet,
+	Phase, QueuedSolutionBackings, QueuedSolutionPages, QueuedSolutionScore, SolutionOf,
+	SortedScores, SubmissionPages, TargetSnapshot, VerifyingPage, VoterSnapshot,
+};
+use frame_election_provider_support::NposSolution;
+use frame_support::{defensive, ensure, traits::Get, weights::Weight};
+use sp_npos_elections::{
+	assignment_ratio_to_staked_normalized, ElectionScore, EvaluateSupport, Support, Supports,
+};
+use sp_runtime::Saturating;
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+
+/// Errors that can happen in the feasibility check of a solution.
+#[derive(Debug, Eq, PartialEq)]
+pub enum FeasibilityError {
+	/// Wrong number of winners presented.
+	WrongWinnerCount,
+	/// The snapshot is not available.
+	///
+	/// Kinda defensive: verification never starts when the snapshot could not be created.
+	SnapshotUnavailable,
+	/// Internal error from the election crate.
+	NposElection(sp_npos_elections::Error),
+	/// A vote is invalid.
+	InvalidVote,
+	/// A voter is invalid.
+	InvalidVoter,
+	/// The given score was invalid.
+	InvalidScore,
+	/// Conversion into bounded types failed.
+	///
+	/// Should never happen under correct configurations.
+	BoundedConversionFailed,
+}
+
+impl From<sp_npos_elections::Error> for FeasibilityError {
+	fn from(e: sp_npos_elections::Error) -> Self {
+		FeasibilityError::NposElection(e)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Verify the next page of the best registered solution.
+	///
+	/// Moves to [`Phase::Export`] once a solution is queued or there is nothing left to verify.
+	pub(crate) fn verify_next_page() -> Weight {
+		let Some((who, claimed_score)) = <SortedScores<T>>::get().last().cloned() else {
+			Self::phase_transition(Phase::Export);
+			return T::WeightInfo::on_initialize_nothing()
+		};
+
+		let page = <VerifyingPage<T>>::get();
+		let result = Self::feasibility_check_page(&who, page).and_then(|supports| {
+			<QueuedSolutionBackings<T>>::mutate(|backings| {
+				for (target, support) in supports.iter() {
+					backings.entry(target.clone()).or_default().saturating_accrue(support.total);
+				}
+			});
+			<QueuedSolutionPages<T>>::insert(page, supports);
+
+			if page.saturating_add(1) < T::Pages::get() {
+				<VerifyingPage<T>>::put(page.saturating_add(1));
+				return Ok(false)
+			}
+
+			Self::finalize_verification(claimed_score).map(|_| true)
+		});
+
+		match result {
+			Ok(false) => log!(debug, "Verified page {} of the solution of {:?}.", page, who),
+			Ok(true) => {
+				log!(info, "Queued the solution of {:?} with score {:?}.", who, claimed_score);
+				<SortedScores<T>>::mutate(|sorted| sorted.pop());
+				Self::reward_submission(&who, claimed_score);
+				<VerifyingPage<T>>::kill();
+				<QueuedSolutionBackings<T>>::kill();
+				Self::phase_transition(Phase::Export);
+			},
+			Err(why) => {
+				log!(warn, "Page {} of the solution of {:?} is invalid: {:?}", page, who, why);
+				<SortedScores<T>>::mutate(|sorted| sorted.pop());
+				Self::slash_submission(&who);
+				Self::reset_verification();
+			},
+		}
+
+		T::WeightInfo::on_initialize_verify_page(T::VoterSnapshotPerBlock::get())
+	}
+
+	/// Compute the supports of the given page of the solution of `who`.
+	///
+	/// A page that was not submitted is treated as empty.
+	pub(crate) fn feasibility_check_page(
+		who: &T::AccountId,
+		page: PageIndex,
+	) -> Result<Supports<T::AccountId>, FeasibilityError> {
+		let solution = <SubmissionPages<T>>::get(who, page).unwrap_or_default();
+		Self::page_supports(page, solution)
+	}
+
+	/// Compute the supports of a single page of a solution, checking it against the same page of
+	/// the voter snapshot.
+	pub fn page_supports(
+		page: PageIndex,
+		solution: SolutionOf<T>,
+	) -> Result<Supports<T::AccountId>, FeasibilityError> {
+		let voters = <VoterSnapshot<T>>::get(page).ok_or(FeasibilityError::SnapshotUnavailable)?;
+		let targets = <TargetSnapshot<T>>::get().ok_or(FeasibilityError::SnapshotUnavailable)?;
+
+		let cache = helpers::generate_voter_cache::<T>(&voters);
+		let voter_at = helpers::voter_at_fn::<T>(&voters);
+		let target_at = helpers::target_at_fn::<T>(&targets);
+
+		// This will fail if any of the indices are gibberish, namely any of the voters or targets.
+		let assignments = solution
+			.into_assignment(voter_at, target_at)
+			.map_err::<FeasibilityError, _>(Into::into)?;
+
+		assignments.iter().try_for_each(|assignment| {
+			// Defensive-only: the voter comes from the snapshot.
+			let index = cache.get(&assignment.who).ok_or(FeasibilityError::InvalidVoter)?;
+			let (_voter, _stake, voter_targets) =
+				voters.get(*index).ok_or(FeasibilityError::InvalidVoter)?;
+
+			if assignment.distribution.iter().any(|(d, _)| !voter_targets.contains(d)) {
+				return Err(FeasibilityError::InvalidVote)
+			}
+			Ok(())
+		})?;
+
+		let stake_of = helpers::stake_of_fn::<T>(&voters, &cache);
+		let staked_assignments = assignment_ratio_to_staked_normalized(assignments, stake_of)
+			.map_err::<FeasibilityError, _>(Into::into)?;
+
+		Ok(sp_npos_elections::to_supports(&staked_assignments))
+	}
+
+	/// Check the number of winners and the score of the verified pages, and queue the solution.
+	fn finalize_verification(claimed_score: ElectionScore) -> Result<(), FeasibilityError> {
+		let desired_targets =
+			<DesiredTargets<T>>::get().ok_or(FeasibilityError::SnapshotUnavailable)?;
+		let backings = <QueuedSolutionBackings<T>>::get();
+		ensure!(backings.len() as u32 == desired_targets, FeasibilityError::WrongWinnerCount);
+
+		// the score only depends on the total backing of each winner.
+		let known_score = backings
+			.into_iter()
+			.map(|(target, total)| (target, Support { total, voters: vec![] }))
+			.collect::<Supports<_>>()
+			.evaluate();
+		ensure!(known_score == claimed_score, FeasibilityError::InvalidScore);
+
+		<QueuedSolutionScore<T>>::put(known_score);
+		Ok(())
+	}
+
+	/// Merge the supports of all pages of the queued solution, removing them.
+	pub(crate) fn queued_supports() -> Result<BoundedSupportsOf<Self>, FeasibilityError> {
+		let mut merged = BTreeMap::<T::AccountId, Support<T::AccountId>>::new();
+		for page in 0..T::Pages::get() {
+			for (target, support) in <QueuedSolutionPages<T>>::take(page).unwrap_or_default() {
+				let entry = merged.entry(target).or_default();
+				entry.total = entry.total.saturating_add(support.total);
+				entry.voters.extend(support.voters);
+			}
+		}
+
+		// the number of winners is equal to `desired_targets` <= `MaxWinners`.
+		merged.into_iter().collect::<Vec<_>>().try_into().map_err(|_| {
+			defensive!("desired targets are capped to MaxWinners");
+			FeasibilityError::BoundedConversionFailed
+		})
+	}
+
+	fn reset_verification() {
+		<VerifyingPage<T>>::kill();
+		<QueuedSolutionBackings<T>>::kill();
+		let _ = <QueuedSolutionPages<T>>::clear(u32::MAX, None);
+	}
+}
@@ -282,7 +282,7 @@ pub use signed::{
 	BalanceOf, GeometricDepositBase, NegativeImbalanceOf, PositiveImbalanceOf, SignedSubmission,
 	SignedSubmissionOf, SignedSubmissions, SubmissionIndicesOf,
 };
-pub use unsigned::{Miner, MinerConfig};
+pub use unsigned::{Miner, MinerConfig, PackedSnapshot};
 
 /// The solution type used by this crate.
 pub type SolutionOf<T> = <T as MinerConfig>::Solution;
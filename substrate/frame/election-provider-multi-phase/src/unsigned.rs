@@ -19,9 +19,10 @@
 
 use crate::{
 	helpers, Call, Config, ElectionCompute, Error, FeasibilityError, Pallet, RawSolution,
-	ReadySolution, RoundSnapshot, SolutionAccuracyOf, SolutionOf, SolutionOrSnapshotSize, Weight,
+	ReadySolution, RoundSnapshot, SolutionAccuracyOf, SolutionOf, SolutionOrSnapshotSize,
+	SolutionTargetIndexOf, SolutionVoterIndexOf, Weight,
 };
-use codec::Encode;
+use codec::{Compact, CompactLen, Encode};
 use frame_election_provider_support::{NposSolution, NposSolver, PerThing128, VoteWeight};
 use frame_support::{
 	dispatch::DispatchResult,
@@ -39,7 +40,7 @@ use sp_runtime::{
 	offchain::storage::{MutateStorageError, StorageValueRef},
 	DispatchError, SaturatedConversion,
 };
-use sp_std::prelude::*;
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
 /// Storage key used to store the last block number at which offchain worker ran.
 pub(crate) const OFFCHAIN_LAST_BLOCK: &[u8] = b"parity/multi-phase-unsigned-election";
@@ -428,6 +429,45 @@ pub trait MinerConfig {
 	fn solution_weight(voters: u32, targets: u32, active_voters: u32, degree: u32) -> Weight;
 }
 
+/// The index of a voter or a target in a [`PackedSnapshot`].
+pub type SnapshotIndex = u32;
+
+/// The part of a snapshot that the [`Miner`] needs once the election result is known.
+///
+/// Only the index and stake of each voter are kept, not their votes, so this is much smaller
+/// than the snapshot itself.
+pub struct PackedSnapshot<T: MinerConfig> {
+	/// The index of each voter in the snapshot.
+	voter_indices: BTreeMap<T::AccountId, SnapshotIndex>,
+	/// The index of each target in the snapshot.
+	target_indices: BTreeMap<T::AccountId, SnapshotIndex>,
+	/// The stake of each voter, in snapshot order.
+	stakes: Vec<VoteWeight>,
+	/// The size of the snapshot.
+	size: SolutionOrSnapshotSize,
+}
+
+impl<T: MinerConfig> PackedSnapshot<T> {
+	/// Pack the given snapshot.
+	pub fn new(voters: &[MinerVoterOf<T>], targets: &[T::AccountId]) -> Self {
+		let voter_indices = voters
+			.iter()
+			.enumerate()
+			.map(|(i, (who, _, _))| (who.clone(), i as SnapshotIndex))
+			.collect();
+		let target_indices = targets
+			.iter()
+			.enumerate()
+			.map(|(i, who)| (who.clone(), i as SnapshotIndex))
+			.collect();
+		let stakes = voters.iter().map(|(_, stake, _)| *stake).collect();
+		let size =
+			SolutionOrSnapshotSize { voters: voters.len() as u32, targets: targets.len() as u32 };
+
+		Self { voter_indices, target_indices, stakes, size }
+	}
+}
+
 /// A base miner, suitable to be used for both signed and unsigned submissions.
 pub struct Miner<T: MinerConfig>(sp_std::marker::PhantomData<T>);
 impl<T: MinerConfig> Miner<T> {
@@ -440,16 +480,18 @@ impl<T: MinerConfig> Miner<T> {
 	where
 		S: NposSolver<AccountId = T::AccountId>,
 	{
-		S::solve(desired_targets as usize, targets.clone(), voters.clone())
+		// the solver takes ownership of the snapshot, only keep what is needed afterwards.
+		let snapshot = PackedSnapshot::<T>::new(&voters, &targets);
+
+		S::solve(desired_targets as usize, targets, voters)
 			.map_err(|e| {
 				log_no_system!(error, "solver error: {:?}", e);
 				MinerError::Solver
 			})
 			.and_then(|e| {
-				Self::prepare_election_result_with_snapshot::<S::Accuracy>(
+				Self::prepare_election_result_with_packed_snapshot::<S::Accuracy>(
 					e,
-					voters,
-					targets,
+					snapshot,
 					desired_targets,
 				)
 			})
@@ -466,23 +508,50 @@ impl<T: MinerConfig> Miner<T> {
 		desired_targets: u32,
 	) -> Result<(SolutionOf<T>, ElectionScore, SolutionOrSnapshotSize, TrimmingStatus), MinerError>
 	{
-		// now make some helper closures.
-		let cache = helpers::generate_voter_cache::<T>(&voters);
-		let voter_index = helpers::voter_index_fn::<T>(&cache);
-		let target_index = helpers::target_index_fn::<T>(&targets);
-		let voter_at = helpers::voter_at_fn::<T>(&voters);
-		let target_at = helpers::target_at_fn::<T>(&targets);
-		let stake_of = helpers::stake_of_fn::<T>(&voters, &cache);
-
-		// Compute the size of a solution comprised of the selected arguments.
-		//
-		// This function completes in `O(edges)`; it's expensive, but linear.
-		let encoded_size_of = |assignments: &[IndexAssignmentOf<T>]| {
-			SolutionOf::<T>::try_from(assignments).map(|s| s.encoded_size())
-		};
+		let snapshot = PackedSnapshot::<T>::new(&voters, &targets);
+		// the votes are not needed anymore, free them before doing the heavy lifting.
+		drop(voters);
+		drop(targets);
+
+		Self::prepare_election_result_with_packed_snapshot(
+			election_result,
+			snapshot,
+			desired_targets,
+		)
+	}
 
+	/// Same as [`Self::prepare_election_result_with_snapshot`], but with a [`PackedSnapshot`].
+	///
+	/// Right after being converted to indices, the election result no longer refers to any
+	/// account, which keeps the memory used by reducing and trimming the solution low.
+	pub fn prepare_election_result_with_packed_snapshot<Accuracy: PerThing128>(
+		election_result: ElectionResult<T::AccountId, Accuracy>,
+		snapshot: PackedSnapshot<T>,
+		desired_targets: u32,
+	) -> Result<(SolutionOf<T>, ElectionScore, SolutionOrSnapshotSize, TrimmingStatus), MinerError>
+	{
+		let PackedSnapshot { voter_indices, target_indices, stakes, size } = snapshot;
 		let ElectionResult { assignments, winners: _ } = election_result;
 
+		// Replace all accounts by their index in the snapshot. Voters and targets are told apart
+		// by `reduce`, so they can share the same identifier type.
+		let assignments = assignments
+			.into_iter()
+			.map(|sp_npos_elections::Assignment { who, distribution }| {
+				let who = *voter_indices.get(&who)?;
+				let distribution = distribution
+					.into_iter()
+					.map(|(target, p)| target_indices.get(&target).map(|t| (*t, p)))
+					.collect::<Option<Vec<_>>>()?;
+				Some(sp_npos_elections::Assignment { who, distribution })
+			})
+			.collect::<Option<Vec<_>>>()
+			.ok_or(sp_npos_elections::Error::SolutionInvalidIndex)?;
+		drop(voter_indices);
+		drop(target_indices);
+
+		let stake_of = |who: &SnapshotIndex| stakes.get(*who as usize).copied().unwrap_or_default();
+
 		// Reduce (requires round-trip to staked form)
 		let sorted_assignments = {
 			// convert to staked and reduce.
@@ -494,21 +563,9 @@ impl<T: MinerConfig> Miner<T> {
 
 			// Sort the assignments by reversed voter stake. This ensures that we can efficiently
 			// truncate the list.
-			staked.sort_by_key(
-				|sp_npos_elections::StakedAssignment::<T::AccountId> { who, .. }| {
-					// though staked assignments are expressed in terms of absolute stake, we'd
-					// still need to iterate over all votes in order to actually compute the total
-					// stake. it should be faster to look it up from the cache.
-					let stake = cache
-						.get(who)
-						.map(|idx| {
-							let (_, stake, _) = voters[*idx];
-							stake
-						})
-						.unwrap_or_default();
-					sp_std::cmp::Reverse(stake)
-				},
-			);
+			staked.sort_by_key(|sp_npos_elections::StakedAssignment { who, .. }| {
+				sp_std::cmp::Reverse(stake_of(who))
+			});
 
 			// convert back.
 			assignment_staked_to_ratio_normalized(staked)?
@@ -516,30 +573,42 @@ impl<T: MinerConfig> Miner<T> {
 
 		// convert to `IndexAssignment`. This improves the runtime complexity of repeatedly
 		// converting to `Solution`.
+		let voter_index = |who: &SnapshotIndex| {
+			<usize as TryInto<SolutionVoterIndexOf<T>>>::try_into(*who as usize).ok()
+		};
+		let target_index = |who: &SnapshotIndex| {
+			<usize as TryInto<SolutionTargetIndexOf<T>>>::try_into(*who as usize).ok()
+		};
 		let mut index_assignments = sorted_assignments
 			.into_iter()
 			.map(|assignment| IndexAssignmentOf::<T>::new(&assignment, &voter_index, &target_index))
 			.collect::<Result<Vec<_>, _>>()?;
 
 		// trim assignments list for weight and length.
-		let size =
-			SolutionOrSnapshotSize { voters: voters.len() as u32, targets: targets.len() as u32 };
 		let weight_trimmed = Self::trim_assignments_weight(
 			desired_targets,
 			size,
 			T::MaxWeight::get(),
 			&mut index_assignments,
 		);
-		let length_trimmed = Self::trim_assignments_length(
-			T::MaxLength::get(),
-			&mut index_assignments,
-			&encoded_size_of,
-		)?;
+		let length_trimmed =
+			Self::trim_assignments_length_packed(T::MaxLength::get(), &mut index_assignments)?;
 
 		// now make solution.
 		let solution = SolutionOf::<T>::try_from(&index_assignments)?;
+		drop(index_assignments);
 
 		// re-calc score.
+		let voter_at = |i: SolutionVoterIndexOf<T>| {
+			<SolutionVoterIndexOf<T> as TryInto<usize>>::try_into(i)
+				.ok()
+				.and_then(|i| SnapshotIndex::try_from(i).ok())
+		};
+		let target_at = |i: SolutionTargetIndexOf<T>| {
+			<SolutionTargetIndexOf<T> as TryInto<usize>>::try_into(i)
+				.ok()
+				.and_then(|i| SnapshotIndex::try_from(i).ok())
+		};
 		let score = solution.clone().score(stake_of, voter_at, target_at)?;
 
 		let is_trimmed = TrimmingStatus { weight: weight_trimmed, length: length_trimmed };
@@ -622,6 +691,61 @@ impl<T: MinerConfig> Miner<T> {
 		Ok(remove)
 	}
 
+	/// Same as [`Self::trim_assignments_length`], but computes the encoded size of the solution
+	/// incrementally, in a single pass over `assignments`.
+	///
+	/// Each assignment is encoded in the vector of the assignments with as many targets, so it
+	/// adds its own encoded size to the size of the solution, and may grow the length prefix of
+	/// that vector. This avoids building a whole new solution for each attempted length.
+	pub fn trim_assignments_length_packed(
+		max_allowed_length: u32,
+		assignments: &mut Vec<IndexAssignmentOf<T>>,
+	) -> Result<usize, MinerError> {
+		let max_allowed_length: usize = max_allowed_length.saturated_into();
+		let empty_size = SolutionOf::<T>::default().encoded_size();
+
+		let mut size = empty_size;
+		let mut counts = sp_std::collections::btree_map::BTreeMap::<usize, u32>::new();
+		let mut maximum_allowed_voters = 0;
+		for assignment in assignments.iter() {
+			if !assignment.distribution.is_empty() {
+				let entry_size = SolutionOf::<T>::try_from(sp_std::slice::from_ref(assignment))?
+					.encoded_size()
+					.saturating_sub(empty_size);
+				let count = counts.entry(assignment.distribution.len()).or_default();
+				let prefix_growth = Compact::<u32>::compact_len(&count.saturating_add(1))
+					.saturating_sub(Compact::<u32>::compact_len(count));
+				*count = count.saturating_add(1);
+				size = size.saturating_add(entry_size).saturating_add(prefix_growth);
+			}
+
+			if size > max_allowed_length {
+				break
+			}
+			maximum_allowed_voters += 1;
+		}
+
+		// ensure our post-conditions are correct
+		debug_assert!(
+			maximum_allowed_voters == 0 ||
+				SolutionOf::<T>::try_from(&assignments[..maximum_allowed_voters])
+					.map_or(false, |s| s.encoded_size() <= max_allowed_length)
+		);
+
+		let remove = assignments.len().saturating_sub(maximum_allowed_voters);
+
+		log_no_system!(
+			debug,
+			"from {} assignments, truncating to {} for length, removing {}",
+			assignments.len(),
+			maximum_allowed_voters,
+			remove
+		);
+		assignments.truncate(maximum_allowed_voters);
+
+		Ok(remove)
+	}
+
 	/// Greedily reduce the size of the solution to fit into the block w.r.t. weight.
 	///
 	/// The weight of the solution is foremost a function of the number of voters (i.e.
@@ -1929,6 +2053,35 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn trim_assignments_length_packed_matches_trim_assignments_length() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to_unsigned();
+
+			let TrimHelpers { assignments, encoded_size_of, .. } = trim_helpers();
+			let encoded_len =
+				SolutionOf::<Runtime>::try_from(assignments.as_slice()).unwrap().encoded_size();
+
+			for max_length in 0..=encoded_len as u32 + 1 {
+				let mut expected = assignments.clone();
+				let expected_removed = Miner::<Runtime>::trim_assignments_length(
+					max_length,
+					&mut expected,
+					&encoded_size_of,
+				)
+				.unwrap();
+
+				let mut trimmed = assignments.clone();
+				let removed =
+					Miner::<Runtime>::trim_assignments_length_packed(max_length, &mut trimmed)
+						.unwrap();
+
+				assert_eq!(removed, expected_removed);
+				assert_eq!(trimmed, expected);
+			}
+		});
+	}
+
 	// all the other solution-generation functions end up delegating to `mine_solution`, so if we
 	// demonstrate that `mine_solution` solutions are all trimmed to an acceptable length, then
 	// we know that higher-level functions will all also have short-enough solutions.
@@ -306,6 +306,25 @@ pub trait ElectionDataProvider {
 	/// appropriate weight at the end of execution with the system pallet directly.
 	fn electing_voters(bounds: DataProviderBounds) -> data_provider::Result<Vec<VoterOf<Self>>>;
 
+	/// A page of the voters returned by [`Self::electing_voters`], starting right after
+	/// `start_after`, or with the first voter if it is `None`.
+	///
+	/// This allows an election provider to fetch the voters over several blocks, passing the last
+	/// voter of the previous page as `start_after`. The default implementation returns all voters
+	/// in the first page and no voters afterwards.
+	///
+	/// This should be implemented as a self-weighing function. The implementor should register its
+	/// appropriate weight at the end of execution with the system pallet directly.
+	fn electing_voters_from(
+		bounds: DataProviderBounds,
+		start_after: Option<&Self::AccountId>,
+	) -> data_provider::Result<Vec<VoterOf<Self>>> {
+		match start_after {
+			None => Self::electing_voters(bounds),
+			Some(_) => Ok(Vec::new()),
+		}
+	}
+
 	/// The number of targets to elect.
 	///
 	/// This should be implemented as a self-weighing function. The implementor should register its
@@ -141,7 +141,10 @@ use sp_std::{marker::PhantomData, prelude::*};
 #[cfg(feature = "try-runtime")]
 use ::{
 	frame_support::{
-		traits::{TryDecodeEntireStorage, TryDecodeEntireStorageError, TryState},
+		traits::{
+			MeasureWeight, TryDecodeEntireStorage, TryDecodeEntireStorageError, TryMeasureHooks,
+			TryState,
+		},
 		StorageNoopGuard,
 	},
 	frame_try_runtime::{TryStateSelect, UpgradeCheckSelect},
@@ -238,7 +241,8 @@ impl<
 			+ OnFinalize<BlockNumberFor<System>>
 			+ OffchainWorker<BlockNumberFor<System>>
 			+ TryState<BlockNumberFor<System>>
-			+ TryDecodeEntireStorage,
+			+ TryDecodeEntireStorage
+			+ TryMeasureHooks<BlockNumberFor<System>>,
 		COnRuntimeUpgrade: OnRuntimeUpgrade,
 	> Executive<System, Block, Context, UnsignedValidator, AllPalletsWithSystem, COnRuntimeUpgrade>
 where
@@ -262,6 +266,99 @@ where
 		state_root_check: bool,
 		signature_check: bool,
 		select: frame_try_runtime::TryStateSelect,
+	) -> Result<Weight, &'static str> {
+		Self::try_execute_block_with(
+			block,
+			state_root_check,
+			signature_check,
+			select,
+			<AllPalletsWithSystem as OnInitialize<BlockNumberFor<System>>>::on_initialize,
+			<AllPalletsWithSystem as OnIdle<BlockNumberFor<System>>>::on_idle,
+			<AllPalletsWithSystem as OnFinalize<BlockNumberFor<System>>>::on_finalize,
+		)
+	}
+
+	/// Execute given block like [`Self::try_execute_block`], while measuring the weight used by
+	/// the `on_initialize`, `on_idle` and `on_finalize` hooks of each pallet with `Measure`.
+	///
+	/// The measured weight of each pallet is compared with the weight declared by its hooks. The
+	/// weight of `on_finalize` has to be declared by `on_initialize`, so both are compared
+	/// together. Every hook that uses more weight than it declared is logged as a warning, and
+	/// fails the execution if `fail_on_underestimate` is set.
+	///
+	/// Should only be used for testing ONLY.
+	pub fn try_execute_block_measuring_hooks<Measure: MeasureWeight>(
+		block: Block,
+		state_root_check: bool,
+		signature_check: bool,
+		select: frame_try_runtime::TryStateSelect,
+		fail_on_underestimate: bool,
+	) -> Result<Weight, &'static str> {
+		let report = sp_std::cell::RefCell::new(Vec::new());
+		let weight = Self::try_execute_block_with(
+			block,
+			state_root_check,
+			signature_check,
+			select,
+			|n| {
+				AllPalletsWithSystem::measured_on_initialize::<Measure>(n, &mut report.borrow_mut())
+			},
+			|n, remaining_weight| {
+				AllPalletsWithSystem::measured_on_idle::<Measure>(
+					n,
+					remaining_weight,
+					&mut report.borrow_mut(),
+				)
+			},
+			|n| AllPalletsWithSystem::measured_on_finalize::<Measure>(n, &mut report.borrow_mut()),
+		)?;
+
+		let mut underestimated = 0;
+		for hook in report.into_inner() {
+			if hook.is_underestimated() {
+				underestimated += 1;
+				log::warn!(
+					target: LOG_TARGET,
+					"{}::{} declared {:?}, but used {:?}",
+					hook.pallet,
+					hook.hook,
+					hook.declared,
+					hook.measured,
+				);
+			} else {
+				log::debug!(
+					target: LOG_TARGET,
+					"{}::{} declared {:?} and used {:?}",
+					hook.pallet,
+					hook.hook,
+					hook.declared,
+					hook.measured,
+				);
+			}
+		}
+
+		if underestimated > 0 && fail_on_underestimate {
+			log::error!(
+				target: LOG_TARGET,
+				"{} hooks used more weight than they declared",
+				underestimated,
+			);
+			return Err("hooks used more weight than they declared")
+		}
+
+		Ok(weight)
+	}
+
+	/// Execute given block like [`Self::try_execute_block`], calling the given functions instead
+	/// of the `on_initialize`, `on_idle` and `on_finalize` hooks of the pallets.
+	fn try_execute_block_with(
+		block: Block,
+		state_root_check: bool,
+		signature_check: bool,
+		select: frame_try_runtime::TryStateSelect,
+		on_initialize: impl FnOnce(BlockNumberFor<System>) -> Weight,
+		on_idle: impl FnOnce(BlockNumberFor<System>, Weight) -> Weight,
+		on_finalize: impl FnOnce(BlockNumberFor<System>),
 	) -> Result<Weight, &'static str> {
 		log::info!(
 			target: LOG_TARGET,
@@ -272,7 +369,7 @@ where
 			select,
 		);
 
-		Self::initialize_block(block.header());
+		Self::initialize_block_with(block.header(), on_initialize);
 		Self::initial_checks(&block);
 
 		let (header, extrinsics) = block.deconstruct();
@@ -311,7 +408,7 @@ where
 
 		// post-extrinsics book-keeping
 		<frame_system::Pallet<System>>::note_finished_extrinsics();
-		Self::idle_and_finalize_hook(*header.number());
+		Self::idle_and_finalize_hook_with(*header.number(), on_idle, on_finalize);
 
 		// run the try-state checks of all pallets, ensuring they don't alter any state.
 		let _guard = frame_support::StorageNoopGuard::default();
@@ -469,10 +566,22 @@ where
 
 	/// Start the execution of a particular block.
 	pub fn initialize_block(header: &frame_system::pallet_prelude::HeaderFor<System>) {
+		Self::initialize_block_with(
+			header,
+			<AllPalletsWithSystem as OnInitialize<BlockNumberFor<System>>>::on_initialize,
+		)
+	}
+
+	/// Start the execution of a particular block, calling `on_initialize` instead of the
+	/// `on_initialize` hooks of the pallets.
+	fn initialize_block_with(
+		header: &frame_system::pallet_prelude::HeaderFor<System>,
+		on_initialize: impl FnOnce(BlockNumberFor<System>) -> Weight,
+	) {
 		sp_io::init_tracing();
 		sp_tracing::enter_span!(sp_tracing::Level::TRACE, "init_block");
 		let digests = Self::extract_pre_digest(header);
-		Self::initialize_block_impl(header.number(), header.parent_hash(), &digests);
+		Self::initialize_block_impl(header.number(), header.parent_hash(), &digests, on_initialize);
 	}
 
 	fn extract_pre_digest(header: &frame_system::pallet_prelude::HeaderFor<System>) -> Digest {
@@ -489,6 +598,7 @@ where
 		block_number: &BlockNumberFor<System>,
 		parent_hash: &System::Hash,
 		digest: &Digest,
+		on_initialize: impl FnOnce(BlockNumberFor<System>) -> Weight,
 	) {
 		// Reset events before apply runtime upgrade hook.
 		// This is required to preserve events from runtime upgrade hook.
@@ -506,9 +616,7 @@ where
 			);
 		}
 		<frame_system::Pallet<System>>::initialize(block_number, parent_hash, digest);
-		weight = weight.saturating_add(<AllPalletsWithSystem as OnInitialize<
-			BlockNumberFor<System>,
-		>>::on_initialize(*block_number));
+		weight = weight.saturating_add(on_initialize(*block_number));
 		weight = weight.saturating_add(
 			<System::BlockWeights as frame_support::traits::Get<_>>::get().base_block,
 		);
@@ -571,6 +679,11 @@ where
 		extrinsics: Vec<Block::Extrinsic>,
 		block_number: NumberFor<Block>,
 	) {
+		// Verify the signatures of all extrinsics as a batch first, which makes checking them one
+		// by one below considerably cheaper.
+		#[cfg(feature = "batch-verification")]
+		<Block::Extrinsic as Checkable<Context>>::verify_batch(&extrinsics, &Default::default());
+
 		extrinsics.into_iter().for_each(|e| {
 			if let Err(e) = Self::apply_extrinsic(e) {
 				let err: &'static str = e.into();
@@ -598,22 +711,33 @@ where
 	}
 
 	fn idle_and_finalize_hook(block_number: NumberFor<Block>) {
+		Self::idle_and_finalize_hook_with(
+			block_number,
+			<AllPalletsWithSystem as OnIdle<BlockNumberFor<System>>>::on_idle,
+			<AllPalletsWithSystem as OnFinalize<BlockNumberFor<System>>>::on_finalize,
+		)
+	}
+
+	/// Like [`Self::idle_and_finalize_hook`], but calling the given functions instead of the
+	/// `on_idle` and `on_finalize` hooks of the pallets.
+	fn idle_and_finalize_hook_with(
+		block_number: NumberFor<Block>,
+		on_idle: impl FnOnce(BlockNumberFor<System>, Weight) -> Weight,
+		on_finalize: impl FnOnce(BlockNumberFor<System>),
+	) {
 		let weight = <frame_system::Pallet<System>>::block_weight();
 		let max_weight = <System::BlockWeights as frame_support::traits::Get<_>>::get().max_block;
 		let remaining_weight = max_weight.saturating_sub(weight.total());
 
 		if remaining_weight.all_gt(Weight::zero()) {
-			let used_weight = <AllPalletsWithSystem as OnIdle<BlockNumberFor<System>>>::on_idle(
-				block_number,
-				remaining_weight,
-			);
+			let used_weight = on_idle(block_number, remaining_weight);
 			<frame_system::Pallet<System>>::register_extra_weight_unchecked(
 				used_weight,
 				DispatchClass::Mandatory,
 			);
 		}
 
-		<AllPalletsWithSystem as OnFinalize<BlockNumberFor<System>>>::on_finalize(block_number);
+		on_finalize(block_number);
 	}
 
 	/// Apply extrinsic outside of the block execution function.
@@ -762,7 +886,7 @@ mod tests {
 
 	use frame_support::{
 		assert_err, derive_impl, parameter_types,
-		traits::{fungible, ConstU32, ConstU64, ConstU8, Currency},
+		traits::{fungible, ConstU32, ConstU64, ConstU8, Currency, Get},
 		weights::{ConstantMultiplier, IdentityFee, RuntimeDbWeight, Weight, WeightToFee},
 	};
 	use frame_system::{ChainContext, LastRuntimeUpgrade, LastRuntimeUpgradeInfo};
@@ -912,15 +1036,38 @@ mod tests {
 				.for_class(DispatchClass::all(), |weights| weights.base_extrinsic = Weight::from_parts(5, 0))
 				.for_class(DispatchClass::non_mandatory(), |weights| weights.max_total = Weight::from_parts(1024, u64::MAX).into())
 				.build_or_panic();
+		/// Like `BlockWeights`, but limiting the proof size of the block, and reserving some of it
+		/// for `Operational` extrinsics.
+		pub ProofSizeLimitedBlockWeights: frame_system::limits::BlockWeights =
+			frame_system::limits::BlockWeights::builder()
+				.base_block(Weight::from_parts(10, 0))
+				.for_class(DispatchClass::all(), |weights| weights.base_extrinsic = Weight::from_parts(5, 0))
+				.for_class(DispatchClass::non_mandatory(), |weights| weights.max_total = Weight::from_parts(1024, 1024).into())
+				.reserved_proof_size(DispatchClass::Operational, 256)
+				.build_or_panic();
+		/// Whether the runtime uses `ProofSizeLimitedBlockWeights` instead of `BlockWeights`.
+		pub static UseProofSizeLimitedBlockWeights: bool = false;
 		pub const DbWeight: RuntimeDbWeight = RuntimeDbWeight {
 			read: 10,
 			write: 100,
 		};
 	}
+
+	pub struct RuntimeBlockWeights;
+	impl Get<frame_system::limits::BlockWeights> for RuntimeBlockWeights {
+		fn get() -> frame_system::limits::BlockWeights {
+			if UseProofSizeLimitedBlockWeights::get() {
+				ProofSizeLimitedBlockWeights::get()
+			} else {
+				BlockWeights::get()
+			}
+		}
+	}
+
 	#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 	impl frame_system::Config for Runtime {
 		type BaseCallFilter = frame_support::traits::Everything;
-		type BlockWeights = BlockWeights;
+		type BlockWeights = RuntimeBlockWeights;
 		type BlockLength = ();
 		type DbWeight = ();
 		type RuntimeOrigin = RuntimeOrigin;
@@ -1227,6 +1374,43 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn proof_size_and_ref_time_limits_are_enforced_separately() {
+		UseProofSizeLimitedBlockWeights::set(true);
+		new_test_ext(10000).execute_with(|| {
+			Executive::initialize_block(&Header::new_from_number(1));
+			let max_block = <Runtime as frame_system::Config>::BlockWeights::get().max_block;
+
+			// `Mandatory` weight overflows the proof size of the block, but not its ref-time.
+			<frame_system::Pallet<Runtime>>::register_extra_weight_unchecked(
+				Weight::from_parts(0, max_block.proof_size() + 1),
+				DispatchClass::Mandatory,
+			);
+
+			// `Operational` has no ref-time reserved, yet it is not held against the
+			// exhausted proof size since it doesn't consume any.
+			let xt = TestXt::new(
+				RuntimeCall::Custom(custom::Call::some_root_operation {}),
+				sign_extra(1, 0, 0),
+			);
+			assert!(Executive::apply_extrinsic(xt).is_ok());
+
+			// Once ref-time overflows as well, there is no reserved ref-time to fall back to.
+			<frame_system::Pallet<Runtime>>::register_extra_weight_unchecked(
+				Weight::from_parts(max_block.ref_time(), 0),
+				DispatchClass::Mandatory,
+			);
+			let xt = TestXt::new(
+				RuntimeCall::Custom(custom::Call::some_root_operation {}),
+				sign_extra(1, 1, 0),
+			);
+			assert_eq!(
+				Executive::apply_extrinsic(xt),
+				Err(InvalidTransaction::ExhaustsResources.into())
+			);
+		});
+	}
+
 	#[test]
 	fn block_weight_and_size_is_stored_per_tx() {
 		let xt = TestXt::new(
@@ -1609,6 +1793,61 @@ mod tests {
 		});
 	}
 
+	#[cfg(feature = "try-runtime")]
+	#[test]
+	fn try_execute_block_measuring_hooks_works() {
+		parameter_types! {
+			pub static MeasuredWeight: Weight = Weight::zero();
+		}
+
+		// Measures every hook as using `MeasuredWeight`.
+		struct MeasureFixed;
+		impl MeasureWeight for MeasureFixed {
+			fn measure<R>(f: impl FnOnce() -> R) -> (R, Weight) {
+				(f(), MeasuredWeight::get())
+			}
+		}
+
+		let header = new_test_ext(1).execute_with(|| {
+			Executive::initialize_block(&Header::new(
+				1,
+				H256::default(),
+				H256::default(),
+				[69u8; 32].into(),
+				Digest::default(),
+			));
+			Executive::finalize_block()
+		});
+		let try_execute_block = |fail_on_underestimate| {
+			new_test_ext(1).execute_with(|| {
+				Executive::try_execute_block_measuring_hooks::<MeasureFixed>(
+					Block::new(header.clone(), vec![]),
+					true,
+					false,
+					TryStateSelect::None,
+					fail_on_underestimate,
+				)
+			})
+		};
+
+		// No hook uses more weight than it declared, and the block is executed as usual.
+		let weight = try_execute_block(true).unwrap();
+		assert_eq!(
+			new_test_ext(1).execute_with(|| Executive::try_execute_block(
+				Block::new(header.clone(), vec![]),
+				true,
+				false,
+				TryStateSelect::None,
+			)),
+			Ok(weight)
+		);
+
+		// `System` declares no weight for its hooks.
+		MeasuredWeight::set(Weight::from_parts(1, 0));
+		assert_eq!(try_execute_block(false), Ok(weight));
+		assert_eq!(try_execute_block(true), Err("hooks used more weight than they declared"));
+	}
+
 	#[test]
 	#[should_panic(expected = "Invalid inherent position for extrinsic at index 1")]
 	fn invalid_inherent_position_fail() {
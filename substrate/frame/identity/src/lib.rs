@@ -74,6 +74,7 @@
 
 mod benchmarking;
 pub mod legacy;
+pub mod migration;
 #[cfg(test)]
 mod tests;
 mod types;
@@ -0,0 +1,50 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Migrations of the identity pallet.
+
+use super::*;
+use frame_support::{migrations::RepriceDepositsConfig, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// The identity and sub-account deposits of the identity pallet, to re-price them with
+/// [`frame_support::migrations::RepriceDeposits`].
+pub struct IdentityDeposits<T>(PhantomData<T>);
+
+impl<T: Config> RepriceDepositsConfig for IdentityDeposits<T> {
+	type AccountId = T::AccountId;
+	type DbWeight = T::DbWeight;
+
+	fn accounts() -> (Vec<T::AccountId>, Weight) {
+		let accounts: Vec<_> = IdentityOf::<T>::iter_keys().collect();
+		let weight = T::DbWeight::get().reads(accounts.len() as u64);
+		(accounts, weight)
+	}
+
+	fn reprice(who: &T::AccountId) -> DispatchResult {
+		Pallet::<T>::poke_deposit(who).map(|_| ())
+	}
+
+	fn reprice_weight() -> Weight {
+		// `IdentityOf`, `SubsOf` and the account of `who`.
+		T::DbWeight::get().reads_writes(3, 3)
+	}
+}
+
+/// Re-prices all deposits of the identity pallet at the current deposit constants, to be run
+/// after they changed.
+pub type RepriceDeposits<T> = frame_support::migrations::RepriceDeposits<IdentityDeposits<T>>;
@@ -137,6 +137,11 @@
 //! this will work since the message could be part of a stale page and be reaped before execution
 //! commences.
 //!
+//! The overweight messages of a queue that were not executed yet are listed by
+//! [`Pallet::overweight_messages`], which is exposed through the [`MessageQueueApi`] runtime API.
+//! Messages that can never be executed can be discarded by governance through
+//! [`Pallet::discard_overweight`], which emits an [`Event::OverweightDiscarded`] event.
+//!
 //! # Terminology
 //!
 //! - `Message`: A blob of data into which the pallet has no introspection, defined as
@@ -354,6 +359,24 @@ impl<
 		}
 	}
 
+	/// Return all messages of the page in the form of `(position, processed, message)`.
+	fn peek_all(&self) -> Vec<(usize, bool, &[u8])> {
+		let mut items = Vec::new();
+		let mut pos = 0;
+		let mut item_slice = &self.heap[..];
+		let header_len: usize = ItemHeader::<Size>::max_encoded_len().saturated_into();
+		while let Ok(h) = ItemHeader::<Size>::decode(&mut item_slice) {
+			let item_len = h.payload_len.into() as usize;
+			if item_slice.len() < item_len {
+				break
+			}
+			items.push((pos, h.is_processed, &item_slice[..item_len]));
+			item_slice = &item_slice[item_len..];
+			pos.saturating_accrue(header_len.saturating_add(item_len));
+		}
+		items
+	}
+
 	/// Return the message with index `index` in the form of `(position, processed, message)`.
 	fn peek_index(&self, index: usize) -> Option<(usize, bool, &[u8])> {
 		let mut pos = 0;
@@ -572,6 +595,21 @@ pub mod pallet {
 			page_index: PageIndex,
 			/// The index of the message within the page.
 			message_index: T::Size,
+			/// The length of the message in bytes.
+			size: u32,
+		},
+		/// An overweight message was discarded without being executed.
+		OverweightDiscarded {
+			/// The `blake2_256` hash of the message.
+			id: [u8; 32],
+			/// The queue of the message.
+			origin: MessageOriginOf<T>,
+			/// The page of the message.
+			page_index: PageIndex,
+			/// The index of the message within the page.
+			message_index: T::Size,
+			/// The length of the message in bytes.
+			size: u32,
 		},
 		/// This page was reaped.
 		PageReaped {
@@ -697,6 +735,56 @@ pub mod pallet {
 				Self::do_execute_overweight(message_origin, page, index, weight_limit)?;
 			Ok(Some(actual_weight).into())
 		}
+
+		/// Discard an overweight message without executing it.
+		///
+		/// This is meant for messages that can never be executed, for example because they were
+		/// sent by a misbehaving origin. It also works while the queue is paused.
+		///
+		/// - `origin`: Must be `Root`.
+		/// - `message_origin`: The origin from which the message to be discarded arrived.
+		/// - `page`: The page in the queue in which the message to be discarded is sitting.
+		/// - `index`: The index into the queue of the message to be discarded.
+		#[pallet::call_index(2)]
+		#[pallet::weight(
+			T::WeightInfo::execute_overweight_page_updated().max(
+			T::WeightInfo::execute_overweight_page_removed())
+		)]
+		pub fn discard_overweight(
+			origin: OriginFor<T>,
+			message_origin: MessageOriginOf<T>,
+			page: PageIndex,
+			index: T::Size,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let actual_weight = Self::do_discard_overweight(message_origin, page, index)?;
+			Ok(Some(actual_weight).into())
+		}
+	}
+}
+
+/// An overweight message that was neither executed nor discarded yet.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OverweightMessage<Size> {
+	/// The `blake2_256` hash of the message.
+	pub id: [u8; 32],
+	/// The page of the message.
+	pub page_index: PageIndex,
+	/// The index of the message within the page.
+	pub message_index: Size,
+	/// The length of the message in bytes.
+	pub size: u32,
+}
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API to inspect the overweight messages of the message queue.
+	pub trait MessageQueueApi<MessageOrigin, Size> where
+		MessageOrigin: Codec,
+		Size: Codec,
+	{
+		/// Returns the overweight messages of the queue of `origin`, see
+		/// [`Pallet::overweight_messages`].
+		fn overweight_messages(origin: MessageOrigin) -> Vec<OverweightMessage<Size>>;
 	}
 }
 
@@ -941,15 +1029,11 @@ impl<T: Config> Pallet<T> {
 		let mut book_state = BookStateFor::<T>::get(&origin);
 		ensure!(!T::QueuePausedQuery::is_paused(&origin), Error::<T>::QueuePaused);
 
-		let mut page = Pages::<T>::get(&origin, page_index).ok_or(Error::<T>::NoPage)?;
+		let page = Pages::<T>::get(&origin, page_index).ok_or(Error::<T>::NoPage)?;
 		let (pos, is_processed, payload) =
 			page.peek_index(index.into() as usize).ok_or(Error::<T>::NoMessage)?;
 		let payload_len = payload.len() as u64;
-		ensure!(
-			page_index < book_state.begin ||
-				(page_index == book_state.begin && pos < page.first.into() as usize),
-			Error::<T>::Queued
-		);
+		ensure!(Self::is_skipped(&book_state, page_index, &page, pos), Error::<T>::Queued);
 		ensure!(!is_processed, Error::<T>::AlreadyProcessed);
 		use MessageExecutionStatus::*;
 		let mut weight_counter = WeightMeter::with_limit(weight_limit);
@@ -966,31 +1050,137 @@ impl<T: Config> Pallet<T> {
 			Overweight | InsufficientWeight => Err(Error::<T>::InsufficientWeight),
 			Unprocessable { permanent: false } => Err(Error::<T>::TemporarilyUnprocessable),
 			Unprocessable { permanent: true } | Processed => {
-				page.note_processed_at_pos(pos);
-				book_state.message_count.saturating_dec();
-				book_state.size.saturating_reduce(payload_len);
-				let page_weight = if page.remaining.is_zero() {
-					debug_assert!(
-						page.remaining_size.is_zero(),
-						"no messages remaining; no space taken; qed"
-					);
-					Pages::<T>::remove(&origin, page_index);
-					debug_assert!(book_state.count >= 1, "page exists, so book must have pages");
-					book_state.count.saturating_dec();
-					T::WeightInfo::execute_overweight_page_removed()
-				// no need to consider .first or ready ring since processing an overweight page
-				// would not alter that state.
-				} else {
-					Pages::<T>::insert(&origin, page_index, page);
-					T::WeightInfo::execute_overweight_page_updated()
-				};
-				BookStateFor::<T>::insert(&origin, &book_state);
-				T::QueueChangeHandler::on_queue_changed(origin, book_state.into());
+				let page_weight = Self::remove_skipped_message(
+					origin,
+					page_index,
+					page,
+					pos,
+					payload_len,
+					&mut book_state,
+				);
 				Ok(weight_counter.consumed().saturating_add(page_weight))
 			},
 		}
 	}
 
+	/// Discard a single message that was marked as overweight without executing it.
+	pub fn do_discard_overweight(
+		origin: MessageOriginOf<T>,
+		page_index: PageIndex,
+		index: T::Size,
+	) -> Result<Weight, Error<T>> {
+		match with_service_mutex(|| Self::do_discard_overweight_inner(origin, page_index, index)) {
+			Err(()) => Err(Error::<T>::RecursiveDisallowed),
+			Ok(x) => x,
+		}
+	}
+
+	/// Same as `do_discard_overweight` but must be called while holding the `service_mutex`.
+	fn do_discard_overweight_inner(
+		origin: MessageOriginOf<T>,
+		page_index: PageIndex,
+		index: T::Size,
+	) -> Result<Weight, Error<T>> {
+		let mut book_state = BookStateFor::<T>::get(&origin);
+		let page = Pages::<T>::get(&origin, page_index).ok_or(Error::<T>::NoPage)?;
+		let (pos, is_processed, payload) =
+			page.peek_index(index.into() as usize).ok_or(Error::<T>::NoMessage)?;
+		ensure!(Self::is_skipped(&book_state, page_index, &page, pos), Error::<T>::Queued);
+		ensure!(!is_processed, Error::<T>::AlreadyProcessed);
+
+		let id = sp_io::hashing::blake2_256(payload);
+		let size = payload.len() as u32;
+		let page_weight = Self::remove_skipped_message(
+			origin.clone(),
+			page_index,
+			page,
+			pos,
+			size as u64,
+			&mut book_state,
+		);
+		Self::deposit_event(Event::<T>::OverweightDiscarded {
+			id,
+			origin,
+			page_index,
+			message_index: index,
+			size,
+		});
+		Ok(page_weight)
+	}
+
+	/// Whether the message at `pos` of the page `page_index` was skipped by the servicing of the
+	/// queue, i.e. it is not queued for future execution.
+	fn is_skipped(
+		book_state: &BookStateOf<T>,
+		page_index: PageIndex,
+		page: &PageOf<T>,
+		pos: usize,
+	) -> bool {
+		page_index < book_state.begin ||
+			(page_index == book_state.begin && pos < page.first.into() as usize)
+	}
+
+	/// Mark the skipped message at `pos` of `page` as processed and store the changes.
+	///
+	/// Returns the weight of updating or removing the page.
+	fn remove_skipped_message(
+		origin: MessageOriginOf<T>,
+		page_index: PageIndex,
+		mut page: PageOf<T>,
+		pos: usize,
+		payload_len: u64,
+		book_state: &mut BookStateOf<T>,
+	) -> Weight {
+		page.note_processed_at_pos(pos);
+		book_state.message_count.saturating_dec();
+		book_state.size.saturating_reduce(payload_len);
+		let page_weight = if page.remaining.is_zero() {
+			debug_assert!(
+				page.remaining_size.is_zero(),
+				"no messages remaining; no space taken; qed"
+			);
+			Pages::<T>::remove(&origin, page_index);
+			debug_assert!(book_state.count >= 1, "page exists, so book must have pages");
+			book_state.count.saturating_dec();
+			T::WeightInfo::execute_overweight_page_removed()
+		// no need to consider .first or ready ring since processing an overweight page
+		// would not alter that state.
+		} else {
+			Pages::<T>::insert(&origin, page_index, page);
+			T::WeightInfo::execute_overweight_page_updated()
+		};
+		BookStateFor::<T>::insert(&origin, &*book_state);
+		T::QueueChangeHandler::on_queue_changed(origin, book_state.clone().into());
+		page_weight
+	}
+
+	/// Returns the overweight messages of the queue of `origin` that were neither executed nor
+	/// discarded yet, ordered by their position in the queue.
+	///
+	/// Iterates over all pages of the queue and should therefore only be called off-chain.
+	pub fn overweight_messages(origin: &MessageOriginOf<T>) -> Vec<OverweightMessage<T::Size>> {
+		let book_state = BookStateFor::<T>::get(origin);
+		let mut messages = Vec::new();
+		for (page_index, page) in Pages::<T>::iter_prefix(origin) {
+			let items = page.peek_all();
+			for (index, (pos, is_processed, payload)) in items.into_iter().enumerate() {
+				if !Self::is_skipped(&book_state, page_index, &page, pos) {
+					break
+				}
+				if !is_processed {
+					messages.push(OverweightMessage {
+						id: sp_io::hashing::blake2_256(payload),
+						page_index,
+						message_index: index.saturated_into(),
+						size: payload.len() as u32,
+					});
+				}
+			}
+		}
+		messages.sort_by_key(|m| (m.page_index, m.message_index));
+		messages
+	}
+
 	/// Remove a stale page or one which has no more messages remaining to be processed.
 	fn do_reap_page(origin: &MessageOriginOf<T>, page_index: PageIndex) -> DispatchResult {
 		match with_service_mutex(|| Self::do_reap_page_inner(origin, page_index)) {
@@ -1421,6 +1611,7 @@ impl<T: Config> Pallet<T> {
 					origin,
 					page_index,
 					message_index,
+					size: message.len() as u32,
 				});
 				MessageExecutionStatus::Overweight
 			},
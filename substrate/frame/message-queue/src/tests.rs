@@ -299,6 +299,7 @@ fn service_queues_regression_1873() {
 				origin: MessageOrigin::Here,
 				message_index: 0,
 				page_index: 0,
+				size: 10,
 			}
 			.into(),
 		);
@@ -711,6 +712,7 @@ fn service_page_item_skips_perm_overweight_message() {
 				origin: MessageOrigin::Here,
 				message_index: 0,
 				page_index: 0,
+				size: 7,
 			}
 			.into(),
 		);
@@ -1113,6 +1115,7 @@ fn execute_overweight_works() {
 				origin: MessageOrigin::Here,
 				message_index: 0,
 				page_index: 0,
+				size: 8,
 			}
 			.into(),
 		);
@@ -1147,6 +1150,136 @@ fn execute_overweight_works() {
 	});
 }
 
+#[test]
+fn discard_overweight_works() {
+	build_and_execute::<Test>(|| {
+		set_weight("bump_service_head", 1.into_weight());
+		set_weight("service_queue_base", 1.into_weight());
+		set_weight("service_page_base_completion", 1.into_weight());
+
+		let origin = MessageOrigin::Here;
+		MessageQueue::enqueue_messages([msg("weight=6"), msg("weight=7")].into_iter(), origin);
+		// Mark both messages as permanently overweight.
+		MessageQueue::service_queues(5.into_weight());
+		assert_eq!(BookStateFor::<Test>::get(origin).message_count, 2);
+		QueueChanges::take();
+
+		// Only root can discard.
+		assert_noop!(
+			MessageQueue::discard_overweight(RuntimeOrigin::signed(1), origin, 0, 0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			MessageQueue::discard_overweight(RuntimeOrigin::root(), origin, 0, 2),
+			Error::<Test>::NoMessage
+		);
+
+		assert_ok!(MessageQueue::discard_overweight(RuntimeOrigin::root(), origin, 0, 0));
+		assert_last_event::<Test>(
+			Event::OverweightDiscarded {
+				id: blake2_256(b"weight=6"),
+				origin,
+				page_index: 0,
+				message_index: 0,
+				size: 8,
+			}
+			.into(),
+		);
+		assert_eq!(QueueChanges::take(), vec![(origin, 1, 8)]);
+		assert!(MessagesProcessed::take().is_empty());
+		assert_noop!(
+			MessageQueue::discard_overweight(RuntimeOrigin::root(), origin, 0, 0),
+			Error::<Test>::AlreadyProcessed
+		);
+
+		// Discarding the last message removes the page.
+		assert_ok!(MessageQueue::discard_overweight(RuntimeOrigin::root(), origin, 0, 1));
+		assert_eq!(QueueChanges::take(), vec![(origin, 0, 0)]);
+		assert!(!Pages::<Test>::contains_key(origin, 0));
+		assert_eq!(BookStateFor::<Test>::get(origin).count, 0);
+	});
+}
+
+#[test]
+fn discard_overweight_works_while_paused() {
+	build_and_execute::<Test>(|| {
+		set_weight("bump_service_head", 1.into_weight());
+		set_weight("service_queue_base", 1.into_weight());
+		set_weight("service_page_base_completion", 1.into_weight());
+
+		let origin = MessageOrigin::Here;
+		MessageQueue::enqueue_message(msg("weight=6"), origin);
+		MessageQueue::service_queues(4.into_weight());
+
+		PausedQueues::set(vec![origin]);
+		assert_noop!(
+			MessageQueue::execute_overweight(
+				RuntimeOrigin::signed(1),
+				origin,
+				0,
+				0,
+				7.into_weight()
+			),
+			Error::<Test>::QueuePaused
+		);
+		assert_ok!(MessageQueue::discard_overweight(RuntimeOrigin::root(), origin, 0, 0));
+		assert!(!Pages::<Test>::contains_key(origin, 0));
+	});
+}
+
+#[test]
+fn discard_overweight_fails_for_queued_message() {
+	build_and_execute::<Test>(|| {
+		let origin = MessageOrigin::Here;
+		MessageQueue::enqueue_message(msg("weight=6"), origin);
+
+		assert_noop!(
+			MessageQueue::discard_overweight(RuntimeOrigin::root(), origin, 0, 0),
+			Error::<Test>::Queued
+		);
+	});
+}
+
+#[test]
+fn overweight_messages_works() {
+	build_and_execute::<Test>(|| {
+		set_weight("bump_service_head", 1.into_weight());
+		set_weight("service_queue_base", 1.into_weight());
+		set_weight("service_page_base_completion", 1.into_weight());
+
+		let origin = MessageOrigin::Here;
+		MessageQueue::enqueue_messages(
+			[msg("weight=6"), msg("weight=1"), msg("weight=7"), msg("weight=8")].into_iter(),
+			origin,
+		);
+		assert!(MessageQueue::overweight_messages(&origin).is_empty());
+
+		// Only the light message is processed, the others are overweight.
+		MessageQueue::service_queues(6.into_weight());
+		assert_eq!(MessagesProcessed::take().len(), 1);
+		let overweight = |message_index, data: &[u8]| OverweightMessage {
+			id: blake2_256(data),
+			page_index: 0,
+			message_index,
+			size: data.len() as u32,
+		};
+		assert_eq!(
+			MessageQueue::overweight_messages(&origin),
+			vec![
+				overweight(0, b"weight=6"),
+				overweight(2, b"weight=7"),
+				overweight(3, b"weight=8")
+			]
+		);
+
+		assert_ok!(MessageQueue::discard_overweight(RuntimeOrigin::root(), origin, 0, 2));
+		assert_eq!(
+			MessageQueue::overweight_messages(&origin),
+			vec![overweight(0, b"weight=6"), overweight(3, b"weight=8")]
+		);
+	});
+}
+
 #[test]
 fn permanently_overweight_book_unknits() {
 	use MessageOrigin::*;
@@ -1168,6 +1301,7 @@ fn permanently_overweight_book_unknits() {
 				origin: Here,
 				message_index: 0,
 				page_index: 0,
+				size: 8,
 			}
 			.into(),
 		);
@@ -1569,6 +1703,7 @@ fn execute_overweight_respects_suspension() {
 				origin,
 				message_index: 0,
 				page_index: 0,
+				size: 8,
 			}
 			.into(),
 		);
@@ -1761,6 +1896,7 @@ fn recursive_overweight_while_service_is_forbidden() {
 					origin: There,
 					message_index: 0,
 					page_index: 0,
+					size: 9,
 				}
 				.into(),
 			);
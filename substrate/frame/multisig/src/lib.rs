@@ -85,7 +85,8 @@ macro_rules! log {
 	};
 }
 
-type BalanceOf<T> =
+/// Balance type of the currency used for multisig deposits.
+pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 /// A global extrinsic index, formed as the extrinsic index within a block, together with that
@@ -118,6 +119,27 @@ where
 	approvals: BoundedVec<AccountId, MaxApprovals>,
 }
 
+impl<BlockNumber, Balance: Copy, AccountId, MaxApprovals>
+	Multisig<BlockNumber, Balance, AccountId, MaxApprovals>
+where
+	MaxApprovals: Get<u32>,
+{
+	/// The amount held in reserve of the `depositor`.
+	pub fn deposit(&self) -> Balance {
+		self.deposit
+	}
+
+	/// The account who opened the operation.
+	pub fn depositor(&self) -> &AccountId {
+		&self.depositor
+	}
+
+	/// The approvals achieved so far, including the depositor.
+	pub fn approvals(&self) -> &[AccountId] {
+		&self.approvals
+	}
+}
+
 type CallHash = [u8; 32];
 
 enum CallOrHash<T: Config> {
@@ -25,6 +25,7 @@ use pallet_nomination_pools::PoolId;
 
 sp_api::decl_runtime_apis! {
 	/// Runtime api for accessing information about nomination pools.
+	#[api_version(2)]
 	pub trait NominationPoolsApi<AccountId, Balance>
 		where
 			AccountId: Codec,
@@ -38,5 +39,10 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns the equivalent points of `new_funds` for a given pool.
 		fn balance_to_points(pool_id: PoolId, new_funds: Balance) -> Balance;
+
+		/// Returns the total balance of the member that the AccountId was given for, both active
+		/// and unbonding.
+		#[api_version(2)]
+		fn member_total_balance(who: AccountId) -> Balance;
 	}
 }
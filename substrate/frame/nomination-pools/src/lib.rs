@@ -540,9 +540,10 @@ impl<T: Config> PoolMember<T> {
 
 	/// Total balance of the member, both active and unbonding.
 	/// Doesn't mutate state.
-	#[cfg(any(feature = "try-runtime", feature = "fuzzing", test, debug_assertions))]
 	fn total_balance(&self) -> BalanceOf<T> {
-		let pool = BondedPool::<T>::get(self.pool_id).unwrap();
+		let Some(pool) = BondedPool::<T>::get(self.pool_id).defensive() else {
+			return Zero::zero()
+		};
 		let active_balance = pool.points_to_balance(self.active_points());
 
 		let sub_pools = match SubPoolsStorage::<T>::get(self.pool_id) {
@@ -556,11 +557,11 @@ impl<T: Config> PoolMember<T> {
 				// if the `SubPools::with_era` has already been merged into the
 				// `SubPools::no_era` use this pool instead.
 				let era_pool = sub_pools.with_era.get(era).unwrap_or(&sub_pools.no_era);
-				accumulator + (era_pool.point_to_balance(*unlocked_points))
+				accumulator.saturating_add(era_pool.point_to_balance(*unlocked_points))
 			},
 		);
 
-		active_balance + unbonding_balance
+		active_balance.saturating_add(unbonding_balance)
 	}
 
 	/// Total points of this member, both active and unbonding.
@@ -3548,6 +3549,15 @@ impl<T: Config> Pallet<T> {
 			Zero::zero()
 		}
 	}
+
+	/// Returns the total balance of the specified `who` account, both active and unbonding.
+	///
+	/// If `who` is not a pool member, returns 0. Used by runtime API.
+	pub fn api_member_total_balance(who: T::AccountId) -> BalanceOf<T> {
+		PoolMembers::<T>::get(who)
+			.map(|member| member.total_balance())
+			.unwrap_or_default()
+	}
 }
 
 impl<T: Config> sp_staking::OnStakingUpdate<T::AccountId, BalanceOf<T>> for Pallet<T> {
@@ -247,6 +247,27 @@ mod bonded_pool {
 		})
 	}
 
+	#[test]
+	fn api_member_total_balance_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			Currency::set_balance(&20, Currency::minimum_balance() + 10);
+			assert_ok!(Pools::join(RuntimeOrigin::signed(20), 10, 1));
+			assert_eq!(Pallet::<Runtime>::api_member_total_balance(20), 10);
+
+			// unbonding balance is included.
+			assert_ok!(Pools::unbond(RuntimeOrigin::signed(20), 20, 4));
+			assert_eq!(Pallet::<Runtime>::api_member_total_balance(20), 10);
+
+			// slash half of the pool's bonded balance. the active balance of 20 halves, while
+			// its unbonding balance is not affected.
+			StakingMock::slash_by(1, 8);
+			assert_eq!(Pallet::<Runtime>::api_member_total_balance(20), 3 + 4);
+
+			// if `who` is not a member, the total balance is 0.
+			assert_eq!(Pallet::<Runtime>::api_member_total_balance(30), 0);
+		})
+	}
+
 	#[test]
 	fn ok_to_join_with_works() {
 		ExtBuilder::default().build_and_execute(|| {
@@ -92,6 +92,13 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The kind and the session of offences, keyed by report identifiers.
+	///
+	/// Only recorded for reports that have been made after this storage item was introduced.
+	#[pallet::storage]
+	pub type ReportSessions<T: Config> =
+		StorageMap<_, Twox64Concat, ReportIdOf<T>, (Kind, SessionIndex)>;
+
 	/// Events type.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -114,12 +121,16 @@ where
 
 		// Go through all offenders in the offence report and find all offenders that were spotted
 		// in unique reports.
-		let TriageOutcome { concurrent_offenders } =
-			match Self::triage_offence_report::<O>(reporters, &time_slot, offenders) {
-				Some(triage) => triage,
-				// The report contained only duplicates, so there is no need to slash again.
-				None => return Err(OffenceError::DuplicateReport),
-			};
+		let TriageOutcome { concurrent_offenders } = match Self::triage_offence_report::<O>(
+			reporters,
+			&time_slot,
+			offence.session_index(),
+			offenders,
+		) {
+			Some(triage) => triage,
+			// The report contained only duplicates, so there is no need to slash again.
+			None => return Err(OffenceError::DuplicateReport),
+		};
 
 		let offenders_count = concurrent_offenders.len() as u32;
 
@@ -152,6 +163,19 @@ where
 }
 
 impl<T: Config> Pallet<T> {
+	/// All reported offences together with their kind and the session they happened in.
+	///
+	/// Offences reported before [`ReportSessions`] was introduced are not included. This iterates
+	/// over all reports, so it should only be used off-chain, e.g. from a runtime API.
+	pub fn offences(
+	) -> Vec<(Kind, SessionIndex, OffenceDetails<T::AccountId, T::IdentificationTuple>)> {
+		ReportSessions::<T>::iter()
+			.filter_map(|(report_id, (kind, session_index))| {
+				Reports::<T>::get(report_id).map(|details| (kind, session_index, details))
+			})
+			.collect()
+	}
+
 	/// Compute the ID for the given report properties.
 	///
 	/// The report id depends on the offence kind, time slot and the id of offender.
@@ -167,6 +191,7 @@ impl<T: Config> Pallet<T> {
 	fn triage_offence_report<O: Offence<T::IdentificationTuple>>(
 		reporters: Vec<T::AccountId>,
 		time_slot: &O::TimeSlot,
+		session_index: SessionIndex,
 		offenders: Vec<T::IdentificationTuple>,
 	) -> Option<TriageOutcome<T>> {
 		let mut storage = ReportIndexStorage::<T, O>::load(time_slot);
@@ -181,6 +206,7 @@ impl<T: Config> Pallet<T> {
 					&report_id,
 					OffenceDetails { offender, reporters: reporters.clone() },
 				);
+				<ReportSessions<T>>::insert(&report_id, (O::ID, session_index));
 
 				storage.insert(report_id);
 			}
@@ -245,3 +245,29 @@ fn should_properly_count_offences() {
 		);
 	});
 }
+
+#[test]
+fn offences_returns_kind_and_session() {
+	new_test_ext().execute_with(|| {
+		// given
+		let offence = Offence { validator_set_count: 5, time_slot: 42, offenders: vec![5, 6] };
+
+		// when
+		Offences::report_offence(vec![1], offence.clone()).unwrap();
+
+		// then
+		let mut offences = Offences::offences();
+		offences.sort_by_key(|(_, _, details)| details.offender);
+		assert_eq!(
+			offences,
+			vec![
+				(KIND, 1, OffenceDetails { offender: 5, reporters: vec![1] }),
+				(KIND, 1, OffenceDetails { offender: 6, reporters: vec![1] }),
+			]
+		);
+
+		// duplicate reports are not recorded again.
+		assert_eq!(Offences::report_offence(vec![2], offence), Err(OffenceError::DuplicateReport));
+		assert_eq!(Offences::offences().len(), 2);
+	});
+}
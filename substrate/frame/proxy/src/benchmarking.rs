@@ -260,5 +260,19 @@ benchmarks! {
 		assert!(!Proxies::<T>::contains_key(&pure_account));
 	}
 
+	poke_deposit {
+		let caller: T::AccountId = whitelisted_caller();
+		add_proxies::<T>(T::MaxProxies::get(), Some(caller.clone()))?;
+		add_announcements::<T>(T::MaxPending::get(), Some(caller.clone()), None)?;
+		// Worst case: both deposits are re-priced and have to be reserved again.
+		Proxies::<T>::mutate(&caller, |(_, deposit)| *deposit = Zero::zero());
+		Announcements::<T>::mutate(&caller, |(_, deposit)| *deposit = Zero::zero());
+		let who = T::Lookup::unlookup(caller.clone());
+	}: _(RawOrigin::Signed(caller.clone()), who)
+	verify {
+		let (proxies, deposit) = Proxies::<T>::get(&caller);
+		assert_eq!(deposit, Pallet::<T>::deposit(proxies.len() as u32));
+	}
+
 	impl_benchmark_test_suite!(Proxy, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -30,6 +30,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
+pub mod migration;
 mod tests;
 pub mod weights;
 
@@ -37,6 +38,7 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::GetDispatchInfo,
 	ensure,
+	migrations::{reprice_reserved_deposit, RepriceOutcome},
 	traits::{Currency, Get, InstanceFilter, IsSubType, IsType, OriginTrait, ReservableCurrency},
 };
 use frame_system::{self as system, ensure_signed, pallet_prelude::BlockNumberFor};
@@ -93,6 +95,15 @@ pub struct Announcement<AccountId, Hash, BlockNumber> {
 	height: BlockNumber,
 }
 
+/// The kind of a deposit held by the pallet.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum DepositKind {
+	/// The deposit for the proxies of an account.
+	Proxies,
+	/// The deposit for the announcements of a proxy.
+	Announcements,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::{DispatchResult, *};
@@ -512,6 +523,27 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Re-price the deposits held by an account for its proxies and announcements at the
+		/// current deposit constants, reserving or unreserving the difference.
+		///
+		/// The dispatch origin for this call must be _Signed_, by any account. The call is free
+		/// if any deposit changed.
+		///
+		/// Parameters:
+		/// - `who`: The account whose deposits are re-priced.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::poke_deposit())]
+		pub fn poke_deposit(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let repriced = Self::reprice_deposits(&who)?;
+
+			Ok(if repriced { Pays::No } else { Pays::Yes }.into())
+		}
 	}
 
 	#[pallet::event]
@@ -543,6 +575,13 @@ pub mod pallet {
 			proxy_type: T::ProxyType,
 			delay: BlockNumberFor<T>,
 		},
+		/// A deposit was re-priced at the current deposit constants.
+		DepositPoked {
+			who: T::AccountId,
+			kind: DepositKind,
+			old_deposit: BalanceOf<T>,
+			new_deposit: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -799,6 +838,53 @@ impl<T: Config> Pallet<T> {
 		Self::deposit_event(Event::ProxyExecuted { result: e.map(|_| ()).map_err(|e| e.error) });
 	}
 
+	/// Re-price the deposits held by `who` for its proxies and announcements at the current
+	/// deposit constants, reserving or unreserving the difference.
+	///
+	/// Returns whether any deposit changed.
+	pub fn reprice_deposits(who: &T::AccountId) -> Result<bool, DispatchError> {
+		let mut repriced = false;
+		Proxies::<T>::try_mutate_exists(who, |x| -> DispatchResult {
+			if let Some((proxies, deposit)) = x {
+				let new_deposit = Self::deposit(proxies.len() as u32);
+				repriced |= Self::reprice_deposit(who, DepositKind::Proxies, deposit, new_deposit)?;
+			}
+			Ok(())
+		})?;
+		Announcements::<T>::try_mutate_exists(who, |x| -> DispatchResult {
+			if let Some((pending, deposit)) = x {
+				let new_deposit = T::AnnouncementDepositBase::get() +
+					T::AnnouncementDepositFactor::get() * (pending.len() as u32).into();
+				repriced |=
+					Self::reprice_deposit(who, DepositKind::Announcements, deposit, new_deposit)?;
+			}
+			Ok(())
+		})?;
+		Ok(repriced)
+	}
+
+	fn reprice_deposit(
+		who: &T::AccountId,
+		kind: DepositKind,
+		deposit: &mut BalanceOf<T>,
+		new_deposit: BalanceOf<T>,
+	) -> Result<bool, DispatchError> {
+		let old_deposit = *deposit;
+		if reprice_reserved_deposit::<_, T::Currency>(who, old_deposit, new_deposit)? ==
+			RepriceOutcome::Unchanged
+		{
+			return Ok(false)
+		}
+		*deposit = new_deposit;
+		Self::deposit_event(Event::DepositPoked {
+			who: who.clone(),
+			kind,
+			old_deposit,
+			new_deposit,
+		});
+		Ok(true)
+	}
+
 	/// Removes all proxy delegates for a given delegator.
 	///
 	/// Parameters:
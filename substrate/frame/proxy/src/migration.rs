@@ -0,0 +1,52 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Migrations of the proxy pallet.
+
+use super::*;
+use frame_support::{migrations::RepriceDepositsConfig, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// The deposits of the proxy pallet, to re-price them with
+/// [`frame_support::migrations::RepriceDeposits`].
+pub struct ProxyDeposits<T>(PhantomData<T>);
+
+impl<T: Config> RepriceDepositsConfig for ProxyDeposits<T> {
+	type AccountId = T::AccountId;
+	type DbWeight = T::DbWeight;
+
+	fn accounts() -> (Vec<T::AccountId>, Weight) {
+		let mut accounts: Vec<_> =
+			Proxies::<T>::iter_keys().chain(Announcements::<T>::iter_keys()).collect();
+		let weight = T::DbWeight::get().reads(accounts.len() as u64);
+		accounts.sort();
+		accounts.dedup();
+		(accounts, weight)
+	}
+
+	fn reprice(who: &T::AccountId) -> DispatchResult {
+		Pallet::<T>::reprice_deposits(who).map(|_| ())
+	}
+
+	fn reprice_weight() -> Weight {
+		T::WeightInfo::poke_deposit()
+	}
+}
+
+/// Re-prices all deposits of the proxy pallet at the current deposit constants, to be run after
+/// they changed.
+pub type RepriceDeposits<T> = frame_support::migrations::RepriceDeposits<ProxyDeposits<T>>;
@@ -25,6 +25,7 @@ use crate as proxy;
 use codec::{Decode, Encode};
 use frame_support::{
 	assert_noop, assert_ok, derive_impl,
+	dispatch::Pays,
 	traits::{ConstU32, ConstU64, Contains},
 };
 use sp_core::H256;
@@ -590,3 +591,74 @@ fn pure_works() {
 		);
 	});
 }
+
+#[test]
+fn poke_deposit_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 1));
+		assert_ok!(Proxy::announce(RuntimeOrigin::signed(3), 1, [1; 32].into()));
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_eq!(Balances::reserved_balance(3), 2);
+
+		// nothing to re-price, the caller pays.
+		let info = Proxy::poke_deposit(RuntimeOrigin::signed(4), 1).unwrap();
+		assert_eq!(info.pays_fee, Pays::Yes);
+
+		// deposits reserved with older deposit constants: 1 paid too much, 3 too little.
+		Proxies::<Test>::mutate(1, |(_, deposit)| *deposit = 5);
+		assert_ok!(Balances::reserve(&1, 2));
+		Announcements::<Test>::mutate(3, |(_, deposit)| *deposit = 1);
+		Balances::unreserve(&3, 1);
+
+		let info = Proxy::poke_deposit(RuntimeOrigin::signed(4), 1).unwrap();
+		assert_eq!(info.pays_fee, Pays::No);
+		System::assert_last_event(
+			ProxyEvent::DepositPoked {
+				who: 1,
+				kind: DepositKind::Proxies,
+				old_deposit: 5,
+				new_deposit: 3,
+			}
+			.into(),
+		);
+		assert_eq!(Proxies::<Test>::get(1).1, 3);
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		assert_ok!(Proxy::poke_deposit(RuntimeOrigin::signed(4), 3));
+		System::assert_last_event(
+			ProxyEvent::DepositPoked {
+				who: 3,
+				kind: DepositKind::Announcements,
+				old_deposit: 1,
+				new_deposit: 2,
+			}
+			.into(),
+		);
+		assert_eq!(Announcements::<Test>::get(3).1, 2);
+		assert_eq!(Balances::reserved_balance(3), 2);
+
+		assert_noop!(Proxy::poke_deposit(RuntimeOrigin::none(), 1), DispatchError::BadOrigin);
+	});
+}
+
+#[test]
+fn reprice_deposits_migration_works() {
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 1));
+		assert_ok!(Proxy::announce(RuntimeOrigin::signed(3), 1, [1; 32].into()));
+		Proxies::<Test>::mutate(1, |(_, deposit)| *deposit = 0);
+		Balances::unreserve(&1, 2);
+		Announcements::<Test>::mutate(3, |(_, deposit)| *deposit = 4);
+		assert_ok!(Balances::reserve(&3, 2));
+
+		migration::RepriceDeposits::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Proxies::<Test>::get(1).1, 2);
+		assert_eq!(Balances::reserved_balance(1), 2);
+		assert_eq!(Announcements::<Test>::get(3).1, 2);
+		assert_eq!(Balances::reserved_balance(3), 2);
+	});
+}
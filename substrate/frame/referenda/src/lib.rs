@@ -137,6 +137,11 @@ macro_rules! impl_tracksinfo_get {
 
 const ASSEMBLY_ID: LockIdentifier = *b"assembly";
 
+/// The maximum number of referenda woken up by a single batched alarm.
+///
+/// Referenda whose alarm falls on a block with a full batch get an alarm of their own.
+pub const MAX_ALARM_BATCH: u32 = 16;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -275,6 +280,18 @@ pub mod pallet {
 	pub type MetadataOf<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, ReferendumIndex, T::Hash>;
 
+	/// The referenda sharing a single scheduled alarm, keyed by the block number it goes off at.
+	///
+	/// The referenda are nudged by one `nudge_referenda` call rather than having an agenda item
+	/// of their own in the scheduler.
+	#[pallet::storage]
+	pub type AlarmBatches<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		(ScheduleAddressOf<T, I>, BoundedVec<ReferendumIndex, ConstU32<MAX_ALARM_BATCH>>),
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -424,6 +441,8 @@ pub mod pallet {
 		BadStatus,
 		/// The preimage does not exist.
 		PreimageNotExist,
+		/// No referenda are waiting for an alarm at the given block.
+		NoAlarmBatch,
 	}
 
 	#[pallet::hooks]
@@ -466,9 +485,7 @@ pub mod pallet {
 				r
 			});
 			let now = frame_system::Pallet::<T>::block_number();
-			let nudge_call =
-				T::Preimages::bound(CallOf::<T, I>::from(Call::nudge_referendum { index }))?;
-			let status = ReferendumStatus {
+			let mut status = ReferendumStatus {
 				track,
 				origin: proposal_origin,
 				proposal: proposal.clone(),
@@ -479,8 +496,13 @@ pub mod pallet {
 				deciding: None,
 				tally: TallyOf::<T, I>::new(track),
 				in_queue: false,
-				alarm: Self::set_alarm(nudge_call, now.saturating_add(T::UndecidingTimeout::get())),
+				alarm: None,
 			};
+			Self::ensure_alarm_at(
+				&mut status,
+				index,
+				now.saturating_add(T::UndecidingTimeout::get()),
+			);
 			ReferendumInfoFor::<T, I>::insert(index, ReferendumInfo::Ongoing(status));
 
 			Self::deposit_event(Event::<T, I>::Submitted { index, track, proposal });
@@ -557,10 +579,8 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::cancel())]
 		pub fn cancel(origin: OriginFor<T>, index: ReferendumIndex) -> DispatchResult {
 			T::CancelOrigin::ensure_origin(origin)?;
-			let status = Self::ensure_ongoing(index)?;
-			if let Some((_, last_alarm)) = status.alarm {
-				let _ = T::Scheduler::cancel(last_alarm);
-			}
+			let mut status = Self::ensure_ongoing(index)?;
+			Self::ensure_no_alarm(&mut status, index);
 			Self::note_one_fewer_deciding(status.track);
 			Self::deposit_event(Event::<T, I>::Cancelled { index, tally: status.tally });
 			let info = ReferendumInfo::Cancelled(
@@ -582,10 +602,8 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::kill())]
 		pub fn kill(origin: OriginFor<T>, index: ReferendumIndex) -> DispatchResult {
 			T::KillOrigin::ensure_origin(origin)?;
-			let status = Self::ensure_ongoing(index)?;
-			if let Some((_, last_alarm)) = status.alarm {
-				let _ = T::Scheduler::cancel(last_alarm);
-			}
+			let mut status = Self::ensure_ongoing(index)?;
+			Self::ensure_no_alarm(&mut status, index);
 			Self::note_one_fewer_deciding(status.track);
 			Self::deposit_event(Event::<T, I>::Killed { index, tally: status.tally });
 			Self::slash_deposit(Some(status.submission_deposit.clone()));
@@ -609,8 +627,11 @@ pub mod pallet {
 			ensure_root(origin)?;
 			let now = frame_system::Pallet::<T>::block_number();
 			let mut status = Self::ensure_ongoing(index)?;
-			// This is our wake-up, so we can disregard the alarm.
-			status.alarm = None;
+			// This is our wake-up, so we can disregard the alarm, but the referendum should no
+			// longer be woken up by a batched alarm.
+			if let Some((when, alarm)) = status.alarm.take() {
+				Self::leave_alarm_batch(index, when, &alarm);
+			}
 			let (info, dirty, branch) = Self::service_referendum(now, index, status);
 			if dirty {
 				ReferendumInfoFor::<T, I>::insert(index, info);
@@ -718,6 +739,39 @@ pub mod pallet {
 				Ok(())
 			}
 		}
+
+		/// Advance all referenda whose alarms were batched for a block. Only used internally.
+		///
+		/// - `origin`: must be `Root`.
+		/// - `when`: the block number the batched alarm was set for.
+		#[pallet::call_index(9)]
+		#[pallet::weight(
+			ServiceBranch::max_weight_of_nudge::<T, I>().saturating_mul(MAX_ALARM_BATCH.into())
+		)]
+		pub fn nudge_referenda(
+			origin: OriginFor<T>,
+			when: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let (_, indices) =
+				AlarmBatches::<T, I>::take(when).ok_or(Error::<T, I>::NoAlarmBatch)?;
+			let mut weight = Weight::zero();
+			for index in indices {
+				let mut status = match Self::ensure_ongoing(index) {
+					Ok(s) => s,
+					Err(_) => continue,
+				};
+				// This is our wake-up, so we can disregard the alarm.
+				status.alarm = None;
+				let (info, dirty, branch) = Self::service_referendum(now, index, status);
+				if dirty {
+					ReferendumInfoFor::<T, I>::insert(index, info);
+				}
+				weight.saturating_accrue(branch.weight_of_nudge::<T, I>());
+			}
+			Ok(Some(weight).into())
+		}
 	}
 }
 
@@ -806,7 +860,7 @@ impl<T: Config<I>, I: 'static> Polling<T::Tally> for Pallet<T, I> {
 	#[cfg(feature = "runtime-benchmarks")]
 	fn end_ongoing(index: Self::Index, approved: bool) -> Result<(), ()> {
 		let mut status = Self::ensure_ongoing(index).map_err(|_| ())?;
-		Self::ensure_no_alarm(&mut status);
+		Self::ensure_no_alarm(&mut status, index);
 		Self::note_one_fewer_deciding(status.track);
 		let now = frame_system::Pallet::<T>::block_number();
 		let info = if approved {
@@ -888,17 +942,21 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		debug_assert!(ok, "LOGIC ERROR: bake_referendum/schedule_named failed");
 	}
 
+	/// The block number an alarm set for `when` actually goes off at.
+	fn alarm_block(when: BlockNumberFor<T>) -> BlockNumberFor<T> {
+		let alarm_interval = T::AlarmInterval::get().max(One::one());
+		// Alarm must go off no earlier than `when`.
+		// This rounds `when` upwards to the next multiple of `alarm_interval`.
+		(when.saturating_add(alarm_interval.saturating_sub(One::one())) / alarm_interval)
+			.saturating_mul(alarm_interval)
+	}
+
 	/// Set an alarm to dispatch `call` at block number `when`.
 	fn set_alarm(
 		call: BoundedCallOf<T, I>,
 		when: BlockNumberFor<T>,
 	) -> Option<(BlockNumberFor<T>, ScheduleAddressOf<T, I>)> {
-		let alarm_interval = T::AlarmInterval::get().max(One::one());
-		// Alarm must go off no earlier than `when`.
-		// This rounds `when` upwards to the next multiple of `alarm_interval`.
-		let when = (when.saturating_add(alarm_interval.saturating_sub(One::one())) /
-			alarm_interval)
-			.saturating_mul(alarm_interval);
+		let when = Self::alarm_block(when);
 		let result = T::Scheduler::schedule(
 			DispatchTime::At(when),
 			None,
@@ -1018,7 +1076,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 	/// Ensure that a `service_referendum` alarm happens for the referendum `index` at `alarm`.
 	///
-	/// This will do nothing if the alarm is already set.
+	/// This will do nothing if the alarm is already set to go off at the same block. Otherwise
+	/// the referendum joins the batch of referenda woken up at that block.
 	///
 	/// Returns `false` if nothing changed.
 	fn ensure_alarm_at(
@@ -1026,27 +1085,72 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		index: ReferendumIndex,
 		alarm: BlockNumberFor<T>,
 	) -> bool {
+		let alarm = Self::alarm_block(alarm);
 		if status.alarm.as_ref().map_or(true, |&(when, _)| when != alarm) {
 			// Either no alarm or one that was different
-			Self::ensure_no_alarm(status);
-			let call =
-				match T::Preimages::bound(CallOf::<T, I>::from(Call::nudge_referendum { index })) {
-					Ok(c) => c,
-					Err(_) => {
-						debug_assert!(
-							false,
-							"Unable to create a bounded call from `nudge_referendum`??",
-						);
-						return false
-					},
-				};
-			status.alarm = Self::set_alarm(call, alarm);
+			Self::ensure_no_alarm(status, index);
+			status.alarm = Self::join_alarm_batch(index, alarm);
 			true
 		} else {
 			false
 		}
 	}
 
+	/// Add the referendum `index` to the batch of referenda woken up at block `when`, scheduling
+	/// the batch's alarm if there is none yet.
+	///
+	/// The referendum gets an alarm of its own if the batch is full.
+	fn join_alarm_batch(
+		index: ReferendumIndex,
+		when: BlockNumberFor<T>,
+	) -> Option<(BlockNumberFor<T>, ScheduleAddressOf<T, I>)> {
+		let (call, new_batch) = match AlarmBatches::<T, I>::get(when) {
+			Some((address, mut indices)) =>
+				if indices.try_push(index).is_ok() {
+					AlarmBatches::<T, I>::insert(when, (address.clone(), indices));
+					return Some((when, address))
+				} else {
+					(Call::nudge_referendum { index }, false)
+				},
+			None => (Call::nudge_referenda { when }, true),
+		};
+		let call = match T::Preimages::bound(CallOf::<T, I>::from(call)) {
+			Ok(c) => c,
+			Err(_) => {
+				debug_assert!(false, "Unable to create a bounded call from a referenda nudge??");
+				return None
+			},
+		};
+		let alarm = Self::set_alarm(call, when)?;
+		if new_batch {
+			let indices = BoundedVec::truncate_from(sp_std::vec![index]);
+			AlarmBatches::<T, I>::insert(when, (alarm.1.clone(), indices));
+		}
+		Some(alarm)
+	}
+
+	/// Remove the referendum `index` from the batch of referenda woken up at block `when`,
+	/// cancelling the batch's alarm once it is empty.
+	///
+	/// Returns `false` if `alarm` is not the alarm of that batch.
+	fn leave_alarm_batch(
+		index: ReferendumIndex,
+		when: BlockNumberFor<T>,
+		alarm: &ScheduleAddressOf<T, I>,
+	) -> bool {
+		AlarmBatches::<T, I>::mutate_exists(when, |maybe_batch| match maybe_batch {
+			Some((address, indices)) if *address == *alarm => {
+				indices.retain(|&i| i != index);
+				if indices.is_empty() {
+					let _ = T::Scheduler::cancel(address.clone());
+					*maybe_batch = None;
+				}
+				true
+			},
+			_ => false,
+		})
+	}
+
 	/// Advance the state of a referendum, which comes down to:
 	/// - If it's ready to be decided, start deciding;
 	/// - If it's not ready to be decided and non-deciding timeout has passed, fail;
@@ -1129,7 +1233,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				// If we didn't move into being decided, then check the timeout.
 				if status.deciding.is_none() && now >= timeout && !status.in_queue {
 					// Too long without being decided - end it.
-					Self::ensure_no_alarm(&mut status);
+					Self::ensure_no_alarm(&mut status, index);
 					Self::deposit_event(Event::<T, I>::TimedOut { index, tally: status.tally });
 					return (
 						ReferendumInfo::TimedOut(
@@ -1155,7 +1259,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					match deciding.confirming {
 						Some(t) if now >= t => {
 							// Passed!
-							Self::ensure_no_alarm(&mut status);
+							Self::ensure_no_alarm(&mut status, index);
 							Self::note_one_fewer_deciding(status.track);
 							let (desired, call) = (status.enactment, status.proposal);
 							Self::schedule_enactment(index, track, desired, status.origin, call);
@@ -1185,7 +1289,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				} else {
 					if now >= deciding.since.saturating_add(track.decision_period) {
 						// Failed!
-						Self::ensure_no_alarm(&mut status);
+						Self::ensure_no_alarm(&mut status, index);
 						Self::note_one_fewer_deciding(status.track);
 						Self::deposit_event(Event::<T, I>::Rejected { index, tally: status.tally });
 						return (
@@ -1215,7 +1319,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let dirty_alarm = if alarm < BlockNumberFor::<T>::max_value() {
 			Self::ensure_alarm_at(&mut status, index, alarm)
 		} else {
-			Self::ensure_no_alarm(&mut status)
+			Self::ensure_no_alarm(&mut status, index)
 		};
 		(ReferendumInfo::Ongoing(status), dirty_alarm || dirty, branch)
 	}
@@ -1239,11 +1343,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
-	/// Cancel the alarm in `status`, if one exists.
-	fn ensure_no_alarm(status: &mut ReferendumStatusOf<T, I>) -> bool {
-		if let Some((_, last_alarm)) = status.alarm.take() {
+	/// Cancel the alarm in `status` of the referendum `index`, if one exists.
+	///
+	/// A batched alarm is only cancelled once no other referendum is waiting for it.
+	fn ensure_no_alarm(status: &mut ReferendumStatusOf<T, I>, index: ReferendumIndex) -> bool {
+		if let Some((when, last_alarm)) = status.alarm.take() {
 			// Incorrect alarm - cancel it.
-			let _ = T::Scheduler::cancel(last_alarm);
+			if !Self::leave_alarm_batch(index, when, &last_alarm) {
+				let _ = T::Scheduler::cancel(last_alarm);
+			}
 			true
 		} else {
 			false
@@ -1331,6 +1439,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Self::try_state_referenda_info()?;
 		Self::try_state_tracks()?;
+		Self::try_state_alarm_batches()?;
 
 		Ok(())
 	}
@@ -1386,4 +1495,24 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})
 	}
+
+	/// Looking at alarm batches:
+	///
+	/// * The referenda in [`AlarmBatches`] must be ongoing and have their alarm set to the
+	///  batch's alarm.
+	#[cfg(any(feature = "try-runtime", test))]
+	fn try_state_alarm_batches() -> Result<(), sp_runtime::TryRuntimeError> {
+		AlarmBatches::<T, I>::iter().try_for_each(|(when, (address, indices))| {
+			indices
+				.iter()
+				.try_for_each(|&index| -> Result<(), sp_runtime::TryRuntimeError> {
+					let status = Self::ensure_ongoing(index)?;
+					ensure!(
+						status.alarm == Some((when, address.clone())),
+						"Batched referenda must have their alarm set to the batch's alarm"
+					);
+					Ok(())
+				})
+		})
+	}
 }
@@ -208,7 +208,7 @@ fn queueing_works() {
 		set_tally(2, 2, 20);
 		set_tally(3, 3, 30);
 		set_tally(4, 100, 0);
-		println!("Agenda #6: {:?}", pallet_scheduler::Agenda::<Test>::get(6));
+		println!("Agenda #6: {:?}", pallet_scheduler::Pallet::<Test>::agenda(6));
 		run_to(6);
 		println!("{:?}", Vec::<_>::from(TrackQueue::<Test>::get(0)));
 
@@ -285,6 +285,80 @@ fn alarm_interval_works() {
 	});
 }
 
+#[test]
+fn alarms_at_the_same_block_are_batched() {
+	ExtBuilder::default().build_and_execute(|| {
+		let live_alarms = |when: u64| {
+			pallet_scheduler::Pallet::<Test>::agenda(when)
+				.iter()
+				.filter(|t| t.is_some())
+				.count()
+		};
+		for i in 1..=3 {
+			assert_ok!(Referenda::submit(
+				RuntimeOrigin::signed(i),
+				Box::new(RawOrigin::Root.into()),
+				set_balance_proposal_bounded(i),
+				DispatchTime::At(10),
+			));
+		}
+
+		// All three referenda time out at the same block and share a single alarm.
+		let (address, indices) = AlarmBatches::<Test>::get(21).unwrap();
+		assert_eq!(indices.into_inner(), vec![0, 1, 2]);
+		assert_eq!(live_alarms(21), 1);
+		for i in 0..3 {
+			assert_eq!(Referenda::ensure_ongoing(i).unwrap().alarm, Some((21, address)));
+		}
+
+		// Cancelling one of them leaves the alarm in place for the others.
+		assert_ok!(Referenda::cancel(RuntimeOrigin::signed(4), 1));
+		assert_eq!(AlarmBatches::<Test>::get(21).unwrap().1.into_inner(), vec![0, 2]);
+		assert_eq!(live_alarms(21), 1);
+
+		// Moving on to another alarm leaves the batch.
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(4), 0));
+		assert_eq!(AlarmBatches::<Test>::get(5).unwrap().1.into_inner(), vec![0]);
+		assert_eq!(AlarmBatches::<Test>::get(21).unwrap().1.into_inner(), vec![2]);
+
+		run_to(21);
+		assert_matches!(
+			ReferendumInfoFor::<Test>::get(2),
+			Some(ReferendumInfo::TimedOut(21, _, None))
+		);
+		assert!(AlarmBatches::<Test>::get(21).is_none());
+	});
+}
+
+#[test]
+fn full_alarm_batch_falls_back_to_own_alarm() {
+	ExtBuilder::default().build_and_execute(|| {
+		for i in 0..=MAX_ALARM_BATCH {
+			assert_ok!(Referenda::submit(
+				RuntimeOrigin::signed(1),
+				Box::new(RawOrigin::Root.into()),
+				set_balance_proposal_bounded(i as u64),
+				DispatchTime::At(10),
+			));
+		}
+
+		let (address, indices) = AlarmBatches::<Test>::get(21).unwrap();
+		assert_eq!(indices.len() as u32, MAX_ALARM_BATCH);
+		let (when, own) = Referenda::ensure_ongoing(MAX_ALARM_BATCH).unwrap().alarm.unwrap();
+		assert_eq!(when, 21);
+		assert_ne!(own, address);
+
+		run_to(21);
+		for i in 0..=MAX_ALARM_BATCH {
+			assert_matches!(
+				ReferendumInfoFor::<Test>::get(i),
+				Some(ReferendumInfo::TimedOut(21, _, None))
+			);
+		}
+		assert!(AlarmBatches::<Test>::get(21).is_none());
+	});
+}
+
 #[test]
 fn decision_time_is_correct() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -54,7 +54,7 @@ fn fill_schedule<T: Config>(
 		let name = u32_to_name(i);
 		Scheduler::<T>::do_schedule_named(name, t, period, 0, origin.clone(), call)?;
 	}
-	ensure!(Agenda::<T>::get(when).len() == n as usize, "didn't fill schedule");
+	ensure!(Scheduler::<T>::agenda(when).len() == n as usize, "didn't fill schedule");
 	Ok(())
 }
 
@@ -136,10 +136,10 @@ benchmarks! {
 		assert_eq!(IncompleteSince::<T>::get(), Some(now - One::one()));
 	}
 
-	// `service_agenda` when no work is done.
+	// `service_agenda` when no work is done on a page with `s` tasks.
 	service_agenda_base {
 		let now = BLOCK_NUMBER.into();
-		let s in 0 .. T::MaxScheduledPerBlock::get();
+		let s in 0 .. T::MaxScheduledPerPage::get();
 		fill_schedule::<T>(now, s)?;
 		let mut executed = 0;
 	}: {
@@ -236,7 +236,7 @@ benchmarks! {
 	}: _(RawOrigin::Root, when, periodic, priority, call)
 	verify {
 		ensure!(
-			Agenda::<T>::get(when).len() == (s + 1) as usize,
+			Scheduler::<T>::agenda(when).len() == (s + 1) as usize,
 			"didn't add to schedule"
 		);
 	}
@@ -246,7 +246,7 @@ benchmarks! {
 		let when = BLOCK_NUMBER.into();
 
 		fill_schedule::<T>(when, s)?;
-		assert_eq!(Agenda::<T>::get(when).len(), s as usize);
+		assert_eq!(Scheduler::<T>::agenda(when).len(), s as usize);
 		let schedule_origin =
 			T::ScheduleOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 	}: _<SystemOrigin<T>>(schedule_origin, when, 0)
@@ -257,11 +257,11 @@ benchmarks! {
 		);
 		// Removed schedule is NONE
 		ensure!(
-			s == 1 || Agenda::<T>::get(when)[0].is_none(),
+			s == 1 || Scheduler::<T>::agenda(when)[0].is_none(),
 			"didn't remove from schedule if more than 1 task scheduled for `when`"
 		);
 		ensure!(
-			s > 1 || Agenda::<T>::get(when).len() == 0,
+			s > 1 || Scheduler::<T>::agenda(when).len() == 0,
 			"remove from schedule if only 1 task scheduled for `when`"
 		);
 	}
@@ -279,7 +279,7 @@ benchmarks! {
 	}: _(RawOrigin::Root, id, when, periodic, priority, call)
 	verify {
 		ensure!(
-			Agenda::<T>::get(when).len() == (s + 1) as usize,
+			Scheduler::<T>::agenda(when).len() == (s + 1) as usize,
 			"didn't add to schedule"
 		);
 	}
@@ -297,11 +297,11 @@ benchmarks! {
 		);
 		// Removed schedule is NONE
 		ensure!(
-			s == 1 || Agenda::<T>::get(when)[0].is_none(),
+			s == 1 || Scheduler::<T>::agenda(when)[0].is_none(),
 			"didn't remove from schedule if more than 1 task scheduled for `when`"
 		);
 		ensure!(
-			s > 1 || Agenda::<T>::get(when).len() == 0,
+			s > 1 || Scheduler::<T>::agenda(when).len() == 0,
 			"remove from schedule if only 1 task scheduled for `when`"
 		);
 	}
@@ -41,6 +41,17 @@
 //! If a call is scheduled using proxy or whatever mechanism which adds filter, then those filter
 //! will not be used when dispatching the schedule runtime call.
 //!
+//! ### Agenda pages
+//!
+//! The tasks scheduled for a block are stored in pages of up to
+//! [`Config::MaxScheduledPerPage`] tasks, so that servicing a block never has to decode all of its
+//! tasks at once. The index of a task within its agenda is `page * MaxScheduledPerPage + slot`.
+//!
+//! The pages of an agenda are serviced in order and the tasks of a page are executed by priority.
+//! When the weight limit is reached, the agenda is resumed at the first page that still has
+//! pending tasks in a later block, without touching the pages that were already serviced or not
+//! yet reached.
+//!
 //! ### Examples
 //!
 //! 1. Scheduling a runtime call at a specific block.
@@ -85,7 +96,7 @@ mod mock;
 mod tests;
 pub mod weights;
 
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::{DispatchResult, GetDispatchInfo, Parameter, RawOrigin},
 	ensure,
@@ -106,7 +117,7 @@ use sp_runtime::{
 	traits::{BadOrigin, Dispatchable, One, Saturating, Zero},
 	BoundedVec, DispatchError, RuntimeDebug,
 };
-use sp_std::{borrow::Borrow, cmp::Ordering, marker::PhantomData, prelude::*};
+use sp_std::{cmp::Ordering, marker::PhantomData, prelude::*};
 
 pub use pallet::*;
 pub use weights::WeightInfo;
@@ -115,6 +126,8 @@ pub use weights::WeightInfo;
 pub type PeriodicIndex = u32;
 /// The location of a scheduled task that can be used to remove it.
 pub type TaskAddress<BlockNumber> = (BlockNumber, u32);
+/// The index of a page of an agenda.
+pub type PageIndex = u32;
 
 pub type CallOrHashOf<T> =
 	MaybeHashed<<T as Config>::RuntimeCall, <T as frame_system::Config>::Hash>;
@@ -174,6 +187,36 @@ pub type ScheduledOf<T> = Scheduled<
 	<T as frame_system::Config>::AccountId,
 >;
 
+/// The pages of the agenda of a block.
+#[derive(
+	Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo,
+)]
+pub struct AgendaInfo {
+	/// The first page that was not fully serviced yet.
+	pub first: PageIndex,
+	/// The number of pages, including the ones that were already serviced.
+	pub pages: PageIndex,
+	/// The number of tasks in the agenda.
+	pub count: u32,
+}
+
+/// A task queued in the scheduler, as returned by [`Pallet::upcoming_tasks`].
+#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub struct UpcomingTask<BlockNumber, PalletsOrigin, Call> {
+	/// The location of the task in the agendas.
+	pub address: TaskAddress<BlockNumber>,
+	/// The name of the task, if it is named.
+	pub id: Option<TaskName>,
+	/// The priority of the task.
+	pub priority: schedule::Priority,
+	/// The period and the remaining number of repetitions of the task, if it is periodic.
+	pub maybe_periodic: Option<schedule::Period<BlockNumber>>,
+	/// The origin the call is going to be dispatched with.
+	pub origin: PalletsOrigin,
+	/// The call to be dispatched, or `None` if its preimage is not available.
+	pub call: Option<Call>,
+}
+
 pub(crate) trait MarginalWeightInfo: WeightInfo {
 	fn service_task(maybe_lookup_len: Option<usize>, named: bool, periodic: bool) -> Weight {
 		let base = Self::service_task_base();
@@ -199,7 +242,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -239,7 +282,9 @@ pub mod pallet {
 		/// Compare the privileges of origins.
 		///
 		/// This will be used when canceling a task, to ensure that the origin that tries
-		/// to cancel has greater or equal privileges as the origin that created the scheduled task.
+		/// to cancel has greater or equal privileges as the origin that created the scheduled task,
+		/// and when scheduling a task with another origin, to ensure that origin has lower or equal
+		/// privileges as the origin scheduling the task.
 		///
 		/// For simplicity the [`EqualPrivilegeOnly`](frame_support::traits::EqualPrivilegeOnly) can
 		/// be used. This will only check if two given origins are equal.
@@ -253,6 +298,12 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxScheduledPerBlock: Get<u32>;
 
+		/// The maximum number of scheduled calls in a single page of an agenda.
+		///
+		/// The tasks of a block are loaded one page at a time when the block is serviced.
+		#[pallet::constant]
+		type MaxScheduledPerPage: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -263,13 +314,20 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type IncompleteSince<T: Config> = StorageValue<_, BlockNumberFor<T>>;
 
-	/// Items to be executed, indexed by the block number that they should be executed on.
+	/// The pages of the agendas, indexed by the block number that they should be executed on.
 	#[pallet::storage]
-	pub type Agenda<T: Config> = StorageMap<
+	pub type Agendas<T: Config> = StorageMap<_, Twox64Concat, BlockNumberFor<T>, AgendaInfo>;
+
+	/// Items to be executed, indexed by the block number that they should be executed on and the
+	/// page of its agenda.
+	#[pallet::storage]
+	pub type AgendaPages<T: Config> = StorageDoubleMap<
 		_,
 		Twox64Concat,
 		BlockNumberFor<T>,
-		BoundedVec<Option<ScheduledOf<T>>, T::MaxScheduledPerBlock>,
+		Twox64Concat,
+		PageIndex,
+		BoundedVec<Option<ScheduledOf<T>>, T::MaxScheduledPerPage>,
 		ValueQuery,
 	>;
 
@@ -315,6 +373,8 @@ pub mod pallet {
 		RescheduleNoChange,
 		/// Attempt to use a non-named function on a named task.
 		Named,
+		/// The origin to dispatch a task with has more privileges than the origin scheduling it.
+		PrivilegeEscalation,
 	}
 
 	#[pallet::hooks]
@@ -325,6 +385,15 @@ pub mod pallet {
 			Self::service_agendas(&mut weight_counter, now, u32::max_value());
 			weight_counter.consumed()
 		}
+
+		fn integrity_test() {
+			assert!(T::MaxScheduledPerPage::get() > 0, "pages must be able to hold a task");
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call]
@@ -440,6 +509,60 @@ pub mod pallet {
 			)?;
 			Ok(())
 		}
+
+		/// Anonymously schedule a task to be dispatched with `as_origin`.
+		///
+		/// `as_origin` must have lower or equal privileges as the origin scheduling the task.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule(T::MaxScheduledPerBlock::get()))]
+		pub fn schedule_as(
+			origin: OriginFor<T>,
+			as_origin: Box<T::PalletsOrigin>,
+			when: BlockNumberFor<T>,
+			maybe_periodic: Option<schedule::Period<BlockNumberFor<T>>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::ensure_not_escalating(origin.caller(), &as_origin)?;
+			Self::do_schedule(
+				DispatchTime::At(when),
+				maybe_periodic,
+				priority,
+				*as_origin,
+				T::Preimages::bound(*call)?,
+			)?;
+			Ok(())
+		}
+
+		/// Schedule a named task to be dispatched with `as_origin`.
+		///
+		/// `as_origin` must have lower or equal privileges as the origin scheduling the task.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_named(T::MaxScheduledPerBlock::get()))]
+		pub fn schedule_named_as(
+			origin: OriginFor<T>,
+			as_origin: Box<T::PalletsOrigin>,
+			id: TaskName,
+			when: BlockNumberFor<T>,
+			maybe_periodic: Option<schedule::Period<BlockNumberFor<T>>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::ensure_not_escalating(origin.caller(), &as_origin)?;
+			Self::do_schedule_named(
+				id,
+				DispatchTime::At(when),
+				maybe_periodic,
+				priority,
+				*as_origin,
+				T::Preimages::bound(*call)?,
+			)?;
+			Ok(())
+		}
 	}
 }
 
@@ -448,7 +571,7 @@ impl<T: Config> Pallet<T> {
 	///
 	/// Returns the weight consumed by this migration.
 	pub fn migrate_v1_to_v4() -> Weight {
-		use migration::v1 as old;
+		use migration::{v1 as old, v4::Agenda};
 		let mut weight = T::DbWeight::get().reads_writes(1, 1);
 
 		// Delete all undecodable values.
@@ -518,7 +641,7 @@ impl<T: Config> Pallet<T> {
 	///
 	/// Returns the weight consumed by this migration.
 	pub fn migrate_v2_to_v4() -> Weight {
-		use migration::v2 as old;
+		use migration::{v2 as old, v4::Agenda};
 		let mut weight = T::DbWeight::get().reads_writes(1, 1);
 
 		// Delete all undecodable values.
@@ -584,7 +707,7 @@ impl<T: Config> Pallet<T> {
 	/// Returns the weight consumed by this migration.
 	#[allow(deprecated)]
 	pub fn migrate_v3_to_v4() -> Weight {
-		use migration::v3 as old;
+		use migration::{v3 as old, v4::Agenda};
 		let mut weight = T::DbWeight::get().reads_writes(2, 1);
 
 		// Delete all undecodable values.
@@ -624,7 +747,8 @@ impl<T: Config> Pallet<T> {
 								let call = match schedule.call {
 									MaybeHashed::Hash(h) => {
 										let bounded = Bounded::from_legacy_hash(h);
-										// Check that the call can be decoded in the new runtime.
+										// Check that the call can be decoded in the new
+										// runtime.
 										if let Err(err) = T::Preimages::peek::<
 											<T as Config>::RuntimeCall,
 										>(&bounded)
@@ -693,7 +817,7 @@ impl<T: Config> Pallet<T> {
 impl<T: Config> Pallet<T> {
 	/// Helper to migrate scheduler when the pallet origin type has changed.
 	pub fn migrate_origin<OldOrigin: Into<T::PalletsOrigin> + codec::Decode>() {
-		Agenda::<T>::translate::<
+		AgendaPages::<T>::translate::<
 			Vec<
 				Option<
 					Scheduled<
@@ -706,7 +830,7 @@ impl<T: Config> Pallet<T> {
 				>,
 			>,
 			_,
-		>(|_, agenda| {
+		>(|_, _, agenda| {
 			Some(BoundedVec::truncate_from(
 				agenda
 					.into_iter()
@@ -758,40 +882,155 @@ impl<T: Config> Pallet<T> {
 		Ok(address)
 	}
 
+	/// Returns the page and the slot within the page of the task at `index` of an agenda.
+	fn locate(index: u32) -> (PageIndex, usize) {
+		let page_size = T::MaxScheduledPerPage::get();
+		(index / page_size, (index % page_size) as usize)
+	}
+
 	fn push_to_agenda(
 		when: BlockNumberFor<T>,
 		what: ScheduledOf<T>,
 	) -> Result<u32, (DispatchError, ScheduledOf<T>)> {
-		let mut agenda = Agenda::<T>::get(when);
-		let index = if (agenda.len() as u32) < T::MaxScheduledPerBlock::get() {
-			// will always succeed due to the above check.
-			let _ = agenda.try_push(Some(what));
-			agenda.len() as u32 - 1
+		let page_size = T::MaxScheduledPerPage::get();
+		let mut info = Agendas::<T>::get(when).unwrap_or_default();
+		let last = info.pages.saturating_sub(1);
+		let mut items = AgendaPages::<T>::get(when, last);
+		let len = last.saturating_mul(page_size).saturating_add(items.len() as u32);
+		let (page, slot) = if len < T::MaxScheduledPerBlock::get() {
+			if info.pages == 0 || items.len() as u32 >= page_size {
+				items = BoundedVec::new();
+				info.pages.saturating_inc();
+			}
+			// will always succeed since the page is not full.
+			let _ = items.try_push(Some(what));
+			(info.pages - 1, items.len() - 1)
 		} else {
-			if let Some(hole_index) = agenda.iter().position(|i| i.is_none()) {
-				agenda[hole_index] = Some(what);
-				hole_index as u32
-			} else {
+			let hole = (info.first..info.pages).find_map(|page| {
+				let items = AgendaPages::<T>::get(when, page);
+				items.iter().position(|i| i.is_none()).map(|slot| (page, slot, items))
+			});
+			let Some((page, slot, hole_items)) = hole else {
 				return Err((DispatchError::Exhausted, what))
-			}
+			};
+			items = hole_items;
+			items[slot] = Some(what);
+			(page, slot)
 		};
-		Agenda::<T>::insert(when, agenda);
-		Ok(index)
+		info.count.saturating_inc();
+		AgendaPages::<T>::insert(when, page, items);
+		Agendas::<T>::insert(when, info);
+		Ok(page.saturating_mul(page_size).saturating_add(slot as u32))
 	}
 
-	/// Remove trailing `None` items of an agenda at `when`. If all items are `None` remove the
-	/// agenda record entirely.
-	fn cleanup_agenda(when: BlockNumberFor<T>) {
-		let mut agenda = Agenda::<T>::get(when);
-		match agenda.iter().rposition(|i| i.is_some()) {
-			Some(i) if agenda.len() > i + 1 => {
-				agenda.truncate(i + 1);
-				Agenda::<T>::insert(when, agenda);
-			},
-			Some(_) => {},
-			None => {
-				Agenda::<T>::remove(when);
-			},
+	/// Takes the task at `index` out of the agenda at `when` if `check` accepts it.
+	///
+	/// Returns `None` if there is no such task.
+	fn take_from_agenda(
+		(when, index): TaskAddress<BlockNumberFor<T>>,
+		check: impl FnOnce(&ScheduledOf<T>) -> DispatchResult,
+	) -> Result<Option<ScheduledOf<T>>, DispatchError> {
+		let Some(mut info) = Agendas::<T>::get(when) else { return Ok(None) };
+		let (page, slot) = Self::locate(index);
+		let mut items = AgendaPages::<T>::get(when, page);
+		let Some(task) = items.get_mut(slot).and_then(Option::take) else { return Ok(None) };
+		check(&task)?;
+		info.count.saturating_dec();
+		Self::cleanup_agenda(when, info, page, items);
+		Ok(Some(task))
+	}
+
+	/// Store the `items` of `page` of the agenda at `when` after a task was taken out of it,
+	/// removing the trailing `None` items of the agenda. If the agenda has no tasks left remove
+	/// the agenda record entirely.
+	fn cleanup_agenda(
+		when: BlockNumberFor<T>,
+		mut info: AgendaInfo,
+		page: PageIndex,
+		items: BoundedVec<Option<ScheduledOf<T>>, T::MaxScheduledPerPage>,
+	) {
+		if info.count == 0 {
+			let _ = AgendaPages::<T>::clear_prefix(when, info.pages, None);
+			Agendas::<T>::remove(when);
+			return
+		}
+
+		AgendaPages::<T>::insert(when, page, items);
+		if page.saturating_add(1) == info.pages {
+			while let Some(last) = info.pages.checked_sub(1) {
+				let mut items = AgendaPages::<T>::get(when, last);
+				match items.iter().rposition(|i| i.is_some()) {
+					Some(i) if items.len() > i + 1 => {
+						items.truncate(i + 1);
+						AgendaPages::<T>::insert(when, last, items);
+						break
+					},
+					Some(_) => break,
+					None => {
+						AgendaPages::<T>::remove(when, last);
+						info.pages = last;
+					},
+				}
+			}
+			info.first = info.first.min(info.pages);
+		}
+		Agendas::<T>::insert(when, info);
+	}
+
+	/// Returns whether the agenda at `when` has a slot for the task at `index`.
+	fn has_slot(when: BlockNumberFor<T>, index: u32) -> bool {
+		let (page, slot) = Self::locate(index);
+		AgendaPages::<T>::decode_len(when, page).map_or(false, |len| slot < len)
+	}
+
+	/// Returns the tasks of the agenda at `when`, indexed by their position in the agenda.
+	///
+	/// The tasks of pages that were already serviced are returned as `None`.
+	pub fn agenda(when: BlockNumberFor<T>) -> Vec<Option<ScheduledOf<T>>> {
+		let page_size = T::MaxScheduledPerPage::get() as usize;
+		let pages = Agendas::<T>::get(when).map_or(0, |info| info.pages);
+		let mut agenda = Vec::new();
+		for page in 0..pages {
+			agenda.resize_with(page as usize * page_size, || None);
+			agenda.extend(AgendaPages::<T>::get(when, page));
+		}
+		agenda
+	}
+
+	/// Returns the tasks queued in the agendas up to and including block `until`, ordered by
+	/// their address.
+	pub fn upcoming_tasks(
+		until: BlockNumberFor<T>,
+	) -> Vec<UpcomingTask<BlockNumberFor<T>, T::PalletsOrigin, <T as Config>::RuntimeCall>> {
+		let mut blocks: Vec<_> = Agendas::<T>::iter_keys().filter(|when| *when <= until).collect();
+		blocks.sort();
+		blocks
+			.into_iter()
+			.flat_map(|when| {
+				Self::agenda(when).into_iter().enumerate().filter_map(move |(index, task)| {
+					let task = task?;
+					Some(UpcomingTask {
+						address: (when, index as u32),
+						call: T::Preimages::peek(&task.call).ok().map(|(call, _)| call),
+						id: task.maybe_id,
+						priority: task.priority,
+						maybe_periodic: task.maybe_periodic,
+						origin: task.origin,
+					})
+				})
+			})
+			.collect()
+	}
+
+	/// Ensure that `origin` has greater or equal privileges as `as_origin`, which it schedules a
+	/// task with.
+	fn ensure_not_escalating(
+		origin: &T::PalletsOrigin,
+		as_origin: &T::PalletsOrigin,
+	) -> DispatchResult {
+		match T::OriginPrivilegeCmp::cmp_privilege(origin, as_origin) {
+			Some(Ordering::Greater | Ordering::Equal) => Ok(()),
+			_ => Err(Error::<T>::PrivilegeEscalation.into()),
 		}
 	}
 
@@ -833,28 +1072,22 @@ impl<T: Config> Pallet<T> {
 		origin: Option<T::PalletsOrigin>,
 		(when, index): TaskAddress<BlockNumberFor<T>>,
 	) -> Result<(), DispatchError> {
-		let scheduled = Agenda::<T>::try_mutate(when, |agenda| {
-			agenda.get_mut(index as usize).map_or(
-				Ok(None),
-				|s| -> Result<Option<Scheduled<_, _, _, _, _>>, DispatchError> {
-					if let (Some(ref o), Some(ref s)) = (origin, s.borrow()) {
-						if matches!(
-							T::OriginPrivilegeCmp::cmp_privilege(o, &s.origin),
-							Some(Ordering::Less) | None
-						) {
-							return Err(BadOrigin.into())
-						}
-					};
-					Ok(s.take())
-				},
-			)
+		let scheduled = Self::take_from_agenda((when, index), |s| {
+			if let Some(ref o) = origin {
+				if matches!(
+					T::OriginPrivilegeCmp::cmp_privilege(o, &s.origin),
+					Some(Ordering::Less) | None
+				) {
+					return Err(BadOrigin.into())
+				}
+			};
+			Ok(())
 		})?;
 		if let Some(s) = scheduled {
 			T::Preimages::drop(&s.call);
 			if let Some(id) = s.maybe_id {
 				Lookup::<T>::remove(id);
 			}
-			Self::cleanup_agenda(when);
 			Self::deposit_event(Event::Canceled { when, index });
 			Ok(())
 		} else {
@@ -872,12 +1105,11 @@ impl<T: Config> Pallet<T> {
 			return Err(Error::<T>::RescheduleNoChange.into())
 		}
 
-		let task = Agenda::<T>::try_mutate(when, |agenda| {
-			let task = agenda.get_mut(index as usize).ok_or(Error::<T>::NotFound)?;
-			ensure!(!matches!(task, Some(Scheduled { maybe_id: Some(_), .. })), Error::<T>::Named);
-			task.take().ok_or(Error::<T>::NotFound)
-		})?;
-		Self::cleanup_agenda(when);
+		let task = Self::take_from_agenda((when, index), |task| {
+			ensure!(task.maybe_id.is_none(), Error::<T>::Named);
+			Ok(())
+		})?
+		.ok_or(Error::<T>::NotFound)?;
 		Self::deposit_event(Event::Canceled { when, index });
 
 		Self::place_task(new_time, task).map_err(|x| x.0)
@@ -927,23 +1159,18 @@ impl<T: Config> Pallet<T> {
 	fn do_cancel_named(origin: Option<T::PalletsOrigin>, id: TaskName) -> DispatchResult {
 		Lookup::<T>::try_mutate_exists(id, |lookup| -> DispatchResult {
 			if let Some((when, index)) = lookup.take() {
-				let i = index as usize;
-				Agenda::<T>::try_mutate(when, |agenda| -> DispatchResult {
-					if let Some(s) = agenda.get_mut(i) {
-						if let (Some(ref o), Some(ref s)) = (origin, s.borrow()) {
-							if matches!(
-								T::OriginPrivilegeCmp::cmp_privilege(o, &s.origin),
-								Some(Ordering::Less) | None
-							) {
-								return Err(BadOrigin.into())
-							}
-							T::Preimages::drop(&s.call);
+				Self::take_from_agenda((when, index), |s| {
+					if let Some(ref o) = origin {
+						if matches!(
+							T::OriginPrivilegeCmp::cmp_privilege(o, &s.origin),
+							Some(Ordering::Less) | None
+						) {
+							return Err(BadOrigin.into())
 						}
-						*s = None;
+						T::Preimages::drop(&s.call);
 					}
 					Ok(())
 				})?;
-				Self::cleanup_agenda(when);
 				Self::deposit_event(Event::Canceled { when, index });
 				Ok(())
 			} else {
@@ -965,11 +1192,8 @@ impl<T: Config> Pallet<T> {
 			return Err(Error::<T>::RescheduleNoChange.into())
 		}
 
-		let task = Agenda::<T>::try_mutate(when, |agenda| {
-			let task = agenda.get_mut(index as usize).ok_or(Error::<T>::NotFound)?;
-			task.take().ok_or(Error::<T>::NotFound)
-		})?;
-		Self::cleanup_agenda(when);
+		let task =
+			Self::take_from_agenda((when, index), |_| Ok(()))?.ok_or(Error::<T>::NotFound)?;
 		Self::deposit_event(Event::Canceled { when, index });
 		Self::place_task(new_time, task).map_err(|x| x.0)
 	}
@@ -994,7 +1218,7 @@ impl<T: Config> Pallet<T> {
 		let mut when = IncompleteSince::<T>::take().unwrap_or(now);
 		let mut executed = 0;
 
-		let max_items = T::MaxScheduledPerBlock::get();
+		let max_items = T::MaxScheduledPerPage::get();
 		let mut count_down = max;
 		let service_agenda_base_weight = T::WeightInfo::service_agenda_base(max_items);
 		while count_down > 0 && when <= now && weight.can_consume(service_agenda_base_weight) {
@@ -1012,6 +1236,9 @@ impl<T: Config> Pallet<T> {
 
 	/// Returns `true` if the agenda was fully completed, `false` if it should be revisited at a
 	/// later block.
+	///
+	/// The pages of the agenda are serviced in order, starting at the first page that was not
+	/// fully serviced yet.
 	fn service_agenda(
 		weight: &mut WeightMeter,
 		executed: &mut u32,
@@ -1019,13 +1246,56 @@ impl<T: Config> Pallet<T> {
 		when: BlockNumberFor<T>,
 		max: u32,
 	) -> bool {
-		let mut agenda = Agenda::<T>::get(when);
-		let mut ordered = agenda
+		let Some(mut info) = Agendas::<T>::get(when) else {
+			let within_limit = weight.try_consume(T::WeightInfo::service_agenda_base(0)).is_ok();
+			debug_assert!(within_limit, "weight limit should have been checked in advance");
+			return true
+		};
+
+		let service_page_base_weight =
+			T::WeightInfo::service_agenda_base(T::MaxScheduledPerPage::get());
+		let mut complete = true;
+		while info.first < info.pages {
+			if !weight.can_consume(service_page_base_weight) {
+				complete = false;
+				break
+			}
+			let (page_complete, serviced) =
+				Self::service_agenda_page(weight, executed, now, when, info.first, max);
+			info.count.saturating_reduce(serviced);
+			if !page_complete {
+				complete = false;
+				break
+			}
+			info.first.saturating_inc();
+		}
+
+		if info.count > 0 {
+			Agendas::<T>::insert(when, info);
+		} else {
+			Agendas::<T>::remove(when);
+		}
+
+		complete
+	}
+
+	/// Service the tasks of `page` of the agenda at `when` by priority.
+	///
+	/// Returns whether the page was fully completed and the number of tasks that were removed
+	/// from it.
+	fn service_agenda_page(
+		weight: &mut WeightMeter,
+		executed: &mut u32,
+		now: BlockNumberFor<T>,
+		when: BlockNumberFor<T>,
+		page: PageIndex,
+		max: u32,
+	) -> (bool, u32) {
+		let mut items = AgendaPages::<T>::get(when, page);
+		let mut ordered = items
 			.iter()
 			.enumerate()
-			.filter_map(|(index, maybe_item)| {
-				maybe_item.as_ref().map(|item| (index as u32, item.priority))
-			})
+			.filter_map(|(slot, maybe_item)| maybe_item.as_ref().map(|item| (slot, item.priority)))
 			.collect::<Vec<_>>();
 		ordered.sort_by_key(|k| k.1);
 		let within_limit = weight
@@ -1037,9 +1307,12 @@ impl<T: Config> Pallet<T> {
 		let mut postponed = (ordered.len() as u32).saturating_sub(max);
 		// Items which we don't know can ever be executed.
 		let mut dropped = 0;
+		// Items which were removed from the page.
+		let mut serviced = 0;
 
-		for (agenda_index, _) in ordered.into_iter().take(max as usize) {
-			let task = match agenda[agenda_index as usize].take() {
+		let first_index = page.saturating_mul(T::MaxScheduledPerPage::get());
+		for (slot, _) in ordered.into_iter().take(max as usize) {
+			let task = match items[slot].take() {
 				None => continue,
 				Some(t) => t,
 			};
@@ -1049,11 +1322,13 @@ impl<T: Config> Pallet<T> {
 				task.maybe_periodic.is_some(),
 			);
 			if !weight.can_consume(base_weight) {
+				items[slot] = Some(task);
 				postponed += 1;
 				break
 			}
+			let agenda_index = first_index.saturating_add(slot as u32);
 			let result = Self::service_task(weight, now, when, agenda_index, *executed == 0, task);
-			agenda[agenda_index as usize] = match result {
+			items[slot] = match result {
 				Err((Unavailable, slot)) => {
 					dropped += 1;
 					slot
@@ -1064,17 +1339,18 @@ impl<T: Config> Pallet<T> {
 				},
 				Ok(()) => {
 					*executed += 1;
+					serviced += 1;
 					None
 				},
 			};
 		}
 		if postponed > 0 || dropped > 0 {
-			Agenda::<T>::insert(when, agenda);
+			AgendaPages::<T>::insert(when, page, items);
 		} else {
-			Agenda::<T>::remove(when);
+			AgendaPages::<T>::remove(when, page);
 		}
 
-		postponed == 0
+		(postponed == 0, serviced)
 	}
 
 	/// Service (i.e. execute) the given task, being careful not to overflow the `weight` counter.
@@ -1192,6 +1468,53 @@ impl<T: Config> Pallet<T> {
 		let _ = weight.try_consume(call_weight);
 		Ok(result)
 	}
+
+	/// Ensure the correctness of the state of this pallet.
+	///
+	/// ## `Agendas`
+	///
+	/// * Every agenda has tasks, but no more than `T::MaxScheduledPerBlock`.
+	/// * The first page that was not fully serviced is not after the last page.
+	/// * The number of tasks of every agenda matches the tasks in its pages.
+	///
+	/// ## `AgendaPages`
+	///
+	/// * Every page belongs to an agenda and is within its pages.
+	///
+	/// ## `Lookup`
+	///
+	/// * Every name points to a task with that name.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		for (when, info) in Agendas::<T>::iter() {
+			ensure!(info.count > 0, "Agendas without tasks must be removed");
+			ensure!(info.count <= T::MaxScheduledPerBlock::get(), "Agenda has too many tasks");
+			ensure!(info.first <= info.pages, "First page to service is after the last page");
+
+			let mut count = 0u32;
+			for (page, items) in AgendaPages::<T>::iter_prefix(when) {
+				ensure!(page < info.pages, "Page is after the last page of its agenda");
+				count.saturating_accrue(items.iter().filter(|i| i.is_some()).count() as u32);
+			}
+			ensure!(count == info.count, "Number of tasks of agenda does not match its pages");
+		}
+
+		for (when, _) in AgendaPages::<T>::iter_keys() {
+			ensure!(Agendas::<T>::contains_key(when), "Page does not belong to an agenda");
+		}
+
+		for (name, (when, index)) in Lookup::<T>::iter() {
+			let (page, slot) = Self::locate(index);
+			let items = AgendaPages::<T>::get(when, page);
+			let task = items.get(slot).and_then(Option::as_ref);
+			ensure!(
+				task.map_or(false, |task| task.maybe_id == Some(name)),
+				"Lookup does not point to the named task"
+			);
+		}
+
+		Ok(())
+	}
 }
 
 impl<T: Config> schedule::v2::Anon<BlockNumberFor<T>, <T as Config>::RuntimeCall, T::PalletsOrigin>
@@ -1224,7 +1547,7 @@ impl<T: Config> schedule::v2::Anon<BlockNumberFor<T>, <T as Config>::RuntimeCall
 	}
 
 	fn next_dispatch_time((when, index): Self::Address) -> Result<BlockNumberFor<T>, ()> {
-		Agenda::<T>::get(when).get(index as usize).ok_or(()).map(|_| when)
+		Self::has_slot(when, index).then_some(when).ok_or(())
 	}
 }
 
@@ -1264,7 +1587,7 @@ impl<T: Config> schedule::v2::Named<BlockNumberFor<T>, <T as Config>::RuntimeCal
 	fn next_dispatch_time(id: Vec<u8>) -> Result<BlockNumberFor<T>, ()> {
 		let name = blake2_256(&id[..]);
 		Lookup::<T>::get(name)
-			.and_then(|(when, index)| Agenda::<T>::get(when).get(index as usize).map(|_| when))
+			.and_then(|(when, index)| Self::has_slot(when, index).then_some(when))
 			.ok_or(())
 	}
 }
@@ -1299,10 +1622,7 @@ impl<T: Config> schedule::v3::Anon<BlockNumberFor<T>, <T as Config>::RuntimeCall
 	fn next_dispatch_time(
 		(when, index): Self::Address,
 	) -> Result<BlockNumberFor<T>, DispatchError> {
-		Agenda::<T>::get(when)
-			.get(index as usize)
-			.ok_or(DispatchError::Unavailable)
-			.map(|_| when)
+		Self::has_slot(when, index).then_some(when).ok_or(DispatchError::Unavailable)
 	}
 }
 
@@ -1338,7 +1658,7 @@ impl<T: Config> schedule::v3::Named<BlockNumberFor<T>, <T as Config>::RuntimeCal
 
 	fn next_dispatch_time(id: TaskName) -> Result<BlockNumberFor<T>, DispatchError> {
 		Lookup::<T>::get(id)
-			.and_then(|(when, index)| Agenda::<T>::get(when).get(index as usize).map(|_| when))
+			.and_then(|(when, index)| Self::has_slot(when, index).then_some(when))
 			.ok_or(DispatchError::Unavailable)
 	}
 }
@@ -1351,3 +1671,16 @@ fn map_err_to_v3_err<T: Config>(err: DispatchError) -> DispatchError {
 		err
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// This runtime API lists the tasks queued in the scheduler.
+	pub trait SchedulerApi<BlockNumber, PalletsOrigin, Call> where
+		BlockNumber: Codec,
+		PalletsOrigin: Codec,
+		Call: Codec,
+	{
+		/// Returns the tasks queued up to and including block `until`, with the origins they are
+		/// going to be dispatched with and their calls, if available.
+		fn upcoming_tasks(until: BlockNumber) -> Vec<UpcomingTask<BlockNumber, PalletsOrigin, Call>>;
+	}
+}
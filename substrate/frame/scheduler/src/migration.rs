@@ -161,13 +161,13 @@ pub mod v3 {
 			ensure!(StorageVersion::get::<Pallet<T>>() == 4, "Must upgrade");
 
 			// Check that everything decoded fine.
-			for k in crate::Agenda::<T>::iter_keys() {
-				ensure!(crate::Agenda::<T>::try_get(k).is_ok(), "Cannot decode V4 Agenda");
+			for k in super::v4::Agenda::<T>::iter_keys() {
+				ensure!(super::v4::Agenda::<T>::try_get(k).is_ok(), "Cannot decode V4 Agenda");
 			}
 
 			let old_agendas: u32 =
 				Decode::decode(&mut &state[..]).expect("pre_upgrade provides a valid state; qed");
-			let new_agendas = crate::Agenda::<T>::iter_keys().count() as u32;
+			let new_agendas = super::v4::Agenda::<T>::iter_keys().count() as u32;
 			if old_agendas != new_agendas {
 				// This is not necessarily an error, but can happen when there are Calls
 				// in an Agenda that are not valid anymore in the new runtime.
@@ -190,6 +190,15 @@ pub mod v4 {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 
+	#[frame_support::storage_alias]
+	pub(crate) type Agenda<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<Option<ScheduledOf<T>>, <T as Config>::MaxScheduledPerBlock>,
+		ValueQuery,
+	>;
+
 	/// This migration cleans up empty agendas of the V4 scheduler.
 	///
 	/// This should be run on a scheduler that does not have
@@ -300,6 +309,85 @@ pub mod v4 {
 	}
 }
 
+pub mod v5 {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	/// Migrate the scheduler pallet from V4 to V5, wrapped in a
+	/// [`frame_support::migrations::VersionedMigration`] that only runs on a V4 scheduler.
+	pub type MigrateV4ToV5<T> = frame_support::migrations::VersionedMigration<
+		4,
+		5,
+		VersionUncheckedMigrateV4ToV5<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
+	/// Splits the agendas of the V4 scheduler into pages of `T::MaxScheduledPerPage` tasks.
+	///
+	/// The tasks keep their index within their agenda, so the `Lookup` stays valid. Agendas without
+	/// any task and pages without any task are not migrated.
+	pub struct VersionUncheckedMigrateV4ToV5<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateV4ToV5<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let agendas = v4::Agenda::<T>::iter_keys().count() as u32;
+			let tasks = v4::Agenda::<T>::iter_values()
+				.map(|agenda| agenda.iter().filter(|s| s.is_some()).count() as u32)
+				.sum::<u32>();
+			log::info!(target: TARGET, "Trying to migrate {} tasks of {} agendas...", tasks, agendas);
+
+			Ok(tasks.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let page_size = T::MaxScheduledPerPage::get() as usize;
+			let mut reads = 0u64;
+			let mut writes = 0u64;
+
+			for (when, agenda) in v4::Agenda::<T>::drain() {
+				reads.saturating_inc();
+				writes.saturating_inc();
+
+				let count = agenda.iter().filter(|s| s.is_some()).count() as u32;
+				let Some(len) = agenda.iter().rposition(|s| s.is_some()).map(|i| i + 1) else {
+					continue
+				};
+				let mut pages = 0;
+				for items in agenda[..len].chunks(page_size) {
+					if items.iter().any(|s| s.is_some()) {
+						AgendaPages::<T>::insert(
+							when,
+							pages,
+							BoundedVec::truncate_from(items.to_vec()),
+						);
+						writes.saturating_inc();
+					}
+					pages.saturating_inc();
+				}
+				Agendas::<T>::insert(when, AgendaInfo { first: 0, pages, count });
+				writes.saturating_inc();
+			}
+
+			log::info!(target: TARGET, "Migrated {} agendas to pages", reads);
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let old_tasks: u32 = Decode::decode(&mut state.as_ref())
+				.map_err(|_| "Cannot decode pre_upgrade state")?;
+
+			ensure!(v4::Agenda::<T>::iter_keys().count() == 0, "Not all V4 agendas were migrated");
+			let tasks = Agendas::<T>::iter_values().map(|info| info.count).sum::<u32>();
+			ensure!(tasks == old_tasks, "Number of tasks must not change");
+
+			Pallet::<T>::do_try_state()
+		}
+	}
+}
+
 #[cfg(test)]
 #[cfg(feature = "try-runtime")]
 mod test {
@@ -375,7 +463,8 @@ mod test {
 			let _w = v3::MigrateToV4::<Test>::on_runtime_upgrade();
 			v3::MigrateToV4::<Test>::post_upgrade(state).unwrap();
 
-			let mut x = Agenda::<Test>::iter().map(|x| (x.0, x.1.into_inner())).collect::<Vec<_>>();
+			let mut x =
+				v4::Agenda::<Test>::iter().map(|x| (x.0, x.1.into_inner())).collect::<Vec<_>>();
 			x.sort_by_key(|x| x.0);
 
 			let bound_large_call = Preimage::bound(large_call).unwrap();
@@ -488,7 +577,8 @@ mod test {
 			// But the migration itself works:
 			let _w = v3::MigrateToV4::<Test>::on_runtime_upgrade();
 
-			let mut x = Agenda::<Test>::iter().map(|x| (x.0, x.1.into_inner())).collect::<Vec<_>>();
+			let mut x =
+				v4::Agenda::<Test>::iter().map(|x| (x.0, x.1.into_inner())).collect::<Vec<_>>();
 			x.sort_by_key(|x| x.0);
 			// The call becomes `None`.
 			let expected = vec![(0, vec![None])];
@@ -533,7 +623,7 @@ mod test {
 
 			// Insert all the agendas.
 			for (i, test) in test_data.iter().enumerate() {
-				Agenda::<Test>::insert(i as u64, test.0.clone());
+				v4::Agenda::<Test>::insert(i as u64, test.0.clone());
 			}
 
 			// Run the migration.
@@ -545,18 +635,77 @@ mod test {
 			for (i, test) in test_data.iter().enumerate() {
 				match test.1.clone() {
 					None => assert!(
-						!Agenda::<Test>::contains_key(i as u64),
+						!v4::Agenda::<Test>::contains_key(i as u64),
 						"Agenda {} should be removed",
 						i
 					),
 					Some(new) => {
-						assert_eq!(Agenda::<Test>::get(i as u64), new, "Agenda wrong {}", i)
+						assert_eq!(v4::Agenda::<Test>::get(i as u64), new, "Agenda wrong {}", i)
 					},
 				}
 			}
 		});
 	}
 
+	#[test]
+	fn migration_v4_to_v5_works() {
+		use sp_core::bounded_vec;
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(4).put::<Scheduler>();
+
+			let task = |maybe_id: Option<TaskName>| {
+				let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+				Some(ScheduledOf::<Test> {
+					maybe_id,
+					priority: 1,
+					call: Preimage::bound(call).unwrap(),
+					maybe_periodic: None,
+					origin: root(),
+					_phantom: Default::default(),
+				})
+			};
+			let page_size = <Test as Config>::MaxScheduledPerPage::get() as usize;
+			let agenda: BoundedVec<_, <Test as Config>::MaxScheduledPerBlock> = bounded_vec![
+				task(Some([1; 32])),
+				None,
+				task(None),
+				None,
+				None,
+				task(Some([2; 32])),
+				None,
+			];
+			assert!(agenda.len() > page_size, "Test needs an agenda with more than one page");
+			v4::Agenda::<Test>::insert(4, agenda.clone());
+			Lookup::<Test>::insert([1; 32], (4, 0));
+			Lookup::<Test>::insert([2; 32], (4, 5));
+			v4::Agenda::<Test>::insert(5, bounded_vec![None, None]);
+			v4::Agenda::<Test>::insert(6, bounded_vec![task(None)]);
+
+			let data = v5::MigrateV4ToV5::<Test>::pre_upgrade().unwrap();
+			let _w = v5::MigrateV4ToV5::<Test>::on_runtime_upgrade();
+			v5::MigrateV4ToV5::<Test>::post_upgrade(data).unwrap();
+
+			assert_eq!(StorageVersion::get::<Scheduler>(), 5);
+			assert_eq!(v4::Agenda::<Test>::iter_keys().count(), 0);
+
+			// The tasks keep their index and the trailing `None` is dropped.
+			assert_eq!(Agendas::<Test>::get(4), Some(AgendaInfo { first: 0, pages: 2, count: 3 }));
+			assert_eq!(Scheduler::agenda(4), agenda[..6].to_vec());
+			assert_eq!(AgendaPages::<Test>::get(4, 1).len(), 6 - page_size);
+			// Agendas without tasks are dropped.
+			assert_eq!(Agendas::<Test>::get(5), None);
+			assert_eq!(AgendaPages::<Test>::iter_prefix(5).count(), 0);
+			assert_eq!(Agendas::<Test>::get(6), Some(AgendaInfo { first: 0, pages: 1, count: 1 }));
+
+			// The named tasks can still be canceled.
+			Scheduler::do_cancel_named(None, [2; 32]).unwrap();
+			Scheduler::do_cancel_named(None, [1; 32]).unwrap();
+			assert_eq!(Agendas::<Test>::get(4), Some(AgendaInfo { first: 0, pages: 1, count: 1 }));
+			assert_eq!(Scheduler::agenda(4), vec![None, None, task(None)]);
+			Scheduler::do_try_state().unwrap();
+		});
+	}
+
 	fn signed(i: u64) -> OriginCaller {
 		system::RawOrigin::Signed(i).into()
 	}
@@ -23,7 +23,8 @@ use crate as scheduler;
 use frame_support::{
 	derive_impl, ord_parameter_types, parameter_types,
 	traits::{
-		ConstU32, ConstU64, Contains, EitherOfDiverse, EqualPrivilegeOnly, OnFinalize, OnInitialize,
+		ConstU32, ConstU64, Contains, EitherOfDiverse, EqualPrivilegeOnly, OnFinalize,
+		OnInitialize, PrivilegeCmp,
 	},
 	weights::constants::RocksDbWeight,
 };
@@ -202,6 +203,20 @@ impl WeightInfo for TestWeightInfo {
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
 		BlockWeights::get().max_block;
+	pub static RootOutranksOthers: bool = false;
+}
+
+/// Only equal origins have equal privileges, unless `RootOutranksOthers` is set, in which case
+/// `Root` has greater privileges than any other origin.
+pub struct TestPrivilegeCmp;
+impl PrivilegeCmp<OriginCaller> for TestPrivilegeCmp {
+	fn cmp_privilege(left: &OriginCaller, right: &OriginCaller) -> Option<Ordering> {
+		match EqualPrivilegeOnly::cmp_privilege(left, right) {
+			None if RootOutranksOthers::get() && *left == root() => Some(Ordering::Greater),
+			None if RootOutranksOthers::get() && *right == root() => Some(Ordering::Less),
+			ordering => ordering,
+		}
+	}
 }
 
 impl Config for Test {
@@ -212,8 +227,9 @@ impl Config for Test {
 	type MaximumWeight = MaximumSchedulerWeight;
 	type ScheduleOrigin = EitherOfDiverse<EnsureRoot<u64>, EnsureSignedBy<One, u64>>;
 	type MaxScheduledPerBlock = ConstU32<10>;
+	type MaxScheduledPerPage = ConstU32<4>;
 	type WeightInfo = TestWeightInfo;
-	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type OriginPrivilegeCmp = TestPrivilegeCmp;
 	type Preimages = Preimage;
 }
 
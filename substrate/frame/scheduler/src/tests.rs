@@ -453,7 +453,7 @@ fn scheduler_does_not_delete_permanently_overweight_call() {
 			crate::Event::PermanentlyOverweight { task: (4, 0), id: None }.into(),
 		);
 		// The call is still in the agenda.
-		assert!(Agenda::<Test>::get(4)[0].is_some());
+		assert!(Scheduler::agenda(4)[0].is_some());
 	});
 }
 
@@ -729,7 +729,7 @@ fn root_calls_works() {
 		assert_ok!(Scheduler::schedule(RuntimeOrigin::root(), 4, None, 127, call2));
 		run_to_block(3);
 		// Scheduled calls are in the agenda.
-		assert_eq!(Agenda::<Test>::get(4).len(), 2);
+		assert_eq!(Scheduler::agenda(4).len(), 2);
 		assert!(logger::log().is_empty());
 		assert_ok!(Scheduler::cancel_named(RuntimeOrigin::root(), [1u8; 32]));
 		assert_ok!(Scheduler::cancel(RuntimeOrigin::root(), 4, 1));
@@ -796,7 +796,7 @@ fn should_use_origin() {
 		assert_ok!(Scheduler::schedule(system::RawOrigin::Signed(1).into(), 4, None, 127, call2,));
 		run_to_block(3);
 		// Scheduled calls are in the agenda.
-		assert_eq!(Agenda::<Test>::get(4).len(), 2);
+		assert_eq!(Scheduler::agenda(4).len(), 2);
 		assert!(logger::log().is_empty());
 		assert_ok!(Scheduler::cancel_named(system::RawOrigin::Signed(1).into(), [1u8; 32]));
 		assert_ok!(Scheduler::cancel(system::RawOrigin::Signed(1).into(), 4, 1));
@@ -857,7 +857,7 @@ fn should_check_origin_for_cancel() {
 		assert_ok!(Scheduler::schedule(system::RawOrigin::Signed(1).into(), 4, None, 127, call2,));
 		run_to_block(3);
 		// Scheduled calls are in the agenda.
-		assert_eq!(Agenda::<Test>::get(4).len(), 2);
+		assert_eq!(Scheduler::agenda(4).len(), 2);
 		assert!(logger::log().is_empty());
 		assert_noop!(
 			Scheduler::cancel_named(system::RawOrigin::Signed(2).into(), [1u8; 32]),
@@ -877,6 +877,119 @@ fn should_check_origin_for_cancel() {
 	});
 }
 
+#[test]
+fn schedule_as_dispatches_with_narrowed_origin() {
+	new_test_ext().execute_with(|| {
+		RootOutranksOthers::set(true);
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 69,
+			weight: Weight::from_parts(10, 0),
+		}));
+		assert_ok!(Scheduler::schedule_as(
+			system::RawOrigin::Root.into(),
+			Box::new(signed.clone()),
+			4,
+			None,
+			127,
+			call.clone(),
+		));
+		assert_ok!(Scheduler::schedule_named_as(
+			system::RawOrigin::Signed(1).into(),
+			Box::new(signed.clone()),
+			[1u8; 32],
+			4,
+			None,
+			127,
+			call.clone(),
+		));
+		// the scheduling origin cannot escalate its privileges.
+		assert_noop!(
+			Scheduler::schedule_as(
+				system::RawOrigin::Signed(1).into(),
+				Box::new(root()),
+				4,
+				None,
+				127,
+				call.clone(),
+			),
+			Error::<Test>::PrivilegeEscalation
+		);
+		assert_noop!(
+			Scheduler::schedule_named_as(
+				system::RawOrigin::Signed(1).into(),
+				Box::new(root()),
+				[2u8; 32],
+				4,
+				None,
+				127,
+				call,
+			),
+			Error::<Test>::PrivilegeEscalation
+		);
+
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(signed.clone(), 69u32), (signed, 69u32)]);
+	});
+}
+
+#[test]
+fn upcoming_tasks_lists_tasks_with_origins_and_calls() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+		let len = call.using_encoded(|x| x.len()) as u32;
+		let hashed = Bounded::Lookup { hash, len };
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(0), call.encode()));
+		// the preimage of this call is never noted.
+		let unavailable = Bounded::Lookup { hash: sp_core::H256::repeat_byte(1), len };
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(6),
+			Some((3, 3)),
+			10,
+			root(),
+			Preimage::bound(call.clone()).unwrap(),
+		));
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, signed.clone(), hashed));
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			signed.clone(),
+			unavailable
+		));
+
+		let task_at_4 = UpcomingTask {
+			address: (4, 0),
+			id: None,
+			priority: 127,
+			maybe_periodic: None,
+			origin: signed.clone(),
+			call: Some(call.clone()),
+		};
+		let unavailable_task_at_4 =
+			UpcomingTask { address: (4, 1), call: None, ..task_at_4.clone() };
+		let task_at_6 = UpcomingTask {
+			address: (6, 0),
+			id: Some([1u8; 32]),
+			priority: 10,
+			maybe_periodic: Some((3, 2)),
+			origin: root(),
+			call: Some(call),
+		};
+		assert_eq!(Scheduler::upcoming_tasks(5), vec![task_at_4.clone(), unavailable_task_at_4]);
+		assert_eq!(Scheduler::upcoming_tasks(6).len(), 3);
+		assert_eq!(Scheduler::upcoming_tasks(6)[2], task_at_6);
+
+		// serviced tasks are not upcoming anymore.
+		run_to_block(4);
+		assert_eq!(Scheduler::upcoming_tasks(6), vec![task_at_6]);
+	});
+}
+
 #[test]
 fn migration_to_v4_works() {
 	new_test_ext().execute_with(|| {
@@ -908,7 +1021,9 @@ fn migration_to_v4_works() {
 
 		Scheduler::migrate_v1_to_v4();
 
-		let mut x = Agenda::<Test>::iter().map(|x| (x.0, x.1.into_inner())).collect::<Vec<_>>();
+		let mut x = migration::v4::Agenda::<Test>::iter()
+			.map(|x| (x.0, x.1.into_inner()))
+			.collect::<Vec<_>>();
 		x.sort_by_key(|x| x.0);
 		let expected = vec![
 			(
@@ -1018,7 +1133,7 @@ fn migration_to_v4_works() {
 fn test_migrate_origin() {
 	new_test_ext().execute_with(|| {
 		for i in 0..3u64 {
-			let k = i.twox_64_concat();
+			let k = [i.twox_64_concat(), 0u32.twox_64_concat()].concat();
 			let old: Vec<Option<Scheduled<[u8; 32], BoundedCallOf<Test>, u64, u32, u64>>> = vec![
 				Some(Scheduled {
 					maybe_id: None,
@@ -1046,7 +1161,7 @@ fn test_migrate_origin() {
 					_phantom: Default::default(),
 				}),
 			];
-			frame_support::migration::put_storage_value(b"Scheduler", b"Agenda", &k, old);
+			frame_support::migration::put_storage_value(b"Scheduler", b"AgendaPages", &k, old);
 		}
 
 		impl Into<OriginCaller> for u32 {
@@ -1062,7 +1177,7 @@ fn test_migrate_origin() {
 		Scheduler::migrate_origin::<u32>();
 
 		assert_eq_uvec!(
-			Agenda::<Test>::iter().map(|x| (x.0, x.1.into_inner())).collect::<Vec<_>>(),
+			AgendaPages::<Test>::iter().map(|x| (x.0, x.2.into_inner())).collect::<Vec<_>>(),
 			vec![
 				(
 					0,
@@ -1191,10 +1306,9 @@ fn postponed_named_task_cannot_be_rescheduled() {
 		assert!(!Lookup::<Test>::contains_key(name));
 
 		// The agenda still contains the call.
-		let agenda = Agenda::<Test>::iter().collect::<Vec<_>>();
-		assert_eq!(agenda.len(), 1);
+		assert_eq!(Agendas::<Test>::iter_keys().collect::<Vec<_>>(), vec![4]);
 		assert_eq!(
-			agenda[0].1,
+			Scheduler::agenda(4),
 			vec![Some(Scheduled {
 				maybe_id: Some(name),
 				priority: 127,
@@ -1756,14 +1870,14 @@ fn cancel_last_task_removes_agenda() {
 		)
 		.unwrap();
 		// two tasks at agenda.
-		assert!(Agenda::<Test>::get(when).len() == 2);
+		assert!(Scheduler::agenda(when).len() == 2);
 		assert_ok!(Scheduler::do_cancel(None, address));
 		// still two tasks at agenda, `None` and `Some`.
-		assert!(Agenda::<Test>::get(when).len() == 2);
+		assert!(Scheduler::agenda(when).len() == 2);
 		// cancel last task from `when` agenda.
 		assert_ok!(Scheduler::do_cancel(None, address2));
 		// if all tasks `None`, agenda fully removed.
-		assert!(Agenda::<Test>::get(when).len() == 0);
+		assert!(Scheduler::agenda(when).len() == 0);
 	});
 }
 
@@ -1792,14 +1906,14 @@ fn cancel_named_last_task_removes_agenda() {
 		)
 		.unwrap();
 		// two tasks at agenda.
-		assert!(Agenda::<Test>::get(when).len() == 2);
+		assert!(Scheduler::agenda(when).len() == 2);
 		assert_ok!(Scheduler::do_cancel_named(None, [2u8; 32]));
 		// removes trailing `None` and leaves one task.
-		assert!(Agenda::<Test>::get(when).len() == 1);
+		assert!(Scheduler::agenda(when).len() == 1);
 		// cancel last task from `when` agenda.
 		assert_ok!(Scheduler::do_cancel_named(None, [1u8; 32]));
 		// if all tasks `None`, agenda fully removed.
-		assert!(Agenda::<Test>::get(when).len() == 0);
+		assert!(Scheduler::agenda(when).len() == 0);
 	});
 }
 
@@ -1826,17 +1940,17 @@ fn reschedule_last_task_removes_agenda() {
 		)
 		.unwrap();
 		// two tasks at agenda.
-		assert!(Agenda::<Test>::get(when).len() == 2);
+		assert!(Scheduler::agenda(when).len() == 2);
 		assert_ok!(Scheduler::do_cancel(None, address));
 		// still two tasks at agenda, `None` and `Some`.
-		assert!(Agenda::<Test>::get(when).len() == 2);
+		assert!(Scheduler::agenda(when).len() == 2);
 		// reschedule last task from `when` agenda.
 		assert_eq!(
 			Scheduler::do_reschedule(address2, DispatchTime::At(when + 1)).unwrap(),
 			(when + 1, 0)
 		);
 		// if all tasks `None`, agenda fully removed.
-		assert!(Agenda::<Test>::get(when).len() == 0);
+		assert!(Scheduler::agenda(when).len() == 0);
 	});
 }
 
@@ -1865,17 +1979,17 @@ fn reschedule_named_last_task_removes_agenda() {
 		)
 		.unwrap();
 		// two tasks at agenda.
-		assert!(Agenda::<Test>::get(when).len() == 2);
+		assert!(Scheduler::agenda(when).len() == 2);
 		assert_ok!(Scheduler::do_cancel_named(None, [1u8; 32]));
 		// still two tasks at agenda, `None` and `Some`.
-		assert!(Agenda::<Test>::get(when).len() == 2);
+		assert!(Scheduler::agenda(when).len() == 2);
 		// reschedule last task from `when` agenda.
 		assert_eq!(
 			Scheduler::do_reschedule_named([2u8; 32], DispatchTime::At(when + 1)).unwrap(),
 			(when + 1, 0)
 		);
 		// if all tasks `None`, agenda fully removed.
-		assert!(Agenda::<Test>::get(when).len() == 0);
+		assert!(Scheduler::agenda(when).len() == 0);
 	});
 }
 
@@ -1917,3 +2031,99 @@ fn unavailable_call_is_detected() {
 		);
 	});
 }
+
+#[test]
+fn agenda_is_paged() {
+	let max: u32 = <Test as Config>::MaxScheduledPerBlock::get();
+	let page_size: u32 = <Test as Config>::MaxScheduledPerPage::get();
+	assert!(max > 2 * page_size, "pre-condition: This test needs an agenda with three pages");
+
+	new_test_ext().execute_with(|| {
+		for i in 0..max {
+			let call =
+				RuntimeCall::Logger(LoggerCall::log { i, weight: Weight::from_parts(10, 0) });
+			let address = Scheduler::do_schedule(
+				DispatchTime::At(4),
+				None,
+				127,
+				root(),
+				Preimage::bound(call).unwrap(),
+			)
+			.unwrap();
+			assert_eq!(address, (4, i));
+		}
+		assert_eq!(Agendas::<Test>::get(4), Some(AgendaInfo { first: 0, pages: 3, count: max }));
+		assert_eq!(AgendaPages::<Test>::get(4, 0).len() as u32, page_size);
+		assert_eq!(AgendaPages::<Test>::get(4, 2).len() as u32, max - 2 * page_size);
+		assert_ok!(Scheduler::do_try_state());
+
+		// Canceling a task only touches its page.
+		assert_ok!(Scheduler::do_cancel(None, (4, page_size + 1)));
+		assert!(AgendaPages::<Test>::get(4, 1)[1].is_none());
+		assert_eq!(Agendas::<Test>::get(4).unwrap().count, max - 1);
+		assert_ok!(Scheduler::do_try_state());
+
+		// All other tasks are executed in order of their pages.
+		run_to_block(4);
+		let expected = (0..max).filter(|i| *i != page_size + 1).map(|i| (root(), i));
+		assert_eq!(logger::log(), expected.collect::<Vec<_>>());
+		assert_eq!(Agendas::<Test>::get(4), None);
+		assert_eq!(AgendaPages::<Test>::iter_prefix(4).count(), 0);
+		assert_ok!(Scheduler::do_try_state());
+	});
+}
+
+#[test]
+fn overweight_agenda_pages_roll_over() {
+	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+	let page_size: u32 = <Test as Config>::MaxScheduledPerPage::get();
+
+	new_test_ext().execute_with(|| {
+		// Only a page of calls fits into a block.
+		let tasks = page_size + 2;
+		for i in 0..tasks {
+			let call = RuntimeCall::Logger(LoggerCall::log { i, weight: max_weight / 5 });
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(4),
+				None,
+				127,
+				root(),
+				Preimage::bound(call).unwrap(),
+			));
+		}
+
+		run_to_block(4);
+		assert_eq!(logger::log().len() as u32, page_size);
+		// The serviced page is gone and the agenda resumes at the next page.
+		assert_eq!(IncompleteSince::<Test>::get(), Some(4));
+		assert_eq!(Agendas::<Test>::get(4), Some(AgendaInfo { first: 1, pages: 2, count: 2 }));
+		assert!(!AgendaPages::<Test>::contains_key(4, 0));
+		assert_ok!(Scheduler::do_try_state());
+
+		run_to_block(5);
+		assert_eq!(logger::log(), (0..tasks).map(|i| (root(), i)).collect::<Vec<_>>());
+		assert_eq!(IncompleteSince::<Test>::get(), None);
+		assert_eq!(Agendas::<Test>::get(4), None);
+		assert_ok!(Scheduler::do_try_state());
+	});
+}
+
+#[test]
+fn try_state_detects_dangling_lookup() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		assert_ok!(Scheduler::do_try_state());
+
+		Lookup::<Test>::insert([2u8; 32], (4, 0));
+		assert!(Scheduler::do_try_state().is_err());
+	});
+}
@@ -115,7 +115,7 @@ mod mock;
 mod tests;
 pub mod weights;
 
-use codec::{Decode, MaxEncodedLen};
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
@@ -410,6 +410,15 @@ pub mod pallet {
 		/// The keys.
 		type Keys: OpaqueKeys + Member + Parameter + MaybeSerializeDeserialize;
 
+		/// Whether [`Pallet::set_keys`] requires a proof that the caller owns the keys.
+		///
+		/// If `false`, keys are accepted without a valid proof, as they were before ownership
+		/// proofs were introduced. Proofs which fail verification are logged. This compatibility
+		/// mode is meant to give tooling, which may still submit placeholders such as `0x00`, the
+		/// time to submit ownership proofs.
+		/// Once it does, this should be switched to `true`. Keys registered beforehand stay valid.
+		type EnforceOwnershipProof: Get<bool>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -581,6 +590,11 @@ pub mod pallet {
 		///
 		/// The dispatch origin of this function must be signed.
 		///
+		/// `proof` must prove that the caller holds the private keys of `keys`, see
+		/// [`OpaqueKeys::ownership_proof_is_valid_for`]. It is created by `create_ownership_proof`
+		/// of the keys, with the SCALE encoded caller account as owner. It is only required if
+		/// [`Config::EnforceOwnershipProof`] is set.
+		///
 		/// ## Complexity
 		/// - `O(1)`. Actual cost depends on the number of length of `T::Keys::key_ids()` which is
 		///   fixed.
@@ -588,7 +602,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::set_keys())]
 		pub fn set_keys(origin: OriginFor<T>, keys: T::Keys, proof: Vec<u8>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			ensure!(keys.ownership_proof_is_valid(&proof), Error::<T>::InvalidProof);
+			Self::ensure_ownership_proof(&who, &keys, &proof)?;
 
 			Self::do_set_keys(&who, keys)?;
 			Ok(())
@@ -781,6 +795,31 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
+	/// Ensure that `proof` proves the ownership of `keys` by `account`.
+	///
+	/// Any proof is accepted unless [`Config::EnforceOwnershipProof`] is set. Non-empty proofs
+	/// which fail verification are logged.
+	fn ensure_ownership_proof(
+		account: &T::AccountId,
+		keys: &T::Keys,
+		proof: &[u8],
+	) -> DispatchResult {
+		let enforce = T::EnforceOwnershipProof::get();
+		if proof.is_empty() && !enforce {
+			return Ok(())
+		}
+
+		if !keys.ownership_proof_is_valid_for(&account.encode(), proof) {
+			ensure!(!enforce, Error::<T>::InvalidProof);
+			log::warn!(
+				target: "runtime::session",
+				"accepting the session keys of {:?} with an invalid ownership proof",
+				account,
+			);
+		}
+		Ok(())
+	}
+
 	/// Perform the set_key operation, checking for duplicates. Does not set `Changed`.
 	///
 	/// This ensures that the reference counter in system is incremented appropriately and as such
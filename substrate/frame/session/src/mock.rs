@@ -110,6 +110,7 @@ parameter_types! {
 	// Stores if `on_before_session_end` was called
 	pub static BeforeSessionEndCalled: bool = false;
 	pub static ValidatorAccounts: BTreeMap<u64, u64> = BTreeMap::new();
+	pub static EnforceOwnershipProof: bool = false;
 }
 
 pub struct TestShouldEndSession;
@@ -288,6 +289,7 @@ impl Config for Test {
 	type ValidatorId = u64;
 	type ValidatorIdOf = TestValidatorIdOf;
 	type Keys = MockSessionKeys;
+	type EnforceOwnershipProof = EnforceOwnershipProof;
 	type RuntimeEvent = RuntimeEvent;
 	type NextSessionRotation = ();
 	type WeightInfo = ();
@@ -21,11 +21,11 @@ use super::*;
 use crate::mock::{
 	authorities, before_session_end_called, force_new_session, new_test_ext,
 	reset_before_session_end_called, session_changed, set_next_validators, set_session_length,
-	PreUpgradeMockSessionKeys, RuntimeOrigin, Session, SessionChanged, System, Test,
-	TestSessionChanged, TestValidatorIdOf,
+	EnforceOwnershipProof, MockSessionKeys, PreUpgradeMockSessionKeys, RuntimeOrigin, Session,
+	SessionChanged, System, Test, TestSessionChanged, TestValidatorIdOf,
 };
 
-use codec::Decode;
+use codec::{Decode, Encode};
 use sp_core::crypto::key_types::DUMMY;
 use sp_runtime::testing::UintAuthorityId;
 
@@ -229,6 +229,65 @@ fn duplicates_are_not_allowed() {
 	});
 }
 
+#[test]
+fn ownership_proof_is_checked() {
+	new_test_ext().execute_with(|| {
+		TestValidatorIdOf::set(vec![(1, 1), (2, 2), (3, 3), (4, 4)].into_iter().collect());
+		EnforceOwnershipProof::set(true);
+		let keys: MockSessionKeys = UintAuthorityId(10).into();
+		let proof = keys.create_ownership_proof(&4u64.encode()).unwrap();
+
+		// a proof for another account is rejected.
+		assert_noop!(
+			Session::set_keys(RuntimeOrigin::signed(3), keys.clone(), proof.clone()),
+			Error::<Test>::InvalidProof,
+		);
+		// so is a proof with trailing data.
+		let mut long_proof = proof.clone();
+		long_proof.push(0);
+		assert_noop!(
+			Session::set_keys(RuntimeOrigin::signed(4), keys.clone(), long_proof),
+			Error::<Test>::InvalidProof,
+		);
+
+		assert_ok!(Session::set_keys(RuntimeOrigin::signed(4), keys, proof));
+		assert_eq!(Session::load_keys(&4), Some(UintAuthorityId(10).into()));
+	});
+}
+
+#[test]
+fn ownership_proof_can_be_enforced() {
+	new_test_ext().execute_with(|| {
+		TestValidatorIdOf::set(vec![(1, 1), (2, 2), (3, 3), (4, 4)].into_iter().collect());
+
+		// in compatibility mode, an empty proof is accepted.
+		assert_ok!(Session::set_keys(RuntimeOrigin::signed(3), UintAuthorityId(10).into(), vec![]));
+		// so is an invalid one, such as the `0x00` placeholder submitted by some tooling.
+		assert_ok!(Session::set_keys(
+			RuntimeOrigin::signed(2),
+			UintAuthorityId(12).into(),
+			vec![0x00]
+		));
+		assert_eq!(Session::load_keys(&2), Some(UintAuthorityId(12).into()));
+
+		EnforceOwnershipProof::set(true);
+		assert_noop!(
+			Session::set_keys(RuntimeOrigin::signed(4), UintAuthorityId(11).into(), vec![]),
+			Error::<Test>::InvalidProof,
+		);
+		assert_noop!(
+			Session::set_keys(RuntimeOrigin::signed(4), UintAuthorityId(11).into(), vec![0x00]),
+			Error::<Test>::InvalidProof,
+		);
+		let keys: MockSessionKeys = UintAuthorityId(11).into();
+		let proof = keys.create_ownership_proof(&4u64.encode()).unwrap();
+		assert_ok!(Session::set_keys(RuntimeOrigin::signed(4), keys, proof));
+
+		// keys registered in compatibility mode are kept.
+		assert_eq!(Session::load_keys(&3), Some(UintAuthorityId(10).into()));
+	});
+}
+
 #[test]
 fn session_changed_flag_works() {
 	reset_before_session_end_called();
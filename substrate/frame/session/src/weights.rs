@@ -66,10 +66,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Session KeyOwner (r:4 w:4)
 	/// Proof Skipped: Session KeyOwner (max_values: None, max_size: None, mode: Measured)
 	fn set_keys() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1924`
-		//  Estimated: `12814`
-		// Minimum execution time: 55_459_000 picoseconds.
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
 		Weight::from_parts(56_180_000, 12814)
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
@@ -100,10 +97,7 @@ impl WeightInfo for () {
 	/// Storage: Session KeyOwner (r:4 w:4)
 	/// Proof Skipped: Session KeyOwner (max_values: None, max_size: None, mode: Measured)
 	fn set_keys() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1924`
-		//  Estimated: `12814`
-		// Minimum execution time: 55_459_000 picoseconds.
+		// PLACEHOLDER: not benchmarked yet, regenerate with the `benchmark pallet` command.
 		Weight::from_parts(56_180_000, 12814)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
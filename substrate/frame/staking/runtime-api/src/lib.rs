@@ -20,6 +20,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
+use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
 	pub trait StakingApi<Balance, AccountId>
@@ -33,4 +34,21 @@ sp_api::decl_runtime_apis! {
 		/// Returns the page count of exposures for a validator in a given era.
 		fn eras_stakers_page_count(era: sp_staking::EraIndex, account: AccountId) -> sp_staking::Page;
 	}
+
+	/// The API to query the history of reported offences.
+	pub trait StakingOffencesApi<AccountId, Balance>
+		where
+			AccountId: Codec,
+			Balance: Codec,
+	{
+		/// Returns the offences that happened in the given era.
+		fn offences_in_era(
+			era: sp_staking::EraIndex,
+		) -> Vec<sp_staking::offence::OffenceRecord<AccountId, Balance>>;
+
+		/// Returns the offences of the given validator within the bonding period.
+		fn offences_of(
+			validator: AccountId,
+		) -> Vec<sp_staking::offence::OffenceRecord<AccountId, Balance>>;
+	}
 }
@@ -559,6 +559,17 @@ benchmarks! {
 		}
 	}
 
+	restore_ledger {
+		let (stash, controller) = create_stash_controller::<T>(0, 100, RewardDestination::Staked)?;
+		// corrupt the bond, so that the controller of the stash holds no ledger.
+		Ledger::<T>::remove(&controller);
+		let total = T::Currency::free_balance(&stash);
+	}: _(RawOrigin::Root, stash.clone(), None, total, None)
+	verify {
+		assert_eq!(Bonded::<T>::get(&stash), Some(stash.clone()));
+		assert_eq!(Ledger::<T>::get(&stash).unwrap().total, total);
+	}
+
 	force_unstake {
 		// Slashing Spans
 		let s in 0 .. MAX_SPANS;
@@ -201,6 +201,28 @@ impl<T: Config> StakingLedger<T> {
 		}
 	}
 
+	/// Moves the ledger from its controller to its stash, so that the stash becomes its own
+	/// controller.
+	///
+	/// Fails if the stash is not bonded to the controller of the ledger, or if the stash already
+	/// holds a ledger as the controller of another stash.
+	pub(crate) fn set_controller_to_stash(self) -> Result<(), Error<T>> {
+		let controller = self.controller().ok_or(Error::<T>::NotController)?;
+
+		if <Bonded<T>>::get(&self.stash) != Some(controller.clone()) {
+			return Err(Error::<T>::BadState)
+		}
+		if <Ledger<T>>::contains_key(&self.stash) {
+			return Err(Error::<T>::AlreadyPaired)
+		}
+
+		<Ledger<T>>::remove(&controller);
+		<Ledger<T>>::insert(&self.stash, &self);
+		<Bonded<T>>::insert(&self.stash, &self.stash);
+
+		Ok(())
+	}
+
 	/// Clears all data related to a staking ledger and its bond in both [`Ledger`] and [`Bonded`]
 	/// storage items and updates the stash staking lock.
 	pub(crate) fn kill(stash: &T::AccountId) -> Result<(), Error<T>> {
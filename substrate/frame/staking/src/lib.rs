@@ -426,6 +426,18 @@ pub struct ValidatorPrefs {
 	pub blocked: bool,
 }
 
+/// The state of the bond of a stash, as far as its [`Bonded`] and [`Ledger`] entries are
+/// concerned.
+#[derive(PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+pub(crate) enum LedgerIntegrityState {
+	/// The controller of the stash holds the ledger of the stash.
+	Ok,
+	/// The controller of the stash holds the ledger of another stash.
+	Corrupted,
+	/// The controller of the stash holds no ledger.
+	CorruptedKilled,
+}
+
 /// Just a Balance/BlockNumber tuple to encode when a chunk of funds will be unlocked.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct UnlockChunk<Balance: HasCompact + MaxEncodedLen> {
@@ -923,6 +935,39 @@ impl<Balance: AtLeast32BitUnsigned + Clone, T: Get<&'static PiecewiseLinear<'sta
 	}
 }
 
+/// Handler for determining how much of its era reward a validator keeps, given the offences it
+/// committed in the era.
+///
+/// This applies on top of any slash, and to the nominators of the validator as well. It is meant
+/// as a softer, automatic penalty for repeated offences which are not slashed, or only slightly.
+pub trait RewardSuppression {
+	/// Determine the portion of the era reward kept by a validator with `offences` in the era,
+	/// `slash_fraction` being the highest among them.
+	///
+	/// Only called for validators with at least one offence in the era.
+	fn reward_kept(offences: u32, slash_fraction: Perbill) -> Perbill;
+}
+
+impl RewardSuppression for () {
+	fn reward_kept(_offences: u32, _slash_fraction: Perbill) -> Perbill {
+		Perbill::one()
+	}
+}
+
+/// Suppresses `Step` of the era reward for every offence of a validator in the era, beyond the
+/// first `Tolerated` offences.
+pub struct SuppressPerOffence<Step, Tolerated>(sp_std::marker::PhantomData<(Step, Tolerated)>);
+impl<Step: Get<Perbill>, Tolerated: Get<u32>> RewardSuppression
+	for SuppressPerOffence<Step, Tolerated>
+{
+	fn reward_kept(offences: u32, _slash_fraction: Perbill) -> Perbill {
+		let suppressed = offences.saturating_sub(Tolerated::get());
+		Perbill::one().saturating_sub(Perbill::from_parts(
+			Step::get().deconstruct().saturating_mul(suppressed),
+		))
+	}
+}
+
 /// Mode of era-forcing.
 #[derive(
 	Copy,
@@ -59,6 +59,205 @@ impl Default for ObsoleteReleases {
 #[storage_alias]
 type StorageVersion<T: Config> = StorageValue<Pallet<T>, ObsoleteReleases, ValueQuery>;
 
+/// Migration of the legacy non-paged exposures to paged exposures.
+///
+/// The runtime upgrade only starts the migration, which is then done over multiple blocks by
+/// [`v15::migrate_step`] in `on_idle`. The progress is kept in `LegacyExposureMigration`.
+///
+/// Every exposure left in `ErasStakers` is moved to `ErasStakersOverview` and `ErasStakersPaged`.
+/// The nominators of the clipped exposure in `ErasStakersClipped` come first, in their own pages.
+/// The other nominators follow in pages that are marked as claimed in `ClaimedRewards`, so that
+/// the rewards of legacy eras are still paid out only to the clipped nominators. Eras whose reward
+/// was already claimed have all pages marked as claimed. `ErasStakers` and `ErasStakersClipped`
+/// are emptied, and `StakingLedger::legacy_claimed_rewards` is cleared for all ledgers afterwards.
+pub mod v15 {
+	use super::*;
+	use frame_support::weights::WeightMeter;
+	use sp_std::collections::btree_set::BTreeSet;
+
+	/// Progress of the v15 migration.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum MigrationCursor<AccountId> {
+		/// Legacy exposures are being moved to paged exposures.
+		Exposures,
+		/// The legacy claimed rewards of the ledgers are being cleared, continuing after the
+		/// given controller.
+		Ledgers(Option<AccountId>),
+	}
+
+	pub struct MigrateToV15<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV15<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let current = Pallet::<T>::current_storage_version();
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+
+			if current == 15 && on_chain == 14 {
+				LegacyExposureMigration::<T>::put(MigrationCursor::Exposures);
+				current.put::<Pallet<T>>();
+
+				log!(info, "v15 applied successfully, legacy exposures are migrated lazily.");
+				T::DbWeight::get().reads_writes(1, 2)
+			} else {
+				log!(warn, "v15 not applied.");
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 15, "v15 not applied");
+			ensure!(
+				LegacyExposureMigration::<T>::exists() ||
+					ErasStakers::<T>::iter_keys().next().is_none(),
+				"legacy exposures are left without an ongoing migration"
+			);
+			Ok(())
+		}
+	}
+
+	/// Migrate as many legacy exposures and ledgers as `meter` allows.
+	///
+	/// Does nothing if no migration is ongoing.
+	pub fn migrate_step<T: Config>(meter: &mut WeightMeter) {
+		// reading and writing the cursor.
+		if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+			return
+		}
+		let Some(mut cursor) = LegacyExposureMigration::<T>::get() else { return };
+
+		loop {
+			cursor = match &cursor {
+				MigrationCursor::Exposures => {
+					if meter.try_consume(T::DbWeight::get().reads(2)).is_err() {
+						break
+					}
+					let next = ErasStakers::<T>::iter_keys()
+						.next()
+						.or_else(|| ErasStakersClipped::<T>::iter_keys().next());
+					match next {
+						Some((era, validator)) => {
+							if migrate_exposure::<T>(era, &validator, meter).is_err() {
+								break
+							}
+							MigrationCursor::Exposures
+						},
+						None => {
+							log!(info, "v15: legacy exposures migrated, clearing ledgers.");
+							MigrationCursor::Ledgers(None)
+						},
+					}
+				},
+				MigrationCursor::Ledgers(last) => {
+					if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+						break
+					}
+					let mut ledgers = match last {
+						Some(last) => Ledger::<T>::iter_from(Ledger::<T>::hashed_key_for(last)),
+						None => Ledger::<T>::iter(),
+					};
+					let Some((controller, mut ledger)) = ledgers.next() else {
+						LegacyExposureMigration::<T>::kill();
+						log!(info, "v15: migration of legacy exposures finished.");
+						return
+					};
+					if !ledger.legacy_claimed_rewards.is_empty() {
+						ledger.legacy_claimed_rewards = Default::default();
+						Ledger::<T>::insert(&controller, ledger);
+					}
+					MigrationCursor::Ledgers(Some(controller))
+				},
+			};
+		}
+
+		LegacyExposureMigration::<T>::put(cursor);
+	}
+
+	/// Move the legacy exposure of `validator` in `era` to the paged exposure storage.
+	///
+	/// Returns an error without changing anything if `meter` can not afford it.
+	fn migrate_exposure<T: Config>(
+		era: EraIndex,
+		validator: &T::AccountId,
+		meter: &mut WeightMeter,
+	) -> Result<(), ()> {
+		let clipped = ErasStakersClipped::<T>::get(era, validator);
+		let exposure =
+			ErasStakers::<T>::try_get(era, validator).unwrap_or_else(|_| clipped.clone());
+
+		let page_size = T::MaxExposurePageSize::get().defensive_max(1);
+		let max_pages =
+			exposure.others.len().saturating_add(page_size as usize) as u64 / page_size as u64 + 1;
+		meter.try_consume(T::DbWeight::get().reads_writes(7, 4 + max_pages))?;
+
+		ErasStakers::<T>::remove(era, validator);
+		ErasStakersClipped::<T>::remove(era, validator);
+
+		// the exposure might already have been stored in a paged manner.
+		if ErasStakersOverview::<T>::contains_key(era, validator) {
+			return Ok(())
+		}
+
+		let rewarded: BTreeSet<_> = clipped.others.into_iter().map(|n| n.who).collect();
+		let (rewarded, unrewarded): (Vec<_>, Vec<_>) =
+			exposure.others.into_iter().partition(|n| rewarded.contains(&n.who));
+		let nominator_count = (rewarded.len() + unrewarded.len()) as u32;
+
+		let (_, mut pages) =
+			Exposure { total: exposure.total, own: exposure.own, others: rewarded }
+				.into_pages(page_size);
+		let (_, unrewarded_pages) =
+			Exposure { total: exposure.total, own: exposure.own, others: unrewarded }
+				.into_pages(page_size);
+		// Without any rewarded nominator, a page without nominators still carries their stake.
+		if pages.is_empty() && !unrewarded_pages.is_empty() {
+			pages.push(ExposurePage { page_total: Zero::zero(), others: Vec::new() });
+		}
+		let rewarded_page_count = pages.len() as Page;
+
+		// The stake of the nominators that are not rewarded is accounted to the first page, so
+		// that the validator still receives its whole commission, as for the clipped exposure.
+		if let Some(first) = pages.first_mut() {
+			for page in unrewarded_pages.iter() {
+				first.page_total.saturating_accrue(page.page_total);
+			}
+		}
+		pages.extend(
+			unrewarded_pages
+				.into_iter()
+				.map(|page| ExposurePage { page_total: Zero::zero(), others: page.others }),
+		);
+
+		let overview = PagedExposureMetadata {
+			total: exposure.total,
+			own: exposure.own,
+			nominator_count,
+			page_count: pages.len() as Page,
+		};
+		ErasStakersOverview::<T>::insert(era, validator, overview);
+		for (page, exposure_page) in pages.into_iter().enumerate() {
+			ErasStakersPaged::<T>::insert((era, validator, page as Page), exposure_page);
+		}
+
+		// legacy exposures are paid out in a single page, marked in the ledger or, since v14, as
+		// page zero in `ClaimedRewards`.
+		let claimed = ClaimedRewards::<T>::get(era, validator).contains(&0) ||
+			Bonded::<T>::get(validator)
+				.and_then(|controller| Ledger::<T>::get(controller))
+				.map_or(false, |ledger| ledger.legacy_claimed_rewards.contains(&era));
+		let page_count = EraInfo::<T>::get_page_count(era, validator);
+		let claimed_pages: Vec<Page> = if claimed {
+			(0..page_count).collect()
+		} else {
+			(rewarded_page_count..page_count).collect()
+		};
+		if !claimed_pages.is_empty() {
+			ClaimedRewards::<T>::insert(era, validator, claimed_pages);
+		}
+
+		Ok(())
+	}
+}
+
 /// Migration of era exposure storage items to paged exposures.
 /// Changelog: [v14.](https://github.com/paritytech/substrate/blob/ankan/paged-rewards-rebased2/frame/staking/CHANGELOG.md#14)
 pub mod v14 {
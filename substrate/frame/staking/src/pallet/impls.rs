@@ -40,7 +40,9 @@ use sp_runtime::{
 };
 use sp_staking::{
 	currency_to_vote::CurrencyToVote,
-	offence::{DisableStrategy, OffenceDetails, OnOffenceHandler},
+	offence::{
+		DisableStrategy, Kind, OffenceDetails, OffenceRecord, OnOffenceHandler, SlashDisposition,
+	},
 	EraIndex, Page, SessionIndex, Stake,
 	StakingAccount::{self, Controller, Stash},
 	StakingInterface,
@@ -50,8 +52,9 @@ use sp_std::prelude::*;
 use crate::{
 	election_size_tracker::StaticTracker, log, slashing, weights::WeightInfo, ActiveEraInfo,
 	BalanceOf, EraInfo, EraPayout, Exposure, ExposureOf, Forcing, IndividualExposure,
-	MaxNominationsOf, MaxWinnersOf, Nominations, NominationsQuota, PositiveImbalanceOf,
-	RewardDestination, SessionInterface, StakingLedger, ValidatorPrefs,
+	LedgerIntegrityState, MaxNominationsOf, MaxWinnersOf, Nominations, NominationsQuota,
+	PositiveImbalanceOf, RewardDestination, RewardSuppression, SessionInterface, StakingLedger,
+	ValidatorPrefs,
 };
 
 use super::pallet::*;
@@ -79,6 +82,21 @@ impl<T: Config> Pallet<T> {
 		StakingLedger::<T>::reward_destination(account)
 	}
 
+	/// Inspects whether the controller of `stash` holds the ledger of `stash`.
+	///
+	/// Returns [`Error::NotStash`] if `stash` is not bonded.
+	pub(crate) fn inspect_bond_state(
+		stash: &T::AccountId,
+	) -> Result<LedgerIntegrityState, Error<T>> {
+		let controller = <Bonded<T>>::get(stash).ok_or(Error::<T>::NotStash)?;
+
+		match <Ledger<T>>::get(&controller) {
+			Some(ledger) if ledger.stash == *stash => Ok(LedgerIntegrityState::Ok),
+			Some(_) => Ok(LedgerIntegrityState::Corrupted),
+			None => Ok(LedgerIntegrityState::CorruptedKilled),
+		}
+	}
+
 	/// Fetches the controller bonded to a stash account, if any.
 	pub fn bonded(stash: &T::AccountId) -> Option<T::AccountId> {
 		StakingLedger::<T>::paired_account(Stash(stash.clone()))
@@ -255,8 +273,16 @@ impl<T: Config> Pallet<T> {
 		let validator_total_reward_part =
 			Perbill::from_rational(validator_reward_points, total_reward_points);
 
+		// Offences of the validator in the era cut into the reward of validator + nominators.
+		let (offences, slash_fraction) = <ErasValidatorOffences<T>>::get(&era, &stash);
+		let reward_kept = if offences.is_zero() {
+			Perbill::one()
+		} else {
+			T::RewardSuppression::reward_kept(offences, slash_fraction)
+		};
+
 		// This is how much validator + nominators are entitled to.
-		let validator_total_payout = validator_total_reward_part * era_payout;
+		let validator_total_payout = reward_kept * (validator_total_reward_part * era_payout);
 
 		let validator_commission = EraInfo::<T>::get_validator_commission(era, &ledger.stash);
 		// total commission validator takes across all nominator pages
@@ -275,6 +301,14 @@ impl<T: Config> Pallet<T> {
 			era_index: era,
 			validator_stash: stash.clone(),
 		});
+		if reward_kept < Perbill::one() {
+			Self::deposit_event(Event::<T>::RewardSuppressed {
+				era_index: era,
+				validator_stash: stash.clone(),
+				offences,
+				kept: reward_kept,
+			});
+		}
 
 		let mut total_imbalance = PositiveImbalanceOf::<T>::zero();
 		// We can now make total validator payout:
@@ -738,6 +772,10 @@ impl<T: Config> Pallet<T> {
 		debug_assert!(cursor.maybe_cursor.is_none());
 		cursor = <ErasStakersOverview<T>>::clear_prefix(era_index, u32::MAX, None);
 		debug_assert!(cursor.maybe_cursor.is_none());
+		cursor = <ErasValidatorOffences<T>>::clear_prefix(era_index, u32::MAX, None);
+		debug_assert!(cursor.maybe_cursor.is_none());
+		cursor = <ErasValidatorOffenceSessions<T>>::clear_prefix(era_index, u32::MAX, None);
+		debug_assert!(cursor.maybe_cursor.is_none());
 
 		<ErasValidatorReward<T>>::remove(era_index);
 		<ErasRewardPoints<T>>::remove(era_index);
@@ -820,6 +858,21 @@ impl<T: Config> Pallet<T> {
 	///
 	/// This function is self-weighing as [`DispatchClass::Mandatory`].
 	pub fn get_npos_voters(bounds: DataProviderBounds) -> Vec<VoterOf<Self>> {
+		// iterating from the start of the voter list can't fail.
+		Self::get_npos_voters_from(bounds, None).defensive_unwrap_or_default()
+	}
+
+	/// Same as [`Self::get_npos_voters`], but starts iterating the voter list right after
+	/// `start_after`, if given.
+	///
+	/// This is used to build the voter snapshot in several pages. [`MinimumActiveStake`] is then
+	/// only lowered by the later pages.
+	///
+	/// Returns an error if `start_after` is not in the voter list.
+	pub fn get_npos_voters_from(
+		bounds: DataProviderBounds,
+		start_after: Option<&T::AccountId>,
+	) -> Result<Vec<VoterOf<Self>>, &'static str> {
 		let mut voters_size_tracker: StaticTracker<Self> = StaticTracker::default();
 
 		let final_predicted_len = {
@@ -837,7 +890,11 @@ impl<T: Config> Pallet<T> {
 		let mut nominators_taken = 0u32;
 		let mut min_active_stake = u64::MAX;
 
-		let mut sorted_voters = T::VoterList::iter();
+		let mut sorted_voters = match start_after {
+			Some(start) => T::VoterList::iter_from(start)
+				.map_err(|_| "voter to start after is not in the voter list")?,
+			None => T::VoterList::iter(),
+		};
 		while all_voters.len() < final_predicted_len as usize &&
 			voters_seen < (NPOS_MAX_ITERATIONS_COEFFICIENT * final_predicted_len as u32)
 		{
@@ -918,7 +975,11 @@ impl<T: Config> Pallet<T> {
 		let min_active_stake: T::CurrencyBalance =
 			if all_voters.is_empty() { Zero::zero() } else { min_active_stake.into() };
 
-		MinimumActiveStake::<T>::put(min_active_stake);
+		if start_after.is_none() {
+			MinimumActiveStake::<T>::put(min_active_stake);
+		} else if !all_voters.is_empty() {
+			MinimumActiveStake::<T>::mutate(|current| *current = min_active_stake.min(*current));
+		}
 
 		log!(
 			info,
@@ -928,7 +989,7 @@ impl<T: Config> Pallet<T> {
 			nominators_taken
 		);
 
-		all_voters
+		Ok(all_voters)
 	}
 
 	/// Get the targets for an upcoming npos election.
@@ -1109,6 +1170,84 @@ impl<T: Config> Pallet<T> {
 	pub fn api_eras_stakers_page_count(era: EraIndex, account: T::AccountId) -> Page {
 		EraInfo::<T>::get_page_count(era, &account)
 	}
+
+	/// Returns the era in which `session` happened, if it is still within the bonding period.
+	pub fn era_of_session(session: SessionIndex) -> Option<EraIndex> {
+		BondedEras::<T>::get()
+			.iter()
+			.rev()
+			.find(|(_, start_session)| *start_session <= session)
+			.map(|(era, _)| *era)
+	}
+
+	/// Combines the given offences with the slashing state of the offenders.
+	///
+	/// Offences of eras that are no longer bonded are skipped, as their slashing state has been
+	/// pruned.
+	///
+	/// Used by the runtime API.
+	pub fn api_offence_records(
+		offences: impl IntoIterator<Item = (T::AccountId, Kind, SessionIndex)>,
+	) -> Vec<OffenceRecord<T::AccountId, BalanceOf<T>>> {
+		let slash_defer_duration = T::SlashDeferDuration::get();
+		let validators = T::SessionInterface::validators();
+		let offending_validators = Self::offending_validators();
+
+		offences
+			.into_iter()
+			.filter_map(|(offender, kind, session_index)| {
+				let era = Self::era_of_session(session_index)?;
+
+				let (slash_fraction, disposition) =
+					match ValidatorSlashInEra::<T>::get(era, &offender) {
+						None => (Perbill::zero(), SlashDisposition::NotSlashed),
+						Some((slash_fraction, own)) => {
+							let apply_at =
+								era.saturating_add(slash_defer_duration).saturating_add(One::one());
+							let deferred = UnappliedSlashes::<T>::get(apply_at)
+								.into_iter()
+								.rev()
+								.find(|unapplied| unapplied.validator == offender);
+
+							let disposition = match deferred {
+								Some(unapplied) => SlashDisposition::Deferred {
+									apply_at,
+									own: unapplied.own,
+									others: unapplied
+										.others
+										.iter()
+										.fold(Zero::zero(), |acc: BalanceOf<T>, (_, value)| {
+											acc.saturating_add(*value)
+										}),
+								},
+								None => SlashDisposition::Applied { own },
+							};
+							(slash_fraction, disposition)
+						},
+					};
+
+				let disabled = validators
+					.iter()
+					.position(|validator| *validator == offender)
+					.and_then(|index| {
+						offending_validators
+							.binary_search_by_key(&(index as u32), |(index, _)| *index)
+							.ok()
+					})
+					.map_or(false, |position| offending_validators[position].1);
+
+				Some(OffenceRecord {
+					offender,
+					kind,
+					session_index,
+					era,
+					slash_fraction,
+					disposition,
+					disabled,
+				})
+			})
+			.collect()
+	}
 }
 
 impl<T: Config> ElectionDataProvider for Pallet<T> {
@@ -1133,6 +1272,20 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 		Ok(voters)
 	}
 
+	fn electing_voters_from(
+		bounds: DataProviderBounds,
+		start_after: Option<&T::AccountId>,
+	) -> data_provider::Result<Vec<VoterOf<Self>>> {
+		let voters = Self::get_npos_voters_from(bounds, start_after)?;
+
+		debug_assert!(!bounds.exhausted(
+			SizeBound(voters.encoded_size() as u32).into(),
+			CountBound(voters.len() as u32).into()
+		));
+
+		Ok(voters)
+	}
+
 	fn electable_targets(bounds: DataProviderBounds) -> data_provider::Result<Vec<T::AccountId>> {
 		let targets = Self::get_npos_targets(bounds);
 
@@ -1437,6 +1590,22 @@ where
 				slash_era,
 			});
 
+			// Record the offence for the suppression of the rewards of the era, unless they can no
+			// longer be paid out. Offences that were already reported are passed again with every
+			// concurrent report; they are only counted again if their slash fraction increased.
+			if slash_era >= active_era.saturating_sub(T::HistoryDepth::get()) {
+				let key = (stash.clone(), slash_session);
+				let counted = <ErasValidatorOffenceSessions<T>>::get(slash_era, &key);
+				if counted.map_or(true, |counted| *slash_fraction > counted) {
+					<ErasValidatorOffenceSessions<T>>::insert(slash_era, &key, *slash_fraction);
+					<ErasValidatorOffences<T>>::mutate(slash_era, stash, |(offences, fraction)| {
+						offences.saturating_inc();
+						*fraction = (*fraction).max(*slash_fraction);
+					});
+				}
+				add_db_reads_writes(2, 2);
+			}
+
 			if let Some(mut unapplied) = unapplied {
 				let nominators_len = unapplied.others.len() as u64;
 				let reporters_len = details.reporters.len() as u64;
@@ -1853,7 +2022,13 @@ impl<T: Config> Pallet<T> {
 
 	fn check_ledgers() -> Result<(), TryRuntimeError> {
 		Bonded::<T>::iter()
-			.map(|(_, ctrl)| Self::ensure_ledger_consistent(ctrl))
+			.map(|(stash, ctrl)| {
+				ensure!(
+					matches!(Self::inspect_bond_state(&stash), Ok(LedgerIntegrityState::Ok)),
+					"bonded stash is not paired with its ledger"
+				);
+				Self::ensure_ledger_consistent(ctrl)
+			})
 			.collect::<Result<Vec<_>, _>>()?;
 		Ok(())
 	}
@@ -27,7 +27,7 @@ use frame_support::{
 		Currency, Defensive, DefensiveSaturating, EnsureOrigin, EstimateNextNewSession, Get,
 		LockableCurrency, OnUnbalanced, UnixTime,
 	},
-	weights::Weight,
+	weights::{Weight, WeightMeter},
 	BoundedVec,
 };
 use frame_system::{ensure_root, ensure_signed, pallet_prelude::*};
@@ -48,9 +48,10 @@ pub use impls::*;
 
 use crate::{
 	slashing, weights::WeightInfo, AccountIdLookupOf, ActiveEraInfo, BalanceOf, EraPayout,
-	EraRewardPoints, Exposure, ExposurePage, Forcing, MaxNominationsOf, NegativeImbalanceOf,
-	Nominations, NominationsQuota, PositiveImbalanceOf, RewardDestination, SessionInterface,
-	StakingLedger, UnappliedSlash, UnlockChunk, ValidatorPrefs,
+	EraRewardPoints, Exposure, ExposurePage, Forcing, LedgerIntegrityState, MaxNominationsOf,
+	NegativeImbalanceOf, Nominations, NominationsQuota, PositiveImbalanceOf, RewardDestination,
+	RewardSuppression, SessionInterface, StakingLedger, UnappliedSlash, UnlockChunk,
+	ValidatorPrefs,
 };
 
 // The speculative number of spans are used as an input of the weight annotation of
@@ -67,7 +68,7 @@ pub mod pallet {
 	use super::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(14);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(15);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -278,6 +279,12 @@ pub mod pallet {
 		/// WARNING: this only reports slashing events for the time being.
 		type EventListeners: sp_staking::OnStakingUpdate<Self::AccountId, BalanceOf<Self>>;
 
+		/// Determines how much of the era reward of a validator, and of its nominators, is kept
+		/// when the validator committed offences in that era.
+		///
+		/// Use `()` not to suppress any rewards.
+		type RewardSuppression: RewardSuppression;
+
 		/// Some parameters of the benchmarking.
 		type BenchmarkingConfig: BenchmarkingConfig;
 
@@ -416,7 +423,8 @@ pub mod pallet {
 	/// Is it removed after [`Config::HistoryDepth`] eras.
 	/// If stakers hasn't been set or has been removed then empty exposure is returned.
 	///
-	/// Note: Deprecated since v14. Use `EraInfo` instead to work with exposures.
+	/// Note: Deprecated since v14. Use `EraInfo` instead to work with exposures. Emptied by the
+	/// v15 migration, which moves the remaining exposures to `ErasStakersPaged`.
 	#[pallet::storage]
 	#[pallet::unbounded]
 	pub type ErasStakers<T: Config> = StorageDoubleMap<
@@ -467,7 +475,8 @@ pub mod pallet {
 	/// It is removed after [`Config::HistoryDepth`] eras.
 	/// If stakers hasn't been set or has been removed then empty exposure is returned.
 	///
-	/// Note: Deprecated since v14. Use `EraInfo` instead to work with exposures.
+	/// Note: Deprecated since v14. Use `EraInfo` instead to work with exposures. Emptied by the
+	/// v15 migration, which moves the remaining exposures to `ErasStakersPaged`.
 	#[pallet::storage]
 	#[pallet::unbounded]
 	#[pallet::getter(fn eras_stakers_clipped)]
@@ -519,6 +528,13 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Progress of the lazy migration of legacy exposures to paged exposures.
+	///
+	/// Set by [`crate::migrations::v15::MigrateToV15`] and removed once the migration is done.
+	#[pallet::storage]
+	pub(crate) type LegacyExposureMigration<T: Config> =
+		StorageValue<_, crate::migrations::v15::MigrationCursor<T::AccountId>, OptionQuery>;
+
 	/// Similar to `ErasStakers`, this holds the preferences of validators.
 	///
 	/// This is keyed first by the era index to allow bulk deletion and then the stash account.
@@ -544,6 +560,37 @@ pub mod pallet {
 	#[pallet::getter(fn eras_validator_reward)]
 	pub type ErasValidatorReward<T: Config> = StorageMap<_, Twox64Concat, EraIndex, BalanceOf<T>>;
 
+	/// The number of offences committed by each validator in an era, along with the highest slash
+	/// fraction among them, for the last [`Config::HistoryDepth`] eras.
+	///
+	/// The era reward of the validator is suppressed as per [`Config::RewardSuppression`].
+	#[pallet::storage]
+	pub type ErasValidatorOffences<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Twox64Concat,
+		T::AccountId,
+		(u32, Perbill),
+		ValueQuery,
+	>;
+
+	/// The highest slash fraction of the offences of a validator in a session that are counted in
+	/// [`ErasValidatorOffences`], for the last [`Config::HistoryDepth`] eras.
+	///
+	/// Reports of an offence that was already counted are ignored unless its slash fraction
+	/// increased.
+	#[pallet::storage]
+	pub type ErasValidatorOffenceSessions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Twox64Concat,
+		(T::AccountId, SessionIndex),
+		Perbill,
+		OptionQuery,
+	>;
+
 	/// Rewards for the last [`Config::HistoryDepth`] eras.
 	/// If reward hasn't been set or has been removed then 0 reward is returned.
 	#[pallet::storage]
@@ -785,6 +832,20 @@ pub mod pallet {
 		SnapshotTargetsSizeExceeded { size: u32 },
 		/// A new force era mode was set.
 		ForceEra { mode: Forcing },
+		/// A batch of controllers has been deprecated, except for `failures` of them whose bonds
+		/// are inconsistent.
+		ControllerBatchDeprecated { failures: u32 },
+		/// The bond of a stash has been restored, with `controller` as its controller and `total`
+		/// as the total balance of its ledger.
+		LedgerRestored { stash: T::AccountId, controller: T::AccountId, total: BalanceOf<T> },
+		/// The era reward of a validator and its nominators has been cut to `kept` of it, as the
+		/// validator committed `offences` in the era. Emitted for every page of the payout.
+		RewardSuppressed {
+			era_index: EraIndex,
+			validator_stash: T::AccountId,
+			offences: u32,
+			kept: Perbill,
+		},
 	}
 
 	#[pallet::error]
@@ -847,6 +908,8 @@ pub mod pallet {
 		BoundNotMet,
 		/// Used when attempting to use deprecated controller account logic.
 		ControllerDeprecated,
+		/// The bond of the stash is consistent, or cannot be restored with the given parameters.
+		CannotRestoreLedger,
 	}
 
 	#[pallet::hooks]
@@ -856,6 +919,12 @@ pub mod pallet {
 			T::DbWeight::get().reads(1)
 		}
 
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			crate::migrations::v15::migrate_step::<T>(&mut meter);
+			meter.consumed()
+		}
+
 		fn on_finalize(_n: BlockNumberFor<T>) {
 			// Set the start of the first era.
 			if let Some(mut active_era) = Self::active_era() {
@@ -878,6 +947,11 @@ pub mod pallet {
 			);
 			// and that MaxNominations is always greater than 1, since we count on this.
 			assert!(!MaxNominationsOf::<T>::get().is_zero());
+			// exposures are split in pages of `MaxExposurePageSize` nominators.
+			assert!(
+				!T::MaxExposurePageSize::get().is_zero(),
+				"MaxExposurePageSize must be greater than zero."
+			);
 
 			// ensure election results are always bounded with the same value
 			assert!(
@@ -1952,7 +2026,7 @@ pub mod pallet {
 						};
 
 						if ledger.stash != *controller && !payee_deprecated {
-							Some((controller.clone(), ledger))
+							Some(ledger)
 						} else {
 							None
 						}
@@ -1960,16 +2034,67 @@ pub mod pallet {
 				})
 				.collect();
 
-			// Update unique pairs.
-			for (controller, ledger) in filtered_batch_with_ledger {
-				let stash = ledger.stash.clone();
-
-				<Bonded<T>>::insert(&stash, &stash);
-				<Ledger<T>>::remove(controller);
-				<Ledger<T>>::insert(stash, ledger);
+			// Update unique pairs, counting the ones whose bonds are inconsistent and are left
+			// untouched.
+			let mut failures = 0;
+			for ledger in filtered_batch_with_ledger {
+				if ledger.set_controller_to_stash().is_err() {
+					failures += 1;
+				}
 			}
+			Self::deposit_event(Event::<T>::ControllerBatchDeprecated { failures });
+
 			Ok(Some(T::WeightInfo::deprecate_controller_batch(controllers.len() as u32)).into())
 		}
+
+		/// Restores the bond of a stash whose controller holds the ledger of another stash, or no
+		/// ledger at all.
+		///
+		/// A new ledger of `total` is bonded to `maybe_controller`, or to the stash itself if it is
+		/// `None`, with `maybe_unlocking` as its unlocking chunks. `total` is capped to the free
+		/// balance of the stash, and the staking lock of the stash is set to it. The new
+		/// controller must not hold a ledger already; deprecate the controller of the stash whose
+		/// ledger it holds first.
+		///
+		/// The dispatch origin must be `T::AdminOrigin`.
+		///
+		/// Emits `LedgerRestored`.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::restore_ledger())]
+		pub fn restore_ledger(
+			origin: OriginFor<T>,
+			stash: T::AccountId,
+			maybe_controller: Option<T::AccountId>,
+			total: BalanceOf<T>,
+			maybe_unlocking: Option<BoundedVec<UnlockChunk<BalanceOf<T>>, T::MaxUnlockingChunks>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				Self::inspect_bond_state(&stash)? != LedgerIntegrityState::Ok,
+				Error::<T>::CannotRestoreLedger
+			);
+
+			let controller = maybe_controller.unwrap_or_else(|| stash.clone());
+			ensure!(!<Ledger<T>>::contains_key(&controller), Error::<T>::CannotRestoreLedger);
+
+			let total = total.min(T::Currency::free_balance(&stash));
+			let unlocking = maybe_unlocking.unwrap_or_default();
+			let active = unlocking
+				.iter()
+				.try_fold(total, |active, chunk| active.checked_sub(&chunk.value))
+				.ok_or(Error::<T>::CannotRestoreLedger)?;
+
+			<Bonded<T>>::insert(&stash, &controller);
+			let mut ledger = StakingLedger::<T>::new(stash.clone(), total);
+			ledger.active = active;
+			ledger.unlocking = unlocking;
+			ledger.controller = Some(controller.clone());
+			ledger.update()?;
+
+			Self::deposit_event(Event::<T>::LedgerRestored { stash, controller, total });
+			Ok(())
+		}
 	}
 }
 
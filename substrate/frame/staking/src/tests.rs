@@ -38,7 +38,7 @@ use sp_runtime::{
 	Perbill, Percent, Perquintill, Rounding, TokenError,
 };
 use sp_staking::{
-	offence::{DisableStrategy, OffenceDetails, OnOffenceHandler},
+	offence::{DisableStrategy, OffenceDetails, OnOffenceHandler, SlashDisposition},
 	SessionIndex,
 };
 use sp_std::prelude::*;
@@ -2352,6 +2352,87 @@ fn offence_deselects_validator_even_when_slash_is_zero() {
 	});
 }
 
+#[test]
+fn rewards_are_suppressed_for_repeated_offences() {
+	ExtBuilder::default().build_and_execute(|| {
+		// every offence beyond the first cuts the era reward by 20%.
+		RewardSuppressionStep::set(Perbill::from_percent(20));
+		Pallet::<Test>::reward_by_ids(vec![(11, 1), (21, 1)]);
+
+		let total_payout_0 = current_total_payout_for_duration(reward_time_per_era());
+		mock::start_active_era(1);
+
+		// 11 committed an offence in each session of era 0, none of them slashed.
+		for session in 0..3 {
+			let _ = Staking::on_offence(
+				&[OffenceDetails {
+					offender: (11, Staking::eras_stakers(0, &11)),
+					reporters: vec![],
+				}],
+				&[Perbill::from_percent(0)],
+				session,
+				DisableStrategy::WhenSlashed,
+			);
+		}
+		assert_eq!(ErasValidatorOffences::<Test>::get(0, 11), (3, Perbill::zero()));
+		let _ = staking_events_since_last_call();
+
+		let paid_out = |events: &[Event<Test>]| -> Balance {
+			events
+				.iter()
+				.filter_map(|e| {
+					if let Event::Rewarded { amount, .. } = e {
+						Some(*amount)
+					} else {
+						None
+					}
+				})
+				.sum()
+		};
+
+		// 11 and its nominators keep 60% of their share of the era reward.
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 0));
+		let events = staking_events_since_last_call();
+		assert!(events.contains(&Event::RewardSuppressed {
+			era_index: 0,
+			validator_stash: 11,
+			offences: 3,
+			kept: Perbill::from_percent(60),
+		}));
+		assert_eq_error_rate!(paid_out(&events), total_payout_0 / 2 * 60 / 100, 2);
+
+		// 21 and its nominators are not affected.
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 21, 0));
+		let events = staking_events_since_last_call();
+		assert!(!events.iter().any(|e| matches!(e, Event::RewardSuppressed { .. })));
+		assert_eq_error_rate!(paid_out(&events), total_payout_0 / 2, 2);
+	});
+}
+
+#[test]
+fn repeated_offence_reports_are_counted_once() {
+	ExtBuilder::default().build_and_execute(|| {
+		let offender = |who: AccountId| OffenceDetails {
+			offender: (who, Staking::eras_stakers(active_era(), &who)),
+			reporters: vec![],
+		};
+
+		on_offence_now(&[offender(11)], &[Perbill::from_percent(10)]);
+		// the same offence is reported again.
+		on_offence_now(&[offender(11)], &[Perbill::from_percent(10)]);
+		// 21 is reported for the same offence, which passes 11 again.
+		on_offence_now(&[offender(11), offender(21)], &[Perbill::from_percent(10); 2]);
+
+		assert_eq!(ErasValidatorOffences::<Test>::get(0, 11), (1, Perbill::from_percent(10)));
+		assert_eq!(ErasValidatorOffences::<Test>::get(0, 21), (1, Perbill::from_percent(10)));
+
+		// an offence with a higher slash fraction in the same session is counted.
+		on_offence_now(&[offender(11)], &[Perbill::from_percent(20)]);
+		assert_eq!(ErasValidatorOffences::<Test>::get(0, 11), (2, Perbill::from_percent(20)));
+		assert_eq!(ErasValidatorOffences::<Test>::get(0, 21), (1, Perbill::from_percent(10)));
+	});
+}
+
 #[test]
 fn slashing_performed_according_exposure() {
 	// This test checks that slashing is performed according the exposure (or more precisely,
@@ -2907,6 +2988,51 @@ fn deferred_slashes_are_deferred() {
 	})
 }
 
+#[test]
+fn offence_records_report_slash_disposition() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		mock::start_active_era(1);
+		let session = Staking::eras_start_session_index(1).unwrap();
+		let offences = || {
+			Staking::api_offence_records(vec![
+				(11, *b"test_offence_kin", session),
+				(21, *b"test_offence_kin", session),
+			])
+		};
+
+		on_offence_now(
+			&[OffenceDetails {
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
+				reporters: vec![],
+			}],
+			&[Perbill::from_percent(10)],
+		);
+
+		let records = offences();
+		assert_eq!(records.len(), 2);
+		assert_eq!(records[0].era, 1);
+		assert_eq!(records[0].slash_fraction, Perbill::from_percent(10));
+		assert!(matches!(
+			records[0].disposition,
+			SlashDisposition::Deferred { apply_at: 4, own: 100, others } if others > 0
+		));
+		assert!(records[0].disabled);
+		assert_eq!(records[1].slash_fraction, Perbill::zero());
+		assert_eq!(records[1].disposition, SlashDisposition::NotSlashed);
+		assert!(!records[1].disabled);
+
+		mock::start_active_era(4);
+
+		let records = offences();
+		assert_eq!(records[0].disposition, SlashDisposition::Applied { own: 100 });
+		assert!(!records[0].disabled);
+
+		// offences outside of the bonding period are skipped.
+		mock::start_active_era(5);
+		assert!(offences().is_empty());
+	})
+}
+
 #[test]
 fn retroactive_deferred_slashes_two_eras_before() {
 	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
@@ -4984,6 +5110,50 @@ mod election_data_provider {
 			});
 	}
 
+	#[test]
+	fn electing_voters_can_be_fetched_in_pages() {
+		ExtBuilder::default()
+			.nominate(false)
+			.add_staker(61, 61, 2_000, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.add_staker(71, 71, 10, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.add_staker(81, 81, 50, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.build_and_execute(|| {
+				let all_voters = <Staking as ElectionDataProvider>::electing_voters(
+					DataProviderBounds::default(),
+				)
+				.unwrap();
+
+				let bounds = ElectionBoundsBuilder::default().voters_count(2.into()).build();
+				let mut paged_voters = vec![];
+				let mut start_after = None;
+				loop {
+					let page = <Staking as ElectionDataProvider>::electing_voters_from(
+						bounds.voters,
+						start_after.as_ref(),
+					)
+					.unwrap();
+					if page.is_empty() {
+						break
+					}
+
+					assert!(page.len() <= 2);
+					start_after = page.last().map(|(who, _, _)| *who);
+					paged_voters.extend(page);
+				}
+
+				assert_eq!(paged_voters, all_voters);
+				// the minimum active stake accounts for all pages.
+				assert_eq!(MinimumActiveStake::<Test>::get(), 10);
+
+				// the voter to start after must be in the voter list.
+				assert!(<Staking as ElectionDataProvider>::electing_voters_from(
+					bounds.voters,
+					Some(&1337),
+				)
+				.is_err());
+			});
+	}
+
 	#[test]
 	fn set_minimum_active_stake_lower_bond_works() {
 		// if there are no voters, minimum active stake is zero (should not happen).
@@ -6641,6 +6811,164 @@ fn test_validator_exposure_is_backward_compatible_with_non_paged_rewards_payout(
 	});
 }
 
+#[test]
+fn migrate_legacy_exposures_to_paged_exposures() {
+	use crate::migrations::v15::{MigrateToV15, MigrationCursor};
+	use frame_support::{
+		traits::{GetStorageVersion, OnRuntimeUpgrade},
+		weights::constants::RocksDbWeight,
+	};
+
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		MaxExposurePageSize::set(10);
+		bond_validator(11, 1000);
+		for i in 0..15 {
+			bond_nominator(1000 + i, 1000 + i as Balance, vec![11]);
+		}
+
+		mock::start_active_era(1);
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(2);
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(3);
+
+		// move the exposures of era 1 and 2 to the legacy storage, with 8 rewarded nominators.
+		for era in 1..=2 {
+			let exposure = EraInfo::<Test>::get_full_exposure(era, &11);
+			let _ = ErasStakersPaged::<Test>::clear_prefix((era,), u32::MAX, None);
+			ErasStakersOverview::<Test>::remove(era, 11);
+
+			let mut clipped = exposure.clone();
+			clipped.others.sort_by(|a, b| b.value.cmp(&a.value));
+			clipped.others.truncate(8);
+			ErasStakers::<Test>::insert(era, 11, exposure);
+			ErasStakersClipped::<Test>::insert(era, 11, clipped);
+		}
+
+		// the reward of era 1 was claimed through the legacy storage.
+		Ledger::<Test>::insert(
+			11,
+			StakingLedgerInspect {
+				stash: 11,
+				total: 1000,
+				active: 1000,
+				unlocking: Default::default(),
+				legacy_claimed_rewards: bounded_vec![1],
+			},
+		);
+		StorageVersion::new(14).put::<Staking>();
+
+		// the upgrade only starts the migration.
+		MigrateToV15::<Test>::on_runtime_upgrade();
+		assert_eq!(Staking::on_chain_storage_version(), StorageVersion::new(15));
+		assert_eq!(LegacyExposureMigration::<Test>::get(), Some(MigrationCursor::Exposures));
+		assert_eq!(ErasStakers::<Test>::iter().count(), 2);
+
+		// one exposure is migrated with the weight of a single step.
+		Staking::on_idle(System::block_number(), RocksDbWeight::get().reads_writes(10, 8));
+		assert_eq!(LegacyExposureMigration::<Test>::get(), Some(MigrationCursor::Exposures));
+		assert_eq!(ErasStakers::<Test>::iter().count(), 1);
+		assert_eq!(ErasStakersClipped::<Test>::iter().count(), 1);
+
+		// the rest of the exposures and all ledgers are migrated in the next block.
+		Staking::on_idle(System::block_number() + 1, Weight::MAX);
+		assert_eq!(LegacyExposureMigration::<Test>::get(), None);
+		assert_eq!(ErasStakers::<Test>::iter().count(), 0);
+		assert_eq!(ErasStakersClipped::<Test>::iter().count(), 0);
+		assert!(Ledger::<Test>::iter().all(|(_, l)| l.legacy_claimed_rewards.is_empty()));
+
+		for era in 1..=2 {
+			assert_eq!(EraInfo::<Test>::get_page_count(era, &11), 2);
+			assert_eq!(EraInfo::<Test>::get_full_exposure(era, &11).others.len(), 15);
+		}
+
+		// all pages of era 1 are claimed, the page of the nominators that are not rewarded in
+		// era 2.
+		assert_eq!(ClaimedRewards::<Test>::get(1, 11), vec![0, 1]);
+		assert_eq!(ClaimedRewards::<Test>::get(2, 11), vec![1]);
+		assert_noop!(
+			Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 1, 0),
+			Error::<Test>::AlreadyClaimed
+				.with_weight(<Test as Config>::WeightInfo::payout_stakers_alive_staked(0))
+		);
+
+		// only the clipped nominators of era 2 are paid out.
+		let _ = staking_events_since_last_call();
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 2, 0));
+		let rewarded = staking_events_since_last_call()
+			.into_iter()
+			.filter(|e| matches!(e, Event::Rewarded { stash, .. } if *stash != 11))
+			.count();
+		assert_eq!(rewarded, 8);
+		assert_noop!(
+			Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 2, 1),
+			Error::<Test>::AlreadyClaimed
+				.with_weight(<Test as Config>::WeightInfo::payout_stakers_alive_staked(0))
+		);
+	});
+}
+
+#[test]
+fn migrate_legacy_exposures_without_rewarded_nominators() {
+	use crate::migrations::v15::MigrateToV15;
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		MaxExposurePageSize::set(10);
+		bond_validator(11, 1000);
+		for i in 0..15 {
+			bond_nominator(1000 + i, 1000 + i as Balance, vec![11]);
+		}
+
+		mock::start_active_era(1);
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(2);
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(3);
+
+		// in era 1 the clipped exposure has no nominators, in era 2 it is missing.
+		let exposure = EraInfo::<Test>::get_full_exposure(1, &11);
+		for era in 1..=2 {
+			let _ = ErasStakersPaged::<Test>::clear_prefix((era,), u32::MAX, None);
+			ErasStakersOverview::<Test>::remove(era, 11);
+			ErasStakers::<Test>::insert(era, 11, exposure.clone());
+		}
+		ErasStakersClipped::<Test>::insert(
+			1,
+			11,
+			Exposure { total: exposure.total, own: exposure.own, others: vec![] },
+		);
+		StorageVersion::new(14).put::<Staking>();
+
+		MigrateToV15::<Test>::on_runtime_upgrade();
+		Staking::on_idle(System::block_number(), Weight::MAX);
+		assert_eq!(ErasStakers::<Test>::iter().count(), 0);
+
+		for era in 1..=2 {
+			// a page without nominators carries the stake of the nominators that are not
+			// rewarded, followed by their two pages.
+			assert_eq!(EraInfo::<Test>::get_page_count(era, &11), 3);
+			let first = ErasStakersPaged::<Test>::get((era, 11, 0)).unwrap();
+			assert!(first.others.is_empty());
+			assert_eq!(first.page_total, exposure.total - exposure.own);
+			assert_eq!(EraInfo::<Test>::get_full_exposure(era, &11).others.len(), 15);
+			assert_eq!(ClaimedRewards::<Test>::get(era, 11), vec![1, 2]);
+
+			// only the validator is paid out.
+			let _ = staking_events_since_last_call();
+			assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, era, 0));
+			let rewarded: Vec<_> = staking_events_since_last_call()
+				.into_iter()
+				.filter_map(|e| match e {
+					Event::Rewarded { stash, .. } => Some(stash),
+					_ => None,
+				})
+				.collect();
+			assert_eq!(rewarded, vec![11]);
+		}
+	});
+}
+
 mod staking_interface {
 	use frame_support::storage::with_storage_layer;
 	use sp_staking::StakingInterface;
@@ -7044,4 +7372,149 @@ mod ledger {
 			assert_eq!(ledger_updated.stash, stash);
 		})
 	}
+
+	#[test]
+	fn deprecate_controller_batch_skips_inconsistent_bonds() {
+		ExtBuilder::default().build_and_execute(|| {
+			// Given:
+
+			let stash: u64 = 1000;
+			let ctlr: u64 = 1001;
+
+			Ledger::<Test>::insert(
+				ctlr,
+				StakingLedger { controller: None, ..StakingLedger::default_from(stash) },
+			);
+			Bonded::<Test>::insert(stash, ctlr);
+			Payee::<Test>::insert(stash, RewardDestination::Staked);
+
+			// the stash is the controller of another stash.
+			Ledger::<Test>::insert(
+				stash,
+				StakingLedger { controller: None, ..StakingLedger::default_from(2000) },
+			);
+			Bonded::<Test>::insert(2000, stash);
+
+			// When:
+
+			let bounded_controllers: BoundedVec<
+				_,
+				<Test as Config>::MaxControllersInDeprecationBatch,
+			> = BoundedVec::try_from(vec![ctlr]).unwrap();
+			assert_ok!(Staking::deprecate_controller_batch(
+				RuntimeOrigin::root(),
+				bounded_controllers
+			));
+
+			// Then:
+
+			// the ledger of the other stash is not overwritten.
+			assert_eq!(Ledger::<Test>::get(stash).unwrap().stash, 2000);
+			assert_eq!(Ledger::<Test>::get(ctlr).unwrap().stash, stash);
+			assert_eq!(Bonded::<Test>::get(stash), Some(ctlr));
+			assert_eq!(
+				*staking_events_since_last_call().last().unwrap(),
+				Event::ControllerBatchDeprecated { failures: 1 }
+			);
+		})
+	}
+
+	#[test]
+	fn restore_ledger_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// Given:
+
+			// stash 21 sets 11 as its controller, overwriting the ledger of stash 11.
+			let ledger_21 = Ledger::<Test>::take(21).unwrap();
+			Ledger::<Test>::insert(11, ledger_21);
+			Bonded::<Test>::insert(21, 11);
+
+			assert!(matches!(
+				Staking::inspect_bond_state(&11),
+				Ok(LedgerIntegrityState::Corrupted)
+			));
+			assert!(matches!(Staking::inspect_bond_state(&21), Ok(LedgerIntegrityState::Ok)));
+			assert!(Staking::do_try_state(System::block_number()).is_err());
+
+			// only `AdminOrigin` can restore a ledger.
+			assert_noop!(
+				Staking::restore_ledger(RuntimeOrigin::signed(11), 11, None, 1000, None),
+				BadOrigin
+			);
+			// consistent bonds cannot be restored.
+			assert_noop!(
+				Staking::restore_ledger(RuntimeOrigin::root(), 21, None, 1000, None),
+				Error::<Test>::CannotRestoreLedger
+			);
+			assert_noop!(
+				Staking::restore_ledger(RuntimeOrigin::root(), 42, None, 1000, None),
+				Error::<Test>::NotStash
+			);
+			// 11 still holds the ledger of stash 21.
+			assert_noop!(
+				Staking::restore_ledger(RuntimeOrigin::root(), 11, None, 1000, None),
+				Error::<Test>::CannotRestoreLedger
+			);
+
+			// When:
+
+			// move the ledger of stash 21 back to it first.
+			let bounded_controllers: BoundedVec<
+				_,
+				<Test as Config>::MaxControllersInDeprecationBatch,
+			> = BoundedVec::try_from(vec![11]).unwrap();
+			assert_ok!(Staking::deprecate_controller_batch(
+				RuntimeOrigin::root(),
+				bounded_controllers
+			));
+			assert_eq!(
+				*staking_events_since_last_call().last().unwrap(),
+				Event::ControllerBatchDeprecated { failures: 0 }
+			);
+			assert!(matches!(
+				Staking::inspect_bond_state(&11),
+				Ok(LedgerIntegrityState::CorruptedKilled)
+			));
+
+			// the unlocking chunks must not exceed the total.
+			let unlocking: BoundedVec<_, <Test as Config>::MaxUnlockingChunks> =
+				BoundedVec::try_from(vec![UnlockChunk { value: 1001, era: 3 }]).unwrap();
+			assert_noop!(
+				Staking::restore_ledger(RuntimeOrigin::root(), 11, None, 1000, Some(unlocking)),
+				Error::<Test>::CannotRestoreLedger
+			);
+
+			// the total is capped to the free balance of the stash.
+			let unlocking: BoundedVec<_, <Test as Config>::MaxUnlockingChunks> =
+				BoundedVec::try_from(vec![UnlockChunk { value: 100, era: 3 }]).unwrap();
+			assert_ok!(Staking::restore_ledger(
+				RuntimeOrigin::root(),
+				11,
+				None,
+				5000,
+				Some(unlocking.clone())
+			));
+
+			// Then:
+
+			assert!(matches!(Staking::inspect_bond_state(&11), Ok(LedgerIntegrityState::Ok)));
+			assert!(matches!(Staking::inspect_bond_state(&21), Ok(LedgerIntegrityState::Ok)));
+			assert_eq!(Bonded::<Test>::get(11), Some(11));
+			assert_eq!(Bonded::<Test>::get(21), Some(21));
+			assert_eq!(
+				Staking::ledger(11.into()).unwrap(),
+				StakingLedgerInspect {
+					stash: 11,
+					total: 1000,
+					active: 900,
+					unlocking,
+					legacy_claimed_rewards: bounded_vec![],
+				}
+			);
+			assert_eq!(
+				staking_events_since_last_call(),
+				vec![Event::LedgerRestored { stash: 11, controller: 11, total: 1000 }]
+			);
+		})
+	}
 }
@@ -398,6 +398,7 @@ fn construct_runtime_final_expansion(
 	let inherent =
 		expand::expand_outer_inherent(&name, &block, &unchecked_extrinsic, &pallets, &scrate);
 	let validate_unsigned = expand::expand_outer_validate_unsigned(&name, &pallets, &scrate);
+	let view_function = expand::expand_outer_view_function(&name, &pallets, &scrate);
 	let freeze_reason = expand::expand_outer_freeze_reason(&pallets, &scrate);
 	let hold_reason = expand::expand_outer_hold_reason(&pallets, &scrate);
 	let lock_id = expand::expand_outer_lock_id(&pallets, &scrate);
@@ -486,6 +487,8 @@ fn construct_runtime_final_expansion(
 
 		#validate_unsigned
 
+		#view_function
+
 		#freeze_reason
 
 		#hold_reason
@@ -1184,6 +1184,39 @@ pub fn extra_constants(_: TokenStream, _: TokenStream) -> TokenStream {
 	pallet_macro_stub()
 }
 
+/// Allows you to define some read-only functions, called view functions, which are listed in
+/// the pallet metadata and can be called through the `RuntimeViewFunction` runtime API.
+///
+/// Item must be defined as:
+///
+/// ```ignore
+/// #[pallet::view_functions]
+/// impl<T: Config> Pallet<T> where $optional_where_clause {
+/// 	/// $some_doc
+/// 	$vis fn $fn_name($some_arg: $some_type, ...) -> $some_return_type {
+/// 		...
+/// 	}
+/// 	...
+/// }
+/// ```
+/// I.e. a regular rust `impl` block with some optional where clause and functions with 0
+/// generics, no `self` receiver and some return type. All argument and return types must
+/// implement `Encode`, `Decode` and `TypeInfo`.
+///
+/// View functions must not modify the state: the runtime API executing them does not commit
+/// any storage changes.
+///
+/// ## Macro expansion
+///
+/// The macro adds the view functions to the pallet metadata and implements
+/// `frame_support::view_functions::DispatchViewFunction` for the pallet. A view function is
+/// identified by the `twox_128` hash of the pallet name followed by the `twox_128` hash of its
+/// signature, and is called with the SCALE encoding of its arguments.
+#[proc_macro_attribute]
+pub fn view_functions(_: TokenStream, _: TokenStream) -> TokenStream {
+	pallet_macro_stub()
+}
+
 /// The `#[pallet::error]` attribute allows you to define an error enum that will be returned
 /// from the dispatchable when an error occurs. The information for this error type is then
 /// stored in metadata.
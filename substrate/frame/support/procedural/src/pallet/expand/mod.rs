@@ -35,6 +35,7 @@ mod tasks;
 mod tt_default_parts;
 mod type_value;
 mod validate_unsigned;
+mod view_functions;
 mod warnings;
 
 use crate::pallet::Def;
@@ -74,6 +75,7 @@ pub fn expand(mut def: Def) -> proc_macro2::TokenStream {
 	let type_values = type_value::expand_type_values(&mut def);
 	let origins = origin::expand_origins(&mut def);
 	let validate_unsigned = validate_unsigned::expand_validate_unsigned(&mut def);
+	let view_functions = view_functions::expand_view_functions(&mut def);
 	let tt_default_parts = tt_default_parts::expand_tt_default_parts(&mut def);
 	let doc_only = doc_only::expand_doc_only(&mut def);
 
@@ -115,6 +117,7 @@ storage item. Otherwise, all storage items are listed among [*Type Definitions*]
 		#type_values
 		#origins
 		#validate_unsigned
+		#view_functions
 		#tt_default_parts
 		#doc_only
 	);
@@ -0,0 +1,121 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::pallet::{parse::helper::two128_str, Def};
+
+/// * Impl fn pallet_view_functions_metadata for pallet.
+/// * Impl `DispatchViewFunction` for pallet.
+pub fn expand_view_functions(def: &mut Def) -> proc_macro2::TokenStream {
+	let frame_support = &def.frame_support;
+	let type_impl_gen = &def.type_impl_generics(proc_macro2::Span::call_site());
+	let type_use_gen = &def.type_use_generics(proc_macro2::Span::call_site());
+	let pallet_ident = &def.pallet_struct.pallet;
+
+	let mut where_clauses = vec![&def.config.where_clause];
+	where_clauses.extend(def.view_functions.iter().map(|d| &d.where_clause));
+	let completed_where_clause = super::merge_where_clauses(&where_clauses);
+
+	let view_functions =
+		def.view_functions.iter().flat_map(|d| &d.view_functions).collect::<Vec<_>>();
+
+	let metadata = view_functions.iter().map(|view_fn| {
+		let name_str = view_fn.ident.to_string();
+		let suffix = two128_str(&view_fn.signature());
+		let return_type = &view_fn.return_type;
+
+		let inputs = view_fn.args.iter().map(|(ident, ty)| {
+			let ident_str = ident.to_string();
+			quote::quote!(
+				#frame_support::__private::metadata_ir::PalletViewFunctionParamMetadataIR {
+					name: #ident_str,
+					ty: #frame_support::__private::scale_info::meta_type::<#ty>(),
+				}
+			)
+		});
+
+		let no_docs = vec![];
+		let doc = if cfg!(feature = "no-metadata-docs") { &no_docs } else { &view_fn.doc };
+
+		quote::quote!({
+			let id = #frame_support::view_functions::ViewFunctionId {
+				prefix: <Self as #frame_support::traits::PalletInfoAccess>::name_hash(),
+				suffix: #suffix,
+			};
+
+			#frame_support::__private::metadata_ir::PalletViewFunctionMetadataIR {
+				name: #name_str,
+				id: id.into(),
+				inputs: #frame_support::__private::sp_std::vec![ #( #inputs ),* ],
+				output: #frame_support::__private::scale_info::meta_type::<#return_type>(),
+				docs: #frame_support::__private::sp_std::vec![ #( #doc ),* ],
+			}
+		})
+	});
+
+	let dispatch_arms = view_functions.iter().map(|view_fn| {
+		let ident = &view_fn.ident;
+		let suffix = two128_str(&view_fn.signature());
+		let arg_names = view_fn.args.iter().map(|(ident, _)| ident).collect::<Vec<_>>();
+		let arg_types = view_fn.args.iter().map(|(_, ty)| ty);
+
+		quote::quote!(
+			#suffix => {
+				let ( #( #arg_names, )* ): ( #( #arg_types, )* ) =
+					#frame_support::__private::codec::Decode::decode(input)?;
+				let result = Self::#ident( #( #arg_names, )* );
+				#frame_support::__private::codec::Encode::encode_to(&result, output);
+				Ok(())
+			},
+		)
+	});
+
+	quote::quote!(
+		impl<#type_impl_gen> #pallet_ident<#type_use_gen> #completed_where_clause {
+			#[doc(hidden)]
+			pub fn pallet_view_functions_metadata()
+				-> #frame_support::__private::sp_std::vec::Vec<
+					#frame_support::__private::metadata_ir::PalletViewFunctionMetadataIR
+				>
+			{
+				#frame_support::__private::sp_std::vec![ #( #metadata ),* ]
+			}
+		}
+
+		impl<#type_impl_gen> #frame_support::view_functions::DispatchViewFunction
+			for #pallet_ident<#type_use_gen> #completed_where_clause
+		{
+			fn dispatch_view_function<O: #frame_support::__private::codec::Output>(
+				id: &#frame_support::view_functions::ViewFunctionId,
+				input: &mut &[u8],
+				output: &mut O,
+			) -> Result<(), #frame_support::view_functions::ViewFunctionDispatchError> {
+				if id.prefix != <Self as #frame_support::traits::PalletInfoAccess>::name_hash() {
+					return Err(
+						#frame_support::view_functions::ViewFunctionDispatchError::NotFound(id.clone())
+					)
+				}
+
+				match id.suffix {
+					#( #dispatch_arms )*
+					_ => Err(
+						#frame_support::view_functions::ViewFunctionDispatchError::NotFound(id.clone())
+					),
+				}
+			}
+		}
+	)
+}
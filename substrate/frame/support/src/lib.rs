@@ -75,6 +75,7 @@ pub mod storage;
 #[cfg(test)]
 mod tests;
 pub mod traits;
+pub mod view_functions;
 pub mod weights;
 #[doc(hidden)]
 pub mod unsigned {
@@ -86,6 +87,8 @@ pub mod unsigned {
 	};
 }
 
+#[cfg(any(feature = "std", feature = "runtime-benchmarks", feature = "try-runtime", test))]
+pub use self::storage::storage_diff::{StorageDiff, StorageSnapshot};
 #[cfg(any(feature = "std", feature = "runtime-benchmarks", feature = "try-runtime", test))]
 pub use self::storage::storage_noop_guard::StorageNoopGuard;
 pub use self::{
@@ -707,6 +710,61 @@ macro_rules! assert_storage_noop {
 	};
 }
 
+/// Evaluate an expression and assert that it has written (inserted, modified or removed) exactly
+/// the given storage keys. Evaluates to the value of the expression.
+///
+/// Used as `assert_storage_writes!(expression_to_assert, [key1, key2])`.
+#[macro_export]
+macro_rules! assert_storage_writes {
+	(
+		$x:expr, [$( $key:expr ),* $(,)?] $(,)?
+	) => {{
+		let snapshot = $crate::StorageSnapshot::take();
+		let result = $x;
+		let expected = [$( AsRef::<[u8]>::as_ref(&$key).to_vec() ),*]
+			.into_iter()
+			.collect::<$crate::__private::sp_std::collections::btree_set::BTreeSet<_>>();
+		assert_eq!(snapshot.diff().written_keys(), expected, "unexpected storage writes");
+		result
+	}};
+}
+
+/// Evaluate an expression and assert that it has written (inserted, modified or removed) the
+/// given number of storage keys. Evaluates to the value of the expression.
+///
+/// Used as `assert_storage_write_count!(expression_to_assert, expected_count)`.
+#[macro_export]
+macro_rules! assert_storage_write_count {
+	(
+		$x:expr, $count:expr $(,)?
+	) => {{
+		let snapshot = $crate::StorageSnapshot::take();
+		let result = $x;
+		assert_eq!(snapshot.diff().write_count(), $count, "unexpected number of storage writes");
+		result
+	}};
+}
+
+/// Evaluate an expression and assert that it has only written storage keys starting with one of
+/// the given prefixes. Evaluates to the value of the expression.
+///
+/// Pass `<Pallet as PalletInfoAccess>::name_hash()` to allow writes to the storage of `Pallet`.
+///
+/// Used as `assert_storage_writes_only_to!(expression_to_assert, [prefix1, prefix2])`.
+#[macro_export]
+macro_rules! assert_storage_writes_only_to {
+	(
+		$x:expr, [$( $prefix:expr ),* $(,)?] $(,)?
+	) => {{
+		let snapshot = $crate::StorageSnapshot::take();
+		let result = $x;
+		let unexpected =
+			snapshot.diff().written_keys_outside(&[$( AsRef::<[u8]>::as_ref(&$prefix) ),*]);
+		assert!(unexpected.is_empty(), "unexpected storage writes: {:?}", unexpected);
+		result
+	}};
+}
+
 /// Assert an expression returns an error specified.
 ///
 /// Used as `assert_err!(expression_to_assert, expected_error_expression)`
@@ -822,7 +880,8 @@ pub use macro_magic;
 pub mod testing_prelude {
 	pub use super::{
 		assert_err, assert_err_ignore_postinfo, assert_err_with_weight, assert_error_encoded_size,
-		assert_noop, assert_ok, assert_storage_noop, parameter_types, traits::Get,
+		assert_noop, assert_ok, assert_storage_noop, assert_storage_write_count,
+		assert_storage_writes, assert_storage_writes_only_to, parameter_types, traits::Get,
 	};
 	pub use sp_arithmetic::assert_eq_error_rate;
 	pub use sp_runtime::{bounded_btree_map, bounded_vec};
@@ -2231,8 +2290,8 @@ pub mod pallet_macros {
 		composite_enum, config, disable_frame_system_supertrait_check, error, event,
 		extra_constants, feeless_if, generate_deposit, generate_store, getter, hooks,
 		import_section, inherent, no_default, no_default_bounds, origin, pallet_section,
-		storage_prefix, storage_version, type_value, unbounded, validate_unsigned, weight,
-		whitelist_storage,
+		storage_prefix, storage_version, type_value, unbounded, validate_unsigned, view_functions,
+		weight, whitelist_storage,
 	};
 
 	/// Allows a pallet to declare a set of functions as a *dispatchable extrinsic*. In
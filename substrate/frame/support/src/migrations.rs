@@ -16,13 +16,18 @@
 // limitations under the License.
 
 use crate::{
-	traits::{GetStorageVersion, NoStorageVersionSet, PalletInfoAccess, StorageVersion},
+	traits::{
+		fungible::{InspectHold, MutateHold},
+		Currency, GetStorageVersion, NoStorageVersionSet, PalletInfoAccess, ReservableCurrency,
+		StorageVersion,
+	},
 	weights::{RuntimeDbWeight, Weight},
 };
 use impl_trait_for_tuples::impl_for_tuples;
 use sp_core::Get;
 use sp_io::{hashing::twox_128, storage::clear_prefix, KillStorageResult};
-use sp_std::marker::PhantomData;
+use sp_runtime::{traits::Zero, DispatchError, DispatchResult, RuntimeDebug, Saturating};
+use sp_std::{marker::PhantomData, vec::Vec};
 
 /// Handles storage migration pallet versioning.
 ///
@@ -344,3 +349,239 @@ impl<P: Get<&'static str>, DbWeight: Get<RuntimeDbWeight>> frame_support::traits
 		Ok(())
 	}
 }
+
+type ReservedBalanceOf<T> = <<T as ReservesToHoldsConfig>::Currency as Currency<
+	<T as ReservesToHoldsConfig>::AccountId,
+>>::Balance;
+
+/// The outcome of converting a reserved deposit into a hold by [`ReservesToHolds`].
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub enum ReserveToHoldOutcome<Balance> {
+	/// The whole deposit is now held.
+	Held,
+	/// Less than the deposit was reserved for the account, so only `held` is now held.
+	PartiallyHeld { held: Balance },
+	/// The deposit could not be held and remains reserved.
+	Failed,
+}
+
+/// The configuration of [`ReservesToHolds`], implemented by the pallet migrating its deposits.
+pub trait ReservesToHoldsConfig: 'static {
+	/// The account ID used in the runtime.
+	type AccountId;
+	/// The currency the deposits were reserved with.
+	type Currency: ReservableCurrency<Self::AccountId>;
+	/// The currency the deposits are to be held with, usually the same as `Currency`.
+	type Fungible: MutateHold<Self::AccountId, Balance = ReservedBalanceOf<Self>>;
+	/// The reason to hold the deposits for.
+	type HoldReason: Get<<Self::Fungible as InspectHold<Self::AccountId>>::Reason>;
+	/// The DB weight as configured in the runtime to calculate the correct weight.
+	type DbWeight: Get<RuntimeDbWeight>;
+
+	/// The deposits currently reserved by the pallet, together with the accounts they are reserved
+	/// from, and the weight consumed reading them.
+	fn deposits() -> (Vec<(Self::AccountId, ReservedBalanceOf<Self>)>, Weight);
+
+	/// Called for every deposit converted with the outcome of the conversion, e.g. to emit an
+	/// event for it.
+	fn on_deposit_migrated(
+		_who: &Self::AccountId,
+		_amount: ReservedBalanceOf<Self>,
+		_outcome: ReserveToHoldOutcome<ReservedBalanceOf<Self>>,
+	) {
+	}
+}
+
+/// Converts the deposits a pallet reserved with [`ReservableCurrency::reserve`] into holds with
+/// the reason given by [`ReservesToHoldsConfig::HoldReason`].
+///
+/// Each deposit is unreserved and then held. If less than the deposit is reserved for the
+/// account, only what could be unreserved is held. If the hold fails, the deposit is reserved
+/// again. Either way the migration carries on with the next deposit and reports the outcome
+/// through [`ReservesToHoldsConfig::on_deposit_migrated`].
+///
+/// Reserves and holds share the reserved balance of an account, so running this migration twice
+/// would hold the deposits twice. Wrap it in a [`VersionedMigration`] to make sure it runs once.
+///
+/// # Examples
+/// ```ignore
+/// pub struct DepositsToHolds<T>(PhantomData<T>);
+/// impl<T: Config> ReservesToHoldsConfig for DepositsToHolds<T> {
+/// 	type AccountId = T::AccountId;
+/// 	type Currency = T::Currency;
+/// 	type Fungible = T::Currency;
+/// 	type HoldReason = DepositHoldReason<T>;
+/// 	type DbWeight = T::DbWeight;
+///
+/// 	fn deposits() -> (Vec<(T::AccountId, BalanceOf<T>)>, Weight) {
+/// 		let deposits: Vec<_> = Deposits::<T>::iter().collect();
+/// 		let weight = T::DbWeight::get().reads(deposits.len() as u64);
+/// 		(deposits, weight)
+/// 	}
+///
+/// 	fn on_deposit_migrated(
+/// 		who: &T::AccountId,
+/// 		amount: BalanceOf<T>,
+/// 		outcome: ReserveToHoldOutcome<BalanceOf<T>>,
+/// 	) {
+/// 		Pallet::<T>::deposit_event(Event::DepositMigrated { who: who.clone(), amount, outcome });
+/// 	}
+/// }
+///
+/// pub type MigrateV1ToV2<T> = VersionedMigration<
+/// 	1,
+/// 	2,
+/// 	ReservesToHolds<DepositsToHolds<T>>,
+/// 	Pallet<T>,
+/// 	<T as frame_system::Config>::DbWeight,
+/// >;
+/// ```
+pub struct ReservesToHolds<T>(PhantomData<T>);
+
+impl<T: ReservesToHoldsConfig> frame_support::traits::OnRuntimeUpgrade for ReservesToHolds<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let (deposits, mut weight) = T::deposits();
+		let reason = T::HoldReason::get();
+		let (mut held, mut failed) = (0u32, 0u32);
+
+		for (who, amount) in deposits {
+			let not_unreserved = T::Currency::unreserve(&who, amount);
+			let unreserved = amount.saturating_sub(not_unreserved);
+			let outcome = match T::Fungible::hold(&reason, &who, unreserved) {
+				Ok(()) if not_unreserved.is_zero() => ReserveToHoldOutcome::Held,
+				Ok(()) => ReserveToHoldOutcome::PartiallyHeld { held: unreserved },
+				Err(e) => {
+					if T::Currency::reserve(&who, unreserved).is_err() {
+						log::error!(
+							"Failed to reserve {:?} again after failing to hold it with {:?}. \
+							THIS SHOULD NEVER HAPPEN! 🚨",
+							unreserved,
+							e,
+						);
+					}
+					ReserveToHoldOutcome::Failed
+				},
+			};
+			match outcome {
+				ReserveToHoldOutcome::Failed => failed.saturating_inc(),
+				_ => held.saturating_inc(),
+			}
+			T::on_deposit_migrated(&who, amount, outcome);
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+		}
+
+		log::info!("Converted {} reserved deposits to holds, {} failed", held, failed);
+		weight
+	}
+}
+
+/// The outcome of re-pricing a reserved deposit with [`reprice_reserved_deposit`].
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub enum RepriceOutcome<Balance> {
+	/// The deposit already has the current price.
+	Unchanged,
+	/// The deposit increased, and `amount` was reserved in addition.
+	Charged { amount: Balance },
+	/// The deposit decreased, and `amount` was unreserved.
+	Refunded { amount: Balance },
+}
+
+/// Re-price a deposit reserved from `who` from the `old` to the `new` amount, by reserving or
+/// unreserving the difference.
+///
+/// Pallets call this when the deposit constants of the runtime may have changed since `old` was
+/// reserved, e.g. when a deposit is touched or poked, so that accounts don't remain on legacy
+/// pricing. Fails without any change if the increase can't be reserved.
+pub fn reprice_reserved_deposit<AccountId, Currency: ReservableCurrency<AccountId>>(
+	who: &AccountId,
+	old: Currency::Balance,
+	new: Currency::Balance,
+) -> Result<RepriceOutcome<Currency::Balance>, DispatchError> {
+	if new > old {
+		let amount = new.saturating_sub(old);
+		Currency::reserve(who, amount)?;
+		Ok(RepriceOutcome::Charged { amount })
+	} else if new < old {
+		let excess = old.saturating_sub(new);
+		let not_unreserved = Currency::unreserve(who, excess);
+		debug_assert!(not_unreserved.is_zero());
+		Ok(RepriceOutcome::Refunded { amount: excess.saturating_sub(not_unreserved) })
+	} else {
+		Ok(RepriceOutcome::Unchanged)
+	}
+}
+
+/// The configuration of [`RepriceDeposits`], implemented by the pallet re-pricing its deposits.
+pub trait RepriceDepositsConfig: 'static {
+	/// The account ID used in the runtime.
+	type AccountId;
+	/// The DB weight as configured in the runtime to calculate the correct weight.
+	type DbWeight: Get<RuntimeDbWeight>;
+
+	/// The accounts holding deposits of the pallet, and the weight consumed reading them.
+	fn accounts() -> (Vec<Self::AccountId>, Weight);
+
+	/// Re-price the deposits of `who` at the current deposit constants, usually with
+	/// [`reprice_reserved_deposit`].
+	fn reprice(who: &Self::AccountId) -> DispatchResult;
+
+	/// The maximum weight of re-pricing the deposits of a single account.
+	fn reprice_weight() -> Weight;
+}
+
+/// Re-prices the deposits of all accounts given by [`RepriceDepositsConfig::accounts`] at the
+/// deposit constants of the runtime, e.g. after they changed with a runtime upgrade.
+///
+/// The difference to the old deposit is charged or refunded eagerly. Accounts whose increase
+/// can't be reserved keep their old deposit, which is then re-priced the next time it is
+/// touched or poked. Re-pricing is idempotent, so this migration doesn't have to be versioned.
+///
+/// All accounts are re-priced in a single block, so this is only suited for pallets with a
+/// bounded number of deposits.
+///
+/// # Examples
+/// ```ignore
+/// pub struct ProxyDeposits<T>(PhantomData<T>);
+/// impl<T: Config> RepriceDepositsConfig for ProxyDeposits<T> {
+/// 	type AccountId = T::AccountId;
+/// 	type DbWeight = T::DbWeight;
+///
+/// 	fn accounts() -> (Vec<T::AccountId>, Weight) {
+/// 		let accounts: Vec<_> = Proxies::<T>::iter_keys().collect();
+/// 		let weight = T::DbWeight::get().reads(accounts.len() as u64);
+/// 		(accounts, weight)
+/// 	}
+///
+/// 	fn reprice(who: &T::AccountId) -> DispatchResult {
+/// 		Pallet::<T>::reprice_deposits(who).map(|_| ())
+/// 	}
+///
+/// 	fn reprice_weight() -> Weight {
+/// 		T::WeightInfo::poke_deposit()
+/// 	}
+/// }
+///
+/// pub type Migrations = (RepriceDeposits<ProxyDeposits<Runtime>>,);
+/// ```
+pub struct RepriceDeposits<T>(PhantomData<T>);
+
+impl<T: RepriceDepositsConfig> frame_support::traits::OnRuntimeUpgrade for RepriceDeposits<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let (accounts, mut weight) = T::accounts();
+		let (mut repriced, mut failed) = (0u32, 0u32);
+
+		for who in accounts {
+			match T::reprice(&who) {
+				Ok(()) => repriced.saturating_inc(),
+				Err(e) => {
+					log::warn!("Failed to re-price the deposits of an account: {:?}", e);
+					failed.saturating_inc()
+				},
+			}
+			weight.saturating_accrue(T::reprice_weight());
+		}
+
+		log::info!("Re-priced the deposits of {} accounts, {} failed", repriced, failed);
+		weight
+	}
+}
@@ -47,6 +47,7 @@ pub mod child;
 pub mod generator;
 pub mod hashed;
 pub mod migration;
+pub mod storage_diff;
 pub mod storage_noop_guard;
 mod stream_iter;
 pub mod transactional;
@@ -0,0 +1,193 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Feature gated since it iterates over the whole storage.
+#![cfg(any(feature = "std", feature = "runtime-benchmarks", feature = "try-runtime", test))]
+
+//! Contains the [`crate::StorageSnapshot`] and [`crate::StorageDiff`] for asserting which
+//! storage keys have been written by a code block.
+//!
+//! This generalizes [`crate::assert_storage_noop`]: instead of only comparing storage roots, it
+//! compares the whole (top) storage before and after the code block. Only the net changes are
+//! visible, so writing a value and then restoring it is not reported. Reads can not be recorded
+//! with plain externalities, so they are not covered.
+
+use sp_std::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	vec::Vec,
+};
+
+/// A copy of the whole top storage, taken at some point.
+#[must_use]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageSnapshot(BTreeMap<Vec<u8>, Vec<u8>>);
+
+impl StorageSnapshot {
+	/// Copy the current top storage.
+	pub fn take() -> Self {
+		let mut storage = BTreeMap::new();
+		let mut key = Vec::new();
+		while let Some(next_key) = sp_io::storage::next_key(&key) {
+			if let Some(value) = sp_io::storage::get(&next_key) {
+				storage.insert(next_key.clone(), value.to_vec());
+			}
+			key = next_key;
+		}
+		Self(storage)
+	}
+
+	/// Compare the snapshot with the current top storage.
+	pub fn diff(&self) -> StorageDiff {
+		let current = Self::take();
+
+		let mut diff = StorageDiff::default();
+		for (key, value) in &current.0 {
+			match self.0.get(key) {
+				None => {
+					diff.inserted.insert(key.clone());
+				},
+				Some(old_value) if old_value != value => {
+					diff.modified.insert(key.clone());
+				},
+				Some(_) => {},
+			}
+		}
+		for key in self.0.keys() {
+			if !current.0.contains_key(key) {
+				diff.removed.insert(key.clone());
+			}
+		}
+		diff
+	}
+}
+
+/// Storage keys that have been changed between a [`StorageSnapshot`] and some later point.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageDiff {
+	/// Keys that did not exist before.
+	pub inserted: BTreeSet<Vec<u8>>,
+	/// Keys that existed before, but have a different value now.
+	pub modified: BTreeSet<Vec<u8>>,
+	/// Keys that have been removed.
+	pub removed: BTreeSet<Vec<u8>>,
+}
+
+impl StorageDiff {
+	/// Run `f` and return its result together with the storage changes that it has made.
+	pub fn record<R>(f: impl FnOnce() -> R) -> (R, Self) {
+		let snapshot = StorageSnapshot::take();
+		let result = f();
+		(result, snapshot.diff())
+	}
+
+	/// Whether no key has been changed.
+	pub fn is_empty(&self) -> bool {
+		self.inserted.is_empty() && self.modified.is_empty() && self.removed.is_empty()
+	}
+
+	/// All inserted, modified and removed keys.
+	pub fn written_keys(&self) -> BTreeSet<Vec<u8>> {
+		self.inserted
+			.iter()
+			.chain(self.modified.iter())
+			.chain(self.removed.iter())
+			.cloned()
+			.collect()
+	}
+
+	/// Number of changed keys.
+	pub fn write_count(&self) -> usize {
+		self.inserted.len() + self.modified.len() + self.removed.len()
+	}
+
+	/// Changed keys that don't start with any of the given `prefixes`.
+	pub fn written_keys_outside(&self, prefixes: &[&[u8]]) -> BTreeSet<Vec<u8>> {
+		self.written_keys()
+			.into_iter()
+			.filter(|key| !prefixes.iter().any(|prefix| key.starts_with(prefix)))
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::storage::unhashed::{kill, put};
+	use sp_io::TestExternalities;
+
+	#[test]
+	fn storage_diff_detects_changes() {
+		TestExternalities::default().execute_with(|| {
+			put(b"modified", &1u32);
+			put(b"removed", &1u32);
+			put(b"untouched", &1u32);
+
+			let ((), diff) = StorageDiff::record(|| {
+				put(b"inserted", &1u32);
+				put(b"modified", &2u32);
+				kill(b"removed");
+				put(b"untouched", &1u32);
+			});
+
+			assert_eq!(diff.inserted, [b"inserted".to_vec()].into_iter().collect());
+			assert_eq!(diff.modified, [b"modified".to_vec()].into_iter().collect());
+			assert_eq!(diff.removed, [b"removed".to_vec()].into_iter().collect());
+			assert_eq!(diff.write_count(), 3);
+			assert_eq!(
+				diff.written_keys_outside(&[&b"ins"[..], &b"mod"[..]]),
+				[b"removed".to_vec()].into_iter().collect(),
+			);
+		});
+	}
+
+	#[test]
+	fn storage_diff_ignores_reverted_changes() {
+		TestExternalities::default().execute_with(|| {
+			let ((), diff) = StorageDiff::record(|| {
+				put(b"key", &1u32);
+				kill(b"key");
+			});
+			assert!(diff.is_empty());
+		});
+	}
+
+	#[test]
+	fn assert_storage_writes_works() {
+		TestExternalities::default().execute_with(|| {
+			assert_eq!(
+				crate::assert_storage_writes!(
+					{
+						put(b"key", &1u32);
+						42
+					},
+					[b"key"]
+				),
+				42
+			);
+			crate::assert_storage_write_count!(put(b"other", &1u32), 1);
+			crate::assert_storage_writes_only_to!(put(b"other", &2u32), [b"oth"]);
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "unexpected storage writes")]
+	fn assert_storage_writes_only_to_panics_on_other_writes() {
+		TestExternalities::default().execute_with(|| {
+			crate::assert_storage_writes_only_to!(put(b"key", &1u32), [b"other"]);
+		});
+	}
+}
@@ -130,6 +130,6 @@ pub use tasks::Task;
 mod try_runtime;
 #[cfg(feature = "try-runtime")]
 pub use try_runtime::{
-	Select as TryStateSelect, TryDecodeEntireStorage, TryDecodeEntireStorageError, TryState,
-	UpgradeCheckSelect,
+	HookWeight, MeasureWeight, Select as TryStateSelect, TryDecodeEntireStorage,
+	TryDecodeEntireStorageError, TryMeasureHooks, TryState, UpgradeCheckSelect,
 };
@@ -0,0 +1,260 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Types to compare the weight declared by the block hooks of pallets with the weight they use.
+
+use crate::{
+	traits::{OnFinalize, OnIdle, OnInitialize, PalletInfoAccess},
+	weights::Weight,
+};
+use impl_trait_for_tuples::impl_for_tuples;
+use sp_arithmetic::traits::AtLeast32BitUnsigned;
+use sp_std::prelude::*;
+
+/// Measures the weight used by some code.
+///
+/// `frame_benchmarking::MeasureWithHostFunctions` measures it with host functions, so that it
+/// works in the Wasm runtime.
+pub trait MeasureWeight {
+	/// Execute `f`, returning its result and the weight it used.
+	fn measure<R>(f: impl FnOnce() -> R) -> (R, Weight);
+}
+
+/// The weight a pallet declared for its block hooks, and the weight they used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookWeight {
+	/// The name of the pallet, as given by [`PalletInfoAccess`].
+	pub pallet: &'static str,
+	/// The hook, either `on_initialize` or `on_idle`.
+	///
+	/// The weight of `on_finalize` has to be declared by `on_initialize`, so it is accounted for
+	/// in the `on_initialize` entry of the pallet.
+	pub hook: &'static str,
+	/// The weight returned by the hook.
+	pub declared: Weight,
+	/// The weight used by the hook.
+	pub measured: Weight,
+}
+
+impl HookWeight {
+	/// Whether the hook used more weight than it declared.
+	pub fn is_underestimated(&self) -> bool {
+		self.measured.any_gt(self.declared)
+	}
+}
+
+/// Execute the block hooks of the pallets in a tuple, like [`OnInitialize`], [`OnIdle`] and
+/// [`OnFinalize`] do, while measuring the weight used by each pallet with `M`.
+///
+/// The hooks of each pallet are reported as a [`HookWeight`], in the order of the pallets.
+pub trait TryMeasureHooks<BlockNumber> {
+	/// Execute `on_initialize` of all pallets, returning the total declared weight.
+	fn measured_on_initialize<M: MeasureWeight>(
+		n: BlockNumber,
+		report: &mut Vec<HookWeight>,
+	) -> Weight;
+
+	/// Execute `on_idle` of all pallets, returning the total declared weight.
+	fn measured_on_idle<M: MeasureWeight>(
+		n: BlockNumber,
+		remaining_weight: Weight,
+		report: &mut Vec<HookWeight>,
+	) -> Weight;
+
+	/// Execute `on_finalize` of all pallets.
+	///
+	/// The measured weight of each pallet is added to its `on_initialize` entry in `report`,
+	/// which is created if missing.
+	fn measured_on_finalize<M: MeasureWeight>(n: BlockNumber, report: &mut Vec<HookWeight>);
+}
+
+fn measured_on_idle_of<P, BlockNumber, M>(
+	n: BlockNumber,
+	remaining_weight: Weight,
+	report: &mut Vec<HookWeight>,
+) -> Weight
+where
+	P: PalletInfoAccess + OnIdle<BlockNumber>,
+	M: MeasureWeight,
+{
+	let (declared, measured) = M::measure(|| P::on_idle(n, remaining_weight));
+	report.push(HookWeight { pallet: P::name(), hook: "on_idle", declared, measured });
+	declared
+}
+
+#[cfg_attr(all(not(feature = "tuples-96"), not(feature = "tuples-128")), impl_for_tuples(64))]
+#[cfg_attr(all(feature = "tuples-96", not(feature = "tuples-128")), impl_for_tuples(96))]
+#[cfg_attr(feature = "tuples-128", impl_for_tuples(128))]
+impl<BlockNumber: Copy + AtLeast32BitUnsigned> TryMeasureHooks<BlockNumber> for Tuple {
+	for_tuples!( where #( Tuple: PalletInfoAccess + OnInitialize<BlockNumber> + OnIdle<BlockNumber> + OnFinalize<BlockNumber> )* );
+
+	fn measured_on_initialize<M: MeasureWeight>(
+		n: BlockNumber,
+		report: &mut Vec<HookWeight>,
+	) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!(#(
+			let (declared, measured) = M::measure(|| Tuple::on_initialize(n));
+			report.push(HookWeight { pallet: Tuple::name(), hook: "on_initialize", declared, measured });
+			weight = weight.saturating_add(declared);
+		)*);
+		weight
+	}
+
+	fn measured_on_idle<M: MeasureWeight>(
+		n: BlockNumber,
+		remaining_weight: Weight,
+		report: &mut Vec<HookWeight>,
+	) -> Weight {
+		// Same order as in the tuple implementation of `OnIdle`.
+		let on_idle_functions: &[fn(BlockNumber, Weight, &mut Vec<HookWeight>) -> Weight] =
+			&[for_tuples!( #( measured_on_idle_of::<Tuple, BlockNumber, M> ),* )];
+		let mut weight = Weight::zero();
+		let len = on_idle_functions.len();
+		let start_index = n % (len as u32).into();
+		let start_index = start_index.try_into().ok().expect(
+			"`start_index % len` always fits into `usize`, because `len` can be in maximum `usize::MAX`; qed"
+		);
+		for on_idle_fn in on_idle_functions.iter().cycle().skip(start_index).take(len) {
+			let adjusted_remaining_weight = remaining_weight.saturating_sub(weight);
+			weight = weight.saturating_add(on_idle_fn(n, adjusted_remaining_weight, report));
+		}
+		weight
+	}
+
+	fn measured_on_finalize<M: MeasureWeight>(n: BlockNumber, report: &mut Vec<HookWeight>) {
+		for_tuples!(#(
+			let ((), measured) = M::measure(|| Tuple::on_finalize(n));
+			match report
+				.iter_mut()
+				.find(|w| w.pallet == Tuple::name() && w.hook == "on_initialize")
+			{
+				Some(w) => w.measured = w.measured.saturating_add(measured),
+				None => report.push(HookWeight {
+					pallet: Tuple::name(),
+					hook: "on_initialize",
+					declared: Weight::zero(),
+					measured,
+				}),
+			}
+		)*);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Measures the weight of a hook as the weight put into `Used`.
+	struct MeasureUsed;
+
+	impl MeasureWeight for MeasureUsed {
+		fn measure<R>(f: impl FnOnce() -> R) -> (R, Weight) {
+			Used::set(Weight::zero());
+			let result = f();
+			(result, Used::take())
+		}
+	}
+
+	crate::parameter_types! {
+		pub static Used: Weight = Weight::zero();
+	}
+
+	macro_rules! impl_test_pallet {
+		($name:ident, $declared:expr, $on_initialize:expr, $on_finalize:expr, $on_idle:expr) => {
+			struct $name;
+			impl PalletInfoAccess for $name {
+				fn index() -> usize {
+					0
+				}
+				fn name() -> &'static str {
+					stringify!($name)
+				}
+				fn name_hash() -> [u8; 16] {
+					Default::default()
+				}
+				fn module_name() -> &'static str {
+					stringify!($name)
+				}
+				fn crate_version() -> crate::traits::CrateVersion {
+					crate::traits::CrateVersion::new(1, 0, 0)
+				}
+			}
+			impl OnInitialize<u32> for $name {
+				fn on_initialize(_n: u32) -> Weight {
+					Used::set(Weight::from_parts($on_initialize, 0));
+					Weight::from_parts($declared, 0)
+				}
+			}
+			impl OnFinalize<u32> for $name {
+				fn on_finalize(_n: u32) {
+					Used::set(Weight::from_parts($on_finalize, 0));
+				}
+			}
+			impl OnIdle<u32> for $name {
+				fn on_idle(_n: u32, _remaining_weight: Weight) -> Weight {
+					Used::set(Weight::from_parts($on_idle, 0));
+					Weight::from_parts($on_idle, 0)
+				}
+			}
+		};
+	}
+
+	impl_test_pallet!(Honest, 10, 5, 5, 7);
+	impl_test_pallet!(Underestimating, 10, 5, 6, 3);
+
+	#[test]
+	fn measured_hooks_work() {
+		type Pallets = (Honest, Underestimating);
+		let mut report = Vec::new();
+
+		assert_eq!(
+			Pallets::measured_on_initialize::<MeasureUsed>(1, &mut report),
+			Weight::from_parts(20, 0)
+		);
+		assert_eq!(
+			Pallets::measured_on_idle::<MeasureUsed>(1, Weight::MAX, &mut report),
+			Weight::from_parts(10, 0)
+		);
+		Pallets::measured_on_finalize::<MeasureUsed>(1, &mut report);
+
+		let w = |pallet, hook, declared, measured| HookWeight {
+			pallet,
+			hook,
+			declared: Weight::from_parts(declared, 0),
+			measured: Weight::from_parts(measured, 0),
+		};
+		assert_eq!(
+			report,
+			vec![
+				w("Honest", "on_initialize", 10, 10),
+				w("Underestimating", "on_initialize", 10, 11),
+				// `on_idle` starts at the pallet with index `n % len`.
+				w("Underestimating", "on_idle", 3, 3),
+				w("Honest", "on_idle", 7, 7),
+			]
+		);
+		assert_eq!(
+			report
+				.iter()
+				.filter(|w| w.is_underestimated())
+				.map(|w| w.pallet)
+				.collect::<Vec<_>>(),
+			vec!["Underestimating"]
+		);
+	}
+}
@@ -19,6 +19,8 @@
 
 pub mod decode_entire_state;
 pub use decode_entire_state::{TryDecodeEntireStorage, TryDecodeEntireStorageError};
+pub mod hook_weights;
+pub use hook_weights::{HookWeight, MeasureWeight, TryMeasureHooks};
 
 use super::StorageInstance;
 
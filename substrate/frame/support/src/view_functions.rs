@@ -0,0 +1,110 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pallet view functions.
+//!
+//! View functions are read-only functions declared by a pallet in a
+//! [`#[pallet::view_functions]`](`crate::pallet_macros::view_functions`) block. They are listed
+//! in the runtime metadata and can be called through the [`runtime_api::RuntimeViewFunction`]
+//! runtime API, without client libraries having to know the storage layout of the pallet.
+//!
+//! A view function is identified by a [`ViewFunctionId`]: the `twox_128` hash of the pallet name,
+//! followed by the `twox_128` hash of the signature of the function. Its input is the SCALE
+//! encoding of its arguments, in order, and its output is the SCALE encoding of its return value.
+
+use codec::{Decode, Encode, MaxEncodedLen, Output};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// The unique identifier of a view function.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub struct ViewFunctionId {
+	/// The part of the id identifying the pallet, the `twox_128` hash of its name.
+	pub prefix: [u8; 16],
+	/// The part of the id identifying the function within the pallet, the `twox_128` hash of
+	/// its signature.
+	pub suffix: [u8; 16],
+}
+
+impl From<ViewFunctionId> for [u8; 32] {
+	fn from(value: ViewFunctionId) -> Self {
+		let mut output = [0u8; 32];
+		output[..16].copy_from_slice(&value.prefix);
+		output[16..].copy_from_slice(&value.suffix);
+		output
+	}
+}
+
+/// Error returned when a view function could not be executed.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub enum ViewFunctionDispatchError {
+	/// View functions are not implemented by this runtime.
+	NotImplemented,
+	/// No view function with the given id exists.
+	NotFound(ViewFunctionId),
+	/// The input could not be decoded into the arguments of the view function.
+	Codec,
+}
+
+impl From<codec::Error> for ViewFunctionDispatchError {
+	fn from(_: codec::Error) -> ViewFunctionDispatchError {
+		ViewFunctionDispatchError::Codec
+	}
+}
+
+/// Dispatch a view function call to its implementation.
+///
+/// Implemented by every pallet, and by the `RuntimeViewFunction` type generated by
+/// `construct_runtime!`, which dispatches to the pallet given by [`ViewFunctionId::prefix`].
+pub trait DispatchViewFunction {
+	/// Execute the view function `id`, decoding its arguments from `input` and encoding its
+	/// result to `output`.
+	fn dispatch_view_function<O: Output>(
+		id: &ViewFunctionId,
+		input: &mut &[u8],
+		output: &mut O,
+	) -> Result<(), ViewFunctionDispatchError>;
+}
+
+impl DispatchViewFunction for () {
+	fn dispatch_view_function<O: Output>(
+		_id: &ViewFunctionId,
+		_input: &mut &[u8],
+		_output: &mut O,
+	) -> Result<(), ViewFunctionDispatchError> {
+		Err(ViewFunctionDispatchError::NotImplemented)
+	}
+}
+
+/// Runtime API for executing view functions.
+pub mod runtime_api {
+	use super::*;
+
+	sp_api::decl_runtime_apis! {
+		/// Runtime API for executing pallet view functions.
+		pub trait RuntimeViewFunction {
+			/// Execute the view function `query_id` with the SCALE encoded arguments `input`.
+			///
+			/// Returns the SCALE encoded result of the view function.
+			fn execute_view_function(
+				query_id: ViewFunctionId,
+				input: Vec<u8>,
+			) -> Result<Vec<u8>, ViewFunctionDispatchError>;
+		}
+	}
+}
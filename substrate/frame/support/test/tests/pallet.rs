@@ -171,6 +171,19 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::view_functions]
+	impl<T: Config> Pallet<T> {
+		/// Query the value of `Value`.
+		pub fn get_value() -> Option<u32> {
+			Value::<T>::get()
+		}
+
+		/// Query the value of `Map2` at `key`.
+		pub fn get_value_with_arg(key: u16) -> Option<u32> {
+			Map2::<T>::get(key)
+		}
+	}
+
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
@@ -1863,7 +1876,7 @@ fn metadata_at_version() {
 
 #[test]
 fn metadata_versions() {
-	assert_eq!(vec![14, LATEST_METADATA_VERSION], Runtime::metadata_versions());
+	assert_eq!(vec![14, LATEST_METADATA_VERSION, u32::MAX], Runtime::metadata_versions());
 }
 
 #[test]
@@ -1910,6 +1923,70 @@ fn test_pallet_runtime_docs() {
 	assert_eq!(docs, expected);
 }
 
+#[test]
+fn view_functions_metadata() {
+	let view_functions = crate::pallet::Pallet::<Runtime>::pallet_view_functions_metadata();
+	let names = view_functions.iter().map(|f| f.name).collect::<Vec<_>>();
+	assert_eq!(names, vec!["get_value", "get_value_with_arg"]);
+
+	let get_value_with_arg = &view_functions[1];
+	assert_eq!(get_value_with_arg.inputs.len(), 1);
+	assert_eq!(get_value_with_arg.inputs[0].name, "key");
+	assert_eq!(get_value_with_arg.inputs[0].ty, meta_type::<u16>());
+	assert_eq!(get_value_with_arg.output, meta_type::<Option<u32>>());
+	assert_eq!(
+		get_value_with_arg.id[..16],
+		<Example as frame_support::traits::PalletInfoAccess>::name_hash()
+	);
+
+	// Pallets without view functions have no view functions metadata.
+	assert!(crate::pallet2::Pallet::<Runtime>::pallet_view_functions_metadata().is_empty());
+}
+
+#[test]
+fn view_functions_dispatch() {
+	use codec::{Decode, Encode};
+	use frame_support::view_functions::{ViewFunctionDispatchError, ViewFunctionId};
+
+	fn id_of(name: &str) -> ViewFunctionId {
+		let metadata = crate::pallet::Pallet::<Runtime>::pallet_view_functions_metadata();
+		let id = metadata.iter().find(|f| f.name == name).unwrap().id;
+		ViewFunctionId {
+			prefix: id[..16].try_into().unwrap(),
+			suffix: id[16..].try_into().unwrap(),
+		}
+	}
+
+	TestExternalities::default().execute_with(|| {
+		pallet::Value::<Runtime>::put(42);
+		pallet::Map2::<Runtime>::insert(1, 7);
+
+		let output = Runtime::execute_view_function(id_of("get_value"), vec![]).unwrap();
+		assert_eq!(Option::<u32>::decode(&mut &output[..]).unwrap(), Some(42));
+
+		let get_value_with_arg = id_of("get_value_with_arg");
+		let output =
+			Runtime::execute_view_function(get_value_with_arg.clone(), 1u16.encode()).unwrap();
+		assert_eq!(Option::<u32>::decode(&mut &output[..]).unwrap(), Some(7));
+		let output =
+			Runtime::execute_view_function(get_value_with_arg.clone(), 2u16.encode()).unwrap();
+		assert_eq!(Option::<u32>::decode(&mut &output[..]).unwrap(), None);
+
+		// Missing arguments.
+		assert_eq!(
+			Runtime::execute_view_function(get_value_with_arg, vec![]),
+			Err(ViewFunctionDispatchError::Codec),
+		);
+
+		// Unknown view function.
+		let unknown = ViewFunctionId { prefix: [0; 16], suffix: [0; 16] };
+		assert_eq!(
+			Runtime::execute_view_function(unknown.clone(), vec![]),
+			Err(ViewFunctionDispatchError::NotFound(unknown)),
+		);
+	});
+}
+
 #[test]
 fn test_pallet_info_access() {
 	assert_eq!(<System as frame_support::traits::PalletInfoAccess>::name(), "System");
@@ -2385,9 +2462,10 @@ fn post_runtime_upgrade_detects_storage_version_issues() {
 		// any storage version "enabled".
 		assert!(
 			ExecutiveWithUpgradePallet4::try_runtime_upgrade(UpgradeCheckSelect::PreAndPost)
-				.unwrap_err() == "On chain storage version set, while the pallet \
+				.unwrap_err() ==
+				"On chain storage version set, while the pallet \
 				doesn't have the `#[pallet::storage_version(VERSION)]` attribute."
-				.into()
+					.into()
 		);
 	});
 }
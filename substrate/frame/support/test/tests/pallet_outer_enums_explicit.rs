@@ -92,6 +92,8 @@ fn module_error_outer_enum_expand_explicit() {
 			frame_system::Error::CallFiltered => (),
 			frame_system::Error::InvalidTask => (),
 			frame_system::Error::FailedTask => (),
+			frame_system::Error::NothingAuthorized => (),
+			frame_system::Error::Unauthorized => (),
 			frame_system::Error::__Ignore(_, _) => (),
 		},
 
@@ -165,5 +165,26 @@ benchmarks! {
 		}
 	}
 
+	authorize_upgrade {
+		let runtime_blob = T::prepare_set_code_data();
+		T::setup_set_code_requirements(&runtime_blob)?;
+		let hash = T::Hashing::hash(&runtime_blob);
+	}: _(RawOrigin::Root, hash)
+	verify {
+		assert!(System::<T>::authorized_upgrade().is_some());
+	}
+
+	apply_authorized_upgrade {
+		let runtime_blob = T::prepare_set_code_data();
+		T::setup_set_code_requirements(&runtime_blob)?;
+		let hash = T::Hashing::hash(&runtime_blob);
+		// Will be heavier when it needs to do verification (i.e. don't use `...without_checks`).
+		System::<T>::authorize_upgrade(RawOrigin::Root.into(), hash)?;
+	}: _(RawOrigin::Root, runtime_blob)
+	verify {
+		assert!(System::<T>::authorized_upgrade().is_none());
+		T::verify_set_code()
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -180,11 +180,19 @@ where
 	}
 
 	// In cases total block weight is exceeded, we need to fall back
-	// to `reserved` pool if there is any.
-	if all_weight.total().any_gt(maximum_weight.max_block) {
+	// to `reserved` pool if there is any. Ref-time and proof size are checked independently, so
+	// only the components that overflow the block are held against the reserved pool.
+	let total = all_weight.total();
+	let max_block = maximum_weight.max_block;
+	if total.any_gt(max_block) {
 		match limit_per_class.reserved {
 			// We are over the limit in reserved pool.
-			Some(reserved) if per_class.any_gt(reserved) => {
+			Some(reserved)
+				if (total.ref_time() > max_block.ref_time() &&
+					per_class.ref_time() > reserved.ref_time()) ||
+					(total.proof_size() > max_block.proof_size() &&
+						per_class.proof_size() > reserved.proof_size()) =>
+			{
 				log::debug!(
 					target: LOG_TARGET,
 					"Total block weight is exceeded.",
@@ -755,4 +763,68 @@ mod tests {
 			InvalidTransaction::ExhaustsResources
 		);
 	}
+
+	#[test]
+	fn reserved_proof_size_is_enforced_independently_of_ref_time() {
+		// given: `Operational` only reserves proof size, no ref-time.
+		let maximum_weight = BlockWeights::builder()
+			.base_block(Weight::zero())
+			.for_class(DispatchClass::all(), |w| w.base_extrinsic = Weight::zero())
+			.for_class(DispatchClass::non_mandatory(), |w| {
+				w.max_total = Some(Weight::from_parts(100, 100))
+			})
+			.max_total_proof_size(DispatchClass::Normal, 75)
+			.reserved_proof_size(DispatchClass::Operational, 25)
+			.build_or_panic();
+		assert_eq!(
+			maximum_weight.get(DispatchClass::Operational).reserved,
+			Some(Weight::from_parts(0, 25))
+		);
+
+		// the proof size of the block is exhausted, but there is plenty of ref-time left.
+		let all_weight = crate::ConsumedWeight::new(|class| match class {
+			DispatchClass::Normal => Weight::from_parts(10, 75),
+			DispatchClass::Operational => Weight::zero(),
+			DispatchClass::Mandatory => Weight::from_parts(0, 25),
+		});
+		assert_eq!(all_weight.total().proof_size(), maximum_weight.max_block.proof_size());
+
+		// fits into the reserved proof size, even though no ref-time is reserved.
+		let operational = DispatchInfo {
+			weight: Weight::from_parts(50, 25),
+			class: DispatchClass::Operational,
+			..Default::default()
+		};
+		// does not fit into the reserved proof size.
+		let too_big_operational = DispatchInfo {
+			weight: Weight::from_parts(50, 26),
+			class: DispatchClass::Operational,
+			..Default::default()
+		};
+		// normal extrinsics cannot use the reserved proof size.
+		let normal = DispatchInfo { weight: Weight::from_parts(1, 1), ..Default::default() };
+
+		// when
+		assert_ok!(calculate_consumed_weight::<<Test as Config>::RuntimeCall>(
+			maximum_weight.clone(),
+			all_weight.clone(),
+			&operational
+		));
+		assert_err!(
+			calculate_consumed_weight::<<Test as Config>::RuntimeCall>(
+				maximum_weight.clone(),
+				all_weight.clone(),
+				&too_big_operational
+			),
+			InvalidTransaction::ExhaustsResources
+		);
+		assert_err!(
+			calculate_consumed_weight::<<Test as Config>::RuntimeCall>(
+				maximum_weight,
+				all_weight,
+				&normal
+			),
+			InvalidTransaction::ExhaustsResources
+		);
+	}
 }
@@ -118,6 +118,10 @@ pub struct WeightsPerClass {
 	/// to go over total block weight (but at most `max_total` for that class).
 	/// Setting to `Some(x)` guarantees that at least `x` weight of particular class
 	/// is processed in every block.
+	///
+	/// The ref-time and proof size components of both `max_total` and `reserved` are enforced
+	/// independently of each other, so a class may for example reserve proof size without
+	/// reserving any ref-time (see [`BlockWeightsBuilder::reserved_proof_size`]).
 	pub reserved: Option<Weight>,
 }
 
@@ -260,9 +264,12 @@ impl BlockWeights {
 				"[{:?}] {:?} (max_extrinsic) must not be 0. Check base cost and average initialization cost.",
 				class, weights.max_extrinsic,
 			);
-			// Make sure that if reserved is set it's greater than base_for_class.
+			// Make sure that each component of reserved, if set, is greater than the same
+			// component of base_for_class.
 			error_assert!(
-				reserved.all_gt(base_for_class) || reserved == Weight::zero(),
+				(reserved.ref_time() > base_for_class.ref_time() || reserved.ref_time() == 0) &&
+					(reserved.proof_size() > base_for_class.proof_size() ||
+						reserved.proof_size() == 0),
 				&mut error,
 				"[{:?}] {:?} (reserved) has to be greater than {:?} (base extrinsic) if set",
 				class,
@@ -400,6 +407,57 @@ impl BlockWeightsBuilder {
 		self
 	}
 
+	/// Set the ref-time component of `max_total` for particular classes, leaving the proof size
+	/// component untouched.
+	///
+	/// A class without a `max_total` limit is treated as having an unlimited proof size.
+	pub fn max_total_ref_time(self, class: impl OneOrMany<DispatchClass>, ref_time: u64) -> Self {
+		self.for_class(class, |weights| {
+			weights.max_total = Some(limit_or_max(weights.max_total).set_ref_time(ref_time));
+		})
+	}
+
+	/// Set the proof size component of `max_total` for particular classes, leaving the ref-time
+	/// component untouched.
+	///
+	/// A class without a `max_total` limit is treated as having an unlimited ref-time.
+	pub fn max_total_proof_size(
+		self,
+		class: impl OneOrMany<DispatchClass>,
+		proof_size: u64,
+	) -> Self {
+		self.for_class(class, |weights| {
+			weights.max_total = Some(limit_or_max(weights.max_total).set_proof_size(proof_size));
+		})
+	}
+
+	/// Set the ref-time component of `reserved` for particular classes, leaving the proof size
+	/// component untouched.
+	///
+	/// A class without a `reserved` limit is treated as having an unlimited proof size
+	/// reservation.
+	pub fn reserved_ref_time(self, class: impl OneOrMany<DispatchClass>, ref_time: u64) -> Self {
+		self.for_class(class, |weights| {
+			weights.reserved = Some(limit_or_max(weights.reserved).set_ref_time(ref_time));
+		})
+	}
+
+	/// Set the proof size component of `reserved` for particular classes, leaving the ref-time
+	/// component untouched.
+	///
+	/// This allows e.g. reserving PoV space for `Operational` extrinsics without also reserving
+	/// any ref-time for them. A class without a `reserved` limit is treated as having an
+	/// unlimited ref-time reservation.
+	pub fn reserved_proof_size(
+		self,
+		class: impl OneOrMany<DispatchClass>,
+		proof_size: u64,
+	) -> Self {
+		self.for_class(class, |weights| {
+			weights.reserved = Some(limit_or_max(weights.reserved).set_proof_size(proof_size));
+		})
+	}
+
 	/// Construct the `BlockWeights` object.
 	pub fn build(self) -> ValidationResult {
 		// compute max extrinsic size
@@ -439,6 +497,11 @@ impl BlockWeightsBuilder {
 	}
 }
 
+/// Treat a missing limit as unlimited.
+fn limit_or_max(limit: Option<Weight>) -> Weight {
+	limit.unwrap_or_else(Weight::max_value)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -447,4 +510,49 @@ mod tests {
 	fn default_weights_are_valid() {
 		BlockWeights::default().validate().unwrap();
 	}
+
+	#[test]
+	fn proof_size_limits_can_be_set_independently() {
+		let weights = BlockWeights::builder()
+			.base_block(Weight::zero())
+			.for_class(DispatchClass::all(), |w| w.base_extrinsic = Weight::zero())
+			.for_class(DispatchClass::non_mandatory(), |w| {
+				w.max_total = Some(Weight::from_parts(1024, 1024))
+			})
+			.max_total_proof_size(DispatchClass::Normal, 768)
+			.reserved_proof_size(DispatchClass::Operational, 256)
+			.reserved_ref_time(DispatchClass::Mandatory, 10)
+			.build_or_panic();
+
+		assert_eq!(weights.max_block, Weight::from_parts(1024, 1024));
+		assert_eq!(
+			weights.get(DispatchClass::Normal).max_total,
+			Some(Weight::from_parts(1024, 768))
+		);
+		assert_eq!(
+			weights.get(DispatchClass::Operational).reserved,
+			Some(Weight::from_parts(0, 256))
+		);
+		assert_eq!(
+			weights.get(DispatchClass::Mandatory).reserved,
+			Some(Weight::from_parts(10, u64::MAX))
+		);
+	}
+
+	#[test]
+	fn reserved_components_are_validated_independently() {
+		let builder = || {
+			BlockWeights::builder()
+				.base_block(Weight::zero())
+				.for_class(DispatchClass::all(), |w| w.base_extrinsic = Weight::from_parts(5, 5))
+				.for_class(DispatchClass::non_mandatory(), |w| {
+					w.max_total = Some(Weight::from_parts(1024, 1024))
+				})
+		};
+
+		// A zero ref-time reservation alongside a proof size reservation is fine.
+		assert!(builder().reserved_proof_size(DispatchClass::Operational, 256).build().is_ok());
+		// A non-zero proof size reservation must still cover the base extrinsic.
+		assert!(builder().reserved_proof_size(DispatchClass::Operational, 5).build().is_err());
+	}
 }
@@ -675,6 +675,95 @@ fn set_code_with_real_wasm_blob() {
 	});
 }
 
+#[test]
+fn authorize_and_apply_upgrade_works() {
+	let executor = substrate_test_runtime_client::new_native_or_wasm_executor();
+	let mut ext = new_test_ext();
+	ext.register_extension(sp_core::traits::ReadRuntimeVersionExt::new(executor));
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		let code = substrate_test_runtime_client::runtime::wasm_binary_unwrap().to_vec();
+		let code_hash = <Test as Config>::Hashing::hash(&code);
+
+		// Nothing authorized yet.
+		assert_noop!(
+			System::apply_authorized_upgrade(RawOrigin::None.into(), code.clone()),
+			Error::<Test>::NothingAuthorized,
+		);
+
+		// Only root can authorize.
+		assert_noop!(
+			System::authorize_upgrade(RawOrigin::Signed(1).into(), code_hash),
+			DispatchError::BadOrigin,
+		);
+		assert_ok!(System::authorize_upgrade(RawOrigin::Root.into(), code_hash));
+		System::assert_has_event(
+			SysEvent::UpgradeAuthorized { code_hash, check_version: true }.into(),
+		);
+		assert!(System::authorized_upgrade().is_some());
+
+		// The unsigned transaction is only valid for the authorized code.
+		assert!(<System as sp_runtime::traits::ValidateUnsigned>::validate_unsigned(
+			sp_runtime::transaction_validity::TransactionSource::External,
+			&Call::apply_authorized_upgrade { code: vec![1, 2, 3] },
+		)
+		.is_err());
+		assert!(<System as sp_runtime::traits::ValidateUnsigned>::validate_unsigned(
+			sp_runtime::transaction_validity::TransactionSource::External,
+			&Call::apply_authorized_upgrade { code: code.clone() },
+		)
+		.is_ok());
+
+		// Code not authorized.
+		assert_noop!(
+			System::apply_authorized_upgrade(RawOrigin::None.into(), vec![1, 2, 3]),
+			Error::<Test>::Unauthorized,
+		);
+
+		// Anyone can provide the authorized code, for free.
+		let post = System::apply_authorized_upgrade(RawOrigin::None.into(), code).unwrap();
+		assert_eq!(post.pays_fee, Pays::No);
+		System::assert_has_event(SysEvent::CodeUpdated.into());
+		assert!(System::authorized_upgrade().is_none());
+	});
+}
+
+#[test]
+fn authorize_upgrade_without_checks_skips_version_checks() {
+	struct ReadRuntimeVersion(Vec<u8>);
+
+	impl sp_core::traits::ReadRuntimeVersion for ReadRuntimeVersion {
+		fn read_runtime_version(
+			&self,
+			_wasm_code: &[u8],
+			_ext: &mut dyn sp_externalities::Externalities,
+		) -> Result<Vec<u8>, String> {
+			Ok(self.0.clone())
+		}
+	}
+
+	// A runtime with a different spec name.
+	let version = RuntimeVersion { spec_name: "test2".into(), ..Default::default() };
+	let mut ext = new_test_ext();
+	ext.register_extension(sp_core::traits::ReadRuntimeVersionExt::new(ReadRuntimeVersion(
+		version.encode(),
+	)));
+	ext.execute_with(|| {
+		let code = vec![1, 2, 3, 4];
+		let code_hash = <Test as Config>::Hashing::hash(&code);
+
+		assert_ok!(System::authorize_upgrade(RawOrigin::Root.into(), code_hash));
+		assert_noop!(
+			System::apply_authorized_upgrade(RawOrigin::None.into(), code.clone()),
+			Error::<Test>::InvalidSpecName,
+		);
+
+		assert_ok!(System::authorize_upgrade_without_checks(RawOrigin::Root.into(), code_hash));
+		assert_ok!(System::apply_authorized_upgrade(RawOrigin::None.into(), code));
+		assert_runtime_updated_digest(1);
+	});
+}
+
 #[test]
 fn runtime_upgraded_with_set_storage() {
 	let executor = substrate_test_runtime_client::new_native_or_wasm_executor();
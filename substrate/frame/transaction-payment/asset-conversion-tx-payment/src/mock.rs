@@ -24,7 +24,9 @@ use frame_support::{
 	ord_parameter_types,
 	pallet_prelude::*,
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, ConstU8, Imbalance, OnUnbalanced},
+	traits::{
+		AsEnsureOriginWithArg, ConstU32, ConstU64, ConstU8, Imbalance, Nothing, OnUnbalanced,
+	},
 	weights::{Weight, WeightToFee as WeightToFeeT},
 	PalletId,
 };
@@ -122,7 +124,7 @@ impl pallet_balances::Config for Runtime {
 	type ReserveIdentifier = [u8; 8];
 	type FreezeIdentifier = ();
 	type MaxFreezes = ();
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = ();
 	type MaxHolds = ();
 }
@@ -238,6 +240,7 @@ ord_parameter_types! {
 impl pallet_asset_conversion::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type AssetBalance = <Self as pallet_balances::Config>::Balance;
 	type AssetId = u32;
 	type PoolAssetId = u32;
@@ -252,6 +255,10 @@ impl pallet_asset_conversion::Config for Runtime {
 	type AllowMultiAssetPools = AllowMultiAssetPools;
 	type MaxSwapPathLength = MaxSwapPathLength;
 	type MintMinLiquidity = ConstU64<100>; // 100 is good enough when the main currency has 12 decimals.
+	type PoolCreationDeposit = ConstU64<0>;
+	type MinInitialLiquidity = ConstU64<100>;
+	type RestrictedAssets = Nothing;
+	type AllowlistOrigin = EnsureRoot<u64>;
 
 	type Balance = u64;
 	type HigherPrecisionBalance = u128;
@@ -334,6 +334,36 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn expire_spend() -> Result<(), BenchmarkError> {
+		let origin = T::SpendOrigin::try_successful_origin().map_err(|_| "No origin")?;
+		let (asset_kind, amount, _, beneficiary_lookup) = create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		Treasury::<T, _>::spend(
+			origin,
+			Box::new(asset_kind.clone()),
+			amount,
+			Box::new(beneficiary_lookup),
+			None,
+		)?;
+		let expire_at =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::PayoutPeriod::get());
+		frame_system::Pallet::<T>::set_block_number(expire_at.saturating_add(1u32.into()));
+		// Only enough weight to check the expired spend.
+		let limit = T::DbWeight::get()
+			.reads_writes(2, 2)
+			.saturating_add(T::WeightInfo::expire_spend());
+
+		#[block]
+		{
+			Treasury::<T, _>::expire_spends(limit);
+		}
+
+		assert!(Spends::<T, I>::get(0).is_none());
+		assert_last_event::<T, I>(Event::SpendExpired { index: 0 }.into());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Treasury,
 		crate::tests::ExtBuilder::default().build(),
@@ -68,7 +68,8 @@
 //! any asset kind managed by the treasury, with payment facilitated by a designated
 //! [`pallet::Config::Paymaster`]. To claim these spends, the `payout` dispatchable should be called
 //! within some temporal bounds, starting from the moment they become valid and within one
-//! [`pallet::Config::PayoutPeriod`].
+//! [`pallet::Config::PayoutPeriod`]. Spends which expire without being paid are removed in the
+//! `on_idle` hook and reported to [`pallet::Config::OnSpendExpired`].
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -83,7 +84,7 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
 use sp_runtime::{
-	traits::{AccountIdConversion, CheckedAdd, Saturating, StaticLookup, Zero},
+	traits::{AccountIdConversion, Bounded, CheckedAdd, Saturating, StaticLookup, Zero},
 	Permill, RuntimeDebug,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
@@ -95,7 +96,7 @@ use frame_support::{
 		tokens::Pay, Currency, ExistenceRequirement::KeepAlive, Get, Imbalance, OnUnbalanced,
 		ReservableCurrency, WithdrawReasons,
 	},
-	weights::Weight,
+	weights::{Weight, WeightMeter},
 	PalletId,
 };
 
@@ -135,6 +136,20 @@ pub trait SpendFunds<T: Config<I>, I: 'static = ()> {
 	);
 }
 
+/// A handler notified about approved spends which expired without being paid.
+///
+/// The weight of the handler is accounted for by the `expire_spend` benchmark.
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait OnSpendExpired<AssetKind, AssetBalance, Beneficiary> {
+	/// The spend `index` of `amount` of `asset_kind` to `beneficiary` has expired.
+	fn on_spend_expired(
+		index: SpendIndex,
+		asset_kind: &AssetKind,
+		amount: &AssetBalance,
+		beneficiary: &Beneficiary,
+	);
+}
+
 /// An index of a proposal. Just a `u32`.
 pub type ProposalIndex = u32;
 
@@ -286,6 +301,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type PayoutPeriod: Get<BlockNumberFor<Self>>;
 
+		/// Handler for approved spends which expired without being paid, e.g. to notify the
+		/// beneficiary's chain over XCM.
+		type OnSpendExpired: OnSpendExpired<
+			Self::AssetKind,
+			AssetBalanceOf<Self, I>,
+			Self::Beneficiary,
+		>;
+
 		/// Helper type for benchmarks.
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: ArgumentsFactory<Self::AssetKind, Self::Beneficiary>;
@@ -339,6 +362,19 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The index of the last spend checked for expiry in the `on_idle` hook.
+	///
+	/// The next check continues after it. `None` once all spends were checked.
+	#[pallet::storage]
+	pub type SpendExpiryCursor<T, I = ()> = StorageValue<_, SpendIndex, OptionQuery>;
+
+	/// The earliest block at which a spend expires, as far as the `on_idle` hook knows.
+	///
+	/// Once all spends were checked, the hook only checks them again after this block. `None`
+	/// if unknown, in which case the spends are checked right away.
+	#[pallet::storage]
+	pub type NextSpendExpiry<T, I = ()> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -401,6 +437,8 @@ pub mod pallet {
 		/// A spend was processed and removed from the storage. It might have been successfully
 		/// paid or it may have expired.
 		SpendProcessed { index: SpendIndex },
+		/// An approved spend expired without being paid and was removed from the storage.
+		SpendExpired { index: SpendIndex },
 	}
 
 	/// Error for the treasury pallet.
@@ -458,6 +496,12 @@ pub mod pallet {
 			}
 		}
 
+		/// ## Complexity
+		/// - `O(S)` where `S` is the number of spends checked within `remaining_weight`
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::expire_spends(remaining_weight)
+		}
+
 		#[cfg(feature = "try-runtime")]
 		fn try_state(
 			_: frame_system::pallet_prelude::BlockNumberFor<T>,
@@ -776,6 +820,7 @@ pub mod pallet {
 				},
 			);
 			SpendCount::<T, I>::put(index + 1);
+			Self::note_spend_expiry(expire_at);
 
 			Self::deposit_event(Event::AssetSpendApproved {
 				index,
@@ -875,6 +920,8 @@ pub mod pallet {
 			match T::Paymaster::check_payment(payment_id) {
 				Status::Failure => {
 					spend.status = PaymentState::Failed;
+					// The spend may expire again, now that it is no longer attempted.
+					Self::note_spend_expiry(spend.expire_at);
 					Spends::<T, I>::insert(index, spend);
 					Self::deposit_event(Event::<T, I>::PaymentFailed { index, payment_id });
 				},
@@ -1022,6 +1069,74 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		total_weight
 	}
 
+	/// Remove the spends which expired without being paid, as many as fit in `limit`.
+	///
+	/// Spends are checked in storage order, continuing after the last spend checked by the
+	/// previous call. Once all spends were checked, they are only checked again after
+	/// [`NextSpendExpiry`]. Returns the weight consumed.
+	pub(crate) fn expire_spends(limit: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(limit);
+		if meter.try_consume(T::DbWeight::get().reads_writes(2, 2)).is_err() {
+			return Weight::zero()
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let start = SpendExpiryCursor::<T, I>::get();
+		let next_expiry = NextSpendExpiry::<T, I>::get();
+		let mut next = match (start, next_expiry) {
+			// Continue checking the spends.
+			(Some(_), Some(next)) => next,
+			// Start checking all spends again, collecting the earliest expiry of the spends kept.
+			(Some(_), None) | (None, None) => BlockNumberFor::<T>::max_value(),
+			(None, Some(next)) if now > next => BlockNumberFor::<T>::max_value(),
+			// No spend expired since all spends were checked.
+			(None, Some(_)) => return T::DbWeight::get().reads(2),
+		};
+		let mut cursor = start;
+		let mut spends = match cursor {
+			Some(index) => Spends::<T, I>::iter_from(Spends::<T, I>::hashed_key_for(index)),
+			None => Spends::<T, I>::iter(),
+		};
+		while meter.try_consume(T::WeightInfo::expire_spend()).is_ok() {
+			let Some((index, spend)) = spends.next() else {
+				// All spends were checked, start over with the next call.
+				cursor = None;
+				break
+			};
+			cursor = Some(index);
+			// A spend with a payment attempt is concluded by `check_status`.
+			if now > spend.expire_at && !matches!(spend.status, PaymentState::Attempted { .. }) {
+				Spends::<T, I>::remove(index);
+				T::OnSpendExpired::on_spend_expired(
+					index,
+					&spend.asset_kind,
+					&spend.amount,
+					&spend.beneficiary,
+				);
+				Self::deposit_event(Event::<T, I>::SpendExpired { index });
+			} else if !matches!(spend.status, PaymentState::Attempted { .. }) {
+				next = next.min(spend.expire_at);
+			}
+		}
+		if cursor != start {
+			SpendExpiryCursor::<T, I>::set(cursor);
+		}
+		if Some(next) != next_expiry {
+			NextSpendExpiry::<T, I>::set(Some(next));
+		}
+
+		meter.consumed()
+	}
+
+	/// Make sure the `on_idle` hook checks the spends again once `expire_at` has passed.
+	fn note_spend_expiry(expire_at: BlockNumberFor<T>) {
+		NextSpendExpiry::<T, I>::mutate(|next| {
+			if let Some(next) = next {
+				*next = (*next).min(expire_at);
+			}
+		});
+	}
+
 	/// Return the amount of money in the pot.
 	// The existential deposit is not part of the pot so treasury account never gets deleted.
 	pub fn pot() -> BalanceOf<T, I> {
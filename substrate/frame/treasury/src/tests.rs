@@ -32,7 +32,7 @@ use frame_support::{
 	parameter_types,
 	traits::{
 		tokens::{ConversionFromAssetBalance, PaymentStatus},
-		ConstU32, ConstU64, OnInitialize,
+		ConstU32, ConstU64, OnIdle, OnInitialize,
 	},
 	PalletId,
 };
@@ -108,6 +108,7 @@ thread_local! {
 	pub static PAID: RefCell<BTreeMap<(u128, u32), u64>> = RefCell::new(BTreeMap::new());
 	pub static STATUS: RefCell<BTreeMap<u64, PaymentStatus>> = RefCell::new(BTreeMap::new());
 	pub static LAST_ID: RefCell<u64> = RefCell::new(0u64);
+	pub static EXPIRED: RefCell<Vec<SpendIndex>> = RefCell::new(Vec::new());
 }
 
 /// paid balance for a given account and asset ids
@@ -125,6 +126,18 @@ fn set_status(id: u64, s: PaymentStatus) {
 	STATUS.with(|m| m.borrow_mut().insert(id, s));
 }
 
+/// indices of the spends reported as expired
+fn expired() -> Vec<SpendIndex> {
+	EXPIRED.with(|e| e.borrow().clone())
+}
+
+pub struct TestOnSpendExpired;
+impl OnSpendExpired<u32, u64, u128> for TestOnSpendExpired {
+	fn on_spend_expired(index: SpendIndex, _: &u32, _: &u64, _: &u128) {
+		EXPIRED.with(|e| e.borrow_mut().push(index));
+	}
+}
+
 pub struct TestPay;
 impl Pay for TestPay {
 	type Beneficiary = u128;
@@ -215,6 +228,7 @@ impl Config for Test {
 	type Paymaster = TestPay;
 	type BalanceConverter = MulBy<ConstU64<2>>;
 	type PayoutPeriod = SpendPayoutPeriod;
+	type OnSpendExpired = TestOnSpendExpired;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -999,6 +1013,129 @@ fn check_status_works() {
 	});
 }
 
+#[test]
+fn expired_spends_are_removed_on_idle() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(<Test as Config>::PayoutPeriod::get(), 5);
+		System::set_block_number(1);
+
+		// spend `0` expires unclaimed.
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(10), Box::new(1), 2, Box::new(6), None));
+		// spend `1` has a payment attempt which is left to `check_status`.
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(10), Box::new(1), 2, Box::new(6), None));
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 1));
+		// spend `2` is still valid.
+		assert_ok!(Treasury::spend(
+			RuntimeOrigin::signed(10),
+			Box::new(1),
+			2,
+			Box::new(6),
+			Some(10)
+		));
+
+		// not expired yet.
+		System::set_block_number(6);
+		Treasury::on_idle(6, Weight::MAX);
+		assert_eq!(Spends::<Test, _>::iter().count(), 3);
+		assert!(expired().is_empty());
+
+		System::set_block_number(7);
+		Treasury::on_idle(7, Weight::MAX);
+		assert_eq!(Spends::<Test, _>::get(0), None);
+		assert!(Spends::<Test, _>::get(1).is_some());
+		assert!(Spends::<Test, _>::get(2).is_some());
+		assert_eq!(expired(), vec![0]);
+		System::assert_has_event(Event::<Test, _>::SpendExpired { index: 0 }.into());
+		// all spends were checked.
+		assert_eq!(SpendExpiryCursor::<Test, _>::get(), None);
+
+		// an expired spend cannot be voided anymore.
+		assert_noop!(
+			Treasury::void_spend(RuntimeOrigin::root(), 0),
+			Error::<Test, _>::InvalidIndex
+		);
+	});
+}
+
+#[test]
+fn expire_spends_respects_weight_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		for _ in 0..3 {
+			assert_ok!(Treasury::spend(
+				RuntimeOrigin::signed(10),
+				Box::new(1),
+				2,
+				Box::new(6),
+				None
+			));
+		}
+		System::set_block_number(7);
+
+		// not even enough weight to read the cursor.
+		assert_eq!(Treasury::on_idle(7, Weight::zero()), Weight::zero());
+		assert_eq!(Spends::<Test, _>::iter().count(), 3);
+
+		// one spend checked per call, resuming after the last one checked.
+		let limit = <Test as frame_system::Config>::DbWeight::get()
+			.reads_writes(2, 2)
+			.saturating_add(<() as WeightInfo>::expire_spend());
+		for remaining in (0..3).rev() {
+			assert_eq!(Treasury::on_idle(7, limit), limit);
+			assert_eq!(Spends::<Test, _>::iter().count(), remaining);
+			assert!(SpendExpiryCursor::<Test, _>::get().is_some());
+		}
+		let mut reported = expired();
+		reported.sort();
+		assert_eq!(reported, vec![0, 1, 2]);
+
+		// nothing left, start over with the next call.
+		Treasury::on_idle(7, limit);
+		assert_eq!(SpendExpiryCursor::<Test, _>::get(), None);
+	});
+}
+
+#[test]
+fn expire_spends_waits_for_the_next_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		let idle_weight = <Test as frame_system::Config>::DbWeight::get().reads(2);
+		System::set_block_number(1);
+		// spend `0` expires after block 15.
+		assert_ok!(Treasury::spend(
+			RuntimeOrigin::signed(10),
+			Box::new(1),
+			2,
+			Box::new(6),
+			Some(10)
+		));
+
+		// all spends are checked once.
+		assert!(Treasury::on_idle(1, Weight::MAX).any_gt(idle_weight));
+		assert_eq!(SpendExpiryCursor::<Test, _>::get(), None);
+		assert_eq!(NextSpendExpiry::<Test, _>::get(), Some(15));
+
+		// then not again until a spend may have expired.
+		System::set_block_number(2);
+		assert_eq!(Treasury::on_idle(2, Weight::MAX), idle_weight);
+
+		// spend `1` expires after block 7, before spend `0`.
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(10), Box::new(1), 2, Box::new(6), None));
+		assert_eq!(NextSpendExpiry::<Test, _>::get(), Some(7));
+
+		System::set_block_number(7);
+		assert_eq!(Treasury::on_idle(7, Weight::MAX), idle_weight);
+		assert!(expired().is_empty());
+
+		System::set_block_number(8);
+		assert!(Treasury::on_idle(8, Weight::MAX).any_gt(idle_weight));
+		assert_eq!(expired(), vec![1]);
+		assert_eq!(NextSpendExpiry::<Test, _>::get(), Some(15));
+
+		System::set_block_number(9);
+		assert_eq!(Treasury::on_idle(9, Weight::MAX), idle_weight);
+	});
+}
+
 #[test]
 fn try_state_proposals_invariant_1_works() {
 	ExtBuilder::default().build().execute_with(|| {
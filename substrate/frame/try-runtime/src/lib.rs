@@ -49,5 +49,19 @@ sp_api::decl_runtime_apis! {
 			signature_check: bool,
 			try_state: TryStateSelect,
 		) -> Weight;
+
+		/// Execute the given block like [`Self::execute_block`], while measuring the weight used
+		/// by the `on_initialize`, `on_idle` and `on_finalize` hooks of each pallet.
+		///
+		/// Hooks that use more weight than they declared are logged, and fail the execution if
+		/// `fail_on_underestimate` is `true`.
+		#[api_version(2)]
+		fn execute_block_measuring_hooks(
+			block: Block,
+			state_root_check: bool,
+			signature_check: bool,
+			try_state: TryStateSelect,
+			fail_on_underestimate: bool,
+		) -> Weight;
 	}
 }
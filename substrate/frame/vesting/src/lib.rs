@@ -82,7 +82,8 @@ pub use pallet::*;
 pub use vesting_info::*;
 pub use weights::WeightInfo;
 
-type BalanceOf<T> =
+/// Balance type of the vested currency.
+pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 type MaxLocksOf<T> =
 	<<T as Config>::Currency as LockableCurrency<<T as frame_system::Config>::AccountId>>::MaxLocks;
@@ -193,13 +193,13 @@ fn generate_runtime_decls(decls: &[ItemTrait]) -> Result<TokenStream> {
 			get_api_version(&found_attributes).map(|v| generate_runtime_api_version(v as u32))?;
 		let id = generate_runtime_api_id(&decl.ident.to_string());
 
+		let trait_api_version = get_api_version(&found_attributes)?;
+
 		#[cfg(feature = "frame-metadata")]
-		let metadata = crate::runtime_metadata::generate_decl_runtime_metadata(&decl);
+		let metadata = crate::runtime_metadata::generate_decl_runtime_metadata(&decl, trait_api_version);
 		#[cfg(not(feature = "frame-metadata"))]
 		let metadata = quote!();
 
-		let trait_api_version = get_api_version(&found_attributes)?;
-
 		let mut methods_by_version: BTreeMap<u64, Vec<TraitItemFn>> = BTreeMap::new();
 
 		// Process the items in the declaration. The filter_map function below does a lot of stuff
@@ -71,8 +71,9 @@ fn collect_docs(attrs: &[syn::Attribute], crate_: &TokenStream2) -> TokenStream2
 /// Generate the runtime metadata of the provided trait.
 ///
 /// The metadata is exposed as a generic function on the hidden module
-/// of the trait generated by the `decl_runtime_apis`.
-pub fn generate_decl_runtime_metadata(decl: &ItemTrait) -> TokenStream2 {
+/// of the trait generated by the `decl_runtime_apis`. `api_version` is the version of the trait
+/// the metadata of the methods is collected for.
+pub fn generate_decl_runtime_metadata(decl: &ItemTrait, api_version: u64) -> TokenStream2 {
 	let crate_ = generate_crate_access();
 	let mut methods = Vec::new();
 
@@ -145,6 +146,7 @@ pub fn generate_decl_runtime_metadata(decl: &ItemTrait) -> TokenStream2 {
 	let trait_name_ident = &decl.ident;
 	let trait_name = trait_name_ident.to_string();
 	let docs = collect_docs(&decl.attrs, &crate_);
+	let api_version = api_version as u32;
 	let attrs = filter_cfg_attributes(&decl.attrs);
 	// The trait generics where already extended with `Block: BlockT`.
 	let mut generics = decl.generics.clone();
@@ -173,6 +175,7 @@ pub fn generate_decl_runtime_metadata(decl: &ItemTrait) -> TokenStream2 {
 				name: #trait_name,
 				methods: #crate_::vec![ #( #methods, )* ],
 				docs: #docs,
+				version: #api_version,
 			}
 		}
 	)
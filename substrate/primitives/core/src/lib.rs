@@ -69,6 +69,8 @@ pub mod hash;
 #[cfg(feature = "std")]
 mod hasher;
 pub mod offchain;
+#[cfg(feature = "secp256r1")]
+pub mod p256;
 pub mod paired_crypto;
 pub mod sr25519;
 pub mod testing;
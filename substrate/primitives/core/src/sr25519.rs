@@ -530,6 +530,30 @@ impl Pair {
 			Err(_) => false,
 		}
 	}
+
+	/// Verify a batch of signatures, each over its own message and public key.
+	///
+	/// Returns `true` if all signatures are good. This gives the same result as verifying each
+	/// of them with [`Pair::verify`], but is considerably faster for larger batches.
+	pub fn verify_batch<'a>(
+		batch: impl IntoIterator<Item = (&'a Signature, &'a [u8], &'a Public)>,
+	) -> bool {
+		let mut transcripts = Vec::new();
+		let mut signatures = Vec::new();
+		let mut public_keys = Vec::new();
+		for (sig, message, pubkey) in batch {
+			let Ok(signature) = schnorrkel::Signature::from_bytes(sig.as_ref()) else {
+				return false
+			};
+			let Ok(public) = PublicKey::from_bytes(pubkey.as_ref()) else { return false };
+			transcripts.push(signing_context(SIGNING_CTX).bytes(message));
+			signatures.push(signature);
+			public_keys.push(public);
+		}
+
+		signatures.is_empty() ||
+			schnorrkel::verify_batch(transcripts, &signatures, &public_keys, false).is_ok()
+	}
 }
 
 impl CryptoType for Public {
@@ -1041,6 +1065,30 @@ mod tests {
 		assert!(!Pair::verify(&js_signature, b"SUBSTRATE", &public));
 	}
 
+	#[test]
+	fn verify_batch_works() {
+		let alice = Pair::from_string("//Alice", None).unwrap();
+		let bob = Pair::from_string("//Bob", None).unwrap();
+		let (msg1, msg2) = (&b"first"[..], &b"second"[..]);
+		let (sig1, sig2) = (alice.sign(msg1), bob.sign(msg2));
+
+		assert!(Pair::verify_batch(vec![]));
+		assert!(Pair::verify_batch(vec![
+			(&sig1, msg1, &alice.public()),
+			(&sig2, msg2, &bob.public()),
+		]));
+		// signatures swapped between the messages.
+		assert!(!Pair::verify_batch(vec![
+			(&sig2, msg1, &alice.public()),
+			(&sig1, msg2, &bob.public()),
+		]));
+		// a single wrong signer spoils the batch.
+		assert!(!Pair::verify_batch(vec![
+			(&sig1, msg1, &alice.public()),
+			(&sig2, msg2, &alice.public()),
+		]));
+	}
+
 	#[test]
 	fn signature_serialization_works() {
 		let pair = Pair::from_seed(b"12345678901234567890123456789012");
@@ -772,6 +772,75 @@ impl Default for UseDalekExt {
 	}
 }
 
+/// Verify an `ed25519` signature using the `ed25519-dalek` crate.
+#[cfg(feature = "std")]
+fn ed25519_verify_dalek(sig: &ed25519::Signature, msg: &[u8], pub_key: &ed25519::Public) -> bool {
+	use ed25519_dalek::Verifier;
+
+	let Ok(public_key) = ed25519_dalek::VerifyingKey::from_bytes(&pub_key.0) else { return false };
+
+	let sig = ed25519_dalek::Signature::from_bytes(&sig.0);
+
+	public_key.verify(msg, &sig).is_ok()
+}
+
+#[cfg(feature = "std")]
+sp_externalities::decl_extension! {
+	/// Signatures that were found valid by one of the batch verification functions, e.g.
+	/// [`crypto::sr25519_verify_batch`].
+	///
+	/// Verifying any of them again in the same context doesn't repeat the cryptography. Only
+	/// valid signatures are remembered, so this never changes the result of a verification.
+	struct VerifiedSignaturesExt(std::collections::HashSet<[u8; 32]>);
+}
+
+/// The key of a signature in [`VerifiedSignaturesExt`].
+#[cfg(feature = "std")]
+fn verified_signature_key(
+	crypto_id: sp_core::crypto::CryptoTypeId,
+	sig: &[u8],
+	msg: &[u8],
+	pub_key: &[u8],
+) -> [u8; 32] {
+	sp_core::hashing::blake2_256(&(crypto_id.0, sig, msg, pub_key).encode())
+}
+
+/// Returns whether the signature was already found valid as part of a batch in the current
+/// externalities context.
+#[cfg(feature = "std")]
+fn is_batch_verified(
+	crypto_id: sp_core::crypto::CryptoTypeId,
+	sig: &[u8],
+	msg: &[u8],
+	pub_key: &[u8],
+) -> bool {
+	sp_externalities::with_externalities(|mut e| {
+		e.extension::<VerifiedSignaturesExt>().map_or(false, |verified| {
+			verified.0.contains(&verified_signature_key(crypto_id, sig, msg, pub_key))
+		})
+	})
+	.unwrap_or_default()
+}
+
+/// Remember the given signatures as valid in the current externalities context.
+#[cfg(feature = "std")]
+fn remember_batch_verified<'a>(
+	mut ext: &mut dyn Externalities,
+	crypto_id: sp_core::crypto::CryptoTypeId,
+	batch: impl Iterator<Item = (&'a [u8], &'a [u8], &'a [u8])>,
+) {
+	if ext.extension::<VerifiedSignaturesExt>().is_none() {
+		ext.register_extension(VerifiedSignaturesExt(Default::default()))
+			.expect("Extension is not registered yet; qed");
+	}
+
+	if let Some(verified) = ext.extension::<VerifiedSignaturesExt>() {
+		verified.0.extend(
+			batch.map(|(sig, msg, pub_key)| verified_signature_key(crypto_id, sig, msg, pub_key)),
+		);
+	}
+}
+
 /// Interfaces for working with crypto related types from within the runtime.
 #[runtime_interface]
 pub trait Crypto {
@@ -817,21 +886,17 @@ pub trait Crypto {
 	///
 	/// Returns `true` when the verification was successful.
 	fn ed25519_verify(sig: &ed25519::Signature, msg: &[u8], pub_key: &ed25519::Public) -> bool {
+		if is_batch_verified(ed25519::CRYPTO_ID, sig.as_ref(), msg, pub_key.as_ref()) {
+			return true
+		}
+
 		// We don't want to force everyone needing to call the function in an externalities context.
 		// So, we assume that we should not use dalek when we are not in externalities context.
 		// Otherwise, we check if the extension is present.
 		if sp_externalities::with_externalities(|mut e| e.extension::<UseDalekExt>().is_some())
 			.unwrap_or_default()
 		{
-			use ed25519_dalek::Verifier;
-
-			let Ok(public_key) = ed25519_dalek::VerifyingKey::from_bytes(&pub_key.0) else {
-				return false
-			};
-
-			let sig = ed25519_dalek::Signature::from_bytes(&sig.0);
-
-			public_key.verify(msg, &sig).is_ok()
+			ed25519_verify_dalek(sig, msg, pub_key)
 		} else {
 			ed25519::Pair::verify(sig, msg, pub_key)
 		}
@@ -866,12 +931,41 @@ pub trait Crypto {
 		res
 	}
 
+	/// Verify a batch of `ed25519` signatures, each over its own message and public key.
+	///
+	/// Returns `true` when all signatures are valid. This is considerably faster than verifying
+	/// the signatures one by one. The signatures of a valid batch are remembered for the rest of
+	/// the current context, so verifying any of them again with [`ed25519_verify`] is cheap.
+	fn ed25519_verify_batch(
+		&mut self,
+		batch: Vec<(ed25519::Signature, Vec<u8>, ed25519::Public)>,
+	) -> bool {
+		let valid = if self.extension::<UseDalekExt>().is_some() {
+			batch.iter().all(|(sig, msg, pub_key)| ed25519_verify_dalek(sig, msg, pub_key))
+		} else {
+			ed25519::Pair::verify_batch(
+				batch.iter().map(|(sig, msg, pub_key)| (sig, &msg[..], pub_key)),
+			)
+		};
+
+		if valid {
+			remember_batch_verified(
+				*self,
+				ed25519::CRYPTO_ID,
+				batch.iter().map(|(sig, msg, pub_key)| (&sig.0[..], &msg[..], &pub_key.0[..])),
+			);
+		}
+
+		valid
+	}
+
 	/// Verify `sr25519` signature.
 	///
 	/// Returns `true` when the verification was successful.
 	#[version(2)]
 	fn sr25519_verify(sig: &sr25519::Signature, msg: &[u8], pub_key: &sr25519::Public) -> bool {
-		sr25519::Pair::verify(sig, msg, pub_key)
+		is_batch_verified(sr25519::CRYPTO_ID, sig.as_ref(), msg, pub_key.as_ref()) ||
+			sr25519::Pair::verify(sig, msg, pub_key)
 	}
 
 	/// Register a `sr25519` signature for batch verification.
@@ -903,6 +997,30 @@ pub trait Crypto {
 		res
 	}
 
+	/// Verify a batch of `sr25519` signatures, each over its own message and public key.
+	///
+	/// Returns `true` when all signatures are valid. This is considerably faster than verifying
+	/// the signatures one by one. The signatures of a valid batch are remembered for the rest of
+	/// the current context, so verifying any of them again with [`sr25519_verify`] is cheap.
+	fn sr25519_verify_batch(
+		&mut self,
+		batch: Vec<(sr25519::Signature, Vec<u8>, sr25519::Public)>,
+	) -> bool {
+		let valid = sr25519::Pair::verify_batch(
+			batch.iter().map(|(sig, msg, pub_key)| (sig, &msg[..], pub_key)),
+		);
+
+		if valid {
+			remember_batch_verified(
+				*self,
+				sr25519::CRYPTO_ID,
+				batch.iter().map(|(sig, msg, pub_key)| (&sig.0[..], &msg[..], &pub_key.0[..])),
+			);
+		}
+
+		valid
+	}
+
 	/// Start verification extension.
 	///
 	/// NOTE: Is tagged with `register_only` to keep the functions around for backwards
@@ -1068,6 +1186,20 @@ pub trait Crypto {
 		ecdsa::Pair::verify_prehashed(sig, msg, pub_key)
 	}
 
+	/// Verify a secp256r1 (NIST P-256) `ecdsa` signature, as produced by passkeys.
+	///
+	/// The `msg` is hashed with SHA-256 before verification.
+	///
+	/// Returns `true` when the verification was successful.
+	#[cfg(feature = "secp256r1")]
+	fn p256_verify(
+		sig: &sp_core::p256::Signature,
+		msg: &[u8],
+		pub_key: &sp_core::p256::Public,
+	) -> bool {
+		sig.verify(msg, pub_key)
+	}
+
 	/// Register a `ecdsa` signature for batch verification.
 	///
 	/// Batch verification must be enabled by calling [`start_batch_verify`].
@@ -1937,6 +2069,43 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn batch_verification_works() {
+		let alice = sr25519::Pair::from_string("//Alice", None).unwrap();
+		let bob = ed25519::Pair::from_string("//Bob", None).unwrap();
+		let msg = b"all good".to_vec();
+		let sr_sig = alice.sign(&msg);
+		let ed_sig = bob.sign(&msg);
+
+		BasicExternalities::default().execute_with(|| {
+			assert!(crypto::sr25519_verify_batch(vec![(
+				sr_sig.clone(),
+				msg.clone(),
+				alice.public()
+			)]));
+			assert!(crypto::ed25519_verify_batch(vec![(
+				ed_sig.clone(),
+				msg.clone(),
+				bob.public()
+			)]));
+			assert!(!crypto::sr25519_verify_batch(vec![
+				(sr_sig.clone(), msg.clone(), alice.public()),
+				(sr_sig.clone(), b"tampered".to_vec(), alice.public()),
+			]));
+			assert!(!crypto::ed25519_verify_batch(vec![(
+				ed_sig.clone(),
+				b"tampered".to_vec(),
+				bob.public()
+			)]));
+
+			// the signatures of valid batches are remembered, invalid ones are not.
+			assert!(crypto::sr25519_verify(&sr_sig, &msg, &alice.public()));
+			assert!(crypto::ed25519_verify(&ed_sig, &msg, &bob.public()));
+			assert!(!crypto::sr25519_verify(&sr_sig, b"tampered", &alice.public()));
+			assert!(!crypto::ed25519_verify(&ed_sig, b"tampered", &bob.public()));
+		});
+	}
+
 	#[test]
 	fn dalek_should_not_panic_on_invalid_signature() {
 		let mut ext = BasicExternalities::default();
@@ -30,6 +30,7 @@ pub use types::*;
 
 mod v14;
 mod v15;
+mod v16;
 
 /// Metadata V14.
 const V14: u32 = 14;
@@ -37,23 +38,29 @@ const V14: u32 = 14;
 /// Metadata V15.
 const V15: u32 = 15;
 
+/// Metadata V16.
+///
+/// Not yet stable, thus exposed as `u32::MAX`.
+const V16: u32 = u32::MAX;
+
 /// Transform the IR to the specified version.
 ///
 /// Use [`supported_versions`] to find supported versions.
 pub fn into_version(metadata: MetadataIR, version: u32) -> Option<RuntimeMetadataPrefixed> {
 	// Note: Unstable metadata version is `u32::MAX` until stabilized.
 	match version {
-		// Latest stable version.
+		// Stable versions.
 		V14 => Some(into_v14(metadata)),
-		// Unstable metadata.
 		V15 => Some(into_latest(metadata)),
+		// Unstable metadata.
+		V16 => Some(into_unstable(metadata)),
 		_ => None,
 	}
 }
 
 /// Returns the supported metadata versions.
 pub fn supported_versions() -> sp_std::vec::Vec<u32> {
-	sp_std::vec![V14, V15]
+	sp_std::vec![V14, V15, V16]
 }
 
 /// Transform the IR to the latest stable metadata version.
@@ -62,6 +69,12 @@ pub fn into_latest(metadata: MetadataIR) -> RuntimeMetadataPrefixed {
 	latest.into()
 }
 
+/// Transform the IR to the unstable metadata version 16.
+pub fn into_unstable(metadata: MetadataIR) -> RuntimeMetadataPrefixed {
+	let unstable: frame_metadata::v16::RuntimeMetadataV16 = metadata.into();
+	unstable.into()
+}
+
 /// Transform the IR to metadata version 14.
 pub fn into_v14(metadata: MetadataIR) -> RuntimeMetadataPrefixed {
 	let latest: frame_metadata::v14::RuntimeMetadataV14 = metadata.into();
@@ -115,4 +128,14 @@ mod test {
 
 		assert!(matches!(metadata.1, RuntimeMetadata::V15(_)));
 	}
+
+	#[test]
+	fn into_version_16() {
+		let ir = ir_metadata();
+		let metadata = into_version(ir, V16).expect("Should return prefixed metadata");
+
+		assert_eq!(metadata.0, META_RESERVED);
+
+		assert!(matches!(metadata.1, RuntimeMetadata::V16(_)));
+	}
 }
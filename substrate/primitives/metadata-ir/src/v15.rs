@@ -51,6 +51,7 @@ impl From<RuntimeApiMetadataIR> for RuntimeApiMetadata {
 			name: ir.name,
 			methods: ir.methods.into_iter().map(Into::into).collect(),
 			docs: ir.docs,
+			// Note: ir.version not part of v15.
 		}
 	}
 }
@@ -83,6 +84,7 @@ impl From<PalletMetadataIR> for PalletMetadata {
 			error: ir.error.map(Into::into),
 			index: ir.index,
 			docs: ir.docs,
+			// Note: ir.view_functions not part of v15.
 		}
 	}
 }
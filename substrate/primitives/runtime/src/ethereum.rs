@@ -0,0 +1,341 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compatibility layer allowing Ethereum wallets to sign transactions.
+//!
+//! Ethereum wallets sign messages using the `personal_sign` method of
+//! [EIP-191](https://eips.ethereum.org/EIPS/eip-191), and identify accounts by 20 byte addresses.
+//! This module provides:
+//!
+//! - [`EthereumCompatibleSignature`], a signature type that verifies like a [`MultiSignature`], and
+//!   also accepts `personal_sign` signatures of the signed payload of a transaction.
+//! - A deterministic mapping of Ethereum addresses to [`AccountId32`]s, see
+//!   [`account_from_address`].
+//! - [`EthereumAccountIdLookup`], an account lookup also accepting Ethereum addresses.
+//!
+//! Both are opt-in: a runtime uses them as its `Signature` and `Lookup` types respectively. The
+//! signature type encodes the same way as a [`MultiSignature`] for the variants they share, so
+//! existing transactions remain valid.
+
+use crate::{
+	traits::{Lazy, LookupError, StaticLookup, Verify},
+	AccountId32, MultiAddress, MultiSignature, MultiSigner,
+};
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
+use scale_info::{StaticTypeInfo, TypeInfo};
+use sp_core::{ecdsa, ed25519, sr25519, H160};
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The prefix of all messages signed with `personal_sign`.
+const PERSONAL_SIGN_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n";
+
+/// The suffix of the [`AccountId32`] an Ethereum address is mapped to.
+const ADDRESS_SUFFIX: [u8; 12] = [0xEE; 12];
+
+/// The hash signed by `personal_sign` for `message`.
+///
+/// That is `keccak_256("\x19Ethereum Signed Message:\n" ++ len(message) ++ message)`, with the
+/// length of the message written in decimal.
+pub fn personal_sign_hash(message: &[u8]) -> [u8; 32] {
+	let mut len = Vec::new();
+	let mut remaining = message.len();
+	loop {
+		len.push(b'0' + (remaining % 10) as u8);
+		remaining /= 10;
+		if remaining == 0 {
+			break
+		}
+	}
+	len.reverse();
+
+	let mut prefixed = Vec::with_capacity(PERSONAL_SIGN_PREFIX.len() + len.len() + message.len());
+	prefixed.extend_from_slice(PERSONAL_SIGN_PREFIX);
+	prefixed.extend_from_slice(&len);
+	prefixed.extend_from_slice(message);
+	sp_io::hashing::keccak_256(&prefixed)
+}
+
+/// The Ethereum address of an uncompressed secp256k1 public key, without its `0x04` prefix.
+pub fn address_of(public: &[u8; 64]) -> H160 {
+	H160::from_slice(&sp_io::hashing::keccak_256(public)[12..])
+}
+
+/// The account an Ethereum address is mapped to.
+///
+/// This is the address followed by 12 `0xEE` bytes, so that it can be converted back with
+/// [`address_from_account`], and can't be the account of a key of another supported scheme
+/// without finding a hash collision.
+pub fn account_from_address(address: &H160) -> AccountId32 {
+	let mut account = [0u8; 32];
+	account[..20].copy_from_slice(address.as_bytes());
+	account[20..].copy_from_slice(&ADDRESS_SUFFIX);
+	account.into()
+}
+
+/// The Ethereum address an account was mapped from, if any.
+pub fn address_from_account(account: &AccountId32) -> Option<H160> {
+	let account: &[u8; 32] = account.as_ref();
+	(account[20..] == ADDRESS_SUFFIX).then(|| H160::from_slice(&account[..20]))
+}
+
+/// Recover the Ethereum address that signed `message` with `personal_sign`.
+pub fn recover_signer(signature: &ecdsa::Signature, message: &[u8]) -> Option<H160> {
+	let hash = personal_sign_hash(message);
+	sp_io::crypto::secp256k1_ecdsa_recover(signature.as_ref(), &hash)
+		.ok()
+		.map(|public| address_of(&public))
+}
+
+/// A [`MultiSignature`] that also accepts Ethereum `personal_sign` signatures.
+///
+/// The first variants are encoded the same way as the ones of [`MultiSignature`]. `P256` and
+/// `P256WebAuthn` signatures come after the `Ethereum` ones, as they are only available with the
+/// `secp256r1` feature.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, MaxEncodedLen, crate::RuntimeDebug, TypeInfo)]
+pub enum EthereumCompatibleSignature {
+	/// An Ed25519 signature.
+	Ed25519(ed25519::Signature),
+	/// An Sr25519 signature.
+	Sr25519(sr25519::Signature),
+	/// An ECDSA/SECP256k1 signature.
+	Ecdsa(ecdsa::Signature),
+	/// An ECDSA/SECP256k1 signature of the `personal_sign` hash of the message, signing for the
+	/// account its Ethereum address is mapped to.
+	Ethereum(ecdsa::Signature),
+	/// An ECDSA/SECP256r1 signature, together with the public key it was made with.
+	#[cfg(feature = "secp256r1")]
+	P256 {
+		/// The signature.
+		signature: sp_core::p256::Signature,
+		/// The public key of the signer.
+		public: sp_core::p256::Public,
+	},
+	/// A WebAuthn assertion of a SECP256r1 key, as made by passkeys.
+	#[cfg(feature = "secp256r1")]
+	P256WebAuthn(crate::webauthn::Assertion),
+}
+
+impl From<MultiSignature> for EthereumCompatibleSignature {
+	fn from(x: MultiSignature) -> Self {
+		match x {
+			MultiSignature::Ed25519(sig) => Self::Ed25519(sig),
+			MultiSignature::Sr25519(sig) => Self::Sr25519(sig),
+			MultiSignature::Ecdsa(sig) => Self::Ecdsa(sig),
+			#[cfg(feature = "secp256r1")]
+			MultiSignature::P256 { signature, public } => Self::P256 { signature, public },
+			#[cfg(feature = "secp256r1")]
+			MultiSignature::P256WebAuthn(assertion) => Self::P256WebAuthn(assertion),
+		}
+	}
+}
+
+impl Verify for EthereumCompatibleSignature {
+	type Signer = MultiSigner;
+	fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &AccountId32) -> bool {
+		match self {
+			Self::Ed25519(sig) => MultiSignature::Ed25519(sig.clone()).verify(msg, signer),
+			Self::Sr25519(sig) => MultiSignature::Sr25519(sig.clone()).verify(msg, signer),
+			Self::Ecdsa(sig) => MultiSignature::Ecdsa(sig.clone()).verify(msg, signer),
+			Self::Ethereum(sig) => recover_signer(sig, msg.get())
+				.map_or(false, |address| &account_from_address(&address) == signer),
+			#[cfg(feature = "secp256r1")]
+			Self::P256 { signature, public } =>
+				MultiSignature::P256 { signature: signature.clone(), public: *public }
+					.verify(msg, signer),
+			#[cfg(feature = "secp256r1")]
+			Self::P256WebAuthn(assertion) =>
+				MultiSignature::P256WebAuthn(assertion.clone()).verify(msg, signer),
+		}
+	}
+}
+
+/// A lookup returning the `AccountId32` of a `MultiAddress`, like
+/// [`AccountIdLookup`](crate::traits::AccountIdLookup), that also accepts Ethereum addresses.
+///
+/// `MultiAddress::Address20` is mapped with [`account_from_address`].
+pub struct EthereumAccountIdLookup<AccountIndex>(PhantomData<AccountIndex>);
+impl<AccountIndex> StaticLookup for EthereumAccountIdLookup<AccountIndex>
+where
+	AccountIndex: Codec + Clone + PartialEq + Debug,
+	MultiAddress<AccountId32, AccountIndex>: Codec + StaticTypeInfo,
+{
+	type Source = MultiAddress<AccountId32, AccountIndex>;
+	type Target = AccountId32;
+	fn lookup(x: Self::Source) -> Result<Self::Target, LookupError> {
+		match x {
+			MultiAddress::Id(i) => Ok(i),
+			MultiAddress::Address20(address) => Ok(account_from_address(&H160(address))),
+			_ => Err(LookupError),
+		}
+	}
+	fn unlookup(x: Self::Target) -> Self::Source {
+		match address_from_account(&x) {
+			Some(address) => MultiAddress::Address20(address.0),
+			None => MultiAddress::Id(x),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::{crypto::Pair, hex2array};
+
+	fn pair() -> ecdsa::Pair {
+		let mut seed = [0u8; 32];
+		seed[31] = 1;
+		ecdsa::Pair::from_seed(&seed)
+	}
+
+	// The well known address of the secret key `1`.
+	const ADDRESS: [u8; 20] = hex2array!("7e5f4552091a69125d5dfcb7b8c2659029395bdf");
+
+	#[test]
+	fn personal_sign_hash_works() {
+		let mut expected = b"\x19Ethereum Signed Message:\n11".to_vec();
+		expected.extend_from_slice(b"hello world");
+		assert_eq!(personal_sign_hash(b"hello world"), sp_io::hashing::keccak_256(&expected));
+	}
+
+	#[test]
+	fn account_mapping_roundtrips() {
+		let address = H160(ADDRESS);
+		let account = account_from_address(&address);
+		assert_eq!(address_from_account(&account), Some(address));
+		assert_eq!(address_from_account(&AccountId32::new([1; 32])), None);
+	}
+
+	#[test]
+	fn ethereum_signature_verifies_for_mapped_account() {
+		let message = b"a SCALE encoded signed payload".to_vec();
+		let signature = pair().sign_prehashed(&personal_sign_hash(&message));
+		assert_eq!(recover_signer(&signature, &message), Some(H160(ADDRESS)));
+
+		let account = account_from_address(&H160(ADDRESS));
+		let signature = EthereumCompatibleSignature::Ethereum(signature);
+		assert!(signature.verify(&message[..], &account));
+		assert!(!signature.verify(&b"another payload"[..], &account));
+		assert!(!signature.verify(&message[..], &AccountId32::new([1; 32])));
+	}
+
+	#[test]
+	fn multi_signatures_still_verify() {
+		let message = b"a SCALE encoded signed payload".to_vec();
+		let pair = sr25519::Pair::from_seed(&[1; 32]);
+		let signature: MultiSignature = pair.sign(&message).into();
+		let account = AccountId32::from(pair.public());
+
+		// encoded the same way as a `MultiSignature`.
+		let compatible = EthereumCompatibleSignature::decode(&mut &signature.encode()[..]).unwrap();
+		assert_eq!(compatible, EthereumCompatibleSignature::from(signature));
+		assert!(compatible.verify(&message[..], &account));
+	}
+
+	#[test]
+	fn ethereum_signed_extrinsic_is_checked_for_mapped_account() {
+		use crate::{
+			generic::{SignedPayload, UncheckedExtrinsic},
+			traits::{Checkable, DispatchInfoOf, Lookup, SignedExtension},
+			transaction_validity::{InvalidTransaction, TransactionValidityError},
+		};
+
+		#[derive(Debug, Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+		struct TestExtra;
+		impl SignedExtension for TestExtra {
+			const IDENTIFIER: &'static str = "TestExtra";
+			type AccountId = AccountId32;
+			type Call = ();
+			type AdditionalSigned = ();
+			type Pre = ();
+
+			fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+				Ok(())
+			}
+
+			fn pre_dispatch(
+				self,
+				who: &Self::AccountId,
+				call: &Self::Call,
+				info: &DispatchInfoOf<Self::Call>,
+				len: usize,
+			) -> Result<Self::Pre, TransactionValidityError> {
+				self.validate(who, call, info, len).map(|_| ())
+			}
+		}
+
+		struct TestContext;
+		impl Lookup for TestContext {
+			type Source = MultiAddress<AccountId32, u32>;
+			type Target = AccountId32;
+			fn lookup(&self, x: Self::Source) -> Result<Self::Target, LookupError> {
+				EthereumAccountIdLookup::<u32>::lookup(x)
+			}
+		}
+
+		type Ex = UncheckedExtrinsic<
+			MultiAddress<AccountId32, u32>,
+			Vec<u8>,
+			EthereumCompatibleSignature,
+			TestExtra,
+		>;
+
+		// the wallet signs the SCALE encoded signed payload with `personal_sign`.
+		let call = b"a call".to_vec();
+		let signature = SignedPayload::new(call.clone(), TestExtra)
+			.unwrap()
+			.using_encoded(|payload| pair().sign_prehashed(&personal_sign_hash(payload)));
+		let signature = EthereumCompatibleSignature::Ethereum(signature);
+
+		let xt = Ex::new_signed(
+			call.clone(),
+			MultiAddress::Address20(ADDRESS),
+			signature.clone(),
+			TestExtra,
+		);
+		let xt = Ex::decode(&mut &xt.encode()[..]).unwrap();
+		let checked = xt.check(&TestContext).unwrap();
+		assert_eq!(checked.signed, Some((account_from_address(&H160(ADDRESS)), TestExtra)));
+		assert_eq!(checked.function, call);
+
+		// the signature does not cover another call.
+		let xt = Ex::new_signed(
+			b"another call".to_vec(),
+			MultiAddress::Address20(ADDRESS),
+			signature,
+			TestExtra,
+		);
+		assert_eq!(xt.check(&TestContext), Err(InvalidTransaction::BadProof.into()));
+	}
+
+	#[test]
+	fn lookup_accepts_ethereum_addresses() {
+		type Lookup = EthereumAccountIdLookup<u32>;
+		let account = account_from_address(&H160(ADDRESS));
+
+		assert_eq!(Lookup::lookup(MultiAddress::Address20(ADDRESS)).unwrap(), account);
+		assert_eq!(Lookup::lookup(MultiAddress::Id(account.clone())).unwrap(), account);
+		assert!(Lookup::lookup(MultiAddress::Index(1)).is_err());
+
+		assert_eq!(Lookup::unlookup(account), MultiAddress::Address20(ADDRESS));
+		let other = AccountId32::new([1; 32]);
+		assert_eq!(Lookup::unlookup(other.clone()), MultiAddress::Id(other));
+	}
+}
@@ -199,6 +199,28 @@ where
 			None => CheckedExtrinsic { signed: None, function: self.function },
 		})
 	}
+
+	#[cfg(feature = "batch-verification")]
+	fn verify_batch(items: &[Self], lookup: &Lookup) {
+		// Items whose signer or payload can't be determined up front are left to `check`.
+		let batch = items
+			.iter()
+			.filter_map(|xt| {
+				let (signed, signature, extra) = xt.signature.as_ref()?;
+				let signed = lookup.lookup(signed.clone()).ok()?;
+				let raw_payload = SignedPayload::new(xt.function.clone(), extra.clone()).ok()?;
+				Some((signature, raw_payload.using_encoded(|payload| payload.to_vec()), signed))
+			})
+			.collect::<Vec<_>>();
+		let batch = batch
+			.iter()
+			.map(|(signature, payload, signed)| (*signature, &payload[..], signed))
+			.collect::<Vec<_>>();
+
+		// The outcome is deliberately ignored, `check` still verifies every signature and
+		// reports invalid ones individually.
+		let _ = Signature::verify_batch(&batch);
+	}
 }
 
 impl<Address, Call, Signature, Extra> ExtrinsicMetadata
@@ -537,6 +559,33 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn verify_batch_does_not_change_check_outcome() {
+		let good = Ex::new_signed(
+			vec![0u8; 0],
+			TEST_ACCOUNT,
+			TestSig(TEST_ACCOUNT, (vec![0u8; 0], TestExtra).encode()),
+			TestExtra,
+		);
+		let bad = Ex::new_signed(
+			vec![0u8; 0],
+			TEST_ACCOUNT,
+			TestSig(TEST_ACCOUNT, vec![0u8; 0]),
+			TestExtra,
+		);
+		let unsigned = Ex::new_unsigned(vec![0u8; 0]);
+		let items = vec![good.clone(), bad.clone(), unsigned.clone()];
+
+		<Ex as Checkable<TestContext>>::verify_batch(&items, &Default::default());
+
+		assert!(<Ex as Checkable<TestContext>>::check(good, &Default::default()).is_ok());
+		assert_eq!(
+			<Ex as Checkable<TestContext>>::check(bad, &Default::default()),
+			Err(InvalidTransaction::BadProof.into()),
+		);
+		assert!(<Ex as Checkable<TestContext>>::check(unsigned, &Default::default()).is_ok());
+	}
+
 	#[test]
 	fn encoding_matches_vec() {
 		let ex = Ex::new_unsigned(vec![0u8; 0]);
@@ -67,6 +67,8 @@ pub use sp_core::storage::StateVersion;
 #[cfg(feature = "std")]
 pub use sp_core::storage::{Storage, StorageChild};
 
+#[cfg(feature = "secp256r1")]
+use sp_core::p256;
 use sp_core::{
 	crypto::{self, ByteArray, FromEntropy},
 	ecdsa, ed25519,
@@ -81,6 +83,7 @@ use scale_info::TypeInfo;
 use sp_std::alloc::format;
 
 pub mod curve;
+pub mod ethereum;
 pub mod generic;
 pub mod legacy;
 mod multiaddress;
@@ -91,6 +94,8 @@ mod runtime_string;
 pub mod testing;
 pub mod traits;
 pub mod transaction_validity;
+#[cfg(feature = "secp256r1")]
+pub mod webauthn;
 
 pub use crate::runtime_string::*;
 
@@ -274,6 +279,23 @@ pub enum MultiSignature {
 	Sr25519(sr25519::Signature),
 	/// An ECDSA/SECP256k1 signature.
 	Ecdsa(ecdsa::Signature),
+	/// An ECDSA/SECP256r1 signature, together with the public key it was made with.
+	///
+	/// The public key can't be recovered from the signature, so it's carried along to be checked
+	/// against the account.
+	#[cfg(feature = "secp256r1")]
+	P256 {
+		/// The signature.
+		signature: p256::Signature,
+		/// The public key of the signer.
+		public: p256::Public,
+	},
+	/// A WebAuthn assertion of a SECP256r1 key, as made by passkeys.
+	///
+	/// The authenticator signs its own data and the client data, which contains the challenge of
+	/// the message. See [`webauthn`].
+	#[cfg(feature = "secp256r1")]
+	P256WebAuthn(webauthn::Assertion),
 }
 
 impl From<ed25519::Signature> for MultiSignature {
@@ -327,6 +349,32 @@ impl TryFrom<MultiSignature> for ecdsa::Signature {
 	}
 }
 
+#[cfg(feature = "secp256r1")]
+impl From<(p256::Signature, p256::Public)> for MultiSignature {
+	fn from((signature, public): (p256::Signature, p256::Public)) -> Self {
+		Self::P256 { signature, public }
+	}
+}
+
+#[cfg(feature = "secp256r1")]
+impl From<webauthn::Assertion> for MultiSignature {
+	fn from(x: webauthn::Assertion) -> Self {
+		Self::P256WebAuthn(x)
+	}
+}
+
+#[cfg(feature = "secp256r1")]
+impl TryFrom<MultiSignature> for p256::Signature {
+	type Error = ();
+	fn try_from(m: MultiSignature) -> Result<Self, Self::Error> {
+		if let MultiSignature::P256 { signature, .. } = m {
+			Ok(signature)
+		} else {
+			Err(())
+		}
+	}
+}
+
 /// Public key for any known crypto algorithm.
 #[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -337,6 +385,9 @@ pub enum MultiSigner {
 	Sr25519(sr25519::Public),
 	/// An SECP256k1/ECDSA identity (actually, the Blake2 hash of the compressed pub key).
 	Ecdsa(ecdsa::Public),
+	/// An SECP256r1/ECDSA identity (actually, the Blake2 hash of the compressed pub key).
+	#[cfg(feature = "secp256r1")]
+	P256(p256::Public),
 }
 
 impl FromEntropy for MultiSigner {
@@ -363,6 +414,8 @@ impl AsRef<[u8]> for MultiSigner {
 			Self::Ed25519(ref who) => who.as_ref(),
 			Self::Sr25519(ref who) => who.as_ref(),
 			Self::Ecdsa(ref who) => who.as_ref(),
+			#[cfg(feature = "secp256r1")]
+			Self::P256(ref who) => who.as_ref(),
 		}
 	}
 }
@@ -374,6 +427,8 @@ impl traits::IdentifyAccount for MultiSigner {
 			Self::Ed25519(who) => <[u8; 32]>::from(who).into(),
 			Self::Sr25519(who) => <[u8; 32]>::from(who).into(),
 			Self::Ecdsa(who) => sp_io::hashing::blake2_256(who.as_ref()).into(),
+			#[cfg(feature = "secp256r1")]
+			Self::P256(who) => sp_io::hashing::blake2_256(who.as_ref()).into(),
 		}
 	}
 }
@@ -429,6 +484,25 @@ impl TryFrom<MultiSigner> for ecdsa::Public {
 	}
 }
 
+#[cfg(feature = "secp256r1")]
+impl From<p256::Public> for MultiSigner {
+	fn from(x: p256::Public) -> Self {
+		Self::P256(x)
+	}
+}
+
+#[cfg(feature = "secp256r1")]
+impl TryFrom<MultiSigner> for p256::Public {
+	type Error = ();
+	fn try_from(m: MultiSigner) -> Result<Self, Self::Error> {
+		if let MultiSigner::P256(x) = m {
+			Ok(x)
+		} else {
+			Err(())
+		}
+	}
+}
+
 #[cfg(feature = "std")]
 impl std::fmt::Display for MultiSigner {
 	fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -436,6 +510,8 @@ impl std::fmt::Display for MultiSigner {
 			Self::Ed25519(ref who) => write!(fmt, "ed25519: {}", who),
 			Self::Sr25519(ref who) => write!(fmt, "sr25519: {}", who),
 			Self::Ecdsa(ref who) => write!(fmt, "ecdsa: {}", who),
+			#[cfg(feature = "secp256r1")]
+			Self::P256(ref who) => write!(fmt, "p256: {}", who),
 		}
 	}
 }
@@ -461,8 +537,54 @@ impl Verify for MultiSignature {
 					_ => false,
 				}
 			},
+			#[cfg(feature = "secp256r1")]
+			(Self::P256 { ref signature, ref public }, who) =>
+				&sp_io::hashing::blake2_256(public.as_ref()) == <dyn AsRef<[u8; 32]>>::as_ref(who) &&
+					sp_io::crypto::p256_verify(signature, msg.get(), public),
+			#[cfg(feature = "secp256r1")]
+			(Self::P256WebAuthn(ref assertion), who) =>
+				&sp_io::hashing::blake2_256(assertion.public.as_ref()) ==
+					<dyn AsRef<[u8; 32]>>::as_ref(who) &&
+					assertion.verify(msg.get()),
 		}
 	}
+
+	#[cfg(feature = "batch-verification")]
+	fn verify_batch(batch: &[(&Self, &[u8], &AccountId32)]) -> bool {
+		// `ed25519` and `sr25519` signatures are verified in a batch each, `ecdsa` and `p256` ones
+		// have no batch verification and are checked one by one.
+		let mut ed25519_batch = Vec::new();
+		let mut sr25519_batch = Vec::new();
+		for (sig, msg, who) in batch {
+			let raw: &[u8; 32] = (*who).as_ref();
+			match sig {
+				Self::Ed25519(sig) =>
+					ed25519_batch.push((sig, *msg, ed25519::Public::from_raw(*raw))),
+				Self::Sr25519(sig) =>
+					sr25519_batch.push((sig, *msg, sr25519::Public::from_raw(*raw))),
+				Self::Ecdsa(_) =>
+					if !sig.verify(*msg, who) {
+						return false
+					},
+				#[cfg(feature = "secp256r1")]
+				Self::P256 { .. } | Self::P256WebAuthn(_) =>
+					if !sig.verify(*msg, who) {
+						return false
+					},
+			}
+		}
+
+		let ed25519_batch = ed25519_batch
+			.iter()
+			.map(|(sig, msg, signer)| (*sig, *msg, signer))
+			.collect::<Vec<_>>();
+		let sr25519_batch = sr25519_batch
+			.iter()
+			.map(|(sig, msg, signer)| (*sig, *msg, signer))
+			.collect::<Vec<_>>();
+		(ed25519_batch.is_empty() || ed25519::Signature::verify_batch(&ed25519_batch)) &&
+			(sr25519_batch.is_empty() || sr25519::Signature::verify_batch(&sr25519_batch))
+	}
 }
 
 /// Signature verify that can work with any known signature types..
@@ -1087,6 +1209,56 @@ mod tests {
 		assert!(multi_sig.verify(msg, &multi_signer.into_account()));
 	}
 
+	#[test]
+	#[cfg(feature = "secp256r1")]
+	fn multi_signature_p256_verify_works() {
+		let msg = &b"test-message"[..];
+		let (pair, _) = p256::Pair::generate();
+		let (other, _) = p256::Pair::generate();
+
+		let multi_sig = MultiSignature::from((pair.sign(msg), pair.public()));
+		let multi_signer = MultiSigner::from(pair.public());
+		assert!(multi_sig.verify(msg, &multi_signer.into_account()));
+		assert!(!multi_sig
+			.verify(&b"other-message"[..], &MultiSigner::from(pair.public()).into_account()));
+
+		// The public key must match the account.
+		assert!(!multi_sig.verify(msg, &MultiSigner::from(other.public()).into_account()));
+		let multi_sig = MultiSignature::from((pair.sign(msg), other.public()));
+		assert!(!multi_sig.verify(msg, &MultiSigner::from(other.public()).into_account()));
+	}
+
+	#[test]
+	fn multi_signature_verify_batch_works() {
+		let msg = &b"test-message"[..];
+		let sr = sr25519::Pair::from_string("//Alice", None).unwrap();
+		let ed = ed25519::Pair::from_string("//Bob", None).unwrap();
+		let (ec, _) = ecdsa::Pair::generate();
+
+		let sr_sig = MultiSignature::from(sr.sign(msg));
+		let ed_sig = MultiSignature::from(ed.sign(msg));
+		let ec_sig = MultiSignature::from(ec.sign(msg));
+		let sr_who = MultiSigner::from(sr.public()).into_account();
+		let ed_who = MultiSigner::from(ed.public()).into_account();
+		let ec_who = MultiSigner::from(ec.public()).into_account();
+
+		TestExternalities::default().execute_with(|| {
+			assert!(MultiSignature::verify_batch(&[
+				(&sr_sig, msg, &sr_who),
+				(&ed_sig, msg, &ed_who),
+				(&ec_sig, msg, &ec_who),
+			]));
+			assert!(!MultiSignature::verify_batch(&[
+				(&sr_sig, msg, &sr_who),
+				(&ed_sig, msg, &sr_who),
+			]));
+			assert!(!MultiSignature::verify_batch(&[
+				(&sr_sig, msg, &sr_who),
+				(&ec_sig, msg, &ed_who),
+			]));
+		});
+	}
+
 	#[test]
 	fn execute_and_generate_proof_works() {
 		use codec::Encode;
@@ -154,6 +154,14 @@ impl OpaqueKeys for UintAuthorityId {
 	fn get<T: Decode>(&self, _: KeyTypeId) -> Option<T> {
 		self.using_encoded(|mut x| T::decode(&mut x)).ok()
 	}
+
+	fn ownership_proof_is_valid_for(&self, owner: &[u8], mut proof: &[u8]) -> bool {
+		let message = traits::session_keys_ownership_message(owner, self);
+		TestSignature::decode(&mut proof).map_or(false, |signature| {
+			proof.is_empty() &&
+				sp_application_crypto::RuntimeAppPublic::verify(self, &message, &signature)
+		})
+	}
 }
 
 impl traits::IdentifyAccount for UintAuthorityId {
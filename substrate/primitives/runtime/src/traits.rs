@@ -108,6 +108,19 @@ pub trait Verify {
 		msg: L,
 		signer: &<Self::Signer as IdentifyAccount>::AccountId,
 	) -> bool;
+
+	/// Verify a batch of signatures, each over its own message and signer.
+	///
+	/// Return `true` if all signatures are valid. With the `batch-verification` feature,
+	/// signature types that support it verify the whole batch in one go, which is considerably
+	/// cheaper than verifying the signatures one by one. The default implementation falls back to
+	/// verifying each signature on its own.
+	fn verify_batch(batch: &[(&Self, &[u8], &<Self::Signer as IdentifyAccount>::AccountId)]) -> bool
+	where
+		Self: Sized,
+	{
+		batch.iter().all(|(sig, msg, signer)| sig.verify(*msg, signer))
+	}
 }
 
 impl Verify for sp_core::ed25519::Signature {
@@ -116,6 +129,16 @@ impl Verify for sp_core::ed25519::Signature {
 	fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &sp_core::ed25519::Public) -> bool {
 		sp_io::crypto::ed25519_verify(self, msg.get(), signer)
 	}
+
+	#[cfg(feature = "batch-verification")]
+	fn verify_batch(batch: &[(&Self, &[u8], &sp_core::ed25519::Public)]) -> bool {
+		sp_io::crypto::ed25519_verify_batch(
+			batch
+				.iter()
+				.map(|(sig, msg, signer)| ((*sig).clone(), msg.to_vec(), **signer))
+				.collect(),
+		)
+	}
 }
 
 impl Verify for sp_core::sr25519::Signature {
@@ -124,6 +147,16 @@ impl Verify for sp_core::sr25519::Signature {
 	fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &sp_core::sr25519::Public) -> bool {
 		sp_io::crypto::sr25519_verify(self, msg.get(), signer)
 	}
+
+	#[cfg(feature = "batch-verification")]
+	fn verify_batch(batch: &[(&Self, &[u8], &sp_core::sr25519::Public)]) -> bool {
+		sp_io::crypto::sr25519_verify_batch(
+			batch
+				.iter()
+				.map(|(sig, msg, signer)| ((*sig).clone(), msg.to_vec(), **signer))
+				.collect(),
+		)
+	}
 }
 
 impl Verify for sp_core::ecdsa::Signature {
@@ -1374,6 +1407,14 @@ pub trait Checkable<Context>: Sized {
 		self,
 		c: &Context,
 	) -> Result<Self::Checked, TransactionValidityError>;
+
+	/// Verify the signatures of a batch of items ahead of checking them one by one.
+	///
+	/// Implementations may verify all signatures at once, which makes the subsequent calls to
+	/// [`Checkable::check`] cheaper for the signatures that were found valid. This never changes
+	/// the outcome of `check`. The default implementation does nothing, as does the one of
+	/// `UncheckedExtrinsic` without the `batch-verification` feature.
+	fn verify_batch(_items: &[Self], _c: &Context) {}
 }
 
 /// A "checkable" piece of information, used by the standard Substrate Executive in order to
@@ -1784,9 +1825,27 @@ pub trait OpaqueKeys: Clone {
 		T::decode(&mut self.get_raw(i)).ok()
 	}
 	/// Verify a proof of ownership for the keys.
+	///
+	/// This doesn't bind the proof to anyone. Use [`Self::ownership_proof_is_valid_for`] to verify
+	/// that the keys are owned by a given account.
 	fn ownership_proof_is_valid(&self, _proof: &[u8]) -> bool {
 		true
 	}
+	/// Verify a proof that `owner` holds the private keys of all the keys.
+	///
+	/// The proof is expected to be the concatenated SCALE encoded signatures of
+	/// [`session_keys_ownership_message`], one by each of the keys, in the order of
+	/// [`Self::key_ids`].
+	///
+	/// The default implementation rejects every proof.
+	fn ownership_proof_is_valid_for(&self, _owner: &[u8], _proof: &[u8]) -> bool {
+		false
+	}
+}
+
+/// The message signed by each of the session `keys` to prove their ownership by `owner`.
+pub fn session_keys_ownership_message<K: Encode>(owner: &[u8], keys: &K) -> Vec<u8> {
+	(b"POP_", owner, keys).encode()
 }
 
 /// Input that adds infinite number of zero after wrapped input.
@@ -2037,6 +2096,42 @@ macro_rules! impl_opaque_keys_inner {
 				keys
 			}
 
+			/// Create a proof of the ownership of these keys by `owner`.
+			///
+			/// Each key signs the `session_keys_ownership_message` with its private key, which is
+			/// requested from the keystore.
+			///
+			/// Returns `None` if any of the private keys could not be found.
+			pub fn create_ownership_proof(
+				&self,
+				owner: &[u8],
+			) -> Option<$crate::sp_std::vec::Vec<u8>> {
+				let message = $crate::traits::session_keys_ownership_message(owner, self);
+				let mut proof = $crate::sp_std::vec::Vec::new();
+				$(
+					let signature = $crate::RuntimeAppPublic::sign(&self.$field, &message)?;
+					$crate::codec::Encode::encode_to(&signature, &mut proof);
+				)*
+				Some(proof)
+			}
+
+			/// Generate a set of keys like [`Self::generate`], and a proof of their ownership by
+			/// `owner` (see [`Self::create_ownership_proof`]).
+			///
+			/// Returns the concatenated SCALE encoded public keys and the proof.
+			pub fn generate_with_ownership_proof(
+				owner: &[u8],
+				seed: Option<$crate::sp_std::vec::Vec<u8>>,
+			) -> ($crate::sp_std::vec::Vec<u8>, $crate::sp_std::vec::Vec<u8>) {
+				let encoded = Self::generate(seed);
+				let keys = <Self as $crate::codec::Decode>::decode(&mut &encoded[..])
+					.expect("the keys were just encoded; qed");
+				let proof = keys
+					.create_ownership_proof(owner)
+					.expect("the private keys were just stored in the keystore; qed");
+				(encoded, proof)
+			}
+
 			/// Decode `Self` from the given `encoded` slice and convert `Self` into the raw public
 			/// keys (see [`Self::into_raw_public_keys`]).
 			///
@@ -2078,6 +2173,25 @@ macro_rules! impl_opaque_keys_inner {
 					_ => &[],
 				}
 			}
+
+			fn ownership_proof_is_valid_for(&self, owner: &[u8], mut proof: &[u8]) -> bool {
+				let message = $crate::traits::session_keys_ownership_message(owner, self);
+				$(
+					let Ok(signature) = <
+						<
+							<
+								$type as $crate::BoundToRuntimeAppPublic
+							>::Public as $crate::RuntimeAppPublic
+						>::Signature as $crate::codec::Decode
+					>::decode(&mut proof) else {
+						return false
+					};
+					if !$crate::RuntimeAppPublic::verify(&self.$field, &message, &signature) {
+						return false
+					}
+				)*
+				proof.is_empty()
+			}
 		}
 	};
 }
@@ -0,0 +1,236 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! WebAuthn assertions, as made by passkeys.
+//!
+//! A WebAuthn authenticator doesn't sign the message it is given. It signs
+//! `authenticatorData || sha256(clientDataJSON)`, where the `clientDataJSON` is assembled by the
+//! browser and contains the base64url encoded challenge. An [`Assertion`] carries both, so that
+//! the signature can be checked, and the challenge is checked to be the [`challenge`] of the
+//! signed message.
+//!
+//! The relying party ID hash of the authenticator data and the origin of the client data are not
+//! checked, as the runtime has no notion of either. Any passkey of the account's key may be used.
+
+use crate::{BoundedVec, RuntimeDebug};
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::{p256, ConstU32};
+use sp_std::prelude::*;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The maximum length of the authenticator data of an [`Assertion`].
+pub const MAX_AUTHENTICATOR_DATA_LEN: u32 = 256;
+
+/// The maximum length of the client data JSON of an [`Assertion`].
+pub const MAX_CLIENT_DATA_JSON_LEN: u32 = 1024;
+
+/// The length of the authenticator data without any extensions: the relying party ID hash, the
+/// flags and the signature counter.
+const MIN_AUTHENTICATOR_DATA_LEN: usize = 37;
+
+/// The offset of the flags in the authenticator data.
+const FLAGS_OFFSET: usize = 32;
+
+/// The "user present" flag.
+const FLAG_USER_PRESENT: u8 = 0x01;
+
+/// The type of the client data of an assertion, as opposed to the one of a new credential.
+const CLIENT_DATA_TYPE: &[u8] = b"\"type\":\"webauthn.get\"";
+
+/// The start of the challenge in the client data.
+const CLIENT_DATA_CHALLENGE: &[u8] = b"\"challenge\":\"";
+
+/// A WebAuthn assertion of a secp256r1 (NIST P-256) key.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct Assertion {
+	/// The signature of `authenticator_data || sha256(client_data_json)`.
+	pub signature: p256::Signature,
+	/// The public key of the signer.
+	pub public: p256::Public,
+	/// The authenticator data, as returned by the authenticator.
+	pub authenticator_data: BoundedVec<u8, ConstU32<MAX_AUTHENTICATOR_DATA_LEN>>,
+	/// The client data JSON, as serialized by the browser.
+	pub client_data_json: BoundedVec<u8, ConstU32<MAX_CLIENT_DATA_JSON_LEN>>,
+}
+
+impl Assertion {
+	/// Verify that this assertion was made by [`Self::public`] for the `message`.
+	///
+	/// The user must have been present, and the client data must be the one of an assertion with
+	/// the [`challenge`] of the `message`.
+	pub fn verify(&self, message: &[u8]) -> bool {
+		if self.authenticator_data.len() < MIN_AUTHENTICATOR_DATA_LEN ||
+			self.authenticator_data[FLAGS_OFFSET] & FLAG_USER_PRESENT == 0
+		{
+			return false
+		}
+
+		let mut expected_challenge = CLIENT_DATA_CHALLENGE.to_vec();
+		base64url_encode(&challenge(message), &mut expected_challenge);
+		expected_challenge.push(b'"');
+		if !contains(&self.client_data_json, CLIENT_DATA_TYPE) ||
+			!contains(&self.client_data_json, &expected_challenge)
+		{
+			return false
+		}
+
+		let mut signed = self.authenticator_data.to_vec();
+		signed.extend_from_slice(&sp_io::hashing::sha2_256(&self.client_data_json));
+		sp_io::crypto::p256_verify(&self.signature, &signed, &self.public)
+	}
+}
+
+/// The challenge that has to be signed with WebAuthn for the `message`.
+///
+/// This is the `blake2_256` hash of the message, so that it has the same length for all messages.
+pub fn challenge(message: &[u8]) -> [u8; 32] {
+	sp_io::hashing::blake2_256(message)
+}
+
+/// Returns true if `needle` is a part of `haystack`.
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+	haystack.windows(needle.len()).any(|window| window == needle)
+}
+
+/// Append the base64url encoding of `data`, without padding, to `out`.
+fn base64url_encode(data: &[u8], out: &mut Vec<u8>) {
+	const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+	for chunk in data.chunks(3) {
+		let bytes = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+		let bits = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+		(0..=chunk.len())
+			.for_each(|i| out.push(ALPHABET[((bits >> (18 - 6 * i)) & 0x3f) as usize]));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		traits::{IdentifyAccount, Verify},
+		MultiSignature, MultiSigner,
+	};
+	use sp_core::Pair;
+
+	/// An assertion of the given `client_data_json`, signed like an authenticator would.
+	fn sign_assertion(pair: &p256::Pair, flags: u8, client_data_json: &[u8]) -> Assertion {
+		let mut authenticator_data = vec![0x49; 32];
+		authenticator_data.push(flags);
+		authenticator_data.extend_from_slice(&1u32.to_be_bytes());
+
+		let mut signed = authenticator_data.clone();
+		signed.extend_from_slice(&sp_io::hashing::sha2_256(client_data_json));
+		Assertion {
+			signature: pair.sign(&signed),
+			public: pair.public(),
+			authenticator_data: authenticator_data.try_into().unwrap(),
+			client_data_json: client_data_json.to_vec().try_into().unwrap(),
+		}
+	}
+
+	fn client_data_json(ty: &str, message: &[u8]) -> Vec<u8> {
+		let mut challenge = Vec::new();
+		base64url_encode(&super::challenge(message), &mut challenge);
+		format!(
+			r#"{{"type":"{ty}","challenge":"{}","origin":"https://example.com","crossOrigin":false}}"#,
+			String::from_utf8(challenge).unwrap(),
+		)
+		.into_bytes()
+	}
+
+	#[test]
+	fn base64url_encode_works() {
+		let encode = |data: &[u8]| {
+			let mut out = Vec::new();
+			base64url_encode(data, &mut out);
+			String::from_utf8(out).unwrap()
+		};
+		assert_eq!(encode(b""), "");
+		assert_eq!(encode(b"f"), "Zg");
+		assert_eq!(encode(b"fo"), "Zm8");
+		assert_eq!(encode(b"foo"), "Zm9v");
+		assert_eq!(encode(b"foob"), "Zm9vYg");
+		assert_eq!(encode(&[0xfb, 0xff]), "-_8");
+	}
+
+	#[test]
+	fn assertion_verify_works() {
+		let (pair, _) = p256::Pair::generate();
+		let message = &b"test-message"[..];
+
+		let assertion =
+			sign_assertion(&pair, FLAG_USER_PRESENT, &client_data_json("webauthn.get", message));
+		assert!(assertion.verify(message));
+		assert!(!assertion.verify(b"other-message"));
+
+		// The signature is over the authenticator data and the client data.
+		let mut tampered = assertion.clone();
+		let mut authenticator_data = tampered.authenticator_data.into_inner();
+		authenticator_data[0] ^= 1;
+		tampered.authenticator_data = authenticator_data.try_into().unwrap();
+		assert!(!tampered.verify(message));
+		let mut tampered = assertion;
+		tampered.client_data_json =
+			client_data_json("webauthn.get", b"other-message").try_into().unwrap();
+		assert!(!tampered.verify(message));
+	}
+
+	#[test]
+	fn assertion_verify_checks_client_data_and_flags() {
+		let (pair, _) = p256::Pair::generate();
+		let message = &b"test-message"[..];
+
+		// The signature of the message itself is not an assertion.
+		let mut assertion =
+			sign_assertion(&pair, FLAG_USER_PRESENT, &client_data_json("webauthn.get", message));
+		assertion.signature = pair.sign(message);
+		assert!(!assertion.verify(message));
+
+		// Client data of a new credential.
+		assert!(!sign_assertion(
+			&pair,
+			FLAG_USER_PRESENT,
+			&client_data_json("webauthn.create", message)
+		)
+		.verify(message));
+
+		// The user was not present.
+		assert!(
+			!sign_assertion(&pair, 0, &client_data_json("webauthn.get", message)).verify(message)
+		);
+	}
+
+	#[test]
+	fn multi_signature_web_authn_verify_works() {
+		let (pair, _) = p256::Pair::generate();
+		let (other, _) = p256::Pair::generate();
+		let message = &b"test-message"[..];
+
+		let signature = MultiSignature::from(sign_assertion(
+			&pair,
+			FLAG_USER_PRESENT,
+			&client_data_json("webauthn.get", message),
+		));
+		assert!(signature.verify(message, &MultiSigner::from(pair.public()).into_account()));
+		assert!(!signature.verify(message, &MultiSigner::from(other.public()).into_account()));
+	}
+}
@@ -15,9 +15,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use codec::{Decode, Encode};
 pub use sp_core::crypto::KeyTypeId;
+use sp_core::RuntimeDebug;
 use sp_std::prelude::*;
 
+/// Session keys generated together with a proof of their ownership.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Default, RuntimeDebug, scale_info::TypeInfo)]
+pub struct OpaqueGeneratedSessionKeys {
+	/// The concatenated SCALE encoded public keys.
+	pub keys: Vec<u8>,
+	/// The proof that the owner holds the private keys of [`Self::keys`].
+	pub proof: Vec<u8>,
+}
+
 sp_api::decl_runtime_apis! {
 	/// Session keys runtime api.
 	pub trait SessionKeys {
@@ -30,6 +41,17 @@ sp_api::decl_runtime_apis! {
 		/// Returns the concatenated SCALE encoded public keys.
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8>;
 
+		/// Generate a set of session keys like [`Self::generate_session_keys`], together with a
+		/// proof that `owner` holds their private keys.
+		///
+		/// `owner` is the SCALE encoded account that is going to submit the keys to `set_keys` of
+		/// `pallet-session`.
+		#[api_version(2)]
+		fn generate_session_keys_with_ownership_proof(
+			owner: Vec<u8>,
+			seed: Option<Vec<u8>>,
+		) -> OpaqueGeneratedSessionKeys;
+
 		/// Decode the given public session keys.
 		///
 		/// Returns the list of public raw public keys + key type.
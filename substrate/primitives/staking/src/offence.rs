@@ -23,7 +23,7 @@ use sp_core::Get;
 use sp_runtime::{transaction_validity::TransactionValidityError, DispatchError, Perbill};
 use sp_std::vec::Vec;
 
-use crate::SessionIndex;
+use crate::{EraIndex, SessionIndex};
 
 /// The kind of an offence, is a byte string representing some kind identifier
 /// e.g. `b"im-online:offlin"`, `b"babe:equivocatio"`
@@ -210,6 +210,49 @@ pub struct OffenceDetails<Reporter, Offender> {
 	pub reporters: Vec<Reporter>,
 }
 
+/// What happened to the slash of an offence, as far as it can be told from the staking state.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, sp_runtime::RuntimeDebug, scale_info::TypeInfo)]
+pub enum SlashDisposition<Balance> {
+	/// No slash has been recorded for the offender in the era of the offence, e.g. because the
+	/// offender is invulnerable or was already slashed more for another offence in that era.
+	NotSlashed,
+	/// The slash is deferred and will be applied at the start of era `apply_at`, unless it is
+	/// cancelled by governance before.
+	Deferred {
+		/// The era in which the slash is applied.
+		apply_at: EraIndex,
+		/// The slash of the offender's own stake.
+		own: Balance,
+		/// The slash of the nominators' stake.
+		others: Balance,
+	},
+	/// The slash is no longer pending. It has been applied, unless governance has cancelled it
+	/// while it was deferred.
+	Applied {
+		/// The slash of the offender's own stake.
+		own: Balance,
+	},
+}
+
+/// A reported offence, together with its consequences for the offender.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, sp_runtime::RuntimeDebug, scale_info::TypeInfo)]
+pub struct OffenceRecord<AccountId, Balance> {
+	/// The offending validator.
+	pub offender: AccountId,
+	/// The kind of the offence.
+	pub kind: Kind,
+	/// The session in which the offence happened.
+	pub session_index: SessionIndex,
+	/// The era in which the offence happened.
+	pub era: EraIndex,
+	/// The highest slash fraction recorded for the offender in `era`.
+	pub slash_fraction: Perbill,
+	/// What happened to the slash.
+	pub disposition: SlashDisposition<Balance>,
+	/// Whether the offender is currently disabled.
+	pub disabled: bool,
+}
+
 /// An abstract system to publish, check and process offence evidences.
 ///
 /// Implementation details are left opaque and we don't assume any specific usage
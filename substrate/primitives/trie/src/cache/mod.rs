@@ -115,13 +115,44 @@ impl CacheSize {
 	}
 }
 
+/// The limits of the [`LocalTrieCache`]s created by a [`SharedTrieCache`].
+///
+/// One local cache is used per state instance, e.g. per runtime call, so these limits bound the
+/// memory used on top of the shared cache by every state instance in use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalCacheLimits {
+	/// The maximum size of the memory allocated on the heap by the local node cache, in bytes.
+	pub max_node_heap_size: usize,
+	/// The maximum size of the memory allocated on the heap by the local value cache, in bytes.
+	///
+	/// The value cache only holds weak references to the values found in the nodes, so this
+	/// should be smaller than [`Self::max_node_heap_size`].
+	pub max_value_heap_size: usize,
+}
+
+impl Default for LocalCacheLimits {
+	fn default() -> Self {
+		Self {
+			max_node_heap_size: LOCAL_NODE_CACHE_MAX_HEAP_SIZE,
+			max_value_heap_size: LOCAL_VALUE_CACHE_MAX_HEAP_SIZE,
+		}
+	}
+}
+
 /// A limiter for the local node cache. This makes sure the local cache doesn't grow too big.
-#[derive(Default)]
 pub struct LocalNodeCacheLimiter {
 	/// The current size (in bytes) of data allocated by this cache on the heap.
 	///
 	/// This doesn't include the size of the map itself.
 	current_heap_size: usize,
+	/// The maximum size (in bytes) of data allocated by this cache on the heap.
+	max_heap_size: usize,
+}
+
+impl LocalNodeCacheLimiter {
+	fn new(max_heap_size: usize) -> Self {
+		Self { current_heap_size: 0, max_heap_size }
+	}
 }
 
 impl<H> schnellru::Limiter<H, NodeCached<H>> for LocalNodeCacheLimiter
@@ -139,7 +170,7 @@ where
 			return false
 		}
 
-		self.current_heap_size > LOCAL_NODE_CACHE_MAX_HEAP_SIZE
+		self.current_heap_size > self.max_heap_size
 	}
 
 	#[inline]
@@ -185,12 +216,19 @@ where
 }
 
 /// A limiter for the local value cache. This makes sure the local cache doesn't grow too big.
-#[derive(Default)]
 pub struct LocalValueCacheLimiter {
 	/// The current size (in bytes) of data allocated by this cache on the heap.
 	///
 	/// This doesn't include the size of the map itself.
 	current_heap_size: usize,
+	/// The maximum size (in bytes) of data allocated by this cache on the heap.
+	max_heap_size: usize,
+}
+
+impl LocalValueCacheLimiter {
+	fn new(max_heap_size: usize) -> Self {
+		Self { current_heap_size: 0, max_heap_size }
+	}
 }
 
 impl<H> schnellru::Limiter<ValueCacheKey<H>, CachedValue<H>> for LocalValueCacheLimiter
@@ -208,7 +246,7 @@ where
 			return false
 		}
 
-		self.current_heap_size > LOCAL_VALUE_CACHE_MAX_HEAP_SIZE
+		self.current_heap_size > self.max_heap_size
 	}
 
 	#[inline]
@@ -260,6 +298,30 @@ struct HitStats {
 	local_fetch_attempts: AtomicU64,
 }
 
+impl HitStats {
+	/// Adds the stats of `self` to `other`.
+	fn add_to(&self, other: &HitStats) {
+		for (from, to) in [
+			(&self.shared_hits, &other.shared_hits),
+			(&self.shared_fetch_attempts, &other.shared_fetch_attempts),
+			(&self.local_hits, &other.local_hits),
+			(&self.local_fetch_attempts, &other.local_fetch_attempts),
+		] {
+			to.fetch_add(from.load(Ordering::Relaxed), Ordering::Relaxed);
+		}
+	}
+
+	/// Returns a snapshot of the current stats.
+	fn snapshot(&self) -> CacheHitStats {
+		CacheHitStats {
+			shared_hits: self.shared_hits.load(Ordering::Relaxed),
+			shared_fetch_attempts: self.shared_fetch_attempts.load(Ordering::Relaxed),
+			local_hits: self.local_hits.load(Ordering::Relaxed),
+			local_fetch_attempts: self.local_fetch_attempts.load(Ordering::Relaxed),
+		}
+	}
+}
+
 impl std::fmt::Display for HitStats {
 	fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
 		let shared_hits = self.shared_hits.load(Ordering::Relaxed);
@@ -292,6 +354,53 @@ struct TrieHitStats {
 	value_cache: HitStats,
 }
 
+impl TrieHitStats {
+	/// Adds the stats of `self` to `other`.
+	fn add_to(&self, other: &TrieHitStats) {
+		self.node_cache.add_to(&other.node_cache);
+		self.value_cache.add_to(&other.value_cache);
+	}
+
+	/// Returns a snapshot of the current stats.
+	fn snapshot(&self) -> TrieCacheStats {
+		TrieCacheStats {
+			node_cache: self.node_cache.snapshot(),
+			value_cache: self.value_cache.snapshot(),
+		}
+	}
+}
+
+/// Hit/miss statistics of one of the caches, see [`TrieCacheStats`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheHitStats {
+	/// Number of lookups that were served by the local caches.
+	pub local_hits: u64,
+	/// Number of lookups in the local caches.
+	pub local_fetch_attempts: u64,
+	/// Number of lookups that were served by the shared cache.
+	pub shared_hits: u64,
+	/// Number of lookups in the shared cache, i.e. the lookups that missed the local caches.
+	pub shared_fetch_attempts: u64,
+}
+
+impl CacheHitStats {
+	/// Number of lookups that could not be served by any of the caches.
+	pub fn misses(&self) -> u64 {
+		self.shared_fetch_attempts.saturating_sub(self.shared_hits)
+	}
+}
+
+/// Hit/miss statistics of the node and value caches of a [`SharedTrieCache`].
+///
+/// The stats of a [`LocalTrieCache`] are only accounted for once it is dropped.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TrieCacheStats {
+	/// Statistics of the node cache.
+	pub node_cache: CacheHitStats,
+	/// Statistics of the value cache.
+	pub value_cache: CacheHitStats,
+}
+
 /// An internal struct to store the cached trie nodes.
 pub(crate) struct NodeCached<H> {
 	/// The cached node.
@@ -403,6 +512,8 @@ impl<H: Hasher> Drop for LocalTrieCache<H> {
 			self.stats.value_cache
 		);
 
+		self.stats.add_to(self.shared.hit_stats());
+
 		let mut shared_inner = match self.shared.write_lock_inner() {
 			Some(inner) => inner,
 			None => {
@@ -727,6 +838,35 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn stats_of_dropped_local_caches_are_accumulated() {
+		let (db, root) = create_trie();
+
+		let shared_cache = Cache::new(CACHE_SIZE);
+		for _ in 0..2 {
+			let local_cache = shared_cache.local_cache();
+			{
+				let mut cache = local_cache.as_trie_db_cache(root);
+				let trie = TrieDBBuilder::<Layout>::new(&db, &root).with_cache(&mut cache).build();
+				assert_eq!(TEST_DATA[0].1.to_vec(), trie.get(TEST_DATA[0].0).unwrap().unwrap());
+			}
+
+			// Nothing is accounted for until the local cache is dropped.
+			let before = shared_cache.stats();
+			drop(local_cache);
+			assert!(
+				shared_cache.stats().value_cache.local_fetch_attempts >
+					before.value_cache.local_fetch_attempts
+			);
+		}
+
+		// The first lookup fetched the nodes from the database, the second one found the value in
+		// the shared cache.
+		let stats = shared_cache.stats();
+		assert!(stats.node_cache.misses() > 0);
+		assert_eq!(stats.value_cache.shared_hits, 1);
+	}
+
 	#[test]
 	fn trie_db_mut_cache_works() {
 		let (mut db, root) = create_trie();
@@ -17,7 +17,7 @@
 
 ///! Provides the [`SharedNodeCache`], the [`SharedValueCache`] and the [`SharedTrieCache`]
 ///! that combines both caches and is exported to the outside.
-use super::{CacheSize, NodeCached};
+use super::{CacheSize, LocalCacheLimits, NodeCached, TrieCacheStats, TrieHitStats};
 use hash_db::Hasher;
 use nohash_hasher::BuildNoHashHasher;
 use parking_lot::{Mutex, RwLock, RwLockWriteGuard};
@@ -606,11 +606,19 @@ impl<H: Hasher> SharedTrieCacheInner<H> {
 /// The instance of this object can be shared between multiple threads.
 pub struct SharedTrieCache<H: Hasher> {
 	inner: Arc<RwLock<SharedTrieCacheInner<H>>>,
+	/// The accumulated stats of all dropped local caches.
+	stats: Arc<TrieHitStats>,
+	/// The limits of the local caches created from this cache.
+	local_cache_limits: LocalCacheLimits,
 }
 
 impl<H: Hasher> Clone for SharedTrieCache<H> {
 	fn clone(&self) -> Self {
-		Self { inner: self.inner.clone() }
+		Self {
+			inner: self.inner.clone(),
+			stats: self.stats.clone(),
+			local_cache_limits: self.local_cache_limits,
+		}
 	}
 }
 
@@ -662,15 +670,30 @@ impl<H: Hasher> SharedTrieCache<H> {
 					value_cache_max_heap_size,
 				),
 			})),
+			stats: Default::default(),
+			local_cache_limits: Default::default(),
 		}
 	}
 
+	/// Use the given limits for the [`LocalTrieCache`](super::LocalTrieCache)s created from this
+	/// cache.
+	pub fn with_local_cache_limits(mut self, limits: LocalCacheLimits) -> Self {
+		self.local_cache_limits = limits;
+		self
+	}
+
 	/// Create a new [`LocalTrieCache`](super::LocalTrieCache) instance from this shared cache.
 	pub fn local_cache(&self) -> super::LocalTrieCache<H> {
 		super::LocalTrieCache {
 			shared: self.clone(),
-			node_cache: Default::default(),
-			value_cache: Default::default(),
+			node_cache: Mutex::new(LruMap::with_hasher(
+				super::LocalNodeCacheLimiter::new(self.local_cache_limits.max_node_heap_size),
+				Default::default(),
+			)),
+			value_cache: Mutex::new(LruMap::with_hasher(
+				super::LocalValueCacheLimiter::new(self.local_cache_limits.max_value_heap_size),
+				Default::default(),
+			)),
 			shared_value_cache_access: Mutex::new(super::ValueAccessSet::with_hasher(
 				schnellru::ByLength::new(super::SHARED_VALUE_CACHE_MAX_PROMOTED_KEYS),
 				Default::default(),
@@ -735,6 +758,17 @@ impl<H: Hasher> SharedTrieCache<H> {
 		self.reset_value_cache();
 	}
 
+	/// Returns the hit/miss statistics of all local caches that were created from this cache
+	/// and that are already dropped.
+	pub fn stats(&self) -> TrieCacheStats {
+		self.stats.snapshot()
+	}
+
+	/// Returns the stats the local caches are adding their stats to when being dropped.
+	pub(super) fn hit_stats(&self) -> &TrieHitStats {
+		&self.stats
+	}
+
 	/// Returns the read locked inner.
 	#[cfg(test)]
 	pub(super) fn read_lock_inner(
@@ -32,6 +32,7 @@ use sp_runtime::{
 };
 
 use clap::Args;
+use codec::Encode;
 use log::info;
 use serde::Serialize;
 use std::{marker::PhantomData, sync::Arc, time::Instant};
@@ -66,6 +67,7 @@ pub(crate) struct Benchmark<Block, C> {
 	params: BenchmarkParams,
 	inherent_data: sp_inherents::InherentData,
 	digest_items: Vec<DigestItem>,
+	record_proof: bool,
 	_p: PhantomData<Block>,
 }
 
@@ -79,13 +81,17 @@ where
 	C::Api: ApiExt<Block> + BlockBuilderApi<Block>,
 {
 	/// Create a new [`Self`] from the arguments.
+	///
+	/// If `record_proof` is set, the blocks are executed with storage proof recording enabled,
+	/// like they are on a parachain collator, and the proof size is measured.
 	pub fn new(
 		client: Arc<C>,
 		params: BenchmarkParams,
 		inherent_data: sp_inherents::InherentData,
 		digest_items: Vec<DigestItem>,
+		record_proof: bool,
 	) -> Self {
-		Self { client, params, inherent_data, digest_items, _p: PhantomData }
+		Self { client, params, inherent_data, digest_items, record_proof, _p: PhantomData }
 	}
 
 	/// Benchmark a block with only inherents.
@@ -95,6 +101,32 @@ where
 		Stats::new(&record)
 	}
 
+	/// Size of the storage proof of a block with only inherents.
+	///
+	/// Returns zero if the proof recording is disabled.
+	pub fn proof_size_block(&self) -> Result<u64> {
+		let (block, _) = self.build_block(None)?;
+		self.measure_proof_size(&block)
+	}
+
+	/// Size of the storage proof of an extrinsic in a full block.
+	///
+	/// Analogous to `bench_extrinsic`, the proof size of an empty block is used as baseline
+	/// and subtracted from the proof size of the full block.
+	/// Returns zero if the proof recording is disabled.
+	pub fn proof_size_extrinsic(&self, ext_builder: &dyn ExtrinsicBuilder) -> Result<u64> {
+		if !self.record_proof {
+			return Ok(0)
+		}
+		let base = self.proof_size_block()?;
+
+		let (block, num_ext) = self.build_block(Some(ext_builder))?;
+		let num_ext = num_ext.ok_or_else(|| Error::Input("Block was empty".into()))?;
+		let size = self.measure_proof_size(&block)?.saturating_sub(base);
+
+		Ok(((size as f64) / (num_ext as f64)).ceil() as u64)
+	}
+
 	/// Benchmark the time of an extrinsic in a full block.
 	///
 	/// First benchmarks an empty block, analogous to `bench_block` and use it as baseline.
@@ -179,8 +211,7 @@ where
 
 		info!("Running {} warmups...", self.params.warmup);
 		for _ in 0..self.params.warmup {
-			self.client
-				.runtime_api()
+			self.runtime_api()
 				.execute_block(genesis, block.clone())
 				.map_err(|e| Error::Client(RuntimeApiError(e)))?;
 		}
@@ -190,7 +221,7 @@ where
 		// Execute a block multiple times and record each execution time.
 		for _ in 0..self.params.repeat {
 			let block = block.clone();
-			let runtime_api = self.client.runtime_api();
+			let runtime_api = self.runtime_api();
 			let start = Instant::now();
 
 			runtime_api
@@ -204,6 +235,33 @@ where
 		Ok(record)
 	}
 
+	/// Measures the size of the storage proof that is needed to execute a block.
+	fn measure_proof_size(&self, block: &Block) -> Result<u64> {
+		if !self.record_proof {
+			return Ok(0)
+		}
+		let genesis = self.client.info().genesis_hash;
+
+		let mut runtime_api = self.runtime_api();
+		runtime_api
+			.execute_block(genesis, block.clone())
+			.map_err(|e| Error::Client(RuntimeApiError(e)))?;
+		let proof = runtime_api
+			.extract_proof()
+			.ok_or_else(|| Error::Input("Proof recording is not enabled".into()))?;
+
+		Ok(proof.encoded_size() as u64)
+	}
+
+	/// Runtime API instance, which records the storage proof if enabled.
+	fn runtime_api(&self) -> sp_api::ApiRef<C::Api> {
+		let mut runtime_api = self.client.runtime_api();
+		if self.record_proof {
+			runtime_api.record_proof();
+		}
+		runtime_api
+	}
+
 	fn max_ext_per_block(&self) -> u32 {
 		self.params.max_ext_per_block.unwrap_or(u32::MAX)
 	}
@@ -0,0 +1,224 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks the random reads and writes of the database backend.
+//!
+//! Unlike the raw disk benchmarks, these go through the configured database (RocksDB or ParityDB)
+//! and use values with the sizes of the state trie nodes, which is what a node is doing when it
+//! reads or writes the state.
+
+use crate::shared::new_rng;
+use rand::{distributions::Uniform, prelude::*};
+use sc_client_db::{DatabaseSource, DbHash};
+use sc_sysinfo::{ExecutionLimit, Metric, Throughput};
+use sp_database::{ColumnId, Database, Transaction};
+use sp_runtime::{generic, traits::BlakeTwo256, OpaqueExtrinsic};
+use std::{
+	path::{Path, PathBuf},
+	sync::Arc,
+	time::{Duration, Instant},
+};
+
+/// Block type of the benchmark database. Only used to open the database.
+type Block = generic::Block<generic::Header<u32, BlakeTwo256>, OpaqueExtrinsic>;
+
+/// Number of values the database is populated with before measuring.
+const NUM_VALUES: usize = 200_000;
+/// Number of values that are read or written in one iteration.
+const BATCH_SIZE: usize = 10_000;
+/// Number of values that are written in one commit, roughly the changes of one block.
+const COMMIT_SIZE: usize = 500;
+/// Size range of the values, from small leaf nodes up to full branch nodes of the state trie.
+const VALUE_SIZE: (usize, usize) = (32, 544);
+
+/// The database backend that is benchmarked.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DatabaseBackend {
+	RocksDb,
+	ParityDb,
+}
+
+impl DatabaseBackend {
+	/// The backend used by the database source of the node.
+	///
+	/// Returns `None` for custom databases, which can't be benchmarked.
+	pub(crate) fn from_source(source: &DatabaseSource) -> Option<Self> {
+		match source {
+			#[cfg(feature = "rocksdb")]
+			DatabaseSource::RocksDb { .. } => Some(Self::RocksDb),
+			DatabaseSource::ParityDb { .. } => Some(Self::ParityDb),
+			// An existing RocksDB database is used, otherwise a ParityDB database is created.
+			DatabaseSource::Auto { rocksdb_path, .. } if rocksdb_path.exists() =>
+				Some(Self::RocksDb),
+			DatabaseSource::Auto { .. } => Some(Self::ParityDb),
+			DatabaseSource::Custom { .. } => None,
+		}
+	}
+
+	/// The backend that is benchmarked by the given metric, if any.
+	pub(crate) fn of_metric(metric: &Metric) -> Option<Self> {
+		match metric {
+			Metric::RocksDbRndRead | Metric::RocksDbRndWrite => Some(Self::RocksDb),
+			Metric::ParityDbRndRead | Metric::ParityDbRndWrite => Some(Self::ParityDb),
+			_ => None,
+		}
+	}
+
+	/// Source of a new database of this backend in `dir`.
+	fn source(&self, dir: &Path, cache_size: usize) -> Result<DatabaseSource, String> {
+		match self {
+			#[cfg(feature = "rocksdb")]
+			Self::RocksDb => Ok(DatabaseSource::RocksDb { path: dir.join("rocksdb"), cache_size }),
+			#[cfg(not(feature = "rocksdb"))]
+			Self::RocksDb => {
+				let _ = cache_size;
+				Err("The `rocksdb` feature is not enabled".into())
+			},
+			Self::ParityDb => Ok(DatabaseSource::ParityDb { path: dir.join("paritydb") }),
+		}
+	}
+}
+
+/// The cache size in MiB of the database source, only used by RocksDB.
+pub(crate) fn cache_size(source: &DatabaseSource) -> usize {
+	match source {
+		#[cfg(feature = "rocksdb")]
+		DatabaseSource::RocksDb { cache_size, .. } => *cache_size,
+		DatabaseSource::Auto { cache_size, .. } => *cache_size,
+		_ => 0,
+	}
+}
+
+/// A populated scratch database that is removed when dropped.
+pub(crate) struct BenchDb {
+	db: Arc<dyn Database<DbHash>>,
+	col: ColumnId,
+	keys: Vec<[u8; 32]>,
+	dir: PathBuf,
+}
+
+impl BenchDb {
+	/// Creates a new database of the given backend in a scratch directory within `dir` and
+	/// populates it with random values.
+	pub(crate) fn new(
+		backend: DatabaseBackend,
+		dir: &Path,
+		cache_size: usize,
+	) -> Result<Self, String> {
+		let dir = dir.join(".machine_db_bench");
+		// Leftovers of an aborted run would distort the results.
+		let _ = std::fs::remove_dir_all(&dir);
+		let source = backend.source(&dir, cache_size)?;
+		let (db, col) = sc_client_db::open_database_for_benchmarks::<Block>(&source)
+			.map_err(|e| format!("Failed to open the {:?} database: {}", backend, e))?;
+
+		let mut bench_db = Self { db, col, keys: Vec::with_capacity(NUM_VALUES), dir };
+		let (mut rng, _) = new_rng(None);
+		while bench_db.keys.len() < NUM_VALUES {
+			let keys = (0..COMMIT_SIZE * 10).map(|_| rng.gen()).collect();
+			bench_db.write(keys, &mut rng)?;
+		}
+
+		Ok(bench_db)
+	}
+
+	/// Measures the throughput of reading random values.
+	pub(crate) fn bench_random_reads(&self, limit: ExecutionLimit) -> Result<Throughput, String> {
+		let (mut rng, _) = new_rng(None);
+
+		benchmark(limit, || {
+			let mut size = 0;
+			for key in self.keys.choose_multiple(&mut rng, BATCH_SIZE) {
+				let mut key = key.to_vec();
+				self.db.sanitize_key(&mut key);
+				size += self.db.get(self.col, &key).ok_or("Value is missing")?.len();
+			}
+			Ok(size)
+		})
+	}
+
+	/// Measures the throughput of overwriting random values, committed in block sized batches.
+	pub(crate) fn bench_random_writes(
+		&mut self,
+		limit: ExecutionLimit,
+	) -> Result<Throughput, String> {
+		let (mut rng, _) = new_rng(None);
+		let keys = self.keys.clone();
+
+		benchmark(limit, || {
+			let batch = keys.choose_multiple(&mut rng, BATCH_SIZE).cloned().collect();
+			self.write(batch, &mut rng)
+		})
+	}
+
+	/// Writes random values for the given keys, returns the number of written bytes.
+	fn write(&mut self, keys: Vec<[u8; 32]>, rng: &mut impl Rng) -> Result<usize, String> {
+		let sizes = Uniform::new_inclusive(VALUE_SIZE.0, VALUE_SIZE.1);
+		let mut size = 0;
+
+		for chunk in keys.chunks(COMMIT_SIZE) {
+			let mut tx = Transaction::<DbHash>::default();
+			for key in chunk {
+				let mut value = vec![0u8; rng.sample(sizes)];
+				rng.fill_bytes(&mut value);
+				size += value.len();
+
+				let mut key = key.to_vec();
+				self.db.sanitize_key(&mut key);
+				tx.set(self.col, &key, &value);
+			}
+			self.db.commit(tx).map_err(|e| format!("Failed to commit: {}", e))?;
+		}
+
+		self.keys
+			.extend(keys.into_iter().take(NUM_VALUES.saturating_sub(self.keys.len())));
+		Ok(size)
+	}
+}
+
+impl Drop for BenchDb {
+	fn drop(&mut self) {
+		if let Err(e) = std::fs::remove_dir_all(&self.dir) {
+			log::warn!("Failed to remove the benchmark database {:?}: {}", self.dir, e);
+		}
+	}
+}
+
+/// Runs `run` until the `limit` is reached and returns the throughput.
+///
+/// `run` returns the number of processed bytes.
+fn benchmark(
+	limit: ExecutionLimit,
+	mut run: impl FnMut() -> Result<usize, String>,
+) -> Result<Throughput, String> {
+	// Warmup.
+	run()?;
+
+	let start = Instant::now();
+	let mut elapsed = Duration::default();
+	let mut size = 0;
+	for _ in 0..limit.max_iterations() {
+		size += run()?;
+
+		elapsed = start.elapsed();
+		if elapsed >= limit.max_duration() {
+			break
+		}
+	}
+
+	Ok(Throughput::from_kibs(size as f64 / (elapsed.as_secs_f64() * 1024.0)))
+}
@@ -62,6 +62,22 @@ mod tests {
 				},
 				Requirement { metric: Metric::DiskSeqWrite, minimum: Throughput::from_mibs(950.0) },
 				Requirement { metric: Metric::DiskRndWrite, minimum: Throughput::from_mibs(420.0) },
+				Requirement {
+					metric: Metric::RocksDbRndRead,
+					minimum: Throughput::from_mibs(150.0)
+				},
+				Requirement {
+					metric: Metric::RocksDbRndWrite,
+					minimum: Throughput::from_mibs(20.0)
+				},
+				Requirement {
+					metric: Metric::ParityDbRndRead,
+					minimum: Throughput::from_mibs(180.0)
+				},
+				Requirement {
+					metric: Metric::ParityDbRndWrite,
+					minimum: Throughput::from_mibs(30.0),
+				},
 			])
 		);
 	}
@@ -18,6 +18,7 @@
 //! Contains the [`MachineCmd`] as entry point for the node
 //! and the core benchmarking logic.
 
+mod database;
 pub mod hardware;
 
 use std::{boxed::Box, fs, path::Path};
@@ -35,6 +36,7 @@ use sc_sysinfo::{
 };
 
 use crate::shared::check_build_profile;
+use database::{BenchDb, DatabaseBackend};
 pub use hardware::SUBSTRATE_REFERENCE_HARDWARE;
 
 /// Command to benchmark the hardware.
@@ -45,6 +47,7 @@ pub use hardware::SUBSTRATE_REFERENCE_HARDWARE;
 /// which influence the results.
 ///
 /// You can use the `--base-path` flag to set a location for the disk benchmarks.
+/// The database benchmarks use the database backend that is configured with `--database`.
 #[derive(Debug, Parser)]
 pub struct MachineCmd {
 	#[allow(missing_docs)]
@@ -79,6 +82,10 @@ pub struct MachineCmd {
 	/// Time limit for each disk benchmark.
 	#[arg(long, default_value_t = 5.0, value_name = "SECONDS")]
 	pub disk_duration: f32,
+
+	/// Time limit for each database benchmark.
+	#[arg(long, default_value_t = 5.0, value_name = "SECONDS")]
+	pub db_duration: f32,
 }
 
 /// Helper for the result of a concrete benchmark.
@@ -116,21 +123,41 @@ impl MachineCmd {
 		// Ensure that the dir exists since the node is not started to take care of it.
 		let dir = cfg.database.path().ok_or("No DB directory provided")?;
 		fs::create_dir_all(dir)?;
+		// Only the database backend of the node is benchmarked.
+		let backend = DatabaseBackend::from_source(&cfg.database);
+		if backend.is_none() {
+			warn!("Skipping the database benchmarks since a custom database is configured");
+		}
+		let requirements = Requirements(
+			requirements
+				.0
+				.into_iter()
+				.filter(|r| {
+					DatabaseBackend::of_metric(&r.metric).map_or(true, |b| Some(b) == backend)
+				})
+				.collect(),
+		);
+		let cache_size = database::cache_size(&cfg.database);
 
 		info!("Running machine benchmarks...");
 		let mut results = Vec::new();
 		for requirement in &requirements.0 {
-			let result = self.run_benchmark(requirement, &dir)?;
+			let result = self.run_benchmark(requirement, &dir, cache_size)?;
 			results.push(result);
 		}
 		self.print_summary(requirements, results)
 	}
 
 	/// Benchmarks a specific metric of the hardware and judges the resulting score.
-	fn run_benchmark(&self, requirement: &Requirement, dir: &Path) -> Result<BenchResult> {
+	fn run_benchmark(
+		&self,
+		requirement: &Requirement,
+		dir: &Path,
+		cache_size: usize,
+	) -> Result<BenchResult> {
 		// Dispatch the concrete function from `sc-sysinfo`.
 
-		let score = self.measure(&requirement.metric, dir)?;
+		let score = self.measure(&requirement.metric, dir, cache_size)?;
 		let rel_score = score.as_bytes() / requirement.minimum.as_bytes();
 
 		// Sanity check if the result is off by factor >100x.
@@ -142,11 +169,14 @@ impl MachineCmd {
 	}
 
 	/// Measures a metric of the hardware.
-	fn measure(&self, metric: &Metric, dir: &Path) -> Result<Throughput> {
+	///
+	/// `cache_size` is the cache size of the database in MiB, which is only used by RocksDB.
+	fn measure(&self, metric: &Metric, dir: &Path, cache_size: usize) -> Result<Throughput> {
 		let verify_limit = ExecutionLimit::from_secs_f32(self.verify_duration);
 		let disk_limit = ExecutionLimit::from_secs_f32(self.disk_duration);
 		let hash_limit = ExecutionLimit::from_secs_f32(self.hash_duration);
 		let memory_limit = ExecutionLimit::from_secs_f32(self.memory_duration);
+		let db_limit = ExecutionLimit::from_secs_f32(self.db_duration);
 
 		let score = match metric {
 			Metric::Blake2256 => benchmark_cpu(hash_limit),
@@ -154,6 +184,16 @@ impl MachineCmd {
 			Metric::MemCopy => benchmark_memory(memory_limit),
 			Metric::DiskSeqWrite => benchmark_disk_sequential_writes(disk_limit, dir)?,
 			Metric::DiskRndWrite => benchmark_disk_random_writes(disk_limit, dir)?,
+			Metric::RocksDbRndRead | Metric::ParityDbRndRead => {
+				let backend =
+					DatabaseBackend::of_metric(metric).expect("Is a database metric; qed");
+				BenchDb::new(backend, dir, cache_size)?.bench_random_reads(db_limit)?
+			},
+			Metric::RocksDbRndWrite | Metric::ParityDbRndWrite => {
+				let backend =
+					DatabaseBackend::of_metric(metric).expect("Is a database metric; qed");
+				BenchDb::new(backend, dir, cache_size)?.bench_random_writes(db_limit)?
+			},
 		};
 		Ok(score)
 	}
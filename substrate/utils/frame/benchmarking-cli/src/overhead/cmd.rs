@@ -81,6 +81,14 @@ pub struct OverheadParams {
 	/// This should only be used for performance analysis and not for final results.
 	#[arg(long)]
 	pub enable_trie_cache: bool,
+
+	/// Benchmark a parachain runtime with the given para id.
+	///
+	/// The blocks are executed with storage proof recording, like on a collator, and the size of
+	/// the proof is included in the resulting weights. The node must provide the parachain
+	/// inherent (validation data of this para id) in the inherent data of the benchmark.
+	#[arg(long, value_name = "PARA_ID")]
+	pub para_id: Option<u32>,
 }
 
 /// Type of a benchmark.
@@ -116,20 +124,41 @@ impl OverheadCmd {
 		if ext_builder.pallet() != "system" || ext_builder.extrinsic() != "remark" {
 			return Err(format!("The extrinsic builder is required to build `System::Remark` extrinsics but builds `{}` extrinsics instead", ext_builder.name()).into());
 		}
-		let bench = Benchmark::new(client, self.params.bench.clone(), inherent_data, digest_items);
+		let bench = Benchmark::new(
+			client,
+			self.params.bench.clone(),
+			inherent_data,
+			digest_items,
+			self.params.para_id.is_some(),
+		);
 
 		// per-block execution overhead
 		{
 			let stats = bench.bench_block()?;
 			info!("Per-block execution overhead [ns]:\n{:?}", stats);
-			let template = TemplateData::new(BenchmarkType::Block, &cfg, &self.params, &stats)?;
+			let proof_size = bench.proof_size_block()?;
+			if let Some(para_id) = self.params.para_id {
+				info!("Per-block proof size of parachain {} [bytes]: {}", para_id, proof_size);
+			}
+			let template =
+				TemplateData::new(BenchmarkType::Block, &cfg, &self.params, &stats, proof_size)?;
 			template.write(&self.params.weight.weight_path)?;
 		}
 		// per-extrinsic execution overhead
 		{
 			let stats = bench.bench_extrinsic(ext_builder)?;
 			info!("Per-extrinsic execution overhead [ns]:\n{:?}", stats);
-			let template = TemplateData::new(BenchmarkType::Extrinsic, &cfg, &self.params, &stats)?;
+			let proof_size = bench.proof_size_extrinsic(ext_builder)?;
+			if let Some(para_id) = self.params.para_id {
+				info!("Per-extrinsic proof size of parachain {} [bytes]: {}", para_id, proof_size);
+			}
+			let template = TemplateData::new(
+				BenchmarkType::Extrinsic,
+				&cfg,
+				&self.params,
+				&stats,
+				proof_size,
+			)?;
 			template.write(&self.params.weight.weight_path)?;
 		}
 
@@ -61,6 +61,8 @@ pub(crate) struct TemplateData {
 	stats: Stats,
 	/// The resulting weight in ns.
 	weight: u64,
+	/// The resulting proof size in bytes. Zero unless a parachain is benchmarked.
+	proof_size: u64,
 }
 
 impl TemplateData {
@@ -70,6 +72,7 @@ impl TemplateData {
 		cfg: &Configuration,
 		params: &OverheadParams,
 		stats: &Stats,
+		proof_size: u64,
 	) -> Result<Self> {
 		let weight = params.weight.calc_weight(stats)?;
 		let header = params
@@ -92,6 +95,7 @@ impl TemplateData {
 			params: params.clone(),
 			stats: stats.clone(),
 			weight,
+			proof_size,
 		})
 	}
 
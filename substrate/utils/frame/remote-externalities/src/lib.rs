@@ -21,6 +21,7 @@
 //! based chain, or a local state snapshot file.
 
 use codec::{Compact, Decode, Encode};
+use futures::StreamExt;
 use indicatif::{ProgressBar, ProgressStyle};
 use jsonrpsee::{
 	core::params::ArrayParams,
@@ -44,7 +45,9 @@ use sp_state_machine::TestExternalities;
 use spinners::{Spinner, Spinners};
 use std::{
 	cmp::{max, min},
+	collections::BTreeMap,
 	fs,
+	io::{Seek, SeekFrom, Write},
 	ops::{Deref, DerefMut},
 	path::{Path, PathBuf},
 	sync::Arc,
@@ -104,6 +107,212 @@ impl<B: BlockT> Snapshot<B> {
 	}
 }
 
+/// A record of the scrape progress file.
+///
+/// The progress file is a sequence of SCALE encoded records, of which the first one is always the
+/// header. Records are only ever appended, such that a partially written last record can be
+/// dropped when the file is loaded again.
+#[derive(Decode, Encode)]
+enum ProgressRecord<Hash> {
+	/// The block at which the state is being scraped.
+	Header { snapshot_version: SnapshotVersion, block_hash: Hash },
+	/// All keys under a top-level prefix.
+	Keys { prefix: Vec<u8>, keys: Vec<Vec<u8>> },
+	/// The next downloaded key-values under a top-level prefix, in the order of its keys.
+	Values { prefix: Vec<u8>, key_values: Vec<(Vec<u8>, Vec<u8>)> },
+	/// All key-values of the child trie under a child-bearing top key.
+	Child { prefixed_top_key: Vec<u8>, key_values: Vec<(Vec<u8>, Vec<u8>)> },
+}
+
+/// The progress of scraping the remote state, persisted next to the state snapshot such that an
+/// interrupted scrape can be resumed.
+///
+/// Does nothing if no snapshot is configured.
+struct ScrapeProgress {
+	file: Option<fs::File>,
+	path: Option<PathBuf>,
+	keys: BTreeMap<Vec<u8>, Vec<StorageKey>>,
+	values: BTreeMap<Vec<u8>, Vec<KeyValue>>,
+	children: BTreeMap<Vec<u8>, Vec<KeyValue>>,
+}
+
+impl ScrapeProgress {
+	/// The path of the progress file of the snapshot at `snapshot_path`.
+	fn path_for(snapshot_path: &Path) -> PathBuf {
+		let mut path = snapshot_path.as_os_str().to_owned();
+		path.push(".progress");
+		path.into()
+	}
+
+	/// Decode the records of the progress file at `path`, together with the length of the
+	/// successfully decoded part of the file.
+	fn read_records<Hash: Decode>(path: &Path) -> Option<(Vec<ProgressRecord<Hash>>, u64)> {
+		let bytes = fs::read(path).ok()?;
+		let mut input = &bytes[..];
+		let mut records = Vec::new();
+		while !input.is_empty() {
+			match ProgressRecord::<Hash>::decode(&mut input) {
+				Ok(record) => records.push(record),
+				Err(_) => {
+					warn!(target: LOG_TARGET, "dropping partially written progress record");
+					break
+				},
+			}
+		}
+		let valid_len = (bytes.len() - input.len()) as u64;
+		Some((records, valid_len))
+	}
+
+	/// The block of the scrape persisted at `path`, if any.
+	fn block_hash<Hash: Decode>(path: &Path) -> Option<Hash> {
+		match Self::read_records(path)?.0.into_iter().next()? {
+			ProgressRecord::Header { snapshot_version, block_hash }
+				if snapshot_version == SNAPSHOT_VERSION =>
+				Some(block_hash),
+			_ => None,
+		}
+	}
+
+	/// Load the progress of scraping the state at `block_hash` from `path`, or start a new one.
+	fn open<Hash: Encode + Decode + PartialEq>(
+		path: Option<PathBuf>,
+		block_hash: Hash,
+	) -> Result<Self, &'static str> {
+		let mut progress = Self {
+			file: None,
+			path: path.clone(),
+			keys: Default::default(),
+			values: Default::default(),
+			children: Default::default(),
+		};
+		let Some(path) = path else { return Ok(progress) };
+
+		let mut valid_len = 0;
+		if let Some((records, len)) = Self::read_records::<Hash>(&path) {
+			let resumable = matches!(
+				records.first(),
+				Some(ProgressRecord::Header { snapshot_version, block_hash: at })
+					if *snapshot_version == SNAPSHOT_VERSION && *at == block_hash
+			);
+			if resumable {
+				valid_len = len;
+				progress.apply(records);
+				info!(
+					target: LOG_TARGET,
+					"📂 resuming scrape from {:?}: {} key prefixes, {} values and {} child tries",
+					path,
+					progress.keys.len(),
+					progress.values.values().map(Vec::len).sum::<usize>(),
+					progress.children.len(),
+				);
+			} else {
+				warn!(
+					target: LOG_TARGET,
+					"discarding progress file {:?} of a different block or snapshot version",
+					path
+				);
+			}
+		}
+
+		let mut file = fs::OpenOptions::new()
+			.create(true)
+			.write(true)
+			.open(&path)
+			.map_err(|_| "failed to open progress file")?;
+		file.set_len(valid_len).map_err(|_| "failed to truncate progress file")?;
+		file.seek(SeekFrom::End(0)).map_err(|_| "failed to seek progress file")?;
+		progress.file = Some(file);
+		if valid_len == 0 {
+			progress.append(ProgressRecord::Header {
+				snapshot_version: SNAPSHOT_VERSION,
+				block_hash,
+			})?;
+		}
+
+		Ok(progress)
+	}
+
+	fn apply<Hash>(&mut self, records: Vec<ProgressRecord<Hash>>) {
+		fn to_key_values(key_values: Vec<(Vec<u8>, Vec<u8>)>) -> Vec<KeyValue> {
+			key_values.into_iter().map(|(k, v)| (StorageKey(k), StorageData(v))).collect()
+		}
+
+		for record in records {
+			match record {
+				ProgressRecord::Header { .. } => {},
+				ProgressRecord::Keys { prefix, keys } => {
+					self.keys.insert(prefix, keys.into_iter().map(StorageKey).collect());
+				},
+				ProgressRecord::Values { prefix, key_values } => {
+					self.values.entry(prefix).or_default().extend(to_key_values(key_values));
+				},
+				ProgressRecord::Child { prefixed_top_key, key_values } => {
+					self.children.insert(prefixed_top_key, to_key_values(key_values));
+				},
+			}
+		}
+	}
+
+	fn append<Hash: Encode>(&mut self, record: ProgressRecord<Hash>) -> Result<(), &'static str> {
+		if let Some(file) = self.file.as_mut() {
+			file.write_all(&record.encode()).map_err(|_| "failed to write progress file")?;
+		}
+		Ok(())
+	}
+
+	/// The previously scraped keys under `prefix`.
+	fn take_keys(&mut self, prefix: &[u8]) -> Option<Vec<StorageKey>> {
+		self.keys.remove(prefix)
+	}
+
+	/// The previously downloaded key-values under `prefix`, in the order of its keys.
+	fn take_values(&mut self, prefix: &[u8]) -> Vec<KeyValue> {
+		self.values.remove(prefix).unwrap_or_default()
+	}
+
+	/// The previously downloaded key-values of the child trie under `prefixed_top_key`.
+	fn take_child(&mut self, prefixed_top_key: &[u8]) -> Option<Vec<KeyValue>> {
+		self.children.remove(prefixed_top_key)
+	}
+
+	fn record_keys(&mut self, prefix: &[u8], keys: &[StorageKey]) -> Result<(), &'static str> {
+		self.append(ProgressRecord::<()>::Keys {
+			prefix: prefix.to_vec(),
+			keys: keys.iter().map(|k| k.0.clone()).collect(),
+		})
+	}
+
+	fn record_values(
+		&mut self,
+		prefix: &[u8],
+		key_values: &[KeyValue],
+	) -> Result<(), &'static str> {
+		self.append(ProgressRecord::<()>::Values {
+			prefix: prefix.to_vec(),
+			key_values: key_values.iter().map(|(k, v)| (k.0.clone(), v.0.clone())).collect(),
+		})
+	}
+
+	fn record_child(
+		&mut self,
+		prefixed_top_key: &[u8],
+		key_values: &[KeyValue],
+	) -> Result<(), &'static str> {
+		self.append(ProgressRecord::<()>::Child {
+			prefixed_top_key: prefixed_top_key.to_vec(),
+			key_values: key_values.iter().map(|(k, v)| (k.0.clone(), v.0.clone())).collect(),
+		})
+	}
+
+	/// Remove the progress file once the scrape is complete.
+	fn finish(self) {
+		drop(self.file);
+		if let Some(path) = self.path {
+			let _ = fs::remove_file(path);
+		}
+	}
+}
+
 /// An externalities that acts exactly the same as [`sp_io::TestExternalities`] but has a few extra
 /// bits and pieces to it, and can be loaded remotely.
 pub struct RemoteExternalities<B: BlockT> {
@@ -226,6 +435,10 @@ pub struct OnlineConfig<B: BlockT> {
 	/// provided.
 	pub at: Option<B::Hash>,
 	/// An optional state snapshot file to WRITE to, not for reading. Not written if set to `None`.
+	///
+	/// If set, the progress of the scrape is persisted to a `.progress` file next to it, such that
+	/// an interrupted scrape is resumed, and the file is removed once the scrape completes. If
+	/// `at` is not set, the block of the interrupted scrape is used.
 	pub state_snapshot: Option<SnapshotConfig>,
 	/// The pallets to scrape. These values are hashed and added to `hashed_prefix`.
 	pub pallets: Vec<String>,
@@ -360,6 +573,8 @@ where
 	const DEFAULT_KEY_DOWNLOAD_PAGE: u32 = 1000;
 	const MAX_RETRIES: usize = 12;
 	const KEYS_PAGE_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+	// number of values downloaded between two writes to the progress file
+	const PROGRESS_CHECKPOINT_SIZE: usize = 50_000;
 
 	async fn rpc_get_storage(
 		&self,
@@ -471,15 +686,11 @@ where
 
 		parallel.close();
 
-		let keys = futures::future::join_all(handles)
-			.await
-			.into_iter()
-			.filter_map(|res| match res {
-				Ok(Ok(keys)) => Some(keys),
-				_ => None,
-			})
-			.flatten()
-			.collect::<Vec<StorageKey>>();
+		// a failed range would silently leave a gap in the state, so fail all of them instead.
+		let mut keys = vec![];
+		for res in futures::future::join_all(handles).await {
+			keys.extend(res.map_err(|_| "key scraping task failed")??);
+		}
 
 		Ok(keys)
 	}
@@ -685,37 +896,50 @@ where
 	/// map them to values one by one.
 	///
 	/// This can work with public nodes. But, expect it to be darn slow.
+	///
+	/// The keys and values are recorded to `progress` as they are downloaded, and taken from it
+	/// if they have been downloaded before.
 	pub(crate) async fn rpc_get_pairs(
 		&self,
 		prefix: StorageKey,
 		at: B::Hash,
 		pending_ext: &mut TestExternalities<HashingFor<B>>,
+		progress: &mut ScrapeProgress,
 	) -> Result<Vec<KeyValue>, &'static str> {
-		let start = Instant::now();
-		let mut sp = Spinner::with_timer(Spinners::Dots, "Scraping keys...".into());
-		// TODO We could start downloading when having collected the first batch of keys
-		// https://github.com/paritytech/polkadot-sdk/issues/2494
-		let keys = self
-			.rpc_get_keys_parallel(&prefix, at, Self::PARALLEL_REQUESTS)
-			.await?
-			.into_iter()
-			.collect::<Vec<_>>();
-		sp.stop_with_message(format!(
-			"✅ Found {} keys ({:.2}s)",
-			keys.len(),
-			start.elapsed().as_secs_f32()
-		));
+		let keys = match progress.take_keys(prefix.as_ref()) {
+			Some(keys) => {
+				info!(target: LOG_TARGET, "✅ Resuming with {} previously found keys", keys.len());
+				keys
+			},
+			None => {
+				let start = Instant::now();
+				let mut sp = Spinner::with_timer(Spinners::Dots, "Scraping keys...".into());
+				// TODO We could start downloading when having collected the first batch of keys
+				// https://github.com/paritytech/polkadot-sdk/issues/2494
+				let keys = self.rpc_get_keys_parallel(&prefix, at, Self::PARALLEL_REQUESTS).await?;
+				sp.stop_with_message(format!(
+					"✅ Found {} keys ({:.2}s)",
+					keys.len(),
+					start.elapsed().as_secs_f32()
+				));
+				progress.record_keys(prefix.as_ref(), &keys)?;
+				keys
+			},
+		};
 		if keys.is_empty() {
 			return Ok(Default::default())
 		}
 
-		let client = self.as_online().rpc_client();
-		let payloads = keys
-			.iter()
-			.map(|key| ("state_getStorage".to_string(), rpc_params!(key, at)))
-			.collect::<Vec<_>>();
+		// values are downloaded in the order of the keys, so the previously downloaded ones
+		// correspond to a prefix of `keys`.
+		let mut key_values = progress.take_values(prefix.as_ref());
+		if key_values.len() > keys.len() {
+			return Err("progress file has more values than keys")
+		}
 
-		let bar = ProgressBar::new(payloads.len() as u64);
+		let client = self.as_online().rpc_client();
+		let bar = ProgressBar::new(keys.len() as u64);
+		bar.set_position(key_values.len() as u64);
 		bar.enable_steady_tick(Duration::from_secs(1));
 		bar.set_message("Downloading key values".to_string());
 		bar.set_style(
@@ -725,40 +949,53 @@ where
 			.unwrap()
 			.progress_chars("=>-"),
 		);
-		let payloads_chunked = payloads.chunks((payloads.len() / Self::PARALLEL_REQUESTS).max(1));
-		let requests = payloads_chunked.map(|payload_chunk| {
-			Self::get_storage_data_dynamic_batch_size(client, payload_chunk.to_vec(), &bar)
-		});
-		// Execute the requests and move the Result outside.
-		let storage_data_result: Result<Vec<_>, _> =
-			futures::future::join_all(requests).await.into_iter().collect();
-		// Handle the Result.
-		let storage_data = match storage_data_result {
-			Ok(storage_data) => storage_data.into_iter().flatten().collect::<Vec<_>>(),
-			Err(e) => {
-				log::error!(target: LOG_TARGET, "Error while getting storage data: {}", e);
-				return Err("Error while getting storage data")
-			},
-		};
+		for checkpoint in keys[key_values.len()..].chunks(Self::PROGRESS_CHECKPOINT_SIZE) {
+			let payloads = checkpoint
+				.iter()
+				.map(|key| ("state_getStorage".to_string(), rpc_params!(key, at)))
+				.collect::<Vec<_>>();
+			let payloads_chunked =
+				payloads.chunks((payloads.len() / Self::PARALLEL_REQUESTS).max(1));
+			let requests = payloads_chunked.map(|payload_chunk| {
+				Self::get_storage_data_dynamic_batch_size(client, payload_chunk.to_vec(), &bar)
+			});
+			// Execute the requests and move the Result outside.
+			let storage_data_result: Result<Vec<_>, _> =
+				futures::future::join_all(requests).await.into_iter().collect();
+			// Handle the Result.
+			let storage_data = match storage_data_result {
+				Ok(storage_data) => storage_data.into_iter().flatten().collect::<Vec<_>>(),
+				Err(e) => {
+					log::error!(target: LOG_TARGET, "Error while getting storage data: {}", e);
+					return Err("Error while getting storage data")
+				},
+			};
+
+			// Check if we got responses for all submitted requests.
+			assert_eq!(checkpoint.len(), storage_data.len());
+
+			let checkpoint_key_values = checkpoint
+				.iter()
+				.zip(storage_data)
+				.map(|(key, maybe_value)| match maybe_value {
+					Some(data) => (key.clone(), data),
+					None => {
+						log::warn!(
+							target: LOG_TARGET,
+							"key {:?} had none corresponding value.",
+							&key
+						);
+						let data = StorageData(vec![]);
+						(key.clone(), data)
+					},
+				})
+				.collect::<Vec<_>>();
+			progress.record_values(prefix.as_ref(), &checkpoint_key_values)?;
+			key_values.extend(checkpoint_key_values);
+		}
 		bar.finish_with_message("✅ Downloaded key values");
 		println!();
 
-		// Check if we got responses for all submitted requests.
-		assert_eq!(keys.len(), storage_data.len());
-
-		let key_values = keys
-			.iter()
-			.zip(storage_data)
-			.map(|(key, maybe_value)| match maybe_value {
-				Some(data) => (key.clone(), data),
-				None => {
-					log::warn!(target: LOG_TARGET, "key {:?} had none corresponding value.", &key);
-					let data = StorageData(vec![]);
-					(key.clone(), data)
-				},
-			})
-			.collect::<Vec<_>>();
-
 		let mut sp = Spinner::with_timer(Spinners::Dots, "Inserting keys into DB...".into());
 		let start = Instant::now();
 		pending_ext.batch_insert(key_values.clone().into_iter().filter_map(|(k, v)| {
@@ -830,19 +1067,31 @@ where
 		child_prefix: StorageKey,
 		at: B::Hash,
 	) -> Result<Vec<StorageKey>, &'static str> {
-		// This is deprecated and will generate a warning which causes the CI to fail.
-		#[allow(warnings)]
-		let child_keys = substrate_rpc_client::ChildStateApi::storage_keys(
-			client,
-			PrefixedStorageKey::new(prefixed_top_key.as_ref().to_vec()),
-			child_prefix,
-			Some(at),
-		)
-		.await
-		.map_err(|e| {
-			error!(target: LOG_TARGET, "Error = {:?}", e);
-			"rpc child_get_keys failed."
-		})?;
+		let mut child_keys: Vec<StorageKey> = vec![];
+		loop {
+			let retry_strategy =
+				FixedInterval::new(Self::KEYS_PAGE_RETRY_INTERVAL).take(Self::MAX_RETRIES);
+			let get_page_closure = || {
+				substrate_rpc_client::ChildStateApi::storage_keys_paged(
+					client,
+					PrefixedStorageKey::new(prefixed_top_key.as_ref().to_vec()),
+					Some(child_prefix.clone()),
+					Self::DEFAULT_KEY_DOWNLOAD_PAGE,
+					child_keys.last().cloned(),
+					Some(at),
+				)
+			};
+			let page = Retry::spawn(retry_strategy, get_page_closure).await.map_err(|e| {
+				error!(target: LOG_TARGET, "Error = {:?}", e);
+				"rpc child_get_keys failed."
+			})?;
+
+			let page_len = page.len();
+			child_keys.extend(page);
+			if page_len < Self::DEFAULT_KEY_DOWNLOAD_PAGE as usize {
+				break
+			}
+		}
 
 		debug!(
 			target: LOG_TARGET,
@@ -869,10 +1118,14 @@ where
 	///
 	/// This function concurrently populates `pending_ext`. the return value is only for writing to
 	/// cache, we can also optimize further.
+	///
+	/// Up to `PARALLEL_REQUESTS` child tries are downloaded at the same time. Each of them is
+	/// recorded to `progress` once downloaded, and taken from it if downloaded before.
 	async fn load_child_remote(
 		&self,
 		top_kv: &[KeyValue],
 		pending_ext: &mut TestExternalities<HashingFor<B>>,
+		progress: &mut ScrapeProgress,
 	) -> Result<ChildKeyValues, &'static str> {
 		let child_roots = top_kv
 			.iter()
@@ -894,15 +1147,34 @@ where
 		let at = self.as_online().at_expected();
 
 		let client = self.as_online().rpc_client();
-		let mut child_kv = vec![];
+		let mut downloaded = vec![];
+		let mut to_download = vec![];
 		for prefixed_top_key in child_roots {
-			let child_keys =
-				Self::rpc_child_get_keys(client, &prefixed_top_key, StorageKey(vec![]), at).await?;
+			match progress.take_child(prefixed_top_key.as_ref()) {
+				Some(child_kv_inner) => downloaded.push((prefixed_top_key, child_kv_inner)),
+				None => to_download.push(prefixed_top_key),
+			}
+		}
 
-			let child_kv_inner =
-				Self::rpc_child_get_storage_paged(client, &prefixed_top_key, child_keys, at)
-					.await?;
+		let mut downloads =
+			futures::stream::iter(to_download.into_iter().map(|prefixed_top_key| async move {
+				let child_keys =
+					Self::rpc_child_get_keys(client, &prefixed_top_key, StorageKey(vec![]), at)
+						.await?;
+				let child_kv_inner =
+					Self::rpc_child_get_storage_paged(client, &prefixed_top_key, child_keys, at)
+						.await?;
+				Ok::<_, &'static str>((prefixed_top_key, child_kv_inner))
+			}))
+			.buffer_unordered(Self::PARALLEL_REQUESTS);
+		while let Some(result) = downloads.next().await {
+			let (prefixed_top_key, child_kv_inner) = result?;
+			progress.record_child(prefixed_top_key.as_ref(), &child_kv_inner)?;
+			downloaded.push((prefixed_top_key, child_kv_inner));
+		}
 
+		let mut child_kv = vec![];
+		for (prefixed_top_key, child_kv_inner) in downloaded {
 			let prefixed_top_key = PrefixedStorageKey::new(prefixed_top_key.clone().0);
 			let un_prefixed = match ChildType::from_prefixed_key(&prefixed_top_key) {
 				Some((ChildType::ParentKeyId, storage_key)) => storage_key,
@@ -931,6 +1203,7 @@ where
 	async fn load_top_remote(
 		&self,
 		pending_ext: &mut TestExternalities<HashingFor<B>>,
+		progress: &mut ScrapeProgress,
 	) -> Result<TopKeyValues, &'static str> {
 		let config = self.as_online();
 		let at = self
@@ -942,8 +1215,9 @@ where
 		let mut keys_and_values = Vec::new();
 		for prefix in &config.hashed_prefixes {
 			let now = std::time::Instant::now();
-			let additional_key_values =
-				self.rpc_get_pairs(StorageKey(prefix.to_vec()), at, pending_ext).await?;
+			let additional_key_values = self
+				.rpc_get_pairs(StorageKey(prefix.to_vec()), at, pending_ext, progress)
+				.await?;
 			let elapsed = now.elapsed();
 			log::info!(
 				target: LOG_TARGET,
@@ -986,7 +1260,22 @@ where
 		// First, initialize the http client.
 		self.as_online_mut().transport.init().await?;
 
-		// Then, if `at` is not set, set it.
+		// Then, if `at` is not set, resume the interrupted scrape, if any.
+		if self.as_online().at.is_none() {
+			let resumed_at = self
+				.progress_path()
+				.and_then(|path| ScrapeProgress::block_hash::<B::Hash>(&path));
+			if let Some(at) = resumed_at {
+				log::info!(
+					target: LOG_TARGET,
+					"since no at is provided, setting it to the block of the interrupted scrape, {:?}",
+					at
+				);
+				self.as_online_mut().at = Some(at);
+			}
+		}
+
+		// Otherwise, if `at` is still not set, set it.
 		if self.as_online().at.is_none() {
 			let at = self.rpc_get_head().await?;
 			log::info!(
@@ -1047,9 +1336,11 @@ where
 			self.overwrite_state_version.unwrap_or(state_version),
 		);
 
-		// Load data from the remote into `pending_ext`.
-		let top_kv = self.load_top_remote(&mut pending_ext).await?;
-		self.load_child_remote(&top_kv, &mut pending_ext).await?;
+		// Load data from the remote into `pending_ext`, resuming a previous scrape if possible.
+		let mut progress =
+			ScrapeProgress::open(self.progress_path(), self.as_online().at_expected())?;
+		let top_kv = self.load_top_remote(&mut pending_ext, &mut progress).await?;
+		self.load_child_remote(&top_kv, &mut pending_ext, &mut progress).await?;
 
 		// If we need to save a snapshot, save the raw storage and root hash to the snapshot.
 		if let Some(path) = self.as_online().state_snapshot.clone().map(|c| c.path) {
@@ -1070,6 +1361,7 @@ where
 				path
 			);
 			std::fs::write(path, encoded).map_err(|_| "fs::write failed")?;
+			progress.finish();
 
 			// pending_ext was consumed when creating the snapshot, need to reinitailize it
 			return Ok(TestExternalities::from_raw_snapshot(
@@ -1082,6 +1374,14 @@ where
 		Ok(pending_ext)
 	}
 
+	/// The path of the scrape progress file, if a state snapshot is configured.
+	fn progress_path(&self) -> Option<PathBuf> {
+		self.as_online()
+			.state_snapshot
+			.as_ref()
+			.map(|c| ScrapeProgress::path_for(&c.path))
+	}
+
 	async fn do_load_remote(&mut self) -> Result<RemoteExternalities<B>, &'static str> {
 		self.init_remote_client().await?;
 		let block_hash = self.as_online().at_expected();
@@ -1257,6 +1557,44 @@ mod tests {
 			.expect("Can't read state snapshot file")
 			.execute_with(|| assert!(sp_io::storage::get(&some_key).is_none()));
 	}
+
+	#[test]
+	fn scrape_progress_is_resumed() {
+		init_logger();
+		let path = std::env::temp_dir().join("scrape_progress_is_resumed.progress");
+		let _ = fs::remove_file(&path);
+		let at = Hash::repeat_byte(1);
+		let kv = |k: u8| (StorageKey(vec![k]), StorageData(vec![k, k]));
+
+		let mut progress = ScrapeProgress::open(Some(path.clone()), at).unwrap();
+		progress.record_keys(b"prefix", &[kv(1).0, kv(2).0]).unwrap();
+		progress.record_values(b"prefix", &[kv(1)]).unwrap();
+		progress.record_child(b"child", &[kv(3)]).unwrap();
+		drop(progress);
+
+		// a partially written record is dropped.
+		let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+		file.write_all(&[2, 24]).unwrap();
+		drop(file);
+
+		let mut progress = ScrapeProgress::open(Some(path.clone()), at).unwrap();
+		assert_eq!(ScrapeProgress::block_hash::<Hash>(&path), Some(at));
+		assert_eq!(progress.take_keys(b"prefix"), Some(vec![kv(1).0, kv(2).0]));
+		assert_eq!(progress.take_values(b"prefix"), vec![kv(1)]);
+		assert_eq!(progress.take_child(b"child"), Some(vec![kv(3)]));
+		progress.record_values(b"prefix", &[kv(2)]).unwrap();
+		drop(progress);
+
+		let mut progress = ScrapeProgress::open(Some(path.clone()), at).unwrap();
+		assert_eq!(progress.take_values(b"prefix"), vec![kv(1), kv(2)]);
+		drop(progress);
+
+		// the progress of another block is discarded.
+		let mut progress = ScrapeProgress::open(Some(path.clone()), Hash::repeat_byte(2)).unwrap();
+		assert_eq!(progress.take_keys(b"prefix"), None);
+		progress.finish();
+		assert!(!path.exists());
+	}
 }
 
 #[cfg(all(test, feature = "remote-test"))]
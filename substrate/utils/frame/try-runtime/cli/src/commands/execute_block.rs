@@ -60,6 +60,19 @@ pub struct ExecuteBlockCmd {
 	)]
 	pub block_ws_uri: Option<String>,
 
+	/// Measure the weight used by the `on_initialize`, `on_idle` and `on_finalize` hooks of each
+	/// pallet, and log the hooks that use more weight than they declared.
+	///
+	/// This calls into `TryRuntime_execute_block_measuring_hooks` instead, which is part of
+	/// version 2 of the `TryRuntime` runtime api. The weight is measured with the benchmarking
+	/// host functions, so `HostFns` has to include them.
+	#[arg(long)]
+	pub measure_hooks: bool,
+
+	/// Fail if a hook uses more weight than it declared.
+	#[arg(long, requires = "measure_hooks")]
+	pub fail_on_underestimated_hooks: bool,
+
 	/// The state type to use.
 	#[command(subcommand)]
 	pub state: State,
@@ -124,12 +137,29 @@ where
 	// for now, hardcoded for the sake of simplicity. We might customize them one day.
 	let state_root_check = false;
 	let signature_check = false;
-	let payload = (block.clone(), state_root_check, signature_check, command.try_state).encode();
+	let (method, payload) = if command.measure_hooks {
+		(
+			"TryRuntime_execute_block_measuring_hooks",
+			(
+				block.clone(),
+				state_root_check,
+				signature_check,
+				command.try_state,
+				command.fail_on_underestimated_hooks,
+			)
+				.encode(),
+		)
+	} else {
+		(
+			"TryRuntime_execute_block",
+			(block.clone(), state_root_check, signature_check, command.try_state).encode(),
+		)
+	};
 
 	let _ = state_machine_call_with_proof::<Block, HostFns>(
 		&ext,
 		&executor,
-		"TryRuntime_execute_block",
+		method,
 		&payload,
 		full_extensions(executor.clone()),
 		shared.export_proof,
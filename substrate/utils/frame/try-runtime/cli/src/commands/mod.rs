@@ -21,3 +21,4 @@ pub mod fast_forward;
 pub mod follow_chain;
 pub mod offchain_worker;
 pub mod on_runtime_upgrade;
+pub mod replay_blocks;
@@ -15,7 +15,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{build_executor, state_machine_call_with_proof, SharedParams, State, LOG_TARGET};
+use crate::{
+	build_executor, humanize_bytes, state_machine_call_with_proof_size, ProofSize, SharedParams,
+	State, LOG_TARGET,
+};
 use frame_try_runtime::UpgradeCheckSelect;
 use parity_scale_codec::{Decode, Encode};
 use sc_executor::sp_wasm_interface::HostFunctions;
@@ -47,6 +50,18 @@ pub struct OnRuntimeUpgradeCmd {
 		require_equals = true,
 		verbatim_doc_comment)]
 	pub checks: UpgradeCheckSelect,
+
+	/// The maximum proof size in bytes that the migrations may consume, e.g. the PoV limit of a
+	/// parachain.
+	///
+	/// Defaults to the proof size of the maximum block weight of the runtime.
+	#[arg(long)]
+	pub max_proof_size: Option<u64>,
+
+	/// Only report the consumed weight and proof size, without failing if they exceed the block
+	/// limits.
+	#[arg(long)]
+	pub no_weight_limit_checks: bool,
 }
 
 pub(crate) async fn on_runtime_upgrade<Block, HostFns>(
@@ -64,7 +79,7 @@ where
 	let executor = build_executor(&shared);
 	let ext = command.state.into_ext::<Block, HostFns>(&shared, &executor, None, true).await?;
 
-	let (_, encoded_result) = state_machine_call_with_proof::<Block, HostFns>(
+	let (_, encoded_result, proof_size) = state_machine_call_with_proof_size::<Block, HostFns>(
 		&ext,
 		&executor,
 		"TryRuntime_on_runtime_upgrade",
@@ -85,5 +100,97 @@ where
 		(weight.proof_size() as f64 / total_weight.proof_size().max(1) as f64) * 100.0,
 	);
 
-	Ok(())
+	let max_proof_size = command.max_proof_size.unwrap_or(total_weight.proof_size());
+	let limits =
+		UpgradeLimits { weight, proof_size, max_ref_time: total_weight.ref_time(), max_proof_size };
+	limits.report(command.checks.any());
+
+	let exceeded = limits.exceeded();
+	if exceeded.is_empty() {
+		return Ok(())
+	}
+
+	for limit in &exceeded {
+		log::error!(target: LOG_TARGET, "❌ Runtime upgrade exceeds the block limits: {}", limit);
+	}
+	if command.no_weight_limit_checks {
+		log::warn!(target: LOG_TARGET, "Ignoring exceeded block limits, as requested");
+		return Ok(())
+	}
+
+	Err("runtime upgrade exceeds the block limits".into())
+}
+
+/// The weight and proof size consumed by a runtime upgrade, together with the block limits they
+/// must fit into.
+struct UpgradeLimits {
+	/// The weight reported by the migrations.
+	weight: Weight,
+	/// The storage proof recorded while executing the migrations.
+	proof_size: ProofSize,
+	/// The ref-time of the maximum block weight.
+	max_ref_time: u64,
+	/// The maximum proof size.
+	max_proof_size: u64,
+}
+
+impl UpgradeLimits {
+	fn report(&self, checks: bool) {
+		let percent = |value: u64, max: u64| value as f64 / max.max(1) as f64 * 100.0;
+		log::info!(
+			target: LOG_TARGET,
+			"📊 Ref-time: {} ps of {} ps ({:.2} %)",
+			self.weight.ref_time(),
+			self.max_ref_time,
+			percent(self.weight.ref_time(), self.max_ref_time),
+		);
+		log::info!(
+			target: LOG_TARGET,
+			"📊 Proof size (weight): {} of {} ({:.2} %)",
+			humanize_bytes(self.weight.proof_size() as usize),
+			humanize_bytes(self.max_proof_size as usize),
+			percent(self.weight.proof_size(), self.max_proof_size),
+		);
+		log::info!(
+			target: LOG_TARGET,
+			"📊 Proof size (measured): {} of {} ({:.2} %), compact {}, zstd-compressed {}",
+			humanize_bytes(self.proof_size.proof),
+			humanize_bytes(self.max_proof_size as usize),
+			percent(self.proof_size.proof as u64, self.max_proof_size),
+			humanize_bytes(self.proof_size.compact),
+			humanize_bytes(self.proof_size.compressed),
+		);
+		if checks {
+			log::warn!(
+				target: LOG_TARGET,
+				"The measured proof size includes the storage accessed by the checks, use `--checks=none` to only measure the migrations"
+			);
+		}
+	}
+
+	/// Descriptions of the limits that are exceeded.
+	fn exceeded(&self) -> Vec<String> {
+		let mut exceeded = Vec::new();
+		if self.weight.ref_time() > self.max_ref_time {
+			exceeded.push(format!(
+				"ref-time {} ps > {} ps",
+				self.weight.ref_time(),
+				self.max_ref_time
+			));
+		}
+		if self.weight.proof_size() > self.max_proof_size {
+			exceeded.push(format!(
+				"weight proof size {} bytes > {} bytes",
+				self.weight.proof_size(),
+				self.max_proof_size
+			));
+		}
+		if self.proof_size.proof as u64 > self.max_proof_size {
+			exceeded.push(format!(
+				"measured proof size {} bytes > {} bytes",
+				self.proof_size.proof, self.max_proof_size
+			));
+		}
+		exceeded
+	}
 }
@@ -0,0 +1,228 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+	build_executor, commands::execute_block::next_hash_of, full_extensions, parse, rpc_err_handler,
+	state_machine_call_with_proof, LiveState, SharedParams, State, LOG_TARGET,
+};
+use parity_scale_codec::{Decode, Encode};
+use sc_executor::sp_wasm_interface::HostFunctions;
+use sp_core::storage::ChildInfo;
+use sp_runtime::{
+	generic::SignedBlock,
+	traits::{Block as BlockT, Header as HeaderT, NumberFor},
+};
+use sp_state_machine::{Backend, StorageCollection};
+use std::{fmt::Debug, io::Write, path::PathBuf, str::FromStr};
+use substrate_rpc_client::{ws_client, ChainApi};
+
+/// Configurations of the [`crate::Command::ReplayBlocks`].
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ReplayBlocksCmd {
+	/// The number of the last block to replay (inclusive).
+	///
+	/// The first replayed block is always the one following the block of `state`.
+	#[arg(long)]
+	pub to: u64,
+
+	/// The ws uri from which to fetch the blocks.
+	///
+	/// If `state` is `Live`, this can be ignored and the same uri is used for both.
+	#[arg(long, value_parser = parse::url)]
+	pub block_ws_uri: Option<String>,
+
+	/// If set, then the state root check is enabled.
+	///
+	/// The replay stops at the first block whose state root doesn't match.
+	#[arg(long)]
+	pub state_root_check: bool,
+
+	/// Which try-state targets to execute when running this command.
+	///
+	/// Expected values:
+	/// - `all`
+	/// - `none`
+	/// - A comma separated list of pallets, as per pallet names in `construct_runtime!()` (e.g.
+	///   `Staking, System`).
+	/// - `rr-[x]` where `[x]` is a number. Then, the given number of pallets are checked in a
+	///   round-robin fashion.
+	#[arg(long, default_value = "none")]
+	pub try_state: frame_try_runtime::TryStateSelect,
+
+	/// File to write the per-block reports to, one JSON object per line.
+	///
+	/// If not provided, the reports are written to stdout.
+	#[arg(long)]
+	pub output: Option<PathBuf>,
+
+	/// The state type to use.
+	#[command(subcommand)]
+	pub state: State,
+}
+
+impl ReplayBlocksCmd {
+	fn block_ws_uri(&self) -> &str {
+		match (&self.block_ws_uri, &self.state) {
+			(Some(block_ws_uri), _) => block_ws_uri,
+			(None, State::Live(LiveState { uri, .. })) => uri,
+			(None, State::Snap { .. }) => {
+				panic!("either `--block-ws-uri` must be provided, or state must be `live`");
+			},
+		}
+	}
+}
+
+/// Encodes `data` as a `0x` prefixed hex string.
+fn hex_string(data: &[u8]) -> String {
+	format!("0x{}", hex::encode(data))
+}
+
+/// The JSON report of the changes of a single storage trie.
+///
+/// `read_old` returns the value of a key before the block was executed.
+fn changes_report(
+	changes: &StorageCollection,
+	read_old: impl Fn(&[u8]) -> sc_cli::Result<Option<Vec<u8>>>,
+) -> sc_cli::Result<Vec<serde_json::Value>> {
+	changes
+		.iter()
+		.map(|(key, new)| {
+			let old = read_old(key)?;
+			Ok(serde_json::json!({
+				"key": hex_string(key),
+				"old": old.as_deref().map(hex_string),
+				"new": new.as_deref().map(hex_string),
+			}))
+		})
+		.collect()
+}
+
+pub(crate) async fn replay_blocks<Block, HostFns>(
+	shared: SharedParams,
+	command: ReplayBlocksCmd,
+) -> sc_cli::Result<()>
+where
+	Block: BlockT + serde::de::DeserializeOwned,
+	<Block::Hash as FromStr>::Err: Debug,
+	Block::Hash: serde::de::DeserializeOwned,
+	Block::Header: serde::de::DeserializeOwned,
+	<NumberFor<Block> as TryInto<u64>>::Error: Debug,
+	HostFns: HostFunctions,
+{
+	let executor = build_executor::<HostFns>(&shared);
+	let mut ext = command.state.into_ext::<Block, HostFns>(&shared, &executor, None, true).await?;
+
+	let rpc = ws_client(command.block_ws_uri()).await?;
+	let mut output: Box<dyn Write> = match command.output {
+		Some(ref path) => Box::new(std::fs::File::create(path).map_err(|e| {
+			format!("failed to create output file {}: {:?}", path.to_string_lossy(), e)
+		})?),
+		None => Box::new(std::io::stdout()),
+	};
+
+	let mut parent_hash = ext.block_hash;
+	loop {
+		let hash = next_hash_of::<Block>(&rpc, parent_hash).await?;
+		let block =
+			ChainApi::<(), Block::Hash, Block::Header, SignedBlock<Block>>::block(&rpc, Some(hash))
+				.await
+				.map_err(rpc_err_handler)?
+				.expect("header exists, block should also exist; qed")
+				.block;
+
+		let number: u64 = (*block.header().number())
+			.try_into()
+			.map_err(|_| "failed to convert block number to u64")?;
+		if number > command.to {
+			break
+		}
+
+		// A digest item gets added when the runtime is processing the block, so we need to pop
+		// the last one to be consistent with what a gossiped block would contain.
+		let (mut header, extrinsics) = block.deconstruct();
+		header.digest_mut().pop();
+		let block = Block::new(header, extrinsics);
+
+		let signature_check = false;
+		let payload =
+			(block, command.state_root_check, signature_check, command.try_state.clone()).encode();
+		let (mut changes, encoded_result) = state_machine_call_with_proof::<Block, HostFns>(
+			&ext,
+			&executor,
+			"TryRuntime_execute_block",
+			&payload,
+			full_extensions(executor.clone()),
+			shared
+				.export_proof
+				.as_ref()
+				.map(|path| path.as_path().join(&format!("{}.json", number))),
+		)
+		.map_err(|e| format!("failed to execute block {} ({:?}): {}", number, hash, e))?;
+
+		let consumed_weight = <sp_weights::Weight as Decode>::decode(&mut &*encoded_result)
+			.map_err(|e| format!("failed to decode weight: {:?}", e))?;
+
+		let storage_changes = changes.drain_storage_changes(&ext.backend, ext.state_version)?;
+
+		// The old values have to be read before the changes are applied.
+		let main_changes = changes_report(&storage_changes.main_storage_changes, |key| {
+			Ok(ext.backend.storage(key)?)
+		})?;
+		let child_changes = storage_changes
+			.child_storage_changes
+			.iter()
+			.map(|(storage_key, child_changes)| {
+				let child_info = ChildInfo::new_default(storage_key);
+				let changes = changes_report(child_changes, |key| {
+					Ok(ext.backend.child_storage(&child_info, key)?)
+				})?;
+				Ok(serde_json::json!({ "child": hex_string(storage_key), "changes": changes }))
+			})
+			.collect::<sc_cli::Result<Vec<_>>>()?;
+
+		ext.backend.apply_transaction(
+			storage_changes.transaction_storage_root,
+			storage_changes.transaction,
+		);
+
+		log::info!(
+			target: LOG_TARGET,
+			"replayed block {} ({:?}), consumed weight {}, {} storage changes",
+			number,
+			hash,
+			consumed_weight,
+			main_changes.len(),
+		);
+
+		let report = serde_json::json!({
+			"number": number,
+			"hash": hex_string(hash.as_ref()),
+			"weight": {
+				"ref_time": consumed_weight.ref_time(),
+				"proof_size": consumed_weight.proof_size(),
+			},
+			"storage_root": hex_string(storage_changes.transaction_storage_root.as_ref()),
+			"changes": main_changes,
+			"child_changes": child_changes,
+		});
+		writeln!(output, "{}", report)?;
+
+		parent_hash = hash;
+	}
+
+	Ok(())
+}
@@ -74,6 +74,9 @@ pub enum Command {
 	/// only triggers all of the `on_runtime_upgrade` hooks in the runtime, and optionally
 	/// `try_state`.
 	///
+	/// The consumed weight and the recorded storage proof size are compared against the block
+	/// limits of the runtime, and the command fails if the migrations do not fit into a block.
+	///
 	/// See [`frame_try_runtime::TryRuntime`] and
 	/// [`commands::on_runtime_upgrade::OnRuntimeUpgradeCmd`] for more information.
 	OnRuntimeUpgrade(commands::on_runtime_upgrade::OnRuntimeUpgradeCmd),
@@ -125,6 +128,19 @@ pub enum Command {
 	///    extrinsics; both things should be defined in your node CLI handling level
 	FastForward(commands::fast_forward::FastForwardCmd),
 
+	/// Replay a contiguous range of blocks on top of some state and report what each block did.
+	///
+	/// This is essentially repeated calls to [`Command::ExecuteBlock`] on the same growing state,
+	/// starting with the block that follows the state and ending with the block `--to`.
+	///
+	/// For every block, one JSON object is written containing the consumed weight, the storage
+	/// root and the storage diff (the old and new value of every changed key, including child
+	/// tries). This makes it possible to bisect which block introduced a state divergence or a
+	/// weight blow-up, e.g. by diffing the output of two runtimes.
+	///
+	/// See [`commands::replay_blocks::ReplayBlocksCmd`] for more information.
+	ReplayBlocks(commands::replay_blocks::ReplayBlocksCmd),
+
 	/// Create a new snapshot file.
 	CreateSnapshot(commands::create_snapshot::CreateSnapshotCmd),
 }
@@ -447,6 +463,12 @@ impl TryRuntimeCmd {
 					block_building_info_provider,
 				)
 				.await,
+			Command::ReplayBlocks(cmd) =>
+				commands::replay_blocks::replay_blocks::<Block, HostFns>(
+					self.shared.clone(),
+					cmd.clone(),
+				)
+				.await,
 			Command::CreateSnapshot(cmd) =>
 				commands::create_snapshot::create_snapshot::<Block, HostFns>(
 					self.shared.clone(),
@@ -565,9 +587,40 @@ pub(crate) fn state_machine_call_with_proof<Block: BlockT, HostFns: HostFunction
 	executor: &WasmExecutor<HostFns>,
 	method: &'static str,
 	data: &[u8],
-	mut extensions: Extensions,
+	extensions: Extensions,
 	maybe_export_proof: Option<PathBuf>,
 ) -> sc_cli::Result<(OverlayedChanges<HashingFor<Block>>, Vec<u8>)> {
+	state_machine_call_with_proof_size::<Block, HostFns>(
+		ext,
+		executor,
+		method,
+		data,
+		extensions,
+		maybe_export_proof,
+	)
+	.map(|(changes, encoded_results, _)| (changes, encoded_results))
+}
+
+/// The sizes of the storage proof recorded by [`state_machine_call_with_proof_size`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ProofSize {
+	/// The size of the encoded storage proof.
+	pub(crate) proof: usize,
+	/// The size of the encoded compact storage proof, as it is included in a parachain PoV.
+	pub(crate) compact: usize,
+	/// The size of the zstd-compressed compact storage proof.
+	pub(crate) compressed: usize,
+}
+
+/// Same as [`state_machine_call_with_proof`], but it also returns the sizes of the storage proof.
+pub(crate) fn state_machine_call_with_proof_size<Block: BlockT, HostFns: HostFunctions>(
+	ext: &TestExternalities<HashingFor<Block>>,
+	executor: &WasmExecutor<HostFns>,
+	method: &'static str,
+	data: &[u8],
+	mut extensions: Extensions,
+	maybe_export_proof: Option<PathBuf>,
+) -> sc_cli::Result<(OverlayedChanges<HashingFor<Block>>, Vec<u8>, ProofSize)> {
 	use parity_scale_codec::Encode;
 
 	let mut changes = Default::default();
@@ -646,35 +699,42 @@ pub(crate) fn state_machine_call_with_proof<Block: BlockT, HostFns: HostFunction
 
 	let proof_nodes = proof.into_nodes();
 
-	let humanize = |s| {
-		if s < 1024 * 1024 {
-			format!("{:.2} KB ({} bytes)", s as f64 / 1024f64, s)
-		} else {
-			format!(
-				"{:.2} MB ({} KB) ({} bytes)",
-				s as f64 / (1024f64 * 1024f64),
-				s as f64 / 1024f64,
-				s
-			)
-		}
-	};
 	log::debug!(
 		target: LOG_TARGET,
 		"proof: 0x{}... / {} nodes",
 		HexDisplay::from(&proof_nodes.iter().flatten().cloned().take(10).collect::<Vec<_>>()),
 		proof_nodes.len()
 	);
-	log::debug!(target: LOG_TARGET, "proof size: {}", humanize(proof_size));
-	log::debug!(target: LOG_TARGET, "compact proof size: {}", humanize(compact_proof_size),);
+	log::debug!(target: LOG_TARGET, "proof size: {}", humanize_bytes(proof_size));
+	log::debug!(target: LOG_TARGET, "compact proof size: {}", humanize_bytes(compact_proof_size),);
 	log::debug!(
 		target: LOG_TARGET,
 		"zstd-compressed compact proof {}",
-		humanize(compressed_proof.len()),
+		humanize_bytes(compressed_proof.len()),
 	);
 
 	log::debug!(target: LOG_TARGET, "{} executed without errors.", method);
 
-	Ok((changes, encoded_results))
+	let proof_size = ProofSize {
+		proof: proof_size,
+		compact: compact_proof_size,
+		compressed: compressed_proof.len(),
+	};
+	Ok((changes, encoded_results, proof_size))
+}
+
+/// Format a number of bytes in a human readable way.
+pub(crate) fn humanize_bytes(s: usize) -> String {
+	if s < 1024 * 1024 {
+		format!("{:.2} KB ({} bytes)", s as f64 / 1024f64, s)
+	} else {
+		format!(
+			"{:.2} MB ({} KB) ({} bytes)",
+			s as f64 / (1024f64 * 1024f64),
+			s as f64 / 1024f64,
+			s
+		)
+	}
 }
 
 pub(crate) fn rpc_err_handler(error: impl Debug) -> &'static str {
@@ -0,0 +1,488 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compare two versions of weight files that have been generated by `benchmark pallet`.
+//!
+//! The weight functions of both versions are parsed and evaluated at the upper end of their
+//! component ranges. Extrinsics whose ref-time or proof-size changed by more than a threshold, or
+//! whose number of storage reads or writes changed, are reported as JSON or markdown.
+//!
+//! ```sh
+//! cargo run -p frame-weight-diff -- old/weights new/weights --threshold 5 --fail-on-regression
+//! ```
+
+use regex::Regex;
+use serde::Serialize;
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	fmt::Write,
+	fs,
+	path::Path,
+};
+
+/// The cost of a weight function, or the cost per unit of one of its components.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cost {
+	/// Ref-time in picoseconds, without the storage reads and writes.
+	pub ref_time: u128,
+	/// Proof-size in bytes.
+	pub proof_size: u128,
+	/// Number of storage reads.
+	pub reads: u128,
+	/// Number of storage writes.
+	pub writes: u128,
+}
+
+impl Cost {
+	fn add(&mut self, other: Cost, factor: u128) {
+		self.ref_time = self.ref_time.saturating_add(other.ref_time.saturating_mul(factor));
+		self.proof_size = self.proof_size.saturating_add(other.proof_size.saturating_mul(factor));
+		self.reads = self.reads.saturating_add(other.reads.saturating_mul(factor));
+		self.writes = self.writes.saturating_add(other.writes.saturating_mul(factor));
+	}
+}
+
+/// A parsed weight function.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WeightFn {
+	/// The cost that does not depend on any component.
+	pub base: Cost,
+	/// The cost per unit of each component.
+	pub slopes: BTreeMap<String, Cost>,
+	/// The benchmarked `[min, max]` range of each component.
+	pub ranges: BTreeMap<String, (u128, u128)>,
+}
+
+impl WeightFn {
+	/// The cost at the upper end of all component ranges.
+	///
+	/// Components without a known range are evaluated at zero.
+	pub fn worst_case(&self) -> Cost {
+		let mut cost = self.base;
+		for (component, slope) in &self.slopes {
+			let max = self.ranges.get(component).map_or(0, |(_, max)| *max);
+			cost.add(*slope, max);
+		}
+		cost
+	}
+}
+
+/// Parse the weight functions of a generated weight file.
+///
+/// Only the first implementation of each function is used, so the `impl WeightInfo for ()`
+/// fallback of pallet weight files is ignored.
+pub fn parse_weight_file(content: &str) -> BTreeMap<String, WeightFn> {
+	let range_re = Regex::new(r"The range of component `(\w+)` is `\[(\d+), (\d+)\]`")
+		.expect("regex is valid; qed");
+	let fn_re = Regex::new(r"^fn (\w+)\(.*\)\s*->\s*Weight\s*\{").expect("regex is valid; qed");
+
+	let mut weight_fns = BTreeMap::new();
+	let mut ranges = BTreeMap::new();
+	let mut current: Option<(String, String)> = None;
+	let mut depth = 0usize;
+
+	for line in content.lines() {
+		let line = line.trim();
+
+		if let Some((name, body)) = current.as_mut() {
+			if !line.starts_with("//") {
+				depth += line.matches('{').count();
+				depth = depth.saturating_sub(line.matches('}').count());
+				body.push_str(line);
+			}
+			if depth == 0 {
+				let (name, body) = (std::mem::take(name), std::mem::take(body));
+				current = None;
+				let weight_fn = parse_body(&body, std::mem::take(&mut ranges));
+				weight_fns.entry(name).or_insert(weight_fn);
+			}
+			continue
+		}
+
+		if let Some(captures) = range_re.captures(line) {
+			let (Ok(min), Ok(max)) = (captures[2].parse(), captures[3].parse()) else { continue };
+			ranges.insert(captures[1].to_string(), (min, max));
+		} else if let Some(captures) = fn_re.captures(line) {
+			current = Some((captures[1].to_string(), String::new()));
+			depth = 1;
+		} else if !line.starts_with("///") && !line.starts_with("#[") {
+			ranges.clear();
+		}
+	}
+
+	weight_fns
+}
+
+/// Parse the body of a weight function into its base cost and slopes.
+fn parse_body(body: &str, ranges: BTreeMap<String, (u128, u128)>) -> WeightFn {
+	let parts_re = Regex::new(
+		r"Weight::from_parts\(([\d_]+)(?:_u64)?,([\d_]+)(?:_u64)?\)(?:\.saturating_mul\((\w+)\.into\(\)\))?",
+	)
+	.expect("regex is valid; qed");
+	let ref_time_re = Regex::new(
+		r"Weight::from_ref_time\(([\d_]+)(?:_u64)?\)(?:\.saturating_mul\((\w+)\.into\(\)\))?",
+	)
+	.expect("regex is valid; qed");
+	let db_re = Regex::new(
+		r"DbWeight::get\(\)\.(reads|writes)\(\(?([\d_]+)(?:_u64)?\)?(?:\.saturating_mul\((\w+)\.into\(\)\))?\)",
+	)
+	.expect("regex is valid; qed");
+	let reads_writes_re =
+		Regex::new(r"DbWeight::get\(\)\.reads_writes\(([\d_]+)(?:_u64)?,([\d_]+)(?:_u64)?\)")
+			.expect("regex is valid; qed");
+
+	let body: String = body.chars().filter(|c| !c.is_whitespace()).collect();
+	let mut weight_fn = WeightFn { ranges, ..Default::default() };
+	let mut add = |component: Option<&str>, cost: Cost| match component {
+		Some(component) => weight_fn.slopes.entry(component.to_string()).or_default().add(cost, 1),
+		None => weight_fn.base.add(cost, 1),
+	};
+
+	for captures in parts_re.captures_iter(&body) {
+		let cost = Cost {
+			ref_time: parse_number(&captures[1]),
+			proof_size: parse_number(&captures[2]),
+			..Default::default()
+		};
+		add(captures.get(3).map(|m| m.as_str()), cost);
+	}
+	for captures in ref_time_re.captures_iter(&body) {
+		let cost = Cost { ref_time: parse_number(&captures[1]), ..Default::default() };
+		add(captures.get(2).map(|m| m.as_str()), cost);
+	}
+	for captures in db_re.captures_iter(&body) {
+		let count = parse_number(&captures[2]);
+		let cost = match &captures[1] {
+			"reads" => Cost { reads: count, ..Default::default() },
+			_ => Cost { writes: count, ..Default::default() },
+		};
+		add(captures.get(3).map(|m| m.as_str()), cost);
+	}
+	for captures in reads_writes_re.captures_iter(&body) {
+		let cost = Cost {
+			reads: parse_number(&captures[1]),
+			writes: parse_number(&captures[2]),
+			..Default::default()
+		};
+		add(None, cost);
+	}
+
+	weight_fn
+}
+
+fn parse_number(number: &str) -> u128 {
+	number.replace('_', "").parse().unwrap_or_default()
+}
+
+/// How an extrinsic changed between the two versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeKind {
+	/// The extrinsic only exists in the new version.
+	Added,
+	/// The extrinsic only exists in the old version.
+	Removed,
+	/// The worst-case cost of the extrinsic changed.
+	Changed,
+}
+
+/// The change of one extrinsic.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Change {
+	/// The weight file of the extrinsic.
+	pub file: String,
+	/// The name of the weight function.
+	pub extrinsic: String,
+	/// How the extrinsic changed.
+	pub kind: ChangeKind,
+	/// The worst-case cost in the old version.
+	pub old: Option<Cost>,
+	/// The worst-case cost in the new version.
+	pub new: Option<Cost>,
+	/// The relative ref-time change in percent, `None` if the old ref-time is zero.
+	pub ref_time_change: Option<f64>,
+	/// The relative proof-size change in percent, `None` if the old proof-size is zero.
+	pub proof_size_change: Option<f64>,
+}
+
+impl Change {
+	/// Whether the ref-time or proof-size increased by more than `threshold` percent.
+	pub fn is_regression(&self, threshold: f64) -> bool {
+		let exceeds = |change: Option<f64>, old: u128, new: u128| match change {
+			Some(change) => change > threshold,
+			None => new > old,
+		};
+		match (self.old, self.new) {
+			(Some(old), Some(new)) =>
+				exceeds(self.ref_time_change, old.ref_time, new.ref_time) ||
+					exceeds(self.proof_size_change, old.proof_size, new.proof_size),
+			_ => false,
+		}
+	}
+}
+
+fn percent_change(old: u128, new: u128) -> Option<f64> {
+	match (old, new) {
+		(0, 0) => Some(0.0),
+		(0, _) => None,
+		(old, new) => Some((new as f64 - old as f64) * 100.0 / old as f64),
+	}
+}
+
+/// Compare the weight functions of one weight file.
+///
+/// Returns the extrinsics that were added or removed, whose ref-time or proof-size changed by
+/// more than `threshold` percent, or whose number of storage reads or writes changed.
+pub fn compare(
+	file: &str,
+	old: &BTreeMap<String, WeightFn>,
+	new: &BTreeMap<String, WeightFn>,
+	threshold: f64,
+) -> Vec<Change> {
+	let extrinsics = old.keys().chain(new.keys()).collect::<BTreeSet<_>>();
+
+	extrinsics
+		.into_iter()
+		.filter_map(|extrinsic| {
+			let old = old.get(extrinsic).map(WeightFn::worst_case);
+			let new = new.get(extrinsic).map(WeightFn::worst_case);
+			let (kind, ref_time_change, proof_size_change) = match (old, new) {
+				(Some(old), Some(new)) => {
+					let ref_time_change = percent_change(old.ref_time, new.ref_time);
+					let proof_size_change = percent_change(old.proof_size, new.proof_size);
+					let significant =
+						|change: Option<f64>| change.map_or(true, |c| c.abs() > threshold);
+					if !significant(ref_time_change) &&
+						!significant(proof_size_change) &&
+						old.reads == new.reads &&
+						old.writes == new.writes
+					{
+						return None
+					}
+					(ChangeKind::Changed, ref_time_change, proof_size_change)
+				},
+				(None, Some(_)) => (ChangeKind::Added, None, None),
+				(Some(_), None) => (ChangeKind::Removed, None, None),
+				(None, None) => return None,
+			};
+
+			Some(Change {
+				file: file.to_string(),
+				extrinsic: extrinsic.clone(),
+				kind,
+				old,
+				new,
+				ref_time_change,
+				proof_size_change,
+			})
+		})
+		.collect()
+}
+
+/// Compare two weight files, or all weight files with the same name in two directories.
+///
+/// Files that only exist in one of the directories are ignored.
+pub fn compare_paths(old: &Path, new: &Path, threshold: f64) -> Result<Vec<Change>, String> {
+	let read = |path: &Path| {
+		fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {e}", path.display()))
+	};
+
+	if old.is_file() && new.is_file() {
+		let file = new.file_name().map(|f| f.to_string_lossy().into_owned()).unwrap_or_default();
+		return Ok(compare(
+			&file,
+			&parse_weight_file(&read(old)?),
+			&parse_weight_file(&read(new)?),
+			threshold,
+		))
+	}
+
+	if !old.is_dir() || !new.is_dir() {
+		return Err("Both paths must either be weight files or directories".into())
+	}
+
+	let mut files = fs::read_dir(new)
+		.map_err(|e| format!("Failed to read {}: {e}", new.display()))?
+		.filter_map(|entry| entry.ok().map(|entry| entry.file_name()))
+		.filter(|file| Path::new(file).extension().map_or(false, |ext| ext == "rs"))
+		.filter(|file| old.join(file).is_file())
+		.collect::<Vec<_>>();
+	files.sort();
+
+	let mut changes = Vec::new();
+	for file in files {
+		changes.extend(compare(
+			&file.to_string_lossy(),
+			&parse_weight_file(&read(&old.join(&file))?),
+			&parse_weight_file(&read(&new.join(&file))?),
+			threshold,
+		));
+	}
+	Ok(changes)
+}
+
+/// Render the changes as a markdown table.
+pub fn render_markdown(changes: &[Change]) -> String {
+	fn format_change(change: Option<f64>, old: Option<u128>, new: Option<u128>) -> String {
+		match (change, old, new) {
+			(Some(change), _, _) => format!("{change:+.2}%"),
+			(None, Some(_), Some(_)) => "from zero".into(),
+			_ => "-".into(),
+		}
+	}
+	fn format_value(old: Option<u128>, new: Option<u128>) -> String {
+		let format = |value: Option<u128>| value.map_or("-".into(), |v| v.to_string());
+		format!("{} → {}", format(old), format(new))
+	}
+
+	if changes.is_empty() {
+		return "No significant weight changes.\n".into()
+	}
+
+	let mut out = String::new();
+	let _ = writeln!(
+		out,
+		"| File | Extrinsic | Change | Ref-time [ps] | Δ ref-time | Proof-size [B] | Δ proof-size | Reads | Writes |"
+	);
+	let _ = writeln!(out, "|---|---|---|---|---|---|---|---|---|");
+	for change in changes {
+		let field = |f: fn(&Cost) -> u128| (change.old.as_ref().map(f), change.new.as_ref().map(f));
+		let (old_ref_time, new_ref_time) = field(|c| c.ref_time);
+		let (old_proof_size, new_proof_size) = field(|c| c.proof_size);
+		let (old_reads, new_reads) = field(|c| c.reads);
+		let (old_writes, new_writes) = field(|c| c.writes);
+		let kind = match change.kind {
+			ChangeKind::Added => "added",
+			ChangeKind::Removed => "removed",
+			ChangeKind::Changed => "changed",
+		};
+
+		let _ = writeln!(
+			out,
+			"| {} | `{}` | {} | {} | {} | {} | {} | {} | {} |",
+			change.file,
+			change.extrinsic,
+			kind,
+			format_value(old_ref_time, new_ref_time),
+			format_change(change.ref_time_change, old_ref_time, new_ref_time),
+			format_value(old_proof_size, new_proof_size),
+			format_change(change.proof_size_change, old_proof_size, new_proof_size),
+			format_value(old_reads, new_reads),
+			format_value(old_writes, new_writes),
+		);
+	}
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const OLD: &str = r#"
+impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	fn create() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `107`
+		//  Estimated: `4273`
+		// Minimum execution time: 30_485_000 picoseconds.
+		Weight::from_parts(31_007_000, 0)
+			.saturating_add(Weight::from_parts(0, 4273))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// The range of component `c` is `[0, 1000]`.
+	fn destroy_accounts(c: u32, ) -> Weight {
+		Weight::from_parts(18_791_000, 0)
+			.saturating_add(Weight::from_parts(0, 4273))
+			// Standard Error: 5_059
+			.saturating_add(Weight::from_parts(12_049_659, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 3207).saturating_mul(c.into()))
+	}
+	fn touch() -> Weight {
+		Weight::from_parts(10_000_000, 1_000)
+	}
+}
+
+impl WeightInfo for () {
+	fn create() -> Weight {
+		Weight::from_parts(1, 1)
+	}
+}
+"#;
+
+	#[test]
+	fn parse_weight_file_works() {
+		let weight_fns = parse_weight_file(OLD);
+		assert_eq!(weight_fns.len(), 3);
+
+		assert_eq!(
+			weight_fns["create"].worst_case(),
+			Cost { ref_time: 31_007_000, proof_size: 4273, reads: 3, writes: 2 }
+		);
+
+		let destroy_accounts = &weight_fns["destroy_accounts"];
+		assert_eq!(destroy_accounts.ranges["c"], (0, 1000));
+		assert_eq!(
+			destroy_accounts.slopes["c"],
+			Cost { ref_time: 12_049_659, proof_size: 3207, reads: 2, writes: 2 }
+		);
+		assert_eq!(
+			destroy_accounts.worst_case(),
+			Cost {
+				ref_time: 18_791_000 + 12_049_659 * 1000,
+				proof_size: 4273 + 3207 * 1000,
+				reads: 2 + 2 * 1000,
+				writes: 1 + 2 * 1000,
+			}
+		);
+	}
+
+	#[test]
+	fn compare_reports_significant_changes() {
+		let new = OLD
+			.replace("Weight::from_parts(31_007_000, 0)", "Weight::from_parts(40_000_000, 0)")
+			.replace("Weight::from_parts(12_049_659, 0)", "Weight::from_parts(12_049_700, 0)")
+			.replace("fn touch()", "fn refund()");
+
+		let changes =
+			compare("pallet_assets.rs", &parse_weight_file(OLD), &parse_weight_file(&new), 5.0);
+		let summary = changes.iter().map(|c| (c.extrinsic.as_str(), c.kind)).collect::<Vec<_>>();
+		assert_eq!(
+			summary,
+			vec![
+				("create", ChangeKind::Changed),
+				("refund", ChangeKind::Added),
+				("touch", ChangeKind::Removed),
+			]
+		);
+
+		assert!(changes[0].is_regression(5.0));
+		assert!(!changes[0].is_regression(50.0));
+		assert!((changes[0].ref_time_change.unwrap() - 29.0).abs() < 0.1);
+
+		let markdown = render_markdown(&changes);
+		assert!(markdown
+			.contains("| pallet_assets.rs | `create` | changed | 31007000 → 40000000 | +29.00% |"));
+	}
+}
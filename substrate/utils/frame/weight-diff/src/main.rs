@@ -0,0 +1,79 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compare two versions of generated weight files and report significant changes.
+
+use clap::{Parser, ValueEnum};
+use frame_weight_diff::{compare_paths, render_markdown};
+use std::{path::PathBuf, process::ExitCode};
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+	Markdown,
+	Json,
+}
+
+#[derive(Debug, Parser)]
+#[command(about = "Compare two versions of generated FRAME weight files")]
+struct Opt {
+	/// The old weight file, or a directory of weight files.
+	old: PathBuf,
+
+	/// The new weight file, or a directory of weight files.
+	new: PathBuf,
+
+	/// Only report ref-time and proof-size changes above this many percent.
+	#[arg(long, default_value_t = 5.0)]
+	threshold: f64,
+
+	/// The output format.
+	#[arg(long, value_enum, default_value_t = Format::Markdown)]
+	format: Format,
+
+	/// Exit with an error if the ref-time or proof-size of any extrinsic increased by more than
+	/// the threshold.
+	#[arg(long)]
+	fail_on_regression: bool,
+}
+
+fn main() -> ExitCode {
+	let opt = Opt::parse();
+
+	let changes = match compare_paths(&opt.old, &opt.new, opt.threshold) {
+		Ok(changes) => changes,
+		Err(err) => {
+			eprintln!("{err}");
+			return ExitCode::FAILURE
+		},
+	};
+
+	match opt.format {
+		Format::Markdown => print!("{}", render_markdown(&changes)),
+		Format::Json => println!(
+			"{}",
+			serde_json::to_string_pretty(&changes).expect("changes are serializable; qed")
+		),
+	}
+
+	let regressions = changes.iter().filter(|c| c.is_regression(opt.threshold)).count();
+	if opt.fail_on_regression && regressions > 0 {
+		eprintln!("{regressions} extrinsic(s) regressed by more than {}%", opt.threshold);
+		return ExitCode::FAILURE
+	}
+
+	ExitCode::SUCCESS
+}
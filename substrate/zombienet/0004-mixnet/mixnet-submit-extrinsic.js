@@ -0,0 +1,53 @@
+const assert = require("assert");
+
+const RETRY_INTERVAL_MS = 6000;
+
+async function run(nodeName, networkInfo, args) {
+  const {wsUri, userDefinedTypes} = networkInfo.nodesByName[nodeName];
+  const api = await zombie.connect(wsUri, userDefinedTypes);
+
+  const keyring = new zombie.Keyring({ type: "sr25519" });
+  const alice = keyring.addFromUri('//Alice');
+  const bob = keyring.addFromUri('//Bob').address;
+
+  // wait for enough mixnodes to be registered and active
+  while (true) {
+    const mixnodes = await api.call.mixnetApi.currentMixnodes();
+    if (mixnodes.isOk) {
+      console.log('Current mixnodes:', mixnodes.asOk.length);
+      break;
+    }
+    console.log('Mixnodes not ready yet:', mixnodes.asErr.toString());
+    await new Promise(resolve => setTimeout(resolve, RETRY_INTERVAL_MS));
+  }
+
+  const { data: { free: before } } = await api.query.system.account(bob);
+  const transfer = await api.tx.balances
+    .transferAllowDeath(bob, 10n**12n)
+    .signAsync(alice, { nonce: -1 });
+
+  // the mixnet may need a few more blocks to connect to the mixnodes, so retry the submission
+  while (true) {
+    try {
+      await api._rpcCore.provider.send('mixnet_submitExtrinsic', [transfer.toHex()]);
+      break;
+    } catch (error) {
+      console.log('Mixnet submission failed, retrying:', error.toString());
+      await new Promise(resolve => setTimeout(resolve, RETRY_INTERVAL_MS));
+    }
+  }
+  console.log('Submitted transfer through the mixnet');
+
+  while (true) {
+    const { data: { free: after } } = await api.query.system.account(bob);
+    if (after.gt(before)) {
+      console.log('Transfer included, balance of Bob:', after.toString());
+      break;
+    }
+    await new Promise(resolve => setTimeout(resolve, RETRY_INTERVAL_MS));
+  }
+
+  assert.ok("test passed");
+}
+
+module.exports = { run }